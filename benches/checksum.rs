@@ -0,0 +1,31 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rs_computer_science::algorithm::checksum::{Adler32, CRC32, Fletcher16, Fletcher32};
+use rs_computer_science::algorithm::random::{Rng, XorShift64Star};
+use std::hint::black_box;
+
+fn throughput(c: &mut Criterion) {
+    let mut rng = XorShift64Star::seed_from_u64(0x9E37_79B9_7F4A_7C15);
+    let data: Vec<u8> = (0..1 << 20).map(|_| rng.next_u64() as u8).collect();
+    // The modular sums need an addition or two per byte; the CRC a table lookup
+    let mut group = c.benchmark_group("checksum");
+    for len in [64usize, 4096, 1 << 20] {
+        let input = &data[..len];
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("crc32", len), input, |b, d| {
+            b.iter(|| CRC32.checksum(black_box(d)))
+        });
+        group.bench_with_input(BenchmarkId::new("adler32", len), input, |b, d| {
+            b.iter(|| Adler32::checksum(black_box(d)))
+        });
+        group.bench_with_input(BenchmarkId::new("fletcher16", len), input, |b, d| {
+            b.iter(|| Fletcher16::checksum(black_box(d)))
+        });
+        group.bench_with_input(BenchmarkId::new("fletcher32", len), input, |b, d| {
+            b.iter(|| Fletcher32::checksum(black_box(d)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, throughput);
+criterion_main!(benches);
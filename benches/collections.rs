@@ -0,0 +1,102 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rs_computer_science::concurrency::BlockingQueue;
+use rs_computer_science::data_structure::{LinkedList, Queue};
+use std::collections::{self, VecDeque};
+use std::hint::black_box;
+
+fn list_push_pop(c: &mut Criterion) {
+    // Pushing at one end and popping at the other, the pattern all three
+    // support in O(1); VecDeque wins on locality, not complexity
+    let mut group = c.benchmark_group("list_push_pop");
+    for len in [1_000u64, 100_000] {
+        group.bench_with_input(BenchmarkId::new("LinkedList", len), &len, |b, &len| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                for i in 0..len {
+                    list.insert_at_tail(black_box(i));
+                }
+                while list.delete_head().is_some() {}
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std_LinkedList", len), &len, |b, &len| {
+            b.iter(|| {
+                let mut list = collections::LinkedList::new();
+                for i in 0..len {
+                    list.push_back(black_box(i));
+                }
+                while list.pop_front().is_some() {}
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", len), &len, |b, &len| {
+            b.iter(|| {
+                let mut deque = VecDeque::new();
+                for i in 0..len {
+                    deque.push_back(black_box(i));
+                }
+                while deque.pop_front().is_some() {}
+            })
+        });
+    }
+    group.finish();
+}
+
+fn list_indexing(c: &mut Criterion) {
+    // Linked lists walk to an index, so reading every element by index is
+    // quadratic against VecDeque's linear
+    let mut group = c.benchmark_group("list_indexing");
+    for len in [100i32, 1_000] {
+        let mut list = LinkedList::new();
+        for i in 0..len {
+            list.insert_at_tail(i);
+        }
+        let deque: VecDeque<i32> = (0..len).collect();
+        group.bench_with_input(BenchmarkId::new("LinkedList", len), &len, |b, &len| {
+            b.iter(|| (0..len).filter_map(|i| list.get(black_box(i))).sum::<i32>())
+        });
+        group.bench_with_input(BenchmarkId::new("VecDeque", len), &len, |b, &len| {
+            b.iter(|| {
+                (0..len as usize)
+                    .filter_map(|i| deque.get(black_box(i)))
+                    .sum::<i32>()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn queue_backends(c: &mut Criterion) {
+    // Queue is backed by a linked list; BlockingQueue adds a mutex on top
+    let mut group = c.benchmark_group("queue_backends");
+    let len = 100_000u64;
+    group.bench_function("Queue", |b| {
+        b.iter(|| {
+            let mut queue = Queue::new();
+            for i in 0..len {
+                queue.enqueue(black_box(i));
+            }
+            while queue.dequeue().is_some() {}
+        })
+    });
+    group.bench_function("VecDeque", |b| {
+        b.iter(|| {
+            let mut queue = VecDeque::new();
+            for i in 0..len {
+                queue.push_back(black_box(i));
+            }
+            while queue.pop_front().is_some() {}
+        })
+    });
+    group.bench_function("BlockingQueue", |b| {
+        b.iter(|| {
+            let queue = BlockingQueue::new();
+            for i in 0..len {
+                queue.push(black_box(i)).unwrap();
+            }
+            while queue.try_pop().is_some() {}
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, list_push_pop, list_indexing, queue_backends);
+criterion_main!(benches);
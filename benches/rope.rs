@@ -0,0 +1,35 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rs_computer_science::data_structure::Rope;
+use std::hint::black_box;
+
+const INSERTS: usize = 1000;
+
+fn middle_insertion(c: &mut Criterion) {
+    // String pays O(n) per insertion to shift the tail; the rope pays O(log n)
+    let mut group = c.benchmark_group("middle_insertion");
+    for len in [10_000usize, 100_000, 1_000_000] {
+        let text = "a".repeat(len);
+        group.bench_with_input(BenchmarkId::new("String", len), &text, |b, text| {
+            b.iter(|| {
+                let mut s = text.clone();
+                for _ in 0..INSERTS {
+                    s.insert_str(s.len() / 2, black_box("hello"));
+                }
+                s.len()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("Rope", len), &text, |b, text| {
+            b.iter(|| {
+                let mut rope = Rope::from(text.as_str());
+                for _ in 0..INSERTS {
+                    rope.insert(rope.len_chars() / 2, black_box("hello"));
+                }
+                rope.len_chars()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, middle_insertion);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rs_computer_science::algorithm::search::{
+    binary_search, exponential_search, interpolation_search,
+};
+use std::hint::black_box;
+
+const LEN: u64 = 1_000_000;
+
+fn uniform_keys(c: &mut Criterion) {
+    // Evenly spaced keys are interpolation search's best case
+    let arr: Vec<u64> = (0..LEN).map(|i| i * 7).collect();
+    let targets: Vec<u64> = (0..1000).map(|i| i * 6_999 % (LEN * 7)).collect();
+    let mut group = c.benchmark_group("uniform_keys");
+    group.bench_function("binary_search", |b| {
+        b.iter(|| {
+            targets
+                .iter()
+                .filter_map(|t| binary_search(&arr, black_box(t)))
+                .count()
+        })
+    });
+    group.bench_function("interpolation_search", |b| {
+        b.iter(|| {
+            targets
+                .iter()
+                .filter_map(|t| interpolation_search(&arr, black_box(t)))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn skewed_keys(c: &mut Criterion) {
+    // Quadratically growing keys mislead interpolation until the binary fallback kicks in
+    let arr: Vec<u64> = (0..LEN).map(|i| i * i).collect();
+    let targets: Vec<u64> = (0..1000u64).map(|i| (i * 997) * (i * 997)).collect();
+    let mut group = c.benchmark_group("skewed_keys");
+    group.bench_function("binary_search", |b| {
+        b.iter(|| {
+            targets
+                .iter()
+                .filter_map(|t| binary_search(&arr, black_box(t)))
+                .count()
+        })
+    });
+    group.bench_function("interpolation_search", |b| {
+        b.iter(|| {
+            targets
+                .iter()
+                .filter_map(|t| interpolation_search(&arr, black_box(t)))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn targets_near_front(c: &mut Criterion) {
+    // Exponential search pays O(log i), so it wins when matches are close to index 0
+    let arr: Vec<u64> = (0..LEN).collect();
+    let mut group = c.benchmark_group("targets_near_front");
+    for position in [10u64, 1_000, 100_000] {
+        group.bench_with_input(
+            BenchmarkId::new("binary_search", position),
+            &position,
+            |b, t| b.iter(|| binary_search(&arr, black_box(t))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("exponential_search", position),
+            &position,
+            |b, t| b.iter(|| exponential_search(&arr, black_box(t))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, uniform_keys, skewed_keys, targets_near_front);
+criterion_main!(benches);
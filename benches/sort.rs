@@ -0,0 +1,81 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rs_computer_science::algorithm::random::{Rng, XorShift64Star};
+use rs_computer_science::algorithm::sort::{
+    GapSequence, heapsort, introsort, lsd_radix_sort, lsd_radix_sort_with_radix, par_merge_sort,
+    par_quicksort, shell_sort, timsort,
+};
+use std::hint::black_box;
+
+fn random_u32(len: usize, seed: u64) -> Vec<u32> {
+    let mut rng = XorShift64Star::seed_from_u64(seed);
+    (0..len).map(|_| rng.next_u32()).collect()
+}
+
+fn radix_vs_comparison(c: &mut Criterion) {
+    let mut group = c.benchmark_group("radix_vs_comparison");
+    for len in [10_000, 100_000, 1_000_000] {
+        let data = random_u32(len, 0x2545_F491_4F6C_DD1D);
+        group.bench_with_input(BenchmarkId::new("lsd_radix_sort", len), &data, |b, data| {
+            b.iter(|| lsd_radix_sort(black_box(&mut data.clone())))
+        });
+        group.bench_with_input(BenchmarkId::new("heapsort", len), &data, |b, data| {
+            b.iter(|| heapsort(black_box(&mut data.clone())))
+        });
+        group.bench_with_input(BenchmarkId::new("sort_unstable", len), &data, |b, data| {
+            b.iter(|| black_box(&mut data.clone()).sort_unstable())
+        });
+    }
+    group.finish();
+}
+
+fn radix_digit_width(c: &mut Criterion) {
+    let mut group = c.benchmark_group("radix_digit_width");
+    let data = random_u32(1_000_000, 0x2545_F491_4F6C_DD1D);
+    for digit_bits in [4, 8, 11, 16] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(digit_bits),
+            &digit_bits,
+            |b, &digit_bits| b.iter(|| lsd_radix_sort_with_radix(&mut data.clone(), digit_bits)),
+        );
+    }
+    group.finish();
+}
+
+fn shell_gap_sequences(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shell_gap_sequences");
+    let data = random_u32(100_000, 0x2545_F491_4F6C_DD1D);
+    for (name, gaps) in [
+        ("shell", GapSequence::Shell),
+        ("knuth", GapSequence::Knuth),
+        ("sedgewick", GapSequence::Sedgewick),
+        ("ciura", GapSequence::Ciura),
+    ] {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &gaps, |b, gaps| {
+            b.iter(|| shell_sort(&mut data.clone(), gaps))
+        });
+    }
+    group.finish();
+}
+
+fn parallel_speedup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_speedup");
+    let data = random_u32(1_000_000, 0x2545_F491_4F6C_DD1D);
+    group.bench_function("timsort", |b| b.iter(|| timsort(&mut data.clone())));
+    group.bench_function("par_merge_sort", |b| {
+        b.iter(|| par_merge_sort(&mut data.clone()))
+    });
+    group.bench_function("introsort", |b| b.iter(|| introsort(&mut data.clone())));
+    group.bench_function("par_quicksort", |b| {
+        b.iter(|| par_quicksort(&mut data.clone()))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    radix_vs_comparison,
+    radix_digit_width,
+    shell_gap_sequences,
+    parallel_speedup
+);
+criterion_main!(benches);
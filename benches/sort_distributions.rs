@@ -0,0 +1,55 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rs_computer_science::algorithm::sort::{
+    GapSequence, heapsort, introsort, par_merge_sort, par_quicksort, shell_sort, timsort,
+};
+use rs_computer_science::testgen::{Shape, TestGen};
+
+const LEN: usize = 100_000;
+
+type Sort = fn(&mut [i64]);
+
+/// Inputs that favour different algorithms: runs help timsort, few
+/// distinct keys hurt naive partitioning, reversed input is the classic
+/// quicksort trap
+fn distributions() -> Vec<(&'static str, Vec<i64>)> {
+    let mut tg = TestGen::new(0x2545_F491_4F6C_DD1D);
+    let sawtooth: Vec<i64> = (0..LEN as i64).map(|i| i % 1000).collect();
+    vec![
+        ("random", tg.ints(LEN, Shape::Uniform)),
+        ("sorted", tg.ints(LEN, Shape::Sorted)),
+        ("reversed", tg.ints(LEN, Shape::Reversed)),
+        (
+            "nearly_sorted",
+            tg.ints(LEN, Shape::NearlySorted(LEN / 100)),
+        ),
+        ("few_unique", tg.ints(LEN, Shape::FewUniques(8))),
+        ("sawtooth", sawtooth),
+    ]
+}
+
+fn sorts_by_distribution(c: &mut Criterion) {
+    let sorts: [(&str, Sort); 8] = [
+        ("timsort", timsort),
+        ("introsort", introsort),
+        ("heapsort", heapsort),
+        ("shell_sort_ciura", |arr| {
+            shell_sort(arr, &GapSequence::Ciura)
+        }),
+        ("par_merge_sort", par_merge_sort),
+        ("par_quicksort", par_quicksort),
+        ("sort", <[i64]>::sort),
+        ("sort_unstable", <[i64]>::sort_unstable),
+    ];
+    for (distribution, data) in distributions() {
+        let mut group = c.benchmark_group(format!("sort_{distribution}"));
+        for (name, sort) in sorts {
+            group.bench_with_input(BenchmarkId::from_parameter(name), &data, |b, data| {
+                b.iter(|| sort(&mut data.clone()))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, sorts_by_distribution);
+criterion_main!(benches);
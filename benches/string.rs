@@ -0,0 +1,72 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rs_computer_science::algorithm::random::{Rng, XorShift64Star};
+use rs_computer_science::algorithm::string::{
+    boyer_moore_search, horspool_search, kmp_search, rabin_karp_search,
+};
+use std::hint::black_box;
+
+type Search = fn(&str, &str) -> Vec<usize>;
+
+const WORDS: [&str; 24] = [
+    "the",
+    "of",
+    "and",
+    "a",
+    "to",
+    "in",
+    "is",
+    "was",
+    "that",
+    "for",
+    "it",
+    "with",
+    "as",
+    "his",
+    "on",
+    "be",
+    "at",
+    "by",
+    "which",
+    "from",
+    "algorithm",
+    "string",
+    "search",
+    "pattern",
+];
+
+/// Pseudo-English text: words drawn from a skewed distribution, separated by spaces
+fn english_like(len: usize) -> String {
+    let mut rng = XorShift64Star::seed_from_u64(0x9E37_79B9_7F4A_7C15);
+    let mut text = String::with_capacity(len + 16);
+    while text.len() < len {
+        // Squaring a uniform draw favours the short, common words at the front
+        let r = rng.gen_index(1000);
+        text.push_str(WORDS[r * r * WORDS.len() / 1_000_000]);
+        text.push(' ');
+    }
+    text
+}
+
+fn long_text(c: &mut Criterion) {
+    let text = english_like(1 << 20);
+    let searches: [(&str, Search); 4] = [
+        ("kmp", kmp_search),
+        ("rabin_karp", rabin_karp_search),
+        ("boyer_moore", boyer_moore_search),
+        ("horspool", horspool_search),
+    ];
+    // Longer patterns let the Boyer–Moore family skip further
+    let patterns = ["the", "string search", "pattern matching algorithm"];
+    let mut group = c.benchmark_group("english_text");
+    for pattern in patterns {
+        for (name, search) in searches {
+            group.bench_with_input(BenchmarkId::new(name, pattern.len()), pattern, |b, p| {
+                b.iter(|| search(black_box(&text), p).len())
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, long_text);
+criterion_main!(benches);
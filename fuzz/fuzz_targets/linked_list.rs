@@ -0,0 +1,94 @@
+//! Drives `LinkedList` with arbitrary operations, checking it against a `Vec`
+//! and walking the raw links after every step.
+//!
+//! Run with `cargo +nightly fuzz run linked_list` from the repository root.
+//! Miri (`cargo +nightly miri test`) catches more of the UB this can only hint at.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use rs_computer_science::data_structure::LinkedList;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    InsertAtHead(u8),
+    InsertAtTail(u8),
+    /// The index is taken modulo `len + 1`, so every insert is in range
+    InsertAtIth(u8, u8),
+    DeleteHead,
+    DeleteTail,
+    /// The index is taken modulo `len + 1`, so one past the end is tried too
+    DeleteIth(u8),
+    Get(i8),
+}
+
+/// Checks that the length matches both walks and that every `next` is
+/// mirrored by a `prev`, then compares the values with the model
+fn check(list: &LinkedList<u8>, model: &[u8]) {
+    assert_eq!(list.length as usize, model.len());
+    assert_eq!(list.head.is_none(), model.is_empty());
+    assert_eq!(list.tail.is_none(), model.is_empty());
+
+    let mut forward = Vec::new();
+    let mut prev = None;
+    let mut cursor = list.head;
+    while let Some(node) = cursor {
+        let node_ref = unsafe { node.as_ref() };
+        assert_eq!(node_ref.prev, prev, "prev link broken at {}", forward.len());
+        forward.push(node_ref.val);
+        assert!(forward.len() <= model.len(), "cycle in next links");
+        prev = Some(node);
+        cursor = node_ref.next;
+    }
+    assert_eq!(prev, list.tail, "last node is not the tail");
+    assert_eq!(forward, model);
+
+    let mut backward = Vec::new();
+    let mut cursor = list.tail;
+    while let Some(node) = cursor {
+        let node_ref = unsafe { node.as_ref() };
+        backward.push(node_ref.val);
+        assert!(backward.len() <= model.len(), "cycle in prev links");
+        cursor = node_ref.prev;
+    }
+    backward.reverse();
+    assert_eq!(backward, model);
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut list = LinkedList::new();
+    let mut model: Vec<u8> = Vec::new();
+    for op in ops {
+        match op {
+            Op::InsertAtHead(value) => {
+                list.insert_at_head(value);
+                model.insert(0, value);
+            }
+            Op::InsertAtTail(value) => {
+                list.insert_at_tail(value);
+                model.push(value);
+            }
+            Op::InsertAtIth(index, value) => {
+                let index = index as usize % (model.len() + 1);
+                list.insert_at_ith(index as u32, value);
+                model.insert(index, value);
+            }
+            Op::DeleteHead => {
+                let expected = (!model.is_empty()).then(|| model.remove(0));
+                assert_eq!(list.delete_head(), expected);
+            }
+            Op::DeleteTail => assert_eq!(list.delete_tail(), model.pop()),
+            Op::DeleteIth(index) => {
+                let index = index as usize % (model.len() + 1);
+                let expected = (index < model.len()).then(|| model.remove(index));
+                assert_eq!(list.delete_ith(index as u32), expected);
+            }
+            Op::Get(index) => {
+                let expected = usize::try_from(index).ok().and_then(|i| model.get(i));
+                assert_eq!(list.get(index as i32), expected);
+            }
+        }
+        check(&list, &model);
+    }
+});
@@ -0,0 +1,73 @@
+const MOD_ADLER: u32 = 65521;
+/// Most bytes summable before `b` could overflow a u32 between reductions
+const NMAX: usize = 5552;
+
+/// Adler-32, the zlib checksum: two running sums modulo the largest prime
+/// below 2^16, `a` of the bytes (plus one) and `b` of the successive `a`s.
+///
+/// Cheaper than a CRC, but weak on short inputs, where `a` barely fills its
+/// 16 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    pub fn new() -> Adler32 {
+        Adler32 { a: 1, b: 0 }
+    }
+
+    /// The checksum of `data` in one call
+    pub fn checksum(data: &[u8]) -> u32 {
+        let mut adler = Adler32::new();
+        adler.update(data);
+        adler.finalize()
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        // Reduce once per chunk instead of once per byte
+        for chunk in data.chunks(NMAX) {
+            for &byte in chunk {
+                self.a += u32::from(byte);
+                self.b += self.a;
+            }
+            self.a %= MOD_ADLER;
+            self.b %= MOD_ADLER;
+        }
+    }
+
+    pub fn finalize(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+impl Default for Adler32 {
+    fn default() -> Self {
+        Adler32::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Adler32;
+
+    #[test]
+    fn known_values() {
+        assert_eq!(Adler32::checksum(b""), 1);
+        assert_eq!(Adler32::checksum(b"Wikipedia"), 0x11E6_0398);
+        assert_eq!(Adler32::checksum(b"123456789"), 0x091E_01DE);
+        // Sums far past NMAX exercise the deferred reduction
+        let data = vec![0xFF; 100_000];
+        let (mut a, mut b) = (1u64, 0u64);
+        for &byte in &data {
+            a = (a + u64::from(byte)) % 65521;
+            b = (b + a) % 65521;
+        }
+        let mut adler = Adler32::new();
+        for chunk in data.chunks(7777) {
+            adler.update(chunk);
+        }
+        assert_eq!(u64::from(adler.finalize()), (b << 16) | a);
+    }
+}
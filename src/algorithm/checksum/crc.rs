@@ -0,0 +1,262 @@
+/// Parameters of a CRC in the usual Rocksoft model: the register width, the
+/// generator polynomial (top bit implied), the initial register, whether
+/// bytes and the result are bit-reflected, and the final XOR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcParams {
+    pub width: u32,
+    pub poly: u64,
+    pub init: u64,
+    pub reflect: bool,
+    pub xor_out: u64,
+}
+
+/// A table-driven CRC of width 8 to 64 bits.
+///
+/// The 256-entry table holds the register change caused by each possible
+/// leading byte, so data is processed a byte per lookup. `Crc::new` is a
+/// `const fn`, so presets are computed at compile time.
+#[derive(Debug, Clone)]
+pub struct Crc {
+    params: CrcParams,
+    table: [u64; 256],
+}
+
+/// CRC-32 as used by Ethernet, zlib and PNG
+pub const CRC32: Crc = Crc::new(CrcParams {
+    width: 32,
+    poly: 0x04C1_1DB7,
+    init: 0xFFFF_FFFF,
+    reflect: true,
+    xor_out: 0xFFFF_FFFF,
+});
+
+/// CRC-32C (Castagnoli), as used by iSCSI, ext4 and SSE4.2's `crc32`
+pub const CRC32C: Crc = Crc::new(CrcParams {
+    width: 32,
+    poly: 0x1EDC_6F41,
+    init: 0xFFFF_FFFF,
+    reflect: true,
+    xor_out: 0xFFFF_FFFF,
+});
+
+/// CRC-16/CCITT-FALSE, the CCITT polynomial with an all-ones start
+pub const CRC16_CCITT: Crc = Crc::new(CrcParams {
+    width: 16,
+    poly: 0x1021,
+    init: 0xFFFF,
+    reflect: false,
+    xor_out: 0,
+});
+
+const fn mask(width: u32) -> u64 {
+    if width == 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+const fn reflect_bits(value: u64, width: u32) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
+
+impl Crc {
+    /// Builds the lookup table. Panics if the width is outside 8..=64
+    pub const fn new(params: CrcParams) -> Crc {
+        assert!(
+            params.width >= 8 && params.width <= 64,
+            "CRC width must be 8 to 64 bits"
+        );
+        let width = params.width;
+        let mut table = [0; 256];
+        let mut byte = 0;
+        while byte < 256 {
+            let mut register;
+            if params.reflect {
+                // Least significant bit first, with the polynomial mirrored
+                let poly = reflect_bits(params.poly, width);
+                register = byte as u64;
+                let mut bit = 0;
+                while bit < 8 {
+                    register = if register & 1 == 1 {
+                        (register >> 1) ^ poly
+                    } else {
+                        register >> 1
+                    };
+                    bit += 1;
+                }
+            } else {
+                let top = 1 << (width - 1);
+                register = (byte as u64) << (width - 8);
+                let mut bit = 0;
+                while bit < 8 {
+                    register = if register & top != 0 {
+                        (register << 1) ^ params.poly
+                    } else {
+                        register << 1
+                    };
+                    bit += 1;
+                }
+            }
+            table[byte] = register & mask(width);
+            byte += 1;
+        }
+        Crc { params, table }
+    }
+
+    pub fn params(&self) -> &CrcParams {
+        &self.params
+    }
+
+    /// The CRC of `data` in one call
+    pub fn checksum(&self, data: &[u8]) -> u64 {
+        let mut digest = self.digest();
+        digest.update(data);
+        digest.finalize()
+    }
+
+    /// Starts an incremental computation
+    pub fn digest(&self) -> Digest<'_> {
+        let init = self.params.init & mask(self.params.width);
+        let register = if self.params.reflect {
+            reflect_bits(init, self.params.width)
+        } else {
+            init
+        };
+        Digest {
+            crc: self,
+            register,
+        }
+    }
+}
+
+/// A CRC computation in progress, fed with `update`
+#[derive(Debug, Clone)]
+pub struct Digest<'a> {
+    crc: &'a Crc,
+    register: u64,
+}
+
+impl Digest<'_> {
+    pub fn update(&mut self, data: &[u8]) {
+        let CrcParams { width, reflect, .. } = self.crc.params;
+        let table = &self.crc.table;
+        for &byte in data {
+            self.register = if reflect {
+                (self.register >> 8) ^ table[((self.register ^ u64::from(byte)) & 0xFF) as usize]
+            } else {
+                let index = ((self.register >> (width - 8)) ^ u64::from(byte)) & 0xFF;
+                ((self.register << 8) ^ table[index as usize]) & mask(width)
+            };
+        }
+    }
+
+    /// The CRC of everything fed so far
+    pub fn finalize(&self) -> u64 {
+        (self.register ^ self.crc.params.xor_out) & mask(self.crc.params.width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CRC16_CCITT, CRC32, CRC32C, Crc, CrcParams};
+
+    /// Bit-at-a-time reference, straight from the definition
+    fn bitwise(params: CrcParams, data: &[u8]) -> u64 {
+        let mask = u64::MAX >> (64 - params.width);
+        let top = 1 << (params.width - 1);
+        let mut register = params.init & mask;
+        for &byte in data {
+            let byte = if params.reflect {
+                byte.reverse_bits()
+            } else {
+                byte
+            };
+            for bit in (0..8).rev() {
+                let incoming = (u64::from(byte) >> bit) & 1 == 1;
+                let feedback = (register & top != 0) != incoming;
+                register = (register << 1) & mask;
+                if feedback {
+                    register ^= params.poly;
+                }
+            }
+        }
+        if params.reflect {
+            register = register.reverse_bits() >> (64 - params.width);
+        }
+        (register ^ params.xor_out) & mask
+    }
+
+    #[test]
+    fn check_values() {
+        // The standard check input is the ASCII digits 1 to 9
+        assert_eq!(CRC32.checksum(b"123456789"), 0xCBF4_3926);
+        assert_eq!(CRC32C.checksum(b"123456789"), 0xE306_9283);
+        assert_eq!(CRC16_CCITT.checksum(b"123456789"), 0x29B1);
+        assert_eq!(CRC32.checksum(b""), 0);
+        assert_eq!(
+            CRC32.checksum(b"The quick brown fox jumps over the lazy dog"),
+            0x414F_A339
+        );
+        let crc64_xz = Crc::new(CrcParams {
+            width: 64,
+            poly: 0x42F0_E1EB_A9EA_3693,
+            init: u64::MAX,
+            reflect: true,
+            xor_out: u64::MAX,
+        });
+        assert_eq!(crc64_xz.checksum(b"123456789"), 0x995D_C9BB_DF19_39FA);
+    }
+
+    #[test]
+    fn incremental_matches_one_shot() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 31 % 251) as u8).collect();
+        for crc in [&CRC32, &CRC32C, &CRC16_CCITT] {
+            let mut digest = crc.digest();
+            for chunk in data.chunks(37) {
+                digest.update(chunk);
+            }
+            assert_eq!(digest.finalize(), crc.checksum(&data));
+        }
+    }
+
+    #[test]
+    fn table_matches_bitwise_definition() {
+        let data: Vec<u8> = (0..300u32).map(|i| (i * 97 + 13) as u8).collect();
+        let params = [
+            *CRC32.params(),
+            *CRC16_CCITT.params(),
+            CrcParams {
+                width: 8,
+                poly: 0x07,
+                init: 0,
+                reflect: false,
+                xor_out: 0,
+            },
+            CrcParams {
+                width: 24,
+                poly: 0x86_4CFB,
+                init: 0xB7_04CE,
+                reflect: false,
+                xor_out: 0,
+            },
+            CrcParams {
+                width: 16,
+                poly: 0x8005,
+                init: 0,
+                reflect: true,
+                xor_out: 0,
+            },
+        ];
+        for params in params {
+            let crc = Crc::new(params);
+            for length in [0, 1, 9, 300] {
+                assert_eq!(
+                    crc.checksum(&data[..length]),
+                    bitwise(params, &data[..length]),
+                    "{params:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/// Fletcher-16: two running sums of bytes modulo 255, the second summing the
+/// first, so unlike a plain sum it notices reordered bytes.
+///
+/// Arithmetic modulo 255 cannot tell the bytes 0x00 and 0xFF apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Fletcher16 {
+    sum1: u32,
+    sum2: u32,
+}
+
+/// Most bytes summable before `sum2` could overflow a u32 between reductions
+const FLETCHER16_BLOCK: usize = 5802;
+
+impl Fletcher16 {
+    pub fn new() -> Fletcher16 {
+        Fletcher16 { sum1: 0, sum2: 0 }
+    }
+
+    /// The checksum of `data` in one call
+    pub fn checksum(data: &[u8]) -> u16 {
+        let mut fletcher = Fletcher16::new();
+        fletcher.update(data);
+        fletcher.finalize()
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        for chunk in data.chunks(FLETCHER16_BLOCK) {
+            for &byte in chunk {
+                self.sum1 += u32::from(byte);
+                self.sum2 += self.sum1;
+            }
+            self.sum1 %= 255;
+            self.sum2 %= 255;
+        }
+    }
+
+    pub fn finalize(&self) -> u16 {
+        ((self.sum2 << 8) | self.sum1) as u16
+    }
+}
+
+/// Fletcher-32: the same scheme over little-endian 16-bit words modulo 65535.
+/// An odd final byte is padded with zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Fletcher32 {
+    sum1: u32,
+    sum2: u32,
+    /// A byte waiting for its partner, when an update ends mid-word
+    pending: Option<u8>,
+}
+
+/// Most words summable before `sum2` could overflow a u32 between reductions
+const FLETCHER32_BLOCK: usize = 359;
+
+impl Fletcher32 {
+    pub fn new() -> Fletcher32 {
+        Fletcher32 {
+            sum1: 0,
+            sum2: 0,
+            pending: None,
+        }
+    }
+
+    /// The checksum of `data` in one call
+    pub fn checksum(data: &[u8]) -> u32 {
+        let mut fletcher = Fletcher32::new();
+        fletcher.update(data);
+        fletcher.finalize()
+    }
+
+    fn add_words(&mut self, words: impl Iterator<Item = u16>) {
+        for (i, word) in words.enumerate() {
+            self.sum1 += u32::from(word);
+            self.sum2 += self.sum1;
+            if (i + 1).is_multiple_of(FLETCHER32_BLOCK) {
+                self.sum1 %= 65535;
+                self.sum2 %= 65535;
+            }
+        }
+        self.sum1 %= 65535;
+        self.sum2 %= 65535;
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        if let Some(low) = self.pending
+            && let Some((&high, rest)) = data.split_first()
+        {
+            self.pending = None;
+            self.add_words(std::iter::once(u16::from_le_bytes([low, high])));
+            data = rest;
+        }
+        let words = data.chunks_exact(2);
+        if let &[last] = words.remainder() {
+            self.pending = Some(last);
+        }
+        self.add_words(words.map(|pair| u16::from_le_bytes([pair[0], pair[1]])));
+    }
+
+    pub fn finalize(&self) -> u32 {
+        let mut copy = *self;
+        if let Some(low) = copy.pending.take() {
+            copy.add_words(std::iter::once(u16::from(low)));
+        }
+        (copy.sum2 << 16) | copy.sum1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fletcher16, Fletcher32};
+
+    #[test]
+    fn known_values() {
+        assert_eq!(Fletcher16::checksum(b"abcde"), 0xC8F0);
+        assert_eq!(Fletcher16::checksum(b"abcdef"), 0x2057);
+        assert_eq!(Fletcher16::checksum(b"abcdefgh"), 0x0627);
+        assert_eq!(Fletcher32::checksum(b"abcde"), 0xF04F_C729);
+        assert_eq!(Fletcher32::checksum(b"abcdef"), 0x5650_2D2A);
+        assert_eq!(Fletcher32::checksum(b"abcdefgh"), 0xEBE1_9591);
+    }
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        let data: Vec<u8> = (0..20_000u32).map(|i| (i * 131 % 256) as u8).collect();
+        for split in [1, 2, 3, 359, 1000, 7000] {
+            let (mut f16, mut f32) = (Fletcher16::new(), Fletcher32::new());
+            for chunk in data.chunks(split) {
+                f16.update(chunk);
+                f32.update(chunk);
+            }
+            assert_eq!(f16.finalize(), Fletcher16::checksum(&data));
+            assert_eq!(f32.finalize(), Fletcher32::checksum(&data));
+        }
+        // Reference without deferred reduction
+        let (mut s1, mut s2) = (0u64, 0u64);
+        for pair in data.chunks(2) {
+            s1 = (s1 + u64::from(pair[0]) + (u64::from(pair[1]) << 8)) % 65535;
+            s2 = (s2 + s1) % 65535;
+        }
+        assert_eq!(u64::from(Fletcher32::checksum(&data)), (s2 << 16) | s1);
+    }
+}
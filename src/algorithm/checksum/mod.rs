@@ -0,0 +1,57 @@
+mod adler;
+mod crc;
+mod fletcher;
+
+pub use self::adler::Adler32;
+pub use self::crc::{CRC16_CCITT, CRC32, CRC32C, Crc, CrcParams, Digest};
+pub use self::fletcher::{Fletcher16, Fletcher32};
+
+#[cfg(test)]
+mod tests {
+    use super::{Adler32, CRC32, Fletcher16, Fletcher32};
+
+    /// Flips one bit at every position of `data` and counts the flips each
+    /// checksum misses
+    fn missed_flips(data: &[u8]) -> [usize; 4] {
+        let sums = |d: &[u8]| {
+            [
+                CRC32.checksum(d),
+                u64::from(Adler32::checksum(d)),
+                u64::from(Fletcher16::checksum(d)),
+                u64::from(Fletcher32::checksum(d)),
+            ]
+        };
+        let original = sums(data);
+        let mut missed = [0; 4];
+        let mut corrupted = data.to_vec();
+        for i in 0..data.len() * 8 {
+            corrupted[i / 8] ^= 1 << (i % 8);
+            for (count, (a, b)) in missed.iter_mut().zip(original.iter().zip(sums(&corrupted))) {
+                if *a == b {
+                    *count += 1;
+                }
+            }
+            corrupted[i / 8] ^= 1 << (i % 8);
+        }
+        missed
+    }
+
+    #[test]
+    fn every_checksum_catches_single_bit_flips() {
+        let data: Vec<u8> = (0..512u32).map(|i| (i * 37 % 251) as u8).collect();
+        assert_eq!(missed_flips(&data), [0; 4]);
+    }
+
+    #[test]
+    fn crc_catches_what_modular_sums_miss() {
+        // Modulo 255, a byte of 0x00 and one of 0xFF weigh the same
+        let (zeros, ones) = (b"\x00data\x00", b"\xFFdata\xFF");
+        assert_eq!(Fletcher16::checksum(zeros), Fletcher16::checksum(ones));
+        assert_ne!(CRC32.checksum(zeros), CRC32.checksum(ones));
+        assert_ne!(Adler32::checksum(zeros), Adler32::checksum(ones));
+        // Likewise modulo 65535 for the words 0x0000 and 0xFFFF
+        let (low, high) = ([0u8, 0, 1, 2], [0xFF, 0xFF, 1, 2]);
+        assert_eq!(Fletcher32::checksum(&low), Fletcher32::checksum(&high));
+        assert_ne!(CRC32.checksum(&low), CRC32.checksum(&high));
+    }
+}
@@ -0,0 +1,259 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Width of the coder's interval bounds
+const PRECISION: u32 = 32;
+const FULL: u64 = (1 << PRECISION) - 1;
+const HALF: u64 = 1 << (PRECISION - 1);
+const QUARTER: u64 = 1 << (PRECISION - 2);
+/// The model halves its counts past this total, which must stay below
+/// `QUARTER` so every symbol keeps a non-empty slice of the narrowest interval
+const MAX_TOTAL: u32 = 1 << 16;
+/// Symbols 0..=255 are bytes and this one marks the end of the data
+const END: usize = 256;
+/// Zero bits the decoder may read past the input before it gives up on
+/// finding the end symbol
+const MAX_PADDING: usize = 2 * PRECISION as usize;
+
+/// Reasons arithmetic-coded bytes fail to decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArithmeticError {
+    /// The input ran out before the end-of-data symbol
+    MissingEnd,
+}
+
+impl Display for ArithmeticError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::MissingEnd => write!(f, "input ends before the end-of-data symbol"),
+        }
+    }
+}
+
+impl Error for ArithmeticError {}
+
+/// Adaptive order-0 model: symbol counts seen so far, starting at one each
+struct Model {
+    counts: Vec<u32>,
+    total: u32,
+}
+
+impl Model {
+    fn new() -> Model {
+        Model {
+            counts: vec![1; END + 1],
+            total: END as u32 + 1,
+        }
+    }
+
+    /// The cumulative range [low, high) of `symbol`
+    fn range(&self, symbol: usize) -> (u32, u32) {
+        let low = self.counts[..symbol].iter().sum::<u32>();
+        (low, low + self.counts[symbol])
+    }
+
+    /// The symbol whose cumulative range contains `target`, and that range
+    fn find(&self, target: u32) -> (usize, (u32, u32)) {
+        let mut low = 0;
+        for (symbol, &count) in self.counts.iter().enumerate() {
+            if target < low + count {
+                return (symbol, (low, low + count));
+            }
+            low += count;
+        }
+        unreachable!("target is below the total")
+    }
+
+    fn update(&mut self, symbol: usize) {
+        self.counts[symbol] += 32;
+        self.total += 32;
+        if self.total > MAX_TOTAL {
+            self.total = 0;
+            for count in &mut self.counts {
+                *count = (*count).div_ceil(2);
+                self.total += *count;
+            }
+        }
+    }
+}
+
+/// The interval shared by encoder and decoder, [low, high] over `PRECISION` bits
+struct Interval {
+    low: u64,
+    high: u64,
+}
+
+impl Interval {
+    /// Narrows to the slice [from, to) out of `total`
+    fn narrow(&mut self, (from, to): (u32, u32), total: u32) {
+        let range = self.high - self.low + 1;
+        self.high = self.low + range * u64::from(to) / u64::from(total) - 1;
+        self.low += range * u64::from(from) / u64::from(total);
+    }
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    filled: u32,
+}
+
+impl BitWriter {
+    fn push(&mut self, bit: bool) {
+        if self.filled.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 0x80 >> (self.filled % 8);
+        }
+        self.filled += 1;
+    }
+}
+
+/// Compresses bytes with an integer arithmetic coder over an adaptive
+/// order-0 model.
+///
+/// The coder keeps an interval [low, high] of 32-bit integers and narrows it
+/// to each symbol's share of the model's counts. Once the top bit of both
+/// bounds agrees it can never change, so it is output and the interval doubled.
+/// When the interval straddles the midpoint while shrinking into the middle
+/// half, the next bit is not yet known: the coder zooms in and counts a
+/// pending bit, written as the opposite of the next settled bit. This plays
+/// the role of carry propagation in range coders, without rewriting output.
+pub fn arithmetic_encode(data: &[u8]) -> Vec<u8> {
+    let mut model = Model::new();
+    let mut interval = Interval { low: 0, high: FULL };
+    let mut out = BitWriter {
+        bytes: Vec::new(),
+        filled: 0,
+    };
+    let mut pending = 0;
+    let emit = |out: &mut BitWriter, pending: &mut usize, bit: bool| {
+        out.push(bit);
+        for _ in 0..std::mem::take(pending) {
+            out.push(!bit);
+        }
+    };
+    for symbol in data.iter().map(|&b| usize::from(b)).chain([END]) {
+        interval.narrow(model.range(symbol), model.total);
+        model.update(symbol);
+        loop {
+            if interval.high < HALF {
+                emit(&mut out, &mut pending, false);
+            } else if interval.low >= HALF {
+                emit(&mut out, &mut pending, true);
+                interval.low -= HALF;
+                interval.high -= HALF;
+            } else if interval.low >= QUARTER && interval.high < HALF + QUARTER {
+                pending += 1;
+                interval.low -= QUARTER;
+                interval.high -= QUARTER;
+            } else {
+                break;
+            }
+            interval.low <<= 1;
+            interval.high = (interval.high << 1) | 1;
+        }
+    }
+    // Two more bits pick a point inside the final interval, whatever follows
+    pending += 1;
+    emit(&mut out, &mut pending, interval.low >= QUARTER);
+    out.bytes
+}
+
+/// Decompresses the output of `arithmetic_encode`
+pub fn arithmetic_decode(encoded: &[u8]) -> Result<Vec<u8>, ArithmeticError> {
+    let mut position = 0;
+    let mut next_bit = || -> Result<u64, ArithmeticError> {
+        let bit = match encoded.get(position / 8) {
+            Some(byte) => u64::from(byte >> (7 - position % 8) & 1),
+            None if position < encoded.len() * 8 + MAX_PADDING => 0,
+            None => return Err(ArithmeticError::MissingEnd),
+        };
+        position += 1;
+        Ok(bit)
+    };
+    let mut model = Model::new();
+    let mut interval = Interval { low: 0, high: FULL };
+    let mut value = 0;
+    for _ in 0..PRECISION {
+        value = (value << 1) | next_bit()?;
+    }
+    let mut decoded = Vec::new();
+    loop {
+        let range = interval.high - interval.low + 1;
+        let target = ((value - interval.low + 1) * u64::from(model.total) - 1) / range;
+        let (symbol, slice) = model.find(target as u32);
+        if symbol == END {
+            return Ok(decoded);
+        }
+        decoded.push(symbol as u8);
+        interval.narrow(slice, model.total);
+        model.update(symbol);
+        loop {
+            let shift = if interval.high < HALF {
+                0
+            } else if interval.low >= HALF {
+                HALF
+            } else if interval.low >= QUARTER && interval.high < HALF + QUARTER {
+                QUARTER
+            } else {
+                break;
+            };
+            interval.low = (interval.low - shift) << 1;
+            interval.high = ((interval.high - shift) << 1) | 1;
+            value = ((value - shift) << 1) | next_bit()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArithmeticError, arithmetic_decode, arithmetic_encode};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn round_trip(data: &[u8]) -> usize {
+        let encoded = arithmetic_encode(data);
+        assert_eq!(arithmetic_decode(&encoded).unwrap(), data);
+        encoded.len()
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip(b"");
+        round_trip(b"a");
+        round_trip(b"abracadabra");
+        round_trip(&(0..=255).collect::<Vec<u8>>());
+        round_trip(&[0xFF; 3]);
+        let mut rng = XorShift64Star::seed_from_u64(0x1234_5678);
+        for len in [10, 1000, 50_000] {
+            let data: Vec<u8> = (0..len).map(|_| rng.next_u64() as u8).collect();
+            round_trip(&data);
+        }
+    }
+
+    #[test]
+    fn compresses_skewed_data() {
+        // A long single run adapts to a few bits per thousand symbols
+        assert!(round_trip(&[b'x'; 100_000]) < 200);
+        // Four equally likely symbols approach two bits each
+        let mut rng = XorShift64Star::seed_from_u64(0xABCD);
+        let data: Vec<u8> = (0..40_000).map(|_| *rng.choose(b"acgt").unwrap()).collect();
+        let size = round_trip(&data);
+        assert!(size < 10_100, "{size} bytes");
+        let text = "the quick brown fox jumps over the lazy dog. ".repeat(200);
+        // Order-0 English text needs a little over four bits a character
+        assert!(round_trip(text.as_bytes()) < text.len() * 6 / 10);
+    }
+
+    #[test]
+    fn decoding_truncated_input_fails_or_differs() {
+        let data = b"some text that needs more than a few bytes to encode".repeat(4);
+        let encoded = arithmetic_encode(&data);
+        for cut in [1, encoded.len() / 2, encoded.len() - 1] {
+            match arithmetic_decode(&encoded[..cut]) {
+                Ok(decoded) => assert_ne!(decoded, data),
+                Err(error) => assert_eq!(error, ArithmeticError::MissingEnd),
+            }
+        }
+    }
+}
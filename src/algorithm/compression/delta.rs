@@ -0,0 +1,147 @@
+use crate::algorithm::encoding::{VarintError, decode_varints, encode_varints};
+
+/// A reversible in-place transform of an integer sequence, usually applied
+/// before a generic coder to make the values smaller or more repetitive
+pub trait SequenceTransform {
+    fn forward(&self, values: &mut [i64]);
+
+    fn inverse(&self, values: &mut [i64]);
+
+    /// Applies `self`, then `next`
+    fn then<U: SequenceTransform>(self, next: U) -> Then<Self, U>
+    where
+        Self: Sized,
+    {
+        Then(self, next)
+    }
+}
+
+/// The identity transform
+impl SequenceTransform for () {
+    fn forward(&self, _: &mut [i64]) {}
+
+    fn inverse(&self, _: &mut [i64]) {}
+}
+
+/// Replaces each value by its difference from the previous one, keeping the
+/// first. Sorted or slowly varying sequences become small numbers.
+/// Differences wrap, so any sequence round-trips
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Delta;
+
+impl SequenceTransform for Delta {
+    fn forward(&self, values: &mut [i64]) {
+        for i in (1..values.len()).rev() {
+            values[i] = values[i].wrapping_sub(values[i - 1]);
+        }
+    }
+
+    fn inverse(&self, values: &mut [i64]) {
+        for i in 1..values.len() {
+            values[i] = values[i].wrapping_add(values[i - 1]);
+        }
+    }
+}
+
+/// Two transforms in sequence, built by `SequenceTransform::then`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Then<A, B>(pub A, pub B);
+
+impl<A: SequenceTransform, B: SequenceTransform> SequenceTransform for Then<A, B> {
+    fn forward(&self, values: &mut [i64]) {
+        self.0.forward(values);
+        self.1.forward(values);
+    }
+
+    fn inverse(&self, values: &mut [i64]) {
+        self.1.inverse(values);
+        self.0.inverse(values);
+    }
+}
+
+/// Delta-of-delta: the first value, the first difference, then the changes
+/// in difference. Regular sequences such as timestamps become runs of zeros
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeltaOfDelta;
+
+impl SequenceTransform for DeltaOfDelta {
+    fn forward(&self, values: &mut [i64]) {
+        Delta.forward(values);
+        if let Some(differences) = values.get_mut(1..) {
+            Delta.forward(differences);
+        }
+    }
+
+    fn inverse(&self, values: &mut [i64]) {
+        if let Some(differences) = values.get_mut(1..) {
+            Delta.inverse(differences);
+        }
+        Delta.inverse(values);
+    }
+}
+
+/// Transforms `values` and writes them as zigzag varints
+pub fn pack_integers<T: SequenceTransform>(transform: &T, values: &[i64]) -> Vec<u8> {
+    let mut values = values.to_vec();
+    transform.forward(&mut values);
+    encode_varints(&values)
+}
+
+/// Reverses `pack_integers` with the same transform
+pub fn unpack_integers<T: SequenceTransform>(
+    transform: &T,
+    bytes: &[u8],
+) -> Result<Vec<i64>, VarintError> {
+    let mut values = decode_varints(bytes)?;
+    transform.inverse(&mut values);
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Delta, DeltaOfDelta, SequenceTransform, pack_integers, unpack_integers};
+
+    #[test]
+    fn transforms_round_trip() {
+        let mut values = vec![10, 12, 15, 15, 20];
+        Delta.forward(&mut values);
+        assert_eq!(values, [10, 2, 3, 0, 5]);
+        Delta.inverse(&mut values);
+        assert_eq!(values, [10, 12, 15, 15, 20]);
+
+        let mut timestamps = vec![1000, 1060, 1120, 1180, 1241, 1300];
+        DeltaOfDelta.forward(&mut timestamps);
+        assert_eq!(timestamps, [1000, 60, 0, 0, 1, -2]);
+        DeltaOfDelta.inverse(&mut timestamps);
+        assert_eq!(timestamps, [1000, 1060, 1120, 1180, 1241, 1300]);
+
+        let mut extremes = vec![i64::MIN, i64::MAX, 0, i64::MIN, -1];
+        let original = extremes.clone();
+        DeltaOfDelta.forward(&mut extremes);
+        DeltaOfDelta.inverse(&mut extremes);
+        assert_eq!(extremes, original);
+        Delta.forward(&mut []);
+        DeltaOfDelta.forward(&mut []);
+
+        // Composed transforms undo in reverse order
+        let mut values = vec![3, 1, 4, 1, 5];
+        Delta.then(DeltaOfDelta).forward(&mut values);
+        Delta.then(DeltaOfDelta).inverse(&mut values);
+        assert_eq!(values, [3, 1, 4, 1, 5]);
+    }
+
+    #[test]
+    fn packing_shrinks_regular_sequences() {
+        let timestamps: Vec<i64> = (0..1000).map(|i| 1_700_000_000 + 15 * i).collect();
+        let plain = pack_integers(&(), &timestamps);
+        let delta = pack_integers(&Delta, &timestamps);
+        let dod = pack_integers(&DeltaOfDelta, &timestamps);
+        assert_eq!(plain.len(), 5000);
+        assert_eq!(delta.len(), 5 + 999);
+        assert_eq!(dod.len(), 5 + 1 + 998);
+        assert_eq!(unpack_integers(&(), &plain).unwrap(), timestamps);
+        assert_eq!(unpack_integers(&Delta, &delta).unwrap(), timestamps);
+        assert_eq!(unpack_integers(&DeltaOfDelta, &dod).unwrap(), timestamps);
+        assert!(unpack_integers(&Delta, &[0x80]).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+mod arithmetic;
+mod delta;
+mod rle;
+
+pub use self::arithmetic::{ArithmeticError, arithmetic_decode, arithmetic_encode};
+pub use self::delta::{
+    Delta, DeltaOfDelta, SequenceTransform, Then, pack_integers, unpack_integers,
+};
+pub use self::rle::{
+    ESCAPE, RleError, RunLengths, rle_decode, rle_decode_bytes, rle_encode, rle_encode_bytes,
+};
@@ -0,0 +1,199 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::iter::{Peekable, repeat_n};
+
+/// Marks an encoded run in `rle_encode_bytes` output: ESCAPE, count, byte
+pub const ESCAPE: u8 = 0x90;
+
+/// Runs shorter than this are cheaper left as literal bytes
+const MIN_RUN: usize = 4;
+
+/// Reasons encoded RLE bytes fail to decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RleError {
+    /// The input ends inside the escape sequence starting at this offset
+    Truncated { offset: usize },
+    /// The escape sequence at this offset has a count of zero
+    ZeroRun { offset: usize },
+}
+
+impl Display for RleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RleError::Truncated { offset } => {
+                write!(
+                    f,
+                    "input ends inside the escape sequence at offset {offset}"
+                )
+            }
+            RleError::ZeroRun { offset } => write!(f, "zero-length run at offset {offset}"),
+        }
+    }
+}
+
+impl Error for RleError {}
+
+/// Iterator over the maximal runs of equal items, as (item, length)
+pub struct RunLengths<I: Iterator> {
+    items: Peekable<I>,
+}
+
+impl<I> Iterator for RunLengths<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<(I::Item, usize)> {
+        let item = self.items.next()?;
+        let mut length = 1;
+        while self.items.next_if_eq(&item).is_some() {
+            length += 1;
+        }
+        Some((item, length))
+    }
+}
+
+/// Groups a sequence into its runs of equal consecutive items
+pub fn rle_encode<I>(items: I) -> RunLengths<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: PartialEq,
+{
+    RunLengths {
+        items: items.into_iter().peekable(),
+    }
+}
+
+/// Expands (item, length) runs back into the sequence
+pub fn rle_decode<I, T>(runs: I) -> impl Iterator<Item = T>
+where
+    I: IntoIterator<Item = (T, usize)>,
+    T: Clone,
+{
+    runs.into_iter()
+        .flat_map(|(item, length)| repeat_n(item, length))
+}
+
+/// Run-length encodes arbitrary bytes.
+///
+/// Runs of at least 4 become the triple `ESCAPE, count, byte` (longer runs are
+/// split at 255), and shorter runs stay literal. Any `ESCAPE` byte in the data
+/// is itself written as a run, so the decoder never mistakes data for an
+/// escape. Data without runs or escapes comes out unchanged, and the worst
+/// case, isolated `ESCAPE` bytes, triples in size.
+pub fn rle_encode_bytes(data: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(data.len());
+    for (byte, mut length) in rle_encode(data.iter().copied()) {
+        while length > 0 {
+            let chunk = length.min(u8::MAX as usize);
+            if chunk >= MIN_RUN || byte == ESCAPE {
+                encoded.extend([ESCAPE, chunk as u8, byte]);
+            } else {
+                encoded.extend(repeat_n(byte, chunk));
+            }
+            length -= chunk;
+        }
+    }
+    encoded
+}
+
+/// Decodes the output of `rle_encode_bytes`
+pub fn rle_decode_bytes(encoded: &[u8]) -> Result<Vec<u8>, RleError> {
+    let mut decoded = Vec::with_capacity(encoded.len());
+    let mut offset = 0;
+    while offset < encoded.len() {
+        if encoded[offset] != ESCAPE {
+            decoded.push(encoded[offset]);
+            offset += 1;
+            continue;
+        }
+        let (count, byte) = match encoded.get(offset + 1..offset + 3) {
+            Some(&[count, byte]) => (count, byte),
+            _ => return Err(RleError::Truncated { offset }),
+        };
+        if count == 0 {
+            return Err(RleError::ZeroRun { offset });
+        }
+        decoded.extend(repeat_n(byte, count as usize));
+        offset += 3;
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ESCAPE, RleError, rle_decode, rle_decode_bytes, rle_encode, rle_encode_bytes};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn round_trip(data: &[u8]) -> Vec<u8> {
+        let encoded = rle_encode_bytes(data);
+        assert_eq!(rle_decode_bytes(&encoded).unwrap(), data);
+        encoded
+    }
+
+    #[test]
+    fn generic_runs() {
+        let runs: Vec<(char, usize)> = rle_encode("aaabccdddd".chars()).collect();
+        assert_eq!(runs, [('a', 3), ('b', 1), ('c', 2), ('d', 4)]);
+        assert_eq!(rle_decode(runs).collect::<String>(), "aaabccdddd");
+        assert_eq!(rle_encode(Vec::<u8>::new()).count(), 0);
+        let words = ["x", "x", "y"];
+        assert_eq!(rle_encode(words).collect::<Vec<_>>(), [("x", 2), ("y", 1)]);
+    }
+
+    #[test]
+    fn data_without_runs_is_unchanged() {
+        let data: Vec<u8> = (0..=255u8).filter(|&b| b != ESCAPE).collect();
+        assert_eq!(round_trip(&data), data);
+        assert_eq!(round_trip(b"aabbcc"), b"aabbcc");
+        assert!(round_trip(&[]).is_empty());
+    }
+
+    #[test]
+    fn long_runs_split_at_255() {
+        assert_eq!(round_trip(&[7; 4]), [ESCAPE, 4, 7]);
+        let encoded = round_trip(&[0; 1000]);
+        assert_eq!(
+            encoded,
+            [
+                ESCAPE, 255, 0, ESCAPE, 255, 0, ESCAPE, 255, 0, ESCAPE, 235, 0
+            ]
+        );
+        // A leftover shorter than a run stays literal
+        assert_eq!(round_trip(&[1; 257]), [ESCAPE, 255, 1, 1, 1]);
+    }
+
+    #[test]
+    fn escape_bytes_round_trip() {
+        assert_eq!(round_trip(&[ESCAPE]), [ESCAPE, 1, ESCAPE]);
+        round_trip(&[
+            ESCAPE, 1, ESCAPE, ESCAPE, 0, ESCAPE, ESCAPE, ESCAPE, ESCAPE, 3,
+        ]);
+        let mut rng = XorShift64Star::seed_from_u64(0xDEADBEEF);
+        for len in [1, 10, 100, 5000] {
+            // Small alphabet including ESCAPE, so runs are common
+            let data: Vec<u8> = (0..len)
+                .map(|_| *rng.choose(&[0, 1, ESCAPE, 255]).unwrap())
+                .collect();
+            round_trip(&data);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(
+            rle_decode_bytes(&[1, ESCAPE, 3]),
+            Err(RleError::Truncated { offset: 1 })
+        );
+        assert_eq!(
+            rle_decode_bytes(&[ESCAPE]),
+            Err(RleError::Truncated { offset: 0 })
+        );
+        assert_eq!(
+            rle_decode_bytes(&[ESCAPE, 0, 5]),
+            Err(RleError::ZeroRun { offset: 0 })
+        );
+    }
+}
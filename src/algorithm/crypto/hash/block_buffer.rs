@@ -0,0 +1,51 @@
+/// Collects input into the 64-byte blocks consumed by MD5 and SHA-256, and
+/// applies their shared Merkle–Damgård padding
+pub(super) struct BlockBuffer {
+    block: [u8; 64],
+    filled: usize,
+    /// Total input length in bytes
+    length: u64,
+}
+
+impl BlockBuffer {
+    pub(super) fn new() -> BlockBuffer {
+        BlockBuffer {
+            block: [0; 64],
+            filled: 0,
+            length: 0,
+        }
+    }
+
+    pub(super) fn update(&mut self, mut data: &[u8], mut compress: impl FnMut(&[u8; 64])) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        while !data.is_empty() {
+            let take = (64 - self.filled).min(data.len());
+            self.block[self.filled..self.filled + take].copy_from_slice(&data[..take]);
+            self.filled += take;
+            data = &data[take..];
+            if self.filled == 64 {
+                compress(&self.block);
+                self.filled = 0;
+            }
+        }
+    }
+
+    /// Appends a 1 bit, zeros up to 56 bytes into a block, then the message
+    /// length in bits as 64 bits of the given byte order
+    pub(super) fn finish(mut self, big_endian: bool, mut compress: impl FnMut(&[u8; 64])) {
+        let bits = self.length.wrapping_mul(8);
+        let length = if big_endian {
+            bits.to_be_bytes()
+        } else {
+            bits.to_le_bytes()
+        };
+        self.block[self.filled] = 0x80;
+        self.block[self.filled + 1..].fill(0);
+        if self.filled >= 56 {
+            compress(&self.block);
+            self.block.fill(0);
+        }
+        self.block[56..].copy_from_slice(&length);
+        compress(&self.block);
+    }
+}
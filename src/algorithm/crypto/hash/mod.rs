@@ -0,0 +1,6 @@
+mod block_buffer;
+mod md5;
+mod sha256;
+
+pub use self::md5::Md5;
+pub use self::sha256::Sha256;
@@ -0,0 +1,4 @@
+pub mod hash;
+mod rsa;
+
+pub use self::rsa::{RsaKeyPair, RsaPrivateKey, RsaPublicKey, generate_keypair};
@@ -0,0 +1,150 @@
+use super::hash::Sha256;
+use crate::algorithm::math::{gcd, is_prime_u64, lcm, mod_inverse, pow_mod};
+use crate::algorithm::random::Rng;
+
+/// The usual public exponent, 2^16 + 1
+const PUBLIC_EXPONENT: u64 = 65537;
+
+/// An RSA public key: the modulus `n = p q` and the exponent `e`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsaPublicKey {
+    pub n: u64,
+    pub e: u64,
+}
+
+/// An RSA private key: the modulus and the exponent `d`, the inverse of `e`
+/// modulo lcm(p - 1, q - 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsaPrivateKey {
+    pub n: u64,
+    pub d: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsaKeyPair {
+    pub public: RsaPublicKey,
+    pub private: RsaPrivateKey,
+}
+
+/// Draws a random prime of exactly `bits` bits, testing odd candidates with
+/// the deterministic Miller–Rabin test
+fn random_prime<R: Rng>(bits: u32, rng: &mut R) -> u64 {
+    loop {
+        let top = 1 << (bits - 1);
+        // Set the top bit so the product has the full size, and the low bit
+        let candidate = (top + rng.gen_range(0..top)) | 1;
+        if is_prime_u64(candidate) {
+            return candidate;
+        }
+    }
+}
+
+/// Generates a key pair with a modulus of `bits` bits.
+///
+/// Textbook RSA for study, not for protecting anything. The modulus fits in a
+/// `u64` (the crate has no big integers), so it is factored in moments.
+/// Messages are raw numbers with no padding, which is deterministic and
+/// malleable. Panics unless `bits` is in 16..=64.
+pub fn generate_keypair<R: Rng>(bits: u32, rng: &mut R) -> RsaKeyPair {
+    assert!((16..=64).contains(&bits), "modulus must be 16 to 64 bits");
+    loop {
+        let p = random_prime(bits / 2, rng);
+        let q = random_prime(bits - bits / 2, rng);
+        let n = p as u128 * q as u128;
+        if p == q || n >> (bits - 1) != 1 {
+            continue;
+        }
+        // Carmichael's function: m^(e d) = m (mod n) whenever e d = 1 mod it
+        let lambda = lcm(p - 1, q - 1);
+        if gcd(PUBLIC_EXPONENT, lambda) != 1 {
+            continue;
+        }
+        let d = mod_inverse(PUBLIC_EXPONENT % lambda, lambda).expect("e is coprime to lambda");
+        let n = n as u64;
+        return RsaKeyPair {
+            public: RsaPublicKey {
+                n,
+                e: PUBLIC_EXPONENT,
+            },
+            private: RsaPrivateKey { n, d },
+        };
+    }
+}
+
+/// Reduces a message's SHA-256 digest below the modulus for signing
+fn message_representative(message: &[u8], n: u64) -> u64 {
+    let digest = Sha256::digest(message);
+    u64::from_be_bytes(digest[..8].try_into().unwrap()) % n
+}
+
+impl RsaPublicKey {
+    /// `m^e mod n`. Panics unless `m < n`
+    pub fn encrypt(&self, m: u64) -> u64 {
+        assert!(m < self.n, "message must be below the modulus");
+        pow_mod(m, self.e, self.n)
+    }
+
+    /// Checks `signature^e mod n` against the message's digest
+    pub fn verify(&self, message: &[u8], signature: u64) -> bool {
+        signature < self.n
+            && pow_mod(signature, self.e, self.n) == message_representative(message, self.n)
+    }
+}
+
+impl RsaPrivateKey {
+    /// `c^d mod n`. Panics unless `c < n`
+    pub fn decrypt(&self, c: u64) -> u64 {
+        assert!(c < self.n, "ciphertext must be below the modulus");
+        pow_mod(c, self.d, self.n)
+    }
+
+    /// Textbook signature: the message's SHA-256 digest, reduced below the
+    /// modulus, raised to `d`
+    pub fn sign(&self, message: &[u8]) -> u64 {
+        pow_mod(message_representative(message, self.n), self.d, self.n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RsaPrivateKey, RsaPublicKey, generate_keypair};
+    use crate::algorithm::math::factorize;
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    #[test]
+    fn textbook_example() {
+        // p = 61, q = 53: n = 3233, lambda = 780, e = 17, d = 413
+        let public = RsaPublicKey { n: 3233, e: 17 };
+        let private = RsaPrivateKey { n: 3233, d: 413 };
+        assert_eq!(public.encrypt(65), 2790);
+        assert_eq!(private.decrypt(2790), 65);
+    }
+
+    #[test]
+    fn round_trips_at_every_size() {
+        let mut rng = XorShift64Star::seed_from_u64(2024);
+        for bits in [16, 24, 32, 48, 62, 64] {
+            let keys = generate_keypair(bits, &mut rng);
+            let n = keys.public.n;
+            assert_eq!(64 - n.leading_zeros(), bits);
+            for _ in 0..20 {
+                let m = rng.gen_range(0..n);
+                let c = keys.public.encrypt(m);
+                assert_eq!(keys.private.decrypt(c), m, "{bits}-bit key");
+            }
+            let signature = keys.private.sign(b"attack at dawn");
+            assert!(keys.public.verify(b"attack at dawn", signature));
+            assert!(!keys.public.verify(b"attack at dusk", signature));
+        }
+    }
+
+    #[test]
+    fn small_keys_are_easily_broken() {
+        let mut rng = XorShift64Star::seed_from_u64(7);
+        let keys = generate_keypair(48, &mut rng);
+        let factors = factorize(keys.public.n);
+        assert_eq!(factors.len(), 2);
+        let (p, q) = (factors[0].0, factors[1].0);
+        assert_eq!(p * q, keys.public.n);
+    }
+}
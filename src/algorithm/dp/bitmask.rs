@@ -0,0 +1,118 @@
+/// Iterator over every submask of a mask, from the mask itself down to 0
+#[derive(Debug, Clone)]
+pub struct SubsetsOf {
+    mask: u64,
+    next: Option<u64>,
+}
+
+impl Iterator for SubsetsOf {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.next?;
+        // Subtracting one and masking steps to the next smaller submask
+        self.next = (current != 0).then(|| (current - 1) & self.mask);
+        Some(current)
+    }
+}
+
+/// Iterates over all 2^popcount(mask) submasks of `mask`, descending.
+///
+/// Enumerating the submasks of every mask this way costs O(3^n) in total.
+pub fn subsets_of(mask: u64) -> SubsetsOf {
+    SubsetsOf {
+        mask,
+        next: Some(mask),
+    }
+}
+
+/// Iterator over the indices of the set bits of a mask, ascending
+#[derive(Debug, Clone)]
+pub struct BitsOf(u64);
+
+impl Iterator for BitsOf {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(bit)
+    }
+}
+
+/// Iterates over the indices of the set bits of `mask`, ascending
+pub fn bits_of(mask: u64) -> BitsOf {
+    BitsOf(mask)
+}
+
+/// Iterator over the n-bit masks with exactly k bits set, ascending
+#[derive(Debug, Clone)]
+pub struct MasksWithPopcount {
+    next: Option<u64>,
+    limit: u64,
+}
+
+impl Iterator for MasksWithPopcount {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.next?;
+        if current == 0 {
+            self.next = None;
+            return Some(0);
+        }
+        // Gosper's hack: the next larger integer with the same popcount
+        let lowest = current & current.wrapping_neg();
+        let ripple = current + lowest;
+        let following = (((ripple ^ current) >> 2) / lowest) | ripple;
+        self.next = (ripple != 0 && following < self.limit).then_some(following);
+        Some(current)
+    }
+}
+
+/// Iterates over the masks of `n` bits with exactly `k` bits set, ascending.
+/// Panics if `n > 63`.
+pub fn masks_with_popcount(n: u32, k: u32) -> MasksWithPopcount {
+    assert!(n < 64, "masks are limited to 63 bits");
+    MasksWithPopcount {
+        next: (k <= n).then(|| (1u64 << k) - 1),
+        limit: 1 << n,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bits_of, masks_with_popcount, subsets_of};
+
+    #[test]
+    fn enumerates_submasks() {
+        let subsets: Vec<u64> = subsets_of(0b1010).collect();
+        assert_eq!(subsets, vec![0b1010, 0b1000, 0b0010, 0]);
+        assert_eq!(subsets_of(0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(subsets_of(0b1111_1111).count(), 256);
+        assert!(subsets_of(0b1011_0110).all(|s| s & !0b1011_0110 == 0));
+    }
+
+    #[test]
+    fn enumerates_bits() {
+        assert_eq!(bits_of(0b1001_0010).collect::<Vec<_>>(), vec![1, 4, 7]);
+        assert_eq!(bits_of(0).count(), 0);
+        assert_eq!(bits_of(u64::MAX).count(), 64);
+    }
+
+    #[test]
+    fn enumerates_fixed_popcounts() {
+        assert_eq!(
+            masks_with_popcount(4, 2).collect::<Vec<_>>(),
+            vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+        );
+        assert_eq!(masks_with_popcount(10, 3).count(), 120);
+        assert!(masks_with_popcount(10, 3).all(|m| m.count_ones() == 3 && m < 1 << 10));
+        assert_eq!(masks_with_popcount(5, 0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(masks_with_popcount(5, 5).collect::<Vec<_>>(), vec![0b11111]);
+        assert_eq!(masks_with_popcount(3, 4).count(), 0);
+    }
+}
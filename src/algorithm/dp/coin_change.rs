@@ -0,0 +1,124 @@
+/// The fewest coins from `denoms` summing to `amount`, or None if no combination does.
+///
+/// Each denomination may be used any number of times; zero denominations are
+/// ignored. Returns the coins largest first, in O(amount * denoms) time.
+pub fn min_coins(amount: usize, denoms: &[usize]) -> Option<Vec<usize>> {
+    // fewest[a] is the fewest coins summing to a, and last[a] one coin of such a solution
+    let mut fewest = vec![usize::MAX; amount + 1];
+    let mut last = vec![0; amount + 1];
+    fewest[0] = 0;
+    for a in 1..=amount {
+        for &coin in denoms {
+            if coin == 0 || coin > a || fewest[a - coin] == usize::MAX {
+                continue;
+            }
+            if fewest[a - coin] + 1 < fewest[a] {
+                fewest[a] = fewest[a - coin] + 1;
+                last[a] = coin;
+            }
+        }
+    }
+    if fewest[amount] == usize::MAX {
+        return None;
+    }
+    let mut coins = Vec::with_capacity(fewest[amount]);
+    let mut a = amount;
+    while a > 0 {
+        coins.push(last[a]);
+        a -= last[a];
+    }
+    coins.sort_unstable_by(|x, y| y.cmp(x));
+    Some(coins)
+}
+
+/// Number of multisets of coins from `denoms` that sum to `amount`, so the
+/// order of coins does not matter.
+///
+/// Counts grow exponentially with the amount, so they are kept in `u128`
+/// (the crate has no big integers); returns None if the count would overflow.
+/// Zero and repeated denominations are ignored.
+pub fn count_ways(amount: usize, denoms: &[usize]) -> Option<u128> {
+    let mut ways = vec![Some(0u128); amount + 1];
+    ways[0] = Some(1);
+    for coin in distinct_coins(denoms) {
+        // Looping over coins outside amounts counts each multiset once
+        for a in coin..=amount {
+            ways[a] = match (ways[a], ways[a - coin]) {
+                (Some(x), Some(y)) => x.checked_add(y),
+                _ => None,
+            };
+        }
+    }
+    ways[amount]
+}
+
+/// `count_ways` modulo `modulus`, for amounts whose counts overflow `u128`
+pub fn count_ways_mod(amount: usize, denoms: &[usize], modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be positive");
+    let mut ways = vec![0u64; amount + 1];
+    ways[0] = 1 % modulus;
+    for coin in distinct_coins(denoms) {
+        for a in coin..=amount {
+            ways[a] = ((ways[a] as u128 + ways[a - coin] as u128) % modulus as u128) as u64;
+        }
+    }
+    ways[amount]
+}
+
+fn distinct_coins(denoms: &[usize]) -> Vec<usize> {
+    let mut coins: Vec<usize> = denoms.iter().copied().filter(|&c| c > 0).collect();
+    coins.sort_unstable();
+    coins.dedup();
+    coins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_ways, count_ways_mod, min_coins};
+
+    #[test]
+    fn fewest_coins() {
+        assert_eq!(min_coins(11, &[1, 2, 5]), Some(vec![5, 5, 1]));
+        // Greedy would take 4 + 1 + 1
+        assert_eq!(min_coins(6, &[1, 3, 4]), Some(vec![3, 3]));
+        assert_eq!(min_coins(0, &[1, 2]), Some(vec![]));
+        assert_eq!(min_coins(63, &[1, 5, 10, 21, 25]), Some(vec![21, 21, 21]));
+    }
+
+    #[test]
+    fn unreachable_amounts() {
+        assert_eq!(min_coins(3, &[2]), None);
+        assert_eq!(min_coins(7, &[]), None);
+        assert_eq!(min_coins(7, &[0]), None);
+        assert_eq!(min_coins(1, &[4, 6]), None);
+        assert_eq!(count_ways(3, &[2]), Some(0));
+        assert_eq!(count_ways(5, &[]), Some(0));
+        assert_eq!(count_ways_mod(11, &[4, 6], 1_000_000_007), 0);
+    }
+
+    #[test]
+    fn counts_ignore_order() {
+        assert_eq!(count_ways(4, &[1, 2, 3]), Some(4));
+        assert_eq!(count_ways(5, &[1, 2, 5]), Some(4));
+        assert_eq!(count_ways(5, &[5, 2, 1, 2, 0]), Some(4));
+        assert_eq!(count_ways(0, &[7]), Some(1));
+        assert_eq!(count_ways(100, &[1, 5, 10, 25, 50, 100]), Some(293));
+    }
+
+    #[test]
+    fn big_amounts() {
+        let denoms: Vec<usize> = (1..=20).collect();
+        // Partitions of 2000 into parts of at most 20 exceed u64
+        let exact = count_ways(2000, &denoms).unwrap();
+        assert!(exact > u64::MAX as u128);
+        let modulus = 1_000_000_007;
+        assert_eq!(
+            count_ways_mod(2000, &denoms, modulus) as u128,
+            exact % modulus as u128
+        );
+        // Partitions of 3000 into any parts are far beyond u128
+        let all: Vec<usize> = (1..=3000).collect();
+        assert_eq!(count_ways(3000, &all), None);
+        assert!(count_ways_mod(3000, &all, modulus) < modulus);
+    }
+}
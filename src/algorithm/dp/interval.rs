@@ -0,0 +1,111 @@
+/// How an interval was split at each level of an `interval_dp` optimum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SplitTree {
+    /// A single position
+    Leaf(usize),
+    /// The interval `[i, j]` split into `[i, k]` and `[k + 1, j]`
+    Split {
+        at: usize,
+        left: Box<SplitTree>,
+        right: Box<SplitTree>,
+    },
+}
+
+/// Optimal costs and split points of every interval, filled by `interval_dp`
+#[derive(Debug, Clone)]
+pub struct IntervalTable {
+    cost: Vec<Vec<u64>>,
+    split: Vec<Vec<usize>>,
+}
+
+impl IntervalTable {
+    /// The optimal cost of the inclusive interval `[i, j]`
+    pub fn cost(&self, i: usize, j: usize) -> u64 {
+        self.cost[i][j]
+    }
+
+    /// The optimal split of `[i, j]`: it divides into `[i, k]` and `[k + 1, j]`.
+    /// Panics if `i >= j`.
+    pub fn split(&self, i: usize, j: usize) -> usize {
+        assert!(i < j, "only intervals of two or more positions are split");
+        self.split[i][j]
+    }
+
+    /// The full tree of optimal splits of `[i, j]`
+    pub fn split_tree(&self, i: usize, j: usize) -> SplitTree {
+        if i == j {
+            return SplitTree::Leaf(i);
+        }
+        let at = self.split[i][j];
+        SplitTree::Split {
+            at,
+            left: Box::new(self.split_tree(i, at)),
+            right: Box::new(self.split_tree(at + 1, j)),
+        }
+    }
+}
+
+/// Solves the interval recurrence over positions `0..n`:
+///
+/// cost[i][i] = 0, and for i < j
+/// cost[i][j] = min over i <= k < j of cost[i][k] + cost[k + 1][j] + weight(i, k, j)
+///
+/// Intervals are filled in order of length, in O(n^3) time and O(n^2) space.
+/// Matrix chain ordering, optimal BSTs and polygon triangulation are all
+/// instances with different weights.
+pub fn interval_dp<W: Fn(usize, usize, usize) -> u64>(n: usize, weight: W) -> IntervalTable {
+    let mut cost = vec![vec![0; n]; n];
+    let mut split = vec![vec![0; n]; n];
+    for len in 2..=n {
+        for i in 0..=n - len {
+            let j = i + len - 1;
+            let (best_k, best) = (i..j)
+                .map(|k| (k, cost[i][k] + cost[k + 1][j] + weight(i, k, j)))
+                .min_by_key(|&(_, c)| c)
+                .expect("an interval of two or more positions has a split");
+            cost[i][j] = best;
+            split[i][j] = best_k;
+        }
+    }
+    IntervalTable { cost, split }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SplitTree, interval_dp};
+
+    fn leaves(tree: &SplitTree, out: &mut Vec<usize>) {
+        match tree {
+            SplitTree::Leaf(i) => out.push(*i),
+            SplitTree::Split { left, right, .. } => {
+                leaves(left, out);
+                leaves(right, out);
+            }
+        }
+    }
+
+    #[test]
+    fn unit_weight_counts_merges() {
+        // Every split costs 1, so any interval of len positions costs len - 1
+        let table = interval_dp(6, |_, _, _| 1);
+        for i in 0..6 {
+            for j in i..6 {
+                assert_eq!(table.cost(i, j), (j - i) as u64);
+            }
+        }
+        let mut order = Vec::new();
+        leaves(&table.split_tree(1, 4), &mut order);
+        assert_eq!(order, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn weight_steers_the_split() {
+        // Splitting the whole range anywhere but after position 2 is expensive
+        let table = interval_dp(5, |i, k, j| match (i, j) {
+            (0, 4) if k == 2 => 0,
+            (0, 4) => 100,
+            _ => 1,
+        });
+        assert_eq!(table.split(0, 4), 2);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::algorithm::search::partition_point;
+
+/// An item that can be packed into a knapsack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub weight: usize,
+    pub value: u64,
+}
+
+/// The best packing found by a knapsack solver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnapsackSolution {
+    /// Total value of the chosen items
+    pub value: u64,
+    /// Indices of the chosen items, ascending
+    pub items: Vec<usize>,
+}
+
+/// Maximum value of a 0/1 knapsack in O(n * capacity) time and O(capacity) space
+pub fn knapsack_01_value(items: &[Item], capacity: usize) -> u64 {
+    let mut best = vec![0; capacity + 1];
+    for item in items {
+        // Walking capacities downwards uses each item at most once
+        for c in (item.weight..=capacity).rev() {
+            best[c] = best[c].max(best[c - item.weight] + item.value);
+        }
+    }
+    best[capacity]
+}
+
+/// Solves the 0/1 knapsack and reconstructs which items to take.
+///
+/// Keeps the 1-D value row, plus one bit per (item, capacity) recording
+/// whether taking the item improved that cell, which is enough to walk back.
+pub fn knapsack_01(items: &[Item], capacity: usize) -> KnapsackSolution {
+    let mut best = vec![0; capacity + 1];
+    let mut took = vec![vec![false; capacity + 1]; items.len()];
+    for (i, item) in items.iter().enumerate() {
+        for c in (item.weight..=capacity).rev() {
+            let with = best[c - item.weight] + item.value;
+            if with > best[c] {
+                best[c] = with;
+                took[i][c] = true;
+            }
+        }
+    }
+    let mut chosen = Vec::new();
+    let mut c = capacity;
+    for i in (0..items.len()).rev() {
+        if took[i][c] {
+            chosen.push(i);
+            c -= items[i].weight;
+        }
+    }
+    chosen.reverse();
+    KnapsackSolution {
+        value: best[capacity],
+        items: chosen,
+    }
+}
+
+/// Maximum value when each item may be taken any number of times, in O(n * capacity)
+pub fn knapsack_unbounded(items: &[Item], capacity: usize) -> u64 {
+    let mut best = vec![0; capacity + 1];
+    for c in 1..=capacity {
+        for item in items {
+            if item.weight <= c {
+                best[c] = best[c].max(best[c - item.weight] + item.value);
+            }
+        }
+    }
+    best[capacity]
+}
+
+/// Maximum value when item `i` may be taken up to `counts[i]` times.
+///
+/// Each bounded item is split into 0/1 bundles of 1, 2, 4, ... copies plus a
+/// remainder, which can make up any count, so this runs in
+/// O(capacity * sum(log counts)). Panics if the slices differ in length.
+pub fn knapsack_bounded(items: &[Item], counts: &[usize], capacity: usize) -> u64 {
+    assert_eq!(items.len(), counts.len(), "every item needs a count");
+    let mut bundles = Vec::new();
+    for (item, &count) in items.iter().zip(counts) {
+        let mut left = count;
+        let mut size = 1;
+        while left > 0 {
+            let take = size.min(left);
+            // A bundle too heavy to even weigh can never fit
+            if let Some(weight) = item.weight.checked_mul(take) {
+                bundles.push(Item {
+                    weight,
+                    value: item.value * take as u64,
+                });
+            }
+            left -= take;
+            size = size.saturating_mul(2);
+        }
+    }
+    knapsack_01_value(&bundles, capacity)
+}
+
+/// Solves the 0/1 knapsack by meet in the middle, for few items with huge weights.
+///
+/// Both halves enumerate their 2^(n/2) subsets; the second half is sorted by
+/// weight with dominated subsets dropped, so every first-half subset finds its
+/// best partner by binary search. O(2^(n/2) * n) time, independent of the
+/// capacity. Panics if there are more than 40 items.
+pub fn knapsack_meet_in_middle(items: &[Item], capacity: usize) -> KnapsackSolution {
+    assert!(
+        items.len() <= 40,
+        "meet in the middle needs at most 40 items"
+    );
+    let (front, back) = items.split_at(items.len() / 2);
+    let first = subsets(front, capacity);
+    let mut second = subsets(back, capacity);
+
+    // Keep only subsets that are strictly more valuable than every lighter one
+    second.sort_by_key(|&(weight, value, _)| (weight, std::cmp::Reverse(value)));
+    let mut frontier: Vec<(usize, u64, u64)> = Vec::new();
+    for subset in second {
+        if frontier
+            .last()
+            .is_none_or(|&(_, value, _)| subset.1 > value)
+        {
+            frontier.push(subset);
+        }
+    }
+
+    let mut best = (0, 0, 0);
+    for (weight, value, mask) in first {
+        let fits = partition_point(&frontier, |&(w, _, _)| w <= capacity - weight);
+        let (_, partner_value, partner_mask) = frontier[fits - 1];
+        if value + partner_value > best.0 {
+            best = (value + partner_value, mask, partner_mask);
+        }
+    }
+    let (value, front_mask, back_mask) = best;
+    let items = (0..front.len())
+        .filter(|&i| front_mask >> i & 1 == 1)
+        .chain(
+            (0..back.len())
+                .filter(|&i| back_mask >> i & 1 == 1)
+                .map(|i| i + front.len()),
+        )
+        .collect();
+    KnapsackSolution { value, items }
+}
+
+/// Every subset of `items` that fits, as (weight, value, membership mask)
+fn subsets(items: &[Item], capacity: usize) -> Vec<(usize, u64, u64)> {
+    let mut all = vec![(0, 0, 0)];
+    for (i, item) in items.iter().enumerate() {
+        for k in 0..all.len() {
+            let (weight, value, mask) = all[k];
+            if item.weight <= capacity - weight {
+                all.push((weight + item.weight, value + item.value, mask | 1 << i));
+            }
+        }
+    }
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Item, knapsack_01, knapsack_01_value, knapsack_bounded, knapsack_meet_in_middle,
+        knapsack_unbounded,
+    };
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn items(pairs: &[(usize, u64)]) -> Vec<Item> {
+        pairs
+            .iter()
+            .map(|&(weight, value)| Item { weight, value })
+            .collect()
+    }
+
+    fn brute_force(items: &[Item], capacity: usize) -> u64 {
+        (0..1u64 << items.len())
+            .filter_map(|mask| {
+                let chosen = items.iter().enumerate().filter(|(i, _)| mask >> i & 1 == 1);
+                let (w, v) = chosen.fold((0, 0), |(w, v), (_, it)| (w + it.weight, v + it.value));
+                (w <= capacity).then_some(v)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn pseudo_random_items(n: usize, max_weight: usize, seed: u64) -> Vec<Item> {
+        let mut rng = XorShift64Star::seed_from_u64(seed);
+        (0..n)
+            .map(|_| Item {
+                weight: 1 + rng.gen_index(max_weight),
+                value: rng.gen_range(0..100),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn classic_01() {
+        let items = items(&[(10, 60), (20, 100), (30, 120)]);
+        assert_eq!(
+            knapsack_01(&items, 50),
+            super::KnapsackSolution {
+                value: 220,
+                items: vec![1, 2]
+            }
+        );
+        assert_eq!(knapsack_01_value(&items, 50), 220);
+        assert_eq!(knapsack_01_value(&items, 5), 0);
+        assert_eq!(knapsack_01(&[], 10).value, 0);
+    }
+
+    #[test]
+    fn solvers_match_brute_force() {
+        for seed in 1..30 {
+            let items = pseudo_random_items(12, 30, seed);
+            let capacity = 20 + seed as usize * 3;
+            let expected = brute_force(&items, capacity);
+            let solution = knapsack_01(&items, capacity);
+            assert_eq!(solution.value, expected);
+            assert_eq!(knapsack_01_value(&items, capacity), expected);
+            assert_eq!(knapsack_meet_in_middle(&items, capacity).value, expected);
+            for s in [&solution, &knapsack_meet_in_middle(&items, capacity)] {
+                let weight: usize = s.items.iter().map(|&i| items[i].weight).sum();
+                let value: u64 = s.items.iter().map(|&i| items[i].value).sum();
+                assert!(weight <= capacity);
+                assert_eq!(value, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn meet_in_the_middle_handles_huge_weights() {
+        let items = items(&[
+            (3_000_000_000, 10),
+            (2_000_000_000, 7),
+            (1_500_000_000, 6),
+            (1_000_000_000, 2),
+            (999_999_999, 2),
+        ]);
+        let solution = knapsack_meet_in_middle(&items, 4_500_000_000);
+        assert_eq!(solution.value, 16);
+        assert_eq!(solution.items, vec![0, 2]);
+    }
+
+    #[test]
+    fn unbounded_and_bounded() {
+        let items = items(&[(5, 10), (4, 40), (6, 30), (3, 50)]);
+        assert_eq!(knapsack_unbounded(&items, 9), 150);
+        assert_eq!(knapsack_bounded(&items, &[0, 0, 0, 2], 9), 100);
+        assert_eq!(
+            knapsack_bounded(&items, &[1, 1, 1, 1], 10),
+            knapsack_01_value(&items, 10)
+        );
+        // Enough copies of everything behaves like the unbounded knapsack
+        assert_eq!(
+            knapsack_bounded(&items, &[100; 4], 50),
+            knapsack_unbounded(&items, 50)
+        );
+        // Bounded equals 0/1 over explicitly repeated items
+        let repeated: Vec<Item> = items.iter().flat_map(|&it| [it; 3]).collect();
+        assert_eq!(
+            knapsack_bounded(&items, &[3; 4], 23),
+            knapsack_01_value(&repeated, 23)
+        );
+    }
+
+    #[test]
+    fn bounded_bundles_heavier_than_usize_do_not_fit() {
+        let items = items(&[(usize::MAX / 2, 7), (2, 3)]);
+        assert_eq!(knapsack_bounded(&items, &[usize::MAX, 4], 9), 12);
+        assert_eq!(knapsack_bounded(&items, &[3, 1], 9), 3);
+    }
+}
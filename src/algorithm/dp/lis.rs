@@ -0,0 +1,180 @@
+use crate::algorithm::search::partition_point;
+
+/// Returns a longest strictly increasing subsequence of `arr` in O(n log n)
+pub fn lis<T: Ord + Clone>(arr: &[T]) -> Vec<T> {
+    patience(arr, true)
+        .into_iter()
+        .map(|i| arr[i].clone())
+        .collect()
+}
+
+/// Returns a longest non-decreasing subsequence of `arr` in O(n log n)
+pub fn lis_non_decreasing<T: Ord + Clone>(arr: &[T]) -> Vec<T> {
+    patience(arr, false)
+        .into_iter()
+        .map(|i| arr[i].clone())
+        .collect()
+}
+
+/// Indices of a longest increasing subsequence, strictly increasing if `strict`.
+///
+/// Patience sorting: each element goes on the leftmost pile whose top is not
+/// smaller than it (or a new pile), remembering the top of the pile to its left
+/// as its predecessor. The number of piles is the LIS length, and following
+/// predecessors from the last pile's top recovers one such subsequence.
+pub fn lis_indices<T: Ord>(arr: &[T], strict: bool) -> Vec<usize> {
+    patience(arr, strict)
+}
+
+fn patience<T: Ord>(arr: &[T], strict: bool) -> Vec<usize> {
+    // tops[k]: index of the smallest element ending an increasing run of length k + 1
+    let mut tops: Vec<usize> = Vec::new();
+    let mut prev = vec![None; arr.len()];
+    for (i, x) in arr.iter().enumerate() {
+        let pile = if strict {
+            partition_point(&tops, |&t| arr[t] < *x)
+        } else {
+            partition_point(&tops, |&t| arr[t] <= *x)
+        };
+        if pile > 0 {
+            prev[i] = Some(tops[pile - 1]);
+        }
+        if pile == tops.len() {
+            tops.push(i);
+        } else {
+            tops[pile] = i;
+        }
+    }
+    let mut indices = Vec::with_capacity(tops.len());
+    let mut current = tops.last().copied();
+    while let Some(i) = current {
+        indices.push(i);
+        current = prev[i];
+    }
+    indices.reverse();
+    indices
+}
+
+/// Counts the distinct (by position) longest strictly increasing subsequences
+/// of `arr`, in O(n log n).
+///
+/// A Fenwick tree over value ranks stores, for the values seen so far, the best
+/// (length, count) of increasing subsequences ending at each value; counts
+/// saturate at `u128::MAX`.
+pub fn lis_count<T: Ord>(arr: &[T]) -> u128 {
+    let mut sorted: Vec<&T> = arr.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+    let mut tree = vec![(0usize, 0u128); sorted.len() + 1];
+
+    for x in arr {
+        let rank = partition_point(&sorted, |v| *v < x);
+        // Best over all strictly smaller values, i.e. ranks 0..rank
+        let (mut length, mut count) = (0, 0);
+        let mut i = rank;
+        while i > 0 {
+            (length, count) = combine((length, count), tree[i]);
+            i &= i - 1;
+        }
+        let ending_here = if length == 0 {
+            (1, 1)
+        } else {
+            (length + 1, count)
+        };
+        let mut i = rank + 1;
+        while i < tree.len() {
+            tree[i] = combine(tree[i], ending_here);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    let (mut length, mut count) = (0, 0);
+    let mut i = sorted.len();
+    while i > 0 {
+        (length, count) = combine((length, count), tree[i]);
+        i &= i - 1;
+    }
+    count
+}
+
+/// Keeps the longer run, adding counts when lengths tie
+fn combine(a: (usize, u128), b: (usize, u128)) -> (usize, u128) {
+    match a.0.cmp(&b.0) {
+        std::cmp::Ordering::Greater => a,
+        std::cmp::Ordering::Less => b,
+        std::cmp::Ordering::Equal => (a.0, a.1.saturating_add(b.1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lis, lis_count, lis_indices, lis_non_decreasing};
+    use crate::testgen::{Shape, TestGen};
+
+    fn brute_force(arr: &[i64], strict: bool) -> (usize, u128) {
+        let mut best = (0, 1);
+        for mask in 1u32..1 << arr.len() {
+            let chosen: Vec<i64> = (0..arr.len())
+                .filter(|&i| mask >> i & 1 == 1)
+                .map(|i| arr[i])
+                .collect();
+            let ok = chosen
+                .windows(2)
+                .all(|w| if strict { w[0] < w[1] } else { w[0] <= w[1] });
+            if ok {
+                if chosen.len() > best.0 {
+                    best = (chosen.len(), 1);
+                } else if chosen.len() == best.0 {
+                    best.1 += 1;
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn classic_examples() {
+        assert_eq!(lis(&[10, 9, 2, 5, 3, 7, 101, 18]).len(), 4);
+        assert_eq!(
+            lis(&[0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15]).len(),
+            6
+        );
+        assert_eq!(lis(&[3, 3, 3]), vec![3]);
+        assert_eq!(lis_non_decreasing(&[3, 3, 1, 3]), vec![3, 3, 3]);
+        assert_eq!(lis::<i32>(&[]), Vec::<i32>::new());
+        assert_eq!(lis_count(&[1, 3, 5, 4, 7]), 2);
+        assert_eq!(lis_count(&[2, 2, 2, 2, 2]), 5);
+        assert_eq!(lis_count::<i32>(&[]), 0);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut tg = TestGen::new(0x51);
+        for len in 1..=12 {
+            for _ in 0..20 {
+                let arr = tg.ints(len, Shape::FewUniques(6));
+                for strict in [true, false] {
+                    let (length, count) = brute_force(&arr, strict);
+                    let indices = lis_indices(&arr, strict);
+                    assert_eq!(indices.len(), length, "{arr:?}");
+                    assert!(indices.windows(2).all(|w| w[0] < w[1]));
+                    assert!(indices.windows(2).all(|w| if strict {
+                        arr[w[0]] < arr[w[1]]
+                    } else {
+                        arr[w[0]] <= arr[w[1]]
+                    }));
+                    if strict {
+                        assert_eq!(lis_count(&arr), count, "{arr:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn count_saturates_instead_of_overflowing() {
+        // Pairs (2k+1, 2k) give 2^200 longest subsequences
+        let arr: Vec<u32> = (0..200).flat_map(|k| [2 * k + 1, 2 * k]).collect();
+        assert_eq!(lis_count(&arr), u128::MAX);
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::{SplitTree, interval_dp};
+
+/// The cheapest way to evaluate a chain of matrix products
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainOrder {
+    /// Number of scalar multiplications
+    pub cost: u64,
+    /// Which products to evaluate first
+    pub order: SplitTree,
+}
+
+impl Display for ChainOrder {
+    /// Writes the parenthesization with 1-based matrix names, e.g. `((A1A2)A3)`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fn write(tree: &SplitTree, f: &mut Formatter<'_>) -> fmt::Result {
+            match tree {
+                SplitTree::Leaf(i) => write!(f, "A{}", i + 1),
+                SplitTree::Split { left, right, .. } => {
+                    write!(f, "(")?;
+                    write(left, f)?;
+                    write(right, f)?;
+                    write!(f, ")")
+                }
+            }
+        }
+        write(&self.order, f)
+    }
+}
+
+/// Finds the parenthesization of a matrix chain with the fewest scalar multiplications.
+///
+/// Matrix `i` is `dims[i] x dims[i + 1]`, so `dims` holds one more entry than
+/// there are matrices. Panics if `dims` has fewer than two entries.
+pub fn matrix_chain_order(dims: &[u64]) -> ChainOrder {
+    assert!(dims.len() >= 2, "a chain needs at least one matrix");
+    let n = dims.len() - 1;
+    let table = interval_dp(n, |i, k, j| dims[i] * dims[k + 1] * dims[j + 1]);
+    ChainOrder {
+        cost: table.cost(0, n - 1),
+        order: table.split_tree(0, n - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matrix_chain_order;
+
+    #[test]
+    fn known_chains() {
+        let clrs = matrix_chain_order(&[30, 35, 15, 5, 10, 20, 25]);
+        assert_eq!(clrs.cost, 15125);
+        assert_eq!(clrs.to_string(), "((A1(A2A3))((A4A5)A6))");
+
+        let small = matrix_chain_order(&[1, 2, 3, 4]);
+        assert_eq!(small.cost, 18);
+        assert_eq!(small.to_string(), "((A1A2)A3)");
+
+        assert_eq!(matrix_chain_order(&[40, 20, 30, 10, 30]).cost, 26000);
+        assert_eq!(matrix_chain_order(&[10, 20, 30, 40, 30]).cost, 30000);
+    }
+
+    #[test]
+    fn single_matrix_costs_nothing() {
+        let one = matrix_chain_order(&[5, 7]);
+        assert_eq!(one.cost, 0);
+        assert_eq!(one.to_string(), "A1");
+    }
+}
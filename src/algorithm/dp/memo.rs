@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Storage for memoized results
+pub trait Cache<K, V> {
+    /// Returns the stored value for `key`
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Stores `value` for `key`, replacing any previous one
+    fn insert(&mut self, key: K, value: V);
+
+    /// Returns the number of stored values
+    fn len(&self) -> usize;
+
+    /// Removes every stored value
+    fn clear(&mut self);
+
+    /// Checks if nothing is stored
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash, V> Cache<K, V> for HashMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn clear(&mut self) {
+        HashMap::clear(self)
+    }
+}
+
+/// Array-backed cache for small dense `usize` keys, growing to fit the largest key
+#[derive(Debug, Clone)]
+pub struct DenseCache<V> {
+    slots: Vec<Option<V>>,
+    filled: usize,
+}
+
+impl<V> Default for DenseCache<V> {
+    fn default() -> Self {
+        DenseCache {
+            slots: Vec::new(),
+            filled: 0,
+        }
+    }
+}
+
+impl<V> Cache<usize, V> for DenseCache<V> {
+    fn get(&self, key: &usize) -> Option<&V> {
+        self.slots.get(*key).and_then(Option::as_ref)
+    }
+
+    fn insert(&mut self, key: usize, value: V) {
+        if key >= self.slots.len() {
+            self.slots.resize_with(key + 1, || None);
+        }
+        if self.slots[key].replace(value).is_none() {
+            self.filled += 1;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.filled
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+        self.filled = 0;
+    }
+}
+
+/// Caches the results of a recursive function so each argument is computed once.
+///
+/// The function is written as usual, except that it takes the `Memo` and wraps
+/// its body in `get_or_compute`, making its recursive calls through the memo:
+/// overlapping subproblems are then solved once and the naive exponential
+/// recursion becomes the top-down form of a DP table.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V, C = HashMap<K, V>> {
+    cache: C,
+    marker: PhantomData<(K, V)>,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    /// Creates an empty memo backed by a `HashMap`
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl<V: Clone> Memo<usize, V, DenseCache<V>> {
+    /// Creates an empty memo backed by an array indexed by the key
+    pub fn dense() -> Self {
+        Memo {
+            cache: DenseCache::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V: Clone, C: Cache<K, V>> Memo<K, V, C> {
+    /// Returns the cached value for `key`, or computes it with `compute`,
+    /// which receives the memo for its own recursive calls, and caches it
+    pub fn get_or_compute<F: FnOnce(&mut Self) -> V>(&mut self, key: K, compute: F) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Returns the cached value for `key` without computing it
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Returns the number of cached results
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Checks if nothing has been cached yet
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets every cached result
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Memo;
+    use std::cell::Cell;
+
+    #[test]
+    fn computes_each_key_once() {
+        let calls = Cell::new(0);
+        fn collatz_steps(memo: &mut Memo<u64, u32>, n: u64, calls: &Cell<u32>) -> u32 {
+            memo.get_or_compute(n, |memo| {
+                calls.set(calls.get() + 1);
+                match n {
+                    1 => 0,
+                    n if n % 2 == 0 => 1 + collatz_steps(memo, n / 2, calls),
+                    n => 1 + collatz_steps(memo, 3 * n + 1, calls),
+                }
+            })
+        }
+        let mut memo = Memo::new();
+        assert_eq!(collatz_steps(&mut memo, 27, &calls), 111);
+        let first = calls.get();
+        assert_eq!(memo.len() as u32, first);
+        // 27 passes through 41, so this is answered from the cache
+        assert_eq!(collatz_steps(&mut memo, 41, &calls), 109);
+        assert_eq!(calls.get(), first);
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+
+    #[test]
+    fn dense_backing() {
+        fn tribonacci(memo: &mut Memo<usize, u64, super::DenseCache<u64>>, n: usize) -> u64 {
+            memo.get_or_compute(n, |memo| match n {
+                0 | 1 => 0,
+                2 => 1,
+                n => tribonacci(memo, n - 1) + tribonacci(memo, n - 2) + tribonacci(memo, n - 3),
+            })
+        }
+        let mut memo = Memo::dense();
+        assert_eq!(tribonacci(&mut memo, 37), 1_132_436_852);
+        assert_eq!(memo.len(), 38);
+        assert_eq!(memo.get(&10), Some(&81));
+    }
+}
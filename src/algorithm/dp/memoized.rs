@@ -0,0 +1,82 @@
+//! Naive recursions next to their memoized versions, as examples of `Memo`
+
+use super::{DenseCache, Memo};
+
+/// The `n`th Fibonacci number by plain recursion, in exponential time
+pub fn fibonacci_naive(n: usize) -> u128 {
+    if n < 2 {
+        n as u128
+    } else {
+        fibonacci_naive(n - 1) + fibonacci_naive(n - 2)
+    }
+}
+
+/// The `n`th Fibonacci number by the same recursion through an array-backed `Memo`, in O(n).
+/// Panics past n = 186, where the result overflows `u128`.
+pub fn fibonacci_memo(n: usize) -> u128 {
+    fn fib(memo: &mut Memo<usize, u128, DenseCache<u128>>, n: usize) -> u128 {
+        memo.get_or_compute(n, |memo| {
+            if n < 2 {
+                n as u128
+            } else {
+                fib(memo, n - 1) + fib(memo, n - 2)
+            }
+        })
+    }
+    fib(&mut Memo::dense(), n)
+}
+
+/// Number of right/down paths across a `rows` x `cols` grid by plain recursion
+pub fn grid_paths_naive(rows: usize, cols: usize) -> u128 {
+    if rows == 0 || cols == 0 {
+        return 0;
+    }
+    if rows == 1 || cols == 1 {
+        return 1;
+    }
+    grid_paths_naive(rows - 1, cols) + grid_paths_naive(rows, cols - 1)
+}
+
+/// Number of right/down paths across a `rows` x `cols` grid, memoized on the
+/// (rows, cols) pair with a `HashMap`-backed `Memo`, in O(rows * cols)
+pub fn grid_paths_memo(rows: usize, cols: usize) -> u128 {
+    fn paths(memo: &mut Memo<(usize, usize), u128>, rows: usize, cols: usize) -> u128 {
+        memo.get_or_compute((rows, cols), |memo| {
+            if rows == 0 || cols == 0 {
+                0
+            } else if rows == 1 || cols == 1 {
+                1
+            } else {
+                paths(memo, rows - 1, cols) + paths(memo, rows, cols - 1)
+            }
+        })
+    }
+    paths(&mut Memo::new(), rows, cols)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fibonacci_memo, fibonacci_naive, grid_paths_memo, grid_paths_naive};
+
+    #[test]
+    fn memoized_matches_naive() {
+        for n in 0..25 {
+            assert_eq!(fibonacci_memo(n), fibonacci_naive(n));
+        }
+        for rows in 0..10 {
+            for cols in 0..10 {
+                assert_eq!(grid_paths_memo(rows, cols), grid_paths_naive(rows, cols));
+            }
+        }
+    }
+
+    #[test]
+    fn memoized_reaches_inputs_naive_cannot() {
+        assert_eq!(
+            fibonacci_memo(186),
+            332_825_110_087_067_562_321_196_029_789_634_457_848
+        );
+        // C(58, 29)
+        assert_eq!(grid_paths_memo(30, 30), 30_067_266_499_541_040);
+    }
+}
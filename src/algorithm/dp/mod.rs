@@ -0,0 +1,29 @@
+mod bitmask;
+mod coin_change;
+mod interval;
+mod knapsack;
+mod lis;
+mod matrix_chain;
+mod memo;
+mod memoized;
+mod optimal_bst;
+pub mod palindrome;
+mod subset_sum;
+mod tsp;
+
+pub use self::bitmask::{
+    BitsOf, MasksWithPopcount, SubsetsOf, bits_of, masks_with_popcount, subsets_of,
+};
+pub use self::coin_change::{count_ways, count_ways_mod, min_coins};
+pub use self::interval::{IntervalTable, SplitTree, interval_dp};
+pub use self::knapsack::{
+    Item, KnapsackSolution, knapsack_01, knapsack_01_value, knapsack_bounded,
+    knapsack_meet_in_middle, knapsack_unbounded,
+};
+pub use self::lis::{lis, lis_count, lis_indices, lis_non_decreasing};
+pub use self::matrix_chain::{ChainOrder, matrix_chain_order};
+pub use self::memo::{Cache, DenseCache, Memo};
+pub use self::memoized::{fibonacci_memo, fibonacci_naive, grid_paths_memo, grid_paths_naive};
+pub use self::optimal_bst::{OptimalBst, optimal_bst};
+pub use self::subset_sum::{can_partition, subset_sum, subset_sum_bitset, subset_sum_exists};
+pub use self::tsp::{Tour, tsp_held_karp};
@@ -0,0 +1,59 @@
+use super::{SplitTree, interval_dp};
+
+/// A binary search tree minimizing the expected search cost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimalBst {
+    /// Sum over keys of frequency times depth, counting the root as depth 1
+    pub cost: u64,
+    /// The shape as a split tree over the `n + 1` gaps between keys: a split
+    /// at `k` is a node holding key `k`, and leaves are empty subtrees
+    pub shape: SplitTree,
+}
+
+/// Builds the optimal BST for keys searched `freq[i]` times each.
+///
+/// A BST over n keys is a full binary tree over the n + 1 gaps around them,
+/// where splitting gaps `[i, j]` after gap `k` roots the subtree at key `k`,
+/// so this is `interval_dp` with every key in the interval one level deeper.
+pub fn optimal_bst(freq: &[u64]) -> OptimalBst {
+    let mut prefix = vec![0; freq.len() + 1];
+    for (i, f) in freq.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + f;
+    }
+    let gaps = freq.len() + 1;
+    let table = interval_dp(gaps, |i, _, j| prefix[j] - prefix[i]);
+    OptimalBst {
+        cost: table.cost(0, gaps - 1),
+        shape: table.split_tree(0, gaps - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::optimal_bst;
+    use crate::algorithm::dp::SplitTree;
+
+    fn root(shape: &SplitTree) -> Option<usize> {
+        match shape {
+            SplitTree::Leaf(_) => None,
+            SplitTree::Split { at, .. } => Some(*at),
+        }
+    }
+
+    #[test]
+    fn known_trees() {
+        let three = optimal_bst(&[34, 8, 50]);
+        assert_eq!(three.cost, 142);
+        assert_eq!(root(&three.shape), Some(2));
+
+        assert_eq!(optimal_bst(&[34, 50]).cost, 118);
+        assert_eq!(optimal_bst(&[]).cost, 0);
+        assert_eq!(optimal_bst(&[5]).cost, 5);
+    }
+
+    #[test]
+    fn heavy_key_becomes_root() {
+        let bst = optimal_bst(&[1, 1, 100, 1, 1]);
+        assert_eq!(root(&bst.shape), Some(2));
+    }
+}
@@ -0,0 +1,7 @@
+mod partition;
+mod subsequence;
+mod table;
+
+pub use self::partition::{min_cuts, palindrome_partition};
+pub use self::subsequence::{longest_palindromic_subsequence, min_insertions};
+pub use self::table::PalindromeTable;
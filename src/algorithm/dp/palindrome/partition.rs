@@ -0,0 +1,69 @@
+use super::PalindromeTable;
+
+impl PalindromeTable {
+    /// Splits the string into the fewest palindromic pieces
+    pub fn partition(&self) -> Vec<String> {
+        let n = self.len();
+        // pieces[j]: fewest palindromes covering chars[..j]; start[j] where the last one begins
+        let mut pieces = vec![0; n + 1];
+        let mut start = vec![0; n + 1];
+        for j in 1..=n {
+            pieces[j] = usize::MAX;
+            for i in 0..j {
+                if self.is_palindrome(i, j) && pieces[i] + 1 < pieces[j] {
+                    pieces[j] = pieces[i] + 1;
+                    start[j] = i;
+                }
+            }
+        }
+        let mut parts = Vec::with_capacity(pieces[n]);
+        let mut j = n;
+        while j > 0 {
+            parts.push(self.chars[start[j]..j].iter().collect());
+            j = start[j];
+        }
+        parts.reverse();
+        parts
+    }
+
+    /// Fewest cuts that split the string into palindromes
+    pub fn min_cuts(&self) -> usize {
+        self.partition().len().saturating_sub(1)
+    }
+}
+
+/// Splits `s` into the fewest palindromic substrings
+pub fn palindrome_partition(s: &str) -> Vec<String> {
+    PalindromeTable::new(s).partition()
+}
+
+/// Returns the fewest cuts that split `s` into palindromes
+pub fn min_cuts(s: &str) -> usize {
+    PalindromeTable::new(s).min_cuts()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{min_cuts, palindrome_partition};
+
+    #[test]
+    fn fewest_pieces() {
+        assert_eq!(palindrome_partition("aab"), vec!["aa", "b"]);
+        assert_eq!(palindrome_partition("racecar"), vec!["racecar"]);
+        assert_eq!(palindrome_partition(""), Vec::<String>::new());
+        assert_eq!(min_cuts("ababbbabbababa"), 3);
+        assert_eq!(min_cuts("abcde"), 4);
+        assert_eq!(min_cuts("a"), 0);
+        assert_eq!(min_cuts(""), 0);
+    }
+
+    #[test]
+    fn pieces_rebuild_the_string() {
+        for s in ["banana", "noonabbad", "ééaé", "abacdcaba"] {
+            let parts = palindrome_partition(s);
+            assert_eq!(parts.concat(), s);
+            assert!(parts.iter().all(|p| p.chars().eq(p.chars().rev())));
+            assert_eq!(parts.len() - 1, min_cuts(s));
+        }
+    }
+}
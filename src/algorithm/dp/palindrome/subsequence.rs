@@ -0,0 +1,79 @@
+use super::PalindromeTable;
+
+impl PalindromeTable {
+    /// Reconstructs a longest palindromic subsequence of the whole string
+    pub fn longest_subsequence(&self) -> String {
+        let chars = &self.chars;
+        let mut left = String::new();
+        let mut middle = None;
+        let (mut i, mut j) = (0, chars.len());
+        // Walk the half-open window chars[i..j] inwards along the table
+        while i < j {
+            if j - i == 1 {
+                middle = Some(chars[i]);
+                break;
+            }
+            if chars[i] == chars[j - 1] {
+                left.push(chars[i]);
+                i += 1;
+                j -= 1;
+            } else if self.subsequence_len(i + 1, j) >= self.subsequence_len(i, j - 1) {
+                i += 1;
+            } else {
+                j -= 1;
+            }
+        }
+        let right: String = left.chars().rev().collect();
+        left.extend(middle);
+        left + &right
+    }
+
+    /// Fewest chars to insert anywhere to make the string a palindrome.
+    ///
+    /// Chars outside a longest palindromic subsequence each need a mirror
+    /// inserted, and no fewer insertions can do.
+    pub fn min_insertions(&self) -> usize {
+        self.len() - self.subsequence_len(0, self.len())
+    }
+}
+
+/// Returns a longest palindromic subsequence of `s`
+pub fn longest_palindromic_subsequence(s: &str) -> String {
+    PalindromeTable::new(s).longest_subsequence()
+}
+
+/// Returns the fewest chars to insert into `s` to make it a palindrome
+pub fn min_insertions(s: &str) -> usize {
+    PalindromeTable::new(s).min_insertions()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{longest_palindromic_subsequence, min_insertions};
+
+    fn is_palindrome(s: &str) -> bool {
+        s.chars().eq(s.chars().rev())
+    }
+
+    #[test]
+    fn longest_subsequences() {
+        assert_eq!(longest_palindromic_subsequence("bbbab"), "bbbb");
+        assert_eq!(longest_palindromic_subsequence("cbbd"), "bb");
+        assert_eq!(longest_palindromic_subsequence(""), "");
+        assert_eq!(longest_palindromic_subsequence("x"), "x");
+        let found = longest_palindromic_subsequence("GEEKSFORGEEKS");
+        assert_eq!(found.chars().count(), 5);
+        assert!(is_palindrome(&found));
+        assert_eq!(longest_palindromic_subsequence("añbña"), "añbña");
+    }
+
+    #[test]
+    fn insertions() {
+        assert_eq!(min_insertions("ab"), 1);
+        assert_eq!(min_insertions("aa"), 0);
+        assert_eq!(min_insertions("abcd"), 3);
+        assert_eq!(min_insertions("abcda"), 2);
+        assert_eq!(min_insertions("mbadm"), 2);
+        assert_eq!(min_insertions(""), 0);
+    }
+}
@@ -0,0 +1,86 @@
+/// Palindrome tables over the chars of a string, shared by the palindrome DP problems.
+///
+/// Both tables take O(n^2) time and space to build; every query after that is
+/// answered from them.
+#[derive(Debug, Clone)]
+pub struct PalindromeTable {
+    pub(super) chars: Vec<char>,
+    // is_palindrome[i][j]: chars[i..=j] reads the same backwards
+    is_palindrome: Vec<Vec<bool>>,
+    // subsequence[i][j]: length of the longest palindromic subsequence of chars[i..=j]
+    pub(super) subsequence: Vec<Vec<usize>>,
+}
+
+impl PalindromeTable {
+    /// Builds both tables for `s`, filling intervals from the shortest up
+    pub fn new(s: &str) -> PalindromeTable {
+        let chars: Vec<char> = s.chars().collect();
+        let n = chars.len();
+        let mut is_palindrome = vec![vec![false; n]; n];
+        let mut subsequence = vec![vec![0; n]; n];
+        for i in (0..n).rev() {
+            is_palindrome[i][i] = true;
+            subsequence[i][i] = 1;
+            for j in i + 1..n {
+                let ends_match = chars[i] == chars[j];
+                is_palindrome[i][j] = ends_match && (j - i < 2 || is_palindrome[i + 1][j - 1]);
+                subsequence[i][j] = if ends_match {
+                    if j - i < 2 {
+                        2
+                    } else {
+                        subsequence[i + 1][j - 1] + 2
+                    }
+                } else {
+                    subsequence[i + 1][j].max(subsequence[i][j - 1])
+                };
+            }
+        }
+        PalindromeTable {
+            chars,
+            is_palindrome,
+            subsequence,
+        }
+    }
+
+    /// Returns the number of chars in the string
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Checks if the string is empty
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Checks if the chars `start..end` form a palindrome; empty ranges do
+    pub fn is_palindrome(&self, start: usize, end: usize) -> bool {
+        start >= end || self.is_palindrome[start][end - 1]
+    }
+
+    /// Length of the longest palindromic subsequence of the chars `start..end`
+    pub fn subsequence_len(&self, start: usize, end: usize) -> usize {
+        if start >= end {
+            0
+        } else {
+            self.subsequence[start][end - 1]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PalindromeTable;
+
+    #[test]
+    fn substring_and_subsequence_queries() {
+        let table = PalindromeTable::new("abacdc");
+        assert!(table.is_palindrome(0, 3));
+        assert!(table.is_palindrome(3, 6));
+        assert!(!table.is_palindrome(0, 4));
+        assert!(table.is_palindrome(2, 2));
+        assert_eq!(table.subsequence_len(0, 6), 3);
+        assert_eq!(table.subsequence_len(1, 1), 0);
+        assert_eq!(table.len(), 6);
+        assert!(PalindromeTable::new("").is_empty());
+    }
+}
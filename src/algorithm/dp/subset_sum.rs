@@ -0,0 +1,117 @@
+use crate::data_structure::BitVec;
+
+/// Finds a subset of `nums` summing to `target` and returns its indices, ascending.
+///
+/// `reachable[i][s]` records whether some subset of the first `i` numbers sums
+/// to `s`; walking back from `reachable[n][target]` recovers the subset. Each
+/// row is a `BitVec` built by one shifted or of the row before it, so this takes
+/// O(n * target / 64) time and O(n * target / 8) bytes.
+pub fn subset_sum(nums: &[usize], target: usize) -> Option<Vec<usize>> {
+    let mut reachable = Vec::with_capacity(nums.len() + 1);
+    let mut row = BitVec::new(target + 1);
+    row.set(0, true);
+    for &x in nums {
+        reachable.push(row.clone());
+        row.or_shifted_left(x);
+    }
+    if !row.get(target) {
+        return None;
+    }
+    let mut chosen = Vec::new();
+    let mut sum = target;
+    for i in (0..nums.len()).rev() {
+        // If the first i numbers cannot reach sum, number i must be in the subset
+        if !reachable[i].get(sum) {
+            chosen.push(i);
+            sum -= nums[i];
+        }
+    }
+    chosen.reverse();
+    Some(chosen)
+}
+
+/// Checks if some subset of `nums` sums to `target` with a plain boolean DP,
+/// in O(n * target) time and O(target) space
+pub fn subset_sum_exists(nums: &[usize], target: usize) -> bool {
+    let mut reachable = vec![false; target + 1];
+    reachable[0] = true;
+    for &x in nums {
+        // Walking sums downwards uses each number at most once
+        for s in (x..=target).rev() {
+            reachable[s] |= reachable[s - x];
+        }
+    }
+    reachable[target]
+}
+
+/// `subset_sum_exists` with the reachable sums packed into a single `BitVec`,
+/// so each number costs one shifted or over target / 64 words
+pub fn subset_sum_bitset(nums: &[usize], target: usize) -> bool {
+    let mut reachable = BitVec::new(target + 1);
+    reachable.set(0, true);
+    for &x in nums {
+        reachable.or_shifted_left(x);
+    }
+    reachable.get(target)
+}
+
+/// Checks if `nums` can be split into two parts with equal sums
+pub fn can_partition(nums: &[usize]) -> bool {
+    let total: usize = nums.iter().sum();
+    total.is_multiple_of(2) && subset_sum_bitset(nums, total / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{can_partition, subset_sum, subset_sum_bitset, subset_sum_exists};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    #[test]
+    fn finds_subsets() {
+        let nums = [3, 34, 4, 12, 5, 2];
+        let chosen = subset_sum(&nums, 9).unwrap();
+        assert_eq!(chosen.iter().map(|&i| nums[i]).sum::<usize>(), 9);
+        assert_eq!(subset_sum(&nums, 30), None);
+        assert_eq!(subset_sum(&nums, 0), Some(vec![]));
+        assert_eq!(subset_sum(&[], 1), None);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        let mut rng = XorShift64Star::seed_from_u64(0xC0FFEE);
+        for _ in 0..50 {
+            let nums: Vec<usize> = (0..10).map(|_| rng.gen_index(40)).collect();
+            for target in 0..=200 {
+                let exists = subset_sum_exists(&nums, target);
+                assert_eq!(subset_sum_bitset(&nums, target), exists);
+                match subset_sum(&nums, target) {
+                    Some(chosen) => {
+                        assert!(exists);
+                        assert_eq!(chosen.iter().map(|&i| nums[i]).sum::<usize>(), target);
+                    }
+                    None => assert!(!exists),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn partitions() {
+        assert!(can_partition(&[1, 5, 11, 5]));
+        assert!(!can_partition(&[1, 2, 3, 5]));
+        assert!(!can_partition(&[1, 2, 4]));
+        assert!(can_partition(&[]));
+    }
+
+    #[test]
+    fn bitset_handles_large_targets() {
+        // Multiples of 1000 plus one odd number: only targets near multiples are reachable
+        let mut nums: Vec<usize> = (1..=100).map(|i| i * 1000).collect();
+        nums.push(7);
+        let total: usize = nums.iter().sum();
+        assert!(subset_sum_bitset(&nums, 2_000_007));
+        assert!(!subset_sum_bitset(&nums, 2_000_008));
+        assert!(!can_partition(&nums));
+        assert!(subset_sum_bitset(&nums, total));
+    }
+}
@@ -0,0 +1,156 @@
+use super::bits_of;
+
+/// A closed tour through every city
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour {
+    /// Total length, including the edge back to the start
+    pub cost: u64,
+    /// Cities in visiting order, starting at city 0 and not repeating it at the end
+    pub order: Vec<usize>,
+}
+
+/// Solves the travelling salesman problem exactly with the Held–Karp bitmask DP.
+///
+/// `best[mask][last]` is the shortest path that starts at city 0, visits exactly
+/// the cities in `mask` (over cities 1..n) and ends at `last`. Runs in
+/// O(2^n * n^2) time and O(2^n * n) space, so it is practical up to about 20
+/// cities. The matrix may be asymmetric. Panics if it is not square or n > 24.
+pub fn tsp_held_karp(dist: &[Vec<u64>]) -> Tour {
+    let n = dist.len();
+    assert!(
+        dist.iter().all(|row| row.len() == n),
+        "distance matrix must be square"
+    );
+    assert!(
+        n <= 24,
+        "Held–Karp needs O(2^n * n) memory; n = {n} is too large"
+    );
+    if n <= 1 {
+        return Tour {
+            cost: 0,
+            order: (0..n).collect(),
+        };
+    }
+    // City i >= 1 is bit i - 1
+    let others = n - 1;
+    let full = (1usize << others) - 1;
+    let mut best = vec![vec![u64::MAX; others]; full + 1];
+    let mut parent = vec![vec![usize::MAX; others]; full + 1];
+    for last in 0..others {
+        best[1 << last][last] = dist[0][last + 1];
+    }
+    for mask in 1..=full {
+        for last in bits_of(mask as u64) {
+            let here = best[mask][last];
+            if here == u64::MAX {
+                continue;
+            }
+            for next in bits_of((full & !mask) as u64) {
+                let extended = mask | 1 << next;
+                let cost = here.saturating_add(dist[last + 1][next + 1]);
+                if cost < best[extended][next] {
+                    best[extended][next] = cost;
+                    parent[extended][next] = last;
+                }
+            }
+        }
+    }
+
+    let (mut last, cost) = (0..others)
+        .map(|last| (last, best[full][last].saturating_add(dist[last + 1][0])))
+        .min_by_key(|&(_, cost)| cost)
+        .expect("at least one other city");
+    let mut order = Vec::with_capacity(n);
+    let mut mask = full;
+    while last != usize::MAX {
+        order.push(last + 1);
+        let previous = parent[mask][last];
+        mask &= !(1 << last);
+        last = previous;
+    }
+    order.push(0);
+    order.reverse();
+    Tour { cost, order }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tsp_held_karp;
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn tour_length(dist: &[Vec<u64>], order: &[usize]) -> u64 {
+        (0..order.len())
+            .map(|i| dist[order[i]][order[(i + 1) % order.len()]])
+            .sum()
+    }
+
+    fn brute_force(dist: &[Vec<u64>]) -> u64 {
+        fn permute(
+            rest: &mut Vec<usize>,
+            path: &mut Vec<usize>,
+            dist: &[Vec<u64>],
+            best: &mut u64,
+        ) {
+            if rest.is_empty() {
+                *best = (*best).min(tour_length(dist, path));
+                return;
+            }
+            for i in 0..rest.len() {
+                let city = rest.remove(i);
+                path.push(city);
+                permute(rest, path, dist, best);
+                path.pop();
+                rest.insert(i, city);
+            }
+        }
+        let mut best = u64::MAX;
+        permute(
+            &mut (1..dist.len()).collect(),
+            &mut vec![0],
+            dist,
+            &mut best,
+        );
+        best
+    }
+
+    #[test]
+    fn classic_four_cities() {
+        let dist = vec![
+            vec![0, 10, 15, 20],
+            vec![10, 0, 35, 25],
+            vec![15, 35, 0, 30],
+            vec![20, 25, 30, 0],
+        ];
+        let tour = tsp_held_karp(&dist);
+        assert_eq!(tour.cost, 80);
+        assert_eq!(tour_length(&dist, &tour.order), 80);
+        assert_eq!(tour.order[0], 0);
+    }
+
+    #[test]
+    fn matches_brute_force_on_asymmetric_matrices() {
+        let mut rng = XorShift64Star::seed_from_u64(0xABCDEF);
+        for n in 2..=8 {
+            let dist: Vec<Vec<u64>> = (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| if i == j { 0 } else { rng.gen_range(1..101) })
+                        .collect()
+                })
+                .collect();
+            let tour = tsp_held_karp(&dist);
+            let mut visited = tour.order.clone();
+            visited.sort();
+            assert_eq!(visited, (0..n).collect::<Vec<_>>());
+            assert_eq!(tour_length(&dist, &tour.order), tour.cost);
+            assert_eq!(tour.cost, brute_force(&dist));
+        }
+    }
+
+    #[test]
+    fn trivial_sizes() {
+        assert_eq!(tsp_held_karp(&[]).order, Vec::<usize>::new());
+        assert_eq!(tsp_held_karp(&[vec![0]]).cost, 0);
+        assert_eq!(tsp_held_karp(&[vec![0, 3], vec![4, 0]]).cost, 7);
+    }
+}
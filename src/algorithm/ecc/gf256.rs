@@ -0,0 +1,169 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub};
+
+/// The reduction polynomial x^8 + x^4 + x^3 + x^2 + 1, for which 2 generates
+/// the multiplicative group
+const POLY: u16 = 0x11D;
+
+/// `EXP[i] = 2^i`, doubled in length so products of logs need no reduction
+const EXP: [u8; 512] = {
+    let mut table = [0; 512];
+    let mut value: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        table[i] = value as u8;
+        table[i + 255] = value as u8;
+        value <<= 1;
+        if value & 0x100 != 0 {
+            value ^= POLY;
+        }
+        i += 1;
+    }
+    table
+};
+
+/// `LOG[2^i] = i`; `LOG[0]` is unused
+const LOG: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 255 {
+        table[EXP[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// An element of the finite field GF(2^8): bytes as polynomials over GF(2)
+/// modulo x^8 + x^4 + x^3 + x^2 + 1.
+///
+/// Addition is XOR. Multiplication adds discrete logarithms through
+/// precomputed tables, since every non-zero element is a power of 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gf256(pub u8);
+
+impl Gf256 {
+    pub const ZERO: Gf256 = Gf256(0);
+    pub const ONE: Gf256 = Gf256(1);
+    /// The generator 2 (the polynomial x)
+    pub const ALPHA: Gf256 = Gf256(2);
+
+    /// `ALPHA^power`, for any power, negative included
+    pub fn alpha_pow(power: i64) -> Gf256 {
+        Gf256(EXP[power.rem_euclid(255) as usize])
+    }
+
+    pub fn pow(self, exp: u64) -> Gf256 {
+        if self.0 == 0 {
+            return if exp == 0 { Gf256::ONE } else { Gf256::ZERO };
+        }
+        Gf256::alpha_pow((u64::from(LOG[self.0 as usize]) * (exp % 255)) as i64)
+    }
+
+    fn plus(self, rhs: Gf256) -> Gf256 {
+        Gf256(self.0 ^ rhs.0)
+    }
+
+    fn times(self, rhs: Gf256) -> Gf256 {
+        if self.0 == 0 || rhs.0 == 0 {
+            return Gf256::ZERO;
+        }
+        Gf256(EXP[LOG[self.0 as usize] as usize + LOG[rhs.0 as usize] as usize])
+    }
+
+    /// The multiplicative inverse, or None for zero
+    pub fn inverse(self) -> Option<Gf256> {
+        (self.0 != 0).then(|| Gf256(EXP[255 - LOG[self.0 as usize] as usize]))
+    }
+}
+
+impl Add for Gf256 {
+    type Output = Gf256;
+
+    fn add(self, rhs: Gf256) -> Gf256 {
+        self.plus(rhs)
+    }
+}
+
+/// Subtraction is addition in characteristic 2
+impl Sub for Gf256 {
+    type Output = Gf256;
+
+    fn sub(self, rhs: Gf256) -> Gf256 {
+        self.plus(rhs)
+    }
+}
+
+impl Mul for Gf256 {
+    type Output = Gf256;
+
+    fn mul(self, rhs: Gf256) -> Gf256 {
+        self.times(rhs)
+    }
+}
+
+impl Div for Gf256 {
+    type Output = Gf256;
+
+    /// Panics on division by zero
+    fn div(self, rhs: Gf256) -> Gf256 {
+        self.times(rhs.inverse().expect("division by zero in GF(2^8)"))
+    }
+}
+
+impl AddAssign for Gf256 {
+    fn add_assign(&mut self, rhs: Gf256) {
+        *self = *self + rhs;
+    }
+}
+
+impl MulAssign for Gf256 {
+    fn mul_assign(&mut self, rhs: Gf256) {
+        *self = *self * rhs;
+    }
+}
+
+impl Display for Gf256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gf256;
+
+    /// Carry-less multiplication reduced bit by bit
+    fn slow_mul(mut a: u8, mut b: u8) -> u8 {
+        let mut product = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                product ^= a;
+            }
+            let carry = a & 0x80 != 0;
+            a <<= 1;
+            if carry {
+                a ^= 0x1D;
+            }
+            b >>= 1;
+        }
+        product
+    }
+
+    #[test]
+    fn field_axioms() {
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                assert_eq!((Gf256(a) * Gf256(b)).0, slow_mul(a, b));
+            }
+            if a != 0 {
+                assert_eq!(Gf256(a) * Gf256(a).inverse().unwrap(), Gf256::ONE);
+                assert_eq!(Gf256(a).pow(255), Gf256::ONE);
+            }
+            assert_eq!(Gf256(a) + Gf256(a), Gf256::ZERO);
+        }
+        assert_eq!(Gf256::ZERO.inverse(), None);
+        assert_eq!(Gf256::alpha_pow(8), Gf256(0x1D));
+        assert_eq!(Gf256::alpha_pow(-1) * Gf256::ALPHA, Gf256::ONE);
+        assert_eq!(Gf256(0x53).pow(3), Gf256(0x53) * Gf256(0x53) * Gf256(0x53));
+    }
+}
@@ -0,0 +1,133 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// A SECDED codeword with two flipped bits: detected, but not correctable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoubleBitError;
+
+impl Display for DoubleBitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "uncorrectable double-bit error")
+    }
+}
+
+impl Error for DoubleBitError {}
+
+/// Codeword bit positions (1-based, bit `p - 1` of the byte) of the data bits;
+/// positions 1, 2 and 4 hold parity
+const DATA_POSITIONS: [u32; 4] = [3, 5, 6, 7];
+
+/// Encodes a nibble as a Hamming(7,4) codeword in the low 7 bits.
+///
+/// Parity bit 2^k covers every position with bit k set, so after an error the
+/// failing checks spell out the flipped position in binary. Panics if
+/// `nibble` has more than 4 bits.
+pub fn hamming74_encode(nibble: u8) -> u8 {
+    assert!(nibble < 16, "data must fit in 4 bits");
+    let mut codeword = 0u8;
+    for (i, &position) in DATA_POSITIONS.iter().enumerate() {
+        codeword |= (nibble >> i & 1) << (position - 1);
+    }
+    for parity in [1, 2, 4] {
+        let covered = (1..=7u32).filter(|p| p & parity != 0);
+        let bit = covered.fold(0, |acc, p| acc ^ (codeword >> (p - 1) & 1));
+        codeword |= bit << (parity - 1);
+    }
+    codeword
+}
+
+/// Position (1-based) of the single flipped bit, or 0 for a valid codeword
+fn syndrome(codeword: u8) -> u32 {
+    (1..=7u32)
+        .filter(|p| codeword >> (p - 1) & 1 == 1)
+        .fold(0, |acc, p| acc ^ p)
+}
+
+fn extract(codeword: u8) -> u8 {
+    DATA_POSITIONS
+        .iter()
+        .enumerate()
+        .fold(0, |nibble, (i, &p)| nibble | (codeword >> (p - 1) & 1) << i)
+}
+
+/// Decodes a Hamming(7,4) codeword, correcting up to one flipped bit. Returns
+/// the nibble and the index of the bit corrected, if any. Two flipped bits
+/// are miscorrected; use SECDED to detect them. The 8th bit is ignored
+pub fn hamming74_decode(codeword: u8) -> (u8, Option<u32>) {
+    let codeword = codeword & 0x7F;
+    match syndrome(codeword) {
+        0 => (extract(codeword), None),
+        position => (extract(codeword ^ 1 << (position - 1)), Some(position - 1)),
+    }
+}
+
+/// Encodes a nibble as an extended Hamming(8,4) codeword: Hamming(7,4) plus
+/// an overall parity bit in bit 7, for single-error correction and
+/// double-error detection
+pub fn secded_encode(nibble: u8) -> u8 {
+    let codeword = hamming74_encode(nibble);
+    codeword | ((codeword.count_ones() % 2) as u8) << 7
+}
+
+/// Decodes a SECDED codeword, correcting one flipped bit and reporting two.
+///
+/// A single error flips the overall parity; two errors leave it intact while
+/// still giving a non-zero syndrome.
+pub fn secded_decode(codeword: u8) -> Result<(u8, Option<u32>), DoubleBitError> {
+    let parity_ok = codeword.count_ones().is_multiple_of(2);
+    match (syndrome(codeword & 0x7F), parity_ok) {
+        (0, true) => Ok((extract(codeword), None)),
+        // Only the overall parity bit itself flipped
+        (0, false) => Ok((extract(codeword), Some(7))),
+        (position, false) => Ok((extract(codeword ^ 1 << (position - 1)), Some(position - 1))),
+        (_, true) => Err(DoubleBitError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoubleBitError, hamming74_decode, hamming74_encode, secded_decode, secded_encode};
+
+    #[test]
+    fn known_codewords() {
+        assert_eq!(hamming74_encode(0), 0);
+        assert_eq!(hamming74_encode(0b1111), 0b111_1111);
+        // Data bits d1 = 1, d2 = 1, d3 = 0, d4 = 1 give p1 = 1, p2 = 0, p3 = 0
+        assert_eq!(hamming74_encode(0b1011), 0b101_0101);
+        let codewords: Vec<u8> = (0..16).map(hamming74_encode).collect();
+        for (i, &a) in codewords.iter().enumerate() {
+            for &b in &codewords[i + 1..] {
+                assert!((a ^ b).count_ones() >= 3, "minimum distance is 3");
+            }
+        }
+    }
+
+    #[test]
+    fn corrects_every_single_bit_flip() {
+        for nibble in 0..16 {
+            let codeword = hamming74_encode(nibble);
+            assert_eq!(hamming74_decode(codeword), (nibble, None));
+            for bit in 0..7 {
+                assert_eq!(hamming74_decode(codeword ^ 1 << bit), (nibble, Some(bit)));
+            }
+            let extended = secded_encode(nibble);
+            assert_eq!(secded_decode(extended), Ok((nibble, None)));
+            for bit in 0..8 {
+                assert_eq!(secded_decode(extended ^ 1 << bit), Ok((nibble, Some(bit))));
+            }
+        }
+    }
+
+    #[test]
+    fn detects_every_double_bit_flip() {
+        for nibble in 0..16 {
+            let extended = secded_encode(nibble);
+            for first in 0..8 {
+                for second in first + 1..8 {
+                    let corrupted = extended ^ 1 << first ^ 1 << second;
+                    assert_eq!(secded_decode(corrupted), Err(DoubleBitError));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod gf256;
+mod hamming;
+mod reed_solomon;
+
+pub use self::gf256::Gf256;
+pub use self::hamming::{
+    DoubleBitError, hamming74_decode, hamming74_encode, secded_decode, secded_encode,
+};
+pub use self::reed_solomon::{ReedSolomon, ReedSolomonError};
@@ -0,0 +1,272 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use super::Gf256;
+
+/// Reasons a Reed–Solomon codeword fails to decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReedSolomonError {
+    /// The codeword is shorter than the parity or longer than 255 bytes
+    InvalidLength(usize),
+    /// More errors than the code can correct
+    TooManyErrors,
+}
+
+impl Display for ReedSolomonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ReedSolomonError::InvalidLength(length) => {
+                write!(f, "invalid codeword length {length}")
+            }
+            ReedSolomonError::TooManyErrors => write!(f, "too many errors to correct"),
+        }
+    }
+}
+
+impl Error for ReedSolomonError {}
+
+/// Evaluates a polynomial given lowest degree first, by Horner's rule
+fn eval(poly: &[Gf256], x: Gf256) -> Gf256 {
+    poly.iter().rev().fold(Gf256::ZERO, |acc, &c| acc * x + c)
+}
+
+/// A systematic Reed–Solomon code over GF(2^8), for teaching rather than
+/// production use.
+///
+/// A codeword is the message followed by `parity` check bytes, chosen so the
+/// codeword, read as a polynomial with the first byte as the highest
+/// coefficient, is a multiple of g(x) = (x - 1)(x - a)...(x - a^(parity-1))
+/// with a = 2. It corrects up to `parity / 2` corrupted bytes anywhere, and
+/// codewords are at most 255 bytes long.
+#[derive(Debug, Clone)]
+pub struct ReedSolomon {
+    /// The generator polynomial, highest degree first
+    generator: Vec<Gf256>,
+}
+
+impl ReedSolomon {
+    /// Creates a code with `parity` check bytes. Panics unless `parity` is in 1..255
+    pub fn new(parity: usize) -> ReedSolomon {
+        assert!((1..255).contains(&parity), "parity must be 1 to 254 bytes");
+        let mut generator = vec![Gf256::ONE];
+        for i in 0..parity {
+            // Multiply by (x - a^i)
+            let root = Gf256::alpha_pow(i as i64);
+            let mut next = vec![Gf256::ZERO; generator.len() + 1];
+            for (j, &c) in generator.iter().enumerate() {
+                next[j] += c;
+                next[j + 1] += c * root;
+            }
+            generator = next;
+        }
+        ReedSolomon { generator }
+    }
+
+    pub fn parity(&self) -> usize {
+        self.generator.len() - 1
+    }
+
+    /// Appends the check bytes: the remainder of message * x^parity divided by
+    /// g(x). Panics if the codeword would exceed 255 bytes
+    pub fn encode(&self, message: &[u8]) -> Vec<u8> {
+        assert!(
+            message.len() + self.parity() <= 255,
+            "codeword must fit in 255 bytes"
+        );
+        let mut remainder = vec![Gf256::ZERO; self.parity()];
+        // Polynomial long division, feeding one message byte at a time
+        for &byte in message {
+            let factor = Gf256(byte) + remainder[0];
+            remainder.rotate_left(1);
+            *remainder.last_mut().unwrap() = Gf256::ZERO;
+            for (r, &g) in remainder.iter_mut().zip(&self.generator[1..]) {
+                *r += factor * g;
+            }
+        }
+        message
+            .iter()
+            .copied()
+            .chain(remainder.iter().map(|c| c.0))
+            .collect()
+    }
+
+    /// Corrects `codeword` in place, returning how many bytes were wrong.
+    ///
+    /// Decoding runs in four steps:
+    /// 1. The syndromes `S_j = r(a^j)` vanish for a valid codeword. Otherwise
+    ///    they depend only on the errors.
+    /// 2. Berlekamp–Massey finds the shortest LFSR generating the syndromes,
+    ///    which is the error locator L(x), whose roots are the inverse error
+    ///    locations `X_k^-1`.
+    /// 3. Chien search tries every position as a root.
+    /// 4. Forney's formula gives each error value from the evaluator
+    ///    O(x) = S(x) L(x) mod x^parity: `e_k = X_k O(X_k^-1) / L'(X_k^-1)`.
+    pub fn correct(&self, codeword: &mut [u8]) -> Result<usize, ReedSolomonError> {
+        let n = codeword.len();
+        let parity = self.parity();
+        if n < parity || n > 255 {
+            return Err(ReedSolomonError::InvalidLength(n));
+        }
+        // The codeword polynomial, lowest degree first
+        let received: Vec<Gf256> = codeword.iter().rev().map(|&b| Gf256(b)).collect();
+        let syndromes: Vec<Gf256> = (0..parity)
+            .map(|j| eval(&received, Gf256::alpha_pow(j as i64)))
+            .collect();
+        if syndromes.iter().all(|&s| s == Gf256::ZERO) {
+            return Ok(0);
+        }
+
+        let locator = berlekamp_massey(&syndromes);
+        let errors = locator.len() - 1;
+        if 2 * errors > parity {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+        // Degree p of the codeword polynomial sits at byte n - 1 - p
+        let powers: Vec<usize> = (0..n)
+            .filter(|&p| eval(&locator, Gf256::alpha_pow(-(p as i64))) == Gf256::ZERO)
+            .collect();
+        if powers.len() != errors {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+
+        let mut evaluator = vec![Gf256::ZERO; parity];
+        for (i, &s) in syndromes.iter().enumerate() {
+            for (j, &l) in locator.iter().enumerate().take(parity - i) {
+                evaluator[i + j] += s * l;
+            }
+        }
+        // The formal derivative keeps the odd-degree terms in characteristic 2
+        let derivative: Vec<Gf256> = locator
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &l)| if i % 2 == 1 { l } else { Gf256::ZERO })
+            .collect();
+        for &p in &powers {
+            let x = Gf256::alpha_pow(p as i64);
+            let x_inverse = Gf256::alpha_pow(-(p as i64));
+            let magnitude = x * eval(&evaluator, x_inverse) / eval(&derivative, x_inverse);
+            codeword[n - 1 - p] ^= magnitude.0;
+        }
+        Ok(errors)
+    }
+
+    /// Corrects a copy of `codeword` and returns the message part
+    pub fn decode(&self, codeword: &[u8]) -> Result<Vec<u8>, ReedSolomonError> {
+        let mut corrected = codeword.to_vec();
+        self.correct(&mut corrected)?;
+        corrected.truncate(codeword.len() - self.parity());
+        Ok(corrected)
+    }
+}
+
+/// The shortest connection polynomial, lowest degree first with constant
+/// term 1, generating `syndromes` as a linear recurrence
+fn berlekamp_massey(syndromes: &[Gf256]) -> Vec<Gf256> {
+    let mut current = vec![Gf256::ONE];
+    let mut previous = vec![Gf256::ONE];
+    let mut length = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = Gf256::ONE;
+    for n in 0..syndromes.len() {
+        let discrepancy = (1..=length).fold(syndromes[n], |acc, i| {
+            acc + current.get(i).copied().unwrap_or_default() * syndromes[n - i]
+        });
+        if discrepancy == Gf256::ZERO {
+            shift += 1;
+            continue;
+        }
+        let scale = discrepancy / previous_discrepancy;
+        let mut next = current.clone();
+        next.resize(next.len().max(previous.len() + shift), Gf256::ZERO);
+        for (i, &b) in previous.iter().enumerate() {
+            next[i + shift] += scale * b;
+        }
+        if 2 * length <= n {
+            length = n + 1 - length;
+            previous = std::mem::replace(&mut current, next);
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            current = next;
+            shift += 1;
+        }
+    }
+    current.truncate(length + 1);
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReedSolomon, ReedSolomonError};
+
+    fn corrupt(codeword: &mut [u8], positions: &[usize], seed: u8) {
+        for (k, &p) in positions.iter().enumerate() {
+            codeword[p] ^= seed.wrapping_add((k as u8).wrapping_mul(37)) | 1;
+        }
+    }
+
+    #[test]
+    fn encodes_a_known_vector() {
+        // The QR code example: version 1-M data with 10 error correction bytes
+        let message = [
+            0x40, 0xD2, 0x75, 0x47, 0x76, 0x17, 0x32, 0x06, 0x27, 0x26, 0x96, 0xC6, 0xC6, 0x96,
+            0x70, 0xEC,
+        ];
+        let codeword = ReedSolomon::new(10).encode(&message);
+        assert_eq!(&codeword[..16], message);
+        assert_eq!(
+            &codeword[16..],
+            [0xBC, 0x2A, 0x90, 0x13, 0x6B, 0xAF, 0xEF, 0xFD, 0x4B, 0xE0]
+        );
+    }
+
+    #[test]
+    fn corrects_up_to_half_the_parity() {
+        let message: Vec<u8> = b"Reed-Solomon codes correct burst and random errors".to_vec();
+        for parity in [2, 4, 8, 16, 32] {
+            let code = ReedSolomon::new(parity);
+            let codeword = code.encode(&message);
+            let n = codeword.len();
+            assert_eq!(code.decode(&codeword).unwrap(), message);
+            for errors in 1..=parity / 2 {
+                for (trial, seed) in [3u8, 101, 250].into_iter().enumerate() {
+                    // Spread the errors, including the first and last bytes
+                    let positions: Vec<usize> = (0..errors)
+                        .map(|k| (k * n / errors + trial * 7) % n)
+                        .collect();
+                    let mut received = codeword.clone();
+                    corrupt(&mut received, &positions, seed);
+                    let mut corrected = received.clone();
+                    assert_eq!(code.correct(&mut corrected), Ok(errors), "parity {parity}");
+                    assert_eq!(corrected, codeword);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reports_uncorrectable_input() {
+        let code = ReedSolomon::new(4);
+        let codeword = code.encode(b"hello world");
+        let mut received = codeword.clone();
+        corrupt(&mut received, &[0, 3, 6], 9);
+        match code.correct(&mut received.clone()) {
+            // Three errors may land on another codeword, but never this one
+            Ok(_) => {}
+            Err(error) => assert_eq!(error, ReedSolomonError::TooManyErrors),
+        }
+        assert_ne!(
+            code.decode(&received).ok().as_deref(),
+            Some(&b"hello world"[..])
+        );
+        assert_eq!(
+            code.decode(&[1, 2, 3]),
+            Err(ReedSolomonError::InvalidLength(3))
+        );
+        assert_eq!(
+            code.decode(&[0; 256]),
+            Err(ReedSolomonError::InvalidLength(256))
+        );
+    }
+}
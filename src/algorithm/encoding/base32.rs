@@ -0,0 +1,60 @@
+use super::Codec;
+
+/// Base32 with the standard alphabet (RFC 4648 section 6), padded; decoding
+/// accepts lowercase
+pub const BASE32: Codec = Codec::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", 5).ignoring_case();
+
+/// Base32 with the "extended hex" alphabet (RFC 4648 section 7), which keeps
+/// the sort order of the data
+pub const BASE32_HEX: Codec = Codec::new(b"0123456789ABCDEFGHIJKLMNOPQRSTUV", 5).ignoring_case();
+
+#[cfg(test)]
+mod tests {
+    use super::{BASE32, BASE32_HEX};
+    use crate::algorithm::encoding::DecodeError;
+
+    #[test]
+    fn rfc_4648_vectors() {
+        let vectors = [
+            ("", "", ""),
+            ("f", "MY======", "CO======"),
+            ("fo", "MZXQ====", "CPNG===="),
+            ("foo", "MZXW6===", "CPNMU==="),
+            ("foob", "MZXW6YQ=", "CPNMUOG="),
+            ("fooba", "MZXW6YTB", "CPNMUOJ1"),
+            ("foobar", "MZXW6YTBOI======", "CPNMUOJ1E8======"),
+        ];
+        for (plain, standard, hex) in vectors {
+            assert_eq!(BASE32.encode(plain.as_bytes()), standard);
+            assert_eq!(BASE32.decode(standard).unwrap(), plain.as_bytes());
+            assert_eq!(
+                BASE32.decode(&standard.to_lowercase()).unwrap(),
+                plain.as_bytes()
+            );
+            assert_eq!(BASE32_HEX.encode(plain.as_bytes()), hex);
+            assert_eq!(BASE32_HEX.decode(hex).unwrap(), plain.as_bytes());
+            let unpadded = standard.trim_end_matches('=');
+            assert_eq!(
+                BASE32.with_padding(false).decode(unpadded).unwrap(),
+                plain.as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_impossible_lengths() {
+        // 1, 3 and 6 symbols never end a group exactly
+        for length in [1, 3, 6] {
+            let text = "A".repeat(length);
+            assert_eq!(
+                BASE32.with_padding(false).decode(&text),
+                Err(DecodeError::InvalidLength(length))
+            );
+        }
+        assert_eq!(
+            BASE32.decode("MY====="),
+            Err(DecodeError::InvalidPadding { offset: 7 })
+        );
+        assert_eq!(BASE32.decode("MZ======"), Err(DecodeError::TrailingBits));
+    }
+}
@@ -0,0 +1,100 @@
+use super::Codec;
+
+/// Base64 with the standard alphabet (RFC 4648 section 4), padded
+pub const BASE64: Codec = Codec::new(
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    6,
+);
+
+/// Base64 with the URL- and filename-safe alphabet (RFC 4648 section 5),
+/// unpadded as is usual in URLs
+pub const BASE64_URL: Codec = Codec::new(
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    6,
+)
+.with_padding(false);
+
+#[cfg(test)]
+mod tests {
+    use super::{BASE64, BASE64_URL};
+    use crate::algorithm::encoding::DecodeError;
+    use std::io::Read;
+
+    #[test]
+    fn rfc_4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(BASE64.encode(plain.as_bytes()), encoded);
+            assert_eq!(BASE64.decode(encoded).unwrap(), plain.as_bytes());
+            let unpadded = encoded.trim_end_matches('=');
+            assert_eq!(
+                BASE64.with_padding(false).encode(plain.as_bytes()),
+                unpadded
+            );
+            assert_eq!(
+                BASE64.with_padding(false).decode(unpadded).unwrap(),
+                plain.as_bytes()
+            );
+        }
+        assert_eq!(BASE64_URL.encode(&[0xFB, 0xFF, 0xBF]), "-_-_");
+        assert_eq!(BASE64.encode(&[0xFB, 0xFF, 0xBF]), "+/+/");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert_eq!(
+            BASE64.decode("Zm9v!A=="),
+            Err(DecodeError::InvalidSymbol {
+                offset: 4,
+                byte: b'!'
+            })
+        );
+        assert_eq!(
+            BASE64.decode("Zg="),
+            Err(DecodeError::InvalidPadding { offset: 3 })
+        );
+        assert_eq!(
+            BASE64.decode("Zg==="),
+            Err(DecodeError::InvalidPadding { offset: 4 })
+        );
+        assert_eq!(
+            BASE64.decode("Zg=a"),
+            Err(DecodeError::InvalidPadding { offset: 3 })
+        );
+        assert_eq!(BASE64.decode("Zm9vY"), Err(DecodeError::InvalidLength(5)));
+        assert_eq!(BASE64.decode("Zh=="), Err(DecodeError::TrailingBits));
+        assert_eq!(
+            BASE64_URL.decode("Zg=="),
+            Err(DecodeError::InvalidSymbol {
+                offset: 2,
+                byte: b'='
+            })
+        );
+    }
+
+    #[test]
+    fn streams_both_ways() {
+        let data: Vec<u8> = (0..5000u32).map(|i| (i * 7 + i / 13) as u8).collect();
+        let encoded: String = BASE64.encode_iter(data.iter().copied()).collect();
+        assert_eq!(encoded, BASE64.encode(&data));
+        let mut decoded = Vec::new();
+        BASE64
+            .decoder(encoded.as_bytes())
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, data);
+        let error = BASE64
+            .decoder(&b"Zm9v*"[..])
+            .read_to_end(&mut Vec::new())
+            .unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,244 @@
+use std::io::{self, Read};
+
+use super::DecodeError;
+
+const PAD: u8 = b'=';
+
+/// A radix-2^k text encoding, like Base64 (k = 6), Base32 (k = 5) or hex
+/// (k = 4): the input is read as a bit stream, k bits per output symbol.
+///
+/// Symbols come in groups that each hold a whole number of bytes (4 symbols
+/// per 3 bytes for Base64); a padded codec fills a short final group with '='.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Codec {
+    alphabet: &'static [u8],
+    bits: u32,
+    padding: bool,
+    ignore_case: bool,
+}
+
+impl Codec {
+    /// Creates a codec over `alphabet`, which must have exactly 2^`bits`
+    /// symbols, with padding and case-sensitive decoding
+    pub const fn new(alphabet: &'static [u8], bits: u32) -> Codec {
+        assert!(bits >= 1 && bits <= 8, "symbols must carry 1 to 8 bits");
+        assert!(
+            alphabet.len() == 1 << bits,
+            "alphabet must have 2^bits symbols"
+        );
+        Codec {
+            alphabet,
+            bits,
+            padding: true,
+            ignore_case: false,
+        }
+    }
+
+    /// The same codec, padding its output and requiring padding on input or not
+    pub const fn with_padding(self, padding: bool) -> Codec {
+        Codec { padding, ..self }
+    }
+
+    /// The same codec, decoding letters of either case
+    pub const fn ignoring_case(self) -> Codec {
+        Codec {
+            ignore_case: true,
+            ..self
+        }
+    }
+
+    /// Symbols per group: the fewest that hold a whole number of bytes
+    fn group(&self) -> usize {
+        8 / gcd(8, self.bits) as usize
+    }
+
+    fn value_of(&self, byte: u8) -> Option<u64> {
+        self.alphabet
+            .iter()
+            .position(|&c| c == byte || (self.ignore_case && c.eq_ignore_ascii_case(&byte)))
+            .map(|v| v as u64)
+    }
+
+    pub fn encode(&self, data: &[u8]) -> String {
+        self.encode_iter(data.iter().copied()).collect()
+    }
+
+    /// Lazily encodes a stream of bytes
+    pub fn encode_iter<I: IntoIterator<Item = u8>>(&self, bytes: I) -> Encode<I::IntoIter> {
+        Encode {
+            codec: *self,
+            bytes: bytes.into_iter(),
+            buffer: 0,
+            buffered: 0,
+            emitted: 0,
+            exhausted: false,
+        }
+    }
+
+    pub fn decode(&self, text: &str) -> Result<Vec<u8>, DecodeError> {
+        let mut state = DecodeState::new(*self);
+        let mut decoded = Vec::with_capacity(text.len() * self.bits as usize / 8);
+        for &byte in text.as_bytes() {
+            state.push(byte, &mut decoded)?;
+        }
+        state.finish()?;
+        Ok(decoded)
+    }
+
+    /// Wraps a reader of encoded text into a reader of the decoded bytes.
+    /// Decoding errors surface as `io::ErrorKind::InvalidData` wrapping a
+    /// `DecodeError`
+    pub fn decoder<R: Read>(&self, reader: R) -> Decoder<R> {
+        Decoder {
+            reader,
+            state: DecodeState::new(*self),
+            decoded: Vec::new(),
+            consumed: 0,
+            finished: false,
+        }
+    }
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Iterator over the symbols encoding a byte stream, returned by `Codec::encode_iter`
+pub struct Encode<I> {
+    codec: Codec,
+    bytes: I,
+    buffer: u64,
+    buffered: u32,
+    emitted: usize,
+    exhausted: bool,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Encode<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let bits = self.codec.bits;
+        while self.buffered < bits && !self.exhausted {
+            match self.bytes.next() {
+                Some(byte) => {
+                    self.buffer = (self.buffer << 8) | u64::from(byte);
+                    self.buffered += 8;
+                }
+                None => self.exhausted = true,
+            }
+        }
+        let symbol = if self.buffered >= bits {
+            self.buffered -= bits;
+            self.codec.alphabet[(self.buffer >> self.buffered) as usize & ((1 << bits) - 1)]
+        } else if self.buffered > 0 {
+            // Zero-fill the last partial symbol
+            let value = (self.buffer << (bits - self.buffered)) as usize & ((1 << bits) - 1);
+            self.buffered = 0;
+            self.codec.alphabet[value]
+        } else if self.codec.padding && !self.emitted.is_multiple_of(self.codec.group()) {
+            PAD
+        } else {
+            return None;
+        };
+        self.emitted += 1;
+        Some(char::from(symbol))
+    }
+}
+
+/// Incremental decoder shared by `Codec::decode` and `Decoder`
+struct DecodeState {
+    codec: Codec,
+    buffer: u64,
+    buffered: u32,
+    symbols: usize,
+    pads: usize,
+}
+
+impl DecodeState {
+    fn new(codec: Codec) -> DecodeState {
+        DecodeState {
+            codec,
+            buffer: 0,
+            buffered: 0,
+            symbols: 0,
+            pads: 0,
+        }
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) -> Result<(), DecodeError> {
+        let offset = self.symbols + self.pads;
+        if byte == PAD && self.codec.padding {
+            self.pads += 1;
+            return Ok(());
+        }
+        if self.pads > 0 {
+            return Err(DecodeError::InvalidPadding { offset });
+        }
+        let value = self
+            .codec
+            .value_of(byte)
+            .ok_or(DecodeError::InvalidSymbol { offset, byte })?;
+        self.buffer = (self.buffer << self.codec.bits) | value;
+        self.buffered += self.codec.bits;
+        self.symbols += 1;
+        if self.buffered >= 8 {
+            self.buffered -= 8;
+            out.push((self.buffer >> self.buffered) as u8);
+        }
+        Ok(())
+    }
+
+    /// Checks the input ended on a valid boundary
+    fn finish(&self) -> Result<(), DecodeError> {
+        let group = self.codec.group();
+        let length = self.symbols + self.pads;
+        // A whole symbol of leftover bits means the last one encodes no data
+        if self.buffered >= self.codec.bits {
+            return Err(DecodeError::InvalidLength(length));
+        }
+        if self.codec.padding {
+            let expected = (group - self.symbols % group) % group;
+            if self.pads != expected {
+                let offset = self.symbols + self.pads.min(expected);
+                return Err(DecodeError::InvalidPadding { offset });
+            }
+        }
+        if self.buffer & ((1 << self.buffered) - 1) != 0 {
+            return Err(DecodeError::TrailingBits);
+        }
+        Ok(())
+    }
+}
+
+/// Reader over the bytes decoded from an inner reader, returned by `Codec::decoder`
+pub struct Decoder<R> {
+    reader: R,
+    state: DecodeState,
+    decoded: Vec<u8>,
+    consumed: usize,
+    finished: bool,
+}
+
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let invalid = |error: DecodeError| io::Error::new(io::ErrorKind::InvalidData, error);
+        while self.consumed == self.decoded.len() && !self.finished {
+            self.decoded.clear();
+            self.consumed = 0;
+            let mut chunk = [0; 1024];
+            let read = self.reader.read(&mut chunk)?;
+            if read == 0 {
+                self.state.finish().map_err(invalid)?;
+                self.finished = true;
+            }
+            for &byte in &chunk[..read] {
+                self.state.push(byte, &mut self.decoded).map_err(invalid)?;
+            }
+        }
+        let available = &self.decoded[self.consumed..];
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.consumed += count;
+        Ok(count)
+    }
+}
@@ -0,0 +1,39 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons encoded text fails to decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A byte outside the alphabet, at this offset of the input
+    InvalidSymbol { offset: usize, byte: u8 },
+    /// Padding where data was expected, a wrong amount of padding, or padding
+    /// the codec does not use, at this offset
+    InvalidPadding { offset: usize },
+    /// No valid encoding has this many symbols
+    InvalidLength(usize),
+    /// The final symbol carries set bits beyond the end of the data
+    TrailingBits,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidSymbol { offset, byte } => {
+                write!(
+                    f,
+                    "invalid symbol {:?} at offset {offset}",
+                    char::from(*byte)
+                )
+            }
+            DecodeError::InvalidPadding { offset } => {
+                write!(f, "invalid padding at offset {offset}")
+            }
+            DecodeError::InvalidLength(length) => {
+                write!(f, "no valid encoding has {length} symbols")
+            }
+            DecodeError::TrailingBits => write!(f, "final symbol has non-zero trailing bits"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
@@ -0,0 +1,52 @@
+use super::Codec;
+
+/// Lowercase hexadecimal; decoding accepts either case
+pub const HEX: Codec = Codec::new(b"0123456789abcdef", 4)
+    .with_padding(false)
+    .ignoring_case();
+
+/// Uppercase hexadecimal; decoding accepts either case
+pub const HEX_UPPER: Codec = Codec::new(b"0123456789ABCDEF", 4)
+    .with_padding(false)
+    .ignoring_case();
+
+#[cfg(test)]
+mod tests {
+    use super::{HEX, HEX_UPPER};
+    use crate::algorithm::encoding::DecodeError;
+    use std::io::Read;
+
+    #[test]
+    fn encodes_and_decodes() {
+        assert_eq!(HEX.encode(&[0x00, 0x1f, 0xab, 0xff]), "001fabff");
+        assert_eq!(HEX_UPPER.encode(b"\xde\xad\xbe\xef"), "DEADBEEF");
+        assert_eq!(HEX.decode("DeadBeef").unwrap(), [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(HEX.decode("").unwrap(), []);
+        let all: Vec<u8> = (0..=255).collect();
+        assert_eq!(HEX.decode(&HEX.encode(&all)).unwrap(), all);
+        let mut streamed = Vec::new();
+        HEX.decoder("cafe".as_bytes())
+            .read_to_end(&mut streamed)
+            .unwrap();
+        assert_eq!(streamed, [0xca, 0xfe]);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert_eq!(HEX.decode("abc"), Err(DecodeError::InvalidLength(3)));
+        assert_eq!(
+            HEX.decode("0g"),
+            Err(DecodeError::InvalidSymbol {
+                offset: 1,
+                byte: b'g'
+            })
+        );
+        assert_eq!(
+            HEX.decode("00=="),
+            Err(DecodeError::InvalidSymbol {
+                offset: 2,
+                byte: b'='
+            })
+        );
+    }
+}
@@ -0,0 +1,16 @@
+mod base32;
+mod base64;
+mod codec;
+mod decode_error;
+mod hex;
+mod varint;
+
+pub use self::base32::{BASE32, BASE32_HEX};
+pub use self::base64::{BASE64, BASE64_URL};
+pub use self::codec::{Codec, Decoder, Encode};
+pub use self::decode_error::DecodeError;
+pub use self::hex::{HEX, HEX_UPPER};
+pub use self::varint::{
+    VarintError, decode_varint, decode_varint_signed, decode_varints, encode_varint,
+    encode_varint_signed, encode_varints, zigzag_decode, zigzag_encode,
+};
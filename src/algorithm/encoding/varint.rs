@@ -0,0 +1,155 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons varint bytes fail to decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VarintError {
+    /// The input ends before the last byte of a varint
+    Truncated,
+    /// The varint encodes a value wider than 64 bits
+    Overflow,
+}
+
+impl Display for VarintError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            VarintError::Truncated => write!(f, "input ends inside a varint"),
+            VarintError::Overflow => write!(f, "varint does not fit in 64 bits"),
+        }
+    }
+}
+
+impl Error for VarintError {}
+
+/// Appends `value` as an unsigned LEB128 varint: 7 bits per byte, least
+/// significant first, with the high bit set on every byte but the last.
+/// Values below 128 take one byte and `u64::MAX` takes ten
+pub fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads one varint from the start of `bytes`, returning it and the number of
+/// bytes it took
+pub fn decode_varint(bytes: &[u8]) -> Result<(u64, usize), VarintError> {
+    let mut value = 0u64;
+    for (i, &byte) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        let payload = u64::from(byte & 0x7F);
+        if shift >= 64 || (shift > 0 && payload >> (64 - shift) != 0) {
+            return Err(VarintError::Overflow);
+        }
+        value |= payload << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(VarintError::Truncated)
+}
+
+/// Maps signed to unsigned so small magnitudes stay small:
+/// 0, -1, 1, -2, 2, ... become 0, 1, 2, 3, 4, ...
+pub fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+pub fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Appends `value` zigzag-mapped as an unsigned varint
+pub fn encode_varint_signed(value: i64, out: &mut Vec<u8>) {
+    encode_varint(zigzag_encode(value), out);
+}
+
+pub fn decode_varint_signed(bytes: &[u8]) -> Result<(i64, usize), VarintError> {
+    decode_varint(bytes).map(|(value, length)| (zigzag_decode(value), length))
+}
+
+/// Encodes a whole sequence of signed values back to back
+pub fn encode_varints(values: &[i64]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(values.len());
+    for &value in values {
+        encode_varint_signed(value, &mut out);
+    }
+    out
+}
+
+/// Decodes the output of `encode_varints`
+pub fn decode_varints(mut bytes: &[u8]) -> Result<Vec<i64>, VarintError> {
+    let mut values = Vec::new();
+    while !bytes.is_empty() {
+        let (value, length) = decode_varint_signed(bytes)?;
+        values.push(value);
+        bytes = &bytes[length..];
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        VarintError, decode_varint, decode_varints, encode_varint, encode_varints, zigzag_decode,
+        zigzag_encode,
+    };
+
+    #[test]
+    fn known_encodings() {
+        let cases: [(u64, &[u8]); 5] = [
+            (0, &[0x00]),
+            (127, &[0x7F]),
+            (128, &[0x80, 0x01]),
+            (300, &[0xAC, 0x02]),
+            (
+                u64::MAX,
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ),
+        ];
+        for (value, bytes) in cases {
+            let mut out = Vec::new();
+            encode_varint(value, &mut out);
+            assert_eq!(out, bytes);
+            assert_eq!(decode_varint(bytes), Ok((value, bytes.len())));
+        }
+        assert_eq!(decode_varint(&[0xAC, 0x02, 0x55]), Ok((300, 2)));
+    }
+
+    #[test]
+    fn rejects_malformed_varints() {
+        assert_eq!(decode_varint(&[]), Err(VarintError::Truncated));
+        assert_eq!(decode_varint(&[0x80, 0x80]), Err(VarintError::Truncated));
+        // The tenth byte may only carry the top bit of a u64
+        let mut too_wide = vec![0xFF; 9];
+        too_wide.push(0x02);
+        assert_eq!(decode_varint(&too_wide), Err(VarintError::Overflow));
+        assert_eq!(decode_varint(&[0x80; 11]), Err(VarintError::Overflow));
+    }
+
+    #[test]
+    fn zigzag_round_trips() {
+        assert_eq!([0, -1, 1, -2, 2].map(zigzag_encode), [0, 1, 2, 3, 4]);
+        assert_eq!(zigzag_encode(i64::MAX), u64::MAX - 1);
+        assert_eq!(zigzag_encode(i64::MIN), u64::MAX);
+        let values = [
+            0,
+            1,
+            -1,
+            63,
+            -64,
+            64,
+            i64::MAX,
+            i64::MIN,
+            1 << 40,
+            -(1 << 40),
+        ];
+        for value in values {
+            assert_eq!(zigzag_decode(zigzag_encode(value)), value);
+        }
+        let bytes = encode_varints(&values);
+        assert_eq!(decode_varints(&bytes).unwrap(), values);
+        assert_eq!(encode_varints(&[0, -1, 1, -64]).len(), 4);
+    }
+}
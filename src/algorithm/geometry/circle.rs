@@ -0,0 +1,153 @@
+use super::{Coordinate, Line, Location, Point2D};
+
+/// A circle, or the closed disk it bounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Circle<T = f64> {
+    pub center: Point2D<T>,
+    pub radius: T,
+}
+
+impl<T: Coordinate> Circle<T> {
+    /// Creates the circle. Panics if the radius is negative
+    pub fn new(center: Point2D<T>, radius: T) -> Circle<T> {
+        assert!(radius >= T::ZERO, "radius must not be negative");
+        Circle { center, radius }
+    }
+
+    /// Locates `p` relative to the disk, comparing squared distances so
+    /// integer circles are exact
+    pub fn locate(&self, p: Point2D<T>) -> Location {
+        let gap = self.center.distance_squared(p) - self.radius * self.radius;
+        let magnitude = self.radius.to_f64().powi(2);
+        if T::is_negligible(gap, magnitude) {
+            Location::Boundary
+        } else if gap < T::ZERO {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius.to_f64().powi(2)
+    }
+
+    pub fn circumference(&self) -> f64 {
+        std::f64::consts::TAU * self.radius.to_f64()
+    }
+}
+
+impl Circle {
+    /// Points where `line` meets the circle: none, one if it is tangent, or two
+    /// ordered along the line's direction
+    pub fn intersect_line(&self, line: &Line) -> Vec<Point2D> {
+        let foot = line.project(self.center);
+        let gap = foot.distance(self.center) - self.radius;
+        if f64::is_negligible(gap, 1.0 + self.radius) {
+            return vec![foot];
+        }
+        if gap > 0.0 {
+            return Vec::new();
+        }
+        let half_chord = (self.radius.powi(2) - self.center.distance_squared(foot)).sqrt();
+        let offset = line.direction().normalize() * half_chord;
+        vec![foot - offset, foot + offset]
+    }
+
+    /// Points where the circles meet: none, one if they are tangent, or two.
+    /// Identical circles share every point and return none
+    pub fn intersect_circle(&self, other: &Circle) -> Vec<Point2D> {
+        let between = other.center - self.center;
+        let d = between.length();
+        let magnitude = 1.0 + self.radius.max(other.radius);
+        if f64::is_negligible(d, magnitude) {
+            return Vec::new();
+        }
+        let outer_gap = d - (self.radius + other.radius);
+        let inner_gap = (self.radius - other.radius).abs() - d;
+        if outer_gap > f64::tolerance(magnitude) || inner_gap > f64::tolerance(magnitude) {
+            return Vec::new();
+        }
+        // Distance from this center to the chord joining the crossings
+        let along = (d * d + self.radius.powi(2) - other.radius.powi(2)) / (2.0 * d);
+        let unit = between * (1.0 / d);
+        let base = self.center + unit * along;
+        let half_chord_squared = self.radius.powi(2) - along * along;
+        if f64::is_negligible(outer_gap, magnitude)
+            || f64::is_negligible(inner_gap, magnitude)
+            || half_chord_squared <= 0.0
+        {
+            return vec![base];
+        }
+        let offset = unit.perpendicular() * half_chord_squared.sqrt();
+        vec![base - offset, base + offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Circle;
+    use crate::algorithm::geometry::{Line, Location, Point2D};
+
+    fn close(a: Point2D, b: Point2D) -> bool {
+        a.distance(b) < 1e-9
+    }
+
+    #[test]
+    fn locates_points() {
+        let circle = Circle::new(Point2D::new(0i64, 0), 5);
+        assert_eq!(circle.locate(Point2D::new(3, 4)), Location::Boundary);
+        assert_eq!(circle.locate(Point2D::new(3, 3)), Location::Inside);
+        assert_eq!(circle.locate(Point2D::new(4, 4)), Location::Outside);
+        let circle = Circle::new(Point2D::new(0.1, 0.2), 0.5);
+        assert_eq!(circle.locate(Point2D::new(0.4, 0.6)), Location::Boundary);
+        assert!(
+            (Circle::new(Point2D::new(0.0, 0.0), 2.0).area() - 4.0 * std::f64::consts::PI).abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn line_intersections() {
+        let circle = Circle::new(Point2D::new(1.0, 1.0), 5.0);
+        let through = Line::new(Point2D::new(-10.0, 1.0), Point2D::new(10.0, 1.0));
+        let points = circle.intersect_line(&through);
+        assert!(
+            close(points[0], Point2D::new(-4.0, 1.0)) && close(points[1], Point2D::new(6.0, 1.0))
+        );
+        let tangent = Line::new(Point2D::new(6.0, 0.0), Point2D::new(6.0, 3.0));
+        assert_eq!(
+            circle.intersect_line(&tangent),
+            vec![Point2D::new(6.0, 1.0)]
+        );
+        let missing = Line::new(Point2D::new(7.0, 0.0), Point2D::new(7.0, 3.0));
+        assert!(circle.intersect_line(&missing).is_empty());
+    }
+
+    #[test]
+    fn circle_intersections() {
+        let a = Circle::new(Point2D::new(0.0, 0.0), 5.0);
+        let b = Circle::new(Point2D::new(8.0, 0.0), 5.0);
+        let points = a.intersect_circle(&b);
+        assert!(
+            close(points[0], Point2D::new(4.0, -3.0)) && close(points[1], Point2D::new(4.0, 3.0))
+        );
+        let touching = Circle::new(Point2D::new(10.0, 0.0), 5.0);
+        let points = a.intersect_circle(&touching);
+        assert_eq!(points.len(), 1);
+        assert!(close(points[0], Point2D::new(5.0, 0.0)));
+        let inside_touching = Circle::new(Point2D::new(2.0, 0.0), 3.0);
+        let points = a.intersect_circle(&inside_touching);
+        assert_eq!(points.len(), 1);
+        assert!(close(points[0], Point2D::new(5.0, 0.0)));
+        assert!(
+            a.intersect_circle(&Circle::new(Point2D::new(20.0, 0.0), 5.0))
+                .is_empty()
+        );
+        assert!(
+            a.intersect_circle(&Circle::new(Point2D::new(1.0, 0.0), 1.0))
+                .is_empty()
+        );
+        assert!(a.intersect_circle(&a).is_empty());
+    }
+}
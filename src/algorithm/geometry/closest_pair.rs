@@ -0,0 +1,145 @@
+use super::Point2D;
+
+/// Finds the two closest points, returning their indices (smaller first) and
+/// distance, or None for fewer than two points.
+///
+/// Divide and conquer in O(n log n): split at the median x, solve both halves,
+/// then check the vertical strip of width 2d around the split, where d is the
+/// best distance so far. Sorted by y, each strip point only needs comparing
+/// with the following points less than d above it, of which there are at
+/// most a constant number. The halves are merged by y as the recursion unwinds,
+/// so the strip never needs sorting.
+pub fn closest_pair(points: &[Point2D]) -> Option<(usize, usize, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut by_x: Vec<usize> = (0..points.len()).collect();
+    by_x.sort_by(|&i, &j| {
+        points[i]
+            .x
+            .total_cmp(&points[j].x)
+            .then(points[i].y.total_cmp(&points[j].y))
+    });
+    let mut best = (by_x[0], by_x[1], points[by_x[0]].distance(points[by_x[1]]));
+    let mut scratch = Vec::with_capacity(points.len());
+    solve(points, &mut by_x, &mut scratch, &mut best);
+    let (i, j, distance) = best;
+    Some((i.min(j), i.max(j), distance))
+}
+
+/// Updates `best` with the closest pair within `indices` (sorted by x on entry),
+/// leaving `indices` sorted by y
+fn solve(
+    points: &[Point2D],
+    indices: &mut [usize],
+    scratch: &mut Vec<usize>,
+    best: &mut (usize, usize, f64),
+) {
+    let consider = |i: usize, j: usize, best: &mut (usize, usize, f64)| {
+        let distance = points[i].distance(points[j]);
+        if distance < best.2 {
+            *best = (i, j, distance);
+        }
+    };
+    if indices.len() <= 3 {
+        for a in 0..indices.len() {
+            for b in a + 1..indices.len() {
+                consider(indices[a], indices[b], best);
+            }
+        }
+        indices.sort_by(|&i, &j| points[i].y.total_cmp(&points[j].y));
+        return;
+    }
+    let mid = indices.len() / 2;
+    let split_x = points[indices[mid]].x;
+    let (left, right) = indices.split_at_mut(mid);
+    solve(points, left, scratch, best);
+    solve(points, right, scratch, best);
+
+    // Merge the halves by y
+    scratch.clear();
+    let (mut a, mut b) = (0, mid);
+    while a < mid || b < indices.len() {
+        let take_left =
+            b == indices.len() || (a < mid && points[indices[a]].y <= points[indices[b]].y);
+        if take_left {
+            scratch.push(indices[a]);
+            a += 1;
+        } else {
+            scratch.push(indices[b]);
+            b += 1;
+        }
+    }
+    indices.copy_from_slice(scratch);
+
+    scratch.clear();
+    scratch.extend(
+        indices
+            .iter()
+            .copied()
+            .filter(|&i| (points[i].x - split_x).abs() < best.2),
+    );
+    for (k, &i) in scratch.iter().enumerate() {
+        for &j in &scratch[k + 1..] {
+            if points[j].y - points[i].y >= best.2 {
+                break;
+            }
+            consider(i, j, best);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::closest_pair;
+    use crate::algorithm::geometry::Point2D;
+    use proptest::prelude::*;
+
+    fn brute_force(points: &[Point2D]) -> f64 {
+        let mut best = f64::INFINITY;
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                best = best.min(points[i].distance(points[j]));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn small_inputs() {
+        assert_eq!(closest_pair(&[]), None);
+        assert_eq!(closest_pair(&[Point2D::new(1.0, 1.0)]), None);
+        let points = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(10.0, 10.0),
+            Point2D::new(3.0, 4.0),
+            Point2D::new(10.5, 10.0),
+        ];
+        assert_eq!(closest_pair(&points), Some((1, 3, 0.5)));
+    }
+
+    #[test]
+    fn duplicates_and_collinear_points() {
+        let points: Vec<Point2D> = (0..100)
+            .map(|i| Point2D::new(0.0, 3.0 * i as f64))
+            .collect();
+        assert_eq!(closest_pair(&points).map(|(_, _, d)| d), Some(3.0));
+        let mut points: Vec<Point2D> = (0..50).map(|i| Point2D::new(i as f64, 0.0)).collect();
+        points.push(Point2D::new(17.0, 0.0));
+        assert_eq!(closest_pair(&points), Some((17, 50, 0.0)));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(coords in prop::collection::vec((-1000i32..1000, -1000i32..1000), 2..200)) {
+            let points: Vec<Point2D> = coords
+                .iter()
+                .map(|&(x, y)| Point2D::new(f64::from(x), f64::from(y)))
+                .collect();
+            let (i, j, distance) = closest_pair(&points).unwrap();
+            prop_assert!(i < j);
+            prop_assert_eq!(points[i].distance(points[j]), distance);
+            prop_assert_eq!(distance, brute_force(&points));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use super::{Coordinate, Orientation, Point2D, orientation};
+
+/// The convex hull by Andrew's monotone chain in O(n log n): its vertices in
+/// counter-clockwise order starting from the lowest-leftmost point, without
+/// repeated or collinear points.
+///
+/// Collinear inputs give just the two extreme points, and a single distinct
+/// point gives itself. Integer coordinates make the hull exact. Points with a
+/// NaN or infinite coordinate have no place on a hull and are skipped.
+pub fn convex_hull<T: Coordinate>(points: &[Point2D<T>]) -> Vec<Point2D<T>> {
+    let mut sorted: Vec<_> = points
+        .iter()
+        .copied()
+        .filter(|p| p.x.to_f64().is_finite() && p.y.to_f64().is_finite())
+        .collect();
+    sorted.sort_by(|p, q| {
+        p.y.partial_cmp(&q.y)
+            .expect("finite coordinates are ordered")
+            .then(
+                p.x.partial_cmp(&q.x)
+                    .expect("finite coordinates are ordered"),
+            )
+    });
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    // The right chain going up, then the left chain coming back down
+    let mut hull = Vec::with_capacity(sorted.len() + 1);
+    for &p in &sorted {
+        extend_chain(&mut hull, 0, p);
+    }
+    let floor = hull.len() - 1;
+    for &p in sorted.iter().rev().skip(1) {
+        extend_chain(&mut hull, floor, p);
+    }
+    // The last point pushed is the start again
+    hull.pop();
+    hull
+}
+
+/// Pushes `p`, first popping points above `floor` that would not turn left
+fn extend_chain<T: Coordinate>(hull: &mut Vec<Point2D<T>>, floor: usize, p: Point2D<T>) {
+    while hull.len() >= floor + 2
+        && orientation(hull[hull.len() - 2], hull[hull.len() - 1], p)
+            != Orientation::CounterClockwise
+    {
+        hull.pop();
+    }
+    hull.push(p);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convex_hull;
+    use crate::algorithm::geometry::{Orientation, Point2D, orientation};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn points(coords: &[(i64, i64)]) -> Vec<Point2D<i64>> {
+        coords.iter().map(|&(x, y)| Point2D::new(x, y)).collect()
+    }
+
+    #[test]
+    fn hull_of_a_square_with_interior_and_edge_points() {
+        let input = points(&[
+            (2, 2),
+            (0, 0),
+            (4, 0),
+            (1, 3),
+            (4, 4),
+            (0, 4),
+            (2, 0),
+            (4, 2),
+            (0, 4),
+        ]);
+        assert_eq!(
+            convex_hull(&input),
+            points(&[(0, 0), (4, 0), (4, 4), (0, 4)])
+        );
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        assert!(convex_hull::<i64>(&[]).is_empty());
+        assert_eq!(convex_hull(&points(&[(1, 1), (1, 1)])), points(&[(1, 1)]));
+        assert_eq!(
+            convex_hull(&points(&[(2, 2), (0, 0), (3, 3), (1, 1)])),
+            points(&[(0, 0), (3, 3)])
+        );
+    }
+
+    #[test]
+    fn non_finite_points_are_skipped() {
+        let input = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(f64::NAN, 1.0),
+            Point2D::new(2.0, 0.0),
+            Point2D::new(f64::INFINITY, f64::NEG_INFINITY),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(0.5, f64::NAN),
+        ];
+        assert_eq!(
+            convex_hull(&input),
+            [
+                Point2D::new(0.0, 0.0),
+                Point2D::new(2.0, 0.0),
+                Point2D::new(1.0, 2.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn hull_is_convex_and_contains_every_point() {
+        let mut rng = XorShift64Star::seed_from_u64(0x5EED);
+        let mut next = || rng.gen_range(0..201) as i64 - 100;
+        let input: Vec<Point2D<i64>> = (0..500).map(|_| Point2D::new(next(), next())).collect();
+        let hull = convex_hull(&input);
+        let n = hull.len();
+        for i in 0..n {
+            let (a, b) = (hull[i], hull[(i + 1) % n]);
+            assert_eq!(
+                orientation(a, b, hull[(i + 2) % n]),
+                Orientation::CounterClockwise
+            );
+            assert!(
+                input
+                    .iter()
+                    .all(|&p| orientation(a, b, p) != Orientation::Clockwise)
+            );
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Relative tolerance of the floating-point predicates: values this small
+/// compared to the magnitudes involved are treated as zero
+pub const EPSILON: f64 = 1e-9;
+
+/// A number usable as a coordinate.
+///
+/// Integer coordinates make every predicate exact, as long as products of
+/// coordinate differences fit (for `i64`, keep coordinates below 2^30 in
+/// magnitude). Floating-point ones compare against a tolerance instead.
+pub trait Coordinate:
+    Copy
+    + PartialOrd
+    + Debug
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+
+    fn to_f64(self) -> f64;
+
+    /// How far from zero a quantity of the given magnitude may stray and
+    /// still count as zero: `EPSILON * magnitude` for floats, 0 for integers
+    fn tolerance(magnitude: f64) -> Self;
+
+    /// Checks if `value` is zero within the tolerance for `magnitude`
+    fn is_negligible(value: Self, magnitude: f64) -> bool {
+        let tolerance = Self::tolerance(magnitude);
+        value <= tolerance && -value <= tolerance
+    }
+}
+
+impl Coordinate for f64 {
+    const ZERO: f64 = 0.0;
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn tolerance(magnitude: f64) -> f64 {
+        EPSILON * magnitude
+    }
+}
+
+impl Coordinate for i64 {
+    const ZERO: i64 = 0;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn tolerance(_: f64) -> i64 {
+        0
+    }
+}
@@ -0,0 +1,98 @@
+use super::{Coordinate, Orientation, Point2D, Segment, Vector2D, orientation};
+
+/// The infinite line through two distinct points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line<T = f64> {
+    pub a: Point2D<T>,
+    pub b: Point2D<T>,
+}
+
+impl<T: Coordinate> Line<T> {
+    /// Creates the line through `a` and `b`. Panics if they coincide
+    pub fn new(a: Point2D<T>, b: Point2D<T>) -> Line<T> {
+        assert!(a != b, "a line needs two distinct points");
+        Line { a, b }
+    }
+
+    /// The line extending `segment`. Panics if the segment is a single point
+    pub fn through(segment: &Segment<T>) -> Line<T> {
+        Line::new(segment.a, segment.b)
+    }
+
+    /// Direction from `a` to `b`
+    pub fn direction(&self) -> Vector2D<T> {
+        self.b - self.a
+    }
+
+    /// Which side of the line, looking from `a` to `b`, `p` lies on:
+    /// counter-clockwise is the left
+    pub fn side(&self, p: Point2D<T>) -> Orientation {
+        orientation(self.a, self.b, p)
+    }
+
+    pub fn contains(&self, p: Point2D<T>) -> bool {
+        self.side(p) == Orientation::Collinear
+    }
+
+    pub fn is_parallel(&self, other: &Line<T>) -> bool {
+        let (d, e) = (self.direction(), other.direction());
+        T::is_negligible(d.cross(e), d.length() * e.length())
+    }
+}
+
+impl Line {
+    /// The foot of the perpendicular from `p`
+    pub fn project(&self, p: Point2D) -> Point2D {
+        self.a + (p - self.a).project_onto(self.direction())
+    }
+
+    /// The mirror image of `p` across the line
+    pub fn reflect(&self, p: Point2D) -> Point2D {
+        let foot = self.project(p);
+        foot + (foot - p)
+    }
+
+    pub fn distance_to(&self, p: Point2D) -> f64 {
+        let direction = self.direction();
+        direction.cross(p - self.a).abs() / direction.length()
+    }
+
+    /// The point where the lines cross, or None if they are parallel
+    pub fn intersection(&self, other: &Line) -> Option<Point2D> {
+        if self.is_parallel(other) {
+            return None;
+        }
+        let (d, e) = (self.direction(), other.direction());
+        let t = (other.a - self.a).cross(e) / d.cross(e);
+        Some(self.a + d * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Line;
+    use crate::algorithm::geometry::{Orientation, Point2D};
+
+    #[test]
+    fn exact_integer_predicates() {
+        let line = Line::new(Point2D::new(0i64, 0), Point2D::new(3, 1));
+        assert_eq!(line.side(Point2D::new(1, 1)), Orientation::CounterClockwise);
+        assert_eq!(line.side(Point2D::new(1, 0)), Orientation::Clockwise);
+        assert!(line.contains(Point2D::new(-300_000_000, -100_000_000)));
+        assert!(!line.contains(Point2D::new(300_000_001, 100_000_000)));
+        assert!(line.is_parallel(&Line::new(Point2D::new(1, 5), Point2D::new(7, 7))));
+        assert!(!line.is_parallel(&Line::new(Point2D::new(1, 5), Point2D::new(7, 8))));
+    }
+
+    #[test]
+    fn projections_and_intersections() {
+        let line = Line::new(Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0));
+        assert_eq!(line.project(Point2D::new(0.0, 2.0)), Point2D::new(1.0, 1.0));
+        assert_eq!(line.reflect(Point2D::new(0.0, 2.0)), Point2D::new(2.0, 0.0));
+        assert!((line.distance_to(Point2D::new(0.0, 2.0)) - 2f64.sqrt()).abs() < 1e-12);
+        let other = Line::new(Point2D::new(0.0, 4.0), Point2D::new(4.0, 0.0));
+        assert_eq!(line.intersection(&other), Some(Point2D::new(2.0, 2.0)));
+        let parallel = Line::new(Point2D::new(0.0, 1.0), Point2D::new(1.0, 2.0));
+        assert_eq!(line.intersection(&parallel), None);
+    }
+}
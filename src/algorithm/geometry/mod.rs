@@ -0,0 +1,25 @@
+mod circle;
+mod closest_pair;
+mod convex_hull;
+mod coordinate;
+mod line;
+mod point;
+mod polygon;
+mod rotating_calipers;
+mod segment;
+mod sweep;
+mod vector;
+
+pub use self::circle::Circle;
+pub use self::closest_pair::closest_pair;
+pub use self::convex_hull::convex_hull;
+pub use self::coordinate::{Coordinate, EPSILON};
+pub use self::line::Line;
+pub use self::point::Point2D;
+pub use self::polygon::{Location, Polygon};
+pub use self::rotating_calipers::{
+    Rectangle, farthest_pair, minimum_area_rectangle, minimum_width,
+};
+pub use self::segment::{Orientation, Segment, SegmentIntersection, orientation};
+pub use self::sweep::{Crossing, sweep_intersections};
+pub use self::vector::Vector2D;
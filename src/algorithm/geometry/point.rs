@@ -0,0 +1,63 @@
+use std::ops::{Add, Sub};
+
+use super::{Coordinate, Vector2D};
+
+/// A point in the plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point2D<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2D<T> {
+    /// Creates the point (x, y)
+    pub fn new(x: T, y: T) -> Point2D<T> {
+        Point2D { x, y }
+    }
+
+    /// Returns the squared Euclidean distance to `other`, exact for integers
+    pub fn distance_squared(self, other: Point2D<T>) -> T {
+        (other - self).length_squared()
+    }
+
+    /// Returns the Euclidean distance to `other`
+    pub fn distance(self, other: Point2D<T>) -> f64 {
+        (other - self).length()
+    }
+
+    /// Converts the coordinates to `f64`
+    pub fn to_f64(self) -> Point2D {
+        Point2D::new(self.x.to_f64(), self.y.to_f64())
+    }
+}
+
+impl Point2D {
+    /// Rotates the point counter-clockwise by `angle` radians around `center`
+    pub fn rotate_around(self, center: Point2D, angle: f64) -> Point2D {
+        center + (self - center).rotate(angle)
+    }
+}
+
+impl<T: Coordinate> Sub for Point2D<T> {
+    type Output = Vector2D<T>;
+
+    fn sub(self, rhs: Point2D<T>) -> Vector2D<T> {
+        Vector2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Add<Vector2D<T>> for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn add(self, rhs: Vector2D<T>) -> Point2D<T> {
+        Point2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub<Vector2D<T>> for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn sub(self, rhs: Vector2D<T>) -> Point2D<T> {
+        Point2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
@@ -0,0 +1,273 @@
+use super::{Orientation, Point2D, Segment, orientation};
+
+/// Where a point lies relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Outside,
+    /// On an edge or a vertex
+    Boundary,
+}
+
+/// A polygon given by its vertices in order, closed by the edge from the last
+/// vertex back to the first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point2D>,
+}
+
+impl Polygon {
+    /// Creates the polygon. Panics if it has fewer than 3 vertices
+    pub fn new(vertices: Vec<Point2D>) -> Polygon {
+        assert!(vertices.len() >= 3, "a polygon needs at least 3 vertices");
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point2D] {
+        &self.vertices
+    }
+
+    /// Iterates over the edges, ending with the closing one
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| Segment::new(self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Area by the shoelace formula: positive for counter-clockwise vertices,
+    /// negative for clockwise ones
+    pub fn signed_area(&self) -> f64 {
+        self.edges()
+            .map(|e| e.a.x * e.b.y - e.b.x * e.a.y)
+            .sum::<f64>()
+            / 2.0
+    }
+
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// Centroid of the enclosed region, or None if the area is zero
+    pub fn centroid(&self) -> Option<Point2D> {
+        let area = self.signed_area();
+        if area == 0.0 {
+            return None;
+        }
+        // Shift to the first vertex to keep the products small
+        let origin = self.vertices[0];
+        let (mut cx, mut cy) = (0.0, 0.0);
+        for e in self.edges() {
+            let (ax, ay) = (e.a.x - origin.x, e.a.y - origin.y);
+            let (bx, by) = (e.b.x - origin.x, e.b.y - origin.y);
+            let cross = ax * by - bx * ay;
+            cx += (ax + bx) * cross;
+            cy += (ay + by) * cross;
+        }
+        Some(Point2D::new(
+            origin.x + cx / (6.0 * area),
+            origin.y + cy / (6.0 * area),
+        ))
+    }
+
+    /// Checks if the polygon is convex: every turn goes the same way and the
+    /// boundary winds around exactly once. Collinear vertices are allowed
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut turn = None;
+        let mut total_angle = 0.0;
+        for i in 0..n {
+            let (a, b, c) = (
+                self.vertices[i],
+                self.vertices[(i + 1) % n],
+                self.vertices[(i + 2) % n],
+            );
+            match orientation(a, b, c) {
+                Orientation::Collinear => {}
+                o if *turn.get_or_insert(o) != o => return false,
+                _ => {}
+            }
+            let incoming = (b.y - a.y).atan2(b.x - a.x);
+            let outgoing = (c.y - b.y).atan2(c.x - b.x);
+            let mut angle = outgoing - incoming;
+            if angle > std::f64::consts::PI {
+                angle -= 2.0 * std::f64::consts::PI;
+            } else if angle < -std::f64::consts::PI {
+                angle += 2.0 * std::f64::consts::PI;
+            }
+            total_angle += angle;
+        }
+        // A star polygon turns consistently but winds around more than once
+        turn.is_some() && (total_angle.abs() - 2.0 * std::f64::consts::PI).abs() < 1e-6
+    }
+
+    fn on_boundary(&self, p: Point2D) -> bool {
+        self.edges().any(|e| e.contains(p))
+    }
+
+    /// Locates `p` by casting a ray to the right and counting edge crossings
+    /// (the even-odd rule).
+    ///
+    /// Points on an edge or vertex are reported as Boundary before counting.
+    /// Each edge is treated as half-open in y, so a ray through a vertex
+    /// counts it once, or not at all where the boundary only touches the ray.
+    pub fn locate_ray_casting(&self, p: Point2D) -> Location {
+        if self.on_boundary(p) {
+            return Location::Boundary;
+        }
+        let mut inside = false;
+        for e in self.edges() {
+            if (e.a.y > p.y) != (e.b.y > p.y) {
+                let x = e.a.x + (p.y - e.a.y) / (e.b.y - e.a.y) * (e.b.x - e.a.x);
+                if p.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// How many times the boundary winds counter-clockwise around `p`, which
+    /// must not lie on the boundary
+    pub fn winding_number(&self, p: Point2D) -> i32 {
+        let mut winding = 0;
+        for e in self.edges() {
+            let side = orientation(e.a, e.b, p);
+            if e.a.y <= p.y {
+                if e.b.y > p.y && side == Orientation::CounterClockwise {
+                    winding += 1;
+                }
+            } else if e.b.y <= p.y && side == Orientation::Clockwise {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    /// Locates `p` by its winding number (the nonzero rule). This agrees with
+    /// ray casting for simple polygons, but counts doubly-wound regions of a
+    /// self-intersecting polygon as inside
+    pub fn locate_winding(&self, p: Point2D) -> Location {
+        if self.on_boundary(p) {
+            Location::Boundary
+        } else if self.winding_number(p) != 0 {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Location, Polygon};
+    use crate::algorithm::geometry::Point2D;
+
+    fn polygon(points: &[(f64, f64)]) -> Polygon {
+        Polygon::new(points.iter().map(|&(x, y)| Point2D::new(x, y)).collect())
+    }
+
+    #[test]
+    fn area_and_centroid() {
+        let square = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        assert_eq!(square.signed_area(), 16.0);
+        assert_eq!(square.centroid(), Some(Point2D::new(2.0, 2.0)));
+        let clockwise = polygon(&[(0.0, 0.0), (0.0, 3.0), (6.0, 0.0)]);
+        assert_eq!(clockwise.signed_area(), -9.0);
+        assert_eq!(clockwise.area(), 9.0);
+        assert_eq!(clockwise.centroid(), Some(Point2D::new(2.0, 1.0)));
+        // An L shape: two unit-width rectangles
+        let l_shape = polygon(&[
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 3.0),
+            (0.0, 3.0),
+        ]);
+        assert_eq!(l_shape.area(), 4.0);
+        let c = l_shape.centroid().unwrap();
+        assert!((c.x - 0.75).abs() < 1e-12 && (c.y - 1.25).abs() < 1e-12);
+        assert_eq!(
+            polygon(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]).centroid(),
+            None
+        );
+    }
+
+    #[test]
+    fn convexity() {
+        assert!(polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]).is_convex());
+        assert!(polygon(&[(0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)]).is_convex());
+        assert!(polygon(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0), (4.0, 4.0)]).is_convex());
+        assert!(
+            !polygon(&[
+                (0.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 1.0),
+                (1.0, 3.0),
+                (0.0, 3.0)
+            ])
+            .is_convex()
+        );
+        let star: Vec<(f64, f64)> = (0..5)
+            .map(|k| {
+                let angle = k as f64 * 4.0 * std::f64::consts::PI / 5.0;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        assert!(!polygon(&star).is_convex());
+        assert!(!polygon(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]).is_convex());
+    }
+
+    #[test]
+    fn point_location_with_boundary_cases() {
+        // A concave "M" whose peaks put vertices level with the test points
+        let m = polygon(&[
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 4.0),
+            (3.0, 2.0),
+            (2.0, 4.0),
+            (1.0, 2.0),
+            (0.0, 4.0),
+        ]);
+        let cases = [
+            ((2.0, 1.0), Location::Inside),
+            ((2.0, 3.0), Location::Inside),
+            ((1.0, 3.0), Location::Outside),
+            ((5.0, 2.0), Location::Outside),
+            ((-1.0, 2.0), Location::Outside),
+            ((-1.0, 4.0), Location::Outside),
+            ((0.5, 2.0), Location::Inside),
+            ((1.5, 2.0), Location::Inside),
+            ((2.0, 0.0), Location::Boundary),
+            ((4.0, 4.0), Location::Boundary),
+            ((3.0, 2.0), Location::Boundary),
+            ((0.0, 1.0), Location::Boundary),
+            ((2.5, 3.0), Location::Boundary),
+        ];
+        for ((x, y), expected) in cases {
+            let p = Point2D::new(x, y);
+            assert_eq!(m.locate_ray_casting(p), expected, "({x}, {y})");
+            assert_eq!(m.locate_winding(p), expected, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn self_intersecting_polygon_rules_differ() {
+        let star: Vec<(f64, f64)> = (0..5)
+            .map(|k| {
+                let angle = k as f64 * 4.0 * std::f64::consts::PI / 5.0;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        let star = polygon(&star);
+        let center = Point2D::new(0.0, 0.0);
+        assert_eq!(star.winding_number(center), 2);
+        assert_eq!(star.locate_winding(center), Location::Inside);
+        assert_eq!(star.locate_ray_casting(center), Location::Outside);
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Coordinate, Point2D, Vector2D, convex_hull};
+
+/// The two input points farthest apart (the diameter of the set), or None if
+/// there are no points.
+///
+/// The farthest pair is always an antipodal pair of hull vertices: ones
+/// touched by two parallel supporting lines. Rotating a pair of such
+/// "calipers" around the hull visits every antipodal pair in O(h) after the
+/// O(n log n) hull, and squared distances keep integer inputs exact.
+pub fn farthest_pair<T: Coordinate>(points: &[Point2D<T>]) -> Option<(Point2D<T>, Point2D<T>)> {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    match n {
+        0 => return None,
+        1 => return Some((hull[0], hull[0])),
+        2 => return Some((hull[0], hull[1])),
+        _ => {}
+    }
+    let mut best = (hull[0], hull[1]);
+    let mut best_distance = hull[0].distance_squared(hull[1]);
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (hull[i], hull[(i + 1) % n]);
+        let edge = b - a;
+        // Advance to the vertex farthest from the edge's line
+        while edge.cross(hull[(j + 1) % n] - a) > edge.cross(hull[j] - a) {
+            j = (j + 1) % n;
+        }
+        for p in [a, b] {
+            let distance = p.distance_squared(hull[j]);
+            if distance > best_distance {
+                best = (p, hull[j]);
+                best_distance = distance;
+            }
+        }
+    }
+    Some(best)
+}
+
+/// The minimum width of the point set: the smallest distance between two
+/// parallel lines enclosing it, with the unit normal of those lines. None if
+/// the points do not span a triangle, as the set then has no width.
+///
+/// An optimal pair of lines always has one flush with a hull edge, so the
+/// calipers try each edge against its farthest vertex.
+pub fn minimum_width(points: &[Point2D]) -> Option<(f64, Vector2D)> {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    if n < 3 {
+        return None;
+    }
+    let mut best: Option<(f64, Vector2D)> = None;
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (hull[i], hull[(i + 1) % n]);
+        let edge = (b - a).normalize();
+        while edge.cross(hull[(j + 1) % n] - a) > edge.cross(hull[j] - a) {
+            j = (j + 1) % n;
+        }
+        let width = edge.cross(hull[j] - a);
+        if best.is_none_or(|(w, _)| width < w) {
+            best = Some((width, edge.perpendicular()));
+        }
+    }
+    best
+}
+
+/// A rectangle, possibly rotated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    /// Corners in counter-clockwise order
+    pub corners: [Point2D; 4],
+    pub area: f64,
+}
+
+/// The minimum-area rectangle enclosing the points, or None if they do not
+/// span a triangle.
+///
+/// Some optimal rectangle has a side flush with a hull edge. For each edge,
+/// the calipers track the vertices extreme in the three other directions,
+/// each only ever moving forward, for O(h) overall after the hull.
+pub fn minimum_area_rectangle(points: &[Point2D]) -> Option<Rectangle> {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    if n < 3 {
+        return None;
+    }
+    let next = |k: usize| (k + 1) % n;
+    let mut best: Option<Rectangle> = None;
+    // Farthest along the edge, farthest from it, and farthest back along it
+    let (mut right, mut top, mut left) = (1, 1, 1);
+    for i in 0..n {
+        let a = hull[i];
+        let u = (hull[next(i)] - a).normalize();
+        let v = u.perpendicular();
+        while u.dot(hull[next(right)] - a) > u.dot(hull[right] - a) {
+            right = next(right);
+        }
+        if i == 0 {
+            top = right;
+        }
+        while v.dot(hull[next(top)] - a) > v.dot(hull[top] - a) {
+            top = next(top);
+        }
+        if i == 0 {
+            left = top;
+        }
+        while u.dot(hull[next(left)] - a) < u.dot(hull[left] - a) {
+            left = next(left);
+        }
+        let (low, high) = (u.dot(hull[left] - a), u.dot(hull[right] - a));
+        let height = v.dot(hull[top] - a);
+        let area = (high - low) * height;
+        if best.is_none_or(|r| area < r.area) {
+            best = Some(Rectangle {
+                corners: [
+                    a + u * low,
+                    a + u * high,
+                    a + u * high + v * height,
+                    a + u * low + v * height,
+                ],
+                area,
+            });
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{farthest_pair, minimum_area_rectangle, minimum_width};
+    use crate::algorithm::geometry::{Point2D, Vector2D};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn random_points(seed: u64, n: usize) -> Vec<Point2D> {
+        let mut rng = XorShift64Star::seed_from_u64(seed);
+        let mut next = || rng.gen_range(0..2001) as f64 / 10.0 - 100.0;
+        (0..n).map(|_| Point2D::new(next(), next())).collect()
+    }
+
+    /// Extent of the points along and across the direction from `a` to `b`
+    fn extents(points: &[Point2D], a: Point2D, b: Point2D) -> (f64, f64) {
+        let u = (b - a).normalize();
+        let range = |f: &dyn Fn(Vector2D) -> f64| {
+            let values = points.iter().map(|&p| f(p - a));
+            let max = values.clone().fold(f64::NEG_INFINITY, f64::max);
+            max - values.fold(f64::INFINITY, f64::min)
+        };
+        (range(&|w| u.dot(w)), range(&|w| u.cross(w)))
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_points() {
+        for (seed, n) in [(1u64, 3), (2, 10), (3, 50), (4, 200), (5, 400)] {
+            let points = random_points(seed * 0x9E37_79B9, n);
+            let (mut diameter, mut width, mut area) = (0.0f64, f64::INFINITY, f64::INFINITY);
+            for (i, &a) in points.iter().enumerate() {
+                for &b in &points[i + 1..] {
+                    diameter = diameter.max(a.distance(b));
+                    if a != b {
+                        let (along, across) = extents(&points, a, b);
+                        width = width.min(across);
+                        area = area.min(along * across);
+                    }
+                }
+            }
+            let (p, q) = farthest_pair(&points).unwrap();
+            assert_eq!(p.distance(q), diameter, "{n} points");
+            let (w, normal) = minimum_width(&points).unwrap();
+            assert!((w - width).abs() < 1e-9, "{n} points: {w} vs {width}");
+            let (_, across) = extents(
+                &points,
+                Point2D::default(),
+                Point2D::default() + normal.perpendicular(),
+            );
+            assert!((across - w).abs() < 1e-9);
+            let rectangle = minimum_area_rectangle(&points).unwrap();
+            assert!((rectangle.area - area).abs() < 1e-6 * area, "{n} points");
+        }
+    }
+
+    #[test]
+    fn known_shapes() {
+        let square: Vec<Point2D<i64>> = [(0, 0), (3, 0), (3, 3), (0, 3), (1, 2)]
+            .iter()
+            .map(|&(x, y)| Point2D::new(x, y))
+            .collect();
+        let (p, q) = farthest_pair(&square).unwrap();
+        assert_eq!(p.distance_squared(q), 18);
+        assert_eq!(farthest_pair::<i64>(&[]), None);
+        assert_eq!(
+            farthest_pair(&[Point2D::new(1i64, 1)]),
+            Some((Point2D::new(1, 1), Point2D::new(1, 1)))
+        );
+
+        // A diamond: the tightest rectangle is the rotated square itself
+        let diamond = [
+            Point2D::new(0.0, -1.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(0.0, 1.0),
+            Point2D::new(-1.0, 0.0),
+        ];
+        let rectangle = minimum_area_rectangle(&diamond).unwrap();
+        assert!((rectangle.area - 2.0).abs() < 1e-12);
+        for corner in rectangle.corners {
+            assert!(diamond.iter().any(|&p| p.distance(corner) < 1e-12));
+        }
+        let (width, _) = minimum_width(&diamond).unwrap();
+        assert!((width - 2f64.sqrt()).abs() < 1e-12);
+        let collinear = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(2.0, 2.0),
+        ];
+        assert_eq!(minimum_width(&collinear), None);
+        assert_eq!(minimum_area_rectangle(&collinear), None);
+    }
+}
@@ -0,0 +1,249 @@
+use super::{Coordinate, EPSILON, Point2D};
+
+/// The turn made going from `a` through `b` to `c`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+/// Classifies the turn `a -> b -> c` by the sign of the cross product
+/// (b - a) x (c - a).
+///
+/// The cross product is |b - a| |c - a| sin(angle), so for floats it is
+/// compared against `EPSILON` scaled by those lengths: the test decides on the
+/// angle alone and works the same for tiny and huge coordinates. Integer
+/// coordinates are classified exactly.
+pub fn orientation<T: Coordinate>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>) -> Orientation {
+    let (ab, ac) = (b - a, c - a);
+    let cross = ab.cross(ac);
+    if T::is_negligible(cross, ab.length() * ac.length()) {
+        Orientation::Collinear
+    } else if cross > T::ZERO {
+        Orientation::CounterClockwise
+    } else {
+        Orientation::Clockwise
+    }
+}
+
+/// How two segments meet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection {
+    None,
+    /// They meet in exactly one point
+    Point(Point2D),
+    /// They are collinear and share this sub-segment
+    Overlap(Segment),
+}
+
+/// A closed line segment between two endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment<T = f64> {
+    pub a: Point2D<T>,
+    pub b: Point2D<T>,
+}
+
+impl<T: Coordinate> Segment<T> {
+    /// Creates the segment from `a` to `b`
+    pub fn new(a: Point2D<T>, b: Point2D<T>) -> Segment<T> {
+        Segment { a, b }
+    }
+
+    /// Returns the length
+    pub fn length(&self) -> f64 {
+        self.a.distance(self.b)
+    }
+
+    /// Checks if `p` lies on the segment, endpoints included, within tolerance
+    pub fn contains(&self, p: Point2D<T>) -> bool {
+        let slack = T::tolerance(1.0 + self.length());
+        let (lo_x, hi_x) = min_max(self.a.x, self.b.x);
+        let (lo_y, hi_y) = min_max(self.a.y, self.b.y);
+        orientation(self.a, self.b, p) == Orientation::Collinear
+            && p.x >= lo_x - slack
+            && p.x <= hi_x + slack
+            && p.y >= lo_y - slack
+            && p.y <= hi_y + slack
+    }
+
+    /// Checks if the segments share at least one point.
+    ///
+    /// They cross when each one's endpoints lie on opposite sides of the
+    /// other; otherwise they can only touch where an endpoint lies on the other.
+    pub fn intersects(&self, other: &Segment<T>) -> bool {
+        let o1 = orientation(self.a, self.b, other.a);
+        let o2 = orientation(self.a, self.b, other.b);
+        let o3 = orientation(other.a, other.b, self.a);
+        let o4 = orientation(other.a, other.b, self.b);
+        let proper = o1 != o2
+            && o3 != o4
+            && [o1, o2, o3, o4]
+                .iter()
+                .all(|&o| o != Orientation::Collinear);
+        proper
+            || self.contains(other.a)
+            || self.contains(other.b)
+            || other.contains(self.a)
+            || other.contains(self.b)
+    }
+
+    /// Converts the endpoints to `f64`
+    pub fn to_f64(&self) -> Segment {
+        Segment::new(self.a.to_f64(), self.b.to_f64())
+    }
+}
+
+fn min_max<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+impl Segment {
+    /// The point of the segment nearest to `p`
+    pub fn closest_point(&self, p: Point2D) -> Point2D {
+        let direction = self.b - self.a;
+        let length_squared = direction.length_squared();
+        if length_squared == 0.0 {
+            return self.a;
+        }
+        let t = ((p - self.a).dot(direction) / length_squared).clamp(0.0, 1.0);
+        self.a + direction * t
+    }
+
+    /// Distance from `p` to the nearest point of the segment
+    pub fn distance_to(&self, p: Point2D) -> f64 {
+        p.distance(self.closest_point(p))
+    }
+
+    /// Computes where the segments meet: nowhere, in one point, or along a
+    /// shared sub-segment if they are collinear
+    pub fn intersect(&self, other: &Segment) -> SegmentIntersection {
+        if !self.intersects(other) {
+            return SegmentIntersection::None;
+        }
+        let (dx, dy) = (self.b.x - self.a.x, self.b.y - self.a.y);
+        let (ex, ey) = (other.b.x - other.a.x, other.b.y - other.a.y);
+        let denom = dx * ey - dy * ex;
+        let parallel = denom.abs() <= EPSILON * dx.hypot(dy) * ex.hypot(ey);
+        if !parallel {
+            // Solve a + t (b - a) on the other segment's line
+            let t = ((other.a.x - self.a.x) * ey - (other.a.y - self.a.y) * ex) / denom;
+            return SegmentIntersection::Point(Point2D::new(self.a.x + t * dx, self.a.y + t * dy));
+        }
+        // Collinear (or a degenerate point): the overlap of the two parameter ranges
+        let along = |p: Point2D| (p.x - self.a.x) * dx + (p.y - self.a.y) * dy;
+        let ends = |s: &Segment| {
+            let (u, v) = (along(s.a), along(s.b));
+            if u <= v {
+                (u, s.a, v, s.b)
+            } else {
+                (v, s.b, u, s.a)
+            }
+        };
+        let (lo1, start1, hi1, end1) = ends(self);
+        let (lo2, start2, hi2, end2) = ends(other);
+        let start = if lo1 >= lo2 { start1 } else { start2 };
+        let end = if hi1 <= hi2 { end1 } else { end2 };
+        if start.distance(end) <= EPSILON * (1.0 + self.length().max(other.length())) {
+            SegmentIntersection::Point(start)
+        } else {
+            SegmentIntersection::Overlap(Segment::new(start, end))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Orientation, Point2D, Segment, SegmentIntersection, orientation};
+
+    fn p(x: f64, y: f64) -> Point2D {
+        Point2D::new(x, y)
+    }
+
+    fn s(ax: f64, ay: f64, bx: f64, by: f64) -> Segment {
+        Segment::new(p(ax, ay), p(bx, by))
+    }
+
+    #[test]
+    fn orientations() {
+        assert_eq!(
+            orientation(p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            orientation(p(0.0, 0.0), p(1.0, 0.0), p(1.0, -1.0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orientation(p(0.0, 0.0), p(1.0, 1.0), p(3.0, 3.0)),
+            Orientation::Collinear
+        );
+        // Rounding noise on huge and tiny coordinates still counts as collinear
+        assert_eq!(
+            orientation(p(1e12, 1e12), p(2e12, 2e12), p(3e12, 3e12 + 1e-4)),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            orientation(p(0.0, 0.0), p(0.1, 0.2), p(0.3, 0.6)),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            orientation(p(0.0, 0.0), p(1e-9, 0.0), p(1e-9, 1e-9)),
+            Orientation::CounterClockwise
+        );
+    }
+
+    #[test]
+    fn intersections() {
+        assert_eq!(
+            s(0.0, 0.0, 2.0, 2.0).intersect(&s(0.0, 2.0, 2.0, 0.0)),
+            SegmentIntersection::Point(p(1.0, 1.0))
+        );
+        assert_eq!(
+            s(0.0, 0.0, 1.0, 1.0).intersect(&s(2.0, 0.0, 0.0, 2.0)),
+            SegmentIntersection::Point(p(1.0, 1.0))
+        );
+        assert_eq!(
+            s(0.0, 0.0, 1.0, 0.0).intersect(&s(0.0, 1.0, 1.0, 1.0)),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            s(0.0, 0.0, 1.0, 1.0).intersect(&s(2.0, 2.0, 3.0, 3.0)),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            s(0.0, 0.0, 2.0, 0.0).intersect(&s(3.0, 0.0, 1.0, 0.0)),
+            SegmentIntersection::Overlap(s(1.0, 0.0, 2.0, 0.0))
+        );
+        assert_eq!(
+            s(0.0, 0.0, 1.0, 0.0).intersect(&s(1.0, 0.0, 2.0, 0.0)),
+            SegmentIntersection::Point(p(1.0, 0.0))
+        );
+        // T-junction: an endpoint on the other segment's interior
+        assert_eq!(
+            s(0.0, 0.0, 2.0, 0.0).intersect(&s(1.0, 0.0, 1.0, 5.0)),
+            SegmentIntersection::Point(p(1.0, 0.0))
+        );
+        assert!(s(0.0, 0.0, 1.0, 0.0).contains(p(0.5, 1e-12)));
+        assert!(!s(0.0, 0.0, 1.0, 0.0).contains(p(0.5, 1e-3)));
+    }
+
+    #[test]
+    fn integer_segments_are_exact() {
+        let seg = |ax, ay, bx, by| Segment::new(Point2D::new(ax, ay), Point2D::new(bx, by));
+        let long = seg(0i64, 0, 1 << 29, (1 << 29) + 1);
+        assert!(!long.contains(Point2D::new(1 << 28, 1 << 28)));
+        assert!(long.intersects(&seg(1 << 28, 0, 1 << 28, 1 << 29)));
+        assert!(!seg(0, 0, 4, 0).intersects(&seg(5, 0, 6, 0)));
+        assert!(seg(0, 0, 4, 0).intersects(&seg(4, 0, 6, 3)));
+    }
+
+    #[test]
+    fn distance_to_points() {
+        let segment = s(0.0, 0.0, 4.0, 0.0);
+        assert_eq!(segment.closest_point(p(1.0, 3.0)), p(1.0, 0.0));
+        assert_eq!(segment.distance_to(p(7.0, 4.0)), 5.0);
+        assert_eq!(segment.distance_to(p(-3.0, -4.0)), 5.0);
+        assert_eq!(s(1.0, 1.0, 1.0, 1.0).distance_to(p(4.0, 5.0)), 5.0);
+    }
+}
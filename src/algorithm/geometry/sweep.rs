@@ -0,0 +1,266 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use super::{EPSILON, Point2D, Segment, SegmentIntersection};
+use crate::algorithm::search::partition_point;
+use crate::data_structure::BinaryHeap;
+
+/// Two input segments that meet, and the first point (in sweep order) they share
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crossing {
+    /// The smaller index of the pair
+    pub first: usize,
+    pub second: usize,
+    pub point: Point2D,
+}
+
+/// A point the sweep line must stop at, optionally where a segment starts
+#[derive(Debug, Clone, Copy)]
+struct Event {
+    point: Point2D,
+    start: Option<usize>,
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Event {}
+
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Event {
+    /// Reversed, so the max-heap pops the leftmost (then lowest) point first
+    fn cmp(&self, other: &Self) -> Ordering {
+        sweep_order(other.point, self.point).then_with(|| other.start.cmp(&self.start))
+    }
+}
+
+/// Left to right, and bottom to top along a vertical line
+fn sweep_order(p: Point2D, q: Point2D) -> Ordering {
+    p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y))
+}
+
+fn same_point(p: Point2D, q: Point2D) -> bool {
+    let slack = EPSILON * (1.0 + p.x.abs().max(p.y.abs()));
+    (p.x - q.x).abs() <= slack && (p.y - q.y).abs() <= slack
+}
+
+/// Reports every pair of segments that meet, with a Bentley–Ottmann-style
+/// plane sweep.
+///
+/// A vertical line sweeps left to right, stopping at endpoints and at crossings
+/// discovered along the way, which wait in a priority queue. The segments
+/// cut by the line are kept ordered bottom to top, and only neighbours in that
+/// order are tested, since two segments must become adjacent just before
+/// they cross. At each stop, all segments through the point are reported
+/// together and their order is reversed, which also handles shared endpoints,
+/// several segments through one point, vertical segments and collinear
+/// overlaps. The ordered set is a sorted `Vec`, so each stop costs O(n)
+/// instead of the O(log n) of a balanced tree, for O((n + k) n) overall.
+pub fn sweep_intersections(segments: &[Segment]) -> Vec<Crossing> {
+    // Orient every segment from its sweep-first endpoint
+    let segs: Vec<Segment> = segments
+        .iter()
+        .map(|s| match sweep_order(s.a, s.b) {
+            Ordering::Greater => Segment::new(s.b, s.a),
+            _ => *s,
+        })
+        .collect();
+    let mut queue = BinaryHeap::with_capacity(2 * segs.len());
+    for (i, s) in segs.iter().enumerate() {
+        queue.push(Event {
+            point: s.a,
+            start: Some(i),
+        });
+        queue.push(Event {
+            point: s.b,
+            start: None,
+        });
+    }
+
+    let mut status: Vec<usize> = Vec::new();
+    let mut reported = HashSet::new();
+    let mut crossings = Vec::new();
+    while let Some(event) = queue.pop() {
+        let p = event.point;
+        let mut starting: Vec<usize> = event.start.into_iter().collect();
+        while let Some(next) = queue.peek()
+            && same_point(next.point, p)
+        {
+            starting.extend(queue.pop().and_then(|e| e.start));
+        }
+
+        // Segments already on the line that pass through (or end at) p
+        let through: Vec<usize> = status
+            .iter()
+            .copied()
+            .filter(|&s| segs[s].contains(p))
+            .collect();
+        let involved: Vec<usize> = through.iter().chain(&starting).copied().collect();
+        for (k, &i) in involved.iter().enumerate() {
+            for &j in &involved[k + 1..] {
+                let pair = (i.min(j), i.max(j));
+                if reported.insert(pair) {
+                    crossings.push(Crossing {
+                        first: pair.0,
+                        second: pair.1,
+                        point: p,
+                    });
+                }
+            }
+        }
+
+        status.retain(|s| !through.contains(s));
+        let position = partition_point(&status, |&s| y_at(&segs[s], p.x, p.y) < p.y);
+        // Segments continuing past p, ordered by their direction leaving it
+        let mut continuing: Vec<usize> = involved
+            .into_iter()
+            .filter(|&s| !same_point(segs[s].b, p))
+            .collect();
+        continuing.sort_by(|&s, &t| {
+            let angle = |s: usize| (segs[s].b.y - p.y).atan2(segs[s].b.x - p.x);
+            angle(s).total_cmp(&angle(t))
+        });
+        continuing.dedup();
+        let inserted = continuing.len();
+        status.splice(position..position, continuing);
+
+        let mut check = |i: usize, j: usize| {
+            if let SegmentIntersection::Point(q) = segs[i].intersect(&segs[j])
+                && sweep_order(q, p) == Ordering::Greater
+                && !same_point(q, p)
+            {
+                queue.push(Event {
+                    point: q,
+                    start: None,
+                });
+            }
+        };
+        let above = position + inserted;
+        if position > 0 && above < status.len() {
+            if inserted == 0 {
+                check(status[position - 1], status[above]);
+            } else {
+                check(status[position - 1], status[position]);
+            }
+        }
+        if inserted > 0 {
+            if position > 0 && above >= status.len() {
+                check(status[position - 1], status[position]);
+            }
+            if above < status.len() {
+                check(status[above - 1], status[above]);
+            }
+        }
+    }
+    crossings.sort_by_key(|c| (c.first, c.second));
+    crossings
+}
+
+/// Height of the segment where it crosses the vertical line at `x`; a
+/// vertical segment is taken to be at `fallback`
+fn y_at(s: &Segment, x: f64, fallback: f64) -> f64 {
+    let dx = s.b.x - s.a.x;
+    if dx.abs() <= EPSILON * (1.0 + s.length()) {
+        fallback
+    } else {
+        s.a.y + (x - s.a.x) / dx * (s.b.y - s.a.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sweep_intersections;
+    use crate::algorithm::geometry::{Point2D, Segment};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn s(ax: f64, ay: f64, bx: f64, by: f64) -> Segment {
+        Segment::new(Point2D::new(ax, ay), Point2D::new(bx, by))
+    }
+
+    fn brute_force(segments: &[Segment]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for i in 0..segments.len() {
+            for j in i + 1..segments.len() {
+                if segments[i].intersects(&segments[j]) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn pairs(segments: &[Segment]) -> Vec<(usize, usize)> {
+        sweep_intersections(segments)
+            .iter()
+            .map(|c| (c.first, c.second))
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_segments() {
+        let mut rng = XorShift64Star::seed_from_u64(0xC0FFEE);
+        let mut next = || rng.gen_range(0..10_000) as f64 / 100.0;
+        for n in [2, 10, 50, 150] {
+            let segments: Vec<Segment> = (0..n)
+                .map(|_| {
+                    let (x, y) = (next(), next());
+                    s(x, y, x + next() / 3.0 - 16.0, y + next() / 3.0 - 16.0)
+                })
+                .collect();
+            assert_eq!(pairs(&segments), brute_force(&segments), "{n} segments");
+        }
+    }
+
+    #[test]
+    fn reports_points() {
+        let crossings = sweep_intersections(&[s(0.0, 0.0, 4.0, 4.0), s(0.0, 4.0, 4.0, 0.0)]);
+        assert_eq!(crossings.len(), 1);
+        assert!(crossings[0].point.distance(Point2D::new(2.0, 2.0)) < 1e-12);
+        assert!(sweep_intersections(&[]).is_empty());
+    }
+
+    #[test]
+    fn handles_degenerate_configurations() {
+        let cases: Vec<Vec<Segment>> = vec![
+            // A grid of horizontal and vertical segments
+            (0..6)
+                .map(|i| s(0.0, i as f64, 5.0, i as f64))
+                .chain((0..6).map(|i| s(i as f64, 0.0, i as f64, 5.0)))
+                .collect(),
+            // Many segments through one point
+            (0..8)
+                .map(|k| {
+                    let angle = k as f64 * std::f64::consts::PI / 8.0;
+                    s(-angle.cos(), -angle.sin(), angle.cos(), angle.sin())
+                })
+                .collect(),
+            // Shared endpoints forming a closed polygon, plus a diagonal
+            vec![
+                s(0.0, 0.0, 2.0, 0.0),
+                s(2.0, 0.0, 2.0, 2.0),
+                s(2.0, 2.0, 0.0, 2.0),
+                s(0.0, 2.0, 0.0, 0.0),
+                s(0.0, 0.0, 2.0, 2.0),
+            ],
+            // Collinear overlaps and a touching T-junction
+            vec![
+                s(0.0, 0.0, 3.0, 0.0),
+                s(2.0, 0.0, 5.0, 0.0),
+                s(4.0, 0.0, 6.0, 0.0),
+                s(1.0, 0.0, 1.0, 3.0),
+                s(7.0, 0.0, 8.0, 0.0),
+            ],
+        ];
+        for segments in cases {
+            assert_eq!(pairs(&segments), brute_force(&segments), "{segments:?}");
+        }
+    }
+}
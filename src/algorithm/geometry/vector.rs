@@ -0,0 +1,154 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::Coordinate;
+
+/// A displacement in the plane, the difference of two points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector2D<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Vector2D<T> {
+    /// Creates the vector (x, y)
+    pub fn new(x: T, y: T) -> Vector2D<T> {
+        Vector2D { x, y }
+    }
+
+    pub fn dot(self, other: Vector2D<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the 3D cross product: positive when `other` points
+    /// counter-clockwise of `self`
+    pub fn cross(self, other: Vector2D<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f64 {
+        self.x.to_f64().hypot(self.y.to_f64())
+    }
+
+    /// The vector rotated a quarter turn counter-clockwise
+    pub fn perpendicular(self) -> Vector2D<T> {
+        Vector2D::new(-self.y, self.x)
+    }
+
+    /// Converts the components to `f64`
+    pub fn to_f64(self) -> Vector2D {
+        Vector2D::new(self.x.to_f64(), self.y.to_f64())
+    }
+}
+
+impl Vector2D {
+    /// Rotates the vector counter-clockwise by `angle` radians
+    pub fn rotate(self, angle: f64) -> Vector2D {
+        let (sin, cos) = angle.sin_cos();
+        Vector2D::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// The vector scaled to length 1. Panics on the zero vector
+    pub fn normalize(self) -> Vector2D {
+        let length = self.length();
+        assert!(length > 0.0, "cannot normalize the zero vector");
+        self * (1.0 / length)
+    }
+
+    /// The component of this vector along `onto`. Panics if `onto` is zero
+    pub fn project_onto(self, onto: Vector2D) -> Vector2D {
+        let length_squared = onto.length_squared();
+        assert!(length_squared > 0.0, "cannot project onto the zero vector");
+        onto * (self.dot(onto) / length_squared)
+    }
+
+    /// Angle of the vector from the positive x axis, in (-pi, pi]
+    pub fn angle(self) -> f64 {
+        self.y.atan2(self.x)
+    }
+}
+
+impl<T: Coordinate> Add for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn add(self, rhs: Vector2D<T>) -> Vector2D<T> {
+        Vector2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn sub(self, rhs: Vector2D<T>) -> Vector2D<T> {
+        Vector2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Neg for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn neg(self) -> Vector2D<T> {
+        Vector2D::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn mul(self, rhs: T) -> Vector2D<T> {
+        Vector2D::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vector2D;
+    use crate::algorithm::geometry::Point2D;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn close(a: Vector2D, b: Vector2D) -> bool {
+        (a - b).length() < 1e-12
+    }
+
+    #[test]
+    fn products_and_lengths() {
+        let (u, v) = (Vector2D::new(3i64, 4), Vector2D::new(-4i64, 3));
+        assert_eq!(u.dot(v), 0);
+        assert_eq!(u.cross(v), 25);
+        assert_eq!(v.cross(u), -25);
+        assert_eq!(u.length_squared(), 25);
+        assert_eq!(u.length(), 5.0);
+        assert_eq!(u.perpendicular(), v);
+        assert_eq!(Point2D::new(1i64, 1) - Point2D::new(4, 5), -u);
+        assert_eq!(
+            Point2D::new(1i64, 1).distance_squared(Point2D::new(4, 5)),
+            25
+        );
+        assert_eq!(Point2D::new(1, 2) + u * 2, Point2D::new(7, 10));
+    }
+
+    #[test]
+    fn rotations_and_projections() {
+        let u = Vector2D::new(2.0, 0.0);
+        assert!(close(u.rotate(FRAC_PI_2), Vector2D::new(0.0, 2.0)));
+        assert!(close(u.rotate(-FRAC_PI_2), -u.perpendicular()));
+        assert!(close(
+            Vector2D::new(3.0, 4.0).normalize(),
+            Vector2D::new(0.6, 0.8)
+        ));
+        assert!(close(
+            Vector2D::new(3.0, 5.0).project_onto(Vector2D::new(2.0, 0.0)),
+            Vector2D::new(3.0, 0.0)
+        ));
+        assert!(close(
+            Vector2D::new(1.0, 3.0).project_onto(Vector2D::new(1.0, 1.0)),
+            Vector2D::new(2.0, 2.0)
+        ));
+        let p = Point2D::new(2.0, 1.0).rotate_around(Point2D::new(1.0, 1.0), FRAC_PI_2);
+        assert!(p.distance(Point2D::new(1.0, 2.0)) < 1e-12);
+        assert_eq!(Point2D::new(3i64, -2).to_f64(), Point2D::new(3.0, -2.0));
+    }
+}
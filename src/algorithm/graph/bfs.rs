@@ -0,0 +1,86 @@
+#[cfg(feature = "parallel")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::data_structure::Queue;
+
+/// The number of edges on a shortest path from `source` to each vertex of a
+/// graph given as adjacency lists, or None where unreachable
+pub fn bfs_levels(adjacency: &[Vec<usize>], source: usize) -> Vec<Option<usize>> {
+    assert!(source < adjacency.len(), "source {source} is not a vertex");
+    let mut levels = vec![None; adjacency.len()];
+    levels[source] = Some(0);
+    let mut queue = Queue::new();
+    queue.enqueue(source);
+    while let Some(v) = queue.dequeue() {
+        let next = levels[v].map(|level| level + 1);
+        for &w in &adjacency[v] {
+            if levels[w].is_none() {
+                levels[w] = next;
+                queue.enqueue(w);
+            }
+        }
+    }
+    levels
+}
+
+/// `bfs_levels` one frontier at a time, expanding each frontier's
+/// neighbours in parallel. A compare-exchange on the level claims each vertex
+/// for exactly one frontier, so the levels match the sequential search.
+#[cfg(feature = "parallel")]
+pub fn par_bfs_levels(adjacency: &[Vec<usize>], source: usize) -> Vec<Option<usize>> {
+    const UNSEEN: usize = usize::MAX;
+    assert!(source < adjacency.len(), "source {source} is not a vertex");
+    let levels: Vec<AtomicUsize> = (0..adjacency.len())
+        .map(|_| AtomicUsize::new(UNSEEN))
+        .collect();
+    levels[source].store(0, Ordering::Relaxed);
+    let mut frontier = vec![source];
+    let mut level = 0;
+    while !frontier.is_empty() {
+        level += 1;
+        frontier = frontier
+            .par_iter()
+            .flat_map_iter(|&v| adjacency[v].iter().copied())
+            .filter(|&w| {
+                levels[w]
+                    .compare_exchange(UNSEEN, level, Ordering::Relaxed, Ordering::Relaxed)
+                    .is_ok()
+            })
+            .collect();
+    }
+    levels
+        .into_iter()
+        .map(|level| Some(level.into_inner()).filter(|&l| l != UNSEEN))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bfs_levels;
+
+    #[test]
+    fn levels_on_a_small_graph() {
+        // 0 -> 1 -> 3, 0 -> 2 -> 3 -> 4, and 5 is unreachable
+        let adjacency = vec![vec![1, 2], vec![3], vec![3], vec![4], vec![], vec![0]];
+        assert_eq!(
+            bfs_levels(&adjacency, 0),
+            [Some(0), Some(1), Some(1), Some(2), Some(3), None]
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_levels_match() {
+        use super::par_bfs_levels;
+        use crate::testgen::TestGen;
+
+        for seed in 0..20 {
+            // About two edges per node, so the frontier stays narrow for many levels
+            let adjacency = TestGen::new(seed).graph(300, 2.0 / 300.0);
+            assert_eq!(par_bfs_levels(&adjacency, 0), bfs_levels(&adjacency, 0));
+        }
+    }
+}
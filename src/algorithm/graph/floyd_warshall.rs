@@ -0,0 +1,144 @@
+use std::ops::Add;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// All-pairs shortest path lengths, where `weights[i][j]` is the weight of
+/// the edge i -> j if there is one and `W::default()` is zero. Returns None
+/// if a cycle of negative weight makes some distances unbounded.
+pub fn floyd_warshall<W>(weights: &[Vec<Option<W>>]) -> Option<Vec<Vec<Option<W>>>>
+where
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut dist = initial(weights);
+    for k in 0..dist.len() {
+        let through_k = dist[k].clone();
+        for row in &mut dist {
+            relax(row, k, &through_k);
+        }
+    }
+    finish(dist)
+}
+
+/// `floyd_warshall` relaxing the rows of each round in parallel. Every row
+/// reads the same copy of row `k`, so the result matches the sequential one.
+#[cfg(feature = "parallel")]
+pub fn par_floyd_warshall<W>(weights: &[Vec<Option<W>>]) -> Option<Vec<Vec<Option<W>>>>
+where
+    W: Copy + Ord + Add<Output = W> + Default + Send + Sync,
+{
+    let mut dist = initial(weights);
+    for k in 0..dist.len() {
+        let through_k = dist[k].clone();
+        dist.par_iter_mut()
+            .for_each(|row| relax(row, k, &through_k));
+    }
+    finish(dist)
+}
+
+fn initial<W: Copy + Ord + Default>(weights: &[Vec<Option<W>>]) -> Vec<Vec<Option<W>>> {
+    let n = weights.len();
+    assert!(
+        weights.iter().all(|row| row.len() == n),
+        "weights must be square"
+    );
+    let mut dist = weights.to_vec();
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[i] = Some(row[i].map_or(W::default(), |w| w.min(W::default())));
+    }
+    dist
+}
+
+/// Shortens every i -> j in `row` (row i) that is cheaper by way of `k`
+fn relax<W: Copy + Ord + Add<Output = W>>(
+    row: &mut [Option<W>],
+    k: usize,
+    through_k: &[Option<W>],
+) {
+    let Some(to_k) = row[k] else {
+        return;
+    };
+    for (d, via) in row.iter_mut().zip(through_k) {
+        if let Some(via) = via {
+            let candidate = to_k + *via;
+            if d.is_none_or(|d| candidate < d) {
+                *d = Some(candidate);
+            }
+        }
+    }
+}
+
+fn finish<W: Copy + Ord + Default>(dist: Vec<Vec<Option<W>>>) -> Option<Vec<Vec<Option<W>>>> {
+    let negative_cycle = (0..dist.len()).any(|i| dist[i][i].is_some_and(|d| d < W::default()));
+    (!negative_cycle).then_some(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::floyd_warshall;
+    use crate::testgen::TestGen;
+
+    fn random_weights(n: usize, seed: u64) -> Vec<Vec<Option<i64>>> {
+        TestGen::new(seed).weighted_graph(n, 1.0 / 3.0, 0..100)
+    }
+
+    #[test]
+    fn shortest_paths_on_a_small_graph() {
+        let weights = vec![
+            vec![None, Some(4), Some(1), None],
+            vec![None, None, None, Some(1)],
+            vec![None, Some(2), None, Some(5)],
+            vec![None, None, None, None],
+        ];
+        let dist = floyd_warshall(&weights).unwrap();
+        assert_eq!(dist[0], [Some(0), Some(3), Some(1), Some(4)]);
+        assert_eq!(dist[3], [None, None, None, Some(0)]);
+    }
+
+    #[test]
+    fn negative_edges_without_cycles_are_fine() {
+        let weights = vec![vec![None, Some(5)], vec![Some(-2), None]];
+        let dist = floyd_warshall(&weights).unwrap();
+        assert_eq!(dist, [[Some(0), Some(5)], [Some(-2), Some(0)]]);
+        let cycle = vec![vec![None, Some(1)], vec![Some(-2), None]];
+        assert_eq!(floyd_warshall(&cycle), None);
+    }
+
+    #[test]
+    fn agrees_with_relaxing_until_stable() {
+        for seed in 0..10 {
+            let weights = random_weights(12, seed);
+            let dist = floyd_warshall(&weights).unwrap();
+            // Bellman-Ford style: relax every edge n times from each source
+            for s in 0..12 {
+                let mut best = vec![None; 12];
+                best[s] = Some(0);
+                for _ in 0..12 {
+                    for u in 0..12 {
+                        for v in 0..12 {
+                            if let (Some(du), Some(w)) = (best[u], weights[u][v])
+                                && best[v].is_none_or(|dv| du + w < dv)
+                            {
+                                best[v] = Some(du + w);
+                            }
+                        }
+                    }
+                }
+                assert_eq!(dist[s], best, "seed {seed} source {s}");
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_sequential() {
+        use super::par_floyd_warshall;
+
+        for seed in 0..10 {
+            let weights = random_weights(40, seed);
+            assert_eq!(par_floyd_warshall(&weights), floyd_warshall(&weights));
+        }
+        let cycle = vec![vec![None, Some(1)], vec![Some(-2), None]];
+        assert_eq!(par_floyd_warshall(&cycle), None);
+    }
+}
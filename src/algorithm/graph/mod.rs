@@ -0,0 +1,9 @@
+mod bfs;
+mod floyd_warshall;
+
+pub use self::bfs::bfs_levels;
+#[cfg(feature = "parallel")]
+pub use self::bfs::par_bfs_levels;
+pub use self::floyd_warshall::floyd_warshall;
+#[cfg(feature = "parallel")]
+pub use self::floyd_warshall::par_floyd_warshall;
@@ -0,0 +1,298 @@
+use super::ModInt;
+
+/// Factorials and inverse factorials modulo the prime `P` up to a limit, for
+/// O(1) binomial coefficients after O(n) precomputation.
+///
+/// `P` must be prime and greater than the limit, otherwise factorials vanish
+/// modulo `P` and have no inverse.
+#[derive(Debug, Clone)]
+pub struct Combinatorics<const P: u64> {
+    fact: Vec<ModInt<P>>,
+    inv_fact: Vec<ModInt<P>>,
+}
+
+impl<const P: u64> Combinatorics<P> {
+    /// Precomputes factorials of `0..=limit`. Panics if `limit >= P`
+    pub fn new(limit: usize) -> Combinatorics<P> {
+        assert!((limit as u64) < P, "the limit must be below the modulus");
+        let mut fact = vec![ModInt::new(1); limit + 1];
+        for i in 1..=limit {
+            fact[i] = fact[i - 1] * ModInt::new(i as u64);
+        }
+        // One inversion, then (i - 1)!^-1 = i!^-1 * i walking down
+        let mut inv_fact = vec![ModInt::new(1); limit + 1];
+        inv_fact[limit] = fact[limit].inverse().expect("P is prime");
+        for i in (1..=limit).rev() {
+            inv_fact[i - 1] = inv_fact[i] * ModInt::new(i as u64);
+        }
+        Combinatorics { fact, inv_fact }
+    }
+
+    /// Returns the largest `n` the tables cover
+    pub fn limit(&self) -> usize {
+        self.fact.len() - 1
+    }
+
+    /// Returns `n!`. Panics if `n` exceeds the limit
+    pub fn factorial(&self, n: usize) -> ModInt<P> {
+        self.fact[n]
+    }
+
+    /// Returns the binomial coefficient C(n, r), 0 if `r > n`. Panics if `n` exceeds the limit
+    pub fn ncr(&self, n: usize, r: usize) -> ModInt<P> {
+        if r > n {
+            return ModInt::new(0);
+        }
+        self.fact[n] * self.inv_fact[r] * self.inv_fact[n - r]
+    }
+
+    /// Returns the number of ordered selections P(n, r), 0 if `r > n`
+    pub fn npr(&self, n: usize, r: usize) -> ModInt<P> {
+        if r > n {
+            return ModInt::new(0);
+        }
+        self.fact[n] * self.inv_fact[n - r]
+    }
+
+    /// C(n, r) for arbitrarily large `n` by Lucas' theorem: the product of the
+    /// binomials of the base-P digits. Needs tables up to `P - 1`, so only
+    /// suits small primes. Panics if the limit is below `P - 1`
+    pub fn lucas(&self, mut n: u64, mut r: u64) -> ModInt<P> {
+        assert!(
+            self.limit() as u64 >= P - 1,
+            "Lucas needs tables up to P - 1"
+        );
+        let mut result = ModInt::new(1);
+        while r > 0 {
+            result *= self.ncr((n % P) as usize, (r % P) as usize);
+            (n, r) = (n / P, r / P);
+        }
+        result
+    }
+
+    /// Returns the `n`th Catalan number C(2n, n) / (n + 1). Panics if `2n` exceeds the limit
+    pub fn catalan(&self, n: usize) -> ModInt<P> {
+        self.ncr(2 * n, n) * self.inv_fact[n + 1] * self.fact[n]
+    }
+
+    /// Stirling number of the second kind S(n, k), the ways to partition `n`
+    /// items into `k` non-empty sets, from the inclusion–exclusion formula
+    /// S(n, k) = 1/k! * sum (-1)^j C(k, j) (k - j)^n in O(k log n).
+    /// Panics if `k` exceeds the limit
+    pub fn stirling2(&self, n: u64, k: usize) -> ModInt<P> {
+        let mut sum = ModInt::new(0);
+        for j in 0..=k {
+            let term = self.ncr(k, j) * ModInt::new((k - j) as u64).pow(n);
+            if j % 2 == 0 {
+                sum += term;
+            } else {
+                sum -= term;
+            }
+        }
+        sum * self.inv_fact[k]
+    }
+}
+
+/// Iterator over the `k`-element combinations of a slice, in lexicographic
+/// order of positions, created by `combinations`
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    done: bool,
+}
+
+/// Iterates over every way to choose `k` of `items`, each yielded as a `Vec`
+/// in the original order; there are C(n, k) of them
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: (0..k).collect(),
+        done: k > items.len(),
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let current = self
+            .indices
+            .iter()
+            .map(|&i| self.items[i].clone())
+            .collect();
+        // Advance the rightmost index that still has room, then reset those after it
+        let (n, k) = (self.items.len(), self.indices.len());
+        match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+        Some(current)
+    }
+}
+
+/// Iterator over the orderings of a slice, in lexicographic order of
+/// positions, created by `permutations`
+#[derive(Debug, Clone)]
+pub struct Permutations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    done: bool,
+}
+
+/// Iterates over all n! orderings of `items`, each yielded as a `Vec`.
+/// Positions are permuted, so repeated values give repeated orderings
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<'_, T> {
+    Permutations {
+        items,
+        indices: (0..items.len()).collect(),
+        done: false,
+    }
+}
+
+impl<T: Clone> Iterator for Permutations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let current = self
+            .indices
+            .iter()
+            .map(|&i| self.items[i].clone())
+            .collect();
+        // Next permutation: find the last ascent, swap its left end with the
+        // smallest larger element to its right, and reverse the suffix
+        let indices = &mut self.indices;
+        match (1..indices.len())
+            .rev()
+            .find(|&i| indices[i - 1] < indices[i])
+        {
+            Some(i) => {
+                let pivot = i - 1;
+                let successor = (i..indices.len())
+                    .rev()
+                    .find(|&j| indices[j] > indices[pivot])
+                    .expect("the ascent guarantees a larger element");
+                indices.swap(pivot, successor);
+                indices[i..].reverse();
+            }
+            None => self.done = true,
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Combinatorics, combinations, permutations};
+
+    const P: u64 = 1_000_000_007;
+
+    #[test]
+    fn binomials_match_pascal() {
+        let comb = Combinatorics::<P>::new(200);
+        let mut row = vec![1u64];
+        for n in 0..=60usize {
+            for (r, &expected) in row.iter().enumerate() {
+                assert_eq!(comb.ncr(n, r).value(), expected % P);
+            }
+            row = (0..=row.len())
+                .map(|r| {
+                    let left = if r > 0 { row[r - 1] } else { 0 };
+                    (left + row.get(r).copied().unwrap_or(0)) % P
+                })
+                .collect();
+        }
+        assert_eq!(comb.ncr(5, 6).value(), 0);
+        assert_eq!(comb.npr(5, 2).value(), 20);
+        assert_eq!(comb.factorial(20).value(), 2_432_902_008_176_640_000 % P);
+    }
+
+    #[test]
+    fn lucas_handles_huge_n() {
+        let comb = Combinatorics::<7>::new(6);
+        let mut pascal = vec![vec![1u64]];
+        for n in 1..300 {
+            let prev: &Vec<u64> = &pascal[n - 1];
+            let row = (0..=n)
+                .map(|r| {
+                    let left = if r > 0 { prev[r - 1] } else { 0 };
+                    (left + prev.get(r).copied().unwrap_or(0)) % 7
+                })
+                .collect();
+            pascal.push(row);
+        }
+        for (n, row) in pascal.iter().enumerate() {
+            for (r, &expected) in row.iter().enumerate() {
+                assert_eq!(
+                    comb.lucas(n as u64, r as u64).value(),
+                    expected,
+                    "C({n}, {r})"
+                );
+            }
+        }
+        assert_eq!(comb.lucas(1_000_000_000_000_000_005, 3).value(), 6);
+        assert_eq!(
+            comb.lucas(1_000_000_000_000_000_000, 1_000_000_000).value(),
+            0
+        );
+        assert_eq!(comb.lucas(49, 7).value(), 0);
+    }
+
+    #[test]
+    fn catalan_and_stirling() {
+        let comb = Combinatorics::<P>::new(100);
+        let catalan: Vec<u64> = (0..10).map(|n| comb.catalan(n).value()).collect();
+        assert_eq!(catalan, vec![1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862]);
+        assert_eq!(comb.stirling2(5, 2).value(), 15);
+        assert_eq!(comb.stirling2(10, 3).value(), 9330);
+        assert_eq!(comb.stirling2(0, 0).value(), 1);
+        assert_eq!(comb.stirling2(4, 5).value(), 0);
+        // Bell numbers are row sums
+        let bell: u64 = (0..=7).map(|k| comb.stirling2(7, k).value()).sum();
+        assert_eq!(bell, 877);
+    }
+
+    #[test]
+    fn generates_combinations() {
+        let picked: Vec<Vec<char>> = combinations(&['a', 'b', 'c', 'd'], 2).collect();
+        assert_eq!(picked.len(), 6);
+        assert_eq!(picked[0], vec!['a', 'b']);
+        assert_eq!(picked[5], vec!['c', 'd']);
+        assert!(picked.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(
+            combinations(&[1, 2, 3], 0).collect::<Vec<_>>(),
+            vec![vec![]]
+        );
+        assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+        assert_eq!(combinations(&(0..10).collect::<Vec<_>>(), 4).count(), 210);
+    }
+
+    #[test]
+    fn generates_permutations() {
+        let orders: Vec<Vec<u8>> = permutations(&[1, 2, 3]).collect();
+        assert_eq!(
+            orders,
+            vec![
+                vec![1, 2, 3],
+                vec![1, 3, 2],
+                vec![2, 1, 3],
+                vec![2, 3, 1],
+                vec![3, 1, 2],
+                vec![3, 2, 1]
+            ]
+        );
+        assert_eq!(permutations(&[0; 5]).count(), 120);
+        assert_eq!(permutations::<u8>(&[]).collect::<Vec<_>>(), vec![vec![]]);
+    }
+}
@@ -0,0 +1,131 @@
+use super::modint::{add_mod, mul_mod, sub_mod};
+use super::{gcd, mod_inverse};
+
+/// Solves the system `x ≡ residues[i] (mod moduli[i])` by the Chinese
+/// remainder theorem, returning `(x, lcm)` with `x` the unique solution in
+/// `0..lcm` of the moduli, or None if the congruences are inconsistent.
+///
+/// Moduli need not be coprime: congruences are merged pairwise, and two of
+/// them agree exactly when their residues match modulo the gcd of the moduli.
+/// Intermediate products use 128 bits. An empty system gives `(0, 1)`.
+/// Panics if the slices differ in length, a modulus is 0, or the lcm of the
+/// moduli does not fit in `u64`.
+pub fn crt(residues: &[u64], moduli: &[u64]) -> Option<(u64, u64)> {
+    assert_eq!(
+        residues.len(),
+        moduli.len(),
+        "every residue needs a modulus"
+    );
+    let (mut x, mut m) = (0u64, 1u64);
+    for (&a, &n) in residues.iter().zip(moduli) {
+        assert!(n > 0, "moduli must be positive");
+        let a = a % n;
+        let g = gcd(m, n);
+        let diff = i128::from(a) - i128::from(x);
+        if diff % i128::from(g) != 0 {
+            return None;
+        }
+        // Solve m * k ≡ a - x (mod n), i.e. (m/g) * k ≡ (a - x)/g (mod n/g)
+        let step = n / g;
+        let lcm = (m / g)
+            .checked_mul(n)
+            .expect("lcm of the moduli overflows u64");
+        let rhs = (diff / i128::from(g)).rem_euclid(i128::from(step)) as u64;
+        let inverse = mod_inverse(m / g % step, step).expect("m/g and n/g are coprime");
+        let k = mul_mod(rhs, inverse, step);
+        x = (u128::from(x) + u128::from(m) * u128::from(k)) as u64 % lcm;
+        m = lcm;
+    }
+    Some((x, m))
+}
+
+/// Garner's algorithm: the solution of the system modulo `modulus`, for
+/// pairwise coprime moduli whose product may be far too large to represent.
+///
+/// The solution is rebuilt in mixed radix, `x = c0 + c1*m0 + c2*m0*m1 + ...`,
+/// finding each digit modulo its own modulus, then evaluated modulo
+/// `modulus` in O(k^2). Panics if the slices differ in length, the moduli are
+/// not pairwise coprime, or any modulus is 0.
+pub fn garner(residues: &[u64], moduli: &[u64], modulus: u64) -> u64 {
+    assert_eq!(
+        residues.len(),
+        moduli.len(),
+        "every residue needs a modulus"
+    );
+    assert!(modulus > 0, "modulus must be positive");
+    let mut digits: Vec<u64> = Vec::with_capacity(moduli.len());
+    for (i, (&a, &n)) in residues.iter().zip(moduli).enumerate() {
+        assert!(n > 0, "moduli must be positive");
+        // Evaluate the digits found so far modulo n, then solve for the next one
+        let (mut value, mut radix) = (0, 1 % n);
+        for (&digit, &previous) in digits.iter().zip(&moduli[..i]) {
+            value = add_mod(value, mul_mod(digit % n, radix, n), n);
+            radix = mul_mod(radix, previous % n, n);
+        }
+        let inverse = mod_inverse(radix, n).expect("moduli must be pairwise coprime");
+        let gap = sub_mod(a % n, value, n);
+        digits.push(mul_mod(gap, inverse, n));
+    }
+    let (mut result, mut radix) = (0, 1 % modulus);
+    for (&digit, &n) in digits.iter().zip(moduli) {
+        result = add_mod(result, mul_mod(digit % modulus, radix, modulus), modulus);
+        radix = mul_mod(radix, n % modulus, modulus);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crt, garner};
+
+    #[test]
+    fn solves_coprime_systems() {
+        assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+        assert_eq!(crt(&[], &[]), Some((0, 1)));
+        assert_eq!(crt(&[10], &[7]), Some((3, 7)));
+    }
+
+    #[test]
+    fn handles_non_coprime_moduli() {
+        assert_eq!(crt(&[2, 4], &[6, 8]), Some((20, 24)));
+        assert_eq!(crt(&[1, 2], &[4, 6]), None);
+        for m1 in 1..=30u64 {
+            for m2 in 1..=30u64 {
+                for (a1, a2) in [(0, 0), (1, 5), (7, 3), (11, 29)] {
+                    let lcm = m1 * m2 / crate::algorithm::math::gcd(m1, m2);
+                    let expected = (0..lcm).find(|x| x % m1 == a1 % m1 && x % m2 == a2 % m2);
+                    assert_eq!(
+                        crt(&[a1, a2], &[m1, m2]),
+                        expected.map(|x| (x, lcm)),
+                        "{a1} mod {m1}, {a2} mod {m2}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_moduli_do_not_overflow() {
+        let (p, q) = (4_294_967_291u64, 4_294_967_279u64);
+        let x = 12_345_678_901_234_567_890u64 % (p * q);
+        assert_eq!(crt(&[x % p, x % q], &[p, q]), Some((x, p * q)));
+    }
+
+    #[test]
+    fn garner_reduces_huge_solutions() {
+        let moduli = [998_244_353, 1_000_000_007, 1_000_000_009, 754_974_721];
+        // Below the product of the moduli, so the system pins it down exactly
+        let target: u128 = 3u128.pow(74);
+        let residues: Vec<u64> = moduli
+            .iter()
+            .map(|&m| (target % u128::from(m)) as u64)
+            .collect();
+        for modulus in [1_000_000_007u64, 1 << 61, 12_345] {
+            assert_eq!(
+                garner(&residues, &moduli, modulus),
+                (target % u128::from(modulus)) as u64
+            );
+        }
+        assert_eq!(garner(&[2, 3, 2], &[3, 5, 7], 1000), 23);
+    }
+}
@@ -0,0 +1,180 @@
+use super::gcd;
+use super::is_prime_u64;
+use super::modint::{add_mod, mul_mod};
+
+/// Factors below this are removed by trial division before Pollard's rho
+const TRIAL_LIMIT: u64 = 1000;
+
+/// Prime factorization of `n` as ascending (prime, exponent) pairs; empty for 1.
+///
+/// Small factors are stripped by trial division, then composite cofactors are
+/// split by Pollard's rho with Brent's cycle detection, using Miller–Rabin to
+/// recognise primes. Expected time is about O(n^(1/4)) multiplications.
+/// Panics if `n` is 0.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    assert!(n > 0, "0 has no factorization");
+    let mut primes = Vec::new();
+    let mut rest = n;
+    let mut d = 2;
+    while d < TRIAL_LIMIT && d * d <= rest {
+        while rest.is_multiple_of(d) {
+            primes.push(d);
+            rest /= d;
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+    let mut pending = vec![rest];
+    while let Some(m) = pending.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime_u64(m) {
+            primes.push(m);
+        } else {
+            let factor = pollard_brent(m);
+            pending.push(factor);
+            pending.push(m / factor);
+        }
+    }
+    primes.sort_unstable();
+
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// All positive divisors of `n`, ascending. Panics if `n` is 0
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut divisors = vec![1];
+    for (p, exponent) in factorize(n) {
+        let count = divisors.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= p;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Finds a nontrivial factor of the odd composite `n`.
+///
+/// Iterates `x -> x^2 + c mod n`, which cycles mod every prime factor p after
+/// about sqrt(p) steps. Brent's variant compares against a checkpoint that
+/// moves at powers of two, and batches the differences into one product so a
+/// gcd is only taken every `BATCH` steps, backtracking if the batch overshot.
+fn pollard_brent(n: u64) -> u64 {
+    const BATCH: u64 = 128;
+    for c in 1.. {
+        let step = |x: u64| add_mod(mul_mod(x, x, n), c, n);
+        let (mut y, mut x, mut saved) = (2, 2, 2);
+        let (mut r, mut q, mut g) = (1, 1, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = step(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                saved = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = step(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // The batch multiplied in a multiple of n; redo it one step at a time
+            loop {
+                saved = step(saved);
+                g = gcd(x.abs_diff(saved), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!("some constant c splits every composite")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{divisors, factorize};
+    use crate::algorithm::math::LinearSieve;
+
+    fn product(factors: &[(u64, u32)]) -> u64 {
+        factors.iter().map(|&(p, e)| p.pow(e)).product()
+    }
+
+    #[test]
+    fn matches_sieve_factorization() {
+        let sieve = LinearSieve::new(50_000);
+        for n in 1..=50_000 {
+            let expected: Vec<(u64, u32)> =
+                sieve.factorize(n).map(|(p, e)| (p as u64, e)).collect();
+            assert_eq!(factorize(n as u64), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn factors_large_semiprimes() {
+        let cases: [(u64, Vec<(u64, u32)>); 5] = [
+            (
+                4_294_967_291 * 4_294_967_279,
+                vec![(4_294_967_279, 1), (4_294_967_291, 1)],
+            ),
+            (
+                1_000_000_007 * 998_244_353,
+                vec![(998_244_353, 1), (1_000_000_007, 1)],
+            ),
+            (
+                u64::MAX,
+                vec![
+                    (3, 1),
+                    (5, 1),
+                    (17, 1),
+                    (257, 1),
+                    (641, 1),
+                    (65_537, 1),
+                    (6_700_417, 1),
+                ],
+            ),
+            (1 << 63, vec![(2, 63)]),
+            (u64::MAX - 58, vec![(u64::MAX - 58, 1)]),
+        ];
+        for (n, expected) in cases {
+            assert_eq!(factorize(n), expected, "{n}");
+        }
+        // A prime squared, beyond the trial division limit
+        let p = 3_037_000_493u64;
+        assert_eq!(factorize(p * p), vec![(p, 2)]);
+        let mixed = 2u64.pow(5) * 3 * 1_000_003u64.pow(2) * 10_007;
+        assert_eq!(product(&factorize(mixed)), mixed);
+    }
+
+    #[test]
+    fn lists_divisors() {
+        assert_eq!(divisors(1), vec![1]);
+        assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(97), vec![1, 97]);
+        for n in 1..=2000u64 {
+            let expected: Vec<u64> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+            assert_eq!(divisors(n), expected);
+        }
+        assert_eq!(divisors(735_134_400).len(), 1344);
+    }
+}
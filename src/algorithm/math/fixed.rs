@@ -0,0 +1,347 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// How to round a result that falls between two representable values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Toward negative infinity
+    Floor,
+    /// Toward positive infinity
+    Ceil,
+    /// Toward zero, discarding the excess
+    TowardZero,
+    /// To the nearest value, ties away from zero
+    Nearest,
+    /// To the nearest value, ties to the even neighbour (banker's rounding)
+    NearestEven,
+}
+
+/// A signed Q-format fixed-point number: an `i64` counting units of
+/// 2^-FRAC_BITS, so it has `63 - FRAC_BITS` integer bits.
+///
+/// Addition and subtraction are exact; multiplication and division go
+/// through `i128` and round to nearest, with `mul_round` and `div_round` for
+/// other modes. Every operation is deterministic across platforms, unlike
+/// floats. Overflow panics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const FRAC_BITS: u32> {
+    bits: i64,
+}
+
+impl<const FRAC_BITS: u32> Fixed<FRAC_BITS> {
+    const VALID: () = assert!(FRAC_BITS < 63, "at most 62 fractional bits");
+
+    pub const ZERO: Self = Fixed { bits: 0 };
+    pub const ONE: Self = Fixed {
+        bits: 1 << FRAC_BITS,
+    };
+    /// The smallest positive value, 2^-FRAC_BITS
+    pub const EPSILON: Self = Fixed { bits: 1 };
+    pub const MAX: Self = Fixed { bits: i64::MAX };
+    pub const MIN: Self = Fixed { bits: i64::MIN };
+
+    /// Wraps a raw value counting units of 2^-FRAC_BITS
+    pub fn from_bits(bits: i64) -> Self {
+        let () = Self::VALID;
+        Fixed { bits }
+    }
+
+    /// Returns the raw value
+    pub fn to_bits(self) -> i64 {
+        self.bits
+    }
+
+    /// Converts an integer exactly. Panics if it is out of range
+    pub fn from_int(value: i64) -> Self {
+        let () = Self::VALID;
+        let bits = value
+            .checked_mul(1 << FRAC_BITS)
+            .expect("fixed-point overflow");
+        Fixed { bits }
+    }
+
+    /// Converts a float to the nearest value, or None if it is not finite or out of range
+    pub fn from_f64(value: f64) -> Option<Self> {
+        Self::from_f64_round(value, RoundingMode::Nearest)
+    }
+
+    /// Converts a float with the given rounding, or None if it is not finite or out of range
+    pub fn from_f64_round(value: f64, mode: RoundingMode) -> Option<Self> {
+        let () = Self::VALID;
+        let scaled = value * (1u64 << FRAC_BITS) as f64;
+        let rounded = match mode {
+            RoundingMode::Floor => scaled.floor(),
+            RoundingMode::Ceil => scaled.ceil(),
+            RoundingMode::TowardZero => scaled.trunc(),
+            RoundingMode::Nearest => scaled.round(),
+            RoundingMode::NearestEven => scaled.round_ties_even(),
+        };
+        // i64::MAX is not a float, but 2^63 is; NaN fails both comparisons
+        let in_range = rounded >= -(2f64.powi(63)) && rounded < 2f64.powi(63);
+        in_range.then_some(Fixed {
+            bits: rounded as i64,
+        })
+    }
+
+    /// Returns the nearest float; exact whenever the raw value fits in 53 bits
+    pub fn to_f64(self) -> f64 {
+        self.bits as f64 / (1u64 << FRAC_BITS) as f64
+    }
+
+    /// Rounds to an integer with the given mode
+    pub fn round_to_int(self, mode: RoundingMode) -> i64 {
+        round_div(i128::from(self.bits), 1 << FRAC_BITS, mode) as i64
+    }
+
+    /// Returns the largest integer not greater than the value
+    pub fn floor(self) -> i64 {
+        self.round_to_int(RoundingMode::Floor)
+    }
+
+    /// Returns the smallest integer not less than the value
+    pub fn ceil(self) -> i64 {
+        self.round_to_int(RoundingMode::Ceil)
+    }
+
+    /// Returns the absolute value. Panics for `MIN`
+    pub fn abs(self) -> Self {
+        if self.bits < 0 { -self } else { self }
+    }
+
+    /// Multiplies with the given rounding. Panics on overflow
+    pub fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self {
+        let product = i128::from(self.bits) * i128::from(rhs.bits);
+        Self::from_wide(round_div(product, 1 << FRAC_BITS, mode))
+    }
+
+    /// Divides with the given rounding. Panics on overflow or division by zero
+    pub fn div_round(self, rhs: Self, mode: RoundingMode) -> Self {
+        assert!(rhs.bits != 0, "division by zero");
+        let numerator = i128::from(self.bits) << FRAC_BITS;
+        Self::from_wide(round_div(numerator, i128::from(rhs.bits), mode))
+    }
+
+    fn from_wide(bits: i128) -> Self {
+        Fixed {
+            bits: i64::try_from(bits).expect("fixed-point overflow"),
+        }
+    }
+}
+
+/// `n / d` rounded according to `mode`
+fn round_div(n: i128, d: i128, mode: RoundingMode) -> i128 {
+    let (n, d) = if d < 0 { (-n, -d) } else { (n, d) };
+    let (floor, rem) = (n.div_euclid(d), n.rem_euclid(d));
+    if rem == 0 {
+        return floor;
+    }
+    let up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => true,
+        RoundingMode::TowardZero => n < 0,
+        RoundingMode::Nearest | RoundingMode::NearestEven => match (2 * rem).cmp(&d) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal if mode == RoundingMode::Nearest => n >= 0,
+            Ordering::Equal => floor % 2 != 0,
+        },
+    };
+    if up { floor + 1 } else { floor }
+}
+
+impl<const FRAC_BITS: u32> Add for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Fixed {
+            bits: self
+                .bits
+                .checked_add(rhs.bits)
+                .expect("fixed-point overflow"),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u32> Sub for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Fixed {
+            bits: self
+                .bits
+                .checked_sub(rhs.bits)
+                .expect("fixed-point overflow"),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u32> Mul for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_round(rhs, RoundingMode::Nearest)
+    }
+}
+
+impl<const FRAC_BITS: u32> Div for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.div_round(rhs, RoundingMode::Nearest)
+    }
+}
+
+impl<const FRAC_BITS: u32> Neg for Fixed<FRAC_BITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed {
+            bits: self.bits.checked_neg().expect("fixed-point overflow"),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u32> AddAssign for Fixed<FRAC_BITS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const FRAC_BITS: u32> SubAssign for Fixed<FRAC_BITS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const FRAC_BITS: u32> MulAssign for Fixed<FRAC_BITS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const FRAC_BITS: u32> DivAssign for Fixed<FRAC_BITS> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const FRAC_BITS: u32> From<i32> for Fixed<FRAC_BITS> {
+    fn from(value: i32) -> Self {
+        Fixed::from_int(i64::from(value))
+    }
+}
+
+impl<const FRAC_BITS: u32> Display for Fixed<FRAC_BITS> {
+    /// Prints the exact decimal expansion, which always terminates since the
+    /// denominator is a power of two
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let magnitude = i128::from(self.bits).unsigned_abs();
+        let mask = (1u128 << FRAC_BITS) - 1;
+        if self.bits < 0 {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", magnitude >> FRAC_BITS)?;
+        let mut fraction = magnitude & mask;
+        if fraction != 0 {
+            f.write_str(".")?;
+            while fraction != 0 {
+                fraction *= 10;
+                write!(f, "{}", fraction >> FRAC_BITS)?;
+                fraction &= mask;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fixed, RoundingMode};
+
+    type Q16 = Fixed<16>;
+
+    fn q(value: f64) -> Q16 {
+        Q16::from_f64(value).unwrap()
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(q(1.5) + q(2.25), q(3.75));
+        assert_eq!(q(1.5) - q(2.25), q(-0.75));
+        assert_eq!(q(1.5) * q(-2.25), q(-3.375));
+        assert_eq!(q(3.0) / q(0.5), q(6.0));
+        assert_eq!(Q16::from_int(7) / Q16::from_int(2), q(3.5));
+        assert_eq!(-q(1.25), q(-1.25));
+        let mut x = Q16::ONE;
+        x *= q(0.5);
+        x += Q16::from(2);
+        x /= q(0.25);
+        assert_eq!(x, Q16::from_int(10));
+        assert!(q(-0.5) < Q16::ZERO && Q16::EPSILON > Q16::ZERO);
+    }
+
+    #[test]
+    fn rounding_modes() {
+        let third = Q16::ONE.div_round(Q16::from_int(3), RoundingMode::Floor);
+        assert_eq!(third.to_bits(), 21_845);
+        let up = Q16::ONE.div_round(Q16::from_int(3), RoundingMode::Ceil);
+        assert_eq!(up.to_bits(), 21_846);
+        assert_eq!((Q16::ONE / Q16::from_int(3)).to_bits(), 21_845);
+
+        let cases = [
+            (2.5, [2, 3, 2, 3, 2]),
+            (3.5, [3, 4, 3, 4, 4]),
+            (-2.5, [-3, -2, -2, -3, -2]),
+            (-2.25, [-3, -2, -2, -2, -2]),
+            (2.75, [2, 3, 2, 3, 3]),
+        ];
+        let modes = [
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero,
+            RoundingMode::Nearest,
+            RoundingMode::NearestEven,
+        ];
+        for (value, expected) in cases {
+            for (mode, want) in modes.into_iter().zip(expected) {
+                assert_eq!(q(value).round_to_int(mode), want, "{value} {mode:?}");
+            }
+        }
+        assert_eq!(q(-0.5).floor(), -1);
+        assert_eq!(q(-0.5).ceil(), 0);
+        // Epsilon-sized products round to zero or to epsilon
+        let tiny = Q16::EPSILON.mul_round(q(0.5), RoundingMode::NearestEven);
+        assert_eq!(tiny, Q16::ZERO);
+        assert_eq!(
+            Q16::EPSILON.mul_round(q(0.5), RoundingMode::Nearest),
+            Q16::EPSILON
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(q(0.1).to_bits(), 6554);
+        assert_eq!(
+            Q16::from_f64_round(0.1, RoundingMode::Floor)
+                .unwrap()
+                .to_bits(),
+            6553
+        );
+        assert_eq!(q(-3.75).to_f64(), -3.75);
+        assert_eq!(Q16::from_f64(f64::NAN), None);
+        assert_eq!(Q16::from_f64(1e15), None);
+        assert_eq!(Fixed::<0>::from_f64(9.3e18), None);
+        assert_eq!(Fixed::<62>::ONE.to_f64(), 1.0);
+        assert_eq!(q(1.5).to_string(), "1.5");
+        assert_eq!(q(-0.0625).to_string(), "-0.0625");
+        assert_eq!(Q16::EPSILON.to_string(), "0.0000152587890625");
+        assert_eq!(Q16::from_int(-12).to_string(), "-12");
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn overflow_panics() {
+        let _ = Q16::from_int(1 << 40) * Q16::from_int(1 << 10);
+    }
+}
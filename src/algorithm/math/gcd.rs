@@ -0,0 +1,133 @@
+use super::{Integer, SignedInteger};
+
+/// Greatest common divisor by Euclid's algorithm, always non-negative.
+///
+/// `gcd(0, 0)` is 0. Panics if the result does not fit, which only happens for
+/// `gcd(MIN, 0)`, `gcd(MIN, MIN)` and `gcd(0, MIN)` on a signed type.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        // Only MIN % -1 fails, and -1 divides everything
+        let r = a.checked_rem(b).unwrap_or(T::ZERO);
+        (a, b) = (b, r);
+    }
+    a.checked_abs().expect("gcd overflows the integer type")
+}
+
+/// Least common multiple, always non-negative; 0 if either argument is 0.
+/// Panics if the result does not fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .and_then(T::checked_abs)
+        .expect("lcm overflows the integer type")
+}
+
+/// Extended Euclid: returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+///
+/// The coefficients satisfy `|x| <= |b| / g` and `|y| <= |a| / g`, so they fit
+/// whenever the inputs do. Bézout coefficients can be negative, so this needs a
+/// signed type. Panics on overflow, which only happens when an argument is `MIN`.
+pub fn ext_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `m` in `0..m`, or None if `gcd(a, m) != 1`.
+///
+/// Works for unsigned types too: the Bézout coefficients of the extended
+/// Euclidean algorithm alternate in sign, so only their magnitudes are kept,
+/// and those never exceed `m`. Panics if `m` is not positive.
+pub fn mod_inverse<T: Integer>(a: T, m: T) -> Option<T> {
+    assert!(m > T::ZERO, "modulus must be positive");
+    let (mut old_r, mut r) = (m, a.rem_euclid(m));
+    // |t| of the coefficients of `a`, whose signs alternate; `negative` is the
+    // sign of `old_t`, which starts at 0 and is followed by a positive 1
+    let (mut old_t, mut t) = (T::ZERO, T::ONE);
+    let mut negative = true;
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_t, t) = (t, old_t + q * t);
+        negative = !negative;
+    }
+    if old_r != T::ONE {
+        None
+    } else if negative && old_t != T::ZERO {
+        Some(m - old_t)
+    } else {
+        Some(old_t.rem_euclid(m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ext_gcd, gcd, lcm, mod_inverse};
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(48u32, 18), 6);
+        assert_eq!(gcd(-48i32, 18), 6);
+        assert_eq!(gcd(48i64, -18), 6);
+        assert_eq!(gcd(0u8, 7), 7);
+        assert_eq!(gcd(0i16, 0), 0);
+        assert_eq!(gcd(i32::MIN, -1), 1);
+        assert_eq!(gcd(i32::MIN, 6), 2);
+        assert_eq!(gcd(u128::MAX, u128::MAX / 5), u128::MAX / 5);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(lcm(0usize, 6), 0);
+        assert_eq!(lcm(255u8, 1), 255);
+    }
+
+    #[test]
+    #[should_panic(expected = "lcm overflows")]
+    fn lcm_panics_on_overflow() {
+        lcm(200u8, 3);
+    }
+
+    #[test]
+    fn ext_gcd_finds_bezout_coefficients() {
+        for a in -60i64..=60 {
+            for b in -60i64..=60 {
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(g, gcd(a, b), "{a} {b}");
+                assert_eq!(a * x + b * y, g, "{a} {b}");
+            }
+        }
+        let (g, x, y) = ext_gcd(i8::MAX, i8::MAX - 1);
+        assert_eq!((g, i16::from(x) * 127 + i16::from(y) * 126), (1, 1));
+    }
+
+    #[test]
+    fn mod_inverse_matches_brute_force() {
+        for m in 1u32..=60 {
+            for a in 0..m {
+                let expected = (0..m).find(|&x| a * x % m == 1 % m);
+                assert_eq!(mod_inverse(a, m), expected, "{a} mod {m}");
+            }
+        }
+        assert_eq!(mod_inverse(-3i32, 7), Some(2));
+        assert_eq!(mod_inverse(4i32, 8), None);
+        assert_eq!(mod_inverse(3u8, 255), None);
+        assert_eq!(mod_inverse(2u8, 255), Some(128));
+        let p = u64::MAX - 58; // the largest 64-bit prime
+        let inv = mod_inverse(123_456_789u64, p).unwrap();
+        assert_eq!(u128::from(inv) * 123_456_789 % u128::from(p), 1);
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Primitive integer types, signed or unsigned, of any width.
+///
+/// Only what the number theory routines need: the arithmetic operators, the
+/// constants 0 and 1, and the few checked operations that differ between
+/// signed and unsigned types.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `self % rhs`, or None when it overflows (`MIN % -1`) or `rhs` is 0
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+
+    /// `self * rhs`, or None on overflow
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// The absolute value, or None for `MIN` of a signed type
+    fn checked_abs(self) -> Option<Self>;
+
+    /// The least non-negative remainder of `self` modulo `rhs`
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+/// Signed integer types, whose values can be negated
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer_unsigned {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                Some(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_integer_signed {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                <$t>::checked_abs(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        }
+
+        impl SignedInteger for $t {})*
+    };
+}
+
+impl_integer_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_integer_signed!(i8, i16, i32, i64, i128, isize);
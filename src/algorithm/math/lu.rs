@@ -0,0 +1,273 @@
+use std::ops::{Div, Neg, Sub};
+
+use super::{Matrix, Rational, Scalar};
+
+/// Scalars that can be divided, as Gaussian elimination needs
+pub trait Field:
+    Scalar + Sub<Output = Self> + Div<Output = Self> + Neg<Output = Self> + PartialOrd
+{
+    /// The size used to pick the largest pivot
+    fn magnitude(&self) -> Self;
+
+    /// Whether the value counts as zero, so it cannot be a pivot
+    fn is_zero(&self) -> bool;
+}
+
+/// Below this, an `f64` pivot is treated as zero
+const F64_EPSILON: f64 = 1e-12;
+
+impl Field for f64 {
+    fn magnitude(&self) -> Self {
+        self.abs()
+    }
+
+    /// Rounding makes exact zeros rare, so anything within 1e-12 counts
+    fn is_zero(&self) -> bool {
+        self.abs() < F64_EPSILON
+    }
+}
+
+impl Field for Rational {
+    fn magnitude(&self) -> Self {
+        self.abs()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Rational::ZERO
+    }
+}
+
+/// An LU decomposition with partial pivoting, `P * A = L * U`, with `L` unit
+/// lower triangular and `U` upper triangular, created by `Matrix::lu`
+#[derive(Debug, Clone)]
+pub struct Lu<T> {
+    /// `L` below the diagonal (its unit diagonal implied) and `U` on and above it
+    factors: Matrix<T>,
+    /// Row `i` of `P * A` is row `permutation[i]` of `A`
+    permutation: Vec<usize>,
+    odd_swaps: bool,
+}
+
+impl<T: Field> Lu<T> {
+    /// Returns the unit lower triangular factor `L`
+    pub fn lower(&self) -> Matrix<T> {
+        let n = self.factors.rows();
+        let mut lower = Matrix::identity(n);
+        for i in 0..n {
+            for j in 0..i {
+                lower[(i, j)] = self.factors[(i, j)].clone();
+            }
+        }
+        lower
+    }
+
+    /// Returns the upper triangular factor `U`
+    pub fn upper(&self) -> Matrix<T> {
+        let n = self.factors.rows();
+        let mut upper = Matrix::new(n, n);
+        for i in 0..n {
+            for j in i..n {
+                upper[(i, j)] = self.factors[(i, j)].clone();
+            }
+        }
+        upper
+    }
+
+    /// Returns the row permutation: row `i` of `P * A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// The determinant, the product of `U`'s diagonal with the sign of the permutation
+    pub fn determinant(&self) -> T {
+        let product =
+            (0..self.factors.rows()).fold(T::one(), |acc, i| acc * self.factors[(i, i)].clone());
+        if self.odd_swaps { -product } else { product }
+    }
+
+    /// Solves `A x = b` by forward then back substitution in O(n^2).
+    /// Panics if `b` has the wrong length
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.factors.rows();
+        assert_eq!(b.len(), n, "right-hand side has the wrong length");
+        // L y = P b
+        let mut x: Vec<T> = self.permutation.iter().map(|&i| b[i].clone()).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i].clone() - self.factors[(i, j)].clone() * x[j].clone();
+            }
+        }
+        // U x = y
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] = x[i].clone() - self.factors[(i, j)].clone() * x[j].clone();
+            }
+            x[i] = x[i].clone() / self.factors[(i, i)].clone();
+        }
+        x
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Decomposes a square matrix by Gaussian elimination with partial
+    /// pivoting, in O(n^3): each column's pivot is the largest remaining entry,
+    /// which keeps floating-point errors small. Returns None if the matrix is
+    /// singular. Panics if it is not square
+    pub fn lu(&self) -> Option<Lu<T>> {
+        assert!(
+            self.is_square(),
+            "only square matrices have an LU decomposition"
+        );
+        let n = self.rows();
+        let mut factors = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut odd_swaps = false;
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| {
+                    let (a, b) = (factors[(a, k)].magnitude(), factors[(b, k)].magnitude());
+                    a.partial_cmp(&b).expect("magnitudes are comparable")
+                })
+                .expect("at least one row remains");
+            if factors[(pivot, k)].is_zero() {
+                return None;
+            }
+            if pivot != k {
+                factors.swap_rows(pivot, k);
+                permutation.swap(pivot, k);
+                odd_swaps = !odd_swaps;
+            }
+            for i in k + 1..n {
+                let multiplier = factors[(i, k)].clone() / factors[(k, k)].clone();
+                for j in k + 1..n {
+                    factors[(i, j)] =
+                        factors[(i, j)].clone() - multiplier.clone() * factors[(k, j)].clone();
+                }
+                factors[(i, k)] = multiplier;
+            }
+        }
+        Some(Lu {
+            factors,
+            permutation,
+            odd_swaps,
+        })
+    }
+
+    /// The determinant, zero for a singular matrix. Panics if not square
+    pub fn determinant(&self) -> T {
+        self.lu().map_or_else(T::zero, |lu| lu.determinant())
+    }
+
+    /// The inverse, or None if the matrix is singular. Panics if not square
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        let lu = self.lu()?;
+        let n = self.rows();
+        let mut inverse = Matrix::new(n, n);
+        for j in 0..n {
+            let unit: Vec<T> = (0..n)
+                .map(|i| if i == j { T::one() } else { T::zero() })
+                .collect();
+            for (i, value) in lu.solve(&unit).into_iter().enumerate() {
+                inverse[(i, j)] = value;
+            }
+        }
+        Some(inverse)
+    }
+
+    /// Solves `A x = b`, or returns None if `A` is singular. Panics if `A` is
+    /// not square or `b` has the wrong length
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        self.lu().map(|lu| lu.solve(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::math::{Matrix, Rational};
+
+    fn close(a: &Matrix<f64>, b: &Matrix<f64>) -> bool {
+        (0..a.rows()).all(|i| (0..a.cols()).all(|j| (a[(i, j)] - b[(i, j)]).abs() < 1e-9))
+    }
+
+    fn hilbert(n: usize) -> Matrix<Rational> {
+        Matrix::from_rows(
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| Rational::new(1, (i + j + 1) as i64))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn solves_f64_systems() {
+        let a = Matrix::from_rows(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ]);
+        let x = a.solve(&[8.0, -11.0, -3.0]).unwrap();
+        for (got, want) in x.iter().zip([2.0, 3.0, -1.0]) {
+            assert!((got - want).abs() < 1e-12);
+        }
+        assert!((a.determinant() - -1.0).abs() < 1e-12);
+        let inverse = a.inverse().unwrap();
+        assert!(close(&(&a * &inverse), &Matrix::identity(3)));
+    }
+
+    #[test]
+    fn lu_reconstructs_the_matrix() {
+        let a = Matrix::from_rows(vec![
+            vec![1.0, 2.0, 3.0, 4.0],
+            vec![2.0, 1.0, 0.5, 7.0],
+            vec![9.0, -3.0, 2.0, 1.0],
+            vec![0.0, 4.0, 4.0, -2.0],
+        ]);
+        let lu = a.lu().unwrap();
+        let mut permuted = Matrix::new(4, 4);
+        for (i, &row) in lu.permutation().iter().enumerate() {
+            for j in 0..4 {
+                permuted[(i, j)] = a[(row, j)];
+            }
+        }
+        assert!(close(&(&lu.lower() * &lu.upper()), &permuted));
+        // Partial pivoting keeps every multiplier at most 1 in size
+        let lower = lu.lower();
+        assert!((0..4).all(|i| (0..i).all(|j| lower[(i, j)].abs() <= 1.0)));
+        assert_eq!(lu.permutation()[0], 2);
+    }
+
+    #[test]
+    fn rational_results_are_exact() {
+        // Hilbert matrices are notoriously ill-conditioned, yet have integer inverses
+        let h = hilbert(5);
+        let inverse = h.inverse().unwrap();
+        assert_eq!(&h * &inverse, Matrix::identity(5));
+        assert_eq!(inverse[(0, 0)], Rational::from(25));
+        assert_eq!(inverse[(4, 4)], Rational::from(44_100));
+        assert!((0..5).all(|i| (0..5).all(|j| inverse[(i, j)].is_integer())));
+        assert_eq!(hilbert(4).determinant(), Rational::new(1, 6_048_000));
+        let b: Vec<Rational> = (1..=5).map(Rational::from).collect();
+        let x = h.solve(&b).unwrap();
+        let check: Vec<Rational> = (0..5)
+            .map(|i| (0..5).fold(Rational::ZERO, |acc, j| acc + h[(i, j)] * x[j]))
+            .collect();
+        assert_eq!(check, b);
+    }
+
+    #[test]
+    fn singular_matrices() {
+        let a = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert!(a.lu().is_none());
+        assert_eq!(a.determinant(), 0.0);
+        assert_eq!(a.inverse(), None);
+        let r = Matrix::from_rows(vec![
+            vec![Rational::from(1), Rational::from(2)],
+            vec![Rational::from(3), Rational::from(6)],
+        ]);
+        assert_eq!(r.solve(&[Rational::ONE, Rational::ONE]), None);
+        assert_eq!(Matrix::<f64>::identity(3).determinant(), 1.0);
+    }
+}
@@ -0,0 +1,456 @@
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{ModInt, Rational, pow_by_squaring};
+
+/// Values that can fill a matrix: anything with addition, multiplication and
+/// their identities
+pub trait Scalar: Clone + Add<Output = Self> + Mul<Output = Self> {
+    fn zero() -> Self;
+
+    fn one() -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($zero:literal, $one:literal; $($t:ty),*) => {
+        $(impl Scalar for $t {
+            fn zero() -> Self {
+                $zero
+            }
+
+            fn one() -> Self {
+                $one
+            }
+        })*
+    };
+}
+
+impl_scalar!(0, 1; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_scalar!(0.0, 1.0; f32, f64);
+
+impl<const M: u64> Scalar for ModInt<M> {
+    fn zero() -> Self {
+        ModInt::new(0)
+    }
+
+    fn one() -> Self {
+        ModInt::new(1)
+    }
+}
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational::ZERO
+    }
+
+    fn one() -> Self {
+        Rational::ONE
+    }
+}
+
+/// A dense matrix stored in row-major order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Creates a `rows` x `cols` matrix of zeros
+    pub fn new(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix {
+            rows,
+            cols,
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// Creates the `n` x `n` identity matrix
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut identity = Matrix::new(n, n);
+        for i in 0..n {
+            identity[(i, i)] = T::one();
+        }
+        identity
+    }
+
+    /// Builds a matrix from its rows. Panics if the rows differ in length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows must have equal lengths"
+        );
+        Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Returns the number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Checks if the matrix has as many rows as columns
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Swaps two rows in place
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows && b < self.rows, "row index out of bounds");
+        for j in 0..self.cols {
+            self.data.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    /// Returns the transpose, with rows and columns swapped
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut transposed = Matrix::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                transposed[(j, i)] = self[(i, j)].clone();
+            }
+        }
+        transposed
+    }
+
+    /// Raises a square matrix to the power `k` in O(n^3 log k).
+    /// Panics if the matrix is not square
+    pub fn pow(&self, k: u64) -> Matrix<T> {
+        assert!(self.is_square(), "only square matrices have powers");
+        pow_by_squaring(self.clone(), k, Matrix::identity(self.rows), |a, b| a * b)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "matrix index out of bounds"
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "matrix index out of bounds"
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Scalar> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// The matrix product in O(n^3). Panics if the inner dimensions differ
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows, "inner dimensions must match");
+        let mut product = Matrix::<T>::new(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = &self[(i, k)];
+                for j in 0..rhs.cols {
+                    product[(i, j)] = product[(i, j)].clone() + a.clone() * rhs[(k, j)].clone();
+                }
+            }
+        }
+        product
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: Scalar + Send + Sync> Matrix<T> {
+    /// The matrix product with the rows of the result computed in parallel.
+    /// Each entry sums its terms in the same order as `*`, so the results are
+    /// identical even for floats. Panics if the inner dimensions differ
+    pub fn par_mul(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows, "inner dimensions must match");
+        let mut product = Matrix::<T>::new(self.rows, rhs.cols);
+        if rhs.cols == 0 {
+            return product;
+        }
+        product
+            .data
+            .par_chunks_mut(rhs.cols)
+            .enumerate()
+            .for_each(|(i, row)| {
+                for k in 0..self.cols {
+                    let a = &self[(i, k)];
+                    for (j, entry) in row.iter_mut().enumerate() {
+                        *entry = entry.clone() + a.clone() * rhs[(k, j)].clone();
+                    }
+                }
+            });
+        product
+    }
+}
+
+impl<T: Scalar> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// The elementwise sum. Panics if the shapes differ
+    fn add(self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "shapes must match"
+        );
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| a.clone() + b.clone())
+                .collect(),
+        }
+    }
+}
+
+impl<T: Scalar + Sub<Output = T>> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// The elementwise difference. Panics if the shapes differ
+    fn sub(self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "shapes must match"
+        );
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| a.clone() - b.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Below this size Strassen's recursion switches to the naive product
+const STRASSEN_CUTOFF: usize = 32;
+
+impl<T: Scalar + Sub<Output = T>> Matrix<T> {
+    /// The matrix product by Strassen's algorithm in O(n^2.81).
+    ///
+    /// Both operands are padded with zeros to a power-of-two square, then
+    /// each level multiplies the quadrants with seven recursive products
+    /// instead of eight. Panics if the inner dimensions differ.
+    pub fn strassen(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows, "inner dimensions must match");
+        let size = self.rows.max(self.cols).max(rhs.cols).next_power_of_two();
+        let product = strassen_square(&self.padded(size), &rhs.padded(size));
+        product.block(0, 0, self.rows, rhs.cols)
+    }
+
+    fn padded(&self, size: usize) -> Matrix<T> {
+        let mut padded = Matrix::new(size, size);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                padded[(i, j)] = self[(i, j)].clone();
+            }
+        }
+        padded
+    }
+
+    /// The `rows` x `cols` block whose top-left corner is at (`row`, `col`)
+    fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> Matrix<T> {
+        let mut block = Matrix::new(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                block[(i, j)] = self[(row + i, col + j)].clone();
+            }
+        }
+        block
+    }
+}
+
+fn strassen_square<T: Scalar + Sub<Output = T>>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let n = a.rows;
+    if n <= STRASSEN_CUTOFF {
+        return a * b;
+    }
+    let h = n / 2;
+    let (a11, a12, a21, a22) = (
+        a.block(0, 0, h, h),
+        a.block(0, h, h, h),
+        a.block(h, 0, h, h),
+        a.block(h, h, h, h),
+    );
+    let (b11, b12, b21, b22) = (
+        b.block(0, 0, h, h),
+        b.block(0, h, h, h),
+        b.block(h, 0, h, h),
+        b.block(h, h, h, h),
+    );
+    let m1 = strassen_square(&(&a11 + &a22), &(&b11 + &b22));
+    let m2 = strassen_square(&(&a21 + &a22), &b11);
+    let m3 = strassen_square(&a11, &(&b12 - &b22));
+    let m4 = strassen_square(&a22, &(&b21 - &b11));
+    let m5 = strassen_square(&(&a11 + &a12), &b22);
+    let m6 = strassen_square(&(&a21 - &a11), &(&b11 + &b12));
+    let m7 = strassen_square(&(&a12 - &a22), &(&b21 + &b22));
+    let quadrants = [
+        &(&(&m1 + &m4) - &m5) + &m7,
+        &m3 + &m5,
+        &m2 + &m4,
+        &(&(&m1 - &m2) + &m3) + &m6,
+    ];
+    let mut product = Matrix::new(n, n);
+    for (q, quadrant) in quadrants.iter().enumerate() {
+        let (row, col) = ((q / 2) * h, (q % 2) * h);
+        for i in 0..h {
+            for j in 0..h {
+                product[(row + i, col + j)] = quadrant[(i, j)].clone();
+            }
+        }
+    }
+    product
+}
+
+/// The `n`th Fibonacci number from the power of `[[1, 1], [1, 0]]`, in O(log n)
+/// multiplications; use a `ModInt` to get it modulo a prime for huge `n`
+pub fn fibonacci_fast<T: Scalar>(n: u64) -> T {
+    let step = Matrix::from_rows(vec![vec![T::one(), T::one()], vec![T::one(), T::zero()]]);
+    step.pow(n)[(0, 1)].clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Matrix, fibonacci_fast};
+    use crate::algorithm::dp::fibonacci_memo;
+    use crate::algorithm::math::ModInt;
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    #[test]
+    fn multiplies_and_powers() {
+        let a = Matrix::from_rows(vec![vec![1i64, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::from_rows(vec![vec![7i64, 8], vec![9, 10], vec![11, 12]]);
+        assert_eq!(
+            &a * &b,
+            Matrix::from_rows(vec![vec![58, 64], vec![139, 154]])
+        );
+        let m = Matrix::from_rows(vec![vec![2i64, 1], vec![0, 3]]);
+        assert_eq!(m.pow(0), Matrix::identity(2));
+        assert_eq!(m.pow(1), m);
+        let mut expected = Matrix::identity(2);
+        for _ in 0..10 {
+            expected = &expected * &m;
+        }
+        assert_eq!(m.pow(10), expected);
+        assert_eq!(m.pow(10)[(1, 1)], 59_049);
+    }
+
+    #[test]
+    fn powers_over_modint() {
+        type Mint = ModInt<1_000_000_007>;
+        let m = Matrix::from_rows(vec![
+            vec![Mint::new(1), Mint::new(1)],
+            vec![Mint::new(1), Mint::new(0)],
+        ]);
+        // The Pisano period modulo 10^9 + 7 divides 2 * (p + 1)
+        assert_eq!(m.pow(2 * 1_000_000_008), Matrix::identity(2));
+    }
+
+    #[test]
+    fn fibonacci_matches_memoized() {
+        for n in 0..=90 {
+            assert_eq!(fibonacci_fast::<u128>(n), fibonacci_memo(n as usize));
+        }
+        assert_eq!(
+            fibonacci_fast::<u128>(180),
+            18_547_707_689_471_986_212_190_138_521_399_707_760
+        );
+        // F(2n) = F(n) * (2 F(n+1) - F(n)), checked far beyond any native width
+        type Mint = ModInt<998_244_353>;
+        let n = 1_000_000_000_000_000_000;
+        let (f, g) = (fibonacci_fast::<Mint>(n), fibonacci_fast::<Mint>(n + 1));
+        assert_eq!(fibonacci_fast::<Mint>(2 * n), f * (g + g - f));
+    }
+
+    #[test]
+    fn adds_and_transposes() {
+        let a = Matrix::from_rows(vec![vec![1i64, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            a.transpose(),
+            Matrix::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(
+            &a + &a,
+            Matrix::from_rows(vec![vec![2, 4, 6], vec![8, 10, 12]])
+        );
+        assert_eq!(&a - &a, Matrix::new(2, 3));
+    }
+
+    #[test]
+    fn strassen_matches_naive() {
+        let mut rng = XorShift64Star::seed_from_u64(0x5EED);
+        let mut random = |rows: usize, cols: usize| {
+            let cells = (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| rng.gen_range(0..21) as i64 - 10)
+                        .collect()
+                })
+                .collect();
+            Matrix::from_rows(cells)
+        };
+        for (n, m, p) in [
+            (1, 1, 1),
+            (3, 5, 2),
+            (33, 33, 33),
+            (70, 45, 90),
+            (128, 128, 128),
+        ] {
+            let (a, b) = (random(n, m), random(m, p));
+            assert_eq!(a.strassen(&b), &a * &b, "{n}x{m} * {m}x{p}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "inner dimensions")]
+    fn rejects_mismatched_shapes() {
+        let a = Matrix::<i64>::new(2, 3);
+        let _ = &a * &a;
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_product_matches() {
+        use crate::algorithm::random::{Rng, XorShift64Star};
+
+        let mut rng = XorShift64Star::seed_from_u64(3);
+        let mut random = |rows: usize, cols: usize| {
+            Matrix::from_rows(
+                (0..rows)
+                    .map(|_| (0..cols).map(|_| rng.gen_f64() - 0.5).collect())
+                    .collect(),
+            )
+        };
+        for (n, m, p) in [(1, 1, 1), (7, 3, 5), (40, 33, 21)] {
+            let (a, b) = (random(n, m), random(m, p));
+            assert_eq!(a.par_mul(&b), &a * &b);
+        }
+        let empty = Matrix::<f64>::new(3, 0);
+        assert_eq!(empty.par_mul(&Matrix::new(0, 4)), Matrix::new(3, 4));
+    }
+}
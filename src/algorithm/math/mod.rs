@@ -0,0 +1,35 @@
+mod combinatorics;
+mod crt;
+mod factorize;
+mod fixed;
+mod gcd;
+mod integer;
+mod lu;
+mod matrix;
+mod modint;
+mod multiplicative;
+mod power;
+mod prefix_sum;
+mod primality;
+mod rational;
+mod sieve;
+
+pub use self::combinatorics::{
+    Combinations, Combinatorics, Permutations, combinations, permutations,
+};
+pub use self::crt::{crt, garner};
+pub use self::factorize::{divisors, factorize};
+pub use self::fixed::{Fixed, RoundingMode};
+pub use self::gcd::{ext_gcd, gcd, lcm, mod_inverse};
+pub use self::integer::{Integer, SignedInteger};
+pub use self::lu::{Field, Lu};
+pub use self::matrix::{Matrix, Scalar, fibonacci_fast};
+pub use self::modint::{DynModInt, ModInt};
+pub use self::multiplicative::{euler_phi, mobius, mobius_table, multiplicative_table, phi_table};
+pub use self::power::{pow_by_squaring, pow_mod};
+#[cfg(feature = "parallel")]
+pub use self::prefix_sum::par_prefix_sum;
+pub use self::prefix_sum::prefix_sum;
+pub use self::primality::{is_prime_u64, is_probable_prime};
+pub use self::rational::Rational;
+pub use self::sieve::{Factors, LinearSieve, SegmentedPrimes, segmented_sieve, sieve};
@@ -0,0 +1,372 @@
+use std::fmt::{self, Display, Formatter};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{mod_inverse, pow_mod};
+
+/// An integer modulo the compile-time modulus `M`, always kept in `0..M`.
+///
+/// Products go through `u128`, so any modulus up to `u64::MAX` works. Division
+/// multiplies by the inverse and panics when there is none.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    const POSITIVE_MODULUS: () = assert!(M > 0, "modulus must be positive");
+
+    /// Creates `value mod M`
+    pub fn new(value: u64) -> Self {
+        let () = Self::POSITIVE_MODULUS;
+        ModInt { value: value % M }
+    }
+
+    /// Returns the representative in `0..M`
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus `M`
+    pub fn modulus(self) -> u64 {
+        M
+    }
+
+    /// Raises to the power `exp` by square-and-multiply
+    pub fn pow(self, exp: u64) -> Self {
+        ModInt {
+            value: pow_mod(self.value, exp, M),
+        }
+    }
+
+    /// Returns the multiplicative inverse, or None if `gcd(value, M) != 1`
+    pub fn inverse(self) -> Option<Self> {
+        mod_inverse(self.value, M).map(|value| ModInt { value })
+    }
+
+    fn add_mod(self, rhs: Self) -> Self {
+        ModInt {
+            value: add_mod(self.value, rhs.value, M),
+        }
+    }
+
+    fn sub_mod(self, rhs: Self) -> Self {
+        ModInt {
+            value: sub_mod(self.value, rhs.value, M),
+        }
+    }
+
+    fn neg_mod(self) -> Self {
+        ModInt {
+            value: sub_mod(0, self.value, M),
+        }
+    }
+
+    fn mul_mod(self, rhs: Self) -> Self {
+        ModInt {
+            value: mul_mod(self.value, rhs.value, M),
+        }
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        ModInt::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        let () = Self::POSITIVE_MODULUS;
+        ModInt {
+            value: i128::from(value).rem_euclid(i128::from(M)) as u64,
+        }
+    }
+}
+
+/// An integer modulo a modulus chosen at runtime, always kept in `0..modulus`.
+///
+/// Behaves like `ModInt`; combining two values with different moduli panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl DynModInt {
+    /// Creates `value mod modulus`. Panics if `modulus` is 0
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be positive");
+        DynModInt {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// Returns the representative in `0..modulus`
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// Raises to the power `exp` by square-and-multiply
+    pub fn pow(self, exp: u64) -> Self {
+        DynModInt {
+            value: pow_mod(self.value, exp, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+
+    /// Returns the multiplicative inverse, or None if `gcd(value, modulus) != 1`
+    pub fn inverse(self) -> Option<Self> {
+        mod_inverse(self.value, self.modulus).map(|value| DynModInt {
+            value,
+            modulus: self.modulus,
+        })
+    }
+
+    fn same_modulus(self, rhs: Self) -> u64 {
+        assert_eq!(self.modulus, rhs.modulus, "moduli differ");
+        self.modulus
+    }
+
+    fn add_mod(self, rhs: Self) -> Self {
+        let modulus = self.same_modulus(rhs);
+        DynModInt {
+            value: add_mod(self.value, rhs.value, modulus),
+            modulus,
+        }
+    }
+
+    fn sub_mod(self, rhs: Self) -> Self {
+        let modulus = self.same_modulus(rhs);
+        DynModInt {
+            value: sub_mod(self.value, rhs.value, modulus),
+            modulus,
+        }
+    }
+
+    fn neg_mod(self) -> Self {
+        DynModInt {
+            value: sub_mod(0, self.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+
+    fn mul_mod(self, rhs: Self) -> Self {
+        let modulus = self.same_modulus(rhs);
+        DynModInt {
+            value: mul_mod(self.value, rhs.value, modulus),
+            modulus,
+        }
+    }
+}
+
+macro_rules! impl_mod_ops {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> Add for $t {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                self.add_mod(rhs)
+            }
+        }
+
+        impl<$($generics)*> Sub for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                self.sub_mod(rhs)
+            }
+        }
+
+        impl<$($generics)*> Mul for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                self.mul_mod(rhs)
+            }
+        }
+
+        impl<$($generics)*> Div for $t {
+            type Output = Self;
+
+            /// Panics if `rhs` has no inverse
+            fn div(self, rhs: Self) -> Self {
+                self.mul_mod(rhs.inverse().expect("divisor is not invertible"))
+            }
+        }
+
+        impl<$($generics)*> Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.neg_mod()
+            }
+        }
+
+        impl<$($generics)*> AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<$($generics)*> SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<$($generics)*> MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<$($generics)*> DivAssign for $t {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<$($generics)*> Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.value)
+            }
+        }
+    };
+}
+
+impl_mod_ops!([const M: u64] ModInt<M>);
+impl_mod_ops!([] DynModInt);
+
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ModInt::new(0), Add::add)
+    }
+}
+
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ModInt::new(1), Mul::mul)
+    }
+}
+
+/// `(a + b) mod m` for `a, b < m`, without overflowing even near `u64::MAX`
+pub(crate) fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    let (sum, carried) = a.overflowing_add(b);
+    if carried || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+/// `(a - b) mod m` for `a, b < m`
+pub(crate) fn sub_mod(a: u64, b: u64, m: u64) -> u64 {
+    if a >= b { a - b } else { m - (b - a) }
+}
+
+/// `(a * b) mod m` through a 128-bit product
+pub(crate) fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(m)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynModInt, ModInt};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    const P: u64 = 998_244_353;
+    type Mint = ModInt<P>;
+
+    #[test]
+    fn arithmetic_matches_u128() {
+        let mut rng = XorShift64Star::seed_from_u64(0x1234_5678);
+        for _ in 0..1000 {
+            let (a, b) = (rng.next_u64(), rng.next_u64());
+            let (x, y) = (Mint::new(a), Mint::new(b));
+            let (a, b) = (u128::from(a % P), u128::from(b % P));
+            let p = u128::from(P);
+            assert_eq!(u128::from((x + y).value()), (a + b) % p);
+            assert_eq!(u128::from((x - y).value()), (a + p - b) % p);
+            assert_eq!(u128::from((x * y).value()), a * b % p);
+            assert_eq!(u128::from((-x).value()), (p - a) % p);
+            if b != 0 {
+                assert_eq!((x / y) * y, x);
+            }
+        }
+    }
+
+    #[test]
+    fn pow_and_inverse() {
+        let x = Mint::new(3);
+        assert_eq!(x.pow(0), Mint::new(1));
+        assert_eq!(x.pow(5).value(), 243);
+        // Fermat's little theorem
+        assert_eq!(x.pow(P - 1), Mint::new(1));
+        assert_eq!(x.inverse(), Some(x.pow(P - 2)));
+        assert_eq!(Mint::new(0).inverse(), None);
+        assert_eq!(ModInt::<12>::new(8).inverse(), None);
+        assert_eq!(ModInt::<12>::new(5).inverse(), Some(ModInt::new(5)));
+        assert_eq!(Mint::from(-1i64).value(), P - 1);
+        assert_eq!(ModInt::<1>::new(5).pow(0).value(), 0);
+    }
+
+    #[test]
+    fn large_moduli_do_not_overflow() {
+        type Big = ModInt<{ u64::MAX - 58 }>;
+        let a = Big::new(u64::MAX - 60);
+        assert_eq!((a + a).value(), u64::MAX - 62);
+        assert_eq!((a - Big::new(u64::MAX - 59)).value(), u64::MAX - 59);
+        assert_eq!((a * a).value(), 4);
+        assert_eq!(a / a, Big::new(1));
+    }
+
+    #[test]
+    fn reads_like_plain_arithmetic_in_dp() {
+        // Binomial coefficients mod P from factorials
+        let n = 1000;
+        let fact: Vec<Mint> = (0..=n)
+            .scan(Mint::new(1), |f, i| {
+                if i > 0 {
+                    *f *= Mint::from(i);
+                }
+                Some(*f)
+            })
+            .collect();
+        let choose = |n: usize, k: usize| fact[n] / (fact[k] * fact[n - k]);
+        assert_eq!(choose(10, 3).value(), 120);
+        let row: Mint = (0..=n as usize).map(|k| choose(n as usize, k)).sum();
+        assert_eq!(row, Mint::new(2).pow(n));
+        assert_eq!((1..=20u64).map(Mint::from).product::<Mint>(), fact[20]);
+    }
+
+    #[test]
+    fn runtime_modulus() {
+        let m = 1_000_000_007;
+        let a = DynModInt::new(m + 5, m);
+        assert_eq!(a.value(), 5);
+        assert_eq!((a * a.inverse().unwrap()).value(), 1);
+        assert_eq!((a - DynModInt::new(6, m)).value(), m - 1);
+        assert_eq!((a / DynModInt::new(5, m)).value(), 1);
+        assert_eq!(a.pow(3).to_string(), "125");
+        assert_eq!((-DynModInt::new(0, m)).value(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "moduli differ")]
+    fn runtime_moduli_must_match() {
+        let _ = DynModInt::new(1, 7) + DynModInt::new(1, 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "not invertible")]
+    fn division_by_non_unit_panics() {
+        let _ = ModInt::<10>::new(3) / ModInt::new(4);
+    }
+}
@@ -0,0 +1,142 @@
+use super::{Scalar, factorize};
+
+/// Values of a multiplicative function `f` for every `n` in `0..=limit`,
+/// from its values on prime powers, in O(limit) with a linear sieve.
+///
+/// `prime_power(p, e)` must return `f(p^e)`. Every other value follows from
+/// `f(a * b) = f(a) * f(b)` for coprime `a` and `b`, splitting off the power
+/// of the smallest prime. `f(1)` is one and index 0 holds zero.
+pub fn multiplicative_table<T, F>(limit: usize, prime_power: F) -> Vec<T>
+where
+    T: Scalar,
+    F: Fn(u64, u32) -> T,
+{
+    // For each n: its smallest prime, that prime's exponent, and n without that prime power
+    let mut smallest = vec![0usize; limit + 1];
+    let mut exponent = vec![0u32; limit + 1];
+    let mut rest = vec![1usize; limit + 1];
+    let mut primes = Vec::new();
+    for i in 2..=limit {
+        if smallest[i] == 0 {
+            smallest[i] = i;
+            exponent[i] = 1;
+            primes.push(i);
+        }
+        for &p in &primes {
+            if p > smallest[i] || i * p > limit {
+                break;
+            }
+            let j = i * p;
+            smallest[j] = p;
+            if p == smallest[i] {
+                exponent[j] = exponent[i] + 1;
+                rest[j] = rest[i];
+            } else {
+                exponent[j] = 1;
+                rest[j] = i;
+            }
+        }
+    }
+
+    let mut values = vec![T::zero(); limit + 1];
+    if limit >= 1 {
+        values[1] = T::one();
+    }
+    for i in 2..=limit {
+        values[i] = if rest[i] == 1 {
+            prime_power(smallest[i] as u64, exponent[i])
+        } else {
+            values[rest[i]].clone() * values[i / rest[i]].clone()
+        };
+    }
+    values
+}
+
+/// Euler's totient: how many of `1..=n` are coprime to `n`, from the prime
+/// factorization as n * prod(1 - 1/p). Panics if `n` is 0
+pub fn euler_phi(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .map(|(p, e)| p.pow(e - 1) * (p - 1))
+        .product()
+}
+
+/// Euler's totient of every number in `0..=limit`, with 0 at index 0
+pub fn phi_table(limit: usize) -> Vec<u64> {
+    multiplicative_table(limit, |p, e| p.pow(e - 1) * (p - 1))
+}
+
+/// The Möbius function: 0 if `n` has a squared prime factor, otherwise
+/// (-1)^k for `k` distinct primes. Panics if `n` is 0
+pub fn mobius(n: u64) -> i8 {
+    let factors = factorize(n);
+    if factors.iter().any(|&(_, e)| e > 1) {
+        0
+    } else if factors.len().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+/// The Möbius function of every number in `0..=limit`, with 0 at index 0
+pub fn mobius_table(limit: usize) -> Vec<i8> {
+    multiplicative_table(limit, |_, e| if e == 1 { -1 } else { 0 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{euler_phi, mobius, mobius_table, multiplicative_table, phi_table};
+    use crate::algorithm::math::{divisors, gcd};
+
+    #[test]
+    fn totient() {
+        assert_eq!(euler_phi(1), 1);
+        assert_eq!(euler_phi(36), 12);
+        assert_eq!(euler_phi(97), 96);
+        assert_eq!(euler_phi(1 << 40), 1 << 39);
+        for n in 1..=300u64 {
+            let coprime = (1..=n).filter(|&k| gcd(k, n) == 1).count() as u64;
+            assert_eq!(euler_phi(n), coprime, "{n}");
+        }
+        let table = phi_table(10_000);
+        assert_eq!(table[0], 0);
+        for (n, &phi) in table.iter().enumerate().skip(1) {
+            assert_eq!(phi, euler_phi(n as u64));
+        }
+        // Gauss: the totients of the divisors of n sum to n
+        for n in 1..=500u64 {
+            assert_eq!(divisors(n).into_iter().map(euler_phi).sum::<u64>(), n);
+        }
+    }
+
+    #[test]
+    fn mobius_function() {
+        let first: Vec<i8> = (1..=10).map(mobius).collect();
+        assert_eq!(first, vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1]);
+        let table = mobius_table(10_000);
+        for (n, &mu) in table.iter().enumerate().skip(1) {
+            assert_eq!(mu, mobius(n as u64));
+        }
+        // Sums over the divisors vanish for every n > 1
+        for n in 2..=500u64 {
+            let sum: i64 = divisors(n).into_iter().map(|d| i64::from(mobius(d))).sum();
+            assert_eq!(sum, 0);
+        }
+    }
+
+    #[test]
+    fn sieves_any_multiplicative_function() {
+        // Number of divisors, d(p^e) = e + 1
+        let tau = multiplicative_table(5000, |_, e| u64::from(e) + 1);
+        // Sum of divisors, sigma(p^e) = 1 + p + ... + p^e
+        let sigma = multiplicative_table(5000, |p, e| (0..=e).map(|k| p.pow(k)).sum::<u64>());
+        for n in 1..=5000u64 {
+            let ds = divisors(n);
+            assert_eq!(tau[n as usize], ds.len() as u64);
+            assert_eq!(sigma[n as usize], ds.iter().sum::<u64>());
+        }
+        assert_eq!(multiplicative_table::<u64, _>(0, |_, _| 7), vec![0]);
+        assert_eq!(multiplicative_table::<u64, _>(1, |_, _| 7), vec![0, 1]);
+    }
+}
@@ -0,0 +1,63 @@
+use super::modint::mul_mod;
+
+/// Raises `base` to `exp` under an associative `mul`, by square-and-multiply.
+///
+/// `identity` is returned for `exp == 0`. Uses O(log exp) multiplications, so
+/// the same routine powers integers, modular integers and matrices.
+pub fn pow_by_squaring<T, F>(base: T, mut exp: u64, identity: T, mul: F) -> T
+where
+    F: Fn(&T, &T) -> T,
+{
+    let mut base = base;
+    let mut result = identity;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul(&result, &base);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = mul(&base, &base);
+        }
+    }
+    result
+}
+
+/// `base^exp mod m`, with 128-bit intermediate products. Panics if `m` is 0
+pub fn pow_mod(base: u64, exp: u64, m: u64) -> u64 {
+    assert!(m > 0, "modulus must be positive");
+    pow_by_squaring(base % m, exp, 1 % m, |&a, &b| mul_mod(a, b, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pow_by_squaring, pow_mod};
+
+    #[test]
+    fn modular_powers() {
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(3, 0, 7), 1);
+        assert_eq!(pow_mod(0, 0, 7), 1);
+        assert_eq!(pow_mod(5, 3, 1), 0);
+        let p = u64::MAX - 58;
+        assert_eq!(pow_mod(123_456_789, p - 1, p), 1);
+        for base in 0..20u64 {
+            for exp in 0..20u32 {
+                let expected = u128::from(base).pow(exp) % 1_000_003;
+                assert_eq!(
+                    u128::from(pow_mod(base, u64::from(exp), 1_000_003)),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn powers_any_associative_operation() {
+        assert_eq!(pow_by_squaring(3u64, 13, 1, |a, b| a * b), 3u64.pow(13));
+        // Repeated string concatenation is associative but not commutative
+        let repeated =
+            pow_by_squaring("ab".to_string(), 5, String::new(), |a, b| format!("{a}{b}"));
+        assert_eq!(repeated, "ab".repeat(5));
+        assert_eq!(pow_by_squaring(7, 0, 1, |a, b| a * b), 1);
+    }
+}
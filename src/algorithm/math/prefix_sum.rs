@@ -0,0 +1,70 @@
+use std::ops::Add;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Replaces each element with the sum of itself and every element before it
+pub fn prefix_sum<T: Copy + Add<Output = T>>(values: &mut [T]) {
+    for i in 1..values.len() {
+        values[i] = values[i - 1] + values[i];
+    }
+}
+
+/// Below this many elements per chunk the passes cost more than they save
+#[cfg(feature = "parallel")]
+const MIN_CHUNK: usize = 4096;
+
+/// `prefix_sum` in two parallel passes: scan each chunk on its own, then add
+/// the total of the chunks before it. `+` must be associative for this to
+/// match the sequential sum, so floating point results may differ slightly.
+#[cfg(feature = "parallel")]
+pub fn par_prefix_sum<T: Copy + Add<Output = T> + Send + Sync>(values: &mut [T]) {
+    let chunk = values
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(MIN_CHUNK);
+    values.par_chunks_mut(chunk).for_each(prefix_sum);
+    let mut offsets = Vec::new();
+    let mut carry: Option<T> = None;
+    for last in values.chunks(chunk).map(|c| c[c.len() - 1]) {
+        offsets.push(carry);
+        carry = Some(carry.map_or(last, |c| c + last));
+    }
+    values
+        .par_chunks_mut(chunk)
+        .zip(offsets)
+        .for_each(|(chunk, offset)| {
+            if let Some(offset) = offset {
+                chunk.iter_mut().for_each(|v| *v = offset + *v);
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prefix_sum;
+
+    #[test]
+    fn running_totals() {
+        let mut values = [3, 1, 4, 1, 5];
+        prefix_sum(&mut values);
+        assert_eq!(values, [3, 4, 8, 9, 14]);
+        prefix_sum::<i32>(&mut []);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_sequential() {
+        use super::par_prefix_sum;
+        use crate::algorithm::random::{Rng, XorShift64Star};
+
+        let mut rng = XorShift64Star::seed_from_u64(7);
+        for len in [0, 1, 4095, 4096, 4097, 50_000] {
+            let input: Vec<i64> = (0..len).map(|_| rng.gen_index(1000) as i64 - 500).collect();
+            let (mut expected, mut actual) = (input.clone(), input);
+            prefix_sum(&mut expected);
+            par_prefix_sum(&mut actual);
+            assert_eq!(actual, expected, "len {len}");
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::algorithm::random::{Rng, XorShift64Star};
+
+use super::modint::mul_mod;
+use super::pow_mod;
+
+/// Witnesses that make Miller–Rabin exact for every 64-bit integer
+const WITNESSES_U64: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Deterministic primality test for 64-bit integers.
+///
+/// Miller–Rabin with the first twelve primes as witnesses has no strong
+/// pseudoprimes below 3.3 * 10^24, so the answer is always correct. Runs in
+/// O(log^3 n) with 128-bit multiplication.
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES_U64 {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let (d, s) = split_even_part(u128::from(n - 1));
+    WITNESSES_U64
+        .iter()
+        .all(|&a| strong_probable_prime(a, d as u64, s, n))
+}
+
+/// Probabilistic primality test for 128-bit integers.
+///
+/// The crate has no big integer type, so `u128` is the widest input. Values
+/// below 2^64 are settled exactly by `is_prime_u64`; larger ones run `rounds`
+/// Miller–Rabin rounds with pseudo-random witnesses, and a composite survives
+/// each round with probability at most 1/4.
+pub fn is_probable_prime(n: u128, rounds: u32) -> bool {
+    if let Ok(small) = u64::try_from(n) {
+        return is_prime_u64(small);
+    }
+    if WITNESSES_U64
+        .iter()
+        .any(|&p| n.is_multiple_of(u128::from(p)))
+    {
+        return false;
+    }
+    let (d, s) = split_even_part(n - 1);
+    // Witnesses come from a generator seeded by n, so results are reproducible
+    let mut rng = XorShift64Star::seed_from_u64(n as u64 ^ (n >> 64) as u64);
+    (0..rounds).all(|_| {
+        // n > 2^64, so every 64-bit draw is a valid witness once shifted past 1
+        let a = 2 + u128::from(rng.next_u64());
+        strong_probable_prime_u128(a, d, s, n)
+    })
+}
+
+/// Writes `m` as `d * 2^s` with `d` odd
+fn split_even_part(m: u128) -> (u128, u32) {
+    let s = m.trailing_zeros();
+    (m >> s, s)
+}
+
+/// Checks whether `n` passes the strong probable prime test to base `a`
+fn strong_probable_prime(a: u64, d: u64, s: u32, n: u64) -> bool {
+    let mut x = pow_mod(a, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = mul_mod(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+    }
+    false
+}
+
+fn strong_probable_prime_u128(a: u128, d: u128, s: u32, n: u128) -> bool {
+    let mut x = pow_mod_u128(a, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = mul_mod_u128(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+    }
+    false
+}
+
+/// `(a + b) mod m` for `a, b < m`
+fn add_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    let (sum, carried) = a.overflowing_add(b);
+    if carried || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+/// `(a * b) mod m` by doubling and adding, since there is no 256-bit product
+fn mul_mod_u128(mut a: u128, mut b: u128, m: u128) -> u128 {
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod_u128(result, a, m);
+        }
+        a = add_mod_u128(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+fn pow_mod_u128(mut base: u128, mut exp: u128, m: u128) -> u128 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod_u128(result, base, m);
+        }
+        base = mul_mod_u128(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_prime_u64, is_probable_prime};
+    use crate::algorithm::math::LinearSieve;
+
+    #[test]
+    fn matches_sieve_for_small_values() {
+        let sieve = LinearSieve::new(200_000);
+        for n in 0..=200_000 {
+            assert_eq!(is_prime_u64(n as u64), sieve.is_prime(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn rejects_carmichael_numbers_and_pseudoprimes() {
+        let carmichael = [
+            561,
+            1105,
+            1729,
+            2465,
+            2821,
+            6601,
+            8911,
+            41041,
+            825_265,
+            321_197_185,
+            5_394_826_801,
+            232_250_619_601,
+            9_746_347_772_161,
+        ];
+        // Strong pseudoprimes to base 2, and to every prime base up to 23
+        let pseudoprimes = [2047, 3_215_031_751, 3_825_123_056_546_413_051];
+        for n in carmichael.into_iter().chain(pseudoprimes) {
+            assert!(!is_prime_u64(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn large_primes() {
+        assert!(is_prime_u64(u64::MAX - 58));
+        assert!(is_prime_u64((1 << 61) - 1));
+        assert!(!is_prime_u64(u64::MAX));
+        assert!(!is_prime_u64(4_294_967_291 * 4_294_967_279));
+    }
+
+    #[test]
+    fn probabilistic_u128() {
+        assert!(is_probable_prime((1 << 89) - 1, 20));
+        assert!(is_probable_prime((1 << 127) - 1, 20));
+        assert!(!is_probable_prime(((1 << 61) - 1) * ((1 << 31) - 1), 20));
+        assert!(!is_probable_prime(
+            u128::from(u64::MAX - 58) * 1_000_000_007,
+            20
+        ));
+        assert!(!is_probable_prime(1 << 100, 20));
+        assert!(is_probable_prime(998_244_353, 1));
+        assert!(!is_probable_prime(561, 1));
+    }
+}
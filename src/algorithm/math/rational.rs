@@ -0,0 +1,317 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::gcd;
+
+/// An exact fraction of two `i64`s, always kept in lowest terms with a
+/// positive denominator, so equal values compare and hash equal.
+///
+/// Arithmetic goes through `i128` and only panics if the reduced result does
+/// not fit back into `i64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i64,
+    denom: i64,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// Creates `numer / denom` in lowest terms. Panics if `denom` is 0
+    pub fn new(numer: i64, denom: i64) -> Rational {
+        Rational::reduce(i128::from(numer), i128::from(denom))
+    }
+
+    /// Returns the numerator, which carries the sign
+    pub fn numer(self) -> i64 {
+        self.numer
+    }
+
+    /// Returns the denominator, which is always positive
+    pub fn denom(self) -> i64 {
+        self.denom
+    }
+
+    /// Checks if the value is a whole number
+    pub fn is_integer(self) -> bool {
+        self.denom == 1
+    }
+
+    /// Returns the absolute value
+    pub fn abs(self) -> Rational {
+        if self.numer < 0 { -self } else { self }
+    }
+
+    /// Returns `1 / self`. Panics if `self` is 0
+    pub fn recip(self) -> Rational {
+        Rational::new(self.denom, self.numer)
+    }
+
+    /// Returns the largest integer not greater than the value
+    pub fn floor(self) -> i64 {
+        self.numer.div_euclid(self.denom)
+    }
+
+    /// Returns the nearest `f64`, which may round
+    pub fn to_f64(self) -> f64 {
+        // Dividing two rounded values could round twice, so shift the quotient
+        // into i128 range first when the parts are too large to be exact
+        if self.numer.unsigned_abs() < 1 << 53 && self.denom < 1 << 53 {
+            self.numer as f64 / self.denom as f64
+        } else {
+            let scaled = (i128::from(self.numer) << 64) / i128::from(self.denom);
+            scaled as f64 / 2f64.powi(64)
+        }
+    }
+
+    /// Returns the value as an `f64` only if the conversion is exact
+    pub fn to_f64_exact(self) -> Option<f64> {
+        let value = self.to_f64();
+        (Rational::from_f64(value) == Some(self)).then_some(value)
+    }
+
+    /// Converts a float exactly, or returns None if it is not finite or its
+    /// exact value does not fit in `i64` numerator and denominator
+    pub fn from_f64(value: f64) -> Option<Rational> {
+        if !value.is_finite() {
+            return None;
+        }
+        if value == 0.0 {
+            return Some(Rational::ZERO);
+        }
+        let bits = value.to_bits();
+        let negative = bits >> 63 == 1;
+        let biased = ((bits >> 52) & 0x7FF) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        // value = mantissa * 2^exponent
+        let (mut mantissa, mut exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+        let zeros = mantissa.trailing_zeros().min(exponent.unsigned_abs()) as i32;
+        if exponent < 0 {
+            mantissa >>= zeros;
+            exponent += zeros;
+        }
+        let (numer, denom) = if exponent >= 0 {
+            let numer = i64::try_from(mantissa).ok()?;
+            (numer.checked_mul(1i64.checked_shl(exponent as u32)?)?, 1)
+        } else if -exponent < 63 {
+            (i64::try_from(mantissa).ok()?, 1i64 << -exponent)
+        } else {
+            return None;
+        };
+        let numer = if negative { -numer } else { numer };
+        Some(Rational::new(numer, denom))
+    }
+
+    fn reduce(numer: i128, denom: i128) -> Rational {
+        assert!(denom != 0, "denominator must not be 0");
+        let g = gcd(numer, denom);
+        let (mut numer, mut denom) = (numer / g, denom / g);
+        if denom < 0 {
+            (numer, denom) = (-numer, -denom);
+        }
+        let fit = |x: i128| i64::try_from(x).expect("rational overflows i64");
+        Rational {
+            numer: fit(numer),
+            denom: fit(denom),
+        }
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational {
+            numer: value,
+            denom: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        let (a, b) = (i128::from(self.numer), i128::from(self.denom));
+        let (c, d) = (i128::from(rhs.numer), i128::from(rhs.denom));
+        Rational::reduce(a * d + c * b, b * d)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        Rational::reduce(
+            i128::from(self.numer) * i128::from(rhs.numer),
+            i128::from(self.denom) * i128::from(rhs.denom),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics if `rhs` is 0
+    fn div(self, rhs: Rational) -> Rational {
+        assert!(rhs.numer != 0, "division by zero");
+        Rational::reduce(
+            i128::from(self.numer) * i128::from(rhs.denom),
+            i128::from(self.denom) * i128::from(rhs.numer),
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational::reduce(-i128::from(self.numer), i128::from(self.denom))
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Rational) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Rational) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, rhs: Rational) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Rational {
+    fn div_assign(&mut self, rhs: Rational) {
+        *self = *self / rhs;
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        // Denominators are positive, so cross-multiplying keeps the order
+        (i128::from(self.numer) * i128::from(other.denom))
+            .cmp(&(i128::from(other.numer) * i128::from(self.denom)))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.denom == 1 {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rational;
+
+    fn r(numer: i64, denom: i64) -> Rational {
+        Rational::new(numer, denom)
+    }
+
+    #[test]
+    fn normalizes() {
+        assert_eq!(r(6, -8), r(-3, 4));
+        assert_eq!(r(6, -8).denom(), 4);
+        assert_eq!(r(0, -5), Rational::ZERO);
+        assert_eq!(r(10, 5), Rational::from(2));
+        assert!(r(10, 5).is_integer());
+        assert_eq!(r(-7, 2).floor(), -4);
+        assert_eq!(r(-7, 2).abs(), r(7, 2));
+        assert_eq!(r(-7, 2).recip(), r(-2, 7));
+        assert_eq!(r(-7, 2).to_string(), "-7/2");
+        assert_eq!(r(4, 2).to_string(), "2");
+    }
+
+    #[test]
+    fn arithmetic_is_exact() {
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+        assert_eq!(-r(2, 3), r(-2, 3));
+        // 1/1 + 1/2 + ... + 1/20 has no rounding error
+        let harmonic: Rational = (1..=20).fold(Rational::ZERO, |acc, k| acc + r(1, k));
+        assert_eq!(harmonic, r(55_835_135, 15_519_504));
+        let mut x = r(1, 3);
+        x *= r(3, 1);
+        x -= Rational::ONE;
+        assert_eq!(x, Rational::ZERO);
+        // Intermediate products beyond i64 are fine if the result fits
+        let big = r(i64::MAX, 3);
+        assert_eq!(big * r(3, i64::MAX), Rational::ONE);
+    }
+
+    #[test]
+    fn orders_by_value() {
+        let mut values = vec![r(1, 2), r(-1, 3), r(2, 3), r(1, 3), r(-1, 2)];
+        values.sort();
+        assert_eq!(values, vec![r(-1, 2), r(-1, 3), r(1, 3), r(1, 2), r(2, 3)]);
+        assert!(r(i64::MAX, i64::MAX - 1) > Rational::ONE);
+    }
+
+    #[test]
+    fn converts_floats_exactly() {
+        assert_eq!(Rational::from_f64(0.75), Some(r(3, 4)));
+        assert_eq!(Rational::from_f64(-2.5), Some(r(-5, 2)));
+        assert_eq!(
+            Rational::from_f64(1e18),
+            Some(Rational::from(1_000_000_000_000_000_000))
+        );
+        // 0.1 is really 3602879701896397 / 2^55
+        assert_eq!(
+            Rational::from_f64(0.1),
+            Some(r(3_602_879_701_896_397, 1 << 55))
+        );
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+        assert_eq!(Rational::from_f64(1e300), None);
+        assert_eq!(Rational::from_f64(1e-300), None);
+        assert_eq!(r(3, 4).to_f64_exact(), Some(0.75));
+        assert_eq!(r(1, 3).to_f64_exact(), None);
+        assert!((r(1, 3).to_f64() - 1.0 / 3.0).abs() < 1e-16);
+        for x in [0.1, -123.456, 0.3, 4503599627370497.0] {
+            assert_eq!(Rational::from_f64(x).unwrap().to_f64(), x);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "rational overflows")]
+    fn overflow_panics() {
+        let _ = r(i64::MAX, 1) + r(i64::MAX, 1);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::data_structure::BitVec;
+
+/// Primes up to and including `n`, by the sieve of Eratosthenes in O(n log log n)
+pub fn sieve(n: usize) -> Vec<usize> {
+    if n < 2 {
+        return Vec::new();
+    }
+    let mut is_prime = BitVec::new(n + 1);
+    for i in 2..=n {
+        is_prime.set(i, true);
+    }
+    let mut p = 2;
+    while p * p <= n {
+        if is_prime.get(p) {
+            // Smaller multiples were already crossed off by smaller primes
+            for multiple in (p * p..=n).step_by(p) {
+                is_prime.set(multiple, false);
+            }
+        }
+        p += 1;
+    }
+    is_prime.iter_ones().collect()
+}
+
+/// A linear sieve up to a limit, which also records every number's smallest
+/// prime factor so numbers up to the limit factor in O(log n).
+///
+/// Each composite is crossed off exactly once, by its smallest prime factor,
+/// so building takes O(n).
+#[derive(Debug, Clone)]
+pub struct LinearSieve {
+    smallest_factor: Vec<usize>,
+    primes: Vec<usize>,
+}
+
+impl LinearSieve {
+    /// Sieves all numbers up to and including `limit`
+    pub fn new(limit: usize) -> LinearSieve {
+        let mut smallest_factor = vec![0; limit + 1];
+        let mut primes = Vec::new();
+        for i in 2..=limit {
+            if smallest_factor[i] == 0 {
+                smallest_factor[i] = i;
+                primes.push(i);
+            }
+            for &p in &primes {
+                if p > smallest_factor[i] || i * p > limit {
+                    break;
+                }
+                smallest_factor[i * p] = p;
+            }
+        }
+        LinearSieve {
+            smallest_factor,
+            primes,
+        }
+    }
+
+    /// Returns the largest number covered
+    pub fn limit(&self) -> usize {
+        self.smallest_factor.len() - 1
+    }
+
+    /// Returns an iterator over the primes up to the limit, ascending
+    pub fn primes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.primes.iter().copied()
+    }
+
+    /// Checks if `n` is prime. Panics if `n` exceeds the limit
+    pub fn is_prime(&self, n: usize) -> bool {
+        n >= 2 && self.smallest_factor(n) == n
+    }
+
+    /// Returns the smallest prime factor of `n`, with 0 for 0 and 1.
+    /// Panics if `n` exceeds the limit
+    pub fn smallest_factor(&self, n: usize) -> usize {
+        assert!(n <= self.limit(), "{n} is beyond the sieve limit");
+        self.smallest_factor[n]
+    }
+
+    /// Returns an iterator over the prime factorization of `n` as ascending
+    /// (prime, exponent) pairs. Panics if `n` is 0 or exceeds the limit
+    pub fn factorize(&self, n: usize) -> Factors<'_> {
+        assert!(n > 0, "0 has no factorization");
+        assert!(n <= self.limit(), "{n} is beyond the sieve limit");
+        Factors {
+            sieve: self,
+            rest: n,
+        }
+    }
+}
+
+/// Iterator over (prime, exponent) pairs, created by `LinearSieve::factorize`
+#[derive(Debug, Clone)]
+pub struct Factors<'a> {
+    sieve: &'a LinearSieve,
+    rest: usize,
+}
+
+impl Iterator for Factors<'_> {
+    type Item = (usize, u32);
+
+    fn next(&mut self) -> Option<(usize, u32)> {
+        if self.rest == 1 {
+            return None;
+        }
+        let p = self.sieve.smallest_factor[self.rest];
+        let mut exponent = 0;
+        while self.rest.is_multiple_of(p) {
+            self.rest /= p;
+            exponent += 1;
+        }
+        Some((p, exponent))
+    }
+}
+
+/// Numbers sieved per segment, small enough to stay in cache
+const SEGMENT: u64 = 1 << 15;
+
+/// Returns an iterator over the primes in `lo..=hi`.
+///
+/// Only the base primes up to sqrt(hi) and one fixed-size segment are held in
+/// memory, so ranges far beyond what a plain sieve could allocate are fine as
+/// long as sqrt(hi) is reasonable.
+pub fn segmented_sieve(lo: u64, hi: u64) -> SegmentedPrimes {
+    let base = sieve(hi.isqrt() as usize)
+        .into_iter()
+        .map(|p| p as u64)
+        .collect();
+    let mut primes = SegmentedPrimes {
+        base,
+        hi,
+        start: lo.max(2),
+        composite: Vec::new(),
+        pos: 0,
+        done: lo.max(2) > hi,
+    };
+    if !primes.done {
+        primes.fill_segment();
+    }
+    primes
+}
+
+/// Iterator over the primes in a range, created by `segmented_sieve`
+#[derive(Debug, Clone)]
+pub struct SegmentedPrimes {
+    base: Vec<u64>,
+    hi: u64,
+    /// First number of the current segment
+    start: u64,
+    composite: Vec<bool>,
+    pos: usize,
+    done: bool,
+}
+
+impl SegmentedPrimes {
+    fn fill_segment(&mut self) {
+        let end = self.start.saturating_add(SEGMENT - 1).min(self.hi);
+        self.composite.clear();
+        self.composite
+            .resize((end - self.start + 1) as usize, false);
+        self.pos = 0;
+        for &p in &self.base {
+            if p * p > end {
+                break;
+            }
+            let Some(first) = self.start.div_ceil(p).checked_mul(p) else {
+                continue;
+            };
+            let mut multiple = first.max(p * p);
+            while multiple <= end {
+                self.composite[(multiple - self.start) as usize] = true;
+                match multiple.checked_add(p) {
+                    Some(next) => multiple = next,
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for SegmentedPrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while !self.done {
+            if let Some(offset) = self.composite[self.pos..].iter().position(|&c| !c) {
+                let n = self.start + (self.pos + offset) as u64;
+                self.pos += offset + 1;
+                return Some(n);
+            }
+            let end = self.start + self.composite.len() as u64 - 1;
+            if end >= self.hi {
+                self.done = true;
+            } else {
+                self.start = end + 1;
+                self.fill_segment();
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearSieve, segmented_sieve, sieve};
+
+    fn is_prime_naive(n: u64) -> bool {
+        n >= 2
+            && (2..)
+                .take_while(|d| d * d <= n)
+                .all(|d| !n.is_multiple_of(d))
+    }
+
+    #[test]
+    fn eratosthenes() {
+        assert_eq!(sieve(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(sieve(1), Vec::<usize>::new());
+        assert_eq!(sieve(2), vec![2]);
+        assert_eq!(sieve(1_000_000).len(), 78_498);
+        let expected: Vec<usize> = (0..2000).filter(|&n| is_prime_naive(n as u64)).collect();
+        assert_eq!(sieve(1999), expected);
+    }
+
+    #[test]
+    fn linear_sieve_matches_eratosthenes() {
+        let linear = LinearSieve::new(100_000);
+        assert_eq!(linear.primes().collect::<Vec<_>>(), sieve(100_000));
+        assert!(linear.is_prime(99_991));
+        assert!(!linear.is_prime(1));
+        assert_eq!(linear.smallest_factor(91), 7);
+        assert_eq!(linear.limit(), 100_000);
+    }
+
+    #[test]
+    fn factorizes_with_smallest_factors() {
+        let linear = LinearSieve::new(10_000);
+        assert_eq!(
+            linear.factorize(360).collect::<Vec<_>>(),
+            vec![(2, 3), (3, 2), (5, 1)]
+        );
+        assert_eq!(linear.factorize(1).count(), 0);
+        assert_eq!(linear.factorize(9973).collect::<Vec<_>>(), vec![(9973, 1)]);
+        for n in 1..=10_000 {
+            let product: usize = linear.factorize(n).map(|(p, e)| p.pow(e)).product();
+            assert_eq!(product, n);
+            assert!(linear.factorize(n).all(|(p, _)| linear.is_prime(p)));
+        }
+    }
+
+    #[test]
+    fn segmented_matches_plain_sieve() {
+        let all: Vec<u64> = sieve(200_000).into_iter().map(|p| p as u64).collect();
+        for (lo, hi) in [(0, 200_000), (1, 1), (2, 2), (100, 90), (65_000, 140_123)] {
+            let expected: Vec<u64> = all
+                .iter()
+                .copied()
+                .filter(|&p| lo <= p && p <= hi)
+                .collect();
+            assert_eq!(segmented_sieve(lo, hi).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn segmented_reaches_large_ranges() {
+        let lo = 1_000_000_000_000;
+        let primes: Vec<u64> = segmented_sieve(lo, lo + 1000).collect();
+        assert_eq!(primes.len(), 37);
+        assert!(primes.iter().all(|&p| is_prime_naive(p)));
+    }
+}
@@ -0,0 +1,14 @@
+pub mod checksum;
+pub mod compression;
+pub mod crypto;
+pub mod dp;
+pub mod ecc;
+pub mod encoding;
+pub mod geometry;
+pub mod graph;
+pub mod math;
+pub mod random;
+pub mod regex;
+pub mod search;
+pub mod sort;
+pub mod string;
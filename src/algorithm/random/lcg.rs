@@ -0,0 +1,38 @@
+use super::Rng;
+
+/// A 64-bit linear congruential generator, `x -> a * x + c mod 2^64`, with
+/// Knuth's MMIX constants.
+///
+/// Fast but statistically weak: the low bits have short periods (bit k
+/// repeats every 2^(k+1) steps), so only the high half of each state is used.
+#[derive(Debug, Clone)]
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+    const INCREMENT: u64 = 1_442_695_040_888_963_407;
+
+    fn step(&mut self) -> u32 {
+        self.state = self
+            .state
+            .wrapping_mul(Lcg::MULTIPLIER)
+            .wrapping_add(Lcg::INCREMENT);
+        (self.state >> 32) as u32
+    }
+}
+
+impl Rng for Lcg {
+    fn seed_from_u64(seed: u64) -> Self {
+        Lcg { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        (u64::from(self.step()) << 32) | u64::from(self.step())
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.step()
+    }
+}
@@ -0,0 +1,72 @@
+use super::Rng;
+
+const N: usize = 624;
+const M: usize = 397;
+
+/// The 32-bit Mersenne Twister MT19937, with period 2^19937 - 1.
+///
+/// A twisted linear recurrence over 624 words, tempered on output. Not
+/// cryptographically secure: 624 consecutive outputs reveal the whole state.
+#[derive(Clone)]
+pub struct MersenneTwister {
+    state: Box<[u32; N]>,
+    index: usize,
+}
+
+impl MersenneTwister {
+    /// Seeds with a 32-bit value, matching the reference `init_genrand`
+    pub fn new(seed: u32) -> MersenneTwister {
+        let mut state = Box::new([0u32; N]);
+        state[0] = seed;
+        for i in 1..N {
+            state[i] = 1_812_433_253u32
+                .wrapping_mul(state[i - 1] ^ (state[i - 1] >> 30))
+                .wrapping_add(i as u32);
+        }
+        MersenneTwister { state, index: N }
+    }
+
+    /// Regenerates all 624 words at once
+    fn twist(&mut self) {
+        for i in 0..N {
+            let y = (self.state[i] & 0x8000_0000) | (self.state[(i + 1) % N] & 0x7FFF_FFFF);
+            let mut next = self.state[(i + M) % N] ^ (y >> 1);
+            if y & 1 == 1 {
+                next ^= 0x9908_B0DF;
+            }
+            self.state[i] = next;
+        }
+        self.index = 0;
+    }
+}
+
+impl std::fmt::Debug for MersenneTwister {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MersenneTwister")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Rng for MersenneTwister {
+    /// Uses the low 32 bits of the seed, since MT19937 is seeded with 32 bits
+    fn seed_from_u64(seed: u64) -> Self {
+        MersenneTwister::new(seed as u32)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        (u64::from(self.next_u32()) << 32) | u64::from(self.next_u32())
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= N {
+            self.twist();
+        }
+        let mut y = self.state[self.index];
+        self.index += 1;
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9D2C_5680;
+        y ^= (y << 15) & 0xEFC6_0000;
+        y ^ (y >> 18)
+    }
+}
@@ -0,0 +1,109 @@
+mod lcg;
+mod mersenne_twister;
+mod pcg;
+mod rng;
+mod xorshift;
+
+pub use self::lcg::Lcg;
+pub use self::mersenne_twister::MersenneTwister;
+pub use self::pcg::Pcg32;
+pub use self::rng::{Rng, entropy};
+pub use self::xorshift::XorShift64Star;
+
+#[cfg(test)]
+mod tests {
+    use super::{Lcg, MersenneTwister, Pcg32, Rng, XorShift64Star};
+
+    fn check_helpers<R: Rng>(mut rng: R) {
+        // Every value in a small range shows up with roughly equal frequency
+        let mut counts = [0usize; 10];
+        for _ in 0..100_000 {
+            counts[rng.gen_range(5..15) as usize - 5] += 1;
+        }
+        assert!(
+            counts.iter().all(|&c| (9_000..11_000).contains(&c)),
+            "{counts:?}"
+        );
+        assert_eq!(rng.gen_range(7..8), 7);
+        assert!((0..1000).all(|_| rng.gen_index(3) < 3));
+
+        let floats: Vec<f64> = (0..10_000).map(|_| rng.gen_f64()).collect();
+        assert!(floats.iter().all(|x| (0.0..1.0).contains(x)));
+        let mean = floats.iter().sum::<f64>() / floats.len() as f64;
+        assert!((mean - 0.5).abs() < 0.02);
+        let heads = (0..10_000).filter(|_| rng.gen_bool(0.25)).count();
+        assert!((2_200..2_800).contains(&heads));
+
+        let mut items: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..100).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+        assert!(rng.choose(&items).is_some_and(|x| *x < 100));
+        assert_eq!(rng.choose::<u32>(&[]), None);
+    }
+
+    #[test]
+    fn helpers_behave_for_every_generator() {
+        check_helpers(Lcg::seed_from_u64(1));
+        check_helpers(XorShift64Star::seed_from_u64(2));
+        check_helpers(Pcg32::seed_from_u64(3));
+        check_helpers(MersenneTwister::seed_from_u64(4));
+        check_helpers(XorShift64Star::from_entropy());
+    }
+
+    #[test]
+    fn seeding_is_deterministic() {
+        let mut a = XorShift64Star::seed_from_u64(0);
+        let mut b = XorShift64Star::seed_from_u64(0);
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+        assert_ne!(a.next_u64(), 0);
+        let mut c = Pcg32::seed_from_u64(1);
+        let mut d = Pcg32::seed_from_u64(2);
+        assert_ne!(
+            (0..4).map(|_| c.next_u32()).collect::<Vec<_>>(),
+            (0..4).map(|_| d.next_u32()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn shuffles_uniformly() {
+        // All 6 orderings of three items appear about equally often
+        let mut rng = Pcg32::seed_from_u64(9);
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..60_000 {
+            let mut items = [0, 1, 2];
+            rng.shuffle(&mut items);
+            *counts.entry(items).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 6);
+        assert!(counts.values().all(|&c| (9_400..10_600).contains(&c)));
+    }
+
+    #[test]
+    fn pcg32_matches_reference_output() {
+        // From the reference pcg32-demo with seed 42 and stream 54
+        let mut rng = Pcg32::new(42, 54);
+        let expected = [
+            0xa15c_02b7,
+            0x7b47_f409,
+            0xba1d_3330,
+            0x83d2_f293,
+            0xbfa4_784b,
+            0xcbed_606e,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u32(), value);
+        }
+    }
+
+    #[test]
+    fn mersenne_twister_matches_reference_output() {
+        let mut rng = MersenneTwister::new(5489);
+        assert_eq!(rng.next_u32(), 3_499_211_612);
+        // The C++ standard requires the 10000th output of the default seed
+        let mut rng = MersenneTwister::new(5489);
+        let last = (0..10_000).map(|_| rng.next_u32()).last();
+        assert_eq!(last, Some(4_123_659_995));
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::ops::Range;
+
+/// A source of pseudo-random bits, with the derived helpers every generator shares.
+///
+/// Implementors only provide `next_u64` and seeding; generators with 32-bit
+/// output override `next_u32` as well.
+pub trait Rng {
+    /// Creates a generator whose whole output is determined by `seed`
+    fn seed_from_u64(seed: u64) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the next 64 random bits
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns the next 32 random bits, from the high half of `next_u64`
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Creates a generator seeded from the process's hash randomness, so runs differ
+    fn from_entropy() -> Self
+    where
+        Self: Sized,
+    {
+        Self::seed_from_u64(entropy())
+    }
+
+    /// Returns a uniform value in `range`. Panics if the range is empty.
+    ///
+    /// Uses Lemire's multiply-and-reject method, which is unbiased and
+    /// usually needs a single draw.
+    fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "cannot sample an empty range");
+        let span = range.end - range.start;
+        let mut product = u128::from(self.next_u64()) * u128::from(span);
+        if (product as u64) < span {
+            // Reject the few low products that would favour some outputs
+            let threshold = span.wrapping_neg() % span;
+            while (product as u64) < threshold {
+                product = u128::from(self.next_u64()) * u128::from(span);
+            }
+        }
+        range.start + (product >> 64) as u64
+    }
+
+    /// Returns a uniform index in `0..len`. Panics if `len` is 0
+    fn gen_index(&mut self, len: usize) -> usize {
+        self.gen_range(0..len as u64) as usize
+    }
+
+    /// Returns a uniform float in `[0, 1)` with 53 random bits
+    fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns true with probability `p`
+    fn gen_bool(&mut self, p: f64) -> bool {
+        self.gen_f64() < p
+    }
+
+    /// Shuffles `items` uniformly in place with the Fisher–Yates algorithm
+    fn shuffle<T>(&mut self, items: &mut [T])
+    where
+        Self: Sized,
+    {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.gen_index(i + 1));
+        }
+    }
+
+    /// Returns a uniformly chosen element, or None if `items` is empty
+    fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T>
+    where
+        Self: Sized,
+    {
+        (!items.is_empty()).then(|| &items[self.gen_index(items.len())])
+    }
+}
+
+/// A seed that differs between runs, taken from the standard library's
+/// randomly keyed hasher
+pub fn entropy() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// SplitMix64, which turns any seed (even 0) into well-mixed state for the
+/// generators that cannot start from arbitrary bits
+pub(super) fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
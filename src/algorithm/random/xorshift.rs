@@ -0,0 +1,29 @@
+use super::Rng;
+use super::rng::split_mix;
+
+/// Marsaglia's xorshift64 scrambled by a final multiplication (xorshift64*).
+///
+/// Three shift-xor steps walk a full 2^64 - 1 period over the nonzero states;
+/// the multiplication hides the linear structure of the raw xorshift output.
+#[derive(Debug, Clone)]
+pub struct XorShift64Star {
+    state: u64,
+}
+
+impl Rng for XorShift64Star {
+    /// Mixes the seed first, since an all-zero state would never leave 0
+    fn seed_from_u64(seed: u64) -> Self {
+        let mut mixer = seed;
+        let state = split_mix(&mut mixer);
+        XorShift64Star {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use super::program::{Inst, Program};
+
+/// A DFA compiled from a regex by subset construction, returned by
+/// `Regex::to_dfa`.
+///
+/// Character classes could cover most of Unicode, so the DFA moves on symbol
+/// classes rather than chars. Splitting the code points at every boundary of
+/// every class in the pattern gives intervals whose chars all behave alike,
+/// and there is one transition per interval.
+#[derive(Debug, Clone)]
+pub struct RegexDfa {
+    /// Sorted first code points of the intervals after the first
+    boundaries: Vec<u32>,
+    /// `transitions[state][interval]`, None for the dead state
+    transitions: Vec<Vec<Option<usize>>>,
+    accepting: Vec<bool>,
+}
+
+impl RegexDfa {
+    pub(super) fn new(program: &Program) -> RegexDfa {
+        let mut boundaries: Vec<u32> = program
+            .insts
+            .iter()
+            .filter_map(|inst| match inst {
+                Inst::Class(class, _) => Some(&class.ranges),
+                _ => None,
+            })
+            .flatten()
+            .flat_map(|&(lo, hi)| [lo, hi + 1])
+            .filter(|&b| b > 0 && b <= char::MAX as u32)
+            .collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        // A representative char of each interval; surrogates have none, but
+        // an interval starting in the gap is represented from its end
+        let representatives: Vec<Option<char>> = std::iter::once(0)
+            .chain(boundaries.iter().copied())
+            .zip(boundaries.iter().map(|&b| b - 1).chain([char::MAX as u32]))
+            .map(|(lo, hi)| (lo..=hi.min(lo + 0x800)).find_map(char::from_u32))
+            .collect();
+
+        let mut seen = vec![false; program.insts.len()];
+        let mut start = Vec::new();
+        program.add_closure(program.start, &mut start, &mut seen);
+        let mut ids = HashMap::from([(start.clone(), 0)]);
+        let mut sets = vec![start];
+        let mut transitions = Vec::new();
+        let mut accepting = Vec::new();
+        let mut i = 0;
+        while i < sets.len() {
+            let set = sets[i].clone();
+            accepting.push(set.iter().any(|&s| program.is_match_state(s)));
+            let row = representatives
+                .iter()
+                .map(|&c| {
+                    let next = program.step(&set, c?);
+                    if next.is_empty() {
+                        return None;
+                    }
+                    let id = *ids.entry(next.clone()).or_insert_with(|| {
+                        sets.push(next);
+                        sets.len() - 1
+                    });
+                    Some(id)
+                })
+                .collect();
+            transitions.push(row);
+            i += 1;
+        }
+        RegexDfa {
+            boundaries,
+            transitions,
+            accepting,
+        }
+    }
+
+    pub fn state_count(&self) -> usize {
+        self.transitions.len()
+    }
+
+    fn interval(&self, c: char) -> usize {
+        self.boundaries.partition_point(|&b| b <= c as u32)
+    }
+
+    /// End of the longest match starting at `start`
+    fn longest_from(&self, text: &str, start: usize) -> Option<usize> {
+        let mut state = 0;
+        let mut end = self.accepting[0].then_some(start);
+        for (offset, c) in text[start..].char_indices() {
+            match self.transitions[state][self.interval(c)] {
+                Some(next) => state = next,
+                None => break,
+            }
+            if self.accepting[state] {
+                end = Some(start + offset + c.len_utf8());
+            }
+        }
+        end
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+
+    /// The leftmost-longest match, trying each start in turn, which is
+    /// quadratic in the worst case but stops as soon as the DFA dies
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        text.char_indices()
+            .map(|(i, _)| i)
+            .chain([text.len()])
+            .find_map(|start| self.longest_from(text, start).map(|end| start..end))
+    }
+}
@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons a pattern fails to parse, each with the byte offset of the problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegexError {
+    /// A '(' without its ')'
+    UnclosedGroup(usize),
+    /// A ')' without its '('
+    UnmatchedParen(usize),
+    /// A '[' without its ']'
+    UnclosedClass(usize),
+    /// A '*', '+' or '?' with nothing before it
+    NothingToRepeat(usize),
+    /// A class range whose end precedes its start, like `[z-a]`
+    InvalidRange(usize),
+    /// A '\' at the end of the pattern
+    TrailingBackslash(usize),
+}
+
+impl Display for RegexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RegexError::UnclosedGroup(at) => write!(f, "unclosed group opened at {at}"),
+            RegexError::UnmatchedParen(at) => write!(f, "unmatched ')' at {at}"),
+            RegexError::UnclosedClass(at) => write!(f, "unclosed character class opened at {at}"),
+            RegexError::NothingToRepeat(at) => {
+                write!(f, "repetition with nothing to repeat at {at}")
+            }
+            RegexError::InvalidRange(at) => write!(f, "invalid class range at {at}"),
+            RegexError::TrailingBackslash(at) => write!(f, "trailing backslash at {at}"),
+        }
+    }
+}
+
+impl Error for RegexError {}
@@ -0,0 +1,9 @@
+mod dfa;
+mod error;
+mod parser;
+mod program;
+mod regex;
+
+pub use self::dfa::RegexDfa;
+pub use self::error::RegexError;
+pub use self::regex::Regex;
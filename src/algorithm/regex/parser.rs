@@ -0,0 +1,227 @@
+use super::RegexError;
+
+/// A set of chars as sorted, disjoint, non-adjacent inclusive ranges of
+/// code points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CharClass {
+    pub(super) ranges: Vec<(u32, u32)>,
+}
+
+impl CharClass {
+    fn new(mut ranges: Vec<(u32, u32)>) -> CharClass {
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (lo, hi) in ranges {
+            match merged.last_mut() {
+                Some(last) if lo <= last.1.saturating_add(1) => last.1 = last.1.max(hi),
+                _ => merged.push((lo, hi)),
+            }
+        }
+        CharClass { ranges: merged }
+    }
+
+    fn single(c: char) -> CharClass {
+        CharClass::new(vec![(c as u32, c as u32)])
+    }
+
+    fn negate(&self) -> CharClass {
+        let mut ranges = Vec::new();
+        let mut next = 0;
+        for &(lo, hi) in &self.ranges {
+            if lo > next {
+                ranges.push((next, lo - 1));
+            }
+            next = hi + 1;
+        }
+        if next <= char::MAX as u32 {
+            ranges.push((next, char::MAX as u32));
+        }
+        CharClass { ranges }
+    }
+
+    pub(super) fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        let i = self.ranges.partition_point(|&(_, hi)| hi < c);
+        self.ranges.get(i).is_some_and(|&(lo, _)| lo <= c)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Node {
+    Empty,
+    Class(CharClass),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+    Star(Box<Node>),
+    Plus(Box<Node>),
+    Optional(Box<Node>),
+}
+
+/// The class behind `\d`, `\w`, `\s` and their negations, or None for
+/// other escapes
+fn shorthand_class(c: char) -> Option<CharClass> {
+    let class = match c.to_ascii_lowercase() {
+        'd' => CharClass::new(vec![('0' as u32, '9' as u32)]),
+        'w' => CharClass::new(
+            [('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')]
+                .map(|(lo, hi)| (lo as u32, hi as u32))
+                .to_vec(),
+        ),
+        's' => CharClass::new(
+            " \t\n\r\x0B\x0C"
+                .chars()
+                .map(|c| (c as u32, c as u32))
+                .collect(),
+        ),
+        _ => return None,
+    };
+    Some(if c.is_ascii_uppercase() {
+        class.negate()
+    } else {
+        class
+    })
+}
+
+fn escaped_char(c: char) -> char {
+    match c {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        other => other,
+    }
+}
+
+/// Recursive-descent parser over the grammar
+///
+/// ```text
+/// alternation := concat ('|' concat)*
+/// concat      := repeat*
+/// repeat      := atom ('*' | '+' | '?')*
+/// atom        := char | '.' | '\' char | '[' class ']' | '(' alternation ')'
+/// ```
+pub(super) struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn parse(pattern: &'a str) -> Result<Node, RegexError> {
+        let mut parser = Parser {
+            chars: pattern.char_indices().peekable(),
+        };
+        let node = parser.alternation()?;
+        match parser.chars.next() {
+            Some((at, _)) => Err(RegexError::UnmatchedParen(at)),
+            None => Ok(node),
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Node, RegexError> {
+        let mut branches = vec![self.concat()?];
+        while self.chars.next_if(|&(_, c)| c == '|').is_some() {
+            branches.push(self.concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            Node::Alternation(branches)
+        })
+    }
+
+    fn concat(&mut self) -> Result<Node, RegexError> {
+        let mut items = Vec::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            items.push(self.repeat()?);
+        }
+        Ok(match items.len() {
+            0 => Node::Empty,
+            1 => items.pop().unwrap(),
+            _ => Node::Concat(items),
+        })
+    }
+
+    fn repeat(&mut self) -> Result<Node, RegexError> {
+        let mut node = self.atom()?;
+        while let Some((_, c)) = self.chars.next_if(|&(_, c)| matches!(c, '*' | '+' | '?')) {
+            node = match c {
+                '*' => Node::Star(Box::new(node)),
+                '+' => Node::Plus(Box::new(node)),
+                _ => Node::Optional(Box::new(node)),
+            };
+        }
+        Ok(node)
+    }
+
+    fn atom(&mut self) -> Result<Node, RegexError> {
+        let (at, c) = self.chars.next().expect("concat checked for input");
+        match c {
+            '*' | '+' | '?' => Err(RegexError::NothingToRepeat(at)),
+            '.' => Ok(Node::Class(CharClass::single('\n').negate())),
+            '\\' => {
+                let (_, escaped) = self.chars.next().ok_or(RegexError::TrailingBackslash(at))?;
+                Ok(Node::Class(shorthand_class(escaped).unwrap_or_else(|| {
+                    CharClass::single(escaped_char(escaped))
+                })))
+            }
+            '[' => self.class(at).map(Node::Class),
+            '(' => {
+                let inner = self.alternation()?;
+                match self.chars.next() {
+                    Some((_, ')')) => Ok(inner),
+                    _ => Err(RegexError::UnclosedGroup(at)),
+                }
+            }
+            literal => Ok(Node::Class(CharClass::single(literal))),
+        }
+    }
+
+    /// Parses a class after its '[': an optional '^', then chars, escapes and
+    /// ranges up to ']'. A '-' first or last is literal
+    fn class(&mut self, open: usize) -> Result<CharClass, RegexError> {
+        let negated = self.chars.next_if(|&(_, c)| c == '^').is_some();
+        let mut ranges = Vec::new();
+        loop {
+            let (at, c) = self.chars.next().ok_or(RegexError::UnclosedClass(open))?;
+            let lo = match c {
+                ']' => break,
+                '\\' => {
+                    let (_, escaped) =
+                        self.chars.next().ok_or(RegexError::TrailingBackslash(at))?;
+                    if let Some(class) = shorthand_class(escaped) {
+                        ranges.extend(class.ranges);
+                        continue;
+                    }
+                    escaped_char(escaped)
+                }
+                c => c,
+            };
+            let is_range = self.chars.peek().is_some_and(|&(_, c)| c == '-');
+            let mut lookahead = self.chars.clone();
+            lookahead.next();
+            let closes = lookahead.peek().is_none_or(|&(_, c)| c == ']');
+            if !is_range || closes {
+                ranges.push((lo as u32, lo as u32));
+                continue;
+            }
+            self.chars.next();
+            let (hi_at, hi) = self.chars.next().ok_or(RegexError::UnclosedClass(open))?;
+            let hi = if hi == '\\' {
+                let (_, escaped) = self
+                    .chars
+                    .next()
+                    .ok_or(RegexError::TrailingBackslash(hi_at))?;
+                escaped_char(escaped)
+            } else {
+                hi
+            };
+            if hi < lo {
+                return Err(RegexError::InvalidRange(at));
+            }
+            ranges.push((lo as u32, hi as u32));
+        }
+        let class = CharClass::new(ranges);
+        Ok(if negated { class.negate() } else { class })
+    }
+}
@@ -0,0 +1,98 @@
+use super::parser::{CharClass, Node};
+
+/// An instruction of a Thompson NFA: consume a char of a class, fork, or accept
+#[derive(Debug, Clone)]
+pub(super) enum Inst {
+    Class(CharClass, usize),
+    Split(usize, usize),
+    Match,
+}
+
+/// The NFA from Thompson's construction: one or two states per operator, with
+/// epsilon moves as `Split`s
+#[derive(Debug, Clone)]
+pub(super) struct Program {
+    pub(super) insts: Vec<Inst>,
+    pub(super) start: usize,
+}
+
+impl Program {
+    pub(super) fn compile(node: &Node) -> Program {
+        let mut insts = vec![Inst::Match];
+        let start = compile(node, 0, &mut insts);
+        Program { insts, start }
+    }
+
+    /// Adds `state` and everything it reaches through splits to `set`, in
+    /// order, skipping states already present
+    pub(super) fn add_closure(&self, state: usize, set: &mut Vec<usize>, seen: &mut [bool]) {
+        if std::mem::replace(&mut seen[state], true) {
+            return;
+        }
+        match self.insts[state] {
+            Inst::Split(a, b) => {
+                self.add_closure(a, set, seen);
+                self.add_closure(b, set, seen);
+            }
+            _ => set.push(state),
+        }
+    }
+
+    /// The states reached from `states` by consuming `c`
+    pub(super) fn step(&self, states: &[usize], c: char) -> Vec<usize> {
+        let mut next = Vec::new();
+        let mut seen = vec![false; self.insts.len()];
+        for &state in states {
+            if let Inst::Class(class, to) = &self.insts[state]
+                && class.contains(c)
+            {
+                self.add_closure(*to, &mut next, &mut seen);
+            }
+        }
+        next
+    }
+
+    pub(super) fn is_match_state(&self, state: usize) -> bool {
+        matches!(self.insts[state], Inst::Match)
+    }
+}
+
+/// Compiles `node` to run before continuing at `next`, returning its entry.
+/// Building back to front means every target is known when an instruction
+/// is created, except a loop's own split, which is patched
+fn compile(node: &Node, next: usize, insts: &mut Vec<Inst>) -> usize {
+    let push = |inst: Inst, insts: &mut Vec<Inst>| {
+        insts.push(inst);
+        insts.len() - 1
+    };
+    match node {
+        Node::Empty => next,
+        Node::Class(class) => push(Inst::Class(class.clone(), next), insts),
+        Node::Concat(items) => items
+            .iter()
+            .rev()
+            .fold(next, |next, item| compile(item, next, insts)),
+        Node::Alternation(branches) => {
+            let entries: Vec<usize> = branches.iter().map(|b| compile(b, next, insts)).collect();
+            let mut entry = *entries.last().unwrap();
+            for &branch in entries.iter().rev().skip(1) {
+                entry = push(Inst::Split(branch, entry), insts);
+            }
+            entry
+        }
+        Node::Star(inner) | Node::Plus(inner) => {
+            let split = push(Inst::Split(usize::MAX, next), insts);
+            let body = compile(inner, split, insts);
+            insts[split] = Inst::Split(body, next);
+            if matches!(node, Node::Star(_)) {
+                split
+            } else {
+                body
+            }
+        }
+        Node::Optional(inner) => {
+            let body = compile(inner, next, insts);
+            push(Inst::Split(body, next), insts)
+        }
+    }
+}
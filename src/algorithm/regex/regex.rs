@@ -0,0 +1,208 @@
+use std::ops::Range;
+
+use super::RegexError;
+use super::dfa::RegexDfa;
+use super::parser::Parser;
+use super::program::{Inst, Program};
+
+/// A compiled regular expression.
+///
+/// Supports concatenation, alternation `|`, grouping `( )`, the repetitions
+/// `*`, `+` and `?`, `.` (any char but newline), classes like `[a-z_]` and
+/// `[^0-9]`, the shorthands `\d`, `\w`, `\s` and their negations, and `\`
+/// to escape a metacharacter. The pattern becomes an NFA by Thompson's
+/// construction, simulated by tracking every current state at once, so
+/// matching never backtracks and takes O(n m) for text length n and pattern
+/// size m.
+#[derive(Debug, Clone)]
+pub struct Regex {
+    program: Program,
+}
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Regex, RegexError> {
+        let ast = Parser::parse(pattern)?;
+        Ok(Regex {
+            program: Program::compile(&ast),
+        })
+    }
+
+    /// Checks if the pattern matches anywhere in `text`
+    pub fn is_match(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+
+    /// Checks if the pattern matches the whole of `text`
+    pub fn is_full_match(&self, text: &str) -> bool {
+        let mut seen = vec![false; self.program.insts.len()];
+        let mut states = Vec::new();
+        self.program
+            .add_closure(self.program.start, &mut states, &mut seen);
+        for c in text.chars() {
+            states = self.program.step(&states, c);
+        }
+        states.iter().any(|&s| self.program.is_match_state(s))
+    }
+
+    /// The byte range of the leftmost match, extended as far as possible.
+    ///
+    /// One pass over the text: a new thread starts at each position until
+    /// something matches, and threads are kept in order of their start, so
+    /// when two reach the same state the earlier start wins.
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        let size = self.program.insts.len();
+        // (state, start) pairs, earliest start first
+        let mut threads: Vec<(usize, usize)> = Vec::new();
+        let mut best: Option<Range<usize>> = None;
+        let positions = text.char_indices().map(Some).chain([None]);
+        for (position, next) in text
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([text.len()])
+            .zip(positions)
+        {
+            if best.is_none() {
+                let mut seen = vec![false; size];
+                for &(state, _) in &threads {
+                    seen[state] = true;
+                }
+                let mut fresh = Vec::new();
+                self.program
+                    .add_closure(self.program.start, &mut fresh, &mut seen);
+                threads.extend(fresh.into_iter().map(|s| (s, position)));
+            }
+            if let Some(&(_, start)) = threads
+                .iter()
+                .find(|&&(s, _)| self.program.is_match_state(s))
+                && best.as_ref().is_none_or(|b| start <= b.start)
+            {
+                best = Some(start..position);
+            }
+            // Threads starting after the best match can no longer win
+            if let Some(b) = &best {
+                threads.retain(|&(_, start)| start <= b.start);
+            }
+            let Some((_, c)) = next else { break };
+            let mut seen = vec![false; size];
+            let mut stepped = Vec::new();
+            for &(state, start) in &threads {
+                let mut reached = Vec::new();
+                if let Inst::Class(class, to) = &self.program.insts[state]
+                    && class.contains(c)
+                {
+                    self.program.add_closure(*to, &mut reached, &mut seen);
+                }
+                stepped.extend(reached.into_iter().map(|s| (s, start)));
+            }
+            threads = stepped;
+            if threads.is_empty() && best.is_some() {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Compiles the pattern further into a DFA
+    pub fn to_dfa(&self) -> RegexDfa {
+        RegexDfa::new(&self.program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Regex;
+    use crate::algorithm::regex::RegexError;
+    use crate::testgen::TestGen;
+
+    #[test]
+    fn matches_basic_operators() {
+        let cases = [
+            ("abc", "xxabcxx", Some(2..5)),
+            ("a|b", "cb", Some(1..2)),
+            ("ab*", "abbbc", Some(0..4)),
+            ("ab+", "ac", None),
+            ("colou?r", "my color", Some(3..8)),
+            ("(ab)+", "xababab", Some(1..7)),
+            ("a.c", "a\nc abc", Some(4..7)),
+            ("[a-c]+", "xxcabz", Some(2..5)),
+            ("[^0-9]+", "123abc456", Some(3..6)),
+            ("\\d+\\.\\d+", "pi is 3.14!", Some(6..10)),
+            ("\\w+@\\w+", "mail: me@host.", Some(6..13)),
+            ("a*", "bbb", Some(0..0)),
+            ("", "abc", Some(0..0)),
+            ("x|", "abc", Some(0..0)),
+            ("(a|ab)(c|bcd)", "abcd", Some(0..4)),
+            ("é+", "caféé!", Some(3..7)),
+        ];
+        for (pattern, text, expected) in cases {
+            let regex = Regex::new(pattern).unwrap();
+            assert_eq!(regex.find(text), expected, "{pattern} in {text:?}");
+            assert_eq!(
+                regex.to_dfa().find(text),
+                expected,
+                "dfa {pattern} in {text:?}"
+            );
+            assert_eq!(regex.is_match(text), expected.is_some());
+        }
+    }
+
+    #[test]
+    fn full_matches() {
+        let regex = Regex::new("(a|b)*abb").unwrap();
+        assert!(regex.is_full_match("babb"));
+        assert!(!regex.is_full_match("babba"));
+        assert!(regex.is_match("babba"));
+        let identifier = Regex::new("[A-Za-z_][A-Za-z0-9_]*").unwrap();
+        assert!(identifier.is_full_match("snake_case_1"));
+        assert!(!identifier.is_full_match("1abc"));
+    }
+
+    #[test]
+    fn pathological_pattern_stays_linear() {
+        // Backtracking engines take exponential time on (a?)^n a^n against a^n
+        let n = 30;
+        let pattern = format!("{}{}", "a?".repeat(n), "a".repeat(n));
+        let regex = Regex::new(&pattern).unwrap();
+        assert!(regex.is_full_match(&"a".repeat(n)));
+        assert!(!regex.is_full_match(&"a".repeat(n - 1)));
+        assert_eq!(regex.find(&"a".repeat(2 * n + 5)), Some(0..2 * n));
+    }
+
+    #[test]
+    fn nfa_and_dfa_agree_on_random_text() {
+        let patterns = ["(a|b)*abb", "a(b|c)*a", "[ab]+c?", "(aa|b)*", "b+a*b"];
+        let mut tg = TestGen::new(0xFACE);
+        for pattern in patterns {
+            let regex = Regex::new(pattern).unwrap();
+            let dfa = regex.to_dfa();
+            for _ in 0..200 {
+                let text = tg.string(12, "abc");
+                assert_eq!(regex.find(&text), dfa.find(&text), "{pattern} in {text}");
+            }
+        }
+    }
+
+    #[test]
+    fn reports_syntax_errors() {
+        assert_eq!(Regex::new("(ab").unwrap_err(), RegexError::UnclosedGroup(0));
+        assert_eq!(
+            Regex::new("ab)").unwrap_err(),
+            RegexError::UnmatchedParen(2)
+        );
+        assert_eq!(Regex::new("[ab").unwrap_err(), RegexError::UnclosedClass(0));
+        assert_eq!(
+            Regex::new("a|*").unwrap_err(),
+            RegexError::NothingToRepeat(2)
+        );
+        assert_eq!(
+            Regex::new("[z-a]").unwrap_err(),
+            RegexError::InvalidRange(1)
+        );
+        assert_eq!(
+            Regex::new("ab\\").unwrap_err(),
+            RegexError::TrailingBackslash(2)
+        );
+        assert!(Regex::new("[-a-]").unwrap().is_full_match("-"));
+        assert!(Regex::new("\\(\\)\\*").unwrap().is_full_match("()*"));
+    }
+}
@@ -0,0 +1,197 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Returns the index of the first element for which `pred` is false.
+///
+/// `arr` must be partitioned: every element satisfying `pred` comes before every
+/// element that does not.
+pub fn partition_point<T, P: FnMut(&T) -> bool>(arr: &[T], mut pred: P) -> usize {
+    let (mut lo, mut hi) = (0, arr.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(&arr[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Returns the index of the first element not less than `target`
+pub fn lower_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    lower_bound_by(arr, |x| x.cmp(target))
+}
+
+/// `lower_bound` where `cmp` orders each element relative to the target
+pub fn lower_bound_by<T, F: FnMut(&T) -> Ordering>(arr: &[T], mut cmp: F) -> usize {
+    partition_point(arr, |x| cmp(x) == Ordering::Less)
+}
+
+/// `lower_bound` on the key extracted from each element
+pub fn lower_bound_by_key<T, K: Ord, F: FnMut(&T) -> K>(arr: &[T], key: &K, mut f: F) -> usize {
+    lower_bound_by(arr, |x| f(x).cmp(key))
+}
+
+/// Returns the index of the first element greater than `target`
+pub fn upper_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    upper_bound_by(arr, |x| x.cmp(target))
+}
+
+/// `upper_bound` where `cmp` orders each element relative to the target
+pub fn upper_bound_by<T, F: FnMut(&T) -> Ordering>(arr: &[T], mut cmp: F) -> usize {
+    partition_point(arr, |x| cmp(x) != Ordering::Greater)
+}
+
+/// `upper_bound` on the key extracted from each element
+pub fn upper_bound_by_key<T, K: Ord, F: FnMut(&T) -> K>(arr: &[T], key: &K, mut f: F) -> usize {
+    upper_bound_by(arr, |x| f(x).cmp(key))
+}
+
+/// Returns the range of indices holding elements equal to `target`
+pub fn equal_range<T: Ord>(arr: &[T], target: &T) -> Range<usize> {
+    equal_range_by(arr, |x| x.cmp(target))
+}
+
+/// `equal_range` where `cmp` orders each element relative to the target
+pub fn equal_range_by<T, F: FnMut(&T) -> Ordering>(arr: &[T], mut cmp: F) -> Range<usize> {
+    let start = lower_bound_by(arr, &mut cmp);
+    let end = start + upper_bound_by(&arr[start..], &mut cmp);
+    start..end
+}
+
+/// `equal_range` on the key extracted from each element
+pub fn equal_range_by_key<T, K: Ord, F: FnMut(&T) -> K>(
+    arr: &[T],
+    key: &K,
+    mut f: F,
+) -> Range<usize> {
+    equal_range_by(arr, |x| f(x).cmp(key))
+}
+
+/// Returns the index of the first element equal to `target`, or None if absent
+pub fn binary_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    binary_search_by(arr, |x| x.cmp(target))
+}
+
+/// `binary_search` where `cmp` orders each element relative to the target
+pub fn binary_search_by<T, F: FnMut(&T) -> Ordering>(arr: &[T], mut cmp: F) -> Option<usize> {
+    let index = lower_bound_by(arr, &mut cmp);
+    (index < arr.len() && cmp(&arr[index]) == Ordering::Equal).then_some(index)
+}
+
+/// `binary_search` on the key extracted from each element
+pub fn binary_search_by_key<T, K: Ord, F: FnMut(&T) -> K>(
+    arr: &[T],
+    key: &K,
+    mut f: F,
+) -> Option<usize> {
+    binary_search_by(arr, |x| f(x).cmp(key))
+}
+
+/// Binary search on the answer: the smallest `x` in `lo..hi` for which the
+/// monotonic predicate holds (false, ..., false, true, ..., true), or None.
+pub fn first_true<P: FnMut(i64) -> bool>(range: Range<i64>, mut pred: P) -> Option<i64> {
+    let (mut lo, mut hi) = (range.start, range.end);
+    let end = hi;
+    while lo < hi {
+        // The span of a full i64 range only fits unsigned
+        let mid = lo + (lo.abs_diff(hi) / 2) as i64;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    (lo < end).then_some(lo)
+}
+
+/// Binary search on the answer: the largest `x` in `lo..hi` for which the
+/// monotonic predicate holds (true, ..., true, false, ..., false), or None.
+pub fn last_true<P: FnMut(i64) -> bool>(range: Range<i64>, mut pred: P) -> Option<i64> {
+    let first_false = first_true(range.clone(), |x| !pred(x)).unwrap_or(range.end);
+    (first_false > range.start).then(|| first_false - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_on_duplicates() {
+        let arr = [1, 2, 2, 2, 5, 7];
+        assert_eq!(lower_bound(&arr, &2), 1);
+        assert_eq!(upper_bound(&arr, &2), 4);
+        assert_eq!(equal_range(&arr, &2), 1..4);
+        assert_eq!(equal_range(&arr, &3), 4..4);
+        assert_eq!(lower_bound(&arr, &0), 0);
+        assert_eq!(upper_bound(&arr, &9), arr.len());
+    }
+
+    #[test]
+    fn binary_search_finds_first_occurrence() {
+        let arr = [1, 3, 3, 3, 8];
+        assert_eq!(binary_search(&arr, &3), Some(1));
+        assert_eq!(binary_search(&arr, &8), Some(4));
+        assert_eq!(binary_search(&arr, &4), None);
+        assert_eq!(binary_search::<i32>(&[], &4), None);
+    }
+
+    #[test]
+    fn key_and_comparator_variants() {
+        let people = [("amy", 21), ("bob", 30), ("cat", 30), ("dan", 45)];
+        assert_eq!(equal_range_by_key(&people, &30, |p| p.1), 1..3);
+        assert_eq!(binary_search_by_key(&people, &45, |p| p.1), Some(3));
+        assert_eq!(lower_bound_by_key(&people, &22, |p| p.1), 1);
+        assert_eq!(upper_bound_by_key(&people, &30, |p| p.1), 3);
+
+        // Descending order via a reversed comparator
+        let desc = [9, 7, 7, 3];
+        assert_eq!(equal_range_by(&desc, |x| 7.cmp(x)), 1..3);
+        assert_eq!(lower_bound_by(&desc, |x| 5.cmp(x)), 3);
+        assert_eq!(upper_bound_by(&desc, |x| 9.cmp(x)), 1);
+        assert_eq!(binary_search_by(&desc, |x| 3.cmp(x)), Some(3));
+    }
+
+    #[test]
+    fn partition_point_splits_predicate() {
+        let arr = [2, 4, 6, 7, 9];
+        assert_eq!(partition_point(&arr, |x| x % 2 == 0), 3);
+        assert_eq!(partition_point(&arr, |_| true), 5);
+        assert_eq!(partition_point(&arr, |_| false), 0);
+    }
+
+    #[test]
+    fn search_on_answer() {
+        // Integer square root: the largest x with x * x <= n
+        let n = 1_000_000_007i64;
+        assert_eq!(last_true(0..n, |x| x * x <= n), Some(31622));
+        // Smallest capacity that ships packages within 5 days
+        let weights = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let days_needed = |cap: i64| {
+            let (mut days, mut load) = (1, 0);
+            for &w in &weights {
+                if load + w > cap {
+                    days += 1;
+                    load = 0;
+                }
+                load += w;
+            }
+            days
+        };
+        assert_eq!(first_true(10..56, |cap| days_needed(cap) <= 5), Some(15));
+        assert_eq!(first_true(0..10, |_| false), None);
+        assert_eq!(last_true(0..10, |_| false), None);
+        assert_eq!(last_true(0..10, |_| true), Some(9));
+    }
+
+    #[test]
+    fn search_on_answer_spans_all_of_i64() {
+        let full = i64::MIN..i64::MAX;
+        assert_eq!(first_true(full.clone(), |x| x >= -7), Some(-7));
+        assert_eq!(first_true(full.clone(), |_| true), Some(i64::MIN));
+        assert_eq!(first_true(full.clone(), |_| false), None);
+        assert_eq!(last_true(full.clone(), |x| x <= 1 << 40), Some(1 << 40));
+        assert_eq!(last_true(full, |_| true), Some(i64::MAX - 1));
+    }
+}
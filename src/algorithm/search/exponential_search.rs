@@ -0,0 +1,105 @@
+use super::lower_bound;
+
+/// Finds `target` in a sorted slice by doubling a bound from the front, then
+/// binary searching the bracketed range.
+///
+/// Takes O(log i) comparisons where i is the target's position, so it beats
+/// plain binary search when matches sit near the start. Returns the index of
+/// the first occurrence, or None.
+pub fn exponential_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    let mut bound = 1;
+    while bound < arr.len() && arr[bound - 1] < *target {
+        bound *= 2;
+    }
+    let lo = bound / 2;
+    let hi = bound.min(arr.len());
+    let index = lo + lower_bound(&arr[lo..hi], target);
+    (index < hi && arr[index] == *target).then_some(index)
+}
+
+/// Exponential search over a sorted sequence of unknown length.
+///
+/// `get(i)` returns the i-th element, or None once `i` is past the end, so this
+/// works on anything indexable without knowing its size up front.
+pub fn exponential_search_unbounded<T, F>(mut get: F, target: &T) -> Option<usize>
+where
+    T: Ord,
+    F: FnMut(usize) -> Option<T>,
+{
+    // Find hi with get(hi) >= target or past the end; the answer lies in [lo, hi]
+    let (mut lo, mut hi) = (0, 1);
+    loop {
+        match get(hi - 1) {
+            Some(x) if x < *target => {
+                lo = hi;
+                // Past the last doubling, the rest of usize is the bracket
+                let Some(doubled) = hi.checked_mul(2) else {
+                    hi = usize::MAX;
+                    break;
+                };
+                hi = doubled;
+            }
+            _ => break,
+        }
+    }
+    // Binary search for the first index whose element is >= target (or missing)
+    let mut hi = hi - 1;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match get(mid) {
+            Some(x) if x < *target => lo = mid + 1,
+            _ => hi = mid,
+        }
+    }
+    (get(lo).as_ref() == Some(target)).then_some(lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{exponential_search, exponential_search_unbounded};
+
+    #[test]
+    fn finds_every_element() {
+        let arr: Vec<i32> = (0..500).map(|i| i * 2).collect();
+        for (i, x) in arr.iter().enumerate() {
+            assert_eq!(exponential_search(&arr, x), Some(i));
+        }
+        assert_eq!(exponential_search(&arr, &-1), None);
+        assert_eq!(exponential_search(&arr, &3), None);
+        assert_eq!(exponential_search(&arr, &1000), None);
+        assert_eq!(exponential_search::<i32>(&[], &0), None);
+    }
+
+    #[test]
+    fn returns_first_of_duplicates() {
+        let arr = [1, 2, 2, 2, 2, 2, 3];
+        assert_eq!(exponential_search(&arr, &2), Some(1));
+    }
+
+    #[test]
+    fn searches_without_knowing_length() {
+        let squares = |i: usize| (i < 10_000).then(|| i * i);
+        assert_eq!(
+            exponential_search_unbounded(squares, &(4321 * 4321)),
+            Some(4321)
+        );
+        assert_eq!(exponential_search_unbounded(squares, &2), None);
+        assert_eq!(
+            exponential_search_unbounded(squares, &(10_000 * 10_000)),
+            None
+        );
+        assert_eq!(exponential_search_unbounded(squares, &0), Some(0));
+        assert_eq!(exponential_search_unbounded(|_| None::<u8>, &0), None);
+    }
+
+    #[test]
+    fn stops_doubling_at_the_end_of_usize() {
+        let identity = |i: usize| Some(i as u128);
+        assert_eq!(exponential_search_unbounded(identity, &u128::MAX), None);
+        let last = usize::MAX as u128 - 1;
+        assert_eq!(
+            exponential_search_unbounded(identity, &last),
+            Some(usize::MAX - 1)
+        );
+    }
+}
@@ -0,0 +1,52 @@
+/// Finds `target` in a sorted slice by splitting the range at Fibonacci
+/// numbers instead of halving it.
+///
+/// Each probe lands about 38% of the way into the remaining range, so the
+/// index arithmetic needs only additions and subtractions, and it still makes
+/// O(log n) comparisons. Returns the index of the first occurrence, or None.
+pub fn fibonacci_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    let mut fibs = vec![1usize, 1];
+    while fibs[fibs.len() - 1] < arr.len() {
+        let next = fibs[fibs.len() - 1] + fibs[fibs.len() - 2];
+        fibs.push(next);
+    }
+
+    // Invariant: the first element >= target lies in lo..=hi
+    let (mut lo, mut hi) = (0, arr.len());
+    let mut k = fibs.len() - 1;
+    while lo < hi {
+        while k > 0 && fibs[k] > hi - lo {
+            k -= 1;
+        }
+        let mid = lo + fibs[k.saturating_sub(1)] - 1;
+        if arr[mid] < *target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo < arr.len() && arr[lo] == *target).then_some(lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fibonacci_search;
+
+    #[test]
+    fn finds_every_element() {
+        for len in [1, 2, 3, 8, 13, 14, 100] {
+            let arr: Vec<u32> = (0..len).map(|i| i * 2 + 1).collect();
+            for (i, x) in arr.iter().enumerate() {
+                assert_eq!(fibonacci_search(&arr, x), Some(i), "len {len}");
+            }
+            assert_eq!(fibonacci_search(&arr, &0), None);
+            assert_eq!(fibonacci_search(&arr, &(len * 2)), None);
+        }
+    }
+
+    #[test]
+    fn empty_and_duplicates() {
+        assert_eq!(fibonacci_search::<u8>(&[], &1), None);
+        assert_eq!(fibonacci_search(&[4, 4, 4, 4, 5], &4), Some(0));
+    }
+}
@@ -0,0 +1,96 @@
+use super::lower_bound;
+
+/// Finds `target` in sorted, roughly uniformly distributed integer keys.
+///
+/// Probes where the target should sit if values were spread evenly, which
+/// takes O(log log n) probes on uniform data. Skewed data can make probes
+/// crawl, so after log2(n) probes the remaining range is binary searched, as
+/// it is when key gaps are too wide for the probe arithmetic in i128.
+/// Returns the index of the first occurrence, or None.
+pub fn interpolation_search<T: Ord + Copy + Into<i128>>(arr: &[T], target: &T) -> Option<usize> {
+    let (mut lo, mut hi) = (0, arr.len());
+    let mut probes_left = arr.len().max(1).ilog2() + 1;
+    let key: i128 = (*target).into();
+
+    while lo < hi {
+        let (first, last): (i128, i128) = (arr[lo].into(), arr[hi - 1].into());
+        if key < first || key > last {
+            return None;
+        }
+        if first == last || probes_left == 0 {
+            break;
+        }
+        probes_left -= 1;
+
+        let offset = key
+            .checked_sub(first)
+            .and_then(|gap| gap.checked_mul((hi - 1 - lo) as i128))
+            .zip(last.checked_sub(first))
+            .map(|(scaled, span)| scaled / span);
+        let Some(offset) = offset else {
+            break;
+        };
+        let probe = lo + offset as usize;
+        match arr[probe].cmp(target) {
+            std::cmp::Ordering::Less => lo = probe + 1,
+            std::cmp::Ordering::Greater => hi = probe,
+            // Keep looking left for the first occurrence
+            std::cmp::Ordering::Equal => hi = probe + 1,
+        }
+        if arr[lo] == *target {
+            return Some(lo);
+        }
+    }
+    let index = lo + lower_bound(&arr[lo..hi], target);
+    (index < hi && arr[index] == *target).then_some(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interpolation_search;
+
+    #[test]
+    fn finds_keys_in_uniform_data() {
+        let arr: Vec<u64> = (0..1000).map(|i| i * 3).collect();
+        for (i, x) in arr.iter().enumerate() {
+            assert_eq!(interpolation_search(&arr, x), Some(i));
+        }
+        assert_eq!(interpolation_search(&arr, &1), None);
+        assert_eq!(interpolation_search(&arr, &3000), None);
+    }
+
+    #[test]
+    fn handles_duplicates_and_skew() {
+        let arr = [1i32, 1, 1, 2, 2, 3, 1000, 1_000_000];
+        assert_eq!(interpolation_search(&arr, &1), Some(0));
+        assert_eq!(interpolation_search(&arr, &2), Some(3));
+        assert_eq!(interpolation_search(&arr, &1000), Some(6));
+        assert_eq!(interpolation_search(&arr, &999), None);
+
+        // Exponentially growing keys defeat interpolation; the fallback still finds them
+        let skewed: Vec<i64> = (0..60).map(|i| 1 << i).collect();
+        for (i, x) in skewed.iter().enumerate() {
+            assert_eq!(interpolation_search(&skewed, x), Some(i));
+        }
+    }
+
+    #[test]
+    fn handles_small_and_negative_inputs() {
+        assert_eq!(interpolation_search::<i8>(&[], &0), None);
+        assert_eq!(interpolation_search(&[5u8], &5), Some(0));
+        assert_eq!(interpolation_search(&[-10i16, -5, 0, 5], &-5), Some(1));
+        assert_eq!(interpolation_search(&[7u8; 10], &7), Some(0));
+    }
+
+    #[test]
+    fn handles_i128_extremes() {
+        let arr = [i128::MIN, -1, 0, 1, i128::MAX];
+        assert_eq!(interpolation_search(&arr[..], &0), Some(2));
+        assert_eq!(interpolation_search(&[i128::MIN, i128::MAX], &0), None);
+        for (i, x) in arr.iter().enumerate() {
+            assert_eq!(interpolation_search(&arr, x), Some(i));
+        }
+        let wide = [0, 1, i128::MAX / 2, i128::MAX];
+        assert_eq!(interpolation_search(&wide, &(i128::MAX / 2)), Some(2));
+    }
+}
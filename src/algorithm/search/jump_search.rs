@@ -0,0 +1,40 @@
+/// Finds `target` in a sorted slice by jumping ahead in blocks of sqrt(n),
+/// then scanning the one block that can contain it.
+///
+/// Takes O(sqrt(n)) comparisons but only ever steps forward, which suits
+/// storage where moving backwards is expensive. Returns the index of the first
+/// occurrence, or None.
+pub fn jump_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    let len = arr.len();
+    let step = len.isqrt().max(1);
+    let mut start = 0;
+    while start < len && arr[(start + step).min(len) - 1] < *target {
+        start += step;
+    }
+    let end = (start + step).min(len);
+    (start..end)
+        .find(|&i| arr[i] >= *target)
+        .filter(|&i| arr[i] == *target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::jump_search;
+
+    #[test]
+    fn finds_elements_across_blocks() {
+        let arr: Vec<i32> = (0..100).map(|i| i * 5).collect();
+        for (i, x) in arr.iter().enumerate() {
+            assert_eq!(jump_search(&arr, x), Some(i));
+        }
+        assert_eq!(jump_search(&arr, &7), None);
+        assert_eq!(jump_search(&arr, &1000), None);
+    }
+
+    #[test]
+    fn finds_first_duplicate_spanning_blocks() {
+        let arr = [1, 2, 2, 2, 2, 2, 2, 2, 9];
+        assert_eq!(jump_search(&arr, &2), Some(1));
+        assert_eq!(jump_search(&[3], &3), Some(0));
+    }
+}
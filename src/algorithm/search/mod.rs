@@ -0,0 +1,69 @@
+mod binary_search;
+mod exponential_search;
+mod fibonacci_search;
+mod interpolation_search;
+mod jump_search;
+mod quickselect;
+mod ternary_search;
+
+pub use self::binary_search::{
+    binary_search, binary_search_by, binary_search_by_key, equal_range, equal_range_by,
+    equal_range_by_key, first_true, last_true, lower_bound, lower_bound_by, lower_bound_by_key,
+    partition_point, upper_bound, upper_bound_by, upper_bound_by_key,
+};
+pub use self::exponential_search::{exponential_search, exponential_search_unbounded};
+pub use self::fibonacci_search::fibonacci_search;
+pub use self::interpolation_search::interpolation_search;
+pub use self::jump_search::jump_search;
+pub use self::quickselect::{median_of_medians, quickselect};
+pub use self::ternary_search::{
+    ternary_search_max, ternary_search_max_f64, ternary_search_min, ternary_search_min_f64,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        binary_search, exponential_search, fibonacci_search, interpolation_search, jump_search,
+    };
+
+    type Search = fn(&[i64], &i64) -> Option<usize>;
+
+    const SEARCHES: [(&str, Search); 5] = [
+        ("binary_search", binary_search),
+        ("exponential_search", exponential_search),
+        ("interpolation_search", interpolation_search),
+        ("jump_search", jump_search),
+        ("fibonacci_search", fibonacci_search),
+    ];
+
+    fn cases() -> Vec<Vec<i64>> {
+        vec![
+            vec![],
+            vec![5],
+            (0..50).collect(),
+            (0..200).map(|i| i * i).collect(),
+            vec![1, 1, 1, 2, 2, 3, 3, 3, 3, 10, 10],
+            vec![-50, -20, -20, 0, 7, 7, 7, 1_000_000],
+            vec![9; 17],
+        ]
+    }
+
+    #[test]
+    fn every_search_agrees_on_every_target() {
+        for arr in cases() {
+            let lo = arr.first().copied().unwrap_or(0) - 2;
+            let hi = arr.last().copied().unwrap_or(0) + 2;
+            let targets = (lo..=hi.min(lo + 500)).chain(arr.iter().copied());
+            for target in targets {
+                let expected = arr.iter().position(|&x| x == target);
+                for (name, search) in SEARCHES {
+                    assert_eq!(
+                        search(&arr, &target),
+                        expected,
+                        "{name} on {arr:?} for {target}"
+                    );
+                }
+            }
+        }
+    }
+}
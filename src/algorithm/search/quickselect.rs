@@ -0,0 +1,114 @@
+use crate::algorithm::random::{Rng, XorShift64Star};
+use crate::algorithm::sort::{insertion_sort, partition_around};
+
+/// Rearranges `arr` so the k-th smallest element (0-based) sits at index `k` and returns it.
+///
+/// Partitions around random pivots for expected O(n) time. Afterwards everything
+/// before `k` is <= the result and everything after is >=. Returns None if `k` is
+/// out of bounds.
+pub fn quickselect<T: Ord>(arr: &mut [T], k: usize) -> Option<&T> {
+    if k >= arr.len() {
+        return None;
+    }
+    let mut rng = XorShift64Star::from_entropy();
+    let (mut lo, mut hi) = (0, arr.len());
+    while hi - lo > 1 {
+        let pivot = rng.gen_index(hi - lo);
+        let p = lo + partition_around(&mut arr[lo..hi], pivot);
+        match k.cmp(&p) {
+            std::cmp::Ordering::Equal => break,
+            std::cmp::Ordering::Less => hi = p,
+            std::cmp::Ordering::Greater => lo = p + 1,
+        }
+    }
+    Some(&arr[k])
+}
+
+/// Deterministic selection with worst-case O(n) time, using the median of
+/// medians of groups of five as the pivot.
+///
+/// Leaves `arr` partitioned around index `k` like `quickselect`. Returns None
+/// if `k` is out of bounds.
+pub fn median_of_medians<T: Ord>(arr: &mut [T], k: usize) -> Option<&T> {
+    if k >= arr.len() {
+        return None;
+    }
+    select(arr, k);
+    Some(&arr[k])
+}
+
+fn select<T: Ord>(mut arr: &mut [T], mut k: usize) {
+    loop {
+        if arr.len() <= 5 {
+            insertion_sort(arr);
+            return;
+        }
+
+        // Move the median of each group of five to the front of the slice
+        let groups = arr.len().div_ceil(5);
+        for g in 0..groups {
+            let start = g * 5;
+            let end = (start + 5).min(arr.len());
+            insertion_sort(&mut arr[start..end]);
+            arr.swap(g, start + (end - start - 1) / 2);
+        }
+        select(&mut arr[..groups], (groups - 1) / 2);
+
+        let p = partition_around(arr, (groups - 1) / 2);
+        match k.cmp(&p) {
+            std::cmp::Ordering::Equal => return,
+            std::cmp::Ordering::Less => arr = &mut arr[..p],
+            std::cmp::Ordering::Greater => {
+                arr = &mut arr[p + 1..];
+                k -= p + 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{median_of_medians, quickselect};
+
+    fn check_selected(arr: &[i32], k: usize, value: i32) {
+        assert_eq!(arr[k], value);
+        assert!(arr[..k].iter().all(|&x| x <= value));
+        assert!(arr[k + 1..].iter().all(|&x| x >= value));
+    }
+
+    #[test]
+    fn selects_every_rank() {
+        let data: Vec<i32> = (0..200).map(|i| (i * 7919) % 211 - 100).collect();
+        let mut sorted = data.clone();
+        sorted.sort();
+        for (k, expected) in sorted.iter().enumerate() {
+            let mut arr = data.clone();
+            assert_eq!(quickselect(&mut arr, k), Some(expected));
+            check_selected(&arr, k, *expected);
+
+            let mut arr = data.clone();
+            assert_eq!(median_of_medians(&mut arr, k), Some(expected));
+            check_selected(&arr, k, *expected);
+        }
+    }
+
+    #[test]
+    fn handles_duplicates_and_sorted_input() {
+        let mut same = vec![4; 100];
+        assert_eq!(quickselect(&mut same, 50), Some(&4));
+        assert_eq!(median_of_medians(&mut same, 99), Some(&4));
+
+        let mut sorted: Vec<i32> = (0..1000).collect();
+        assert_eq!(median_of_medians(&mut sorted, 500), Some(&500));
+        let mut reversed: Vec<i32> = (0..1000).rev().collect();
+        assert_eq!(quickselect(&mut reversed, 0), Some(&0));
+    }
+
+    #[test]
+    fn out_of_bounds_is_none() {
+        let mut arr = vec![1, 2, 3];
+        assert_eq!(quickselect(&mut arr, 3), None);
+        assert_eq!(median_of_medians(&mut arr, 3), None);
+        assert_eq!(quickselect::<u8>(&mut [], 0), None);
+    }
+}
@@ -0,0 +1,119 @@
+use std::ops::Range;
+
+/// Upper bound on float iterations, reached only when `eps` is below f64 resolution
+const MAX_FLOAT_ITERATIONS: usize = 300;
+
+/// Returns the `x` in `range` maximizing a strictly unimodal `f`, or None if the range is empty.
+///
+/// Each step discards a third of the range, so `f` is called O(log n) times.
+pub fn ternary_search_max<T, F>(range: Range<i64>, mut f: F) -> Option<i64>
+where
+    T: PartialOrd,
+    F: FnMut(i64) -> T,
+{
+    if range.is_empty() {
+        return None;
+    }
+    let (mut lo, mut hi) = (range.start, range.end - 1);
+    // The span of a full i64 range only fits unsigned
+    while hi.abs_diff(lo) > 2 {
+        let third = (hi.abs_diff(lo) / 3) as i64;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1 + 1;
+        } else {
+            hi = m2;
+        }
+    }
+    let mut best = lo;
+    let mut best_value = f(lo);
+    for x in lo + 1..=hi {
+        let value = f(x);
+        if value > best_value {
+            best = x;
+            best_value = value;
+        }
+    }
+    Some(best)
+}
+
+/// Returns the `x` in `range` minimizing a strictly unimodal `f`, or None if the range is empty
+pub fn ternary_search_min<T, F>(range: Range<i64>, mut f: F) -> Option<i64>
+where
+    T: PartialOrd,
+    F: FnMut(i64) -> T,
+{
+    ternary_search_max(range, |x| std::cmp::Reverse(f(x)))
+}
+
+/// Returns an `x` within `eps` of the maximizer of a unimodal `f` on `[lo, hi]`
+pub fn ternary_search_max_f64<F: FnMut(f64) -> f64>(lo: f64, hi: f64, eps: f64, mut f: F) -> f64 {
+    let (mut lo, mut hi) = (lo, hi);
+    for _ in 0..MAX_FLOAT_ITERATIONS {
+        if hi - lo <= eps {
+            break;
+        }
+        let third = (hi - lo) / 3.0;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1;
+        } else {
+            hi = m2;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// Returns an `x` within `eps` of the minimizer of a unimodal `f` on `[lo, hi]`
+pub fn ternary_search_min_f64<F: FnMut(f64) -> f64>(lo: f64, hi: f64, eps: f64, mut f: F) -> f64 {
+    ternary_search_max_f64(lo, hi, eps, |x| -f(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_extrema() {
+        assert_eq!(
+            ternary_search_max(-100..100, |x| -(x - 37) * (x - 37)),
+            Some(37)
+        );
+        assert_eq!(ternary_search_min(-100..100, |x| (x + 12).abs()), Some(-12));
+        // Extremum on the boundary
+        assert_eq!(ternary_search_max(0..50, |x| x), Some(49));
+        assert_eq!(ternary_search_min(0..50, |x| x), Some(0));
+        assert_eq!(ternary_search_max(5..6, |x| x), Some(5));
+        assert_eq!(ternary_search_max(5..5, |x| x), None);
+    }
+
+    #[test]
+    fn integer_search_spans_all_of_i64() {
+        let full = i64::MIN..i64::MAX;
+        let peak = ternary_search_max(full.clone(), |x| -(i128::from(x) - 5).abs());
+        assert_eq!(peak, Some(5));
+        assert_eq!(ternary_search_min(full.clone(), |x| x), Some(i64::MIN));
+        assert_eq!(ternary_search_max(full, |x| x), Some(i64::MAX - 1));
+    }
+
+    #[test]
+    fn integer_search_works_on_float_values() {
+        let peak = ternary_search_max(0..300, |x| (x as f64 / 100.0).sin());
+        assert_eq!(peak, Some(157));
+    }
+
+    #[test]
+    fn float_extrema_respect_precision() {
+        let x = ternary_search_max_f64(0.0, 3.0, 1e-9, f64::sin);
+        assert!((x - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+
+        let x = ternary_search_min_f64(-10.0, 10.0, 1e-3, |x| (x - 2.5).powi(2));
+        assert!((x - 2.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn float_search_terminates_with_tiny_eps() {
+        let x = ternary_search_min_f64(0.0, 1.0, 0.0, |x| (x - 0.3).abs());
+        assert!((x - 0.3).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,56 @@
+use super::SortStats;
+
+/// Sorts `arr` in place by repeatedly swapping adjacent out-of-order pairs
+pub fn bubble_sort<T: Ord>(arr: &mut [T]) {
+    bubble_sort_counted(arr, &mut SortStats::new());
+}
+
+/// Bubble sort that records its comparisons and swaps in `stats`.
+///
+/// Stops early once a pass makes no swaps, so sorted input costs n - 1 comparisons.
+pub fn bubble_sort_counted<T: Ord>(arr: &mut [T], stats: &mut SortStats) {
+    let mut end = arr.len();
+    while end > 1 {
+        let mut swapped = false;
+        for i in 1..end {
+            if stats.less(&arr[i], &arr[i - 1]) {
+                stats.swap(arr, i - 1, i);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+        end -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SortStats, bubble_sort, bubble_sort_counted};
+
+    #[test]
+    fn sorts_numbers() {
+        let mut arr = vec![5, 2, 9, -1, 0, 2];
+        bubble_sort(&mut arr);
+        assert_eq!(arr, vec![-1, 0, 2, 2, 5, 9]);
+    }
+
+    #[test]
+    fn sorted_input_is_linear() {
+        let mut arr: Vec<i32> = (0..10).collect();
+        let mut stats = SortStats::new();
+        bubble_sort_counted(&mut arr, &mut stats);
+        assert_eq!(stats.comparisons, 9);
+        assert_eq!(stats.swaps, 0);
+    }
+
+    #[test]
+    fn reversed_input_swaps_every_pair() {
+        let mut arr: Vec<i32> = (0..10).rev().collect();
+        let mut stats = SortStats::new();
+        bubble_sort_counted(&mut arr, &mut stats);
+        assert_eq!(arr, (0..10).collect::<Vec<_>>());
+        assert_eq!(stats.swaps, 45);
+    }
+}
@@ -0,0 +1,75 @@
+use super::SortError;
+
+/// The most buckets `bucket_sort` will allocate, however long the input is
+pub const MAX_BUCKETS: usize = 1 << 20;
+
+/// Sorts floats drawn from [0, 1) by scattering them into equal-width buckets.
+///
+/// Expected O(n) time for uniformly distributed input. Fails without touching
+/// `arr` if any value is NaN or outside [0, 1).
+pub fn bucket_sort(arr: &mut [f64]) -> Result<(), SortError> {
+    if let Some(&bad) = arr.iter().find(|x| !(0.0..1.0).contains(*x)) {
+        return Err(SortError::OutOfRange(bad));
+    }
+    if arr.len() < 2 {
+        return Ok(());
+    }
+
+    let bucket_count = arr.len().min(MAX_BUCKETS);
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); bucket_count];
+    for &x in arr.iter() {
+        let index = ((x * bucket_count as f64) as usize).min(bucket_count - 1);
+        buckets[index].push(x);
+    }
+
+    let mut pos = 0;
+    for bucket in buckets.iter_mut() {
+        // Buckets are short on average, so insertion sort is the right tool
+        for i in 1..bucket.len() {
+            let mut j = i;
+            while j > 0 && bucket[j] < bucket[j - 1] {
+                bucket.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+        arr[pos..pos + bucket.len()].copy_from_slice(bucket);
+        pos += bucket.len();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SortError, bucket_sort};
+
+    #[test]
+    fn sorts_unit_interval_values() {
+        let mut arr = vec![
+            0.78, 0.17, 0.39, 0.26, 0.72, 0.94, 0.21, 0.12, 0.23, 0.68, 0.0,
+        ];
+        bucket_sort(&mut arr).unwrap();
+        assert_eq!(
+            arr,
+            vec![
+                0.0, 0.12, 0.17, 0.21, 0.23, 0.26, 0.39, 0.68, 0.72, 0.78, 0.94
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_clustered_values() {
+        let mut arr: Vec<f64> = (0..100).rev().map(|i| 0.5 + i as f64 * 1e-6).collect();
+        bucket_sort(&mut arr).unwrap();
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn rejects_values_outside_unit_interval() {
+        let mut arr = vec![0.5, 1.0, 0.25];
+        assert_eq!(bucket_sort(&mut arr), Err(SortError::OutOfRange(1.0)));
+        assert_eq!(arr, vec![0.5, 1.0, 0.25]);
+
+        let mut arr = vec![f64::NAN];
+        assert!(bucket_sort(&mut arr).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use super::SortError;
+
+/// The largest key range `counting_sort` will allocate counters for
+pub const MAX_COUNTING_RANGE: usize = 1 << 24;
+
+/// Stably sorts `arr` by the integer returned from `key`.
+///
+/// Runs in O(n + k) time where k is the span between the smallest and largest key,
+/// and fails instead of allocating when k exceeds `MAX_COUNTING_RANGE`.
+pub fn counting_sort<T, F>(arr: &mut [T], key: F) -> Result<(), SortError>
+where
+    F: Fn(&T) -> i64,
+{
+    let keys: Vec<i64> = arr.iter().map(&key).collect();
+    let (Some(&min), Some(&max)) = (keys.iter().min(), keys.iter().max()) else {
+        return Ok(());
+    };
+    let range = (max as i128 - min as i128 + 1) as u128;
+    if range > MAX_COUNTING_RANGE as u128 {
+        return Err(SortError::KeyRangeTooLarge {
+            range,
+            limit: MAX_COUNTING_RANGE,
+        });
+    }
+
+    let mut counts = vec![0usize; range as usize];
+    for &k in keys.iter() {
+        counts[(k - min) as usize] += 1;
+    }
+    let mut total = 0;
+    for count in counts.iter_mut() {
+        let current = *count;
+        *count = total;
+        total += current;
+    }
+
+    // Compute every element's final slot, then apply the permutation in place
+    let mut dest: Vec<usize> = keys
+        .iter()
+        .map(|&k| {
+            let slot = &mut counts[(k - min) as usize];
+            *slot += 1;
+            *slot - 1
+        })
+        .collect();
+    for i in 0..arr.len() {
+        while dest[i] != i {
+            let j = dest[i];
+            arr.swap(i, j);
+            dest.swap(i, j);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_COUNTING_RANGE, SortError, counting_sort};
+
+    #[test]
+    fn sorts_integers_with_negatives() {
+        let mut arr = vec![4, -2, 2, 8, 3, 3, -1];
+        counting_sort(&mut arr, |&x| x).unwrap();
+        assert_eq!(arr, vec![-2, -1, 2, 3, 3, 4, 8]);
+    }
+
+    #[test]
+    fn is_stable_by_key() {
+        let mut people = vec![
+            ("eve", 31),
+            ("bob", 25),
+            ("amy", 31),
+            ("dan", 25),
+            ("cat", 40),
+        ];
+        counting_sort(&mut people, |&(_, age)| age).unwrap();
+        assert_eq!(
+            people,
+            vec![
+                ("bob", 25),
+                ("dan", 25),
+                ("eve", 31),
+                ("amy", 31),
+                ("cat", 40)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_huge_key_range() {
+        let mut arr = vec![0, i64::MAX];
+        let err = counting_sort(&mut arr, |&x| x).unwrap_err();
+        assert!(
+            matches!(err, SortError::KeyRangeTooLarge { limit, .. } if limit == MAX_COUNTING_RANGE)
+        );
+        assert_eq!(arr, vec![0, i64::MAX]);
+    }
+
+    #[test]
+    fn empty_input_is_ok() {
+        let mut arr: Vec<u8> = vec![];
+        assert_eq!(counting_sort(&mut arr, |&x| x as i64), Ok(()));
+    }
+}
@@ -0,0 +1,370 @@
+use std::cmp::Reverse;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::iter::FusedIterator;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::data_structure::BinaryHeap;
+
+/// A record that can be spilled to disk and read back by `external_sort`
+pub trait Record: Ord + Sized {
+    /// Writes the record to `writer`
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Reads the next record from `reader`, or returns None at a clean end of input
+    fn read_from<R: Read>(reader: &mut R) -> io::Result<Option<Self>>;
+}
+
+macro_rules! impl_record_for_int {
+    ($($t:ty),*) => {
+        $(impl Record for $t {
+            fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+
+            fn read_from<R: Read>(reader: &mut R) -> io::Result<Option<Self>> {
+                let mut bytes = [0u8; size_of::<$t>()];
+                Ok(read_exact_or_eof(reader, &mut bytes)?.then(|| <$t>::from_le_bytes(bytes)))
+            }
+        })*
+    };
+}
+
+impl_record_for_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Record for Vec<u8> {
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.len() as u64).write_to(writer)?;
+        writer.write_all(self)
+    }
+
+    fn read_from<R: Read>(reader: &mut R) -> io::Result<Option<Self>> {
+        let Some(len) = u64::read_from(reader)? else {
+            return Ok(None);
+        };
+        let mut bytes = vec![0u8; len as usize];
+        reader.read_exact(&mut bytes)?;
+        Ok(Some(bytes))
+    }
+}
+
+impl Record for String {
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.len() as u64).write_to(writer)?;
+        writer.write_all(self.as_bytes())
+    }
+
+    fn read_from<R: Read>(reader: &mut R) -> io::Result<Option<Self>> {
+        match Vec::<u8>::read_from(reader)? {
+            Some(bytes) => String::from_utf8(bytes)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Fills `buf`, returning false if the reader was already at end of input
+fn read_exact_or_eof<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<bool> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(false),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(true)
+}
+
+static NEXT_SORT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Sorts `records` holding at most `run_capacity` of them in memory at once,
+/// spilling sorted runs to the system temporary directory.
+///
+/// See `external_sort_in` for details.
+pub fn external_sort<T, I>(records: I, run_capacity: usize) -> io::Result<ExternalSortIter<T>>
+where
+    T: Record,
+    I: IntoIterator<Item = T>,
+{
+    external_sort_in(records, run_capacity, std::env::temp_dir())
+}
+
+/// Sorts `records` holding at most `run_capacity` of them in memory at once.
+///
+/// The input is cut into runs of `run_capacity` records, each run is sorted in
+/// memory and written to a temporary file in `dir`, and the runs are then
+/// k-way merged through a `BinaryHeap` holding one record per run. Input that
+/// fits in a single run is never written to disk; telling that apart from a
+/// longer input reads one record ahead of the first run. The sort is stable,
+/// and the temporary files are removed when the returned iterator is dropped.
+/// Panics if `run_capacity` is zero.
+///
+/// A read error while merging is yielded after the record that was already
+/// taken from the heap, so no record is lost to it. The error ends the
+/// iteration: the failed run's remaining records cannot be merged in order,
+/// so every later call returns None rather than a truncated stream.
+pub fn external_sort_in<T, I, P>(
+    records: I,
+    run_capacity: usize,
+    dir: P,
+) -> io::Result<ExternalSortIter<T>>
+where
+    T: Record,
+    I: IntoIterator<Item = T>,
+    P: AsRef<Path>,
+{
+    assert!(run_capacity > 0, "run_capacity must be positive");
+    let sort_id = NEXT_SORT_ID.fetch_add(1, Ordering::Relaxed);
+    let mut records = records.into_iter().peekable();
+    // Built up front so that run files are cleaned up if a later step fails
+    let mut merge = Merge {
+        readers: Vec::new(),
+        heap: BinaryHeap::new(),
+        paths: Vec::new(),
+        error: None,
+    };
+    let mut run: Vec<T> = Vec::with_capacity(run_capacity);
+
+    loop {
+        run.extend(records.by_ref().take(run_capacity));
+        if merge.paths.is_empty() && records.peek().is_none() {
+            run.sort();
+            return Ok(ExternalSortIter {
+                source: Source::InMemory(std::mem::take(&mut run).into_iter()),
+            });
+        }
+        if run.is_empty() {
+            break;
+        }
+
+        run.sort();
+        let path = dir.as_ref().join(format!(
+            "rs-cs-external-sort-{}-{sort_id}-{}.run",
+            std::process::id(),
+            merge.paths.len()
+        ));
+        merge.paths.push(path.clone());
+        let mut writer = BufWriter::new(File::create(&path)?);
+        for record in run.drain(..) {
+            record.write_to(&mut writer)?;
+        }
+        writer.flush()?;
+    }
+
+    for (index, path) in merge.paths.iter().enumerate() {
+        let mut reader = BufReader::new(File::open(path)?);
+        if let Some(record) = T::read_from(&mut reader)? {
+            merge.heap.push(Reverse((record, index)));
+        }
+        merge.readers.push(reader);
+    }
+    Ok(ExternalSortIter {
+        source: Source::Merge(merge),
+    })
+}
+
+/// Iterator over the records produced by `external_sort`, in ascending order
+pub struct ExternalSortIter<T: Record> {
+    source: Source<T>,
+}
+
+enum Source<T: Record> {
+    InMemory(std::vec::IntoIter<T>),
+    Merge(Merge<T>),
+}
+
+struct Merge<T: Record> {
+    readers: Vec<BufReader<File>>,
+    // Ties are broken by run index, which keeps the merge stable
+    heap: BinaryHeap<Reverse<(T, usize)>>,
+    paths: Vec<PathBuf>,
+    /// A read error held back until the record popped with it is yielded
+    error: Option<io::Error>,
+}
+
+impl<T: Record> Iterator for ExternalSortIter<T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<io::Result<T>> {
+        match &mut self.source {
+            Source::InMemory(records) => records.next().map(Ok),
+            Source::Merge(merge) => {
+                if let Some(e) = merge.error.take() {
+                    // Dropping the merge removes the run files too
+                    self.source = Source::InMemory(Vec::new().into_iter());
+                    return Some(Err(e));
+                }
+                let Reverse((record, index)) = merge.heap.pop()?;
+                match T::read_from(&mut merge.readers[index]) {
+                    Ok(Some(next)) => merge.heap.push(Reverse((next, index))),
+                    Ok(None) => {}
+                    Err(e) => merge.error = Some(e),
+                }
+                Some(Ok(record))
+            }
+        }
+    }
+}
+
+impl<T: Record> FusedIterator for ExternalSortIter<T> {}
+
+impl<T: Record> Drop for Merge<T> {
+    fn drop(&mut self) {
+        self.readers.clear();
+        for path in self.paths.iter() {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Record, external_sort, external_sort_in};
+    use std::fs;
+    use std::io::{self, Read, Write};
+
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("rs-cs-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn sorts_integers_across_many_runs() {
+        let data: Vec<u64> = (0..10_000u64)
+            .map(|i| i.wrapping_mul(0x9E37_79B9) % 7919)
+            .collect();
+        let mut expected = data.clone();
+        expected.sort();
+
+        let dir = scratch_dir("ints");
+        let sorted: Vec<u64> = external_sort_in(data, 100, &dir)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(sorted, expected);
+        // Dropping the iterator removed every run file
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn run_files_exist_until_iterator_is_dropped() {
+        let dir = scratch_dir("runs");
+        let mut iter = external_sort_in((0..50i32).rev(), 10, &dir).unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 5);
+        assert_eq!(iter.next().unwrap().unwrap(), 0);
+        drop(iter);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn sorts_strings() {
+        let words = ["delta", "alpha", "echo", "charlie", "bravo", "", "alpha"];
+        let sorted: Vec<String> = external_sort(words.iter().map(|w| w.to_string()), 2)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            sorted,
+            ["", "alpha", "alpha", "bravo", "charlie", "delta", "echo"]
+        );
+    }
+
+    #[test]
+    fn small_and_empty_inputs_stay_in_memory() {
+        let dir = scratch_dir("memory");
+        let sorted: Vec<i16> = external_sort_in(vec![3, -1, 2], 10, &dir)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(sorted, vec![-1, 2, 3]);
+        assert_eq!(
+            external_sort_in(Vec::<u8>::new(), 10, &dir)
+                .unwrap()
+                .count(),
+            0
+        );
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn exactly_one_run_stays_in_memory() {
+        let dir = scratch_dir("one-run");
+        let iter = external_sort_in((0..10u32).rev(), 10, &dir).unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        assert!(iter.map(Result::unwrap).eq(0..10));
+        let iter = external_sort_in((0..11u32).rev(), 10, &dir).unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+        drop(iter);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    /// A record that fails to read back when its value is 13
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Unlucky(u32);
+
+    impl Record for Unlucky {
+        fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+            self.0.write_to(writer)
+        }
+
+        fn read_from<R: Read>(reader: &mut R) -> io::Result<Option<Self>> {
+            match u32::read_from(reader)? {
+                Some(13) => Err(io::ErrorKind::InvalidData.into()),
+                value => Ok(value.map(Unlucky)),
+            }
+        }
+    }
+
+    #[test]
+    fn read_errors_follow_the_record_already_taken() {
+        let dir = scratch_dir("errors");
+        // One run holds 12 then 13, so reading 13 fails once 12 is taken
+        let input = [12, 13, 1, 2].map(Unlucky);
+        let results: Vec<_> = external_sort_in(input, 2, &dir).unwrap().collect();
+        let values: Vec<Option<u32>> = results
+            .iter()
+            .map(|r| r.as_ref().ok().map(|u| u.0))
+            .collect();
+        assert_eq!(values, [Some(1), Some(2), Some(12), None]);
+        assert_eq!(
+            results[3].as_ref().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_errors_end_the_merge() {
+        let dir = scratch_dir("terminal-errors");
+        // Runs [12, 13, 14] and [1, 2, 20]: reading 13 fails with 14 behind
+        // it, and merging on would wrongly put 20 straight after 12
+        let input = [12, 13, 14, 1, 2, 20].map(Unlucky);
+        let mut sorted = external_sort_in(input, 3, &dir).unwrap();
+        let mut values = Vec::new();
+        for result in sorted.by_ref() {
+            match result {
+                Ok(record) => values.push(record.0),
+                Err(e) => {
+                    assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                    break;
+                }
+            }
+        }
+        assert_eq!(values, [1, 2, 12]);
+        assert!(sorted.next().is_none());
+        assert!(sorted.next().is_none());
+        // The run files went with the merge
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        drop(sorted);
+        fs::remove_dir(&dir).unwrap();
+    }
+}
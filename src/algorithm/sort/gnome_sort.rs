@@ -0,0 +1,43 @@
+use super::SortStats;
+
+/// Sorts `arr` in place by stepping back after every swap, like a garden gnome sorting pots
+pub fn gnome_sort<T: Ord>(arr: &mut [T]) {
+    gnome_sort_counted(arr, &mut SortStats::new());
+}
+
+/// Gnome sort that records its comparisons and swaps in `stats`
+pub fn gnome_sort_counted<T: Ord>(arr: &mut [T], stats: &mut SortStats) {
+    let mut pos = 1;
+    while pos < arr.len() {
+        if stats.less(&arr[pos], &arr[pos - 1]) {
+            stats.swap(arr, pos - 1, pos);
+            if pos > 1 {
+                pos -= 1;
+            }
+        } else {
+            pos += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SortStats, gnome_sort, gnome_sort_counted};
+
+    #[test]
+    fn sorts_numbers() {
+        let mut arr = vec![3, -2, 7, 7, 0, 1];
+        gnome_sort(&mut arr);
+        assert_eq!(arr, vec![-2, 0, 1, 3, 7, 7]);
+    }
+
+    #[test]
+    fn swaps_match_insertion_sort() {
+        // Both algorithms perform exactly one swap per inversion
+        let mut arr = vec![4, 3, 2, 1];
+        let mut stats = SortStats::new();
+        gnome_sort_counted(&mut arr, &mut stats);
+        assert_eq!(arr, vec![1, 2, 3, 4]);
+        assert_eq!(stats.swaps, 6);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::data_structure::{heapify, sift_down};
+
+/// Sorts `arr` in place in O(n log n) without allocating.
+///
+/// Uses the same sift-down routine as `BinaryHeap`. Heapsort is not stable.
+pub fn heapsort<T: Ord>(arr: &mut [T]) {
+    heapify(arr);
+    for end in (1..arr.len()).rev() {
+        arr.swap(0, end);
+        sift_down(arr, 0, end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::heapsort;
+
+    #[test]
+    fn sorts_empty_and_single() {
+        let mut empty: Vec<i32> = vec![];
+        heapsort(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut single = vec![42];
+        heapsort(&mut single);
+        assert_eq!(single, vec![42]);
+    }
+
+    #[test]
+    fn sorts_numbers_and_strings() {
+        let mut numbers = vec![5, -3, 9, 0, 9, 2, -8, 1];
+        heapsort(&mut numbers);
+        assert_eq!(numbers, vec![-8, -3, 0, 1, 2, 5, 9, 9]);
+
+        let mut words = vec!["pear", "apple", "fig", "banana"];
+        heapsort(&mut words);
+        assert_eq!(words, vec!["apple", "banana", "fig", "pear"]);
+    }
+
+    #[test]
+    fn sorts_already_sorted_and_reversed() {
+        let mut sorted: Vec<u32> = (0..100).collect();
+        heapsort(&mut sorted);
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut reversed: Vec<u32> = (0..100).rev().collect();
+        heapsort(&mut reversed);
+        assert_eq!(reversed, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn is_not_stable() {
+        // Elements compare by key only; the tag records original order
+        #[derive(Debug, Clone, Copy)]
+        struct Keyed(u8, usize);
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Keyed {}
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut arr: Vec<Keyed> = [1, 1, 1, 0]
+            .iter()
+            .enumerate()
+            .map(|(i, &k)| Keyed(k, i))
+            .collect();
+        heapsort(&mut arr);
+        let keys: Vec<u8> = arr.iter().map(|k| k.0).collect();
+        let tags: Vec<usize> = arr.iter().map(|k| k.1).collect();
+        assert_eq!(keys, vec![0, 1, 1, 1]);
+        // Equal keys come out in a different order than they went in
+        assert_ne!(tags, vec![3, 0, 1, 2]);
+    }
+}
@@ -0,0 +1,41 @@
+use super::SortStats;
+
+/// Sorts `arr` in place by growing a sorted prefix one element at a time
+pub fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    insertion_sort_counted(arr, &mut SortStats::new());
+}
+
+/// Insertion sort that records its comparisons and swaps in `stats`.
+///
+/// The number of swaps equals the number of inversions in the input.
+pub fn insertion_sort_counted<T: Ord>(arr: &mut [T], stats: &mut SortStats) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && stats.less(&arr[j], &arr[j - 1]) {
+            stats.swap(arr, j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SortStats, insertion_sort, insertion_sort_counted};
+
+    #[test]
+    fn sorts_strings() {
+        let mut arr = vec!["d", "a", "c", "b"];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn swaps_equal_inversions() {
+        // (3,1), (3,2) and (4,2) are the inversions
+        let mut arr = vec![3, 1, 4, 2];
+        let mut stats = SortStats::new();
+        insertion_sort_counted(&mut arr, &mut stats);
+        assert_eq!(arr, vec![1, 2, 3, 4]);
+        assert_eq!(stats.swaps, 3);
+    }
+}
@@ -0,0 +1,188 @@
+use super::{heapsort, insertion_sort, partition_around};
+
+/// Partitions at or below this length are finished with insertion sort
+const INSERTION_THRESHOLD: usize = 16;
+
+/// Sorts `arr` in place with introsort, as production unstable sorts do.
+///
+/// Quicksort with a median-of-three pivot does the bulk of the work, small
+/// partitions are handed to insertion sort, and once recursion gets deeper than
+/// 2 * log2(n) the remaining partition is heapsorted, so the worst case stays
+/// O(n log n) even on inputs built to defeat quicksort.
+pub fn introsort<T: Ord>(arr: &mut [T]) {
+    if arr.len() < 2 {
+        return;
+    }
+    let depth_limit = 2 * arr.len().ilog2();
+    introsort_loop(arr, depth_limit);
+}
+
+fn introsort_loop<T: Ord>(mut arr: &mut [T], mut depth_limit: u32) {
+    while arr.len() > INSERTION_THRESHOLD {
+        if depth_limit == 0 {
+            heapsort(arr);
+            return;
+        }
+        depth_limit -= 1;
+
+        let pivot = partition(arr);
+        let (left, right) = arr.split_at_mut(pivot);
+        let right = &mut right[1..];
+        // Recurse into the smaller side and loop on the larger to bound stack depth
+        if left.len() < right.len() {
+            introsort_loop(left, depth_limit);
+            arr = right;
+        } else {
+            introsort_loop(right, depth_limit);
+            arr = left;
+        }
+    }
+    insertion_sort(arr);
+}
+
+/// Partitions around the median of the first, middle and last elements.
+///
+/// Returns the pivot's final index; see `partition_around`.
+pub(super) fn partition<T: Ord>(arr: &mut [T]) -> usize {
+    let last = arr.len() - 1;
+    let mid = arr.len() / 2;
+    if arr[mid] < arr[0] {
+        arr.swap(mid, 0);
+    }
+    if arr[last] < arr[0] {
+        arr.swap(last, 0);
+    }
+    if arr[last] < arr[mid] {
+        arr.swap(last, mid);
+    }
+    partition_around(arr, mid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{introsort, partition};
+    use std::cell::RefCell;
+    use std::cmp::Ordering;
+    use std::rc::Rc;
+
+    #[test]
+    fn sorts_common_inputs() {
+        let inputs: Vec<Vec<i32>> = vec![
+            vec![],
+            vec![1],
+            (0..1000).collect(),
+            (0..1000).rev().collect(),
+            vec![7; 500],
+            (0..1000).map(|i| (i * 7919) % 1009 - 500).collect(),
+            (0..1000).map(|i| i % 3).collect(),
+        ];
+        for data in inputs {
+            let mut expected = data.clone();
+            expected.sort();
+            let mut arr = data;
+            introsort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn partition_places_pivot() {
+        let mut arr = vec![9, 3, 7, 1, 8, 2, 6, 5, 4];
+        let p = partition(&mut arr);
+        assert!(arr[..p].iter().all(|x| *x <= arr[p]));
+        assert!(arr[p + 1..].iter().all(|x| *x >= arr[p]));
+    }
+
+    /// McIlroy's adversary: values are decided lazily during comparisons so the
+    /// current pivot candidate always ends up as small as possible
+    struct Adversary {
+        val: Vec<usize>,
+        gas: usize,
+        solid: usize,
+        candidate: usize,
+        comparisons: usize,
+    }
+
+    impl Adversary {
+        fn compare(&mut self, x: usize, y: usize) -> Ordering {
+            self.comparisons += 1;
+            if x == y {
+                return Ordering::Equal;
+            }
+            if self.val[x] == self.gas && self.val[y] == self.gas {
+                let frozen = if x == self.candidate { x } else { y };
+                self.val[frozen] = self.solid;
+                self.solid += 1;
+            }
+            if self.val[x] == self.gas {
+                self.candidate = x;
+            } else if self.val[y] == self.gas {
+                self.candidate = y;
+            }
+            self.val[x].cmp(&self.val[y])
+        }
+    }
+
+    struct Item {
+        index: usize,
+        adversary: Rc<RefCell<Adversary>>,
+    }
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+    impl Eq for Item {}
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.adversary.borrow_mut().compare(self.index, other.index)
+        }
+    }
+
+    fn comparisons_against_adversary(n: usize, sort: fn(&mut [Item])) -> usize {
+        let adversary = Rc::new(RefCell::new(Adversary {
+            val: vec![n; n],
+            gas: n,
+            solid: 0,
+            candidate: 0,
+            comparisons: 0,
+        }));
+        let mut items: Vec<Item> = (0..n)
+            .map(|index| Item {
+                index,
+                adversary: Rc::clone(&adversary),
+            })
+            .collect();
+        sort(&mut items);
+        adversary.borrow().comparisons
+    }
+
+    fn plain_quicksort<T: Ord>(arr: &mut [T]) {
+        if arr.len() < 2 {
+            return;
+        }
+        let pivot = partition(arr);
+        let (left, right) = arr.split_at_mut(pivot);
+        plain_quicksort(left);
+        plain_quicksort(&mut right[1..]);
+    }
+
+    #[test]
+    fn adversarial_input_defeats_quicksort_but_not_introsort() {
+        let n = 2000;
+        let quick = comparisons_against_adversary(n, plain_quicksort);
+        let intro = comparisons_against_adversary(n, introsort);
+        // The adversary drives plain quicksort quadratic; introsort bails out to heapsort
+        assert!(quick > n * n / 8, "quicksort made only {quick} comparisons");
+        assert!(
+            intro < 4 * n * n.ilog2() as usize,
+            "introsort made {intro} comparisons"
+        );
+    }
+}
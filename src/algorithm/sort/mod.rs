@@ -0,0 +1,46 @@
+mod bubble_sort;
+mod bucket_sort;
+mod counting_sort;
+mod external_sort;
+mod gnome_sort;
+mod heap_sort;
+mod insertion_sort;
+mod intro_sort;
+mod parallel_sort;
+mod partition;
+mod radix_sort;
+mod selection_sort;
+mod shell_sort;
+mod sort_error;
+mod sort_harness;
+mod sort_stats;
+mod sorter;
+mod tim_sort;
+
+pub use self::bubble_sort::{bubble_sort, bubble_sort_counted};
+pub use self::bucket_sort::{MAX_BUCKETS, bucket_sort};
+pub use self::counting_sort::{MAX_COUNTING_RANGE, counting_sort};
+pub use self::external_sort::{ExternalSortIter, Record, external_sort, external_sort_in};
+pub use self::gnome_sort::{gnome_sort, gnome_sort_counted};
+pub use self::heap_sort::heapsort;
+pub use self::insertion_sort::{insertion_sort, insertion_sort_counted};
+pub use self::intro_sort::introsort;
+pub use self::parallel_sort::{
+    PAR_SORT_THRESHOLD, par_merge_sort, par_merge_sort_with_threshold, par_quicksort,
+    par_quicksort_with_threshold,
+};
+pub use self::partition::partition_around;
+pub use self::radix_sort::{RadixKey, lsd_radix_sort, lsd_radix_sort_with_radix, msd_radix_sort};
+pub use self::selection_sort::{selection_sort, selection_sort_counted};
+pub use self::shell_sort::{GapSequence, shell_sort, shell_sort_counted};
+pub use self::sort_error::SortError;
+pub use self::sort_harness::{
+    Counted, Distribution, SortReport, check_integer_sorters, compare_sorters, comparison_sorters,
+};
+pub use self::sort_stats::SortStats;
+pub use self::sorter::{
+    BubbleSort, BucketSort, CountingSort, GnomeSort, HeapSort, InsertionSort, IntroSort,
+    LsdRadixSort, MsdRadixSort, ParMergeSort, ParQuickSort, SelectionSort, ShellSort, Sorter,
+    TimSort,
+};
+pub use self::tim_sort::timsort;
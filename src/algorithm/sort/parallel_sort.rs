@@ -0,0 +1,175 @@
+use std::sync::OnceLock;
+use std::thread;
+
+use super::intro_sort::partition;
+use super::{introsort, timsort};
+use crate::concurrency::ThreadPool;
+
+/// Slices shorter than this are sorted sequentially; spawning costs more than it saves
+pub const PAR_SORT_THRESHOLD: usize = 4096;
+
+/// The pool the parallel sorts run their halves on, one worker per core
+fn pool() -> &'static ThreadPool {
+    static POOL: OnceLock<ThreadPool> = OnceLock::new();
+    POOL.get_or_init(|| ThreadPool::new(thread::available_parallelism().map_or(1, |n| n.get())))
+}
+
+/// How many levels of recursion may fork, giving roughly two threads per core
+fn fork_depth() -> u32 {
+    thread::available_parallelism().map_or(1, |n| n.get().ilog2() + 1)
+}
+
+/// Stable merge sort that sorts both halves as tasks on a shared thread pool.
+///
+/// Slices below `PAR_SORT_THRESHOLD`, and recursion deeper than the core count
+/// warrants, fall back to the sequential `timsort`.
+pub fn par_merge_sort<T: Ord + Clone + Send + Sync>(arr: &mut [T]) {
+    par_merge_sort_with_threshold(arr, PAR_SORT_THRESHOLD);
+}
+
+/// `par_merge_sort` with a custom crossover length to sequential sorting
+pub fn par_merge_sort_with_threshold<T: Ord + Clone + Send + Sync>(
+    arr: &mut [T],
+    threshold: usize,
+) {
+    merge_sort_recursive(arr, threshold.max(2), fork_depth());
+}
+
+fn merge_sort_recursive<T: Ord + Clone + Send + Sync>(arr: &mut [T], threshold: usize, depth: u32) {
+    if arr.len() <= threshold || depth == 0 {
+        timsort(arr);
+        return;
+    }
+    let mid = arr.len() / 2;
+    let (left, right) = arr.split_at_mut(mid);
+    pool().scope(|s| {
+        s.execute(|| merge_sort_recursive(right, threshold, depth - 1));
+        merge_sort_recursive(left, threshold, depth - 1);
+    });
+
+    if arr[mid - 1] <= arr[mid] {
+        return;
+    }
+    let mut merged = Vec::with_capacity(arr.len());
+    let (left, right) = arr.split_at(mid);
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        // Taking from the left on ties keeps the sort stable
+        if right[j] < left[i] {
+            merged.push(right[j].clone());
+            j += 1;
+        } else {
+            merged.push(left[i].clone());
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    arr.clone_from_slice(&merged);
+}
+
+/// Quicksort that sorts both partitions as tasks on a shared thread pool.
+///
+/// Slices below `PAR_SORT_THRESHOLD`, and recursion deeper than the core count
+/// warrants, fall back to the sequential `introsort`. Not stable.
+pub fn par_quicksort<T: Ord + Send>(arr: &mut [T]) {
+    par_quicksort_with_threshold(arr, PAR_SORT_THRESHOLD);
+}
+
+/// `par_quicksort` with a custom crossover length to sequential sorting
+pub fn par_quicksort_with_threshold<T: Ord + Send>(arr: &mut [T], threshold: usize) {
+    quicksort_recursive(arr, threshold.max(2), fork_depth());
+}
+
+fn quicksort_recursive<T: Ord + Send>(arr: &mut [T], threshold: usize, depth: u32) {
+    if arr.len() <= threshold || depth == 0 {
+        introsort(arr);
+        return;
+    }
+    let pivot = partition(arr);
+    let (left, right) = arr.split_at_mut(pivot);
+    let right = &mut right[1..];
+    pool().scope(|s| {
+        s.execute(|| quicksort_recursive(right, threshold, depth - 1));
+        quicksort_recursive(left, threshold, depth - 1);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        par_merge_sort, par_merge_sort_with_threshold, par_quicksort, par_quicksort_with_threshold,
+    };
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn pseudo_random(len: usize, seed: u64) -> Vec<u64> {
+        let mut rng = XorShift64Star::seed_from_u64(seed);
+        (0..len).map(|_| rng.gen_range(0..10_000)).collect()
+    }
+
+    #[test]
+    fn matches_std_sort_above_threshold() {
+        let data = pseudo_random(50_000, 17);
+        let mut expected = data.clone();
+        expected.sort();
+
+        let mut merged = data.clone();
+        par_merge_sort(&mut merged);
+        assert_eq!(merged, expected);
+
+        let mut quick = data;
+        par_quicksort(&mut quick);
+        assert_eq!(quick, expected);
+    }
+
+    #[test]
+    fn tiny_threshold_forks_on_small_inputs() {
+        for len in [0, 1, 2, 3, 10, 100] {
+            let data = pseudo_random(len, len as u64 + 3);
+            let mut expected = data.clone();
+            expected.sort();
+
+            let mut merged = data.clone();
+            par_merge_sort_with_threshold(&mut merged, 2);
+            assert_eq!(merged, expected);
+
+            let mut quick = data;
+            par_quicksort_with_threshold(&mut quick, 2);
+            assert_eq!(quick, expected);
+        }
+    }
+
+    #[test]
+    fn merge_sort_is_stable() {
+        let data: Vec<(u64, usize)> = pseudo_random(20_000, 5)
+            .into_iter()
+            .enumerate()
+            .map(|(i, k)| (k % 16, i))
+            .collect();
+        // Sorting only by key must keep the original index order within a key
+        #[derive(Clone, Debug)]
+        struct ByKey((u64, usize));
+        impl PartialEq for ByKey {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.0 == other.0.0
+            }
+        }
+        impl Eq for ByKey {}
+        impl PartialOrd for ByKey {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for ByKey {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.0.cmp(&other.0.0)
+            }
+        }
+
+        let mut arr: Vec<ByKey> = data.iter().copied().map(ByKey).collect();
+        par_merge_sort_with_threshold(&mut arr, 64);
+        let mut expected = data;
+        expected.sort_by_key(|&(k, _)| k);
+        assert_eq!(arr.into_iter().map(|b| b.0).collect::<Vec<_>>(), expected);
+    }
+}
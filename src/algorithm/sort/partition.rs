@@ -0,0 +1,63 @@
+/// Hoare partition of `arr` around the element at `pivot`.
+///
+/// Returns the pivot's final index; everything before it is <= the pivot and
+/// everything after it is >= the pivot. Elements equal to the pivot may land
+/// on either side, which keeps splits balanced on inputs with many duplicates.
+/// Shared by the quicksorts and by selection algorithms.
+///
+/// Panics if `pivot` is out of bounds, so in particular on an empty slice.
+pub fn partition_around<T: Ord>(arr: &mut [T], pivot: usize) -> usize {
+    assert!(
+        pivot < arr.len(),
+        "pivot {pivot} out of bounds for length {}",
+        arr.len()
+    );
+    arr.swap(0, pivot);
+    let (mut i, mut j) = (1, arr.len() - 1);
+    loop {
+        while i <= j && arr[i] < arr[0] {
+            i += 1;
+        }
+        while i <= j && arr[0] < arr[j] {
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+        arr.swap(i, j);
+        i += 1;
+        j -= 1;
+    }
+    arr.swap(0, j);
+    j
+}
+
+#[cfg(test)]
+mod tests {
+    use super::partition_around;
+
+    #[test]
+    fn every_pivot_choice_partitions() {
+        let data = vec![5, 1, 9, 3, 3, 7, 0, 3, 8];
+        for pivot in 0..data.len() {
+            let mut arr = data.clone();
+            let value = arr[pivot];
+            let p = partition_around(&mut arr, pivot);
+            assert_eq!(arr[p], value);
+            assert!(arr[..p].iter().all(|&x| x <= value));
+            assert!(arr[p + 1..].iter().all(|&x| x >= value));
+        }
+    }
+
+    #[test]
+    fn single_element() {
+        let mut arr = [42];
+        assert_eq!(partition_around(&mut arr, 0), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "pivot 0 out of bounds for length 0")]
+    fn empty_slice_has_no_pivot() {
+        partition_around::<i32>(&mut [], 0);
+    }
+}
@@ -0,0 +1,228 @@
+/// Integer types that can be sorted digit by digit.
+///
+/// `key` maps a value to an unsigned integer with the same ordering, so signed
+/// types are adapted by flipping their sign bit.
+pub trait RadixKey: Copy {
+    const BITS: u32;
+
+    fn key(self) -> u64;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            fn key(self) -> u64 {
+                self as u64
+            }
+        })*
+    };
+}
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(impl RadixKey for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            fn key(self) -> u64 {
+                ((self as $u) ^ (1 << (<$t>::BITS - 1))) as u64
+            }
+        })*
+    };
+}
+
+impl_radix_key_unsigned!(u8, u16, u32, u64, usize);
+impl_radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+/// Sorts integers with a least-significant-digit radix sort using 8-bit digits
+pub fn lsd_radix_sort<T: RadixKey>(arr: &mut [T]) {
+    lsd_radix_sort_with_radix(arr, 8);
+}
+
+/// LSD radix sort with `2^digit_bits` buckets per pass.
+///
+/// Runs in O((n + 2^digit_bits) * BITS / digit_bits) time and is stable.
+/// Panics if `digit_bits` is not in `1..=16`.
+pub fn lsd_radix_sort_with_radix<T: RadixKey>(arr: &mut [T], digit_bits: u32) {
+    assert!(
+        (1..=16).contains(&digit_bits),
+        "digit_bits must be between 1 and 16"
+    );
+    if arr.len() < 2 {
+        return;
+    }
+
+    let radix = 1usize << digit_bits;
+    let mask = (radix - 1) as u64;
+    let mut buffer = arr.to_vec();
+    let mut counts = vec![0usize; radix];
+    let mut shift = 0;
+    while shift < T::BITS {
+        counts.iter_mut().for_each(|c| *c = 0);
+        for value in arr.iter() {
+            counts[((value.key() >> shift) & mask) as usize] += 1;
+        }
+        // Skip passes where every element shares the same digit
+        if counts.contains(&arr.len()) {
+            shift += digit_bits;
+            continue;
+        }
+
+        let mut total = 0;
+        for count in counts.iter_mut() {
+            let current = *count;
+            *count = total;
+            total += current;
+        }
+        for value in arr.iter() {
+            let digit = ((value.key() >> shift) & mask) as usize;
+            buffer[counts[digit]] = *value;
+            counts[digit] += 1;
+        }
+        arr.copy_from_slice(&buffer);
+        shift += digit_bits;
+    }
+}
+
+const MSD_INSERTION_CUTOFF: usize = 16;
+
+/// Sorts byte strings lexicographically with a most-significant-digit radix sort.
+///
+/// Each recursion level buckets by one byte; small buckets fall back to insertion sort.
+pub fn msd_radix_sort<S: AsRef<[u8]> + Clone>(arr: &mut [S]) {
+    if arr.len() < 2 {
+        return;
+    }
+    let mut buffer = arr.to_vec();
+    msd_sort_range(arr, &mut buffer, 0);
+}
+
+// Bucket 0 holds strings that end before `depth`, buckets 1..=256 hold byte values
+fn byte_at<S: AsRef<[u8]>>(s: &S, depth: usize) -> usize {
+    s.as_ref().get(depth).map_or(0, |&b| b as usize + 1)
+}
+
+fn msd_sort_range<S: AsRef<[u8]> + Clone>(arr: &mut [S], buffer: &mut [S], depth: usize) {
+    if arr.len() <= MSD_INSERTION_CUTOFF {
+        insertion_sort_from(arr, depth);
+        return;
+    }
+
+    let mut counts = [0usize; 258];
+    for s in arr.iter() {
+        counts[byte_at(s, depth) + 1] += 1;
+    }
+    for i in 1..counts.len() {
+        counts[i] += counts[i - 1];
+    }
+    let starts = counts;
+    for s in arr.iter() {
+        let bucket = byte_at(s, depth);
+        buffer[counts[bucket]] = s.clone();
+        counts[bucket] += 1;
+    }
+    arr.clone_from_slice(&buffer[..arr.len()]);
+
+    // Bucket 0 is already fully sorted since those strings are identical prefixes
+    for bucket in 1..257 {
+        let (lo, hi) = (starts[bucket], starts[bucket + 1]);
+        if hi - lo > 1 {
+            msd_sort_range(&mut arr[lo..hi], &mut buffer[lo..hi], depth + 1);
+        }
+    }
+}
+
+fn insertion_sort_from<S: AsRef<[u8]>>(arr: &mut [S], depth: usize) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        // Every string in a bucket at `depth` has at least `depth` bytes
+        while j > 0 && arr[j].as_ref()[depth..] < arr[j - 1].as_ref()[depth..] {
+            arr.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lsd_radix_sort, lsd_radix_sort_with_radix, msd_radix_sort};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+
+    fn pseudo_random(len: usize, seed: u64) -> Vec<u64> {
+        let mut rng = XorShift64Star::seed_from_u64(seed);
+        (0..len).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn sorts_unsigned() {
+        let mut arr: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![2, 24, 45, 66, 75, 90, 170, 802]);
+    }
+
+    #[test]
+    fn sorts_signed_through_adapter() {
+        let mut arr: Vec<i64> = vec![5, -3, i64::MIN, 0, i64::MAX, -1, 7];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![i64::MIN, -3, -1, 0, 5, 7, i64::MAX]);
+    }
+
+    #[test]
+    fn every_radix_matches_std_sort() {
+        let data = pseudo_random(1000, 0x9E37_79B9_7F4A_7C15);
+        let mut expected = data.clone();
+        expected.sort_unstable();
+        for digit_bits in [1, 4, 8, 11, 16] {
+            let mut arr = data.clone();
+            lsd_radix_sort_with_radix(&mut arr, digit_bits);
+            assert_eq!(arr, expected, "digit_bits = {digit_bits}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "digit_bits")]
+    fn rejects_zero_radix() {
+        lsd_radix_sort_with_radix(&mut [1u8, 0], 0);
+    }
+
+    #[test]
+    fn msd_sorts_strings_with_shared_prefixes() {
+        let mut words = vec![
+            "she",
+            "sells",
+            "seashells",
+            "by",
+            "the",
+            "sea",
+            "shore",
+            "the",
+            "shells",
+            "she",
+            "sells",
+            "are",
+            "surely",
+            "seashells",
+            "",
+            "s",
+            "sea",
+            "shell",
+        ];
+        let mut expected = words.clone();
+        expected.sort();
+        msd_radix_sort(&mut words);
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn msd_sorts_large_byte_input() {
+        let mut strings: Vec<Vec<u8>> = pseudo_random(500, 42)
+            .into_iter()
+            .map(|x| x.to_le_bytes()[..(x % 6) as usize].to_vec())
+            .collect();
+        let mut expected = strings.clone();
+        expected.sort();
+        msd_radix_sort(&mut strings);
+        assert_eq!(strings, expected);
+    }
+}
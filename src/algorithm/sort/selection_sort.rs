@@ -0,0 +1,51 @@
+use super::SortStats;
+
+/// Sorts `arr` in place by repeatedly selecting the minimum of the unsorted suffix
+pub fn selection_sort<T: Ord>(arr: &mut [T]) {
+    selection_sort_counted(arr, &mut SortStats::new());
+}
+
+/// Selection sort that records its comparisons and swaps in `stats`.
+///
+/// Always makes n(n - 1) / 2 comparisons but at most n - 1 swaps.
+pub fn selection_sort_counted<T: Ord>(arr: &mut [T], stats: &mut SortStats) {
+    let len = arr.len();
+    for i in 0..len.saturating_sub(1) {
+        let mut min = i;
+        for j in (i + 1)..len {
+            if stats.less(&arr[j], &arr[min]) {
+                min = j;
+            }
+        }
+        if min != i {
+            stats.swap(arr, i, min);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SortStats, selection_sort, selection_sort_counted};
+
+    #[test]
+    fn sorts_numbers() {
+        let mut arr = vec![64, 25, 12, 22, 11];
+        selection_sort(&mut arr);
+        assert_eq!(arr, vec![11, 12, 22, 25, 64]);
+    }
+
+    #[test]
+    fn comparisons_do_not_depend_on_input() {
+        let mut sorted: Vec<i32> = (0..10).collect();
+        let mut stats = SortStats::new();
+        selection_sort_counted(&mut sorted, &mut stats);
+        assert_eq!(stats.comparisons, 45);
+        assert_eq!(stats.swaps, 0);
+
+        let mut reversed: Vec<i32> = (0..10).rev().collect();
+        let mut stats = SortStats::new();
+        selection_sort_counted(&mut reversed, &mut stats);
+        assert_eq!(stats.comparisons, 45);
+        assert!(stats.swaps <= 9);
+    }
+}
@@ -0,0 +1,153 @@
+use super::SortStats;
+
+/// The gap sequences `shell_sort` can use, from largest gap to 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GapSequence {
+    /// Shell's original n/2, n/4, ..., 1, which degrades to O(n²)
+    Shell,
+    /// Knuth's (3^k - 1) / 2: 1, 4, 13, 40, ... with O(n^(3/2)) worst case
+    Knuth,
+    /// Sedgewick's 4^k + 3 * 2^(k-1) + 1: 1, 8, 23, 77, ... with O(n^(4/3)) worst case
+    Sedgewick,
+    /// Ciura's empirically tuned 1, 4, 10, 23, 57, ..., extended by a factor of 2.25
+    Ciura,
+    /// Caller-supplied gaps in any order; a final pass with gap 1 is always added
+    Custom(Vec<usize>),
+}
+
+const CIURA: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+impl GapSequence {
+    /// Returns the gaps to use for a slice of length `len`, in descending order
+    pub fn gaps(&self, len: usize) -> Vec<usize> {
+        let mut gaps = match self {
+            GapSequence::Shell => {
+                let mut gaps = Vec::new();
+                let mut gap = len / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                gaps
+            }
+            GapSequence::Knuth => {
+                let mut gaps = vec![1];
+                let mut gap = 4;
+                while gap < len.div_ceil(3) {
+                    gaps.push(gap);
+                    gap = 3 * gap + 1;
+                }
+                gaps
+            }
+            GapSequence::Sedgewick => {
+                let mut gaps = vec![1];
+                for k in 1.. {
+                    let gap = 4usize.pow(k) + 3 * 2usize.pow(k - 1) + 1;
+                    if gap >= len {
+                        break;
+                    }
+                    gaps.push(gap);
+                }
+                gaps
+            }
+            GapSequence::Ciura => {
+                let mut gaps: Vec<usize> = CIURA.iter().copied().filter(|&g| g < len).collect();
+                let mut gap = *CIURA.last().unwrap();
+                loop {
+                    gap = (gap as f64 * 2.25) as usize;
+                    if gap >= len {
+                        break;
+                    }
+                    gaps.push(gap);
+                }
+                gaps
+            }
+            GapSequence::Custom(gaps) => gaps.iter().copied().filter(|&g| g > 0).collect(),
+        };
+        gaps.sort_unstable_by(|a, b| b.cmp(a));
+        gaps.dedup();
+        if gaps.last() != Some(&1) {
+            gaps.push(1);
+        }
+        gaps
+    }
+}
+
+/// Sorts `arr` in place with gapped insertion sort passes over `gaps`
+pub fn shell_sort<T: Ord>(arr: &mut [T], gaps: &GapSequence) {
+    shell_sort_counted(arr, gaps, &mut SortStats::new());
+}
+
+/// Shell sort that records its comparisons and swaps in `stats`
+pub fn shell_sort_counted<T: Ord>(arr: &mut [T], gaps: &GapSequence, stats: &mut SortStats) {
+    for gap in gaps.gaps(arr.len()) {
+        for i in gap..arr.len() {
+            let mut j = i;
+            while j >= gap && stats.less(&arr[j], &arr[j - gap]) {
+                stats.swap(arr, j - gap, j);
+                j -= gap;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GapSequence, SortStats, shell_sort, shell_sort_counted};
+
+    fn all_sequences() -> Vec<GapSequence> {
+        vec![
+            GapSequence::Shell,
+            GapSequence::Knuth,
+            GapSequence::Sedgewick,
+            GapSequence::Ciura,
+            GapSequence::Custom(vec![7, 3]),
+        ]
+    }
+
+    #[test]
+    fn gaps_are_descending_and_end_in_one() {
+        for sequence in all_sequences() {
+            for len in [0, 1, 2, 10, 1000, 100_000] {
+                let gaps = sequence.gaps(len);
+                assert_eq!(gaps.last(), Some(&1), "{sequence:?} len {len}");
+                assert!(
+                    gaps.windows(2).all(|w| w[0] > w[1]),
+                    "{sequence:?} len {len}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn known_prefixes() {
+        assert_eq!(GapSequence::Knuth.gaps(1000), vec![121, 40, 13, 4, 1]);
+        assert_eq!(GapSequence::Sedgewick.gaps(300), vec![281, 77, 23, 8, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(100), vec![57, 23, 10, 4, 1]);
+        assert_eq!(GapSequence::Shell.gaps(16), vec![8, 4, 2, 1]);
+    }
+
+    #[test]
+    fn every_sequence_sorts() {
+        let data: Vec<i32> = (0..500).map(|i| (i * 7919) % 503 - 250).collect();
+        let mut expected = data.clone();
+        expected.sort();
+        for sequence in all_sequences() {
+            let mut arr = data.clone();
+            shell_sort(&mut arr, &sequence);
+            assert_eq!(arr, expected, "{sequence:?}");
+        }
+    }
+
+    #[test]
+    fn ciura_beats_shell_on_comparisons() {
+        let data: Vec<u32> = (0..2048u32)
+            .map(|i| i.wrapping_mul(2654435761) >> 16)
+            .collect();
+        let mut shell_stats = SortStats::new();
+        shell_sort_counted(&mut data.clone(), &GapSequence::Shell, &mut shell_stats);
+        let mut ciura_stats = SortStats::new();
+        shell_sort_counted(&mut data.clone(), &GapSequence::Ciura, &mut ciura_stats);
+        assert!(ciura_stats.comparisons < shell_stats.comparisons);
+    }
+}
@@ -0,0 +1,24 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons a distribution sort refuses its input instead of allocating or misplacing values
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortError {
+    /// The keys span more distinct values than the sort is willing to allocate counters for
+    KeyRangeTooLarge { range: u128, limit: usize },
+    /// A value fell outside the interval the sort is defined for
+    OutOfRange(f64),
+}
+
+impl Display for SortError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SortError::KeyRangeTooLarge { range, limit } => {
+                write!(f, "key range {range} exceeds the limit of {limit}")
+            }
+            SortError::OutOfRange(value) => write!(f, "value {value} is outside [0, 1)"),
+        }
+    }
+}
+
+impl Error for SortError {}
@@ -0,0 +1,252 @@
+use std::cmp::Ordering as CmpOrdering;
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::testgen::{Shape, TestGen};
+
+use super::{
+    BubbleSort, GapSequence, GnomeSort, HeapSort, InsertionSort, IntroSort, ParMergeSort,
+    ParQuickSort, SelectionSort, ShellSort, SortStats, Sorter, TimSort,
+};
+
+/// The shapes of input every sorter is run against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    Random,
+    Sorted,
+    Reversed,
+    /// Random values drawn from only a handful of distinct keys
+    FewUniques,
+}
+
+impl Distribution {
+    pub const ALL: [Distribution; 4] = [
+        Distribution::Random,
+        Distribution::Sorted,
+        Distribution::Reversed,
+        Distribution::FewUniques,
+    ];
+
+    /// The `TestGen` shape this distribution draws from
+    pub fn shape(&self) -> Shape {
+        match self {
+            Distribution::Random => Shape::Uniform,
+            Distribution::Sorted => Shape::Sorted,
+            Distribution::Reversed => Shape::Reversed,
+            Distribution::FewUniques => Shape::FewUniques(8),
+        }
+    }
+
+    /// Generates `len` values with this shape; the same seed always gives the same input
+    pub fn generate(&self, len: usize, seed: u64) -> Vec<u32> {
+        // Every shape used here draws from 0..2^32
+        TestGen::new(seed)
+            .ints(len, self.shape())
+            .into_iter()
+            .map(|value| value as u32)
+            .collect()
+    }
+}
+
+/// A value that counts every comparison made on it in a shared counter
+#[derive(Debug, Clone)]
+pub struct Counted {
+    pub value: u32,
+    comparisons: Arc<AtomicU64>,
+}
+
+impl PartialEq for Counted {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl Eq for Counted {}
+
+impl PartialOrd for Counted {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Counted {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        self.comparisons.fetch_add(1, Ordering::Relaxed);
+        self.value.cmp(&other.value)
+    }
+}
+
+/// The outcome of running one sorter on one input
+#[derive(Debug, Clone)]
+pub struct SortReport {
+    pub sorter: &'static str,
+    pub distribution: Distribution,
+    pub len: usize,
+    /// None for sorters that never compare elements
+    pub comparisons: Option<u64>,
+    /// Element swaps, which set apart sorts with similar comparison counts.
+    /// None for sorters not instrumented with `Sorter::sort_counted`
+    pub swaps: Option<u64>,
+    pub elapsed: Duration,
+    pub correct: bool,
+}
+
+impl Display for SortReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let count = |c: Option<u64>| c.map_or_else(|| "-".to_string(), |c| c.to_string());
+        write!(
+            f,
+            "{:<22} {:<10} n={:<7} comparisons={:<10} swaps={:<10} time={:?}{}",
+            self.sorter,
+            format!("{:?}", self.distribution),
+            self.len,
+            count(self.comparisons),
+            count(self.swaps),
+            self.elapsed,
+            if self.correct { "" } else { " INCORRECT" }
+        )
+    }
+}
+
+/// Every comparison-based sorter in the module, with Ciura gaps for shell sort
+pub fn comparison_sorters() -> Vec<Box<dyn Sorter<Counted>>> {
+    vec![
+        Box::new(BubbleSort),
+        Box::new(InsertionSort),
+        Box::new(SelectionSort),
+        Box::new(GnomeSort),
+        Box::new(ShellSort(GapSequence::Ciura)),
+        Box::new(HeapSort),
+        Box::new(TimSort),
+        Box::new(IntroSort),
+        Box::new(ParMergeSort),
+        Box::new(ParQuickSort),
+    ]
+}
+
+/// Runs every comparison sorter over every distribution, counting comparisons
+/// (and swaps, where the sorter is instrumented) and checking the output
+/// against the standard library sort
+pub fn compare_sorters(
+    sorters: &[Box<dyn Sorter<Counted>>],
+    distributions: &[Distribution],
+    len: usize,
+    seed: u64,
+) -> Vec<SortReport> {
+    let mut reports = Vec::new();
+    for &distribution in distributions {
+        let input = distribution.generate(len, seed);
+        let mut expected = input.clone();
+        expected.sort_unstable();
+        for sorter in sorters {
+            let comparisons = Arc::new(AtomicU64::new(0));
+            let mut arr: Vec<Counted> = input
+                .iter()
+                .map(|&value| Counted {
+                    value,
+                    comparisons: Arc::clone(&comparisons),
+                })
+                .collect();
+            let mut stats = SortStats::new();
+            let start = Instant::now();
+            let counted = sorter.sort_counted(&mut arr, &mut stats);
+            let elapsed = start.elapsed();
+            reports.push(SortReport {
+                sorter: sorter.name(),
+                distribution,
+                len,
+                comparisons: Some(comparisons.load(Ordering::Relaxed)),
+                swaps: counted.then_some(stats.swaps),
+                elapsed,
+                correct: arr.iter().map(|c| c.value).eq(expected.iter().copied()),
+            });
+        }
+    }
+    reports
+}
+
+/// Runs sorters that work on plain integers (such as radix and counting sort)
+/// over every distribution, checking the output against the standard library sort
+pub fn check_integer_sorters(
+    sorters: &[&dyn Sorter<u32>],
+    distributions: &[Distribution],
+    len: usize,
+    seed: u64,
+) -> Vec<SortReport> {
+    let mut reports = Vec::new();
+    for &distribution in distributions {
+        let input = distribution.generate(len, seed);
+        let mut expected = input.clone();
+        expected.sort_unstable();
+        for sorter in sorters {
+            let mut arr = input.clone();
+            let start = Instant::now();
+            sorter.sort(&mut arr);
+            reports.push(SortReport {
+                sorter: sorter.name(),
+                distribution,
+                len,
+                comparisons: None,
+                swaps: None,
+                elapsed: start.elapsed(),
+                correct: arr == expected,
+            });
+        }
+    }
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Distribution, check_integer_sorters, compare_sorters, comparison_sorters};
+    use crate::algorithm::sort::{CountingSort, LsdRadixSort};
+
+    #[test]
+    fn every_comparison_sorter_is_correct_on_every_distribution() {
+        let reports = compare_sorters(&comparison_sorters(), &Distribution::ALL, 300, 11);
+        assert_eq!(reports.len(), 10 * 4);
+        for report in &reports {
+            assert!(report.correct, "{report}");
+            assert!(report.comparisons.unwrap() > 0, "{report}");
+        }
+    }
+
+    #[test]
+    fn counts_reflect_algorithmic_differences() {
+        let reports = compare_sorters(&comparison_sorters(), &[Distribution::Random], 500, 3);
+        let comparisons = |name: &str| {
+            reports
+                .iter()
+                .find(|r| r.sorter == name)
+                .and_then(|r| r.comparisons)
+                .unwrap()
+        };
+        assert_eq!(comparisons("selection_sort"), 500 * 499 / 2);
+        assert!(comparisons("heapsort") < comparisons("insertion_sort"));
+        assert!(comparisons("timsort") < comparisons("bubble_sort"));
+
+        let swaps = |name: &str| reports.iter().find(|r| r.sorter == name).unwrap().swaps;
+        // Selection sort compares most but moves each element at most once
+        assert!(swaps("selection_sort").unwrap() < 500);
+        assert!(swaps("insertion_sort").unwrap() > 500 * 499 / 8);
+        assert_eq!(swaps("insertion_sort"), swaps("bubble_sort"));
+        assert_eq!(swaps("timsort"), None);
+    }
+
+    #[test]
+    fn integer_sorters_are_correct() {
+        let mut reports = check_integer_sorters(&[&LsdRadixSort], &Distribution::ALL, 1000, 5);
+        // Keys drawn from all of u32 span far more values than counting sort
+        // will allocate for, so only the few-uniques input suits it
+        reports.extend(check_integer_sorters(
+            &[&CountingSort],
+            &[Distribution::FewUniques],
+            1000,
+            5,
+        ));
+        assert_eq!(reports.len(), 5);
+        assert!(reports.iter().all(|r| r.correct && r.comparisons.is_none()));
+    }
+}
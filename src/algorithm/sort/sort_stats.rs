@@ -0,0 +1,47 @@
+/// Counts the comparisons and swaps performed by an instrumented sort
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SortStats {
+    pub comparisons: u64,
+    pub swaps: u64,
+}
+
+impl SortStats {
+    /// Creates a new zeroed counter
+    pub fn new() -> SortStats {
+        SortStats::default()
+    }
+
+    /// Returns `a < b`, recording one comparison
+    pub fn less<T: Ord>(&mut self, a: &T, b: &T) -> bool {
+        self.comparisons += 1;
+        a < b
+    }
+
+    /// Swaps `arr[i]` and `arr[j]`, recording one swap
+    pub fn swap<T>(&mut self, arr: &mut [T], i: usize, j: usize) {
+        self.swaps += 1;
+        arr.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SortStats;
+
+    #[test]
+    fn records_comparisons_and_swaps() {
+        let mut stats = SortStats::new();
+        let mut arr = [2, 1];
+        if stats.less(&arr[1], &arr[0]) {
+            stats.swap(&mut arr, 0, 1);
+        }
+        assert_eq!(arr, [1, 2]);
+        assert_eq!(
+            stats,
+            SortStats {
+                comparisons: 1,
+                swaps: 1
+            }
+        );
+    }
+}
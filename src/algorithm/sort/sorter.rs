@@ -0,0 +1,184 @@
+use super::{
+    GapSequence, RadixKey, SortStats, bubble_sort, bubble_sort_counted, bucket_sort, counting_sort,
+    gnome_sort, gnome_sort_counted, heapsort, insertion_sort, insertion_sort_counted, introsort,
+    lsd_radix_sort, msd_radix_sort, par_merge_sort, par_quicksort, selection_sort,
+    selection_sort_counted, shell_sort, shell_sort_counted, timsort,
+};
+
+/// A sorting algorithm that can sort slices of `T`, so algorithms can be swapped and compared
+pub trait Sorter<T> {
+    /// A short identifier for reports
+    fn name(&self) -> &'static str;
+
+    /// Sorts `arr` in ascending order
+    fn sort(&self, arr: &mut [T]);
+
+    /// Sorts like `sort`, recording comparisons and swaps in `stats`. Returns
+    /// false, leaving `stats` untouched, for sorters that are not instrumented.
+    fn sort_counted(&self, arr: &mut [T], stats: &mut SortStats) -> bool {
+        let _ = stats;
+        self.sort(arr);
+        false
+    }
+}
+
+pub struct BubbleSort;
+pub struct InsertionSort;
+pub struct SelectionSort;
+pub struct GnomeSort;
+pub struct HeapSort;
+pub struct ShellSort(pub GapSequence);
+pub struct TimSort;
+pub struct IntroSort;
+pub struct ParMergeSort;
+pub struct ParQuickSort;
+pub struct LsdRadixSort;
+pub struct MsdRadixSort;
+/// Panics if the keys span more than `MAX_COUNTING_RANGE` values
+pub struct CountingSort;
+/// Panics if a value is outside [0, 1)
+pub struct BucketSort;
+
+macro_rules! impl_comparison_sorter {
+    ($sorter:ty, $name:literal, $sort:path, counted: $counted:path) => {
+        impl<T: Ord> Sorter<T> for $sorter {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn sort(&self, arr: &mut [T]) {
+                $sort(arr)
+            }
+
+            fn sort_counted(&self, arr: &mut [T], stats: &mut SortStats) -> bool {
+                $counted(arr, stats);
+                true
+            }
+        }
+    };
+    ($sorter:ty, $name:literal, $sort:path $(, $bound:path)*) => {
+        impl<T: Ord $(+ $bound)*> Sorter<T> for $sorter {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn sort(&self, arr: &mut [T]) {
+                $sort(arr)
+            }
+        }
+    };
+}
+
+impl_comparison_sorter!(BubbleSort, "bubble_sort", bubble_sort, counted: bubble_sort_counted);
+impl_comparison_sorter!(
+    InsertionSort,
+    "insertion_sort",
+    insertion_sort,
+    counted: insertion_sort_counted
+);
+impl_comparison_sorter!(
+    SelectionSort,
+    "selection_sort",
+    selection_sort,
+    counted: selection_sort_counted
+);
+impl_comparison_sorter!(GnomeSort, "gnome_sort", gnome_sort, counted: gnome_sort_counted);
+impl_comparison_sorter!(HeapSort, "heapsort", heapsort);
+impl_comparison_sorter!(TimSort, "timsort", timsort, Clone);
+impl_comparison_sorter!(IntroSort, "introsort", introsort);
+impl_comparison_sorter!(
+    ParMergeSort,
+    "par_merge_sort",
+    par_merge_sort,
+    Clone,
+    Send,
+    Sync
+);
+impl_comparison_sorter!(ParQuickSort, "par_quicksort", par_quicksort, Send);
+
+impl<T: Ord> Sorter<T> for ShellSort {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            GapSequence::Shell => "shell_sort(shell)",
+            GapSequence::Knuth => "shell_sort(knuth)",
+            GapSequence::Sedgewick => "shell_sort(sedgewick)",
+            GapSequence::Ciura => "shell_sort(ciura)",
+            GapSequence::Custom(_) => "shell_sort(custom)",
+        }
+    }
+
+    fn sort(&self, arr: &mut [T]) {
+        shell_sort(arr, &self.0)
+    }
+
+    fn sort_counted(&self, arr: &mut [T], stats: &mut SortStats) -> bool {
+        shell_sort_counted(arr, &self.0, stats);
+        true
+    }
+}
+
+impl<T: RadixKey> Sorter<T> for LsdRadixSort {
+    fn name(&self) -> &'static str {
+        "lsd_radix_sort"
+    }
+
+    fn sort(&self, arr: &mut [T]) {
+        lsd_radix_sort(arr)
+    }
+}
+
+impl<S: AsRef<[u8]> + Clone> Sorter<S> for MsdRadixSort {
+    fn name(&self) -> &'static str {
+        "msd_radix_sort"
+    }
+
+    fn sort(&self, arr: &mut [S]) {
+        msd_radix_sort(arr)
+    }
+}
+
+impl<T: Copy + Into<i64>> Sorter<T> for CountingSort {
+    fn name(&self) -> &'static str {
+        "counting_sort"
+    }
+
+    fn sort(&self, arr: &mut [T]) {
+        counting_sort(arr, |&x| x.into()).expect("key range too large for counting sort")
+    }
+}
+
+impl Sorter<f64> for BucketSort {
+    fn name(&self) -> &'static str {
+        "bucket_sort"
+    }
+
+    fn sort(&self, arr: &mut [f64]) {
+        bucket_sort(arr).expect("bucket sort input must lie in [0, 1)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketSort, CountingSort, HeapSort, LsdRadixSort, MsdRadixSort, Sorter, TimSort};
+
+    #[test]
+    fn sorters_are_interchangeable() {
+        let sorters: [&dyn Sorter<u16>; 4] = [&HeapSort, &TimSort, &LsdRadixSort, &CountingSort];
+        for sorter in sorters {
+            let mut arr = vec![9u16, 4, 4, 0, 65535, 12];
+            sorter.sort(&mut arr);
+            assert_eq!(arr, vec![0, 4, 4, 9, 12, 65535], "{}", sorter.name());
+        }
+    }
+
+    #[test]
+    fn non_comparison_sorters_handle_their_domains() {
+        let mut words = vec!["pear", "fig", "apple"];
+        MsdRadixSort.sort(&mut words);
+        assert_eq!(words, vec!["apple", "fig", "pear"]);
+
+        let mut floats = vec![0.5, 0.25, 0.75];
+        BucketSort.sort(&mut floats);
+        assert_eq!(floats, vec![0.25, 0.5, 0.75]);
+    }
+}
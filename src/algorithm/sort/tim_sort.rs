@@ -0,0 +1,257 @@
+/// Runs shorter than this are never worth computing a minimum run length for
+const MIN_MERGE: usize = 64;
+/// Consecutive wins by one side before a merge switches to galloping
+const MIN_GALLOP: usize = 7;
+
+/// Stably sorts `arr` with a simplified Timsort.
+///
+/// Natural runs are detected (strictly descending ones are reversed), short runs
+/// are extended to a minimum length with binary insertion sort, and runs are
+/// merged under Timsort's stack invariants using galloping merges. Already
+/// sorted or reversed input takes O(n) time.
+pub fn timsort<T: Ord + Clone>(arr: &mut [T]) {
+    let len = arr.len();
+    if len < 2 {
+        return;
+    }
+    let min_run = min_run_length(len);
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+
+    let mut start = 0;
+    while start < len {
+        let mut run_len = count_run_and_make_ascending(&mut arr[start..]);
+        if run_len < min_run {
+            let forced = min_run.min(len - start);
+            binary_insertion_sort(&mut arr[start..start + forced], run_len);
+            run_len = forced;
+        }
+        runs.push((start, run_len));
+        merge_collapse(arr, &mut runs);
+        start += run_len;
+    }
+
+    while runs.len() > 1 {
+        let n = runs.len() - 2;
+        merge_at(arr, &mut runs, n);
+    }
+}
+
+/// Picks a run length in [32, 64] so that len / min_run is close to a power of two
+fn min_run_length(mut len: usize) -> usize {
+    let mut extra = 0;
+    while len >= MIN_MERGE {
+        extra |= len & 1;
+        len >>= 1;
+    }
+    len + extra
+}
+
+fn count_run_and_make_ascending<T: Ord>(arr: &mut [T]) -> usize {
+    if arr.len() < 2 {
+        return arr.len();
+    }
+    let mut end = 2;
+    if arr[1] < arr[0] {
+        // Only strictly descending runs may be reversed without breaking stability
+        while end < arr.len() && arr[end] < arr[end - 1] {
+            end += 1;
+        }
+        arr[..end].reverse();
+    } else {
+        while end < arr.len() && arr[end] >= arr[end - 1] {
+            end += 1;
+        }
+    }
+    end
+}
+
+/// Sorts `arr` given that `arr[..sorted]` is already sorted
+fn binary_insertion_sort<T: Ord>(arr: &mut [T], sorted: usize) {
+    for i in sorted.max(1)..arr.len() {
+        let pos = arr[..i].partition_point(|x| x <= &arr[i]);
+        arr[pos..=i].rotate_right(1);
+    }
+}
+
+fn merge_collapse<T: Ord + Clone>(arr: &mut [T], runs: &mut Vec<(usize, usize)>) {
+    while runs.len() > 1 {
+        let mut n = runs.len() - 2;
+        let len = |i: usize| runs[i].1;
+        if (n > 0 && len(n - 1) <= len(n) + len(n + 1))
+            || (n > 1 && len(n - 2) <= len(n - 1) + len(n))
+        {
+            if len(n - 1) < len(n + 1) {
+                n -= 1;
+            }
+        } else if len(n) > len(n + 1) {
+            break;
+        }
+        merge_at(arr, runs, n);
+    }
+}
+
+fn merge_at<T: Ord + Clone>(arr: &mut [T], runs: &mut Vec<(usize, usize)>, i: usize) {
+    let (start, left_len) = runs[i];
+    let right_len = runs[i + 1].1;
+    merge(&mut arr[start..start + left_len + right_len], left_len);
+    runs[i].1 = left_len + right_len;
+    runs.remove(i + 1);
+}
+
+/// Merges the sorted halves `arr[..mid]` and `arr[mid..]`, galloping through long streaks
+fn merge<T: Ord + Clone>(arr: &mut [T], mid: usize) {
+    // Elements of the left run already smaller than the right run's first stay put
+    let skip = gallop_right(&arr[mid], &arr[..mid]);
+    if skip == mid {
+        return;
+    }
+    let left: Vec<T> = arr[skip..mid].to_vec();
+    let (mut i, mut j, mut k) = (0, mid, skip);
+    let (mut left_wins, mut right_wins) = (0, 0);
+
+    while i < left.len() && j < arr.len() {
+        if arr[j] < left[i] {
+            arr.swap(k, j);
+            k += 1;
+            j += 1;
+            right_wins += 1;
+            left_wins = 0;
+            if right_wins >= MIN_GALLOP {
+                let count = gallop_left(&left[i], &arr[j..]);
+                for _ in 0..count {
+                    arr.swap(k, j);
+                    k += 1;
+                    j += 1;
+                }
+                right_wins = 0;
+            }
+        } else {
+            arr[k] = left[i].clone();
+            k += 1;
+            i += 1;
+            left_wins += 1;
+            right_wins = 0;
+            if left_wins >= MIN_GALLOP && j < arr.len() {
+                let count = gallop_right(&arr[j], &left[i..]);
+                arr[k..k + count].clone_from_slice(&left[i..i + count]);
+                k += count;
+                i += count;
+                left_wins = 0;
+            }
+        }
+    }
+    // Any leftover right-run elements are already in their final place
+    let rest = left.len() - i;
+    arr[k..k + rest].clone_from_slice(&left[i..]);
+}
+
+/// Number of leading elements of sorted `run` that are <= `key`, found by exponential search
+fn gallop_right<T: Ord>(key: &T, run: &[T]) -> usize {
+    gallop(run, |x| x <= key)
+}
+
+/// Number of leading elements of sorted `run` that are < `key`, found by exponential search
+fn gallop_left<T: Ord>(key: &T, run: &[T]) -> usize {
+    gallop(run, |x| x < key)
+}
+
+fn gallop<T, F: Fn(&T) -> bool>(run: &[T], pred: F) -> usize {
+    let mut bound = 1;
+    while bound <= run.len() && pred(&run[bound - 1]) {
+        bound *= 2;
+    }
+    let lo = bound / 2;
+    let hi = bound.min(run.len());
+    lo + run[lo..hi].partition_point(pred)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{min_run_length, timsort};
+    use crate::algorithm::random::{Rng, XorShift64Star};
+    use std::cmp::Ordering;
+
+    fn pseudo_random(len: usize, seed: u64) -> Vec<u32> {
+        let mut rng = XorShift64Star::seed_from_u64(seed);
+        (0..len).map(|_| rng.gen_range(0..1000) as u32).collect()
+    }
+
+    fn check(data: Vec<u32>) {
+        let mut expected = data.clone();
+        expected.sort();
+        let mut arr = data;
+        timsort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn min_run_is_between_32_and_64() {
+        assert_eq!(min_run_length(10), 10);
+        assert_eq!(min_run_length(64), 32);
+        assert_eq!(min_run_length(65), 33);
+        for len in 64..5000 {
+            assert!((32..=64).contains(&min_run_length(len)));
+        }
+    }
+
+    #[test]
+    fn sorts_random_input() {
+        for len in [0, 1, 2, 31, 64, 65, 1000, 5000] {
+            check(pseudo_random(len, len as u64 + 1));
+        }
+    }
+
+    #[test]
+    fn sorts_partially_sorted_input() {
+        let mut data: Vec<u32> = (0..3000).collect();
+        for i in (0..3000).step_by(100) {
+            data.swap(i, (i * 7) % 3000);
+        }
+        check(data.clone());
+
+        // Long ascending and descending runs glued together
+        let mut runs: Vec<u32> = (0..1000).collect();
+        runs.extend((0..1000).rev());
+        runs.extend(500..1500);
+        check(runs);
+    }
+
+    #[test]
+    fn sorts_organ_pipe_input() {
+        let mut data: Vec<u32> = (0..2000).collect();
+        data.extend((0..2000).rev());
+        check(data);
+    }
+
+    #[test]
+    fn is_stable() {
+        #[derive(Debug, Clone)]
+        struct Keyed(u32, usize);
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Keyed {}
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut arr: Vec<Keyed> = pseudo_random(4000, 7)
+            .into_iter()
+            .enumerate()
+            .map(|(i, k)| Keyed(k % 10, i))
+            .collect();
+        timsort(&mut arr);
+        for pair in arr.windows(2) {
+            assert!(pair[0].0 < pair[1].0 || (pair[0].0 == pair[1].0 && pair[0].1 < pair[1].1));
+        }
+    }
+}
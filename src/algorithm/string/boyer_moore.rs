@@ -0,0 +1,119 @@
+/// Returns the byte offsets of every (possibly overlapping) occurrence of
+/// `pattern` in `text` with Boyer–Moore.
+///
+/// The pattern is compared right to left, and after a mismatch the window
+/// slides by the larger of the bad-character shift (align the mismatched text
+/// byte with its last occurrence in the pattern) and the good-suffix shift
+/// (align the matched suffix with another occurrence of it). Sublinear on
+/// typical text; O(n + m) per match in the worst case.
+pub fn boyer_moore_search(text: &str, pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    let (n, m) = (text.len(), pattern.len());
+    let last = last_occurrences(pattern);
+    let good_suffix = good_suffix_shifts(pattern);
+    let mut matches = Vec::new();
+    let mut s = 0;
+    while s + m <= n {
+        let mut j = m;
+        while j > 0 && pattern[j - 1] == text[s + j - 1] {
+            j -= 1;
+        }
+        if j == 0 {
+            matches.push(s);
+            s += good_suffix[0];
+        } else {
+            let bad_char = (j - 1) as isize - last[text[s + j - 1] as usize];
+            s += good_suffix[j].max(bad_char.max(1) as usize);
+        }
+    }
+    matches
+}
+
+/// Returns the byte offsets of every (possibly overlapping) occurrence of
+/// `pattern` in `text` with Boyer–Moore–Horspool.
+///
+/// Drops the good-suffix rule and always shifts by the bad-character distance
+/// of the text byte under the window's last position. Simpler tables and
+/// usually just as fast on natural text, but O(nm) in the worst case.
+pub fn horspool_search(text: &str, pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    let (n, m) = (text.len(), pattern.len());
+    let mut shift = [m; 256];
+    for (i, &byte) in pattern[..m - 1].iter().enumerate() {
+        shift[byte as usize] = m - 1 - i;
+    }
+    let mut matches = Vec::new();
+    let mut s = 0;
+    while s + m <= n {
+        if text[s..s + m] == *pattern {
+            matches.push(s);
+        }
+        s += shift[text[s + m - 1] as usize];
+    }
+    matches
+}
+
+/// Index of each byte's last occurrence in `pattern`, or -1
+fn last_occurrences(pattern: &[u8]) -> [isize; 256] {
+    let mut last = [-1; 256];
+    for (i, &byte) in pattern.iter().enumerate() {
+        last[byte as usize] = i as isize;
+    }
+    last
+}
+
+/// Strong good-suffix table: `shift[j]` is how far to slide when the mismatch
+/// happens at `pattern[j - 1]`, so `pattern[j..]` matched; `shift[0]` after a full match.
+fn good_suffix_shifts(pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    let mut shift = vec![0; m + 1];
+    // border[i]: start of the widest border of pattern[i..]
+    let mut border = vec![0; m + 1];
+    let (mut i, mut j) = (m, m + 1);
+    border[i] = j;
+    while i > 0 {
+        while j <= m && pattern[i - 1] != pattern[j - 1] {
+            if shift[j] == 0 {
+                shift[j] = j - i;
+            }
+            j = border[j];
+        }
+        i -= 1;
+        j -= 1;
+        border[i] = j;
+    }
+    // Suffixes with no other occurrence fall back to the widest border of the whole pattern
+    let mut j = border[0];
+    for (i, slot) in shift.iter_mut().enumerate() {
+        if *slot == 0 {
+            *slot = j;
+        }
+        if i == j {
+            j = border[j];
+        }
+    }
+    shift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{boyer_moore_search, good_suffix_shifts};
+
+    #[test]
+    fn good_suffix_table() {
+        assert_eq!(good_suffix_shifts(b"ABBABAB"), vec![5, 5, 5, 5, 2, 5, 4, 1]);
+    }
+
+    #[test]
+    fn skips_past_mismatches() {
+        let text = "HERE IS A SIMPLE EXAMPLE";
+        assert_eq!(boyer_moore_search(text, "EXAMPLE"), vec![17]);
+        assert_eq!(boyer_moore_search(text, "AMPLE"), vec![19]);
+    }
+}
@@ -0,0 +1,210 @@
+use std::ops::Range;
+
+use super::EditOp;
+
+/// A run of changes: `a[old]` is deleted and `b[new]` is inserted in its place.
+///
+/// Either range may be empty for a pure insertion or deletion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    pub old: Range<usize>,
+    pub new: Range<usize>,
+}
+
+/// The shortest edit script turning `a` into `b`, using only `Keep`, `Insert`
+/// and `Delete`, found with Myers' O((n + m) * D) algorithm.
+///
+/// Each round d extends, for every diagonal k = x - y reachable with d edits,
+/// the furthest-reaching path along a "snake" of matching elements. The kept
+/// elements form a longest common subsequence of `a` and `b`.
+pub fn diff_ops<T: PartialEq>(a: &[T], b: &[T]) -> Vec<EditOp> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+    let offset = max + 1;
+    // v[k + offset] is the furthest x reached on diagonal k
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    let mut trace = Vec::new();
+    'search: for d in 0..=max {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let at = |k: isize| (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    // Walk the recorded rounds backwards from (n, m)
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| (k + offset) as usize;
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[at(prev_k)];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push(EditOp::Keep {
+                a: x as usize,
+                b: y as usize,
+            });
+        }
+        if d > 0 {
+            if x == prev_x {
+                ops.push(EditOp::Insert { b: prev_y as usize });
+            } else {
+                ops.push(EditOp::Delete { a: prev_x as usize });
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
+/// Diffs `a` against `b`, grouping the edits of `diff_ops` into hunks separated
+/// by unchanged elements. Typically used with the lines of two texts.
+pub fn diff<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Hunk> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut in_hunk = false;
+    for op in diff_ops(a, b) {
+        if let EditOp::Keep { .. } = op {
+            i += 1;
+            j += 1;
+            in_hunk = false;
+            continue;
+        }
+        if !in_hunk {
+            hunks.push(Hunk {
+                old: i..i,
+                new: j..j,
+            });
+            in_hunk = true;
+        }
+        let hunk = hunks.last_mut().expect("a hunk was just opened");
+        match op {
+            EditOp::Delete { .. } => {
+                i += 1;
+                hunk.old.end = i;
+            }
+            EditOp::Insert { .. } => {
+                j += 1;
+                hunk.new.end = j;
+            }
+            EditOp::Keep { .. } | EditOp::Substitute { .. } => unreachable!(),
+        }
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hunk, diff, diff_ops};
+    use crate::algorithm::string::{EditOp, lcs_length};
+
+    fn apply(a: &[&str], b: &[&str], hunks: &[Hunk]) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        for hunk in hunks {
+            out.extend(a[i..hunk.old.start].iter().map(|s| s.to_string()));
+            out.extend(b[hunk.new.clone()].iter().map(|s| s.to_string()));
+            i = hunk.old.end;
+        }
+        out.extend(a[i..].iter().map(|s| s.to_string()));
+        out
+    }
+
+    #[test]
+    fn line_diff_produces_hunks() {
+        let a = ["fn main() {", "    let x = 1;", "    println!(x);", "}"];
+        let b = [
+            "fn main() {",
+            "    let x = 2;",
+            "    println!(x);",
+            "    done();",
+            "}",
+        ];
+        let hunks = diff(&a, &b);
+        assert_eq!(
+            hunks,
+            vec![
+                Hunk {
+                    old: 1..2,
+                    new: 1..2
+                },
+                Hunk {
+                    old: 3..3,
+                    new: 3..4
+                },
+            ]
+        );
+        assert_eq!(apply(&a, &b, &hunks), b);
+    }
+
+    #[test]
+    fn edge_cases() {
+        let empty: [&str; 0] = [];
+        assert_eq!(diff(&empty, &empty), vec![]);
+        assert_eq!(
+            diff(&empty, &["a", "b"]),
+            vec![Hunk {
+                old: 0..0,
+                new: 0..2
+            }]
+        );
+        assert_eq!(
+            diff(&["a", "b"], &empty),
+            vec![Hunk {
+                old: 0..2,
+                new: 0..0
+            }]
+        );
+        assert_eq!(diff(&["a"], &["a"]), vec![]);
+    }
+
+    #[test]
+    fn script_is_minimal() {
+        let pairs: [(&[u8], &[u8]); 5] = [
+            (b"ABCABBA", b"CBABAC"),
+            (b"kitten", b"sitting"),
+            (b"", b"xyz"),
+            (b"the quick brown fox", b"a quick brown dog jumps"),
+            (b"aaaa", b"aaaa"),
+        ];
+        for (a, b) in pairs {
+            let ops = diff_ops(a, b);
+            let kept = ops
+                .iter()
+                .filter(|op| matches!(op, EditOp::Keep { .. }))
+                .count();
+            assert_eq!(kept, lcs_length(a, b));
+            assert_eq!(ops.len() - kept, a.len() + b.len() - 2 * kept);
+
+            let strings_a: Vec<String> = a.iter().map(|&c| (c as char).to_string()).collect();
+            let strings_b: Vec<String> = b.iter().map(|&c| (c as char).to_string()).collect();
+            let ra: Vec<&str> = strings_a.iter().map(String::as_str).collect();
+            let rb: Vec<&str> = strings_b.iter().map(String::as_str).collect();
+            assert_eq!(apply(&ra, &rb, &diff(&ra, &rb)), strings_b);
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// One step of an edit script turning `a` into `b`, by index into each sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// `a[a]` is kept as `b[b]`
+    Keep { a: usize, b: usize },
+    /// `a[a]` is replaced by `b[b]`
+    Substitute { a: usize, b: usize },
+    /// `b[b]` is inserted
+    Insert { b: usize },
+    /// `a[a]` is deleted
+    Delete { a: usize },
+}
+
+/// Levenshtein distance between `a` and `b` in O(nm) time and O(min(n, m)) space
+pub fn levenshtein<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    // Keep the row as short as possible
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        // `diagonal` holds the previous row's value at j - 1
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitute = diagonal + usize::from(x != y);
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The full Levenshtein table: `table[i][j]` is the distance between `a[..i]` and `b[..j]`
+pub fn levenshtein_matrix<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitute = table[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            table[i][j] = substitute.min(table[i - 1][j] + 1).min(table[i][j - 1] + 1);
+        }
+    }
+    table
+}
+
+/// Levenshtein distance if it is at most `max_distance`, otherwise None.
+///
+/// Only cells within `max_distance` of the diagonal are filled (Ukkonen's
+/// band), so this runs in O(k * min(n, m)) time.
+pub fn levenshtein_banded<T: PartialEq>(a: &[T], b: &[T], max_distance: usize) -> Option<usize> {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if a.len() - b.len() > max_distance {
+        return None;
+    }
+    // Cells outside the band are treated as unreachable
+    let outside = max_distance + 1;
+    let mut prev: Vec<usize> = (0..=b.len()).map(|j| j.min(outside)).collect();
+    let mut row = vec![outside; b.len() + 1];
+    for i in 1..=a.len() {
+        let lo = i.saturating_sub(max_distance).max(1);
+        let hi = (i + max_distance).min(b.len());
+        row.fill(outside);
+        row[0] = i.min(outside);
+        for j in lo..=hi {
+            let substitute = prev[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            row[j] = substitute.min(prev[j] + 1).min(row[j - 1] + 1).min(outside);
+        }
+        if row[lo.saturating_sub(1)..=hi].iter().all(|&d| d >= outside) {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut row);
+    }
+    Some(prev[b.len()]).filter(|&d| d <= max_distance)
+}
+
+/// A minimal edit script turning `a` into `b`, in order of position.
+///
+/// Backtracks through `levenshtein_matrix`, so it takes O(nm) space. The number
+/// of non-`Keep` operations equals `levenshtein(a, b)`.
+pub fn alignment<T: PartialEq>(a: &[T], b: &[T]) -> Vec<EditOp> {
+    let table = levenshtein_matrix(a, b);
+    let mut ops = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let same = a[i - 1] == b[j - 1];
+            if table[i][j] == table[i - 1][j - 1] + usize::from(!same) {
+                ops.push(if same {
+                    EditOp::Keep { a: i - 1, b: j - 1 }
+                } else {
+                    EditOp::Substitute { a: i - 1, b: j - 1 }
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && table[i][j] == table[i - 1][j] + 1 {
+            ops.push(EditOp::Delete { a: i - 1 });
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert { b: j - 1 });
+            j -= 1;
+        }
+    }
+    ops.reverse();
+    ops
+}
+
+/// Damerau–Levenshtein distance, which also counts swapping two adjacent
+/// elements as a single edit.
+///
+/// This is the unrestricted distance (Lowrance–Wagner): a transposed pair may
+/// be edited again, unlike the optimal string alignment variant.
+pub fn damerau_levenshtein<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let unreachable = n + m;
+    // Row and column 0 of `table` are sentinels; the real table is offset by one
+    let mut table = vec![vec![0; m + 2]; n + 2];
+    table[0][0] = unreachable;
+    for i in 0..=n {
+        table[i + 1][0] = unreachable;
+        table[i + 1][1] = i;
+    }
+    for j in 0..=m {
+        table[0][j + 1] = unreachable;
+        table[1][j + 1] = j;
+    }
+    // Last row in which each element of `a` was seen
+    let mut last_row: HashMap<&T, usize> = HashMap::new();
+    for i in 1..=n {
+        let mut last_match_col = 0;
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            table[i + 1][j + 1] = (table[i][j] + cost)
+                .min(table[i + 1][j] + 1)
+                .min(table[i][j + 1] + 1)
+                .min(table[k][l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+        last_row.insert(&a[i - 1], i);
+    }
+    table[n + 1][m + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        EditOp, alignment, damerau_levenshtein, levenshtein, levenshtein_banded, levenshtein_matrix,
+    };
+
+    const PAIRS: [(&str, &str, usize); 8] = [
+        ("", "", 0),
+        ("", "abc", 3),
+        ("kitten", "sitting", 3),
+        ("flaw", "lawn", 2),
+        ("saturday", "sunday", 3),
+        ("gumbo", "gambol", 2),
+        ("abc", "abc", 0),
+        ("intention", "execution", 5),
+    ];
+
+    #[test]
+    fn variants_agree_on_known_distances() {
+        for (a, b, expected) in PAIRS {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            assert_eq!(levenshtein(a, b), expected);
+            assert_eq!(levenshtein(b, a), expected);
+            assert_eq!(levenshtein_matrix(a, b)[a.len()][b.len()], expected);
+            assert_eq!(levenshtein_banded(a, b, expected), Some(expected));
+            assert_eq!(levenshtein_banded(a, b, expected + 3), Some(expected));
+            if expected > 0 {
+                assert_eq!(levenshtein_banded(a, b, expected - 1), None);
+            }
+        }
+    }
+
+    #[test]
+    fn alignment_replays_to_target() {
+        for (a, b, expected) in PAIRS {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            let ops = alignment(a, b);
+            let edits = ops
+                .iter()
+                .filter(|op| !matches!(op, EditOp::Keep { .. }))
+                .count();
+            assert_eq!(edits, expected);
+
+            let mut rebuilt = Vec::new();
+            let mut next_a = 0;
+            for op in ops {
+                match op {
+                    EditOp::Keep { a: i, b: j } => {
+                        assert_eq!(a[i], b[j]);
+                        assert_eq!(i, next_a);
+                        rebuilt.push(a[i]);
+                        next_a += 1;
+                    }
+                    EditOp::Substitute { a: i, b: j } => {
+                        assert_eq!(i, next_a);
+                        rebuilt.push(b[j]);
+                        next_a += 1;
+                    }
+                    EditOp::Insert { b: j } => rebuilt.push(b[j]),
+                    EditOp::Delete { a: i } => {
+                        assert_eq!(i, next_a);
+                        next_a += 1;
+                    }
+                }
+            }
+            assert_eq!(next_a, a.len());
+            assert_eq!(rebuilt, b);
+        }
+    }
+
+    #[test]
+    fn kitten_to_sitting_script() {
+        assert_eq!(
+            alignment(b"kitten", b"sitting"),
+            vec![
+                EditOp::Substitute { a: 0, b: 0 },
+                EditOp::Keep { a: 1, b: 1 },
+                EditOp::Keep { a: 2, b: 2 },
+                EditOp::Keep { a: 3, b: 3 },
+                EditOp::Substitute { a: 4, b: 4 },
+                EditOp::Keep { a: 5, b: 5 },
+                EditOp::Insert { b: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn damerau_counts_transpositions_once() {
+        assert_eq!(damerau_levenshtein(b"ab", b"ba"), 1);
+        assert_eq!(levenshtein(b"ab", b"ba"), 2);
+        // Optimal string alignment would give 3 here
+        assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+        assert_eq!(damerau_levenshtein(b"", b"abc"), 3);
+        for (a, b, expected) in PAIRS {
+            assert!(damerau_levenshtein(a.as_bytes(), b.as_bytes()) <= expected);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+/// Returns the prefix function of `pattern`: entry `i` is the length of the
+/// longest proper prefix of `pattern[..=i]` that is also a suffix of it.
+///
+/// Computed in O(m) over the bytes of `pattern`.
+pub fn failure_function(pattern: &str) -> Vec<usize> {
+    let pattern = pattern.as_bytes();
+    let mut failure = vec![0; pattern.len()];
+    let mut len = 0;
+    for i in 1..pattern.len() {
+        while len > 0 && pattern[i] != pattern[len] {
+            len = failure[len - 1];
+        }
+        if pattern[i] == pattern[len] {
+            len += 1;
+        }
+        failure[i] = len;
+    }
+    failure
+}
+
+/// Returns the byte offsets of every (possibly overlapping) occurrence of
+/// `pattern` in `text`, in O(n + m) time.
+///
+/// An empty pattern matches at every offset from 0 to `text.len()`.
+pub fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    let failure = failure_function(pattern);
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    let mut matches = Vec::new();
+    let mut len = 0;
+    for (i, &byte) in text.iter().enumerate() {
+        while len > 0 && byte != pattern[len] {
+            len = failure[len - 1];
+        }
+        if byte == pattern[len] {
+            len += 1;
+        }
+        if len == pattern.len() {
+            matches.push(i + 1 - len);
+            len = failure[len - 1];
+        }
+    }
+    matches
+}
+
+/// Returns the length of the shortest `p` such that `s` is a prefix of `s[..p]` repeated.
+///
+/// `s` is an exact repetition of its period when the period divides `s.len()`.
+pub fn smallest_period(s: &str) -> usize {
+    match failure_function(s).last() {
+        Some(&border) => s.len() - border,
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{failure_function, kmp_search, smallest_period};
+
+    #[test]
+    fn failure_function_of_classic_patterns() {
+        assert_eq!(failure_function("ababaca"), vec![0, 0, 1, 2, 3, 0, 1]);
+        assert_eq!(failure_function("aaaa"), vec![0, 1, 2, 3]);
+        assert!(failure_function("").is_empty());
+    }
+
+    #[test]
+    fn finds_overlapping_matches() {
+        assert_eq!(kmp_search("abababab", "abab"), vec![0, 2, 4]);
+        assert_eq!(kmp_search("aaaaa", "aa"), vec![0, 1, 2, 3]);
+        assert_eq!(kmp_search("hello world", "o"), vec![4, 7]);
+        assert_eq!(kmp_search("hello", "xyz"), Vec::<usize>::new());
+        assert_eq!(kmp_search("ab", "abc"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn empty_pattern_matches_everywhere() {
+        assert_eq!(kmp_search("abc", ""), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn offsets_are_in_bytes() {
+        assert_eq!(kmp_search("héllo héllo", "llo"), vec![3, 10]);
+    }
+
+    #[test]
+    fn detects_periods() {
+        assert_eq!(smallest_period("abcabcabc"), 3);
+        assert_eq!(smallest_period("abcabca"), 3);
+        assert_eq!(smallest_period("abcd"), 4);
+        assert_eq!(smallest_period(""), 0);
+    }
+}
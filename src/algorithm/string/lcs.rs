@@ -0,0 +1,91 @@
+/// The LCS table: `table[i][j]` is the length of the longest common
+/// subsequence of `a[..i]` and `b[..j]`
+pub fn lcs_table<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            table[i + 1][j + 1] = if x == y {
+                table[i][j] + 1
+            } else {
+                table[i][j + 1].max(table[i + 1][j])
+            };
+        }
+    }
+    table
+}
+
+/// Length of the longest common subsequence in O(nm) time and O(min(n, m)) space
+pub fn lcs_length<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut row = vec![0; b.len() + 1];
+    for x in a {
+        // `diagonal` holds the previous row's value at j
+        let mut diagonal = 0;
+        for (j, y) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// A longest common subsequence of `a` and `b`, recovered by backtracking `lcs_table`
+pub fn lcs<T: PartialEq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let table = lcs_table(a, b);
+    let mut common = Vec::with_capacity(table[a.len()][b.len()]);
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            common.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    common.reverse();
+    common
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lcs, lcs_length, lcs_table};
+
+    fn is_subsequence(sub: &[u8], of: &[u8]) -> bool {
+        let mut rest = of.iter();
+        sub.iter().all(|x| rest.any(|y| y == x))
+    }
+
+    #[test]
+    fn known_subsequences() {
+        assert_eq!(lcs(b"ABCBDAB", b"BDCABA").len(), 4);
+        assert_eq!(lcs(b"AGGTAB", b"GXTXAYB"), b"GTAB");
+        assert_eq!(lcs(b"abc", b"def"), b"");
+        assert_eq!(lcs(b"", b"abc"), b"");
+        assert_eq!(lcs(b"same", b"same"), b"same");
+    }
+
+    #[test]
+    fn length_matches_table_and_subsequence() {
+        let pairs: [(&[u8], &[u8]); 4] = [
+            (b"ABCBDAB", b"BDCABA"),
+            (b"kitten", b"sitting"),
+            (b"the quick brown fox", b"a quick brown dog"),
+            (b"x", b""),
+        ];
+        for (a, b) in pairs {
+            let common = lcs(a, b);
+            assert!(is_subsequence(&common, a) && is_subsequence(&common, b));
+            assert_eq!(lcs_length(a, b), common.len());
+            assert_eq!(lcs_length(b, a), common.len());
+            assert_eq!(lcs_table(a, b)[a.len()][b.len()], common.len());
+        }
+    }
+}
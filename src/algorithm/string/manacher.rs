@@ -0,0 +1,134 @@
+/// Palindrome radii around every center of a string, as computed by `manacher`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PalindromeRadii {
+    /// `odd[i]` is the number of odd-length palindromes centered on char `i`,
+    /// so the longest one spans `2 * odd[i] - 1` chars
+    pub odd: Vec<usize>,
+    /// `even[i]` is the number of even-length palindromes centered between
+    /// chars `i - 1` and `i`, so the longest one spans `2 * even[i]` chars
+    pub even: Vec<usize>,
+}
+
+/// Computes the odd and even palindrome radii at every char of `s` in O(n)
+pub fn manacher(s: &str) -> PalindromeRadii {
+    let chars: Vec<char> = s.chars().collect();
+    PalindromeRadii {
+        odd: radii(&chars, 1),
+        even: radii(&chars, 0),
+    }
+}
+
+/// Manacher's sweep for odd (`parity == 1`) or even (`parity == 0`) centers
+fn radii(chars: &[char], parity: usize) -> Vec<usize> {
+    let n = chars.len();
+    let mut radius = vec![0; n];
+    // [left, right) is the rightmost palindrome found so far
+    let (mut left, mut right) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < right {
+            radius[left + right - i - parity].min(right - i)
+        } else {
+            parity
+        };
+        while i + k < n && i + parity > k && chars[i + k] == chars[i + parity - k - 1] {
+            k += 1;
+        }
+        radius[i] = k;
+        if i + k > right {
+            left = i + parity - k;
+            right = i + k;
+        }
+    }
+    radius
+}
+
+/// Returns the longest palindromic substring of `s`, the leftmost one on ties
+pub fn longest_palindromic_substring(s: &str) -> &str {
+    let radii = manacher(s);
+    // (start char, length in chars)
+    let mut best = (0, 0);
+    for (i, &r) in radii.odd.iter().enumerate() {
+        if 2 * r - 1 > best.1 {
+            best = (i + 1 - r, 2 * r - 1);
+        }
+    }
+    for (i, &r) in radii.even.iter().enumerate() {
+        if 2 * r > best.1 || (2 * r == best.1 && r > 0 && i - r < best.0) {
+            best = (i - r, 2 * r);
+        }
+    }
+
+    let mut offsets = s.char_indices().map(|(offset, _)| offset).chain([s.len()]);
+    let start = offsets.nth(best.0).unwrap_or(0);
+    let end = if best.1 == 0 {
+        start
+    } else {
+        offsets.nth(best.1 - 1).unwrap_or(s.len())
+    };
+    &s[start..end]
+}
+
+/// Counts the palindromic substrings of `s` by position, so repeats count separately
+pub fn count_palindromic_substrings(s: &str) -> usize {
+    let radii = manacher(s);
+    radii.odd.iter().sum::<usize>() + radii.even.iter().sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_palindromic_substrings, longest_palindromic_substring, manacher};
+    use proptest::prelude::*;
+
+    fn is_palindrome(chars: &[char]) -> bool {
+        chars.iter().eq(chars.iter().rev())
+    }
+
+    /// O(n^2) oracle: the leftmost longest palindrome and the total count
+    fn brute_force(s: &str) -> (String, usize) {
+        let chars: Vec<char> = s.chars().collect();
+        let mut longest: &[char] = &[];
+        let mut count = 0;
+        for i in 0..chars.len() {
+            for j in i + 1..=chars.len() {
+                if is_palindrome(&chars[i..j]) {
+                    count += 1;
+                    if j - i > longest.len() {
+                        longest = &chars[i..j];
+                    }
+                }
+            }
+        }
+        (longest.iter().collect(), count)
+    }
+
+    #[test]
+    fn radii_of_known_string() {
+        let radii = manacher("abaaba");
+        assert_eq!(radii.odd, vec![1, 2, 1, 1, 2, 1]);
+        assert_eq!(radii.even, vec![0, 0, 0, 3, 0, 0]);
+    }
+
+    #[test]
+    fn longest_and_count() {
+        assert_eq!(longest_palindromic_substring("babad"), "bab");
+        assert_eq!(longest_palindromic_substring("cbbd"), "bb");
+        assert_eq!(
+            longest_palindromic_substring("forgeeksskeegfor"),
+            "geeksskeeg"
+        );
+        assert_eq!(longest_palindromic_substring(""), "");
+        assert_eq!(longest_palindromic_substring("été"), "été");
+        assert_eq!(count_palindromic_substrings("aaa"), 6);
+        assert_eq!(count_palindromic_substrings("abc"), 3);
+        assert_eq!(count_palindromic_substrings(""), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(s in "[ab]{0,40}|[a-dé]{0,20}") {
+            let (longest, count) = brute_force(&s);
+            prop_assert_eq!(longest_palindromic_substring(&s), longest);
+            prop_assert_eq!(count_palindromic_substrings(&s), count);
+        }
+    }
+}
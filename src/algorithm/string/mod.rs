@@ -0,0 +1,109 @@
+mod boyer_moore;
+mod diff;
+mod edit_distance;
+mod kmp;
+mod lcs;
+mod manacher;
+mod rabin_karp;
+mod similarity;
+mod suffix_array;
+
+pub use self::boyer_moore::{boyer_moore_search, horspool_search};
+pub use self::diff::{Hunk, diff, diff_ops};
+pub use self::edit_distance::{
+    EditOp, alignment, damerau_levenshtein, levenshtein, levenshtein_banded, levenshtein_matrix,
+};
+pub use self::kmp::{failure_function, kmp_search, smallest_period};
+pub use self::lcs::{lcs, lcs_length, lcs_table};
+pub use self::manacher::{
+    PalindromeRadii, count_palindromic_substrings, longest_palindromic_substring, manacher,
+};
+pub use self::rabin_karp::{
+    RollingHash, rabin_karp_multi, rabin_karp_multi_with_params, rabin_karp_search,
+};
+pub use self::similarity::{
+    Cosine, Hamming, Jaro, JaroWinkler, Similarity, SorensenDice, best_match, cosine_similarity,
+    hamming_distance, jaro, jaro_winkler, jaro_winkler_with_scale, sorensen_dice,
+};
+pub use self::suffix_array::{SuffixArray, lcp_array, suffix_array, suffix_array_doubling};
+
+#[cfg(test)]
+mod tests {
+    use super::{boyer_moore_search, horspool_search, kmp_search, rabin_karp_search};
+    use crate::testgen::TestGen;
+
+    type Search = fn(&str, &str) -> Vec<usize>;
+
+    const SEARCHES: [(&str, Search); 4] = [
+        ("kmp_search", kmp_search),
+        ("rabin_karp_search", rabin_karp_search),
+        ("boyer_moore_search", boyer_moore_search),
+        ("horspool_search", horspool_search),
+    ];
+
+    fn naive(text: &str, pattern: &str) -> Vec<usize> {
+        (0..=text.len())
+            .filter(|&i| text.as_bytes()[i..].starts_with(pattern.as_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn every_search_agrees_with_naive() {
+        let texts = [
+            "",
+            "a",
+            "aaaaaaaaaa",
+            "abracadabra",
+            "HERE IS A SIMPLE EXAMPLE",
+            "abababababbababaabab",
+            "héllo wörld, héllo again",
+            "the quick brown fox jumps over the lazy dog; the end",
+        ];
+        let patterns = [
+            "",
+            "a",
+            "aa",
+            "aaa",
+            "abra",
+            "ab",
+            "bab",
+            "ababa",
+            "EXAMPLE",
+            "llo",
+            "ö",
+            "the",
+            "dog;",
+            "missing",
+            "abracadabra!",
+        ];
+        for text in texts {
+            for pattern in patterns {
+                let expected = naive(text, pattern);
+                for (name, search) in SEARCHES {
+                    assert_eq!(
+                        search(text, pattern),
+                        expected,
+                        "{name} for {pattern:?} in {text:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_search_agrees_on_small_alphabets() {
+        let mut tg = TestGen::new(0x2545_F491);
+        for round in 0..200 {
+            let text = tg.string(40 + round % 7, "ab");
+            let pattern = tg.string(1 + round % 6, "ab");
+            let expected = naive(&text, &pattern);
+            for (name, search) in SEARCHES {
+                assert_eq!(
+                    search(&text, &pattern),
+                    expected,
+                    "{name} for {pattern:?} in {text:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+/// A polynomial hash of a fixed-length byte window that can slide one byte at a time in O(1)
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    base: u64,
+    modulus: u64,
+    /// base^(window length - 1) mod modulus, the weight of the outgoing byte
+    high_power: u64,
+    value: u64,
+}
+
+impl RollingHash {
+    pub const DEFAULT_BASE: u64 = 256;
+    pub const DEFAULT_MODULUS: u64 = 1_000_000_007;
+
+    /// Hashes `window` with the default base and modulus
+    pub fn new(window: &[u8]) -> RollingHash {
+        RollingHash::with_params(window, Self::DEFAULT_BASE, Self::DEFAULT_MODULUS)
+    }
+
+    /// Hashes `window` with a custom base and modulus; panics if `modulus` is zero
+    pub fn with_params(window: &[u8], base: u64, modulus: u64) -> RollingHash {
+        assert!(modulus > 0, "modulus must be positive");
+        let base = base % modulus;
+        let mut hash = RollingHash {
+            base,
+            modulus,
+            high_power: 1 % modulus,
+            value: 0,
+        };
+        for (i, &byte) in window.iter().enumerate() {
+            if i > 0 {
+                hash.high_power = hash.mul_add(hash.high_power, base, 0);
+            }
+            hash.value = hash.mul_add(hash.value, base, byte as u64);
+        }
+        hash
+    }
+
+    /// Returns the hash of the current window
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Slides the window one byte: drops `outgoing` from the front and appends `incoming`
+    pub fn roll(&mut self, outgoing: u8, incoming: u8) {
+        let dropped = self.mul_add(outgoing as u64, self.high_power, 0);
+        // Adding the modulus keeps the difference non-negative; u128 keeps it from overflowing
+        let m = self.modulus as u128;
+        let without = ((self.value as u128 + m - dropped as u128) % m) as u64;
+        self.value = self.mul_add(without, self.base, incoming as u64);
+    }
+
+    /// (a * b + c) mod modulus, computed in u128 so no modulus can overflow it
+    fn mul_add(&self, a: u64, b: u64, c: u64) -> u64 {
+        ((a as u128 * b as u128 + c as u128) % self.modulus as u128) as u64
+    }
+}
+
+/// Returns the byte offsets of every occurrence of `pattern` in `text`.
+///
+/// Hash matches are verified byte by byte, so collisions never produce false positives.
+pub fn rabin_karp_search(text: &str, pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    rabin_karp_multi(text, &[pattern])
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// Finds every occurrence of several equal-length patterns in one pass over `text`.
+///
+/// Returns `(byte offset, pattern index)` pairs ordered by offset.
+/// Panics if the patterns are empty strings or differ in length.
+pub fn rabin_karp_multi(text: &str, patterns: &[&str]) -> Vec<(usize, usize)> {
+    rabin_karp_multi_with_params(
+        text,
+        patterns,
+        RollingHash::DEFAULT_BASE,
+        RollingHash::DEFAULT_MODULUS,
+    )
+}
+
+/// `rabin_karp_multi` with a custom hash base and modulus
+pub fn rabin_karp_multi_with_params(
+    text: &str,
+    patterns: &[&str],
+    base: u64,
+    modulus: u64,
+) -> Vec<(usize, usize)> {
+    let Some(first) = patterns.first() else {
+        return Vec::new();
+    };
+    let len = first.len();
+    assert!(len > 0, "patterns must not be empty");
+    assert!(
+        patterns.iter().all(|p| p.len() == len),
+        "patterns must have equal length"
+    );
+    let text = text.as_bytes();
+    if text.len() < len {
+        return Vec::new();
+    }
+
+    let mut by_hash: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (index, pattern) in patterns.iter().enumerate() {
+        let hash = RollingHash::with_params(pattern.as_bytes(), base, modulus).value();
+        by_hash.entry(hash).or_default().push(index);
+    }
+
+    let mut matches = Vec::new();
+    let mut window = RollingHash::with_params(&text[..len], base, modulus);
+    for start in 0..=text.len() - len {
+        if start > 0 {
+            window.roll(text[start - 1], text[start + len - 1]);
+        }
+        if let Some(candidates) = by_hash.get(&window.value()) {
+            for &index in candidates {
+                if &text[start..start + len] == patterns[index].as_bytes() {
+                    matches.push((start, index));
+                }
+            }
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RollingHash, rabin_karp_multi, rabin_karp_multi_with_params, rabin_karp_search};
+
+    #[test]
+    fn rolling_matches_fresh_hash() {
+        let text = b"the quick brown fox";
+        let mut rolling = RollingHash::new(&text[..5]);
+        for start in 1..=text.len() - 5 {
+            rolling.roll(text[start - 1], text[start + 4]);
+            assert_eq!(
+                rolling.value(),
+                RollingHash::new(&text[start..start + 5]).value()
+            );
+        }
+    }
+
+    #[test]
+    fn rolling_survives_moduli_near_u64_max() {
+        let text = b"\xff\xfe\xfd rolling near the top of u64 \xff\xff";
+        for modulus in [u64::MAX, u64::MAX - 58, (1 << 63) + 29] {
+            let base = modulus - 3;
+            let mut rolling = RollingHash::with_params(&text[..7], base, modulus);
+            for start in 1..=text.len() - 7 {
+                rolling.roll(text[start - 1], text[start + 6]);
+                let fresh = RollingHash::with_params(&text[start..start + 7], base, modulus);
+                assert_eq!(rolling.value(), fresh.value());
+            }
+        }
+    }
+
+    #[test]
+    fn finds_single_pattern() {
+        assert_eq!(rabin_karp_search("abracadabra", "abra"), vec![0, 7]);
+        assert_eq!(rabin_karp_search("aaaa", "aa"), vec![0, 1, 2]);
+        assert_eq!(rabin_karp_search("abc", "abcd"), Vec::<usize>::new());
+        assert_eq!(rabin_karp_search("ab", ""), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn finds_multiple_patterns_in_one_pass() {
+        let matches = rabin_karp_multi("she sells sea shells", &["sea", "she", "ell"]);
+        assert_eq!(matches, vec![(0, 1), (5, 2), (10, 0), (14, 1), (16, 2)]);
+    }
+
+    #[test]
+    fn collisions_are_verified() {
+        // A modulus of 3 makes most windows collide with the pattern
+        let text = "abcdefghijabc";
+        let matches = rabin_karp_multi_with_params(text, &["abc", "hij"], 256, 3);
+        assert_eq!(matches, vec![(0, 0), (7, 1), (10, 0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "equal length")]
+    fn rejects_unequal_lengths() {
+        rabin_karp_multi("text", &["ab", "abc"]);
+    }
+}
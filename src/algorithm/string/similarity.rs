@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+
+/// A string similarity score in [0, 1], where 1 means identical, so metrics
+/// can be swapped in fuzzy matching
+pub trait Similarity {
+    /// A short identifier for reports
+    fn name(&self) -> &'static str;
+
+    /// Scores how alike `a` and `b` are, from 0 (nothing in common) to 1 (equal)
+    fn similarity(&self, a: &str, b: &str) -> f64;
+}
+
+/// Fraction of equal chars at equal positions; 0 for strings of different lengths
+pub struct Hamming;
+pub struct Jaro;
+/// Jaro–Winkler with the given prefix scale, conventionally 0.1 and at most 0.25
+pub struct JaroWinkler(pub f64);
+pub struct SorensenDice;
+/// Cosine similarity over char n-grams of the given length
+pub struct Cosine(pub usize);
+
+impl Default for JaroWinkler {
+    fn default() -> Self {
+        JaroWinkler(0.1)
+    }
+}
+
+impl Similarity for Hamming {
+    fn name(&self) -> &'static str {
+        "hamming"
+    }
+
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let len = a.chars().count();
+        match hamming_distance(a, b) {
+            Some(_) if len == 0 => 1.0,
+            Some(d) => 1.0 - d as f64 / len as f64,
+            None => 0.0,
+        }
+    }
+}
+
+impl Similarity for Jaro {
+    fn name(&self) -> &'static str {
+        "jaro"
+    }
+
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        jaro(a, b)
+    }
+}
+
+impl Similarity for JaroWinkler {
+    fn name(&self) -> &'static str {
+        "jaro_winkler"
+    }
+
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        jaro_winkler_with_scale(a, b, self.0)
+    }
+}
+
+impl Similarity for SorensenDice {
+    fn name(&self) -> &'static str {
+        "sorensen_dice"
+    }
+
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        sorensen_dice(a, b)
+    }
+}
+
+impl Similarity for Cosine {
+    fn name(&self) -> &'static str {
+        "cosine"
+    }
+
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        cosine_similarity(a, b, self.0)
+    }
+}
+
+/// Number of char positions at which `a` and `b` differ, or None if their lengths differ
+pub fn hamming_distance(a: &str, b: &str) -> Option<usize> {
+    if a.chars().count() != b.chars().count() {
+        return None;
+    }
+    Some(a.chars().zip(b.chars()).filter(|(x, y)| x != y).count())
+}
+
+/// Jaro similarity: chars match if equal and no further apart than half the
+/// longer length, and the score combines the match count with how many
+/// matched chars are out of order (transpositions)
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, x) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_matched[j] && b[j] == *x {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+    let in_a = a
+        .iter()
+        .zip(&a_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| c);
+    let in_b = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| c);
+    let transpositions = in_a.zip(in_b).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Jaro–Winkler similarity with the standard prefix scale of 0.1
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    jaro_winkler_with_scale(a, b, 0.1)
+}
+
+/// Jaro similarity boosted by the length (up to 4) of the common prefix, times `scale`
+pub fn jaro_winkler_with_scale(a: &str, b: &str, scale: f64) -> f64 {
+    let j = jaro(a, b);
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+    j + prefix as f64 * scale * (1.0 - j)
+}
+
+/// Sørensen–Dice coefficient on char bigrams: twice the shared bigrams over the total
+pub fn sorensen_dice(a: &str, b: &str) -> f64 {
+    let (x, y) = (ngram_counts(a, 2), ngram_counts(b, 2));
+    let total: usize = x.values().sum::<usize>() + y.values().sum::<usize>();
+    if total == 0 {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    let shared: usize = x
+        .iter()
+        .map(|(gram, &count)| count.min(y.get(gram).copied().unwrap_or(0)))
+        .sum();
+    2.0 * shared as f64 / total as f64
+}
+
+/// Cosine of the angle between the char `n`-gram count vectors of `a` and `b`.
+///
+/// Strings shorter than `n` have no n-grams; two such strings score 1 only if equal.
+/// Panics if `n` is zero.
+pub fn cosine_similarity(a: &str, b: &str, n: usize) -> f64 {
+    assert!(n > 0, "n-gram length must be positive");
+    let (x, y) = (ngram_counts(a, n), ngram_counts(b, n));
+    let norm =
+        |v: &HashMap<Vec<char>, usize>| v.values().map(|&c| (c * c) as f64).sum::<f64>().sqrt();
+    let (nx, ny) = (norm(&x), norm(&y));
+    if nx == 0.0 || ny == 0.0 {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    let dot: usize = x
+        .iter()
+        .map(|(gram, &count)| count * y.get(gram).copied().unwrap_or(0))
+        .sum();
+    dot as f64 / (nx * ny)
+}
+
+/// Returns the candidate most similar to `query` under `metric` and its score,
+/// the earliest one on ties
+pub fn best_match<'a>(
+    metric: &dyn Similarity,
+    query: &str,
+    candidates: &[&'a str],
+) -> Option<(&'a str, f64)> {
+    let mut best: Option<(&'a str, f64)> = None;
+    for &candidate in candidates {
+        let score = metric.similarity(query, candidate);
+        if best.is_none_or(|(_, s)| score > s) {
+            best = Some((candidate, score));
+        }
+    }
+    best
+}
+
+fn ngram_counts(s: &str, n: usize) -> HashMap<Vec<char>, usize> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut counts = HashMap::new();
+    for gram in chars.windows(n) {
+        *counts.entry(gram.to_vec()).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Cosine, Hamming, Jaro, JaroWinkler, Similarity, SorensenDice, best_match,
+        cosine_similarity, hamming_distance, jaro, jaro_winkler, sorensen_dice,
+    };
+
+    fn close(x: f64, y: f64) -> bool {
+        (x - y).abs() < 1e-3
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(hamming_distance("karolin", "kathrin"), Some(3));
+        assert_eq!(hamming_distance("abc", "ab"), None);
+        assert!(close(jaro("MARTHA", "MARHTA"), 0.944));
+        assert!(close(jaro("DIXON", "DICKSONX"), 0.767));
+        assert!(close(jaro_winkler("MARTHA", "MARHTA"), 0.961));
+        assert!(close(jaro_winkler("DIXON", "DICKSONX"), 0.813));
+        assert!(close(sorensen_dice("night", "nacht"), 0.25));
+        assert!(close(cosine_similarity("abc", "abc", 2), 1.0));
+        assert!(close(cosine_similarity("ab", "cd", 1), 0.0));
+        // "aab" -> {a: 2, b: 1}, "ab" -> {a: 1, b: 1}
+        assert!(close(
+            cosine_similarity("aab", "ab", 1),
+            3.0 / (5f64.sqrt() * 2f64.sqrt())
+        ));
+    }
+
+    #[test]
+    fn every_metric_is_bounded_and_reflexive() {
+        let metrics: [&dyn Similarity; 5] = [
+            &Hamming,
+            &Jaro,
+            &JaroWinkler::default(),
+            &SorensenDice,
+            &Cosine(2),
+        ];
+        let words = [
+            "", "a", "rust", "trust", "crate", "create", "éclair", "eclair",
+        ];
+        for metric in metrics {
+            for a in words {
+                assert!(
+                    close(metric.similarity(a, a), 1.0),
+                    "{} on {a:?}",
+                    metric.name()
+                );
+                for b in words {
+                    let s = metric.similarity(a, b);
+                    assert!(
+                        (0.0..=1.0 + 1e-9).contains(&s),
+                        "{} on {a:?} {b:?}",
+                        metric.name()
+                    );
+                    assert!(
+                        close(s, metric.similarity(b, a)),
+                        "{} is not symmetric",
+                        metric.name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fuzzy_matching_picks_the_intended_word() {
+        let commands = ["commit", "checkout", "cherry-pick", "clone", "config"];
+        let metrics: [&dyn Similarity; 4] =
+            [&Jaro, &JaroWinkler::default(), &SorensenDice, &Cosine(2)];
+        for metric in metrics {
+            assert_eq!(
+                best_match(metric, "chekout", &commands).unwrap().0,
+                "checkout"
+            );
+            assert_eq!(best_match(metric, "comit", &commands).unwrap().0, "commit");
+        }
+        assert_eq!(best_match(&Jaro, "x", &[]), None);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::algorithm::search::partition_point;
+
+/// A suffix array over a byte string together with its LCP array
+#[derive(Debug, Clone)]
+pub struct SuffixArray<'a> {
+    text: &'a [u8],
+    sa: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<'a> SuffixArray<'a> {
+    /// Builds the suffix array with SA-IS and the LCP array with Kasai's algorithm, both O(n)
+    pub fn new(text: &'a [u8]) -> SuffixArray<'a> {
+        let sa = suffix_array(text);
+        let lcp = lcp_array(text, &sa);
+        SuffixArray { text, sa, lcp }
+    }
+
+    /// Returns the start offsets of the suffixes in lexicographic order
+    pub fn suffixes(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// Returns the LCP array: entry `i` is the longest common prefix of the
+    /// suffixes at ranks `i - 1` and `i`, and entry 0 is 0
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Returns the sorted offsets of every occurrence of `pattern`, in O(m log n)
+    pub fn find_all(&self, pattern: &[u8]) -> Vec<usize> {
+        let prefix = |i: usize| &self.text[i..(i + pattern.len()).min(self.text.len())];
+        let start = partition_point(&self.sa, |&i| prefix(i) < pattern);
+        let end = start + partition_point(&self.sa[start..], |&i| prefix(i) == pattern);
+        let mut positions = self.sa[start..end].to_vec();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Returns the longest substring that occurs at least twice (possibly
+    /// overlapping), the lexicographically smallest one on ties
+    pub fn longest_repeated_substring(&self) -> &'a [u8] {
+        let best = (0..self.lcp.len()).max_by_key(|&i| (self.lcp[i], std::cmp::Reverse(i)));
+        match best {
+            Some(i) => &self.text[self.sa[i]..self.sa[i] + self.lcp[i]],
+            None => &[],
+        }
+    }
+
+    /// Counts the distinct non-empty substrings of the text
+    pub fn distinct_substrings(&self) -> usize {
+        let n = self.text.len();
+        n * (n + 1) / 2 - self.lcp.iter().sum::<usize>()
+    }
+}
+
+/// Builds the suffix array of `text` in O(n) with SA-IS (induced sorting)
+pub fn suffix_array(text: &[u8]) -> Vec<usize> {
+    let s: Vec<usize> = text.iter().map(|&b| b as usize).collect();
+    sa_is(&s, 255)
+}
+
+/// Builds the suffix array of `text` by prefix doubling in O(n log n).
+///
+/// Suffixes are ranked by their first 2^k bytes for increasing k, each round
+/// ordering pairs of ranks with two stable counting-sort passes.
+pub fn suffix_array_doubling(text: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    sa.sort_by_key(|&i| text[i]);
+    if n < 2 {
+        return sa;
+    }
+    let mut rank: Vec<usize> = text.iter().map(|&b| b as usize).collect();
+    let mut next_rank = vec![0; n];
+    let mut by_second = Vec::with_capacity(n);
+    let mut k = 1;
+    loop {
+        // Order by the second half: suffixes without one first, then by sa
+        by_second.clear();
+        by_second.extend(n.saturating_sub(k)..n);
+        by_second.extend(sa.iter().filter(|&&p| p >= k).map(|&p| p - k));
+
+        // Stable counting sort by the first half
+        let mut counts = vec![0; rank.iter().max().map_or(0, |&r| r + 2)];
+        for &i in &by_second {
+            counts[rank[i] + 1] += 1;
+        }
+        for r in 1..counts.len() {
+            counts[r] += counts[r - 1];
+        }
+        for &i in &by_second {
+            sa[counts[rank[i]]] = i;
+            counts[rank[i]] += 1;
+        }
+
+        let key = |i: usize| (rank[i], if i + k < n { rank[i + k] + 1 } else { 0 });
+        next_rank[sa[0]] = 0;
+        for w in 1..n {
+            let bump = usize::from(key(sa[w - 1]) != key(sa[w]));
+            next_rank[sa[w]] = next_rank[sa[w - 1]] + bump;
+        }
+        std::mem::swap(&mut rank, &mut next_rank);
+        if rank[sa[n - 1]] == n - 1 {
+            break;
+        }
+        k *= 2;
+    }
+    sa
+}
+
+/// Computes the LCP array for `text` and its suffix array with Kasai's algorithm in O(n)
+pub fn lcp_array(text: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut rank = vec![0; n];
+    for (r, &i) in sa.iter().enumerate() {
+        rank[i] = r;
+    }
+    let mut lcp = vec![0; n];
+    let mut h = 0;
+    for i in 0..n {
+        if rank[i] == 0 {
+            h = 0;
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && text[i + h] == text[j + h] {
+            h += 1;
+        }
+        lcp[rank[i]] = h;
+        // The next suffix shares at least h - 1 characters with its predecessor
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+const EMPTY: usize = usize::MAX;
+
+/// SA-IS over symbols in `0..=upper`
+fn sa_is(s: &[usize], upper: usize) -> Vec<usize> {
+    let n = s.len();
+    match n {
+        0 => return vec![],
+        1 => return vec![0],
+        2 => return if s[0] < s[1] { vec![0, 1] } else { vec![1, 0] },
+        _ => {}
+    }
+
+    // is_s[i]: suffix i is S-type (smaller than suffix i + 1)
+    let mut is_s = vec![false; n];
+    for i in (0..n - 1).rev() {
+        is_s[i] = if s[i] == s[i + 1] {
+            is_s[i + 1]
+        } else {
+            s[i] < s[i + 1]
+        };
+    }
+
+    // Bucket boundaries: sum_l[c] is where c's L-type suffixes start, sum_s[c] its S-type ones
+    let mut sum_l = vec![0; upper + 1];
+    let mut sum_s = vec![0; upper + 1];
+    for i in 0..n {
+        if is_s[i] {
+            sum_l[s[i] + 1] += 1;
+        } else {
+            sum_s[s[i]] += 1;
+        }
+    }
+    for c in 0..=upper {
+        sum_s[c] += sum_l[c];
+        if c < upper {
+            sum_l[c + 1] += sum_s[c];
+        }
+    }
+
+    let induce = |lms: &[usize], sa: &mut [usize]| {
+        sa.fill(EMPTY);
+        let mut buf = sum_s.clone();
+        for &d in lms {
+            if d != n {
+                sa[buf[s[d]]] = d;
+                buf[s[d]] += 1;
+            }
+        }
+        let mut buf = sum_l.clone();
+        sa[buf[s[n - 1]]] = n - 1;
+        buf[s[n - 1]] += 1;
+        for i in 0..n {
+            let v = sa[i];
+            if v != EMPTY && v >= 1 && !is_s[v - 1] {
+                sa[buf[s[v - 1]]] = v - 1;
+                buf[s[v - 1]] += 1;
+            }
+        }
+        let mut buf = sum_l.clone();
+        for i in (0..n).rev() {
+            let v = sa[i];
+            if v != EMPTY && v >= 1 && is_s[v - 1] {
+                buf[s[v - 1] + 1] -= 1;
+                sa[buf[s[v - 1] + 1]] = v - 1;
+            }
+        }
+    };
+
+    // Leftmost S-type positions, and their index among all LMS positions
+    let lms: Vec<usize> = (1..n).filter(|&i| !is_s[i - 1] && is_s[i]).collect();
+    let mut lms_index = vec![EMPTY; n + 1];
+    for (k, &i) in lms.iter().enumerate() {
+        lms_index[i] = k;
+    }
+    let m = lms.len();
+
+    let mut sa = vec![EMPTY; n];
+    induce(&lms, &mut sa);
+    if m > 0 {
+        let mut sorted_lms: Vec<usize> = sa
+            .iter()
+            .copied()
+            .filter(|&v| v != EMPTY && lms_index[v] != EMPTY)
+            .collect();
+
+        // Name LMS substrings; equal substrings share a name
+        let mut reduced = vec![0; m];
+        let mut name = 0;
+        reduced[lms_index[sorted_lms[0]]] = 0;
+        for w in 1..m {
+            let (mut l, mut r) = (sorted_lms[w - 1], sorted_lms[w]);
+            let end_l = lms.get(lms_index[l] + 1).copied().unwrap_or(n);
+            let end_r = lms.get(lms_index[r] + 1).copied().unwrap_or(n);
+            let mut same = end_l - l == end_r - r;
+            if same {
+                while l < end_l && s[l] == s[r] {
+                    l += 1;
+                    r += 1;
+                }
+                if l == n || r == n || s[l] != s[r] {
+                    same = false;
+                }
+            }
+            if !same {
+                name += 1;
+            }
+            reduced[lms_index[sorted_lms[w]]] = name;
+        }
+
+        let reduced_sa = sa_is(&reduced, name);
+        for (slot, &r) in sorted_lms.iter_mut().zip(reduced_sa.iter()) {
+            *slot = lms[r];
+        }
+        induce(&sorted_lms, &mut sa);
+    }
+    sa
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SuffixArray, lcp_array, suffix_array, suffix_array_doubling};
+    use crate::testgen::TestGen;
+
+    fn naive(text: &[u8]) -> Vec<usize> {
+        let mut sa: Vec<usize> = (0..text.len()).collect();
+        sa.sort_by_key(|&i| &text[i..]);
+        sa
+    }
+
+    #[test]
+    fn banana() {
+        let text = b"banana";
+        assert_eq!(suffix_array(text), vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(suffix_array_doubling(text), vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(lcp_array(text, &suffix_array(text)), vec![0, 1, 3, 0, 0, 2]);
+    }
+
+    #[test]
+    fn both_constructions_match_naive() {
+        let mut tg = TestGen::new(0x1234_5678);
+        for len in 0..200 {
+            let text = tg.string(len, "abc").into_bytes();
+            let expected = naive(&text);
+            assert_eq!(suffix_array(&text), expected, "{text:?}");
+            assert_eq!(suffix_array_doubling(&text), expected, "{text:?}");
+        }
+        let repetitive = b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaabaaaaaaaaaaaa";
+        assert_eq!(suffix_array(repetitive), naive(repetitive));
+    }
+
+    #[test]
+    fn finds_all_occurrences() {
+        let sa = SuffixArray::new(b"mississippi");
+        assert_eq!(sa.find_all(b"issi"), vec![1, 4]);
+        assert_eq!(sa.find_all(b"s"), vec![2, 3, 5, 6]);
+        assert_eq!(sa.find_all(b"ippi"), vec![7]);
+        assert_eq!(sa.find_all(b"x"), Vec::<usize>::new());
+        assert_eq!(sa.find_all(b"mississippis"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn repeated_and_distinct_substrings() {
+        assert_eq!(
+            SuffixArray::new(b"banana").longest_repeated_substring(),
+            b"ana"
+        );
+        assert_eq!(SuffixArray::new(b"abcd").longest_repeated_substring(), b"");
+        assert_eq!(SuffixArray::new(b"").longest_repeated_substring(), b"");
+        assert_eq!(SuffixArray::new(b"aaa").distinct_substrings(), 3);
+        assert_eq!(SuffixArray::new(b"abab").distinct_substrings(), 7);
+    }
+}
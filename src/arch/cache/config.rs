@@ -0,0 +1,60 @@
+/// Which line of a full set a cache evicts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Replacement {
+    /// The least recently used
+    Lru,
+    /// The one brought in longest ago
+    Fifo,
+    /// One picked by a generator seeded with this value
+    Random(u64),
+}
+
+/// The shape of one cache level, with sizes in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    pub size: usize,
+    pub block_size: usize,
+    /// Lines per set: 1 is direct mapped, `size / block_size` fully associative
+    pub associativity: usize,
+    pub replacement: Replacement,
+}
+
+impl CacheConfig {
+    pub fn new(
+        size: usize,
+        block_size: usize,
+        associativity: usize,
+        replacement: Replacement,
+    ) -> CacheConfig {
+        let config = CacheConfig {
+            size,
+            block_size,
+            associativity,
+            replacement,
+        };
+        assert!(block_size > 0 && associativity > 0, "empty cache geometry");
+        assert!(
+            size > 0 && size.is_multiple_of(block_size * associativity),
+            "the size must be a whole number of sets"
+        );
+        config
+    }
+
+    /// One line per set, so replacement has no choice to make
+    pub fn direct_mapped(size: usize, block_size: usize) -> CacheConfig {
+        CacheConfig::new(size, block_size, 1, Replacement::Lru)
+    }
+
+    /// A single set holding every line
+    pub fn fully_associative(
+        size: usize,
+        block_size: usize,
+        replacement: Replacement,
+    ) -> CacheConfig {
+        CacheConfig::new(size, block_size, size / block_size, replacement)
+    }
+
+    pub fn sets(&self) -> usize {
+        self.size / (self.block_size * self.associativity)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::algorithm::random::{Rng, XorShift64Star};
+
+use super::{CacheConfig, Replacement};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Line {
+    tag: u64,
+    /// When the line was last touched and when it was filled, in accesses
+    used: u64,
+    filled: u64,
+}
+
+/// One level of a cache: a table of sets, each a few tagged lines
+#[derive(Debug, Clone)]
+pub(super) struct Level {
+    pub(super) config: CacheConfig,
+    sets: Vec<Vec<Line>>,
+    clock: u64,
+    rng: XorShift64Star,
+}
+
+impl Level {
+    pub(super) fn new(config: CacheConfig) -> Level {
+        let seed = match config.replacement {
+            Replacement::Random(seed) => seed,
+            _ => 0,
+        };
+        Level {
+            config,
+            sets: vec![Vec::new(); config.sets()],
+            clock: 0,
+            rng: XorShift64Star::seed_from_u64(seed),
+        }
+    }
+
+    /// Splits an address into its set index and tag
+    fn locate(&self, address: u64) -> (usize, u64) {
+        let block = address / self.config.block_size as u64;
+        let sets = self.sets.len() as u64;
+        ((block % sets) as usize, block / sets)
+    }
+
+    /// Looks the address up, touching its line on a hit
+    pub(super) fn lookup(&mut self, address: u64) -> bool {
+        self.clock += 1;
+        let (set, tag) = self.locate(address);
+        match self.sets[set].iter_mut().find(|line| line.tag == tag) {
+            Some(line) => {
+                line.used = self.clock;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Brings the block holding the address in, evicting a line if its set
+    /// is full
+    pub(super) fn fill(&mut self, address: u64) {
+        let (set, tag) = self.locate(address);
+        let line = Line {
+            tag,
+            used: self.clock,
+            filled: self.clock,
+        };
+        let lines = &mut self.sets[set];
+        if lines.len() < self.config.associativity {
+            lines.push(line);
+            return;
+        }
+        let victim = match self.config.replacement {
+            Replacement::Lru => (0..lines.len()).min_by_key(|&i| lines[i].used).unwrap(),
+            Replacement::Fifo => (0..lines.len()).min_by_key(|&i| lines[i].filled).unwrap(),
+            Replacement::Random(_) => self.rng.gen_index(lines.len()),
+        };
+        lines[victim] = line;
+    }
+}
@@ -0,0 +1,6 @@
+mod config;
+mod level;
+mod sim;
+
+pub use self::config::{CacheConfig, Replacement};
+pub use self::sim::{CacheSim, LevelStats};
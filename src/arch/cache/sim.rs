@@ -0,0 +1,175 @@
+use super::CacheConfig;
+use super::level::Level;
+
+/// Hits and misses of one level. A lower level only sees the accesses
+/// that missed above it, so its rates are local ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LevelStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl LevelStats {
+    pub fn accesses(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    pub fn hit_rate(&self) -> f64 {
+        match self.accesses() {
+            0 => 0.0,
+            accesses => self.hits as f64 / accesses as f64,
+        }
+    }
+
+    pub fn miss_rate(&self) -> f64 {
+        match self.accesses() {
+            0 => 0.0,
+            accesses => self.misses as f64 / accesses as f64,
+        }
+    }
+}
+
+/// A cache hierarchy, L1 first, simulated one address at a time.
+///
+/// An access goes down the levels until one hits, or to memory, and the
+/// block is then filled into every level that missed. Reads and writes are
+/// not told apart.
+#[derive(Debug, Clone)]
+pub struct CacheSim {
+    levels: Vec<Level>,
+    stats: Vec<LevelStats>,
+}
+
+impl CacheSim {
+    /// A single-level cache
+    pub fn new(config: CacheConfig) -> CacheSim {
+        CacheSim::with_levels(&[config])
+    }
+
+    /// A hierarchy of the given levels, L1 first
+    pub fn with_levels(configs: &[CacheConfig]) -> CacheSim {
+        assert!(!configs.is_empty(), "a cache needs at least one level");
+        CacheSim {
+            levels: configs.iter().copied().map(Level::new).collect(),
+            stats: vec![LevelStats::default(); configs.len()],
+        }
+    }
+
+    /// Accesses a byte address, returning the index of the level that hit,
+    /// or None if it went to memory
+    pub fn access(&mut self, address: u64) -> Option<usize> {
+        let mut hit = None;
+        for (i, level) in self.levels.iter_mut().enumerate() {
+            if level.lookup(address) {
+                self.stats[i].hits += 1;
+                hit = Some(i);
+                break;
+            }
+            self.stats[i].misses += 1;
+        }
+        let missed = hit.unwrap_or(self.levels.len());
+        for level in &mut self.levels[..missed] {
+            level.fill(address);
+        }
+        hit
+    }
+
+    /// Accesses every address of a trace in order
+    pub fn run(&mut self, trace: impl IntoIterator<Item = u64>) {
+        for address in trace {
+            self.access(address);
+        }
+    }
+
+    /// The stats of each level, L1 first
+    pub fn stats(&self) -> &[LevelStats] {
+        &self.stats
+    }
+
+    pub fn config(&self, level: usize) -> CacheConfig {
+        self.levels[level].config
+    }
+
+    /// The average memory access time: each level's hit time, plus its local
+    /// miss rate times the cost of going on to the next level or memory
+    pub fn average_access_time(&self, hit_times: &[f64], memory_time: f64) -> f64 {
+        assert_eq!(hit_times.len(), self.levels.len(), "one hit time per level");
+        hit_times
+            .iter()
+            .zip(&self.stats)
+            .rev()
+            .fold(memory_time, |below, (hit_time, stats)| {
+                hit_time + stats.miss_rate() * below
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CacheSim;
+    use crate::arch::cache::{CacheConfig, Replacement};
+
+    #[test]
+    fn direct_mapped_conflicts() {
+        // 4 sets of one 16-byte block: 0 and 64 both map to set 0
+        let mut cache = CacheSim::new(CacheConfig::direct_mapped(64, 16));
+        let hits: Vec<Option<usize>> = [0, 4, 64, 0, 16, 20, 64]
+            .into_iter()
+            .map(|address| cache.access(address))
+            .collect();
+        assert_eq!(hits, [None, Some(0), None, None, None, Some(0), None]);
+        assert_eq!((cache.stats()[0].hits, cache.stats()[0].misses), (2, 5));
+        // Two ways per set hold both
+        let mut cache = CacheSim::new(CacheConfig::new(64, 16, 2, Replacement::Lru));
+        cache.run([0, 64, 0, 64, 0, 64]);
+        assert_eq!((cache.stats()[0].hits, cache.stats()[0].misses), (4, 2));
+    }
+
+    #[test]
+    fn replacement_policies() {
+        // One set of three lines, blocks A B C A D then A and B again
+        let run = |replacement| {
+            let mut cache = CacheSim::new(CacheConfig::fully_associative(48, 16, replacement));
+            [0, 16, 32, 0, 48, 0, 16].map(|address| cache.access(address).is_some())
+        };
+        // LRU evicts B for D, since A was just used; FIFO evicts A, the oldest
+        assert_eq!(
+            run(Replacement::Lru),
+            [false, false, false, true, false, true, false]
+        );
+        assert_eq!(
+            run(Replacement::Fifo),
+            [false, false, false, true, false, false, false]
+        );
+        // Random replacement is repeatable for a seed
+        assert_eq!(run(Replacement::Random(7)), run(Replacement::Random(7)));
+    }
+
+    #[test]
+    fn sequential_scan_hit_rate() {
+        // Reading words in order misses once per 64-byte block
+        let mut cache = CacheSim::new(CacheConfig::new(4_096, 64, 4, Replacement::Lru));
+        cache.run((0..16_384).step_by(4));
+        assert_eq!(cache.stats()[0].miss_rate(), 1.0 / 16.0);
+        assert_eq!(cache.config(0).sets(), 16);
+    }
+
+    #[test]
+    fn two_levels() {
+        let l1 = CacheConfig::direct_mapped(256, 32);
+        let l2 = CacheConfig::new(4_096, 32, 8, Replacement::Lru);
+        let mut cache = CacheSim::with_levels(&[l1, l2]);
+        // A 1 KiB working set thrashes L1 but fits in L2
+        for _ in 0..10 {
+            cache.run((0..1_024).step_by(32));
+        }
+        let [l1, l2] = cache.stats() else {
+            panic!("expected two levels");
+        };
+        assert_eq!((l1.hits, l1.misses), (0, 320));
+        assert_eq!((l2.hits, l2.misses), (288, 32));
+        assert_eq!(l2.hit_rate(), 0.9);
+        // 1 + 1.0 * (10 + 0.1 * 100)
+        assert_eq!(cache.average_access_time(&[1.0, 10.0], 100.0), 21.0);
+    }
+}
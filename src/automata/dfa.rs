@@ -0,0 +1,339 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+
+use crate::visualize::ToDot;
+
+/// A deterministic finite automaton over `char`s, with states numbered from
+/// 0 and state 0 as the start.
+///
+/// Transitions may be missing: input with nowhere to go is rejected, as if it
+/// fell into an implicit dead state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dfa {
+    transitions: Vec<BTreeMap<char, usize>>,
+    accepting: Vec<bool>,
+}
+
+impl Default for Dfa {
+    fn default() -> Self {
+        Dfa::new()
+    }
+}
+
+impl Dfa {
+    /// A DFA with just the non-accepting start state
+    pub fn new() -> Dfa {
+        Dfa {
+            transitions: vec![BTreeMap::new()],
+            accepting: vec![false],
+        }
+    }
+
+    /// Builds a DFA from a transition table over states `0..states`. Panics if
+    /// an entry names a missing state or two entries disagree
+    pub fn from_table(states: usize, table: &[(usize, char, usize)], accepting: &[usize]) -> Dfa {
+        assert!(states > 0, "a DFA needs a start state");
+        let mut dfa = Dfa {
+            transitions: vec![BTreeMap::new(); states],
+            accepting: vec![false; states],
+        };
+        for &(from, symbol, to) in table {
+            dfa.add_transition(from, symbol, to);
+        }
+        for &state in accepting {
+            dfa.set_accepting(state, true);
+        }
+        dfa
+    }
+
+    /// Adds a state and returns its number
+    pub fn add_state(&mut self, accepting: bool) -> usize {
+        self.transitions.push(BTreeMap::new());
+        self.accepting.push(accepting);
+        self.transitions.len() - 1
+    }
+
+    pub fn set_accepting(&mut self, state: usize, accepting: bool) {
+        self.accepting[state] = accepting;
+    }
+
+    /// Adds `from --symbol--> to`. Panics if either state is missing or
+    /// `from` already moves elsewhere on `symbol`
+    pub fn add_transition(&mut self, from: usize, symbol: char, to: usize) {
+        assert!(to < self.transitions.len(), "no state {to}");
+        let previous = self.transitions[from].insert(symbol, to);
+        assert!(
+            previous.is_none_or(|p| p == to),
+            "state {from} already moves on {symbol:?}"
+        );
+    }
+
+    pub fn state_count(&self) -> usize {
+        self.transitions.len()
+    }
+
+    pub fn is_accepting(&self, state: usize) -> bool {
+        self.accepting[state]
+    }
+
+    /// The state reached from `state` on `symbol`, if any
+    pub fn next(&self, state: usize, symbol: char) -> Option<usize> {
+        self.transitions[state].get(&symbol).copied()
+    }
+
+    /// Every symbol with a transition
+    pub fn alphabet(&self) -> BTreeSet<char> {
+        self.transitions
+            .iter()
+            .flat_map(|t| t.keys().copied())
+            .collect()
+    }
+
+    pub fn accepts(&self, input: &str) -> bool {
+        input
+            .chars()
+            .try_fold(0, |state, c| self.next(state, c))
+            .is_some_and(|state| self.accepting[state])
+    }
+
+    /// The minimal DFA for the same language, by Hopcroft's partition refinement.
+    ///
+    /// Unreachable states are dropped and the partial automaton is completed
+    /// with a dead state. Hopcroft's algorithm then starts from the partition
+    /// {accepting, rejecting} and splits blocks by their predecessors under
+    /// each symbol, in O(k n log n). A dead block is left implicit again. The
+    /// result is numbered in breadth-first order over sorted symbols, so
+    /// DFAs for the same language minimize to equal values.
+    pub fn minimize(&self) -> Dfa {
+        let alphabet: Vec<char> = self.alphabet().into_iter().collect();
+        let reachable = self.reachable();
+        // Complete automaton over the reachable states, plus the dead state
+        let dead = reachable.len();
+        let index: BTreeMap<usize, usize> =
+            reachable.iter().enumerate().map(|(i, &s)| (s, i)).collect();
+        let n = dead + 1;
+        let delta: Vec<Vec<usize>> = (0..n)
+            .map(|i| {
+                alphabet
+                    .iter()
+                    .map(|&c| match reachable.get(i) {
+                        Some(&s) => self.next(s, c).map_or(dead, |t| index[&t]),
+                        None => dead,
+                    })
+                    .collect()
+            })
+            .collect();
+        let accepting = |i: usize| reachable.get(i).is_some_and(|&s| self.accepting[s]);
+
+        // Predecessors per symbol
+        let mut inverse = vec![vec![Vec::new(); n]; alphabet.len()];
+        for (q, row) in delta.iter().enumerate() {
+            for (c, &to) in row.iter().enumerate() {
+                inverse[c][to].push(q);
+            }
+        }
+
+        let (finals, rest): (Vec<usize>, Vec<usize>) = (0..n).partition(|&q| accepting(q));
+        let mut blocks: Vec<BTreeSet<usize>> = [finals, rest]
+            .into_iter()
+            .filter(|b| !b.is_empty())
+            .map(|b| b.into_iter().collect())
+            .collect();
+        let mut block_of = vec![0; n];
+        for (b, block) in blocks.iter().enumerate() {
+            for &q in block {
+                block_of[q] = b;
+            }
+        }
+        let mut work: Vec<usize> = (0..blocks.len()).collect();
+        let mut in_work = vec![true; blocks.len()];
+        while let Some(splitter) = work.pop() {
+            in_work[splitter] = false;
+            let splitter_states = blocks[splitter].clone();
+            for predecessors in &inverse {
+                let x: BTreeSet<usize> = splitter_states
+                    .iter()
+                    .flat_map(|&q| predecessors[q].iter().copied())
+                    .collect();
+                let touched: BTreeSet<usize> = x.iter().map(|&q| block_of[q]).collect();
+                for y in touched {
+                    let (inside, outside): (BTreeSet<usize>, BTreeSet<usize>) =
+                        blocks[y].iter().partition(|q| x.contains(q));
+                    if outside.is_empty() {
+                        continue;
+                    }
+                    let new = blocks.len();
+                    for &q in &outside {
+                        block_of[q] = new;
+                    }
+                    let smaller_is_new = outside.len() < inside.len();
+                    blocks[y] = inside;
+                    blocks.push(outside);
+                    in_work.push(false);
+                    let add = if in_work[y] || smaller_is_new { new } else { y };
+                    if !in_work[add] {
+                        in_work[add] = true;
+                        work.push(add);
+                    }
+                }
+            }
+        }
+
+        // Renumber blocks breadth-first from the start, skipping the dead block
+        let dead_block = block_of[dead];
+        let mut number = BTreeMap::new();
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([block_of[0]]);
+        number.insert(block_of[0], 0);
+        while let Some(b) = queue.pop_front() {
+            order.push(b);
+            let q = *blocks[b].first().unwrap();
+            for (c, _) in alphabet.iter().enumerate() {
+                let to = block_of[delta[q][c]];
+                if to != dead_block && !number.contains_key(&to) {
+                    number.insert(to, number.len());
+                    queue.push_back(to);
+                }
+            }
+        }
+        let mut minimal = Dfa {
+            transitions: vec![BTreeMap::new(); order.len()],
+            accepting: order
+                .iter()
+                .map(|&b| accepting(*blocks[b].first().unwrap()))
+                .collect(),
+        };
+        for &b in &order {
+            let q = *blocks[b].first().unwrap();
+            for (c, &symbol) in alphabet.iter().enumerate() {
+                let to = block_of[delta[q][c]];
+                if to != dead_block {
+                    minimal.add_transition(number[&b], symbol, number[&to]);
+                }
+            }
+        }
+        minimal
+    }
+
+    /// States reachable from the start, in breadth-first order
+    fn reachable(&self) -> Vec<usize> {
+        let mut seen = vec![false; self.transitions.len()];
+        let mut order = vec![0];
+        seen[0] = true;
+        let mut i = 0;
+        while i < order.len() {
+            for &to in self.transitions[order[i]].values() {
+                if !seen[to] {
+                    seen[to] = true;
+                    order.push(to);
+                }
+            }
+            i += 1;
+        }
+        order
+    }
+
+    /// Graphviz source drawing the automaton, with accepting states doubly
+    /// circled and parallel transitions merged into one labelled edge
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from(
+            "digraph dfa {\n    rankdir=LR;\n    start [shape=point];\n    start -> 0;\n",
+        );
+        for (state, &accepting) in self.accepting.iter().enumerate() {
+            let shape = if accepting { "doublecircle" } else { "circle" };
+            writeln!(dot, "    {state} [shape={shape}];").unwrap();
+        }
+        for (from, transitions) in self.transitions.iter().enumerate() {
+            let mut edges: BTreeMap<usize, Vec<char>> = BTreeMap::new();
+            for (&symbol, &to) in transitions {
+                edges.entry(to).or_default().push(symbol);
+            }
+            for (to, symbols) in edges {
+                let label: Vec<String> = symbols
+                    .iter()
+                    .map(|c| c.escape_default().to_string())
+                    .collect();
+                writeln!(dot, "    {from} -> {to} [label=\"{}\"];", label.join(",")).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl ToDot for Dfa {
+    fn to_dot(&self) -> String {
+        Dfa::to_dot(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dfa;
+
+    /// Binary numbers divisible by 3: the state is the value mod 3
+    fn divisible_by_three() -> Dfa {
+        let table = [
+            (0, '0', 0),
+            (0, '1', 1),
+            (1, '0', 2),
+            (1, '1', 0),
+            (2, '0', 1),
+            (2, '1', 2),
+        ];
+        Dfa::from_table(3, &table, &[0])
+    }
+
+    #[test]
+    fn accepts_multiples_of_three() {
+        let dfa = divisible_by_three();
+        for n in 0..200u32 {
+            assert_eq!(dfa.accepts(&format!("{n:b}")), n % 3 == 0, "{n}");
+        }
+        assert!(dfa.accepts(""));
+        assert!(!dfa.accepts("12"));
+    }
+
+    #[test]
+    fn minimization_merges_equivalent_states() {
+        // Value mod 6 tracks more than needed for divisibility by 3
+        let mut table = Vec::new();
+        for r in 0..6 {
+            table.push((r, '0', (2 * r) % 6));
+            table.push((r, '1', (2 * r + 1) % 6));
+        }
+        let redundant = Dfa::from_table(6, &table, &[0, 3]);
+        let minimal = redundant.minimize();
+        assert_eq!(minimal.state_count(), 3);
+        assert_eq!(minimal, divisible_by_three().minimize());
+        for n in 0..100u32 {
+            assert_eq!(minimal.accepts(&format!("{n:b}")), n % 3 == 0);
+        }
+    }
+
+    #[test]
+    fn minimization_drops_unreachable_and_dead_states() {
+        let mut dfa = Dfa::new();
+        let a = dfa.add_state(true);
+        let dead = dfa.add_state(false);
+        let unreachable = dfa.add_state(true);
+        dfa.add_transition(0, 'a', a);
+        dfa.add_transition(a, 'b', dead);
+        dfa.add_transition(dead, 'b', dead);
+        dfa.add_transition(unreachable, 'a', 0);
+        let minimal = dfa.minimize();
+        assert_eq!(minimal, Dfa::from_table(2, &[(0, 'a', 1)], &[1]));
+        assert_eq!(Dfa::new().minimize(), Dfa::new());
+    }
+
+    #[test]
+    fn dot_output() {
+        let dfa = Dfa::from_table(2, &[(0, 'a', 1), (0, 'b', 1), (1, 'a', 1)], &[1]);
+        assert_eq!(
+            dfa.to_dot(),
+            "digraph dfa {\n    rankdir=LR;\n    start [shape=point];\n    start -> 0;\n    \
+             0 [shape=circle];\n    1 [shape=doublecircle];\n    0 -> 1 [label=\"a,b\"];\n    \
+             1 -> 1 [label=\"a\"];\n}\n"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod dfa;
+mod nfa;
+mod pda;
+
+pub use self::dfa::Dfa;
+pub use self::nfa::Nfa;
+pub use self::pda::{Acceptance, Pda};
@@ -0,0 +1,274 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+
+use super::Dfa;
+use crate::visualize::ToDot;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct NfaState {
+    accepting: bool,
+    transitions: BTreeMap<char, BTreeSet<usize>>,
+    epsilon: BTreeSet<usize>,
+}
+
+/// A nondeterministic finite automaton with epsilon moves, with states
+/// numbered from 0 and state 0 as the start
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nfa {
+    states: Vec<NfaState>,
+}
+
+impl Default for Nfa {
+    fn default() -> Self {
+        Nfa::new()
+    }
+}
+
+impl Nfa {
+    /// An NFA with just the non-accepting start state
+    pub fn new() -> Nfa {
+        Nfa {
+            states: vec![NfaState::default()],
+        }
+    }
+
+    /// Builds an NFA from a transition table over states `0..states`, where a
+    /// symbol of None is an epsilon move
+    pub fn from_table(
+        states: usize,
+        table: &[(usize, Option<char>, usize)],
+        accepting: &[usize],
+    ) -> Nfa {
+        assert!(states > 0, "an NFA needs a start state");
+        let mut nfa = Nfa {
+            states: vec![NfaState::default(); states],
+        };
+        for &(from, symbol, to) in table {
+            match symbol {
+                Some(symbol) => nfa.add_transition(from, symbol, to),
+                None => nfa.add_epsilon(from, to),
+            }
+        }
+        for &state in accepting {
+            nfa.set_accepting(state, true);
+        }
+        nfa
+    }
+
+    /// Adds a state and returns its number
+    pub fn add_state(&mut self, accepting: bool) -> usize {
+        self.states.push(NfaState {
+            accepting,
+            ..NfaState::default()
+        });
+        self.states.len() - 1
+    }
+
+    pub fn set_accepting(&mut self, state: usize, accepting: bool) {
+        self.states[state].accepting = accepting;
+    }
+
+    pub fn add_transition(&mut self, from: usize, symbol: char, to: usize) {
+        assert!(to < self.states.len(), "no state {to}");
+        self.states[from]
+            .transitions
+            .entry(symbol)
+            .or_default()
+            .insert(to);
+    }
+
+    /// Adds a move from `from` to `to` that consumes no input
+    pub fn add_epsilon(&mut self, from: usize, to: usize) {
+        assert!(to < self.states.len(), "no state {to}");
+        self.states[from].epsilon.insert(to);
+    }
+
+    pub fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Every state reachable from `states` by epsilon moves alone, `states` included
+    pub fn epsilon_closure(&self, states: &BTreeSet<usize>) -> BTreeSet<usize> {
+        let mut closure = states.clone();
+        let mut stack: Vec<usize> = states.iter().copied().collect();
+        while let Some(state) = stack.pop() {
+            for &next in &self.states[state].epsilon {
+                if closure.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        closure
+    }
+
+    /// The states reachable from `states` by one `symbol`, then epsilon moves
+    pub fn step(&self, states: &BTreeSet<usize>, symbol: char) -> BTreeSet<usize> {
+        let moved = states
+            .iter()
+            .filter_map(|&s| self.states[s].transitions.get(&symbol))
+            .flatten()
+            .copied()
+            .collect();
+        self.epsilon_closure(&moved)
+    }
+
+    /// Simulates every path at once, tracking the set of current states
+    pub fn accepts(&self, input: &str) -> bool {
+        let mut current = self.epsilon_closure(&BTreeSet::from([0]));
+        for c in input.chars() {
+            current = self.step(&current, c);
+            if current.is_empty() {
+                return false;
+            }
+        }
+        current.iter().any(|&s| self.states[s].accepting)
+    }
+
+    /// Subset construction: a DFA whose states are the reachable sets of NFA
+    /// states, accepting where the set holds an accepting state. The empty
+    /// set is left implicit, so the result is a partial DFA
+    pub fn to_dfa(&self) -> Dfa {
+        let alphabet: BTreeSet<char> = self
+            .states
+            .iter()
+            .flat_map(|s| s.transitions.keys().copied())
+            .collect();
+        let start = self.epsilon_closure(&BTreeSet::from([0]));
+        let accepting = |set: &BTreeSet<usize>| set.iter().any(|&s| self.states[s].accepting);
+        let mut dfa = Dfa::new();
+        dfa.set_accepting(0, accepting(&start));
+        let mut ids = BTreeMap::from([(start.clone(), 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(set) = queue.pop_front() {
+            let from = ids[&set];
+            for &c in &alphabet {
+                let next = self.step(&set, c);
+                if next.is_empty() {
+                    continue;
+                }
+                let to = match ids.get(&next) {
+                    Some(&to) => to,
+                    None => {
+                        let to = dfa.add_state(accepting(&next));
+                        ids.insert(next.clone(), to);
+                        queue.push_back(next);
+                        to
+                    }
+                };
+                dfa.add_transition(from, c, to);
+            }
+        }
+        dfa
+    }
+
+    /// Graphviz source drawing the automaton, with epsilon moves labelled ε
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from(
+            "digraph nfa {\n    rankdir=LR;\n    start [shape=point];\n    start -> 0;\n",
+        );
+        for (i, state) in self.states.iter().enumerate() {
+            let shape = if state.accepting {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            writeln!(dot, "    {i} [shape={shape}];").unwrap();
+        }
+        for (from, state) in self.states.iter().enumerate() {
+            let mut edges: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+            for &to in &state.epsilon {
+                edges.entry(to).or_default().push("ε".to_string());
+            }
+            for (symbol, targets) in &state.transitions {
+                for &to in targets {
+                    edges
+                        .entry(to)
+                        .or_default()
+                        .push(symbol.escape_default().to_string());
+                }
+            }
+            for (to, labels) in edges {
+                writeln!(dot, "    {from} -> {to} [label=\"{}\"];", labels.join(",")).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl ToDot for Nfa {
+    fn to_dot(&self) -> String {
+        Nfa::to_dot(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Nfa;
+
+    /// Strings over {a, b} whose third-to-last symbol is 'a': 4 NFA states,
+    /// but 8 DFA states, since the DFA must remember the last three symbols
+    fn third_from_last_is_a() -> Nfa {
+        let table = [
+            (0, Some('a'), 0),
+            (0, Some('b'), 0),
+            (0, Some('a'), 1),
+            (1, Some('a'), 2),
+            (1, Some('b'), 2),
+            (2, Some('a'), 3),
+            (2, Some('b'), 3),
+        ];
+        Nfa::from_table(4, &table, &[3])
+    }
+
+    fn strings(max_len: usize) -> Vec<String> {
+        let mut all = vec![String::new()];
+        let mut frontier = vec![String::new()];
+        for _ in 0..max_len {
+            frontier = frontier
+                .iter()
+                .flat_map(|s| ['a', 'b'].map(|c| format!("{s}{c}")))
+                .collect();
+            all.extend(frontier.iter().cloned());
+        }
+        all
+    }
+
+    #[test]
+    fn subset_construction_preserves_the_language() {
+        let nfa = third_from_last_is_a();
+        let dfa = nfa.to_dfa();
+        assert_eq!(dfa.state_count(), 8);
+        assert_eq!(dfa.minimize().state_count(), 8);
+        for s in strings(8) {
+            let expected = s.len() >= 3 && s.as_bytes()[s.len() - 3] == b'a';
+            assert_eq!(nfa.accepts(&s), expected, "{s}");
+            assert_eq!(dfa.accepts(&s), expected, "{s}");
+        }
+    }
+
+    #[test]
+    fn epsilon_moves() {
+        // a* followed by b*, as two loops joined by an epsilon move
+        let nfa = Nfa::from_table(
+            2,
+            &[(0, Some('a'), 0), (0, None, 1), (1, Some('b'), 1)],
+            &[1],
+        );
+        let dfa = nfa.to_dfa().minimize();
+        for s in strings(6) {
+            let expected = !s.contains("ba");
+            assert_eq!(nfa.accepts(&s), expected, "{s}");
+            assert_eq!(dfa.accepts(&s), expected, "{s}");
+        }
+        assert_eq!(dfa.state_count(), 2);
+        assert!(nfa.to_dot().contains("0 -> 1 [label=\"ε\"];"));
+        // An epsilon cycle terminates
+        let mut cycle = Nfa::new();
+        let s = cycle.add_state(true);
+        cycle.add_epsilon(0, s);
+        cycle.add_epsilon(s, 0);
+        assert!(cycle.accepts(""));
+        assert!(!cycle.accepts("a"));
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+/// When a pushdown automaton accepts its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acceptance {
+    /// The input is used up in an accepting state
+    FinalState,
+    /// The input is used up with nothing left on the stack
+    EmptyStack,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PdaTransition {
+    from: usize,
+    input: Option<char>,
+    pop: Option<char>,
+    push: Vec<char>,
+    to: usize,
+}
+
+/// A nondeterministic pushdown automaton, with states numbered from 0, state
+/// 0 as the start, and a stack that initially holds one start symbol.
+///
+/// A transition may read a char of input or none, may pop a given symbol off
+/// the stack or leave it alone, and then pushes a string of symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pda {
+    accepting: Vec<bool>,
+    transitions: Vec<PdaTransition>,
+    start_symbol: char,
+    acceptance: Acceptance,
+}
+
+impl Pda {
+    /// A PDA with just the non-accepting start state and `start_symbol` on
+    /// the stack
+    pub fn new(start_symbol: char, acceptance: Acceptance) -> Pda {
+        Pda {
+            accepting: vec![false],
+            transitions: Vec::new(),
+            start_symbol,
+            acceptance,
+        }
+    }
+
+    /// Adds a state and returns its number
+    pub fn add_state(&mut self, accepting: bool) -> usize {
+        self.accepting.push(accepting);
+        self.accepting.len() - 1
+    }
+
+    pub fn set_accepting(&mut self, state: usize, accepting: bool) {
+        self.accepting[state] = accepting;
+    }
+
+    /// Adds a move from `from` to `to` that reads `input` (None reads
+    /// nothing), pops `pop` (None leaves the stack alone) and pushes `push`,
+    /// whose first char ends up on top
+    pub fn add_transition(
+        &mut self,
+        from: usize,
+        input: Option<char>,
+        pop: Option<char>,
+        push: &str,
+        to: usize,
+    ) {
+        assert!(from < self.accepting.len(), "no state {from}");
+        assert!(to < self.accepting.len(), "no state {to}");
+        self.transitions.push(PdaTransition {
+            from,
+            input,
+            pop,
+            push: push.chars().rev().collect(),
+            to,
+        });
+    }
+
+    pub fn state_count(&self) -> usize {
+        self.accepting.len()
+    }
+
+    pub fn acceptance(&self) -> Acceptance {
+        self.acceptance
+    }
+
+    /// Searches the configurations (state, input read, stack) breadth first
+    /// for an accepting one.
+    ///
+    /// Moves that read nothing can push forever, so stacks taller than a
+    /// bound linear in the input are pruned. If the input is accepted at
+    /// all, some accepting run stays under it.
+    pub fn accepts(&self, input: &str) -> bool {
+        let input: Vec<char> = input.chars().collect();
+        let limit = self.stack_limit(input.len());
+        let start = (0, 0, vec![self.start_symbol]);
+        let mut seen = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([start]);
+        while let Some((state, read, stack)) = queue.pop_front() {
+            if read == input.len() && self.is_accepting(state, &stack) {
+                return true;
+            }
+            for t in self.transitions.iter().filter(|t| t.from == state) {
+                let next_read = match t.input {
+                    None => read,
+                    Some(c) if input.get(read) == Some(&c) => read + 1,
+                    Some(_) => continue,
+                };
+                let mut next_stack = stack.clone();
+                if let Some(symbol) = t.pop
+                    && next_stack.pop() != Some(symbol)
+                {
+                    continue;
+                }
+                next_stack.extend(&t.push);
+                if next_stack.len() > limit {
+                    continue;
+                }
+                let next = (t.to, next_read, next_stack);
+                if seen.insert(next.clone()) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+
+    fn is_accepting(&self, state: usize, stack: &[char]) -> bool {
+        match self.acceptance {
+            Acceptance::FinalState => self.accepting[state],
+            Acceptance::EmptyStack => stack.is_empty(),
+        }
+    }
+
+    /// A stack height no shortest accepting run needs to exceed: each input
+    /// char can be followed by at most states x symbols moves that read
+    /// nothing before one of them repeats a (state, top) pair
+    fn stack_limit(&self, input_len: usize) -> usize {
+        let symbols: BTreeSet<char> = self
+            .transitions
+            .iter()
+            .flat_map(|t| t.push.iter().copied().chain(t.pop))
+            .chain([self.start_symbol])
+            .collect();
+        let longest_push = self.transitions.iter().map(|t| t.push.len()).max();
+        let per_char = (self.state_count() * symbols.len() + 1) * longest_push.unwrap_or(0).max(1);
+        (input_len + 1) * per_char + 1
+    }
+
+    /// Accepts strings of balanced parentheses by empty stack: '(' pushes a
+    /// marker, ')' pops one, and the start symbol can be popped at any time
+    pub fn balanced_parentheses() -> Pda {
+        let mut pda = Pda::new('Z', Acceptance::EmptyStack);
+        pda.add_transition(0, Some('('), None, "(", 0);
+        pda.add_transition(0, Some(')'), Some('('), "", 0);
+        pda.add_transition(0, None, Some('Z'), "", 0);
+        pda
+    }
+
+    /// Accepts a^n b^n for n >= 0 by final state: count the a's on the
+    /// stack, then pop one for each b and accept once back at the start symbol
+    pub fn a_n_b_n() -> Pda {
+        let mut pda = Pda::new('Z', Acceptance::FinalState);
+        let popping = pda.add_state(false);
+        let done = pda.add_state(true);
+        pda.add_transition(0, Some('a'), None, "A", 0);
+        pda.add_transition(0, None, None, "", popping);
+        pda.add_transition(popping, Some('b'), Some('A'), "", popping);
+        pda.add_transition(popping, None, Some('Z'), "Z", done);
+        pda
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Acceptance, Pda};
+
+    fn strings(alphabet: [char; 2], max_len: usize) -> Vec<String> {
+        let mut all = vec![String::new()];
+        let mut frontier = vec![String::new()];
+        for _ in 0..max_len {
+            frontier = frontier
+                .iter()
+                .flat_map(|s| alphabet.map(|c| format!("{s}{c}")))
+                .collect();
+            all.extend(frontier.iter().cloned());
+        }
+        all
+    }
+
+    #[test]
+    fn balanced_parentheses() {
+        let pda = Pda::balanced_parentheses();
+        for s in strings(['(', ')'], 10) {
+            let mut depth = 0i32;
+            let balanced = s.chars().all(|c| {
+                depth += if c == '(' { 1 } else { -1 };
+                depth >= 0
+            }) && depth == 0;
+            assert_eq!(pda.accepts(&s), balanced, "{s}");
+        }
+    }
+
+    #[test]
+    fn a_n_b_n() {
+        let pda = Pda::a_n_b_n();
+        for s in strings(['a', 'b'], 10) {
+            let n = s.len() / 2;
+            let expected = s == format!("{}{}", "a".repeat(n), "b".repeat(n));
+            assert_eq!(pda.accepts(&s), expected, "{s}");
+        }
+        assert!(pda.accepts(&format!("{}{}", "a".repeat(200), "b".repeat(200))));
+    }
+
+    #[test]
+    fn nondeterministic_palindromes() {
+        // Even palindromes w w^R: push w, guess the middle, then match w^R
+        let mut pda = Pda::new('Z', Acceptance::FinalState);
+        let matching = pda.add_state(false);
+        let done = pda.add_state(true);
+        for c in ['a', 'b'] {
+            let symbol = c.to_ascii_uppercase().to_string();
+            pda.add_transition(0, Some(c), None, &symbol, 0);
+            pda.add_transition(matching, Some(c), c.to_uppercase().next(), "", matching);
+        }
+        pda.add_transition(0, None, None, "", matching);
+        pda.add_transition(matching, None, Some('Z'), "", done);
+        for s in strings(['a', 'b'], 8) {
+            let reversed: String = s.chars().rev().collect();
+            let expected = s.len() % 2 == 0 && s == reversed;
+            assert_eq!(pda.accepts(&s), expected, "{s}");
+        }
+    }
+
+    #[test]
+    fn epsilon_loops_that_grow_the_stack_terminate() {
+        let mut pda = Pda::new('Z', Acceptance::EmptyStack);
+        pda.add_transition(0, None, None, "X", 0);
+        pda.add_transition(0, Some('a'), Some('Y'), "", 0);
+        assert!(!pda.accepts("a"));
+        assert!(!pda.accepts(""));
+        pda.add_transition(0, Some('a'), Some('Z'), "", 0);
+        pda.add_transition(0, None, Some('X'), "", 0);
+        assert!(pda.accepts("a"));
+        assert!(!pda.accepts("aa"));
+    }
+}
@@ -0,0 +1,418 @@
+//! Runs the library's algorithms on input from a file or stdin.
+//!
+//! ```text
+//! csdemo sort --algo quicksort --input numbers.txt
+//! csdemo os rr --quantum 2 --input processes.txt
+//! csdemo graph bfs --source 0 --input edges.txt
+//! ```
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use rs_computer_science::algorithm::graph::{bfs_levels, floyd_warshall};
+use rs_computer_science::algorithm::sort::{
+    BubbleSort, GapSequence, GnomeSort, HeapSort, InsertionSort, IntroSort, LsdRadixSort,
+    ParMergeSort, ParQuickSort, SelectionSort, ShellSort, Sorter, TimSort, counting_sort,
+};
+use rs_computer_science::os::scheduling::{
+    Process, Schedule, fcfs, mlfq, priority, round_robin, sjf, srtf,
+};
+
+const USAGE: &str = "\
+usage: csdemo <command> [options]
+
+commands:
+  sort --algo <name> [--input <file>]
+      Sorts whitespace-separated integers. Algorithms: bubble, insertion,
+      selection, gnome, shell, heap, tim, intro, quicksort, mergesort,
+      radix, counting
+  os <fcfs|sjf|srtf|priority|rr|mlfq> [--quantum <n>] [--input <file>]
+      Schedules processes given one per line as `arrival burst [priority]`,
+      numbered P1, P2, ... in order. rr needs --quantum; mlfq uses
+      quanta 2, 4 and 8
+  graph <bfs|floyd> [--source <n>] [--input <file>]
+      Reads directed edges one per line as `from to [weight]`, with nodes
+      numbered from 0 up to 4095. bfs prints each node's level from --source
+      (default 0), ignoring weights; floyd prints all-pairs shortest
+      distances, taking a missing weight as 1
+
+Input is read from stdin when --input is missing.";
+
+/// The options after a command, as `--flag value` pairs and bare words
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    positional: Vec<String>,
+    flags: Vec<(String, String)>,
+}
+
+impl Args {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(flag) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("--{flag} needs a value"))?;
+                    parsed.flags.push((flag.to_string(), value));
+                }
+                None => parsed.positional.push(arg),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn flag(&self, name: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .rev()
+            .find(|(flag, _)| flag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Rejects flags the command does not know, to catch typos
+    fn expect_flags(&self, known: &[&str]) -> Result<(), String> {
+        match self
+            .flags
+            .iter()
+            .find(|(flag, _)| !known.contains(&flag.as_str()))
+        {
+            Some((flag, _)) => Err(format!("unknown option --{flag}")),
+            None => Ok(()),
+        }
+    }
+
+    fn input(&self) -> Result<String, String> {
+        match self.flag("input") {
+            Some(path) => fs::read_to_string(path).map_err(|e| format!("{path}: {e}")),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn sorter(name: &str) -> Option<Box<dyn Sorter<i64>>> {
+    Some(match name {
+        "bubble" => Box::new(BubbleSort),
+        "insertion" => Box::new(InsertionSort),
+        "selection" => Box::new(SelectionSort),
+        "gnome" => Box::new(GnomeSort),
+        "shell" => Box::new(ShellSort(GapSequence::Ciura)),
+        "heap" => Box::new(HeapSort),
+        "tim" => Box::new(TimSort),
+        "intro" => Box::new(IntroSort),
+        "quicksort" => Box::new(ParQuickSort),
+        "mergesort" => Box::new(ParMergeSort),
+        "radix" => Box::new(LsdRadixSort),
+        _ => return None,
+    })
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<i64>, String> {
+    input
+        .split_whitespace()
+        .map(|word| word.parse().map_err(|_| format!("not an integer: {word}")))
+        .collect()
+}
+
+fn sort(args: &Args, input: &str) -> Result<String, String> {
+    let name = args.flag("algo").ok_or("sort needs --algo")?;
+    // Counting sort refuses a wide key range, which its `Sorter` impl can only panic on
+    let sorter = match name {
+        "counting" => None,
+        _ => Some(sorter(name).ok_or_else(|| format!("unknown algorithm {name}"))?),
+    };
+    let mut numbers = parse_numbers(input)?;
+    match sorter {
+        Some(sorter) => sorter.sort(&mut numbers),
+        None => counting_sort(&mut numbers, |&x| x).map_err(|e| format!("counting: {e}"))?,
+    }
+    let sorted: Vec<String> = numbers.iter().map(i64::to_string).collect();
+    Ok(sorted.join(" "))
+}
+
+fn parse_processes(input: &str) -> Result<Vec<Process>, String> {
+    let mut processes = Vec::new();
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let id = processes.len() + 1;
+        let fields: Vec<u64> = line
+            .split_whitespace()
+            .map(|field| {
+                field
+                    .parse()
+                    .map_err(|_| format!("P{id}: not a number: {field}"))
+            })
+            .collect::<Result<_, _>>()?;
+        let (arrival, burst, priority) = match fields[..] {
+            [arrival, burst] => (arrival, burst, 0),
+            [arrival, burst, priority] => (arrival, burst, priority),
+            _ => return Err(format!("P{id}: expected `arrival burst [priority]`")),
+        };
+        if burst == 0 {
+            return Err(format!("P{id}: burst must be positive"));
+        }
+        let priority = u32::try_from(priority).map_err(|_| format!("P{id}: priority too large"))?;
+        processes.push(Process::new(id, arrival, burst, priority));
+    }
+    Ok(processes)
+}
+
+fn schedule(args: &Args, input: &str) -> Result<String, String> {
+    let [policy] = &args.positional[..] else {
+        return Err("os needs exactly one policy".to_string());
+    };
+    let processes = parse_processes(input)?;
+    let schedule: Schedule = match policy.as_str() {
+        "fcfs" => fcfs(&processes),
+        "sjf" => sjf(&processes),
+        "srtf" => srtf(&processes),
+        "priority" => priority(&processes, true),
+        "rr" => {
+            let quantum = args.flag("quantum").ok_or("rr needs --quantum")?;
+            match quantum.parse() {
+                Ok(quantum) if quantum > 0 => round_robin(&processes, quantum),
+                _ => return Err(format!("invalid quantum {quantum}")),
+            }
+        }
+        "mlfq" => mlfq(&processes, &[2, 4, 8], None),
+        _ => return Err(format!("unknown policy {policy}")),
+    };
+    Ok(format!(
+        "{schedule}\naverage waiting {:.2}, turnaround {:.2}, response {:.2}, {} context switches",
+        schedule.average_waiting(),
+        schedule.average_turnaround(),
+        schedule.average_response(),
+        schedule.context_switches()
+    ))
+}
+
+/// A directed edge as `(from, to, weight)`
+type Edge = (usize, usize, Option<i64>);
+
+/// Node IDs size the graph, and floyd keeps a matrix over all of them
+const MAX_NODES: usize = 4096;
+
+/// The edges and the node count, one past the largest node
+fn parse_edges(input: &str) -> Result<(Vec<Edge>, usize), String> {
+    let mut edges = Vec::new();
+    let mut nodes = 0;
+    for (line_no, line) in input.lines().enumerate() {
+        let line_no = line_no + 1;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (from, to, weight) = match fields[..] {
+            [] => continue,
+            [from, to] => (from, to, None),
+            [from, to, weight] => (from, to, Some(weight)),
+            _ => return Err(format!("line {line_no}: expected `from to [weight]`")),
+        };
+        let node = |field: &str| match field.parse::<usize>() {
+            Ok(node) if node < MAX_NODES => Ok(node),
+            Ok(_) => Err(format!(
+                "line {line_no}: node {field} is not below {MAX_NODES}"
+            )),
+            Err(_) => Err(format!("line {line_no}: not a node: {field}")),
+        };
+        let (from, to) = (node(from)?, node(to)?);
+        let weight = match weight {
+            Some(w) => Some(
+                w.parse()
+                    .map_err(|_| format!("line {line_no}: not a weight: {w}"))?,
+            ),
+            None => None,
+        };
+        nodes = nodes.max(from + 1).max(to + 1);
+        edges.push((from, to, weight));
+    }
+    Ok((edges, nodes))
+}
+
+fn graph(args: &Args, input: &str) -> Result<String, String> {
+    let [algorithm] = &args.positional[..] else {
+        return Err("graph needs exactly one algorithm".to_string());
+    };
+    let (edges, nodes) = parse_edges(input)?;
+    match algorithm.as_str() {
+        "bfs" => {
+            let source = match args.flag("source") {
+                Some(source) => source
+                    .parse()
+                    .map_err(|_| format!("invalid source {source}"))?,
+                None => 0,
+            };
+            if source >= nodes {
+                return Err(format!("source {source} is not in the graph"));
+            }
+            let mut adjacency = vec![Vec::new(); nodes];
+            for &(from, to, _) in &edges {
+                adjacency[from].push(to);
+            }
+            let lines: Vec<String> = bfs_levels(&adjacency, source)
+                .iter()
+                .enumerate()
+                .map(|(node, level)| match level {
+                    Some(level) => format!("{node}: {level}"),
+                    None => format!("{node}: unreachable"),
+                })
+                .collect();
+            Ok(lines.join("\n"))
+        }
+        "floyd" => {
+            if args.flag("source").is_some() {
+                return Err("floyd takes no --source".to_string());
+            }
+            let mut weights = vec![vec![None; nodes]; nodes];
+            for &(from, to, weight) in &edges {
+                let weight = weight.unwrap_or(1);
+                // Keep the lightest of parallel edges
+                let slot: &mut Option<i64> = &mut weights[from][to];
+                *slot = Some(slot.map_or(weight, |w| w.min(weight)));
+            }
+            let dist = floyd_warshall(&weights).ok_or("the graph has a negative cycle")?;
+            let rows: Vec<String> = dist
+                .iter()
+                .map(|row| {
+                    let cells: Vec<String> = row
+                        .iter()
+                        .map(|d| d.map_or("-".to_string(), |d| d.to_string()))
+                        .collect();
+                    cells.join(" ")
+                })
+                .collect();
+            Ok(rows.join("\n"))
+        }
+        _ => Err(format!("unknown graph algorithm {algorithm}")),
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<String, String> {
+    let command = args.next().ok_or(USAGE)?;
+    let args = Args::parse(args)?;
+    match command.as_str() {
+        "sort" => {
+            args.expect_flags(&["algo", "input"])?;
+            if !args.positional.is_empty() {
+                return Err("sort takes no positional arguments".to_string());
+            }
+            sort(&args, &args.input()?)
+        }
+        "os" => {
+            args.expect_flags(&["quantum", "input"])?;
+            schedule(&args, &args.input()?)
+        }
+        "graph" => {
+            args.expect_flags(&["source", "input"])?;
+            graph(&args, &args.input()?)
+        }
+        "help" | "--help" | "-h" => Ok(USAGE.to_string()),
+        _ => Err(format!("unknown command {command}\n\n{USAGE}")),
+    }
+}
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1)) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("csdemo: {message}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Args, graph, parse_processes, schedule, sort};
+
+    fn args(words: &[&str]) -> Args {
+        Args::parse(words.iter().map(|w| w.to_string())).unwrap()
+    }
+
+    #[test]
+    fn parses_flags_and_positionals() {
+        let parsed = args(&["rr", "--quantum", "2"]);
+        assert_eq!(parsed.positional, ["rr"]);
+        assert_eq!(parsed.flag("quantum"), Some("2"));
+        assert!(parsed.expect_flags(&["quantum"]).is_ok());
+        assert!(parsed.expect_flags(&["input"]).is_err());
+        assert!(Args::parse(["--input".to_string()]).is_err());
+    }
+
+    #[test]
+    fn sorts_with_every_algorithm() {
+        for algo in [
+            "bubble",
+            "insertion",
+            "selection",
+            "gnome",
+            "shell",
+            "heap",
+            "tim",
+            "intro",
+            "quicksort",
+            "mergesort",
+            "radix",
+            "counting",
+        ] {
+            let output = sort(&args(&["--algo", algo]), "3 -1 2\n10 0");
+            assert_eq!(output.as_deref(), Ok("-1 0 2 3 10"), "{algo}");
+        }
+        assert!(sort(&args(&["--algo", "bogo"]), "1").is_err());
+        // Too wide a range for counting sort is an error, not a panic
+        let wide = sort(&args(&["--algo", "counting"]), "0 4000000000 -4000000000");
+        assert!(wide.is_err_and(|e| e.starts_with("counting: key range")));
+        assert!(sort(&args(&["--algo", "heap"]), "1 x").is_err());
+    }
+
+    #[test]
+    fn schedules_round_robin() {
+        let input = "0 5\n1 3\n\n2 1\n";
+        let output = schedule(&args(&["rr", "--quantum", "2"]), input).unwrap();
+        assert_eq!(
+            output.lines().next(),
+            Some("0 [P1] 2 [P2] 4 [P3] 5 [P1] 7 [P2] 8 [P1] 9")
+        );
+        assert!(schedule(&args(&["rr"]), input).is_err());
+        assert!(schedule(&args(&["rr", "--quantum", "0"]), input).is_err());
+        assert!(schedule(&args(&["lottery"]), input).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_processes() {
+        assert_eq!(parse_processes("0 4 1\n").unwrap()[0].priority, 1);
+        assert!(parse_processes("0\n").is_err());
+        assert!(parse_processes("0 0\n").is_err());
+        assert!(parse_processes("0 1 2 3\n").is_err());
+    }
+
+    #[test]
+    fn runs_graph_algorithms() {
+        let input = "0 1 4\n0 2 1\n2 1 2\n1 3\n\n5 0 7\n";
+        let levels = graph(&args(&["bfs"]), input).unwrap();
+        assert_eq!(
+            levels,
+            "0: 0\n1: 1\n2: 1\n3: 2\n4: unreachable\n5: unreachable"
+        );
+        let from_five = graph(&args(&["bfs", "--source", "5"]), input).unwrap();
+        assert!(from_five.ends_with("3: 3\n4: unreachable\n5: 0"));
+        let dist = graph(&args(&["floyd"]), input).unwrap();
+        assert_eq!(dist.lines().next(), Some("0 3 1 4 - -"));
+        assert_eq!(dist.lines().last(), Some("7 10 8 11 - 0"));
+
+        assert!(graph(&args(&["bfs", "--source", "9"]), input).is_err());
+        assert!(graph(&args(&["floyd"]), "0 1 1\n1 0 -2").is_err());
+        assert!(graph(&args(&["floyd"]), "0 x").is_err());
+        assert!(graph(&args(&["bfs"]), "0 18446744073709551615").is_err());
+        assert!(graph(&args(&["floyd"]), "0 100000000").is_err());
+        assert!(graph(&args(&["dfs"]), input).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::Growth;
+
+/// How well `cost ≈ coefficient * growth(n)` explains the samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub growth: Growth,
+    pub coefficient: f64,
+    /// Root mean square of the relative residuals, so 0.1 means the curve
+    /// is typically 10% off
+    pub error: f64,
+}
+
+/// The samples and every candidate fit, best first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub samples: Vec<(usize, f64)>,
+    pub fits: Vec<Fit>,
+}
+
+impl Report {
+    /// The growth rate that fits best
+    pub fn best(&self) -> Growth {
+        self.fits[0].growth
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for &(n, cost) in &self.samples {
+            writeln!(f, "n={n:<10} cost={cost:.6e}")?;
+        }
+        for fit in &self.fits {
+            writeln!(
+                f,
+                "{:<11} coefficient={:.3e} error={:.1}%",
+                fit.growth.to_string(),
+                fit.coefficient,
+                fit.error * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Fits every `Growth` to `(size, cost)` samples and ranks them.
+///
+/// Each fit minimises relative rather than absolute error, so the small
+/// sizes count as much as the large ones. Otherwise the largest sample
+/// alone decides the coefficient and every curve fits it. Panics with fewer
+/// than two samples or with a cost that is not positive.
+pub fn fit(samples: &[(usize, f64)]) -> Report {
+    assert!(
+        samples.len() >= 2,
+        "need at least two samples to fit a curve"
+    );
+    assert!(
+        samples.iter().all(|&(_, cost)| cost > 0.0),
+        "costs must be positive"
+    );
+    let mut fits: Vec<Fit> = Growth::ALL
+        .iter()
+        .map(|&growth| {
+            // Minimising sum((c * g / t - 1)^2) gives c = sum(r) / sum(r^2) with r = g / t
+            let ratios: Vec<f64> = samples
+                .iter()
+                .map(|&(n, cost)| growth.eval(n as f64) / cost)
+                .collect();
+            let coefficient =
+                ratios.iter().sum::<f64>() / ratios.iter().map(|r| r * r).sum::<f64>();
+            let squares: f64 = ratios.iter().map(|r| (coefficient * r - 1.0).powi(2)).sum();
+            Fit {
+                growth,
+                coefficient,
+                error: (squares / samples.len() as f64).sqrt(),
+            }
+        })
+        .collect();
+    fits.sort_by(|a, b| a.error.total_cmp(&b.error));
+    Report {
+        samples: samples.to_vec(),
+        fits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fit;
+    use crate::complexity::{Growth, geometric_sizes};
+
+    #[test]
+    fn recovers_exact_curves() {
+        for growth in Growth::ALL {
+            let samples: Vec<(usize, f64)> = geometric_sizes(64, 8)
+                .into_iter()
+                .map(|n| (n, 3.0 * growth.eval(n as f64)))
+                .collect();
+            let report = fit(&samples);
+            assert_eq!(report.best(), growth);
+            assert!(report.fits[0].error < 1e-9);
+            assert!((report.fits[0].coefficient - 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn tolerates_noise_and_constant_overhead() {
+        // n log n plus a fixed cost and up to ±10% jitter
+        let samples: Vec<(usize, f64)> = geometric_sizes(256, 8)
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| {
+                let jitter = [1.0, 1.1, 0.9, 1.05, 0.95, 1.08, 0.92, 1.0][i];
+                (n, (Growth::Linearithmic.eval(n as f64) + 500.0) * jitter)
+            })
+            .collect();
+        assert_eq!(fit(&samples).best(), Growth::Linearithmic);
+    }
+
+    #[cfg(feature = "algorithm")]
+    #[test]
+    fn classifies_sorts_by_comparison_count() {
+        use crate::algorithm::random::{Rng, XorShift64Star};
+        use crate::algorithm::sort::{
+            GapSequence, SortStats, Sorter, TimSort, insertion_sort_counted, shell_sort_counted,
+        };
+        use std::cell::Cell;
+
+        let sizes = geometric_sizes(128, 7);
+        let mut rng = XorShift64Star::seed_from_u64(1);
+        let inputs: Vec<Vec<u32>> = sizes
+            .iter()
+            .map(|&n| (0..n).map(|_| rng.next_u32()).collect())
+            .collect();
+        let count = |sort: &dyn Fn(&mut Vec<u32>, &mut SortStats)| -> Vec<(usize, f64)> {
+            inputs
+                .iter()
+                .map(|input| {
+                    let mut stats = SortStats::new();
+                    sort(&mut input.clone(), &mut stats);
+                    (input.len(), stats.comparisons as f64)
+                })
+                .collect()
+        };
+        let insertion = fit(&count(&|arr, stats| insertion_sort_counted(arr, stats)));
+        assert_eq!(insertion.best(), Growth::Quadratic, "\n{insertion}");
+
+        let shell = fit(&count(&|arr, stats| {
+            shell_sort_counted(arr, &GapSequence::Ciura, stats)
+        }));
+        assert_ne!(shell.best(), Growth::Quadratic, "\n{shell}");
+
+        // Already sorted input is linear for timsort; count comparisons via Ord
+        #[derive(Clone, PartialEq, Eq)]
+        struct Tally<'a>(u32, &'a Cell<u64>);
+        impl PartialOrd for Tally<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Tally<'_> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.1.set(self.1.get() + 1);
+                self.0.cmp(&other.0)
+            }
+        }
+        let samples: Vec<(usize, f64)> = sizes
+            .iter()
+            .map(|&n| {
+                let comparisons = Cell::new(0);
+                let mut arr: Vec<Tally> = (0..n as u32).map(|v| Tally(v, &comparisons)).collect();
+                TimSort.sort(&mut arr);
+                (n, comparisons.get() as f64)
+            })
+            .collect();
+        assert_eq!(fit(&samples).best(), Growth::Linear);
+    }
+}
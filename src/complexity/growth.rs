@@ -0,0 +1,32 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A candidate curve for how cost grows with input size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Growth {
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl Growth {
+    pub const ALL: [Growth; 3] = [Growth::Linear, Growth::Linearithmic, Growth::Quadratic];
+
+    /// The curve's value at `n`
+    pub fn eval(&self, n: f64) -> f64 {
+        match self {
+            Growth::Linear => n,
+            Growth::Linearithmic => n * n.max(2.0).log2(),
+            Growth::Quadratic => n * n,
+        }
+    }
+}
+
+impl Display for Growth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Growth::Linear => write!(f, "O(n)"),
+            Growth::Linearithmic => write!(f, "O(n log n)"),
+            Growth::Quadratic => write!(f, "O(n^2)"),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::time::Instant;
+
+use super::{Report, fit};
+
+/// `count` sizes starting at `start` and doubling each time
+pub fn geometric_sizes(start: usize, count: usize) -> Vec<usize> {
+    assert!(start > 0, "sizes must start above zero");
+    (0..count).map(|i| start << i).collect()
+}
+
+/// Times `run` on an input built by `setup` for each size, keeping the fastest
+/// of `repeats` runs to shed scheduling noise, and fits the timings.
+/// Building the input is not timed.
+pub fn measure<T>(
+    sizes: &[usize],
+    repeats: usize,
+    mut setup: impl FnMut(usize) -> T,
+    mut run: impl FnMut(T),
+) -> Report {
+    assert!(repeats > 0, "need at least one run per size");
+    let samples: Vec<(usize, f64)> = sizes
+        .iter()
+        .map(|&n| {
+            let fastest = (0..repeats)
+                .map(|_| {
+                    let input = setup(n);
+                    let start = Instant::now();
+                    run(input);
+                    start.elapsed().as_secs_f64()
+                })
+                .fold(f64::INFINITY, f64::min);
+            // A run too quick for the clock still costs something
+            (n, fastest.max(1e-9))
+        })
+        .collect();
+    fit(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{geometric_sizes, measure};
+
+    #[test]
+    fn sizes_double() {
+        assert_eq!(geometric_sizes(3, 4), [3, 6, 12, 24]);
+    }
+
+    #[test]
+    fn measures_every_size() {
+        let sizes = geometric_sizes(1000, 4);
+        let report = measure(
+            &sizes,
+            2,
+            |n| vec![1u64; n],
+            |v| {
+                black_box(v.iter().sum::<u64>());
+            },
+        );
+        assert_eq!(
+            report.samples.iter().map(|&(n, _)| n).collect::<Vec<_>>(),
+            sizes
+        );
+        assert!(report.samples.iter().all(|&(_, t)| t > 0.0));
+        assert_eq!(report.fits.len(), 3);
+    }
+}
@@ -0,0 +1,11 @@
+//! Guesses an algorithm's growth rate from costs measured at increasing
+//! input sizes. Costs can be timings from `measure`, or exact counts such as
+//! `SortStats::comparisons`, which make for tests that never flake.
+
+mod fit;
+mod growth;
+mod measure;
+
+pub use self::fit::{Fit, Report, fit};
+pub use self::growth::Growth;
+pub use self::measure::{geometric_sizes, measure};
@@ -0,0 +1,415 @@
+use std::cell::UnsafeCell;
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::process;
+use std::ptr::NonNull;
+
+#[cfg(loom)]
+use loom::{
+    hint,
+    sync::atomic::{self, AtomicUsize, Ordering},
+};
+#[cfg(not(loom))]
+use std::{
+    hint,
+    sync::atomic::{self, AtomicUsize, Ordering},
+};
+
+/// Counts above this abort, as `std::sync::Arc` does, since leaked clones
+/// could otherwise overflow them
+const MAX_COUNT: usize = isize::MAX as usize;
+
+/// The weak count while `get_mut` checks for uniqueness
+const LOCKED: usize = usize::MAX;
+
+struct Inner<T> {
+    /// How many `MyArc`s there are
+    strong: AtomicUsize,
+    /// How many `Weak`s there are, plus one shared by all the `MyArc`s
+    weak: AtomicUsize,
+    value: UnsafeCell<ManuallyDrop<T>>,
+}
+
+/// A thread-safe reference-counted pointer, like `std::sync::Arc`.
+///
+/// The value is dropped when the last `MyArc` goes, and the allocation
+/// when the last `Weak` does too. Count increments can be `Relaxed`, since
+/// whoever increments already holds a reference. Decrements are `Release`,
+/// and the one that reaches zero takes an `Acquire` fence, so every use
+/// of the value through other pointers happens before it is dropped.
+pub struct MyArc<T> {
+    inner: NonNull<Inner<T>>,
+    /// Tells the drop checker that dropping a `MyArc` may drop a `T`
+    owns: PhantomData<Inner<T>>,
+}
+
+/// A pointer that does not keep the value alive, but can be upgraded to a
+/// `MyArc` while some other one does
+pub struct Weak<T> {
+    inner: NonNull<Inner<T>>,
+}
+
+// Any thread may end up dropping the value and every thread can share it
+unsafe impl<T: Send + Sync> Send for MyArc<T> {}
+unsafe impl<T: Send + Sync> Sync for MyArc<T> {}
+unsafe impl<T: Send + Sync> Send for Weak<T> {}
+unsafe impl<T: Send + Sync> Sync for Weak<T> {}
+
+impl<T> MyArc<T> {
+    pub fn new(value: T) -> MyArc<T> {
+        let inner = Box::new(Inner {
+            strong: AtomicUsize::new(1),
+            weak: AtomicUsize::new(1),
+            value: UnsafeCell::new(ManuallyDrop::new(value)),
+        });
+        MyArc {
+            inner: NonNull::from(Box::leak(inner)),
+            owns: PhantomData,
+        }
+    }
+
+    fn inner(&self) -> &Inner<T> {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// A weak pointer to the same value
+    pub fn downgrade(this: &MyArc<T>) -> Weak<T> {
+        let weak = &this.inner().weak;
+        let mut count = weak.load(Ordering::Relaxed);
+        loop {
+            // `get_mut` on another pointer holds the count; it puts it back shortly
+            if count == LOCKED {
+                hint::spin_loop();
+                count = weak.load(Ordering::Relaxed);
+                continue;
+            }
+            if count > MAX_COUNT {
+                process::abort();
+            }
+            // Acquire pairs with the Release that unlocks the count in `get_mut`
+            match weak.compare_exchange_weak(count, count + 1, Ordering::Acquire, Ordering::Relaxed)
+            {
+                Ok(_) => return Weak { inner: this.inner },
+                Err(actual) => count = actual,
+            }
+        }
+    }
+
+    pub fn strong_count(this: &MyArc<T>) -> usize {
+        this.inner().strong.load(Ordering::Relaxed)
+    }
+
+    pub fn weak_count(this: &MyArc<T>) -> usize {
+        match this.inner().weak.load(Ordering::Relaxed) {
+            // Only locked while there are no `Weak`s
+            LOCKED => 0,
+            count => count - 1,
+        }
+    }
+
+    /// Whether two pointers share one value
+    pub fn ptr_eq(this: &MyArc<T>, other: &MyArc<T>) -> bool {
+        this.inner == other.inner
+    }
+
+    /// Mutable access, if no other `MyArc` or `Weak` points to the value.
+    /// With `&mut` to the only strong pointer, no other can be made
+    ///
+    /// Reading the two counts one after the other is not enough: between
+    /// the reads another thread could upgrade a `Weak` and then drop it, or
+    /// downgrade another `MyArc` and then drop that. So, as in
+    /// `std::sync::Arc`, the weak count is locked first. That only succeeds
+    /// when there are no `Weak`s, and `downgrade` waits while it is held.
+    pub fn get_mut(this: &mut MyArc<T>) -> Option<&mut T> {
+        let inner = this.inner();
+        // Acquire pairs with the Release of dropped `Weak`s
+        if inner
+            .weak
+            .compare_exchange(1, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return None;
+        }
+        // Acquire pairs with the Release of dropped `MyArc`s
+        let unique = inner.strong.load(Ordering::Acquire) == 1;
+        inner.weak.store(1, Ordering::Release);
+        unique.then(|| unsafe { &mut **this.inner().value.get() })
+    }
+}
+
+impl<T> Deref for MyArc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.inner().value.get() }
+    }
+}
+
+impl<T> Clone for MyArc<T> {
+    fn clone(&self) -> MyArc<T> {
+        if self.inner().strong.fetch_add(1, Ordering::Relaxed) > MAX_COUNT {
+            process::abort();
+        }
+        MyArc {
+            inner: self.inner,
+            owns: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for MyArc<T> {
+    fn drop(&mut self) {
+        if self.inner().strong.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+        atomic::fence(Ordering::Acquire);
+        unsafe { ManuallyDrop::drop(&mut *self.inner().value.get()) };
+        // Give up the weak reference the strong ones shared
+        drop(Weak { inner: self.inner });
+    }
+}
+
+impl<T: Debug> Debug for MyArc<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> Weak<T> {
+    fn inner(&self) -> &Inner<T> {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// A `MyArc` to the value, or None if it has been dropped. The weak
+    /// count cannot be locked here: this `Weak` keeps it above 1.
+    pub fn upgrade(&self) -> Option<MyArc<T>> {
+        let strong = &self.inner().strong;
+        let mut count = strong.load(Ordering::Relaxed);
+        loop {
+            // Never bring the count back from zero: the value is gone
+            if count == 0 {
+                return None;
+            }
+            if count > MAX_COUNT {
+                process::abort();
+            }
+            match strong.compare_exchange_weak(
+                count,
+                count + 1,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    return Some(MyArc {
+                        inner: self.inner,
+                        owns: PhantomData,
+                    });
+                }
+                Err(actual) => count = actual,
+            }
+        }
+    }
+
+    pub fn strong_count(&self) -> usize {
+        self.inner().strong.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Clone for Weak<T> {
+    fn clone(&self) -> Weak<T> {
+        if self.inner().weak.fetch_add(1, Ordering::Relaxed) > MAX_COUNT {
+            process::abort();
+        }
+        Weak { inner: self.inner }
+    }
+}
+
+impl<T> Debug for Weak<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "(Weak)")
+    }
+}
+
+impl<T> Drop for Weak<T> {
+    fn drop(&mut self) {
+        if self.inner().weak.fetch_sub(1, Ordering::Release) == 1 {
+            atomic::fence(Ordering::Acquire);
+            drop(unsafe { Box::from_raw(self.inner.as_ptr()) });
+        }
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use crate::concurrency::{MyArc, Weak};
+
+    /// Counts its drops
+    struct Probe<'a>(&'a AtomicUsize);
+
+    impl Drop for Probe<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn counts_and_upgrades() {
+        let drops = AtomicUsize::new(0);
+        let a = MyArc::new(Probe(&drops));
+        let b = a.clone();
+        let weak = MyArc::downgrade(&a);
+        assert_eq!((MyArc::strong_count(&a), MyArc::weak_count(&a)), (2, 1));
+        assert!(MyArc::ptr_eq(&a, &weak.upgrade().unwrap()));
+        drop(a);
+        drop(b);
+        // The value is gone though the weak pointer keeps the allocation
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        assert!(weak.upgrade().is_none());
+        assert_eq!(weak.strong_count(), 0);
+    }
+
+    #[test]
+    fn get_mut_needs_a_unique_pointer() {
+        let mut a = MyArc::new(1);
+        *MyArc::get_mut(&mut a).unwrap() += 1;
+        let b = a.clone();
+        assert!(MyArc::get_mut(&mut a).is_none());
+        drop(b);
+        let weak = MyArc::downgrade(&a);
+        assert!(MyArc::get_mut(&mut a).is_none());
+        drop(weak);
+        assert_eq!(MyArc::get_mut(&mut a).copied(), Some(2));
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let drops = AtomicUsize::new(0);
+        let arc = MyArc::new((Probe(&drops), 7));
+        let weak = MyArc::downgrade(&arc);
+        thread::scope(|s| {
+            for _ in 0..4 {
+                let arc = arc.clone();
+                let weak: Weak<_> = weak.clone();
+                s.spawn(move || {
+                    for _ in 0..1000 {
+                        let clone = arc.clone();
+                        let upgraded = weak.upgrade().unwrap();
+                        assert_eq!(clone.1 + upgraded.1, 14);
+                    }
+                });
+            }
+        });
+        assert_eq!((MyArc::strong_count(&arc), MyArc::weak_count(&arc)), (1, 1));
+        // Threads race to drop the last strong and weak pointers
+        thread::scope(|s| {
+            s.spawn(move || drop(arc));
+            s.spawn(move || drop(weak));
+        });
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+}
+
+/// Exhaustive checks of every interleaving, run with
+/// `RUSTFLAGS="--cfg loom" cargo test --release --lib concurrency::arc`
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use loom::cell::UnsafeCell;
+    use loom::sync::Arc;
+    use loom::sync::atomic::{AtomicUsize, Ordering};
+    use loom::thread;
+
+    use crate::concurrency::MyArc;
+
+    /// Counts its drops
+    struct Probe(Arc<AtomicUsize>);
+
+    impl Drop for Probe {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// A value whose reads and writes loom checks for data races
+    struct Slot(UnsafeCell<usize>);
+
+    unsafe impl Sync for Slot {}
+
+    impl Slot {
+        fn read(&self) -> usize {
+            self.0.with(|p| unsafe { *p })
+        }
+
+        fn write(&self, value: usize) {
+            self.0.with_mut(|p| unsafe { *p = value })
+        }
+    }
+
+    #[test]
+    fn clone_and_drop_free_once() {
+        loom::model(|| {
+            let drops = Arc::new(AtomicUsize::new(0));
+            let arc = MyArc::new(Probe(drops.clone()));
+            let clone = arc.clone();
+            let weak = MyArc::downgrade(&arc);
+            let t = thread::spawn(move || drop(clone));
+            let u = thread::spawn(move || drop(weak));
+            drop(arc);
+            t.join().unwrap();
+            u.join().unwrap();
+            assert_eq!(drops.load(Ordering::Relaxed), 1);
+        });
+    }
+
+    #[test]
+    fn upgrade_races_the_last_drop() {
+        loom::model(|| {
+            let drops = Arc::new(AtomicUsize::new(0));
+            let arc = MyArc::new((Probe(drops.clone()), Slot(UnsafeCell::new(7))));
+            let weak = MyArc::downgrade(&arc);
+            let t = thread::spawn(move || weak.upgrade().map(|up| up.1.read()));
+            drop(arc);
+            let seen = t.join().unwrap();
+            assert!(seen.is_none_or(|v| v == 7));
+            assert_eq!(drops.load(Ordering::Relaxed), 1);
+        });
+    }
+
+    #[test]
+    fn get_mut_excludes_an_upgrade_then_weak_drop() {
+        loom::model(|| {
+            let mut arc = MyArc::new(Slot(UnsafeCell::new(0)));
+            let weak = MyArc::downgrade(&arc);
+            let t = thread::spawn(move || {
+                let up = weak.upgrade();
+                drop(weak);
+                up.map(|up| up.read())
+            });
+            if let Some(slot) = MyArc::get_mut(&mut arc) {
+                slot.write(1);
+            }
+            t.join().unwrap();
+        });
+    }
+
+    #[test]
+    fn get_mut_excludes_a_downgrade_then_strong_drop() {
+        loom::model(|| {
+            let mut arc = MyArc::new(Slot(UnsafeCell::new(0)));
+            let other = arc.clone();
+            let t = thread::spawn(move || {
+                let weak = MyArc::downgrade(&other);
+                drop(other);
+                weak.upgrade().map(|up| up.read())
+            });
+            if let Some(slot) = MyArc::get_mut(&mut arc) {
+                slot.write(1);
+            }
+            t.join().unwrap();
+        });
+    }
+}
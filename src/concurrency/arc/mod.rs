@@ -0,0 +1,3 @@
+mod arc;
+
+pub use self::arc::{MyArc, Weak};
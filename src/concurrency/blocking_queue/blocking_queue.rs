@@ -0,0 +1,136 @@
+use std::sync::{Condvar, Mutex};
+
+use crate::data_structure::Queue;
+
+#[derive(Debug)]
+struct State<T> {
+    items: Queue<T>,
+    closed: bool,
+}
+
+/// An unbounded multi-producer multi-consumer queue whose `pop` waits for
+/// an item. Closing it lets consumers drain what is left and then stop
+#[derive(Debug)]
+pub struct BlockingQueue<T> {
+    state: Mutex<State<T>>,
+    pushed: Condvar,
+}
+
+impl<T> BlockingQueue<T> {
+    /// Creates a new empty, open queue
+    pub fn new() -> BlockingQueue<T> {
+        BlockingQueue {
+            state: Mutex::new(State {
+                items: Queue::new(),
+                closed: false,
+            }),
+            pushed: Condvar::new(),
+        }
+    }
+
+    /// Adds an item at the back, or hands it back if the queue is closed
+    pub fn push(&self, item: T) -> Result<(), T> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            return Err(item);
+        }
+        state.items.enqueue(item);
+        self.pushed.notify_one();
+        Ok(())
+    }
+
+    /// Removes the front item, waiting for one while the queue is open.
+    /// Returns None once it is closed and empty
+    pub fn pop(&self) -> Option<T> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(item) = state.items.dequeue() {
+                return Some(item);
+            }
+            if state.closed {
+                return None;
+            }
+            state = self.pushed.wait(state).unwrap();
+        }
+    }
+
+    /// Removes the front item if there is one, without waiting
+    pub fn try_pop(&self) -> Option<T> {
+        self.state.lock().unwrap().items.dequeue()
+    }
+
+    /// Stops further pushes and wakes every waiting consumer
+    pub fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.pushed.notify_all();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+
+    /// Returns the number of items in the queue
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for BlockingQueue<T> {
+    fn default() -> BlockingQueue<T> {
+        BlockingQueue::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::thread;
+
+    use crate::concurrency::BlockingQueue;
+
+    #[test]
+    fn close_drains_then_stops() {
+        let queue = BlockingQueue::new();
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        queue.close();
+        assert_eq!(queue.push(3), Err(3));
+        assert_eq!(
+            (queue.pop(), queue.pop(), queue.pop()),
+            (Some(1), Some(2), None)
+        );
+        assert_eq!(queue.try_pop(), None);
+    }
+
+    #[test]
+    fn many_producers_and_consumers() {
+        let queue = BlockingQueue::new();
+        let taken = Mutex::new(Vec::new());
+        thread::scope(|s| {
+            let consumers: Vec<_> = (0..3)
+                .map(|_| {
+                    s.spawn(|| {
+                        while let Some(item) = queue.pop() {
+                            taken.lock().unwrap().push(item);
+                        }
+                    })
+                })
+                .collect();
+            thread::scope(|s| {
+                for p in 0..4 {
+                    let queue = &queue;
+                    s.spawn(move || (0..1000).for_each(|i| queue.push(p * 1000 + i).unwrap()));
+                }
+            });
+            queue.close();
+            consumers.into_iter().for_each(|c| c.join().unwrap());
+        });
+        let mut taken = taken.into_inner().unwrap();
+        taken.sort_unstable();
+        assert_eq!(taken, (0..4000).collect::<Vec<_>>());
+    }
+}
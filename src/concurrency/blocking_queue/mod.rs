@@ -0,0 +1,3 @@
+mod blocking_queue;
+
+pub use self::blocking_queue::BlockingQueue;
@@ -0,0 +1,117 @@
+use std::sync::Mutex;
+
+use crate::concurrency::Semaphore;
+use crate::data_structure::Queue;
+
+/// The classic producer-consumer buffer: a fixed number of slots shared
+/// by any number of threads putting and taking items.
+///
+/// One semaphore counts the empty slots and another the full ones, so a
+/// producer waits while the buffer is full and a consumer while it is
+/// empty, and a mutex guards the queue itself.
+#[derive(Debug)]
+pub struct BoundedBuffer<T> {
+    capacity: usize,
+    items: Mutex<Queue<T>>,
+    empty: Semaphore,
+    full: Semaphore,
+}
+
+impl<T> BoundedBuffer<T> {
+    /// Creates an empty buffer of `capacity` slots
+    pub fn new(capacity: usize) -> BoundedBuffer<T> {
+        assert!(capacity > 0, "a buffer needs at least one slot");
+        BoundedBuffer {
+            capacity,
+            items: Mutex::new(Queue::new()),
+            empty: Semaphore::new(capacity),
+            full: Semaphore::new(0),
+        }
+    }
+
+    /// Adds an item at the back, waiting for a free slot
+    pub fn put(&self, item: T) {
+        self.empty.acquire();
+        self.items.lock().unwrap().enqueue(item);
+        self.full.release();
+    }
+
+    /// Removes the front item, waiting for one to be put
+    pub fn take(&self) -> T {
+        self.full.acquire();
+        let item = self.items.lock().unwrap().dequeue();
+        self.empty.release();
+        item.expect("a full slot holds an item")
+    }
+
+    /// Returns the number of items in the buffer
+    pub fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::thread;
+
+    use crate::concurrency::BoundedBuffer;
+
+    #[test]
+    fn single_thread_fifo() {
+        let buffer = BoundedBuffer::new(3);
+        for i in 0..3 {
+            buffer.put(i);
+        }
+        assert_eq!(buffer.len(), 3);
+        assert_eq!((buffer.take(), buffer.take(), buffer.take()), (0, 1, 2));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn many_producers_and_consumers() {
+        const PRODUCERS: usize = 4;
+        const CONSUMERS: usize = 4;
+        const ITEMS: usize = 2000;
+        let buffer = BoundedBuffer::new(8);
+        let taken = Mutex::new(Vec::new());
+        thread::scope(|s| {
+            for p in 0..PRODUCERS {
+                let buffer = &buffer;
+                s.spawn(move || {
+                    for i in 0..ITEMS {
+                        buffer.put(p * ITEMS + i);
+                    }
+                });
+            }
+            for _ in 0..CONSUMERS {
+                s.spawn(|| {
+                    let mut mine = Vec::new();
+                    for _ in 0..PRODUCERS * ITEMS / CONSUMERS {
+                        assert!(buffer.len() <= buffer.capacity());
+                        mine.push(buffer.take());
+                    }
+                    // Each producer's items come out in the order it put them
+                    for p in 0..PRODUCERS {
+                        let from_p = mine.iter().filter(|&&item| item / ITEMS == p);
+                        assert!(from_p.clone().zip(from_p.skip(1)).all(|(a, b)| a < b));
+                    }
+                    taken.lock().unwrap().extend(mine);
+                });
+            }
+        });
+        // Nothing lost and nothing taken twice
+        let mut taken = taken.into_inner().unwrap();
+        taken.sort_unstable();
+        assert_eq!(taken, (0..PRODUCERS * ITEMS).collect::<Vec<_>>());
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+mod bounded_buffer;
+
+pub use self::bounded_buffer::BoundedBuffer;
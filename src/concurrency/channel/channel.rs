@@ -0,0 +1,242 @@
+use std::sync::{Arc, Condvar, Mutex};
+
+use super::{RecvError, SendError, TryRecvError};
+use crate::data_structure::Queue;
+
+#[derive(Debug)]
+struct State<T> {
+    items: Queue<T>,
+    senders: usize,
+    receiver: bool,
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    /// How many items may wait in the queue, if limited
+    bound: Option<usize>,
+    sent: Condvar,
+    received: Condvar,
+}
+
+/// The sending half of a channel. Clone it for more producers
+#[derive(Debug)]
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The receiving half of a channel
+#[derive(Debug)]
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+fn new_channel<T>(bound: Option<usize>) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            items: Queue::new(),
+            senders: 1,
+            receiver: true,
+        }),
+        bound,
+        sent: Condvar::new(),
+        received: Condvar::new(),
+    });
+    let sender = Sender {
+        shared: shared.clone(),
+    };
+    (sender, Receiver { shared })
+}
+
+/// A multi-producer single-consumer channel with no limit on how many
+/// values it holds, so `send` never waits. Values from one sender arrive
+/// in the order they were sent
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    new_channel(None)
+}
+
+/// A channel holding at most `bound` values, where `send` waits while it
+/// is full
+pub fn sync_channel<T>(bound: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(bound > 0, "a bounded channel needs room for a value");
+    new_channel(Some(bound))
+}
+
+impl<T> Sender<T> {
+    /// Sends a value, waiting for room if the channel is bounded and full.
+    /// Fails, handing the value back, once the receiver is gone
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let shared = &*self.shared;
+        let mut state = shared.state.lock().unwrap();
+        loop {
+            if !state.receiver {
+                return Err(SendError(value));
+            }
+            match shared.bound {
+                Some(bound) if state.items.len() >= bound => {
+                    state = shared.received.wait(state).unwrap();
+                }
+                _ => break,
+            }
+        }
+        state.items.enqueue(value);
+        shared.sent.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Sender<T> {
+        self.shared.state.lock().unwrap().senders += 1;
+        Sender {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            // Wake the receiver so it sees the disconnect
+            self.shared.sent.notify_all();
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Receives the next value, waiting while the channel is empty. Fails
+    /// once it is empty and every sender is gone
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let shared = &*self.shared;
+        let mut state = shared.state.lock().unwrap();
+        loop {
+            if let Some(value) = state.items.dequeue() {
+                shared.received.notify_one();
+                return Ok(value);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = shared.sent.wait(state).unwrap();
+        }
+    }
+
+    /// Receives the next value if there is one, without waiting
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.state.lock().unwrap();
+        match state.items.dequeue() {
+            Some(value) => {
+                self.shared.received.notify_one();
+                Ok(value)
+            }
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Receives values until every sender is gone
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { receiver: self }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.receiver = false;
+        state.items.drain();
+        // Wake senders waiting for room so they see the disconnect
+        self.shared.received.notify_all();
+    }
+}
+
+/// The values of a `Receiver`, waiting for each, until it disconnects
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    receiver: &'a Receiver<T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::concurrency::{RecvError, SendError, TryRecvError, channel, sync_channel};
+
+    #[test]
+    fn disconnects_when_senders_drop() {
+        let (sender, receiver) = channel();
+        let second = sender.clone();
+        sender.send(1).unwrap();
+        second.send(2).unwrap();
+        drop(sender);
+        assert_eq!(receiver.try_recv(), Ok(1));
+        assert_eq!(receiver.try_recv(), Ok(2));
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+        drop(second);
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+        assert_eq!(receiver.recv(), Err(RecvError));
+    }
+
+    #[test]
+    fn send_fails_without_a_receiver() {
+        let (sender, receiver) = sync_channel(1);
+        drop(receiver);
+        assert_eq!(sender.send("lost"), Err(SendError("lost")));
+        // A sender waiting for room is woken by the receiver going away
+        let (sender, receiver) = sync_channel(1);
+        sender.send(0).unwrap();
+        thread::scope(|s| {
+            let blocked = s.spawn(|| sender.send(1));
+            thread::yield_now();
+            drop(receiver);
+            assert_eq!(blocked.join().unwrap(), Err(SendError(1)));
+        });
+    }
+
+    #[test]
+    fn each_sender_keeps_its_order() {
+        for bound in [None, Some(1), Some(4)] {
+            let (sender, receiver) = match bound {
+                Some(bound) => sync_channel(bound),
+                None => channel(),
+            };
+            thread::scope(|s| {
+                for id in 0..4 {
+                    let sender = sender.clone();
+                    s.spawn(move || (0..500).for_each(|i| sender.send((id, i)).unwrap()));
+                }
+                drop(sender);
+                let mut next = [0; 4];
+                for (id, i) in receiver.iter() {
+                    assert_eq!(i, next[id]);
+                    next[id] += 1;
+                }
+                assert_eq!(next, [500; 4]);
+            });
+        }
+    }
+
+    #[test]
+    fn bounded_send_waits_for_room() {
+        let (sender, receiver) = sync_channel(2);
+        thread::scope(|s| {
+            s.spawn(move || (0..10).for_each(|i| sender.send(i).unwrap()));
+            let mut received = Vec::new();
+            while let Ok(value) = receiver.recv() {
+                assert!(receiver.shared.state.lock().unwrap().items.len() <= 2);
+                received.push(value);
+            }
+            assert_eq!(received, (0..10).collect::<Vec<_>>());
+        });
+    }
+}
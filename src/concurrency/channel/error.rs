@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// The receiver is gone; holds the value that could not be sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> Display for SendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a channel whose receiver is gone")
+    }
+}
+
+impl<T: Debug> Error for SendError<T> {}
+
+/// Every sender is gone and the channel is empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+impl Display for RecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "receiving on an empty channel whose senders are gone")
+    }
+}
+
+impl Error for RecvError {}
+
+/// Reasons `try_recv` returns no value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// Nothing has been sent yet, but senders remain
+    Empty,
+    /// Every sender is gone and the channel is empty
+    Disconnected,
+}
+
+impl Display for TryRecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "the channel is empty"),
+            TryRecvError::Disconnected => write!(f, "{RecvError}"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
@@ -0,0 +1,5 @@
+mod channel;
+mod error;
+
+pub use self::channel::{Iter, Receiver, Sender, channel, sync_channel};
+pub use self::error::{RecvError, SendError, TryRecvError};
@@ -0,0 +1,5 @@
+mod simulation;
+mod table;
+
+pub use self::simulation::{Report, simulate};
+pub use self::table::Strategy;
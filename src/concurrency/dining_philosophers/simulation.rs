@@ -0,0 +1,145 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::Strategy;
+use super::table::{State, Table};
+use crate::algorithm::random::{Rng, XorShift64Star};
+
+/// What happened at the table over a run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Meals each philosopher finished
+    pub meals: Vec<u64>,
+    /// The most steps each philosopher spent hungry in one go
+    pub longest_wait: Vec<u64>,
+    /// Steps taken, fewer than asked for if the table deadlocked
+    pub steps: u64,
+    /// Whether the run stopped because nobody could do anything
+    pub deadlocked: bool,
+}
+
+impl Report {
+    pub fn total_meals(&self) -> u64 {
+        self.meals.iter().sum()
+    }
+
+    /// The philosophers who at some point waited longer than `limit` steps
+    pub fn starving(&self, limit: u64) -> Vec<usize> {
+        (0..self.meals.len())
+            .filter(|&p| self.longest_wait[p] > limit)
+            .collect()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (p, (meals, wait)) in self.meals.iter().zip(&self.longest_wait).enumerate() {
+            writeln!(f, "P{p}: {meals} meals, longest wait {wait} steps")?;
+        }
+        match self.deadlocked {
+            true => write!(f, "deadlocked after {} steps", self.steps),
+            false => write!(f, "{} steps", self.steps),
+        }
+    }
+}
+
+/// Runs `philosophers` philosophers with one strategy for up to `steps`
+/// steps.
+///
+/// Each step a scheduler seeded with `seed` picks one of the philosophers
+/// that can do something and it does its next thing: get hungry, pick up
+/// a fork, start eating or finish. If no one can do anything the table is
+/// deadlocked and the run stops.
+pub fn simulate(philosophers: usize, strategy: Strategy, steps: u64, seed: u64) -> Report {
+    let mut table = Table::new(philosophers, strategy);
+    let mut rng = XorShift64Star::seed_from_u64(seed);
+    let mut meals = vec![0; philosophers];
+    let mut longest_wait = vec![0; philosophers];
+    let mut hungry_since = vec![0; philosophers];
+    for step in 0..steps {
+        let ready: Vec<usize> = (0..philosophers).filter(|&p| table.can_act(p)).collect();
+        if ready.is_empty() {
+            for p in 0..philosophers {
+                if table.states[p] == State::Hungry {
+                    longest_wait[p] = longest_wait[p].max(step - hungry_since[p]);
+                }
+            }
+            return Report {
+                meals,
+                longest_wait,
+                steps: step,
+                deadlocked: true,
+            };
+        }
+        let p = ready[rng.gen_index(ready.len())];
+        let was = table.states[p];
+        if table.act(p) {
+            meals[p] += 1;
+        }
+        match (was, table.states[p]) {
+            (State::Thinking, State::Hungry) => hungry_since[p] = step,
+            (State::Hungry, State::Eating) => {
+                longest_wait[p] = longest_wait[p].max(step - hungry_since[p]);
+            }
+            _ => {}
+        }
+    }
+    for p in 0..philosophers {
+        if table.states[p] == State::Hungry {
+            longest_wait[p] = longest_wait[p].max(steps - hungry_since[p]);
+        }
+    }
+    Report {
+        meals,
+        longest_wait,
+        steps,
+        deadlocked: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::concurrency::dining_philosophers::{Strategy, simulate};
+
+    #[test]
+    fn naive_strategy_deadlocks() {
+        let report = simulate(5, Strategy::Naive, 100_000, 7);
+        assert!(report.deadlocked);
+        assert!(report.steps < 100_000);
+        // Everyone is stuck holding one fork, so everyone waits forever
+        assert_eq!(report.starving(0), [0, 1, 2, 3, 4]);
+        assert!(
+            report
+                .to_string()
+                .ends_with(&format!("deadlocked after {} steps", report.steps))
+        );
+    }
+
+    #[test]
+    fn strategies_make_progress() {
+        for strategy in [
+            Strategy::ResourceOrdering,
+            Strategy::Waiter,
+            Strategy::ChandyMisra,
+        ] {
+            for seed in 0..10 {
+                let report = simulate(5, strategy, 20_000, seed);
+                assert!(!report.deadlocked, "{strategy:?}");
+                assert!(
+                    report.meals.iter().all(|&m| m > 100),
+                    "{strategy:?}: {report}"
+                );
+                assert!(report.starving(1_000).is_empty(), "{strategy:?}: {report}");
+            }
+        }
+    }
+
+    #[test]
+    fn deterministic_for_a_seed() {
+        let a = simulate(7, Strategy::ChandyMisra, 5_000, 42);
+        assert_eq!(a, simulate(7, Strategy::ChandyMisra, 5_000, 42));
+        assert_ne!(a, simulate(7, Strategy::ChandyMisra, 5_000, 43));
+        // Two philosophers share both forks, so at most one eats at a time
+        let report = simulate(2, Strategy::Waiter, 1_000, 1);
+        assert!(!report.deadlocked && report.total_meals() > 0);
+    }
+}
@@ -0,0 +1,133 @@
+/// How a hungry philosopher gets hold of its two forks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Left fork, then right. Deadlocks once everyone holds their left one
+    Naive,
+    /// The lower-numbered fork first, which breaks the cycle of waits
+    ResourceOrdering,
+    /// A waiter hands out both forks at once, or none
+    Waiter,
+    /// Chandy and Misra's: forks get dirty when eaten with, and a dirty
+    /// fork goes to a neighbour that asks for it unless its holder is
+    /// eating. Forks start with the lower-numbered neighbour
+    ChandyMisra,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum State {
+    Thinking,
+    Hungry,
+    Eating,
+}
+
+/// The one thing a philosopher can do next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    GetHungry,
+    Take(usize),
+    TakeBoth,
+    Eat,
+    Finish,
+}
+
+/// Philosopher `p` sits between fork `p` on its left and fork `p + 1`
+#[derive(Debug, Clone)]
+pub(super) struct Table {
+    strategy: Strategy,
+    pub(super) states: Vec<State>,
+    holders: Vec<Option<usize>>,
+    dirty: Vec<bool>,
+}
+
+impl Table {
+    pub(super) fn new(philosophers: usize, strategy: Strategy) -> Table {
+        assert!(philosophers >= 2, "a table needs at least two philosophers");
+        let holders = match strategy {
+            Strategy::ChandyMisra => (0..philosophers)
+                .map(|fork| Some(fork.min((fork + philosophers - 1) % philosophers)))
+                .collect(),
+            _ => vec![None; philosophers],
+        };
+        Table {
+            strategy,
+            states: vec![State::Thinking; philosophers],
+            holders,
+            dirty: vec![true; philosophers],
+        }
+    }
+
+    fn forks(&self, p: usize) -> (usize, usize) {
+        (p, (p + 1) % self.states.len())
+    }
+
+    fn holds(&self, p: usize, fork: usize) -> bool {
+        self.holders[fork] == Some(p)
+    }
+
+    /// What philosopher `p` can do now, or None if it has to wait
+    fn action(&self, p: usize) -> Option<Action> {
+        let (left, right) = self.forks(p);
+        match self.states[p] {
+            State::Thinking => return Some(Action::GetHungry),
+            State::Eating => return Some(Action::Finish),
+            State::Hungry => {}
+        }
+        if self.holds(p, left) && self.holds(p, right) {
+            return Some(Action::Eat);
+        }
+        match self.strategy {
+            Strategy::Naive | Strategy::ResourceOrdering => {
+                let order = match self.strategy {
+                    Strategy::Naive => [left, right],
+                    _ => [left.min(right), left.max(right)],
+                };
+                let fork = order.into_iter().find(|&f| !self.holds(p, f))?;
+                self.holders[fork].is_none().then_some(Action::Take(fork))
+            }
+            Strategy::Waiter => (self.holders[left].is_none() && self.holders[right].is_none())
+                .then_some(Action::TakeBoth),
+            Strategy::ChandyMisra => [left, right]
+                .into_iter()
+                .find(|&f| match self.holders[f] {
+                    Some(q) => q != p && self.dirty[f] && self.states[q] != State::Eating,
+                    None => false,
+                })
+                .map(Action::Take),
+        }
+    }
+
+    pub(super) fn can_act(&self, p: usize) -> bool {
+        self.action(p).is_some()
+    }
+
+    /// Carries out the next action of `p`, returning whether it finished
+    /// a meal
+    pub(super) fn act(&mut self, p: usize) -> bool {
+        let (left, right) = self.forks(p);
+        match self.action(p).expect("the philosopher can act") {
+            Action::GetHungry => self.states[p] = State::Hungry,
+            Action::Take(fork) => {
+                // Under Chandy-Misra this is the request and the neighbour's
+                // cleaned reply in one step
+                self.holders[fork] = Some(p);
+                self.dirty[fork] = false;
+            }
+            Action::TakeBoth => {
+                self.holders[left] = Some(p);
+                self.holders[right] = Some(p);
+            }
+            Action::Eat => self.states[p] = State::Eating,
+            Action::Finish => {
+                self.states[p] = State::Thinking;
+                for fork in [left, right] {
+                    match self.strategy {
+                        Strategy::ChandyMisra => self.dirty[fork] = true,
+                        _ => self.holders[fork] = None,
+                    }
+                }
+                return true;
+            }
+        }
+        false
+    }
+}
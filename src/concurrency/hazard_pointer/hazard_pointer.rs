@@ -0,0 +1,369 @@
+use std::collections::HashSet;
+use std::ptr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+/// Retired objects a domain lets pile up before it tries to free them
+const RECLAIM_THRESHOLD: usize = 64;
+
+/// One published hazard: a pointer some thread may be reading through
+struct Slot {
+    hazard: AtomicPtr<()>,
+    active: AtomicBool,
+    next: *mut Slot,
+}
+
+/// An object waiting to be freed, with how to free it
+struct Retired {
+    ptr: *mut (),
+    free: unsafe fn(*mut ()),
+}
+
+// Only the domain touches these, under its lock
+unsafe impl Send for Retired {}
+
+unsafe fn free_box<T>(ptr: *mut ()) {
+    drop(unsafe { Box::from_raw(ptr as *mut T) });
+}
+
+/// Hazard-pointer memory reclamation for lock-free structures.
+///
+/// A thread about to read through a shared pointer first publishes it in
+/// a `HazardPointer`. A thread that unlinks an object retires it instead
+/// of freeing it, and the domain frees retired objects only once no
+/// hazard pointer holds them.
+pub struct HazardDomain {
+    /// A list of slots that only grows; freed slots are reused
+    slots: AtomicPtr<Slot>,
+    retired: Mutex<Vec<Retired>>,
+}
+
+// Slots are shared through atomics, retired objects behind the lock
+unsafe impl Send for HazardDomain {}
+unsafe impl Sync for HazardDomain {}
+
+impl HazardDomain {
+    pub const fn new() -> HazardDomain {
+        HazardDomain {
+            slots: AtomicPtr::new(ptr::null_mut()),
+            retired: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn slots(&self) -> impl Iterator<Item = &Slot> {
+        let mut slot = self.slots.load(Ordering::Acquire);
+        std::iter::from_fn(move || {
+            let current = unsafe { slot.as_ref()? };
+            slot = current.next;
+            Some(current)
+        })
+    }
+
+    /// Takes a free slot, or adds one
+    fn acquire(&self) -> &Slot {
+        for slot in self.slots() {
+            if !slot.active.load(Ordering::Relaxed)
+                && slot
+                    .active
+                    .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                return slot;
+            }
+        }
+        let slot = Box::into_raw(Box::new(Slot {
+            hazard: AtomicPtr::new(ptr::null_mut()),
+            active: AtomicBool::new(true),
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.slots.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*slot).next = head };
+            match self
+                .slots
+                .compare_exchange_weak(head, slot, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => return unsafe { &*slot },
+                Err(actual) => head = actual,
+            }
+        }
+    }
+
+    /// Hands over an object unlinked from a structure, to be freed as a
+    /// `Box<T>` once no hazard pointer protects it. Whichever thread calls
+    /// `reclaim` next may free it, at any later time, hence `Send + 'static`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from `Box::into_raw`, must no longer be reachable
+    /// from the structure, and must be retired only once
+    pub unsafe fn retire<T: Send + 'static>(&self, ptr: *mut T) {
+        let pending = {
+            let mut retired = self.retired.lock().unwrap();
+            retired.push(Retired {
+                ptr: ptr as *mut (),
+                free: free_box::<T>,
+            });
+            retired.len()
+        };
+        if pending >= RECLAIM_THRESHOLD {
+            self.reclaim();
+        }
+    }
+
+    /// Frees every retired object no hazard pointer protects, returning
+    /// how many were freed
+    pub fn reclaim(&self) -> usize {
+        let candidates = std::mem::take(&mut *self.retired.lock().unwrap());
+        let protected: HashSet<*mut ()> = self
+            .slots()
+            .map(|slot| slot.hazard.load(Ordering::SeqCst))
+            .filter(|hazard| !hazard.is_null())
+            .collect();
+        let (kept, free): (Vec<Retired>, Vec<Retired>) = candidates
+            .into_iter()
+            .partition(|object| protected.contains(&object.ptr));
+        self.retired.lock().unwrap().extend(kept);
+        // Destructors run without the lock held, since they may retire more
+        for object in &free {
+            unsafe { (object.free)(object.ptr) };
+        }
+        free.len()
+    }
+
+    /// How many retired objects are waiting to be freed
+    pub fn retired(&self) -> usize {
+        self.retired.lock().unwrap().len()
+    }
+}
+
+impl Default for HazardDomain {
+    fn default() -> HazardDomain {
+        HazardDomain::new()
+    }
+}
+
+impl Drop for HazardDomain {
+    fn drop(&mut self) {
+        // No hazard pointer can outlive the domain, so all can go
+        for object in self.retired.get_mut().unwrap().drain(..) {
+            unsafe { (object.free)(object.ptr) };
+        }
+        let mut slot = *self.slots.get_mut();
+        while !slot.is_null() {
+            let next = unsafe { (*slot).next };
+            drop(unsafe { Box::from_raw(slot) });
+            slot = next;
+        }
+    }
+}
+
+/// A slot in a domain through which a thread protects one pointer at a
+/// time. Dropping it gives the slot back
+pub struct HazardPointer<'a> {
+    slot: &'a Slot,
+}
+
+impl<'a> HazardPointer<'a> {
+    pub fn new(domain: &'a HazardDomain) -> HazardPointer<'a> {
+        HazardPointer {
+            slot: domain.acquire(),
+        }
+    }
+
+    /// Loads `source` and protects what it points to. The pointer is
+    /// published and then checked to still be in `source`, since it could
+    /// have been unlinked and retired before the hazard was visible
+    pub fn protect<T>(&mut self, source: &AtomicPtr<T>) -> *mut T {
+        let mut ptr = source.load(Ordering::Relaxed);
+        loop {
+            self.slot.hazard.store(ptr as *mut (), Ordering::SeqCst);
+            let current = source.load(Ordering::Acquire);
+            if current == ptr {
+                return ptr;
+            }
+            ptr = current;
+        }
+    }
+
+    /// Stops protecting the pointer
+    pub fn reset(&mut self) {
+        self.slot.hazard.store(ptr::null_mut(), Ordering::Release);
+    }
+}
+
+impl Drop for HazardPointer<'_> {
+    fn drop(&mut self) {
+        self.reset();
+        self.slot.active.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+    use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+    use std::thread;
+
+    use crate::concurrency::{HazardDomain, HazardPointer};
+
+    /// Counts its drops
+    struct Probe(&'static AtomicUsize);
+
+    impl Drop for Probe {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn protected_objects_outlive_retirement() {
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+        let drops = &DROPS;
+        let domain = HazardDomain::new();
+        let shared = AtomicPtr::new(Box::into_raw(Box::new(Probe(drops))));
+        let mut hazard = HazardPointer::new(&domain);
+        let protected = hazard.protect(&shared);
+        // Another thread unlinks and retires it while it is protected
+        let unlinked = shared.swap(ptr::null_mut(), Ordering::AcqRel);
+        unsafe { domain.retire(unlinked) };
+        assert_eq!(domain.reclaim(), 0);
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+        assert!(ptr::eq(unsafe { (*protected).0 }, drops));
+        hazard.reset();
+        assert_eq!(domain.reclaim(), 1);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        assert_eq!(domain.retired(), 0);
+    }
+
+    #[test]
+    fn destructors_may_retire() {
+        static DOMAIN: HazardDomain = HazardDomain::new();
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        /// Retires the rest of its chain when dropped, as a node owning
+        /// other shared nodes would
+        struct Link(*mut Link);
+
+        unsafe impl Send for Link {}
+
+        impl Drop for Link {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+                if !self.0.is_null() {
+                    unsafe { DOMAIN.retire(self.0) };
+                }
+            }
+        }
+
+        let tail = Box::into_raw(Box::new(Link(ptr::null_mut())));
+        let head = Box::into_raw(Box::new(Link(tail)));
+        unsafe { DOMAIN.retire(head) };
+        assert_eq!(DOMAIN.reclaim(), 1);
+        assert_eq!(DOMAIN.retired(), 1);
+        assert_eq!(DOMAIN.reclaim(), 1);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn slots_are_reused() {
+        let domain = HazardDomain::new();
+        drop(HazardPointer::new(&domain));
+        let a = HazardPointer::new(&domain);
+        let b = HazardPointer::new(&domain);
+        assert_eq!(domain.slots().count(), 2);
+        assert!(!ptr::eq(a.slot, b.slot));
+    }
+
+    struct Node {
+        value: usize,
+        next: *mut Node,
+    }
+
+    // Nodes are only reached through the stack's atomics
+    unsafe impl Send for Node {}
+
+    /// A Treiber stack that retires popped nodes through the domain
+    struct Stack {
+        head: AtomicPtr<Node>,
+    }
+
+    unsafe impl Sync for Stack {}
+
+    impl Stack {
+        fn push(&self, value: usize) {
+            let node = Box::into_raw(Box::new(Node {
+                value,
+                next: ptr::null_mut(),
+            }));
+            let mut head = self.head.load(Ordering::Relaxed);
+            loop {
+                unsafe { (*node).next = head };
+                match self.head.compare_exchange_weak(
+                    head,
+                    node,
+                    Ordering::Release,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(actual) => head = actual,
+                }
+            }
+        }
+
+        fn pop(&self, domain: &HazardDomain) -> Option<usize> {
+            let mut hazard = HazardPointer::new(domain);
+            loop {
+                let head = hazard.protect(&self.head);
+                if head.is_null() {
+                    return None;
+                }
+                // Safe to read: the node cannot be freed while protected
+                let next = unsafe { (*head).next };
+                if self
+                    .head
+                    .compare_exchange(head, next, Ordering::AcqRel, Ordering::Relaxed)
+                    .is_ok()
+                {
+                    let value = unsafe { (*head).value };
+                    hazard.reset();
+                    unsafe { domain.retire(head) };
+                    return Some(value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lock_free_stack() {
+        let domain = HazardDomain::new();
+        let stack = Stack {
+            head: AtomicPtr::new(ptr::null_mut()),
+        };
+        let popped = AtomicUsize::new(0);
+        let sum = AtomicUsize::new(0);
+        thread::scope(|s| {
+            for t in 0..4 {
+                let (stack, domain, popped, sum) = (&stack, &domain, &popped, &sum);
+                s.spawn(move || {
+                    for i in 0..2000 {
+                        stack.push(t * 2000 + i);
+                        if let Some(value) = stack.pop(domain) {
+                            popped.fetch_add(1, Ordering::SeqCst);
+                            sum.fetch_add(value, Ordering::SeqCst);
+                        }
+                    }
+                });
+            }
+        });
+        while let Some(value) = stack.pop(&domain) {
+            popped.fetch_add(1, Ordering::SeqCst);
+            sum.fetch_add(value, Ordering::SeqCst);
+        }
+        // Every value comes out exactly once
+        assert_eq!(popped.load(Ordering::SeqCst), 8000);
+        assert_eq!(sum.load(Ordering::SeqCst), 7999 * 8000 / 2);
+        domain.reclaim();
+        assert_eq!(domain.retired(), 0);
+    }
+}
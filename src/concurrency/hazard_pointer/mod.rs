@@ -0,0 +1,3 @@
+mod hazard_pointer;
+
+pub use self::hazard_pointer::{HazardDomain, HazardPointer};
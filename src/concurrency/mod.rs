@@ -0,0 +1,26 @@
+mod arc;
+mod blocking_queue;
+mod bounded_buffer;
+mod channel;
+// The simulation draws its schedule from `algorithm::random`
+#[cfg(feature = "algorithm")]
+pub mod dining_philosophers;
+mod hazard_pointer;
+mod rw_lock;
+mod semaphore;
+mod spin_lock;
+mod thread_pool;
+mod ticket_lock;
+
+pub use self::arc::{MyArc, Weak};
+pub use self::blocking_queue::BlockingQueue;
+pub use self::bounded_buffer::BoundedBuffer;
+pub use self::channel::{
+    Iter, Receiver, RecvError, SendError, Sender, TryRecvError, channel, sync_channel,
+};
+pub use self::hazard_pointer::{HazardDomain, HazardPointer};
+pub use self::rw_lock::{Preference, RwLock, RwLockReadGuard, RwLockWriteGuard};
+pub use self::semaphore::{Semaphore, SemaphorePermit};
+pub use self::spin_lock::{SpinLock, SpinLockGuard};
+pub use self::thread_pool::{Scope, ThreadPool};
+pub use self::ticket_lock::{TicketLock, TicketLockGuard};
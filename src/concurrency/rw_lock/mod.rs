@@ -0,0 +1,3 @@
+mod rw_lock;
+
+pub use self::rw_lock::{Preference, RwLock, RwLockReadGuard, RwLockWriteGuard};
@@ -0,0 +1,246 @@
+use std::cell::UnsafeCell;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Condvar, Mutex};
+
+/// Who goes first when readers and writers both want the lock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preference {
+    /// New readers join current ones even while a writer waits, so a
+    /// steady stream of readers can starve writers
+    Readers,
+    /// New readers wait behind any waiting writer
+    Writers,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    readers: usize,
+    writing: bool,
+    waiting_writers: usize,
+}
+
+/// A readers-writer lock built from a mutex and two condition variables:
+/// any number of readers or a single writer at a time
+#[derive(Debug)]
+pub struct RwLock<T> {
+    preference: Preference,
+    state: Mutex<State>,
+    can_read: Condvar,
+    can_write: Condvar,
+    value: UnsafeCell<T>,
+}
+
+// The state only hands out shared access to many threads or exclusive
+// access to one, like `std::sync::RwLock`
+unsafe impl<T: Send> Send for RwLock<T> {}
+unsafe impl<T: Send + Sync> Sync for RwLock<T> {}
+
+impl<T> RwLock<T> {
+    pub fn new(value: T, preference: Preference) -> RwLock<T> {
+        RwLock {
+            preference,
+            state: Mutex::new(State::default()),
+            can_read: Condvar::new(),
+            can_write: Condvar::new(),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    fn reader_blocked(&self, state: &State) -> bool {
+        state.writing || (self.preference == Preference::Writers && state.waiting_writers > 0)
+    }
+
+    /// Takes shared access, waiting while it is not allowed
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        let mut state = self.state.lock().unwrap();
+        while self.reader_blocked(&state) {
+            state = self.can_read.wait(state).unwrap();
+        }
+        state.readers += 1;
+        RwLockReadGuard { lock: self }
+    }
+
+    /// Takes shared access if that is allowed right now
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        let mut state = self.state.lock().unwrap();
+        if self.reader_blocked(&state) {
+            return None;
+        }
+        state.readers += 1;
+        Some(RwLockReadGuard { lock: self })
+    }
+
+    /// Takes exclusive access, waiting for current readers and writer
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        let mut state = self.state.lock().unwrap();
+        state.waiting_writers += 1;
+        while state.writing || state.readers > 0 {
+            state = self.can_write.wait(state).unwrap();
+        }
+        state.waiting_writers -= 1;
+        state.writing = true;
+        RwLockWriteGuard { lock: self }
+    }
+
+    /// Takes exclusive access if no one else holds the lock
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        let mut state = self.state.lock().unwrap();
+        if state.writing || state.readers > 0 {
+            return None;
+        }
+        state.writing = true;
+        Some(RwLockWriteGuard { lock: self })
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Wakes whoever may be able to go now. Waiters check again for
+    /// themselves, so waking too many is harmless
+    fn wake(&self, state: &State) {
+        if state.readers == 0 {
+            self.can_write.notify_one();
+        }
+        if !self.reader_blocked(state) {
+            self.can_read.notify_all();
+        }
+    }
+}
+
+/// Shared access to the value, given up on drop
+#[derive(Debug)]
+pub struct RwLockReadGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+impl<T> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut state = self.lock.state.lock().unwrap();
+        state.readers -= 1;
+        self.lock.wake(&state);
+    }
+}
+
+/// Exclusive access to the value, given up on drop
+#[derive(Debug)]
+pub struct RwLockWriteGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+impl<T> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut state = self.lock.state.lock().unwrap();
+        state.writing = false;
+        self.lock.wake(&state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+
+    use crate::concurrency::{Preference, RwLock};
+
+    fn wait_for_writer<T>(lock: &RwLock<T>) {
+        while lock.state.lock().unwrap().waiting_writers == 0 {
+            thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn readers_share_and_writers_exclude() {
+        for preference in [Preference::Readers, Preference::Writers] {
+            let lock = RwLock::new(0, preference);
+            {
+                let a = lock.read();
+                let b = lock.read();
+                assert_eq!(*a + *b, 0);
+                assert!(lock.try_write().is_none());
+            }
+            let mut writer = lock.write();
+            *writer += 1;
+            assert!(lock.try_read().is_none());
+            drop(writer);
+            assert_eq!(*lock.read(), 1);
+        }
+    }
+
+    #[test]
+    fn concurrent_increments() {
+        let lock = RwLock::new(0, Preference::Writers);
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..1000 {
+                        *lock.write() += 1;
+                        let seen = *lock.read();
+                        assert!(seen > 0 && seen <= 4000);
+                    }
+                });
+            }
+        });
+        assert_eq!(lock.into_inner(), 4000);
+    }
+
+    #[test]
+    fn reader_preference_starves_writers() {
+        let lock = RwLock::new(0, Preference::Readers);
+        let written = AtomicBool::new(false);
+        thread::scope(|s| {
+            let mut held = lock.read();
+            s.spawn(|| {
+                *lock.write() = 1;
+                written.store(true, Ordering::SeqCst);
+            });
+            wait_for_writer(&lock);
+            // Readers overlap, each arriving before the last one leaves, so
+            // there is never a moment with no reader for the writer to use
+            for _ in 0..1000 {
+                let next = lock.try_read().expect("readers go ahead of the writer");
+                drop(held);
+                held = next;
+                assert!(!written.load(Ordering::SeqCst));
+            }
+            drop(held);
+        });
+        assert!(written.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn writer_preference_holds_back_new_readers() {
+        let lock = RwLock::new(0, Preference::Writers);
+        thread::scope(|s| {
+            let held = lock.read();
+            s.spawn(|| *lock.write() = 1);
+            wait_for_writer(&lock);
+            assert!(lock.try_read().is_none());
+            drop(held);
+            // The next reader gets in after the writer
+            assert_eq!(*lock.read(), 1);
+        });
+    }
+}
@@ -0,0 +1,3 @@
+mod semaphore;
+
+pub use self::semaphore::{Semaphore, SemaphorePermit};
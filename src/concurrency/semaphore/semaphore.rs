@@ -0,0 +1,155 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+
+/// A counting semaphore: `acquire` takes a permit, waiting until one is
+/// free, and `release` gives one back.
+///
+/// Permits are an atomic count, so taking a free one or giving one back
+/// while nobody waits never touches the mutex. Only a thread that finds
+/// none left sleeps on the condition variable.
+#[derive(Debug, Default)]
+pub struct Semaphore {
+    permits: AtomicUsize,
+    waiters: AtomicUsize,
+    lock: Mutex<()>,
+    released: Condvar,
+}
+
+impl Semaphore {
+    /// Creates a semaphore with `permits` permits free
+    pub fn new(permits: usize) -> Semaphore {
+        Semaphore {
+            permits: AtomicUsize::new(permits),
+            waiters: AtomicUsize::new(0),
+            lock: Mutex::new(()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Takes a permit if one is free, without waiting
+    pub fn try_acquire(&self) -> bool {
+        self.permits
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    /// Takes a permit, blocking while there are none
+    pub fn acquire(&self) {
+        if self.try_acquire() {
+            return;
+        }
+        let mut lock = self.lock.lock().unwrap();
+        // Announced before trying again, so a release either leaves a
+        // permit for the retry or sees the waiter and wakes it
+        self.waiters.fetch_add(1, Ordering::SeqCst);
+        while !self.try_acquire() {
+            lock = self.released.wait(lock).unwrap();
+        }
+        self.waiters.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Gives a permit back, waking one waiter if there are any
+    pub fn release(&self) {
+        self.permits.fetch_add(1, Ordering::SeqCst);
+        if self.waiters.load(Ordering::SeqCst) > 0 {
+            let _lock = self.lock.lock().unwrap();
+            self.released.notify_one();
+        }
+    }
+
+    /// Takes a permit that is given back when the guard drops
+    pub fn permit(&self) -> SemaphorePermit<'_> {
+        self.acquire();
+        SemaphorePermit { semaphore: self }
+    }
+
+    /// `permit` if one is free right now
+    pub fn try_permit(&self) -> Option<SemaphorePermit<'_>> {
+        self.try_acquire()
+            .then(|| SemaphorePermit { semaphore: self })
+    }
+
+    /// How many permits are free right now
+    pub fn available_permits(&self) -> usize {
+        self.permits.load(Ordering::SeqCst)
+    }
+}
+
+/// A permit of a `Semaphore`, released on drop
+#[derive(Debug)]
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl SemaphorePermit<'_> {
+    /// Keeps the permit taken for good instead of releasing it
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use crate::concurrency::Semaphore;
+
+    #[test]
+    fn try_acquire_and_permits() {
+        let semaphore = Semaphore::new(2);
+        assert!(semaphore.try_acquire());
+        let permit = semaphore.try_permit().unwrap();
+        assert_eq!(semaphore.available_permits(), 0);
+        assert!(!semaphore.try_acquire());
+        assert!(semaphore.try_permit().is_none());
+        drop(permit);
+        assert_eq!(semaphore.available_permits(), 1);
+        semaphore.permit().forget();
+        assert_eq!(semaphore.available_permits(), 0);
+        semaphore.release();
+        assert_eq!(semaphore.available_permits(), 1);
+    }
+
+    #[test]
+    fn release_wakes_a_waiter() {
+        let semaphore = Semaphore::new(0);
+        thread::scope(|s| {
+            let waiter = s.spawn(|| semaphore.acquire());
+            while semaphore.waiters.load(Ordering::SeqCst) == 0 {
+                thread::yield_now();
+            }
+            semaphore.release();
+            waiter.join().unwrap();
+        });
+        assert_eq!(semaphore.available_permits(), 0);
+    }
+
+    #[test]
+    fn limits_concurrent_holders() {
+        let semaphore = Semaphore::new(3);
+        let inside = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+        thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    for _ in 0..200 {
+                        let _permit = semaphore.permit();
+                        let now = inside.fetch_add(1, Ordering::SeqCst) + 1;
+                        most.fetch_max(now, Ordering::SeqCst);
+                        thread::yield_now();
+                        inside.fetch_sub(1, Ordering::SeqCst);
+                    }
+                });
+            }
+        });
+        assert!(most.load(Ordering::SeqCst) <= 3);
+        assert_eq!(semaphore.available_permits(), 3);
+    }
+}
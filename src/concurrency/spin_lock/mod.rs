@@ -0,0 +1,3 @@
+mod spin_lock;
+
+pub use self::spin_lock::{SpinLock, SpinLockGuard};
@@ -0,0 +1,145 @@
+use std::cell::UnsafeCell;
+use std::hint;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+/// Spins past this many hints between tries before yielding the thread
+const MAX_BACKOFF: u32 = 1 << 10;
+
+/// A lock that busy-waits on an atomic flag instead of sleeping.
+///
+/// Waiters test the flag with plain loads and only try to take it once it
+/// looks free, and back off exponentially between tries, so contended
+/// threads do not all hammer the same cache line. It makes no promise of
+/// fairness; see `TicketLock` for that.
+#[derive(Debug, Default)]
+pub struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// The flag gives one thread at a time access, like `std::sync::Mutex`
+unsafe impl<T: Send> Send for SpinLock<T> {}
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    pub const fn new(value: T) -> SpinLock<T> {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Takes the lock, spinning until it is free
+    pub fn lock(&self) -> SpinLockGuard<'_, T> {
+        let mut backoff = 1;
+        loop {
+            if let Some(guard) = self.try_lock() {
+                return guard;
+            }
+            while self.locked.load(Ordering::Relaxed) {
+                if backoff < MAX_BACKOFF {
+                    (0..backoff).for_each(|_| hint::spin_loop());
+                    backoff *= 2;
+                } else {
+                    thread::yield_now();
+                }
+            }
+        }
+    }
+
+    /// Takes the lock if it is free right now
+    pub fn try_lock(&self) -> Option<SpinLockGuard<'_, T>> {
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| SpinLockGuard { lock: self })
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Relaxed)
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+/// Access to the value of a `SpinLock`, unlocking it on drop
+///
+/// A guard can only be shared between threads when `T` is `Sync`:
+///
+/// ```compile_fail
+/// use std::cell::Cell;
+/// use rs_computer_science::concurrency::SpinLock;
+///
+/// fn assert_sync<T: Sync>(_: &T) {}
+/// let lock = SpinLock::new(Cell::new(0));
+/// assert_sync(&lock.lock());
+/// ```
+#[derive(Debug)]
+pub struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+// Sharing the guard shares `&T`, so it is only `Sync` when `T` is, as with
+// `MutexGuard`. The auto impl would only ask for `T: Send`
+unsafe impl<T: Sync> Sync for SpinLockGuard<'_, T> {}
+
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::concurrency::SpinLock;
+
+    #[test]
+    fn excludes_other_holders() {
+        let lock = SpinLock::new(vec![1]);
+        let mut guard = lock.lock();
+        guard.push(2);
+        assert!(lock.is_locked());
+        assert!(lock.try_lock().is_none());
+        drop(guard);
+        assert_eq!(*lock.try_lock().unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn concurrent_increments() {
+        let lock = SpinLock::new(0u64);
+        thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    for _ in 0..10_000 {
+                        *lock.lock() += 1;
+                    }
+                });
+            }
+        });
+        assert_eq!(lock.into_inner(), 80_000);
+    }
+}
@@ -0,0 +1,5 @@
+mod scope;
+mod thread_pool;
+
+pub use self::scope::Scope;
+pub use self::thread_pool::ThreadPool;
@@ -0,0 +1,96 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use super::thread_pool::{Job, Shared};
+
+/// Tasks still running in a scope
+#[derive(Debug, Default)]
+struct Pending {
+    count: Mutex<usize>,
+    done: Condvar,
+    panicked: AtomicBool,
+}
+
+/// Lets tasks on a pool borrow anything that outlives the scope, like
+/// `std::thread::scope` does for threads.
+///
+/// The scope waits for its tasks before returning. While it waits the
+/// thread runs queued tasks itself, so a task on the pool can open a scope
+/// of its own without tying up its worker for nothing.
+pub struct Scope<'scope, 'env: 'scope> {
+    shared: &'scope Shared,
+    pending: Arc<Pending>,
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    pub(super) fn run<F, R>(shared: &Shared, f: F) -> R
+    where
+        F: for<'s> FnOnce(&'s Scope<'s, 'env>) -> R,
+    {
+        let scope = Scope {
+            shared,
+            pending: Arc::new(Pending::default()),
+            scope: PhantomData,
+            env: PhantomData,
+        };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+        scope.wait();
+        match result {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(_) if scope.pending.panicked.load(Ordering::SeqCst) => {
+                panic!("a scoped task panicked")
+            }
+            Ok(result) => result,
+        }
+    }
+
+    /// Queues a closure that may borrow from outside the scope
+    pub fn execute<F: FnOnce() + Send + 'scope>(&self, f: F) {
+        *self.pending.count.lock().unwrap() += 1;
+        let pending = Arc::clone(&self.pending);
+        let job: Box<dyn FnOnce() + Send + 'scope> = Box::new(move || {
+            if panic::catch_unwind(AssertUnwindSafe(f)).is_err() {
+                pending.panicked.store(true, Ordering::SeqCst);
+            }
+            *pending.count.lock().unwrap() -= 1;
+            pending.done.notify_all();
+        });
+        // Safety: `run` does not return until the count is back to zero,
+        // so the job is done with its borrows before they end
+        let job: Job = unsafe { mem::transmute(job) };
+        if self.shared.jobs.push(job).is_err() {
+            unreachable!("the queue is only closed on shutdown");
+        }
+    }
+
+    /// Runs queued tasks until this scope's are all done, napping briefly
+    /// when there are none to run
+    fn wait(&self) {
+        loop {
+            let count = self.pending.count.lock().unwrap();
+            if *count == 0 {
+                return;
+            }
+            drop(count);
+            match self.shared.jobs.try_pop() {
+                Some(job) => self.shared.run(job),
+                None => {
+                    let count = self.pending.count.lock().unwrap();
+                    if *count > 0 {
+                        let _ = self
+                            .pending
+                            .done
+                            .wait_timeout(count, Duration::from_millis(1))
+                            .unwrap();
+                    }
+                }
+            }
+        }
+    }
+}
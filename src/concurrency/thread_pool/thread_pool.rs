@@ -0,0 +1,179 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::{self, JoinHandle};
+
+use super::Scope;
+use crate::concurrency::BlockingQueue;
+
+pub(super) type Job = Box<dyn FnOnce() + Send + 'static>;
+
+#[derive(Default)]
+pub(super) struct Shared {
+    pub(super) jobs: BlockingQueue<Job>,
+    panicked: AtomicUsize,
+}
+
+impl Shared {
+    /// Runs a job, counting rather than spreading a panic
+    pub(super) fn run(&self, job: Job) {
+        if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+            self.panicked.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// A fixed set of worker threads taking closures off a shared queue.
+///
+/// A panicking task is caught and counted, and its worker goes on to the
+/// next one. Joining, or dropping, the pool lets the workers finish every
+/// queued task before they exit.
+pub struct ThreadPool {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPool {
+    /// Starts `workers` worker threads
+    pub fn new(workers: usize) -> ThreadPool {
+        assert!(workers > 0, "a pool needs at least one worker");
+        let shared = Arc::new(Shared::default());
+        let workers = (0..workers)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    while let Some(job) = shared.jobs.pop() {
+                        shared.run(job);
+                    }
+                })
+            })
+            .collect();
+        ThreadPool { shared, workers }
+    }
+
+    /// Queues a closure for some worker to run
+    pub fn execute<F: FnOnce() + Send + 'static>(&self, f: F) {
+        if self.shared.jobs.push(Box::new(f)).is_err() {
+            unreachable!("the queue is only closed on shutdown");
+        }
+    }
+
+    /// Runs `f` with a scope whose tasks may borrow from outside it, and
+    /// waits for all of them before returning. See `Scope`
+    pub fn scope<'env, F, R>(&self, f: F) -> R
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> R,
+    {
+        Scope::run(&self.shared, f)
+    }
+
+    pub fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// How many tasks have panicked so far
+    pub fn panicked(&self) -> usize {
+        self.shared.panicked.load(Ordering::SeqCst)
+    }
+
+    /// Finishes every queued task and stops the workers, returning how
+    /// many tasks panicked in all
+    pub fn join(mut self) -> usize {
+        self.shutdown();
+        self.panicked()
+    }
+
+    fn shutdown(&mut self) {
+        self.shared.jobs.close();
+        for worker in self.workers.drain(..) {
+            worker.join().expect("workers catch task panics");
+        }
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::concurrency::ThreadPool;
+
+    #[test]
+    fn runs_every_task_before_join_returns() {
+        let pool = ThreadPool::new(4);
+        let count = Arc::new(AtomicUsize::new(0));
+        for i in 0..1000 {
+            let count = Arc::clone(&count);
+            pool.execute(move || {
+                count.fetch_add(i, Ordering::SeqCst);
+            });
+        }
+        assert_eq!(pool.join(), 0);
+        assert_eq!(count.load(Ordering::SeqCst), 999 * 1000 / 2);
+    }
+
+    #[test]
+    fn panics_stay_in_their_task() {
+        let pool = ThreadPool::new(2);
+        let count = Arc::new(AtomicUsize::new(0));
+        for i in 0..20 {
+            let count = Arc::clone(&count);
+            pool.execute(move || {
+                if i % 5 == 0 {
+                    panic!("task {i} fails");
+                }
+                count.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        // Both workers survive the four panics and run the rest
+        assert_eq!(pool.join(), 4);
+        assert_eq!(count.load(Ordering::SeqCst), 16);
+    }
+
+    #[test]
+    fn scoped_tasks_borrow() {
+        let pool = ThreadPool::new(3);
+        let mut data: Vec<u64> = (0..1000).collect();
+        pool.scope(|s| {
+            for chunk in data.chunks_mut(100) {
+                s.execute(move || chunk.iter_mut().for_each(|x| *x *= 2));
+            }
+        });
+        assert_eq!(data.iter().sum::<u64>(), 999 * 1000);
+    }
+
+    #[test]
+    fn nested_scopes_on_one_worker() {
+        // The only worker waits on an inner scope, so the waiting thread
+        // has to run the inner tasks itself
+        let pool = ThreadPool::new(1);
+        let count = AtomicUsize::new(0);
+        pool.scope(|s| {
+            for _ in 0..4 {
+                s.execute(|| {
+                    pool.scope(|inner| {
+                        for _ in 0..4 {
+                            inner.execute(|| {
+                                count.fetch_add(1, Ordering::SeqCst);
+                            });
+                        }
+                    });
+                });
+            }
+        });
+        assert_eq!(count.load(Ordering::SeqCst), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "a scoped task panicked")]
+    fn scope_passes_on_task_panics() {
+        let pool = ThreadPool::new(2);
+        pool.scope(|s| s.execute(|| panic!("inner")));
+    }
+}
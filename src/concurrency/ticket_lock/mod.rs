@@ -0,0 +1,3 @@
+mod ticket_lock;
+
+pub use self::ticket_lock::{TicketLock, TicketLockGuard};
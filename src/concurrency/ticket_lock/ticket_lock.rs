@@ -0,0 +1,159 @@
+use std::cell::UnsafeCell;
+use std::hint;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Spins this many times waiting for a turn before yielding the thread
+const MAX_SPINS: u32 = 100;
+
+/// A fair spin lock: each thread draws a ticket and waits until it is
+/// served, so the lock goes to threads in the order they asked for it
+#[derive(Debug, Default)]
+pub struct TicketLock<T> {
+    next_ticket: AtomicUsize,
+    now_serving: AtomicUsize,
+    value: UnsafeCell<T>,
+}
+
+// Only the thread whose ticket is served has access
+unsafe impl<T: Send> Send for TicketLock<T> {}
+unsafe impl<T: Send> Sync for TicketLock<T> {}
+
+impl<T> TicketLock<T> {
+    pub const fn new(value: T) -> TicketLock<T> {
+        TicketLock {
+            next_ticket: AtomicUsize::new(0),
+            now_serving: AtomicUsize::new(0),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Takes the lock once every thread that asked earlier has had it
+    pub fn lock(&self) -> TicketLockGuard<'_, T> {
+        let ticket = self.next_ticket.fetch_add(1, Ordering::Relaxed);
+        let mut spins = 0;
+        while self.now_serving.load(Ordering::Acquire) != ticket {
+            // Give the CPU up after a short spin, in case the holder or
+            // the threads ahead are waiting for it
+            if spins < MAX_SPINS {
+                hint::spin_loop();
+                spins += 1;
+            } else {
+                thread::yield_now();
+            }
+        }
+        TicketLockGuard { lock: self }
+    }
+
+    /// Takes the lock if it is free and nobody is waiting for it
+    pub fn try_lock(&self) -> Option<TicketLockGuard<'_, T>> {
+        let serving = self.now_serving.load(Ordering::Relaxed);
+        self.next_ticket
+            .compare_exchange(
+                serving,
+                serving.wrapping_add(1),
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .ok()
+            .map(|_| TicketLockGuard { lock: self })
+    }
+
+    /// How many threads hold or wait for the lock
+    pub fn queue_len(&self) -> usize {
+        let next = self.next_ticket.load(Ordering::Relaxed);
+        next.wrapping_sub(self.now_serving.load(Ordering::Relaxed))
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+/// Access to the value of a `TicketLock`, serving the next ticket on drop
+///
+/// A guard can only be shared between threads when `T` is `Sync`:
+///
+/// ```compile_fail
+/// use std::cell::Cell;
+/// use rs_computer_science::concurrency::TicketLock;
+///
+/// fn assert_sync<T: Sync>(_: &T) {}
+/// let lock = TicketLock::new(Cell::new(0));
+/// assert_sync(&lock.lock());
+/// ```
+#[derive(Debug)]
+pub struct TicketLockGuard<'a, T> {
+    lock: &'a TicketLock<T>,
+}
+
+// Sharing the guard shares `&T`, so it is only `Sync` when `T` is, as with
+// `MutexGuard`. The auto impl would only ask for `T: Send`
+unsafe impl<T: Sync> Sync for TicketLockGuard<'_, T> {}
+
+impl<T> Deref for TicketLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for TicketLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for TicketLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.now_serving.fetch_add(1, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::concurrency::TicketLock;
+
+    #[test]
+    fn serves_in_ticket_order() {
+        let lock = TicketLock::new(Vec::new());
+        thread::scope(|s| {
+            let held = lock.lock();
+            // Start each thread only once the one before holds a ticket
+            for id in 0..8 {
+                let lock = &lock;
+                s.spawn(move || lock.lock().push(id));
+                while lock.queue_len() < id + 2 {
+                    thread::yield_now();
+                }
+            }
+            assert!(lock.try_lock().is_none());
+            drop(held);
+        });
+        assert_eq!(lock.into_inner(), (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn concurrent_increments() {
+        let lock = TicketLock::new(0u64);
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..5_000 {
+                        *lock.lock() += 1;
+                    }
+                });
+            }
+        });
+        assert_eq!(lock.queue_len(), 0);
+        assert_eq!(*lock.try_lock().unwrap(), 20_000);
+    }
+}
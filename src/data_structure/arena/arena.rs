@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+
+const FIRST_CHUNK: usize = 16;
+
+/// A typed bump allocator: values are moved into chunks that never
+/// reallocate, so each `alloc` hands back a reference that lives as long as
+/// the arena. Nothing is freed until the whole arena is dropped, which makes
+/// it a cheap home for the nodes of graphs and trees, cycles included.
+pub struct Arena<T> {
+    chunks: RefCell<Vec<Vec<T>>>,
+}
+
+impl<T> Arena<T> {
+    /// Creates an empty arena
+    pub fn new() -> Arena<T> {
+        Arena::with_capacity(FIRST_CHUNK)
+    }
+
+    /// Creates an arena whose first chunk holds `capacity` values
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena {
+            chunks: RefCell::new(vec![Vec::with_capacity(capacity.max(1))]),
+        }
+    }
+
+    /// Moves `value` into the arena
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: T) -> &mut T {
+        let mut chunks = self.chunks.borrow_mut();
+        let last = chunks.last().expect("an arena always has a chunk");
+        if last.len() == last.capacity() {
+            // Doubling keeps the number of chunks logarithmic in the count
+            let capacity = last.capacity() * 2;
+            chunks.push(Vec::with_capacity(capacity));
+        }
+        let chunk = chunks.last_mut().expect("an arena always has a chunk");
+        chunk.push(value);
+        // The push stayed within capacity, so the chunk's buffer never moves
+        // and no other reference points at this slot. `as_mut_ptr` avoids
+        // reborrowing the whole slice, which would invalidate earlier slots.
+        unsafe { &mut *chunk.as_mut_ptr().add(chunk.len() - 1) }
+    }
+
+    /// Returns the number of values allocated
+    pub fn len(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::len).sum()
+    }
+
+    /// Checks if nothing has been allocated
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Consumes the arena, returning the values in allocation order
+    pub fn into_vec(self) -> Vec<T> {
+        self.chunks.into_inner().into_iter().flatten().collect()
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Arena<T> {
+        Arena::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::Arena;
+
+    #[test]
+    fn references_survive_later_allocations() {
+        let arena = Arena::with_capacity(1);
+        let first = arena.alloc(0);
+        let refs: Vec<&mut i32> = (1..1000).map(|i| arena.alloc(i)).collect();
+        *first += 7;
+        assert_eq!(*first, 7);
+        assert!(refs.iter().enumerate().all(|(i, r)| **r == i as i32 + 1));
+        assert_eq!(arena.len(), 1000);
+        assert_eq!(arena.into_vec()[..3], [7, 1, 2]);
+    }
+
+    struct Node<'a> {
+        value: u32,
+        next: Cell<Option<&'a Node<'a>>>,
+    }
+
+    #[test]
+    fn holds_cyclic_structures() {
+        let arena = Arena::new();
+        let a = arena.alloc(Node {
+            value: 1,
+            next: Cell::new(None),
+        });
+        let b = arena.alloc(Node {
+            value: 2,
+            next: Cell::new(Some(a)),
+        });
+        a.next.set(Some(b));
+        let mut cursor: &Node = a;
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            seen.push(cursor.value);
+            cursor = cursor.next.get().unwrap();
+        }
+        assert_eq!(seen, [1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn drops_every_value_once() {
+        let counter = Rc::new(());
+        let arena = Arena::with_capacity(3);
+        for _ in 0..50 {
+            arena.alloc(Rc::clone(&counter));
+        }
+        assert_eq!(Rc::strong_count(&counter), 51);
+        drop(arena);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+}
@@ -0,0 +1,5 @@
+mod arena;
+mod slab;
+
+pub use self::arena::Arena;
+pub use self::slab::{Slab, SlabKey};
@@ -0,0 +1,223 @@
+/// A handle to a value in a `Slab`. It remembers the generation of its slot,
+/// so once the value is removed the key stops working even if the slot is
+/// reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlabKey {
+    index: usize,
+    generation: u32,
+}
+
+impl SlabKey {
+    /// The slot this key points at
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Entry<T> {
+    Occupied {
+        value: T,
+        generation: u32,
+    },
+    Vacant {
+        next_free: Option<usize>,
+        generation: u32,
+    },
+}
+
+/// Values stored in a `Vec` of slots and addressed by `SlabKey`. Removed
+/// slots are chained into a free list and reused, so inserts and removes
+/// are O(1) and there is no per-value allocation.
+#[derive(Debug, Clone)]
+pub struct Slab<T> {
+    entries: Vec<Entry<T>>,
+    free: Option<usize>,
+    len: usize,
+}
+
+impl<T> Slab<T> {
+    /// Creates an empty slab
+    pub fn new() -> Slab<T> {
+        Slab::with_capacity(0)
+    }
+
+    /// Creates an empty slab with room for `capacity` values
+    pub fn with_capacity(capacity: usize) -> Slab<T> {
+        Slab {
+            entries: Vec::with_capacity(capacity),
+            free: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the slab holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Stores `value`, reusing a freed slot if there is one
+    pub fn insert(&mut self, value: T) -> SlabKey {
+        self.len += 1;
+        match self.free {
+            Some(index) => {
+                let Entry::Vacant {
+                    next_free,
+                    generation,
+                } = self.entries[index]
+                else {
+                    unreachable!("the free list only links vacant slots");
+                };
+                self.free = next_free;
+                self.entries[index] = Entry::Occupied { value, generation };
+                SlabKey { index, generation }
+            }
+            None => {
+                self.entries.push(Entry::Occupied {
+                    value,
+                    generation: 0,
+                });
+                SlabKey {
+                    index: self.entries.len() - 1,
+                    generation: 0,
+                }
+            }
+        }
+    }
+
+    /// Returns the value for `key`, or None if it has been removed
+    pub fn get(&self, key: SlabKey) -> Option<&T> {
+        match self.entries.get(key.index)? {
+            Entry::Occupied { value, generation } if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value for `key` mutably, or None if it has been removed
+    pub fn get_mut(&mut self, key: SlabKey) -> Option<&mut T> {
+        match self.entries.get_mut(key.index)? {
+            Entry::Occupied { value, generation } if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Checks if `key` still points at a value
+    pub fn contains(&self, key: SlabKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes and returns the value for `key`, or None if already removed
+    pub fn remove(&mut self, key: SlabKey) -> Option<T> {
+        self.get(key)?;
+        // Bumping the generation invalidates every copy of `key`
+        let vacant = Entry::Vacant {
+            next_free: self.free,
+            generation: key.generation.wrapping_add(1),
+        };
+        let Entry::Occupied { value, .. } = std::mem::replace(&mut self.entries[key.index], vacant)
+        else {
+            unreachable!("checked occupied above");
+        };
+        self.free = Some(key.index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes every value. Keys handed out before stay invalid
+    pub fn clear(&mut self) {
+        for index in 0..self.entries.len() {
+            if let Entry::Occupied { generation, .. } = self.entries[index] {
+                let key = SlabKey { index, generation };
+                self.remove(key);
+            }
+        }
+    }
+
+    /// Returns an iterator over the keys and values, in slot order
+    pub fn iter(&self) -> impl Iterator<Item = (SlabKey, &T)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| match entry {
+                Entry::Occupied { value, generation } => Some((
+                    SlabKey {
+                        index,
+                        generation: *generation,
+                    },
+                    value,
+                )),
+                Entry::Vacant { .. } => None,
+            })
+    }
+}
+
+impl<T> Default for Slab<T> {
+    fn default() -> Slab<T> {
+        Slab::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Slab, SlabKey};
+    use proptest::prelude::*;
+
+    #[test]
+    fn stale_keys_miss_after_reuse() {
+        let mut slab = Slab::new();
+        let a = slab.insert("a");
+        let b = slab.insert("b");
+        assert_eq!(slab.remove(a), Some("a"));
+        assert_eq!(slab.remove(a), None);
+        let c = slab.insert("c");
+        assert_eq!(c.index(), a.index(), "the freed slot is reused");
+        assert_eq!(slab.get(a), None);
+        assert_eq!(slab.get(c), Some(&"c"));
+        *slab.get_mut(b).unwrap() = "B";
+        assert_eq!(slab.iter().map(|(_, v)| *v).collect::<Vec<_>>(), ["c", "B"]);
+        assert_eq!(slab.len(), 2);
+    }
+
+    #[test]
+    fn clear_invalidates_every_key() {
+        let mut slab = Slab::new();
+        let keys: Vec<SlabKey> = (0..5).map(|i| slab.insert(i)).collect();
+        slab.clear();
+        assert!(slab.is_empty());
+        assert!(keys.iter().all(|&k| !slab.contains(k)));
+        let fresh = slab.insert(9);
+        assert!(keys.iter().all(|&k| slab.get(k).is_none()));
+        assert_eq!(slab.get(fresh), Some(&9));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_a_map_model(ops in prop::collection::vec((any::<bool>(), any::<u16>()), 0..200)) {
+            let mut slab = Slab::new();
+            let mut model: HashMap<SlabKey, u16> = HashMap::new();
+            let mut issued: Vec<SlabKey> = Vec::new();
+            for (insert, value) in ops {
+                if insert || issued.is_empty() {
+                    let key = slab.insert(value);
+                    prop_assert!(!model.contains_key(&key));
+                    model.insert(key, value);
+                    issued.push(key);
+                } else {
+                    let key = issued[value as usize % issued.len()];
+                    prop_assert_eq!(slab.remove(key), model.remove(&key));
+                }
+                prop_assert_eq!(slab.len(), model.len());
+                for key in &issued {
+                    prop_assert_eq!(slab.get(*key), model.get(key));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::ops::{BitAndAssign, BitOrAssign};
+
+use crate::Error;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A fixed-length sequence of bits packed into `u64` words, so bulk operations
+/// such as shifting and or-ing touch 64 bits at a time
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// Creates a BitVec of `len` zero bits
+    pub fn new(len: usize) -> BitVec {
+        BitVec {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// Returns the number of bits
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the BitVec holds no bits
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns bit `index`. Panics if out of bounds
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "bit index {index} out of bounds");
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// Sets bit `index` to `value`. Panics if out of bounds
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "bit index {index} out of bounds");
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            self.words[index / WORD_BITS] |= mask;
+        } else {
+            self.words[index / WORD_BITS] &= !mask;
+        }
+    }
+
+    /// Returns bit `index`, or fails if out of bounds
+    pub fn try_get(&self, index: usize) -> Result<bool, Error> {
+        self.check(index)?;
+        Ok(self.get(index))
+    }
+
+    /// Sets bit `index` to `value`, or fails if out of bounds
+    pub fn try_set(&mut self, index: usize, value: bool) -> Result<(), Error> {
+        self.check(index)?;
+        self.set(index, value);
+        Ok(())
+    }
+
+    fn check(&self, index: usize) -> Result<(), Error> {
+        if index < self.len {
+            Ok(())
+        } else {
+            Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len,
+            })
+        }
+    }
+
+    /// Returns the number of set bits
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over the indices of the set bits, ascending
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    i * WORD_BITS + bit
+                })
+            })
+        })
+    }
+
+    /// Sets `self |= self << shift` in place, dropping bits shifted past the end.
+    ///
+    /// Words are visited from high to low so every source word is read before
+    /// it is overwritten.
+    pub fn or_shifted_left(&mut self, shift: usize) {
+        let (word_shift, bit_shift) = (shift / WORD_BITS, shift % WORD_BITS);
+        for i in (word_shift..self.words.len()).rev() {
+            let src = i - word_shift;
+            let mut shifted = self.words[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                shifted |= self.words[src - 1] >> (WORD_BITS - bit_shift);
+            }
+            self.words[i] |= shifted;
+        }
+        self.clear_unused_bits();
+    }
+
+    /// Keeps the bits past `len` in the last word zero so counts and comparisons stay exact
+    fn clear_unused_bits(&mut self) {
+        let used = self.len % WORD_BITS;
+        if used > 0
+            && let Some(last) = self.words.last_mut()
+        {
+            *last &= (1 << used) - 1;
+        }
+    }
+}
+
+impl BitOrAssign<&BitVec> for BitVec {
+    /// Panics if the lengths differ
+    fn bitor_assign(&mut self, other: &BitVec) {
+        assert_eq!(self.len, other.len, "BitVec lengths differ");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+}
+
+impl BitAndAssign<&BitVec> for BitVec {
+    /// Panics if the lengths differ
+    fn bitand_assign(&mut self, other: &BitVec) {
+        assert_eq!(self.len, other.len, "BitVec lengths differ");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitVec;
+    use crate::Error;
+    use proptest::prelude::*;
+
+    #[test]
+    fn get_set_and_count() {
+        let mut bits = BitVec::new(130);
+        assert_eq!(bits.len(), 130);
+        for i in [0, 63, 64, 129] {
+            bits.set(i, true);
+        }
+        assert!(bits.get(63) && bits.get(64) && !bits.get(65));
+        assert_eq!(bits.count_ones(), 4);
+        assert_eq!(bits.iter_ones().collect::<Vec<_>>(), vec![0, 63, 64, 129]);
+        bits.set(63, false);
+        assert_eq!(bits.count_ones(), 3);
+        assert!(BitVec::new(0).is_empty());
+    }
+
+    #[test]
+    fn try_get_and_set_reject_out_of_bounds() {
+        let mut bits = BitVec::new(3);
+        assert_eq!(bits.try_set(2, true), Ok(()));
+        assert_eq!(bits.try_get(2), Ok(true));
+        let err = Err(Error::IndexOutOfBounds { index: 3, len: 3 });
+        assert_eq!(bits.try_get(3), err);
+        assert_eq!(bits.try_set(3, true), err.map(|_| ()));
+    }
+
+    #[test]
+    fn or_shifted_left_matches_bool_model() {
+        for shift in [0, 1, 5, 63, 64, 65, 100, 200] {
+            let len = 150;
+            let mut bits = BitVec::new(len);
+            let mut model = vec![false; len];
+            for i in (0..len).filter(|i| i % 7 == 0 || i % 11 == 3) {
+                bits.set(i, true);
+                model[i] = true;
+            }
+            bits.or_shifted_left(shift);
+            let expected: Vec<usize> = (0..len)
+                .filter(|&i| model[i] || (i >= shift && model[i - shift]))
+                .collect();
+            assert_eq!(
+                bits.iter_ones().collect::<Vec<_>>(),
+                expected,
+                "shift {shift}"
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_assign() {
+        let mut a = BitVec::new(70);
+        let mut b = BitVec::new(70);
+        a.set(1, true);
+        a.set(69, true);
+        b.set(69, true);
+        b.set(2, true);
+        let mut or = a.clone();
+        or |= &b;
+        assert_eq!(or.iter_ones().collect::<Vec<_>>(), vec![1, 2, 69]);
+        a &= &b;
+        assert_eq!(a.iter_ones().collect::<Vec<_>>(), vec![69]);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_vec_of_bool_model(
+            len in 1..200usize,
+            sets in prop::collection::vec((any::<usize>(), any::<bool>()), 0..100),
+            shift in 0..250usize,
+        ) {
+            let mut bits = BitVec::new(len);
+            let mut model = vec![false; len];
+            for (i, value) in sets {
+                bits.set(i % len, value);
+                model[i % len] = value;
+            }
+            let ones: Vec<usize> = (0..len).filter(|&i| model[i]).collect();
+            prop_assert_eq!(bits.iter_ones().collect::<Vec<_>>(), ones);
+            prop_assert_eq!(bits.count_ones(), model.iter().filter(|&&b| b).count());
+            // Shifted bits past the end fall off
+            bits.or_shifted_left(shift);
+            let shifted: Vec<usize> = (0..len)
+                .filter(|&i| model[i] || (i >= shift && model[i - shift]))
+                .collect();
+            prop_assert_eq!(bits.iter_ones().collect::<Vec<_>>(), shifted);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod bit_vec;
+
+pub use self::bit_vec::BitVec;
@@ -0,0 +1,244 @@
+use std::fmt::{Display, Write};
+
+use crate::visualize::{ToDot, ToMermaid, escape_dot, escape_mermaid};
+
+/// A max-heap backed by a `Vec`, stored in the usual implicit array layout
+#[derive(Debug, Clone)]
+pub struct BinaryHeap<T> {
+    elements: Vec<T>,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    /// Creates a new empty heap
+    pub fn new() -> BinaryHeap<T> {
+        BinaryHeap {
+            elements: Vec::new(),
+        }
+    }
+
+    /// Creates an empty heap with room for `capacity` elements
+    pub fn with_capacity(capacity: usize) -> BinaryHeap<T> {
+        BinaryHeap {
+            elements: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds an element to the heap
+    pub fn push(&mut self, value: T) {
+        let pos = self.elements.len();
+        self.elements.push(value);
+        sift_up(&mut self.elements, pos);
+    }
+
+    /// Removes and returns the greatest element, or None if empty
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.elements.len().checked_sub(1)?;
+        self.elements.swap(0, last);
+        let top = self.elements.pop();
+        sift_down(&mut self.elements, 0, last);
+        top
+    }
+
+    /// Returns a reference to the greatest element, or None if empty
+    pub fn peek(&self) -> Option<&T> {
+        self.elements.first()
+    }
+
+    /// Returns the number of elements in the heap
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Checks if the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Consumes the heap and returns its elements in ascending order
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut end = self.elements.len();
+        while end > 1 {
+            end -= 1;
+            self.elements.swap(0, end);
+            sift_down(&mut self.elements, 0, end);
+        }
+        self.elements
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> BinaryHeap<T> {
+        BinaryHeap::new()
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinaryHeap<T> {
+    fn from(mut elements: Vec<T>) -> BinaryHeap<T> {
+        heapify(&mut elements);
+        BinaryHeap { elements }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for BinaryHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> BinaryHeap<T> {
+        BinaryHeap::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Display> ToDot for BinaryHeap<T> {
+    /// The implicit tree: slot `i` has children `2i + 1` and `2i + 2`
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph heap {\n    node [shape=circle];\n");
+        for (i, value) in self.elements.iter().enumerate() {
+            writeln!(
+                dot,
+                "    {i} [label=\"{}\"];",
+                escape_dot(&value.to_string())
+            )
+            .unwrap();
+        }
+        for child in 1..self.elements.len() {
+            writeln!(dot, "    {} -> {child};", (child - 1) / 2).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<T: Display> ToMermaid for BinaryHeap<T> {
+    fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart TD\n");
+        for (i, value) in self.elements.iter().enumerate() {
+            let label = escape_mermaid(&value.to_string());
+            writeln!(mermaid, "    n{i}((\"{label}\"))").unwrap();
+        }
+        for child in 1..self.elements.len() {
+            writeln!(mermaid, "    n{} --> n{child}", (child - 1) / 2).unwrap();
+        }
+        mermaid
+    }
+}
+
+/// Rearranges `arr` into max-heap order in O(n)
+pub(crate) fn heapify<T: Ord>(arr: &mut [T]) {
+    let len = arr.len();
+    for start in (0..len / 2).rev() {
+        sift_down(arr, start, len);
+    }
+}
+
+/// Moves `arr[pos]` down until the heap property holds within `arr[..end]`
+pub(crate) fn sift_down<T: Ord>(arr: &mut [T], mut pos: usize, end: usize) {
+    loop {
+        let left = 2 * pos + 1;
+        if left >= end {
+            return;
+        }
+        let right = left + 1;
+        let child = if right < end && arr[right] > arr[left] {
+            right
+        } else {
+            left
+        };
+        if arr[pos] >= arr[child] {
+            return;
+        }
+        arr.swap(pos, child);
+        pos = child;
+    }
+}
+
+fn sift_up<T: Ord>(arr: &mut [T], mut pos: usize) {
+    while pos > 0 {
+        let parent = (pos - 1) / 2;
+        if arr[pos] <= arr[parent] {
+            return;
+        }
+        arr.swap(pos, parent);
+        pos = parent;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BinaryHeap;
+    use crate::visualize::{ToDot, ToMermaid};
+    use proptest::prelude::*;
+    use std::cmp::Reverse;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn push_and_pop_return_greatest_first() {
+        let mut heap = BinaryHeap::new();
+        assert!(heap.is_empty());
+        for value in [5, 1, 8, 3, 9, 2] {
+            heap.push(value);
+        }
+        assert_eq!(heap.len(), 6);
+        assert_eq!(heap.peek(), Some(&9));
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![9, 8, 5, 3, 2, 1]);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn reverse_gives_min_heap() {
+        let mut heap: BinaryHeap<Reverse<i32>> = [4, -1, 7, 0].into_iter().map(Reverse).collect();
+        assert_eq!(heap.pop(), Some(Reverse(-1)));
+        assert_eq!(heap.pop(), Some(Reverse(0)));
+    }
+
+    #[test]
+    fn into_sorted_vec_is_ascending() {
+        let heap = BinaryHeap::from(vec![3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(heap.into_sorted_vec(), vec![1, 1, 2, 3, 4, 5, 6, 9]);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_multiset_model(ops in prop::collection::vec(prop::option::of(0..20i32), 0..100)) {
+            // Some(x) pushes x, None pops; the model counts each value
+            let mut heap = BinaryHeap::new();
+            let mut model: BTreeMap<i32, usize> = BTreeMap::new();
+            for op in ops {
+                match op {
+                    Some(x) => {
+                        heap.push(x);
+                        *model.entry(x).or_default() += 1;
+                    }
+                    None => {
+                        let expected = model.last_entry().map(|mut entry| {
+                            *entry.get_mut() -= 1;
+                            match *entry.get() {
+                                0 => entry.remove_entry().0,
+                                _ => *entry.key(),
+                            }
+                        });
+                        prop_assert_eq!(heap.pop(), expected);
+                    }
+                }
+                prop_assert_eq!(heap.len(), model.values().sum::<usize>());
+                prop_assert_eq!(heap.peek(), model.keys().next_back());
+            }
+        }
+    }
+
+    #[test]
+    fn renders_the_implicit_tree() {
+        let heap = BinaryHeap::from(vec![1, 2, 3, 4]);
+        assert_eq!(
+            heap.to_dot(),
+            "digraph heap {\n    node [shape=circle];\n    0 [label=\"4\"];\n    1 [label=\"2\"];\n    \
+             2 [label=\"3\"];\n    3 [label=\"1\"];\n    0 -> 1;\n    0 -> 2;\n    1 -> 3;\n}\n"
+        );
+        assert_eq!(
+            heap.to_mermaid(),
+            "flowchart TD\n    n0((\"4\"))\n    n1((\"2\"))\n    n2((\"3\"))\n    n3((\"1\"))\n    \
+             n0 --> n1\n    n0 --> n2\n    n1 --> n3\n"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod binary_heap;
+
+pub use self::binary_heap::BinaryHeap;
+#[cfg(feature = "algorithm")]
+pub(crate) use self::binary_heap::{heapify, sift_down};
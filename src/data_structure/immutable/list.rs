@@ -0,0 +1,254 @@
+use std::fmt::{self, Debug, Formatter};
+use std::rc::Rc;
+
+use crate::Error;
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+/// A persistent singly linked list. Pushing onto the front is O(1) and the
+/// new list shares every node of the old one, so cloning is O(1) as well.
+pub struct List<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    /// Creates an empty list
+    pub fn new() -> List<T> {
+        List { head: None, len: 0 }
+    }
+
+    /// Returns the number of values
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the list holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a new list with `value` in front of this one
+    pub fn push(&self, value: T) -> List<T> {
+        List {
+            head: Some(Rc::new(Node {
+                value,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    /// Returns the first value
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns the list without its first value, sharing every node
+    pub fn tail(&self) -> Option<List<T>> {
+        self.head.as_ref().map(|node| List {
+            head: node.next.clone(),
+            len: self.len - 1,
+        })
+    }
+
+    /// Returns the value at `index` in O(index)
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Returns an iterator from front to back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T: Clone> List<T> {
+    /// Returns a new list with the value at `index` replaced. The nodes
+    /// before it are copied and the ones after it are shared. Panics if
+    /// `index` is out of bounds.
+    pub fn update(&self, index: usize, value: T) -> List<T> {
+        match self.try_update(index, value) {
+            Ok(list) => list,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// `update` that reports an out of bounds `index` instead of panicking
+    pub fn try_update(&self, index: usize, value: T) -> Result<List<T>, Error> {
+        if index >= self.len {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+        let mut prefix = Vec::with_capacity(index);
+        let mut node = self.head.as_ref().expect("index checked above");
+        for _ in 0..index {
+            prefix.push(node.value.clone());
+            node = node.next.as_ref().expect("index checked above");
+        }
+        let suffix = List {
+            head: node.next.clone(),
+            len: self.len - index - 1,
+        };
+        Ok(prefix
+            .into_iter()
+            .rev()
+            .fold(suffix.push(value), |list, value| list.push(value)))
+    }
+
+    /// Returns a new list in the opposite order. Nothing can be shared.
+    pub fn reverse(&self) -> List<T> {
+        self.iter()
+            .fold(List::new(), |list, value| list.push(value.clone()))
+    }
+}
+
+impl<T> Clone for List<T> {
+    fn clone(&self) -> List<T> {
+        List {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Drop for List<T> {
+    // The default recursive drop would overflow the stack on long lists.
+    // Stop at the first node another list still shares.
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => next = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> List<T> {
+        List::new()
+    }
+}
+
+impl<T: Debug> Debug for List<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &List<T>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T> FromIterator<T> for List<T> {
+    /// Keeps the iterator's order, so the first item becomes the head
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> List<T> {
+        let values: Vec<T> = iter.into_iter().collect();
+        values
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, value| list.push(value))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An iterator over a `List` from front to back
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        self.len -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::List;
+    use crate::Error;
+
+    #[test]
+    fn versions_share_their_tails() {
+        let empty = List::new();
+        let one = empty.push(1);
+        let two = one.push(2);
+        let other = one.push(20);
+        assert_eq!(empty.len(), 0);
+        assert_eq!(one.iter().copied().collect::<Vec<_>>(), [1]);
+        assert_eq!(two.iter().copied().collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(other.iter().copied().collect::<Vec<_>>(), [20, 1]);
+        let shared = |list: &List<i32>| list.head.as_ref().unwrap().next.clone().unwrap();
+        assert!(Rc::ptr_eq(&shared(&two), &shared(&other)));
+        assert_eq!(two.tail(), Some(one.clone()));
+        assert_eq!(two.head(), Some(&2));
+        assert_eq!(empty.tail(), None);
+    }
+
+    #[test]
+    fn update_copies_only_the_prefix() {
+        let list: List<char> = "abcde".chars().collect();
+        let updated = list.update(2, 'X');
+        assert_eq!(updated.iter().collect::<String>(), "abXde");
+        assert_eq!(list.iter().collect::<String>(), "abcde");
+        let suffix = |list: &List<char>| list.tail().unwrap().tail().unwrap().tail().unwrap();
+        assert!(Rc::ptr_eq(
+            suffix(&list).head.as_ref().unwrap(),
+            suffix(&updated).head.as_ref().unwrap()
+        ));
+        assert_eq!(updated.get(2), Some(&'X'));
+        assert_eq!(updated.get(5), None);
+        assert_eq!(
+            list.try_update(5, 'Y'),
+            Err(Error::IndexOutOfBounds { index: 5, len: 5 })
+        );
+        assert_eq!(list.reverse().iter().collect::<String>(), "edcba");
+    }
+
+    #[test]
+    fn dropping_a_long_list_does_not_overflow() {
+        let list: List<u32> = (0..1_000_000).collect();
+        let tail = list.tail().unwrap();
+        drop(list);
+        assert_eq!(tail.len(), 999_999);
+        assert_eq!(tail.head(), Some(&1));
+    }
+}
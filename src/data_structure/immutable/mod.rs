@@ -0,0 +1,8 @@
+//! Persistent collections: every "modification" returns a new version and
+//! leaves the old one intact, sharing whatever the two have in common.
+
+mod list;
+mod vector;
+
+pub use self::list::{Iter, List};
+pub use self::vector::{Vector, VectorIter};
@@ -0,0 +1,383 @@
+use std::fmt::{self, Debug, Formatter};
+use std::ops::Index;
+use std::rc::Rc;
+
+use crate::Error;
+
+/// Each branch splits the index on this many bits
+const BITS: u32 = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+enum Node<T> {
+    Branch(Vec<Rc<Node<T>>>),
+    Leaf(Vec<T>),
+}
+
+/// A persistent vector: a 32-way trie indexed by the bits of the position,
+/// five at a time, plus a tail buffer holding the last partial leaf.
+///
+/// `get` walks at most log32(n) levels, and `push` and `update` copy only the
+/// nodes on one root-to-leaf path and share everything else with the old
+/// version. Pushes fill the tail first, so most of them copy only the tail.
+pub struct Vector<T> {
+    len: usize,
+    /// The bit shift of the root's children: BITS for a root of leaves
+    shift: u32,
+    root: Rc<Node<T>>,
+    tail: Rc<Vec<T>>,
+}
+
+impl<T> Vector<T> {
+    /// Creates an empty vector
+    pub fn new() -> Vector<T> {
+        Vector {
+            len: 0,
+            shift: BITS,
+            root: Rc::new(Node::Branch(Vec::new())),
+            tail: Rc::new(Vec::new()),
+        }
+    }
+
+    /// Returns the number of values
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the vector holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value at `index`
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < self.len).then(|| &self.leaf_for(index)[index & MASK])
+    }
+
+    /// Returns an iterator from front to back
+    pub fn iter(&self) -> VectorIter<'_, T> {
+        VectorIter {
+            vector: self,
+            index: 0,
+            leaf: &[],
+        }
+    }
+
+    /// The index of the tail's first value; everything before it is in the trie
+    fn tail_offset(&self) -> usize {
+        if self.len < WIDTH {
+            0
+        } else {
+            ((self.len - 1) >> BITS) << BITS
+        }
+    }
+
+    /// The leaf holding `index`, which must be in bounds
+    fn leaf_for(&self, index: usize) -> &[T] {
+        if index >= self.tail_offset() {
+            return &self.tail;
+        }
+        let mut node = &*self.root;
+        let mut shift = self.shift;
+        loop {
+            match node {
+                Node::Branch(children) => {
+                    node = &children[(index >> shift) & MASK];
+                    shift -= BITS;
+                }
+                Node::Leaf(values) => return values,
+            }
+        }
+    }
+}
+
+impl<T: Clone> Vector<T> {
+    /// Returns a new vector with `value` appended
+    pub fn push(&self, value: T) -> Vector<T> {
+        if self.len - self.tail_offset() < WIDTH {
+            let mut tail = Vec::with_capacity(self.tail.len() + 1);
+            tail.extend_from_slice(&self.tail);
+            tail.push(value);
+            return Vector {
+                len: self.len + 1,
+                shift: self.shift,
+                root: Rc::clone(&self.root),
+                tail: Rc::new(tail),
+            };
+        }
+        // The tail is full: move it into the trie and start a new one
+        let leaf = Rc::new(Node::Leaf(self.tail.to_vec()));
+        let (root, shift) = if (self.len >> BITS) > (1 << self.shift) {
+            // The trie is full at this height, so grow a new root above it
+            let path = new_path(self.shift, leaf);
+            let root = Node::Branch(vec![Rc::clone(&self.root), path]);
+            (Rc::new(root), self.shift + BITS)
+        } else {
+            (
+                push_leaf(&self.root, self.shift, self.len - 1, leaf),
+                self.shift,
+            )
+        };
+        Vector {
+            len: self.len + 1,
+            shift,
+            root,
+            tail: Rc::new(vec![value]),
+        }
+    }
+
+    /// Returns a new vector with the value at `index` replaced. Panics if
+    /// `index` is out of bounds.
+    pub fn update(&self, index: usize, value: T) -> Vector<T> {
+        match self.try_update(index, value) {
+            Ok(vector) => vector,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// `update` that reports an out of bounds `index` instead of panicking
+    pub fn try_update(&self, index: usize, value: T) -> Result<Vector<T>, Error> {
+        if index >= self.len {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+        let mut vector = self.clone();
+        if index >= self.tail_offset() {
+            let mut tail = self.tail.to_vec();
+            tail[index & MASK] = value;
+            vector.tail = Rc::new(tail);
+        } else {
+            vector.root = Rc::new(assoc(&self.root, self.shift, index, value));
+        }
+        Ok(vector)
+    }
+}
+
+/// A chain of single-child branches from `shift` down to `leaf`
+fn new_path<T>(shift: u32, leaf: Rc<Node<T>>) -> Rc<Node<T>> {
+    if shift == 0 {
+        leaf
+    } else {
+        Rc::new(Node::Branch(vec![new_path(shift - BITS, leaf)]))
+    }
+}
+
+/// Copies the path to where the leaf holding `last` belongs and hangs `leaf` there
+fn push_leaf<T>(node: &Node<T>, shift: u32, last: usize, leaf: Rc<Node<T>>) -> Rc<Node<T>> {
+    let Node::Branch(children) = node else {
+        unreachable!("leaves only sit below shift 0")
+    };
+    let mut children = children.clone();
+    let slot = (last >> shift) & MASK;
+    if shift == BITS {
+        children.push(leaf);
+    } else if slot < children.len() {
+        children[slot] = push_leaf(&children[slot], shift - BITS, last, leaf);
+    } else {
+        children.push(new_path(shift - BITS, leaf));
+    }
+    Rc::new(Node::Branch(children))
+}
+
+/// A copy of `node` with the value at `index` replaced, sharing every
+/// subtree off the path to it
+fn assoc<T: Clone>(node: &Node<T>, shift: u32, index: usize, value: T) -> Node<T> {
+    match node {
+        Node::Branch(children) => {
+            let mut children = children.clone();
+            let slot = (index >> shift) & MASK;
+            children[slot] = Rc::new(assoc(&children[slot], shift - BITS, index, value));
+            Node::Branch(children)
+        }
+        Node::Leaf(values) => {
+            let mut values = values.clone();
+            values[index & MASK] = value;
+            Node::Leaf(values)
+        }
+    }
+}
+
+impl<T> Clone for Vector<T> {
+    fn clone(&self) -> Vector<T> {
+        Vector {
+            len: self.len,
+            shift: self.shift,
+            root: Rc::clone(&self.root),
+            tail: Rc::clone(&self.tail),
+        }
+    }
+}
+
+impl<T> Default for Vector<T> {
+    fn default() -> Vector<T> {
+        Vector::new()
+    }
+}
+
+impl<T> Index<usize> for Vector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "{}",
+                Error::IndexOutOfBounds {
+                    index,
+                    len: self.len
+                }
+            ),
+        }
+    }
+}
+
+impl<T: Debug> Debug for Vector<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Vector<T> {
+    fn eq(&self, other: &Vector<T>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Vector<T> {}
+
+impl<T: Clone> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Vector<T> {
+        iter.into_iter()
+            .fold(Vector::new(), |vector, value| vector.push(value))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = VectorIter<'a, T>;
+
+    fn into_iter(self) -> VectorIter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An iterator over a `Vector` from front to back, a leaf at a time
+pub struct VectorIter<'a, T> {
+    vector: &'a Vector<T>,
+    index: usize,
+    /// What is left of the current leaf
+    leaf: &'a [T],
+}
+
+impl<'a, T> Iterator for VectorIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.leaf.is_empty() {
+            if self.index >= self.vector.len {
+                return None;
+            }
+            self.leaf = &self.vector.leaf_for(self.index)[self.index & MASK..];
+        }
+        let (first, rest) = self.leaf.split_first()?;
+        self.leaf = rest;
+        self.index += 1;
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.vector.len - self.index;
+        (left, Some(left))
+    }
+}
+
+impl<T> ExactSizeIterator for VectorIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::{Node, Vector, WIDTH};
+    use crate::Error;
+    use proptest::prelude::*;
+
+    #[test]
+    fn grows_through_several_levels() {
+        // 40_000 values need a root two levels above the leaves
+        let vector: Vector<usize> = (0..40_000).collect();
+        assert_eq!(vector.len(), 40_000);
+        assert!((0..40_000).all(|i| vector[i] == i));
+        assert_eq!(vector.get(40_000), None);
+        assert!(vector.iter().copied().eq(0..40_000));
+        assert_eq!(vector.iter().len(), 40_000);
+    }
+
+    #[test]
+    fn update_shares_untouched_subtrees() {
+        let old: Vector<usize> = (0..WIDTH * WIDTH * 2).collect();
+        let new = old.update(5, 500);
+        assert_eq!((old[5], new[5]), (5, 500));
+        let (Node::Branch(a), Node::Branch(b)) = (&*old.root, &*new.root) else {
+            panic!("the root of a non-empty trie is a branch");
+        };
+        assert!(!Rc::ptr_eq(&a[0], &b[0]), "the path to index 5 is copied");
+        assert!(a[1..].iter().zip(&b[1..]).all(|(x, y)| Rc::ptr_eq(x, y)));
+        assert!(Rc::ptr_eq(&old.tail, &new.tail));
+
+        let tail_update = old.update(old.len() - 1, 0);
+        assert!(Rc::ptr_eq(&old.root, &tail_update.root));
+        assert_eq!(
+            old.try_update(old.len(), 0).err(),
+            Some(Error::IndexOutOfBounds {
+                index: old.len(),
+                len: old.len()
+            })
+        );
+    }
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Push(u16),
+        Update(usize, u16),
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            3 => any::<u16>().prop_map(Op::Push),
+            1 => (any::<usize>(), any::<u16>()).prop_map(|(i, v)| Op::Update(i, v)),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn every_version_matches_a_vec(base in 0usize..2000, ops in prop::collection::vec(op(), 1..200)) {
+            // Build each version from a random earlier one, so history branches
+            let mut versions = vec![(0..base as u16).collect::<Vector<u16>>()];
+            let mut models = vec![(0..base as u16).collect::<Vec<u16>>()];
+            for (step, op) in ops.into_iter().enumerate() {
+                let from = step * 7919 % versions.len();
+                let (mut vector, mut model) = (versions[from].clone(), models[from].clone());
+                match op {
+                    Op::Push(value) => {
+                        vector = vector.push(value);
+                        model.push(value);
+                    }
+                    Op::Update(index, value) if !model.is_empty() => {
+                        let index = index % model.len();
+                        vector = vector.update(index, value);
+                        model[index] = value;
+                    }
+                    Op::Update(..) => {}
+                }
+                versions.push(vector);
+                models.push(model);
+            }
+            for (vector, model) in versions.iter().zip(&models) {
+                prop_assert_eq!(vector.len(), model.len());
+                prop_assert!(vector.iter().eq(model.iter()));
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
-use std::fmt::{self, Display, Formatter};
+use std::fmt::{self, Display, Formatter, Write};
 use std::marker::PhantomData;
 use std::ptr::NonNull;
 
 use super::node::Node;
+use crate::Error;
+use crate::visualize::{ToDot, ToMermaid, escape_dot, escape_mermaid};
 
 pub struct LinkedList<T> {
     pub length: u32,
@@ -28,6 +30,14 @@ impl<T> LinkedList<T> {
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
     pub fn insert_at_head(&mut self, obj: T) {
         let mut node = Box::new(Node::new(obj));
         node.next = self.head;
@@ -54,6 +64,18 @@ impl<T> LinkedList<T> {
         self.length += 1;
     }
 
+    /// Inserts at `index`, or fails if `index > length`
+    pub fn try_insert_at_ith(&mut self, index: u32, obj: T) -> Result<(), Error> {
+        if index > self.length {
+            return Err(Error::IndexOutOfBounds {
+                index: index as usize,
+                len: self.length as usize,
+            });
+        }
+        self.insert_at_ith(index, obj);
+        Ok(())
+    }
+
     pub fn insert_at_ith(&mut self, index: u32, obj: T) {
         if self.length < index {
             panic!("Index out of bounds");
@@ -128,16 +150,36 @@ impl<T> LinkedList<T> {
         })
     }
 
+    /// Removes the element at `index`, or fails if `index >= length`
+    pub fn try_delete_ith(&mut self, index: u32) -> Result<T, Error> {
+        if index >= self.length {
+            return Err(Error::IndexOutOfBounds {
+                index: index as usize,
+                len: self.length as usize,
+            });
+        }
+        Ok(self.delete_ith(index).expect("index checked above"))
+    }
+
+    /// Removes the element at `index`. One past the end returns None, as
+    /// with `get`; further out panics
     pub fn delete_ith(&mut self, index: u32) -> Option<T> {
         if self.length < index {
             panic!("Index out of bounds");
         }
 
+        // One past the end is in bounds for inserting but holds nothing
+        if index == self.length {
+            return None;
+        }
+
         if index == 0 || self.head.is_none() {
             return self.delete_head();
         }
 
-        if self.length == index {
+        // The last node has no next to take over its prev, so the tail
+        // pointer has to move
+        if index + 1 >= self.length {
             return self.delete_tail();
         }
 
@@ -202,10 +244,101 @@ where
     }
 }
 
+impl<T: Display> LinkedList<T> {
+    /// The node values from head to tail, as display strings
+    fn labels(&self) -> Vec<String> {
+        let mut labels = Vec::with_capacity(self.length as usize);
+        let mut cursor = self.head;
+        while let Some(node) = cursor {
+            let node = unsafe { node.as_ref() };
+            labels.push(node.val.to_string());
+            cursor = node.next;
+        }
+        labels
+    }
+}
+
+impl<T: Display> ToDot for LinkedList<T> {
+    /// Nodes left to right, with the double links drawn as two-headed edges
+    fn to_dot(&self) -> String {
+        let labels = self.labels();
+        let mut dot = String::from("digraph list {\n    rankdir=LR;\n    node [shape=box];\n");
+        for (i, label) in labels.iter().enumerate() {
+            writeln!(dot, "    {i} [label=\"{}\"];", escape_dot(label)).unwrap();
+        }
+        if !labels.is_empty() {
+            dot.push_str("    head [shape=plaintext];\n    tail [shape=plaintext];\n");
+            writeln!(dot, "    head -> 0;\n    tail -> {};", labels.len() - 1).unwrap();
+        }
+        for i in 1..labels.len() {
+            writeln!(dot, "    {} -> {i} [dir=both];", i - 1).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<T: Display> ToMermaid for LinkedList<T> {
+    fn to_mermaid(&self) -> String {
+        let labels = self.labels();
+        let mut mermaid = String::from("flowchart LR\n");
+        for (i, label) in labels.iter().enumerate() {
+            writeln!(mermaid, "    n{i}[\"{}\"]", escape_mermaid(label)).unwrap();
+        }
+        for i in 1..labels.len() {
+            writeln!(mermaid, "    n{} <--> n{i}", i - 1).unwrap();
+        }
+        mermaid
+    }
+}
+
 // TODO : Add test cases
 #[cfg(test)]
 mod tests {
     use super::LinkedList;
+    use crate::Error;
+    use crate::visualize::{ToDot, ToMermaid};
+    use proptest::prelude::*;
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        InsertAtHead(i32),
+        InsertAtTail(i32),
+        /// The index is taken modulo the length plus one
+        InsertAtIth(usize, i32),
+        DeleteHead,
+        DeleteTail,
+        /// The index is taken modulo the length
+        DeleteIth(usize),
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            any::<i32>().prop_map(Op::InsertAtHead),
+            any::<i32>().prop_map(Op::InsertAtTail),
+            (any::<usize>(), any::<i32>()).prop_map(|(i, x)| Op::InsertAtIth(i, x)),
+            Just(Op::DeleteHead),
+            Just(Op::DeleteTail),
+            any::<usize>().prop_map(Op::DeleteIth),
+        ]
+    }
+
+    /// Every element read front to back by index, and back to front by
+    /// following the prev links, so both directions are checked
+    fn contents(list: &LinkedList<i32>) -> (Vec<i32>, Vec<i32>) {
+        let forward = (0..list.length as i32)
+            .map(|i| *list.get(i).unwrap())
+            .collect();
+        let mut backward = Vec::new();
+        let mut node = list.tail;
+        while let Some(ptr) = node {
+            let node_ref = unsafe { ptr.as_ref() };
+            backward.push(node_ref.val);
+            node = node_ref.prev;
+        }
+        backward.reverse();
+        (forward, backward)
+    }
 
     #[test]
     fn insert_at_tail_works() {
@@ -399,6 +532,40 @@ mod tests {
         assert_eq!(list.length, 1);
     }
 
+    #[test]
+    fn delete_ith_one_past_the_end_deletes_nothing() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(list.delete_ith(0), None);
+        list.insert_at_tail(1);
+        list.insert_at_tail(2);
+        assert_eq!(list.delete_ith(2), None);
+        assert_eq!(list.length, 2);
+        assert_eq!(list.get(1), Some(&2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn delete_ith_further_out_panics() {
+        let mut list = LinkedList::new();
+        list.insert_at_tail(1);
+        list.delete_ith(2);
+    }
+
+    #[test]
+    fn try_variants_report_the_bad_index() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.try_insert_at_ith(0, 'a'), Ok(()));
+        assert_eq!(
+            list.try_insert_at_ith(2, 'b'),
+            Err(Error::IndexOutOfBounds { index: 2, len: 1 })
+        );
+        assert_eq!(
+            list.try_delete_ith(1),
+            Err(Error::IndexOutOfBounds { index: 1, len: 1 })
+        );
+        assert_eq!(list.try_delete_ith(0), Ok('a'));
+    }
+
     #[test]
     fn delete_ith_can_delete_at_head() {
         let mut list = LinkedList::<i32>::new();
@@ -481,4 +648,61 @@ mod tests {
         assert!(retrived_item.is_some());
         assert_eq!("B", *retrived_item.unwrap());
     }
+
+    proptest! {
+        #[test]
+        fn matches_vec_model(ops in prop::collection::vec(op(), 0..64)) {
+            let mut list = LinkedList::new();
+            let mut model = Vec::new();
+            for op in ops {
+                match op {
+                    Op::InsertAtHead(x) => {
+                        list.insert_at_head(x);
+                        model.insert(0, x);
+                    }
+                    Op::InsertAtTail(x) => {
+                        list.insert_at_tail(x);
+                        model.push(x);
+                    }
+                    Op::InsertAtIth(i, x) => {
+                        let i = i % (model.len() + 1);
+                        list.insert_at_ith(i as u32, x);
+                        model.insert(i, x);
+                    }
+                    Op::DeleteHead => {
+                        let expected = (!model.is_empty()).then(|| model.remove(0));
+                        prop_assert_eq!(list.delete_head(), expected);
+                    }
+                    Op::DeleteTail => prop_assert_eq!(list.delete_tail(), model.pop()),
+                    Op::DeleteIth(i) if !model.is_empty() => {
+                        let i = i % model.len();
+                        prop_assert_eq!(list.delete_ith(i as u32), Some(model.remove(i)));
+                    }
+                    Op::DeleteIth(_) => {}
+                }
+                prop_assert_eq!(list.length as usize, model.len());
+                let (forward, backward) = contents(&list);
+                prop_assert_eq!(&forward, &model);
+                prop_assert_eq!(&backward, &model);
+            }
+        }
+    }
+
+    #[test]
+    fn renders_dot_and_mermaid() {
+        let mut list = LinkedList::new();
+        list.insert_at_tail("a");
+        list.insert_at_tail("\"b\"");
+        assert_eq!(
+            list.to_dot(),
+            "digraph list {\n    rankdir=LR;\n    node [shape=box];\n    0 [label=\"a\"];\n    \
+             1 [label=\"\\\"b\\\"\"];\n    head [shape=plaintext];\n    tail [shape=plaintext];\n    \
+             head -> 0;\n    tail -> 1;\n    0 -> 1 [dir=both];\n}\n"
+        );
+        assert_eq!(
+            list.to_mermaid(),
+            "flowchart LR\n    n0[\"a\"]\n    n1[\"#quot;b#quot;\"]\n    n0 <--> n1\n"
+        );
+        assert_eq!(LinkedList::<i32>::new().to_mermaid(), "flowchart LR\n");
+    }
 }
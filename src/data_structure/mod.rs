@@ -1,5 +1,16 @@
+mod arena;
+mod bit_vec;
+mod heap;
+pub mod immutable;
 mod linked_list;
 mod queue;
+mod rope;
 
+pub use self::arena::{Arena, Slab, SlabKey};
+pub use self::bit_vec::BitVec;
+pub use self::heap::BinaryHeap;
+#[cfg(feature = "algorithm")]
+pub(crate) use self::heap::{heapify, sift_down};
 pub use self::linked_list::LinkedList;
 pub use self::queue::Queue;
+pub use self::rope::{Chunks, Rope};
@@ -59,6 +59,8 @@ impl<T> Default for Queue<T> {
 #[cfg(test)]
 mod tests {
     use super::Queue;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
 
     #[test]
     fn test_queue_functionality() {
@@ -83,4 +85,25 @@ mod tests {
         assert_eq!(queue.len(), 0);
         assert_eq!(queue.dequeue(), None);
     }
+
+    proptest! {
+        #[test]
+        fn matches_vec_deque_model(ops in prop::collection::vec(prop::option::of(any::<u8>()), 0..100)) {
+            // Some(x) enqueues x, None dequeues
+            let mut queue = Queue::new();
+            let mut model = VecDeque::new();
+            for op in ops {
+                match op {
+                    Some(x) => {
+                        queue.enqueue(x);
+                        model.push_back(x);
+                    }
+                    None => prop_assert_eq!(queue.dequeue(), model.pop_front()),
+                }
+                prop_assert_eq!(queue.len(), model.len());
+                prop_assert_eq!(queue.peek_front(), model.front());
+                prop_assert_eq!(queue.peek_back(), model.back());
+            }
+        }
+    }
 }
@@ -0,0 +1,4 @@
+mod node;
+mod rope;
+
+pub use self::rope::{Chunks, Rope};
@@ -0,0 +1,289 @@
+/// Leaves are split so that no chunk exceeds this many bytes
+pub(super) const MAX_LEAF: usize = 1024;
+
+/// A node of a rope: a chunk of text, or a concatenation of two subtrees that
+/// caches their combined char and newline counts and its height
+#[derive(Debug, Clone)]
+pub(super) enum Node {
+    Leaf {
+        text: String,
+        chars: usize,
+        newlines: usize,
+    },
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        chars: usize,
+        newlines: usize,
+        height: usize,
+    },
+}
+
+impl Node {
+    pub(super) fn leaf(text: String) -> Node {
+        let chars = text.chars().count();
+        let newlines = text.bytes().filter(|&b| b == b'\n').count();
+        Node::Leaf {
+            text,
+            chars,
+            newlines,
+        }
+    }
+
+    fn branch(left: Node, right: Node) -> Node {
+        Node::Branch {
+            chars: left.chars() + right.chars(),
+            newlines: left.newlines() + right.newlines(),
+            height: left.height().max(right.height()) + 1,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Builds a perfectly balanced tree over `text` cut into chunks of at most `MAX_LEAF` bytes
+    pub(super) fn from_str(text: &str) -> Node {
+        let mut leaves = Vec::with_capacity(text.len() / MAX_LEAF + 1);
+        let mut rest = text;
+        while rest.len() > MAX_LEAF {
+            let mut cut = MAX_LEAF;
+            while !rest.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            leaves.push(Node::leaf(rest[..cut].to_string()));
+            rest = &rest[cut..];
+        }
+        leaves.push(Node::leaf(rest.to_string()));
+        Node::build(leaves)
+    }
+
+    fn build(mut leaves: Vec<Node>) -> Node {
+        if leaves.len() == 1 {
+            return leaves.pop().expect("one leaf");
+        }
+        let right = leaves.split_off(leaves.len() / 2);
+        Node::branch(Node::build(leaves), Node::build(right))
+    }
+
+    pub(super) fn chars(&self) -> usize {
+        match self {
+            Node::Leaf { chars, .. } | Node::Branch { chars, .. } => *chars,
+        }
+    }
+
+    pub(super) fn newlines(&self) -> usize {
+        match self {
+            Node::Leaf { newlines, .. } | Node::Branch { newlines, .. } => *newlines,
+        }
+    }
+
+    pub(super) fn height(&self) -> usize {
+        match self {
+            Node::Leaf { .. } => 0,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    /// Visits the tree in preorder, numbering nodes from `next_id` and passing
+    /// each one with its own id and its parent's
+    pub(super) fn walk(
+        &self,
+        next_id: &mut usize,
+        parent: Option<usize>,
+        visit: &mut impl FnMut(usize, Option<usize>, &Node),
+    ) {
+        let id = *next_id;
+        *next_id += 1;
+        visit(id, parent, self);
+        if let Node::Branch { left, right, .. } = self {
+            left.walk(next_id, Some(id), visit);
+            right.walk(next_id, Some(id), visit);
+        }
+    }
+
+    /// Concatenates two trees, AVL-style: the shorter tree is joined into the
+    /// taller one's inner spine and rotations restore the height balance on the
+    /// way back up, so the cost is O(|height difference| + 1).
+    pub(super) fn join(left: Node, right: Node) -> Node {
+        if left.chars() == 0 {
+            return right;
+        }
+        if right.chars() == 0 {
+            return left;
+        }
+        if let (Node::Leaf { text: a, .. }, Node::Leaf { text: b, .. }) = (&left, &right)
+            && a.len() + b.len() <= MAX_LEAF
+        {
+            return Node::leaf(format!("{a}{b}"));
+        }
+        let (hl, hr) = (left.height(), right.height());
+        if hl > hr + 1 {
+            let Node::Branch {
+                left: ll,
+                right: lr,
+                ..
+            } = left
+            else {
+                unreachable!("a taller tree is a branch")
+            };
+            Node::balance(*ll, Node::join(*lr, right))
+        } else if hr > hl + 1 {
+            let Node::Branch {
+                left: rl,
+                right: rr,
+                ..
+            } = right
+            else {
+                unreachable!("a taller tree is a branch")
+            };
+            Node::balance(Node::join(left, *rl), *rr)
+        } else {
+            Node::branch(left, right)
+        }
+    }
+
+    /// Makes a branch of two subtrees whose heights differ by at most 2, rotating if needed
+    fn balance(left: Node, right: Node) -> Node {
+        if left.height() > right.height() + 1 {
+            let Node::Branch {
+                left: ll,
+                right: lr,
+                ..
+            } = left
+            else {
+                unreachable!("a taller tree is a branch")
+            };
+            if ll.height() >= lr.height() {
+                Node::branch(*ll, Node::branch(*lr, right))
+            } else {
+                let Node::Branch {
+                    left: lrl,
+                    right: lrr,
+                    ..
+                } = *lr
+                else {
+                    unreachable!("a taller tree is a branch")
+                };
+                Node::branch(Node::branch(*ll, *lrl), Node::branch(*lrr, right))
+            }
+        } else if right.height() > left.height() + 1 {
+            let Node::Branch {
+                left: rl,
+                right: rr,
+                ..
+            } = right
+            else {
+                unreachable!("a taller tree is a branch")
+            };
+            if rr.height() >= rl.height() {
+                Node::branch(Node::branch(left, *rl), *rr)
+            } else {
+                let Node::Branch {
+                    left: rll,
+                    right: rlr,
+                    ..
+                } = *rl
+                else {
+                    unreachable!("a taller tree is a branch")
+                };
+                Node::branch(Node::branch(left, *rll), Node::branch(*rlr, *rr))
+            }
+        } else {
+            Node::branch(left, right)
+        }
+    }
+
+    /// Splits into the first `at` chars and the rest, in O(log n) joins
+    pub(super) fn split(self, at: usize) -> (Node, Node) {
+        match self {
+            Node::Leaf { mut text, .. } => {
+                let byte = char_to_byte(&text, at);
+                let rest = text.split_off(byte);
+                (Node::leaf(text), Node::leaf(rest))
+            }
+            Node::Branch { left, right, .. } => {
+                let left_chars = left.chars();
+                if at == left_chars {
+                    (*left, *right)
+                } else if at < left_chars {
+                    let (a, b) = left.split(at);
+                    (a, Node::join(b, *right))
+                } else {
+                    let (a, b) = right.split(at - left_chars);
+                    (Node::join(*left, a), b)
+                }
+            }
+        }
+    }
+
+    pub(super) fn char_at(&self, index: usize) -> char {
+        match self {
+            Node::Leaf { text, .. } => text.chars().nth(index).expect("index checked by caller"),
+            Node::Branch { left, right, .. } => {
+                if index < left.chars() {
+                    left.char_at(index)
+                } else {
+                    right.char_at(index - left.chars())
+                }
+            }
+        }
+    }
+
+    /// Char index just past the `n`th newline (1-based), which must exist
+    pub(super) fn after_newline(&self, n: usize) -> usize {
+        match self {
+            Node::Leaf { text, .. } => {
+                let (i, _) = text
+                    .chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '\n')
+                    .nth(n - 1)
+                    .expect("newline count checked by caller");
+                i + 1
+            }
+            Node::Branch { left, right, .. } => {
+                if n <= left.newlines() {
+                    left.after_newline(n)
+                } else {
+                    left.chars() + right.after_newline(n - left.newlines())
+                }
+            }
+        }
+    }
+
+    /// Number of newlines among the first `index` chars
+    pub(super) fn newlines_before(&self, index: usize) -> usize {
+        match self {
+            Node::Leaf { text, .. } => text.chars().take(index).filter(|&c| c == '\n').count(),
+            Node::Branch { left, right, .. } => {
+                if index <= left.chars() {
+                    left.newlines_before(index)
+                } else {
+                    left.newlines() + right.newlines_before(index - left.chars())
+                }
+            }
+        }
+    }
+
+    /// Appends the chars in `start..end` to `out`, visiting only overlapping subtrees
+    pub(super) fn write_range(&self, start: usize, end: usize, out: &mut String) {
+        if start >= end {
+            return;
+        }
+        match self {
+            Node::Leaf { text, .. } => {
+                out.push_str(&text[char_to_byte(text, start)..char_to_byte(text, end)]);
+            }
+            Node::Branch { left, right, .. } => {
+                let mid = left.chars();
+                left.write_range(start, end.min(mid), out);
+                right.write_range(start.max(mid) - mid, end.saturating_sub(mid), out);
+            }
+        }
+    }
+}
+
+fn char_to_byte(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(byte, _)| byte)
+}
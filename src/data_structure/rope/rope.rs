@@ -0,0 +1,475 @@
+use std::fmt::{self, Display, Formatter, Write};
+use std::ops::Range;
+
+use super::node::Node;
+use crate::Error;
+use crate::visualize::{ToDot, ToMermaid, escape_dot, escape_mermaid};
+
+/// A string stored as a height-balanced binary tree of chunks, so inserting,
+/// deleting, splitting and concatenating anywhere take O(log n) instead of
+/// the O(n) copying a `String` needs.
+///
+/// Indices count chars, not bytes; lines are separated by `'\n'`.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    root: Node,
+}
+
+impl Rope {
+    /// Creates an empty rope
+    pub fn new() -> Rope {
+        Rope {
+            root: Node::leaf(String::new()),
+        }
+    }
+
+    /// Returns the number of chars
+    pub fn len_chars(&self) -> usize {
+        self.root.chars()
+    }
+
+    /// Returns the number of lines, which is one more than the number of newlines
+    pub fn len_lines(&self) -> usize {
+        self.root.newlines() + 1
+    }
+
+    /// Checks if the rope is empty
+    pub fn is_empty(&self) -> bool {
+        self.len_chars() == 0
+    }
+
+    /// Returns the height of the chunk tree, which stays O(log n)
+    pub fn height(&self) -> usize {
+        self.root.height()
+    }
+
+    /// Inserts `text` before the char at `index`. Panics if `index > len_chars()`
+    pub fn insert(&mut self, index: usize, text: &str) {
+        // Checked before taking the root, so a panic leaves the rope intact
+        assert!(index <= self.len_chars(), "insert index out of bounds");
+        let (left, right) = self.take().split(index);
+        self.root = Node::join(Node::join(left.root, Node::from_str(text)), right.root);
+    }
+
+    /// Removes the chars in `range`. Panics if the range is out of bounds
+    pub fn delete(&mut self, range: Range<usize>) {
+        // Checked before taking the root, so a panic leaves the rope intact
+        if let Err(e) = self.check_range(&range) {
+            panic!("{e}");
+        }
+        let (left, rest) = self.take().split(range.start);
+        let (_, right) = rest.split(range.end - range.start);
+        self.root = Node::join(left.root, right.root);
+    }
+
+    /// Inserts `text` before the char at `index`, or fails if `index > len_chars()`
+    pub fn try_insert(&mut self, index: usize, text: &str) -> Result<(), Error> {
+        if index > self.len_chars() {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len_chars(),
+            });
+        }
+        self.insert(index, text);
+        Ok(())
+    }
+
+    /// Removes the chars in `range`, or fails if the range is out of bounds
+    pub fn try_delete(&mut self, range: Range<usize>) -> Result<(), Error> {
+        self.check_range(&range)?;
+        self.delete(range);
+        Ok(())
+    }
+
+    /// Appends `other` to the end of this rope
+    pub fn concat(self, other: Rope) -> Rope {
+        Rope {
+            root: Node::join(self.root, other.root),
+        }
+    }
+
+    /// Splits into the first `index` chars and the rest. Panics if `index > len_chars()`
+    pub fn split(self, index: usize) -> (Rope, Rope) {
+        assert!(index <= self.len_chars(), "split index out of bounds");
+        let (left, right) = self.root.split(index);
+        (Rope { root: left }, Rope { root: right })
+    }
+
+    /// Returns the char at `index`, or None if out of bounds
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        (index < self.len_chars()).then(|| self.root.char_at(index))
+    }
+
+    /// Returns the chars in `range` as a `String`. Panics if the range is out of bounds
+    pub fn slice(&self, range: Range<usize>) -> String {
+        assert!(
+            range.start <= range.end && range.end <= self.len_chars(),
+            "slice range out of bounds"
+        );
+        let mut out = String::new();
+        self.root.write_range(range.start, range.end, &mut out);
+        out
+    }
+
+    /// Returns the chars in `range` as a `String`, or fails if the range is out of bounds
+    pub fn try_slice(&self, range: Range<usize>) -> Result<String, Error> {
+        self.check_range(&range)?;
+        Ok(self.slice(range))
+    }
+
+    fn check_range(&self, range: &Range<usize>) -> Result<(), Error> {
+        let len = self.len_chars();
+        if range.start <= range.end && range.end <= len {
+            Ok(())
+        } else {
+            Err(Error::InvalidRange {
+                start: range.start,
+                end: range.end,
+                len,
+            })
+        }
+    }
+
+    /// Returns the char index at which line `line` starts. Panics if `line >= len_lines()`
+    pub fn line_to_char(&self, line: usize) -> usize {
+        assert!(line < self.len_lines(), "line index out of bounds");
+        if line == 0 {
+            0
+        } else {
+            self.root.after_newline(line)
+        }
+    }
+
+    /// Returns the line containing the char at `index`. Panics if `index > len_chars()`
+    pub fn char_to_line(&self, index: usize) -> usize {
+        assert!(index <= self.len_chars(), "char index out of bounds");
+        self.root.newlines_before(index)
+    }
+
+    /// Returns line `line` without its trailing newline. Panics if `line >= len_lines()`
+    pub fn line(&self, line: usize) -> String {
+        let start = self.line_to_char(line);
+        let end = if line + 1 < self.len_lines() {
+            self.line_to_char(line + 1) - 1
+        } else {
+            self.len_chars()
+        };
+        self.slice(start..end)
+    }
+
+    /// Returns an iterator over the text chunks, in order
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            stack: vec![&self.root],
+        }
+    }
+
+    /// Returns an iterator over the chars
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chunks().flat_map(str::chars)
+    }
+
+    /// Rebuilds the tree as perfectly balanced with full chunks.
+    ///
+    /// Edits already keep the height logarithmic, but many small edits can
+    /// leave short chunks behind; rebalancing packs them back together.
+    pub fn rebalance(&mut self) {
+        self.root = Node::from_str(&self.to_string());
+    }
+
+    fn take(&mut self) -> Rope {
+        std::mem::take(self)
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Rope::new()
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Self {
+        Rope {
+            root: Node::from_str(text),
+        }
+    }
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToDot for Rope {
+    /// Branches labelled with their char count, leaves with their text
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph rope {\n");
+        let mut next_id = 0;
+        self.root.walk(&mut next_id, None, &mut |id, parent, node| {
+            let (shape, label) = match node {
+                Node::Leaf { text, .. } => ("box", escape_dot(text)),
+                Node::Branch { chars, .. } => ("circle", chars.to_string()),
+            };
+            writeln!(dot, "    {id} [shape={shape}, label=\"{label}\"];").unwrap();
+            if let Some(parent) = parent {
+                writeln!(dot, "    {parent} -> {id};").unwrap();
+            }
+        });
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl ToMermaid for Rope {
+    fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart TD\n");
+        let mut next_id = 0;
+        self.root.walk(&mut next_id, None, &mut |id, parent, node| {
+            match node {
+                Node::Leaf { text, .. } => {
+                    writeln!(mermaid, "    n{id}[\"{}\"]", escape_mermaid(text)).unwrap()
+                }
+                Node::Branch { chars, .. } => {
+                    writeln!(mermaid, "    n{id}((\"{chars}\"))").unwrap()
+                }
+            }
+            if let Some(parent) = parent {
+                writeln!(mermaid, "    n{parent} --> n{id}").unwrap();
+            }
+        });
+        mermaid
+    }
+}
+
+/// Iterator over the chunks of a `Rope`, created by `Rope::chunks`
+pub struct Chunks<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match node {
+                Node::Leaf { text, .. } if !text.is_empty() => return Some(text),
+                Node::Leaf { .. } => {}
+                Node::Branch { left, right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::node::MAX_LEAF;
+    use super::Rope;
+    use crate::Error;
+    use crate::visualize::{ToDot, ToMermaid};
+    use proptest::prelude::*;
+
+    fn sample(lines: usize) -> String {
+        (0..lines).map(|i| format!("line {i} ünïcødé\n")).collect()
+    }
+
+    /// AVL height bound: about 1.44 * log2(leaves + 2)
+    fn assert_balanced(rope: &Rope) {
+        let leaves = rope.chunks().count().max(1) as f64;
+        let bound = 1.45 * (leaves + 2.0).log2() + 1.0;
+        assert!(
+            (rope.height() as f64) <= bound,
+            "height {} for {leaves} leaves",
+            rope.height()
+        );
+    }
+
+    #[test]
+    fn builds_from_str() {
+        let text = sample(1000);
+        let rope = Rope::from(text.as_str());
+        assert_eq!(rope.to_string(), text);
+        assert_eq!(rope.len_chars(), text.chars().count());
+        assert_eq!(rope.len_lines(), 1001);
+        assert!(rope.chunks().all(|c| c.len() <= MAX_LEAF));
+        assert_balanced(&rope);
+        assert!(Rope::new().is_empty());
+        assert_eq!(Rope::new().len_lines(), 1);
+    }
+
+    #[cfg(feature = "algorithm")]
+    #[test]
+    fn edits_match_string() {
+        use crate::algorithm::random::{Rng, XorShift64Star};
+
+        let mut rope = Rope::from(sample(300).as_str());
+        let mut expected: Vec<char> = sample(300).chars().collect();
+        let mut rng = XorShift64Star::seed_from_u64(0xDEAD_BEEF);
+        let mut next = |bound: usize| rng.gen_index(bound + 1);
+        for round in 0..500 {
+            if round % 3 == 2 {
+                let start = next(expected.len());
+                let end = start + next(expected.len() - start).min(40);
+                rope.delete(start..end);
+                expected.drain(start..end);
+            } else {
+                let at = next(expected.len());
+                let text = format!("<{round}é>");
+                rope.insert(at, &text);
+                expected.splice(at..at, text.chars());
+            }
+        }
+        assert_eq!(rope.to_string(), expected.iter().collect::<String>());
+        assert_eq!(rope.len_chars(), expected.len());
+        assert_balanced(&rope);
+
+        rope.rebalance();
+        assert_eq!(rope.to_string(), expected.iter().collect::<String>());
+        assert_balanced(&rope);
+    }
+
+    #[test]
+    fn split_and_concat_round_trip() {
+        let text = sample(500);
+        for at in [0, 1, 999, 5000, text.chars().count()] {
+            let (left, right) = Rope::from(text.as_str()).split(at);
+            assert_eq!(left.len_chars(), at);
+            assert_balanced(&left);
+            assert_balanced(&right);
+            let joined = left.concat(right);
+            assert_eq!(joined.to_string(), text);
+            assert_balanced(&joined);
+        }
+        // Very different heights still join into a balanced tree
+        let tall = Rope::from(sample(5000).as_str());
+        let joined = Rope::from("x").concat(tall);
+        assert_balanced(&joined);
+    }
+
+    #[test]
+    fn indexes_chars_and_lines() {
+        let text = sample(400);
+        let rope = Rope::from(text.as_str());
+        let chars: Vec<char> = text.chars().collect();
+        for i in [0, 7, 100, 4321, chars.len() - 1] {
+            assert_eq!(rope.char_at(i), Some(chars[i]));
+        }
+        assert_eq!(rope.char_at(chars.len()), None);
+        assert_eq!(rope.line(0), "line 0 ünïcødé");
+        assert_eq!(rope.line(250), "line 250 ünïcødé");
+        assert_eq!(rope.line(400), "");
+        let start = rope.line_to_char(250);
+        assert_eq!(rope.char_to_line(start), 250);
+        assert_eq!(rope.char_to_line(start - 1), 249);
+        assert_eq!(rope.slice(start..start + 8), "line 250");
+    }
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        /// Indices are taken modulo the length plus one
+        Insert(usize, String),
+        Delete(usize, usize),
+        SplitAndConcat(usize),
+        Rebalance,
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            (any::<usize>(), "[a-cé\n]{0,300}").prop_map(|(i, s)| Op::Insert(i, s)),
+            (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::Delete(a, b)),
+            any::<usize>().prop_map(Op::SplitAndConcat),
+            Just(Op::Rebalance),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn matches_string_model(ops in prop::collection::vec(op(), 0..40)) {
+            let mut rope = Rope::new();
+            let mut model: Vec<char> = Vec::new();
+            for op in ops {
+                let len = model.len();
+                match op {
+                    Op::Insert(i, text) => {
+                        let i = i % (len + 1);
+                        rope.insert(i, &text);
+                        model.splice(i..i, text.chars());
+                    }
+                    Op::Delete(a, b) => {
+                        let (a, b) = (a % (len + 1), b % (len + 1));
+                        let range = a.min(b)..a.max(b);
+                        rope.delete(range.clone());
+                        model.drain(range);
+                    }
+                    Op::SplitAndConcat(i) => {
+                        let (left, right) = rope.split(i % (len + 1));
+                        rope = left.concat(right);
+                    }
+                    Op::Rebalance => rope.rebalance(),
+                }
+                let text: String = model.iter().collect();
+                prop_assert_eq!(rope.to_string(), text.clone());
+                prop_assert_eq!(rope.len_chars(), model.len());
+                prop_assert_eq!(rope.len_lines(), text.split('\n').count());
+            }
+        }
+    }
+
+    #[test]
+    fn renders_branches_and_leaves() {
+        assert_eq!(
+            Rope::from("hi\n").to_dot(),
+            "digraph rope {\n    0 [shape=box, label=\"hi\\n\"];\n}\n"
+        );
+        let rope = Rope::from("x".repeat(MAX_LEAF + 1).as_str());
+        let dot = rope.to_dot();
+        assert!(dot.contains(&format!("0 [shape=circle, label=\"{}\"];", MAX_LEAF + 1)));
+        assert!(dot.contains("0 -> 1;") && dot.contains("0 -> 2;"));
+        let mermaid = rope.to_mermaid();
+        assert!(mermaid.starts_with(&format!("flowchart TD\n    n0((\"{}\"))\n", MAX_LEAF + 1)));
+        assert!(mermaid.contains("n0 --> n1") && mermaid.contains("n0 --> n2"));
+    }
+
+    #[test]
+    fn try_variants_reject_bad_positions() {
+        let mut rope = Rope::from("héllo");
+        assert_eq!(
+            rope.try_insert(6, "!"),
+            Err(Error::IndexOutOfBounds { index: 6, len: 5 })
+        );
+        assert_eq!(rope.try_insert(5, "!"), Ok(()));
+        assert_eq!(rope.try_slice(1..3).as_deref(), Ok("él"));
+        let reversed = Error::InvalidRange {
+            start: 3,
+            end: 1,
+            len: 6,
+        };
+        let (start, end) = (3, 1);
+        assert_eq!(rope.try_slice(start..end), Err(reversed.clone()));
+        assert_eq!(rope.try_delete(start..end), Err(reversed));
+        assert!(rope.try_delete(4..7).is_err());
+        assert_eq!(rope.try_delete(0..1), Ok(()));
+        assert_eq!(rope.to_string(), "éllo!");
+    }
+
+    #[test]
+    fn failed_edits_leave_the_rope_intact() {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+
+        let mut rope = Rope::from("héllo");
+        assert!(catch_unwind(AssertUnwindSafe(|| rope.insert(6, "!"))).is_err());
+        assert_eq!(rope.to_string(), "héllo");
+        assert!(catch_unwind(AssertUnwindSafe(|| rope.delete(2..9))).is_err());
+        assert_eq!(rope.to_string(), "héllo");
+        rope.delete(1..2);
+        assert_eq!(rope.to_string(), "hllo");
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "algorithm")]
+use crate::algorithm::{
+    compression::{ArithmeticError, RleError},
+    ecc::{DoubleBitError, ReedSolomonError},
+    encoding::{DecodeError, VarintError},
+    regex::RegexError,
+    sort::SortError,
+};
+#[cfg(feature = "concurrency")]
+use crate::concurrency::{RecvError, SendError, TryRecvError};
+#[cfg(feature = "language")]
+use crate::language::{
+    brainfuck::BrainfuckError,
+    expr::{EvalError, ParseError},
+    grammar::GrammarError,
+    lambda::LambdaError,
+    vm::{AssembleError, VmError},
+};
+#[cfg(feature = "os")]
+use crate::os::{deadlock::RequestError, memory::MemoryError, paging::PagingError};
+
+/// Declares `Error` with the shared variants below plus one variant wrapping
+/// each module's own error type, converted with `From` so `?` works across
+/// modules. Wrapped errors display as themselves.
+macro_rules! error {
+    ($($(#[$doc:meta])* $feature:literal $variant:ident($ty:ty),)*) => {
+        /// Any failure reported by the crate
+        #[derive(Debug, Clone, PartialEq)]
+        #[non_exhaustive]
+        pub enum Error {
+            /// A position past the end of a structure holding `len` items
+            IndexOutOfBounds { index: usize, len: usize },
+            /// A range that is reversed or ends past `len`
+            InvalidRange { start: usize, end: usize, len: usize },
+            /// There was nothing to take, but more may arrive
+            Empty,
+            /// The other side of a channel is gone
+            Disconnected,
+            $($(#[$doc])* #[cfg(feature = $feature)] $variant($ty),)*
+        }
+
+        impl Display for Error {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                match self {
+                    Error::IndexOutOfBounds { index, len } => {
+                        write!(f, "index {index} out of bounds for length {len}")
+                    }
+                    Error::InvalidRange { start, end, len } => {
+                        write!(f, "range {start}..{end} invalid for length {len}")
+                    }
+                    Error::Empty => write!(f, "nothing to take yet"),
+                    Error::Disconnected => write!(f, "the other side is disconnected"),
+                    $(#[cfg(feature = $feature)] Error::$variant(e) => e.fmt(f),)*
+                }
+            }
+        }
+
+        $(
+            #[cfg(feature = $feature)]
+            impl From<$ty> for Error {
+                fn from(e: $ty) -> Error {
+                    Error::$variant(e)
+                }
+            }
+        )*
+    };
+}
+
+error! {
+    "algorithm" Arithmetic(ArithmeticError),
+    "algorithm" Rle(RleError),
+    /// A Hamming SECDED codeword with two flipped bits
+    "algorithm" DoubleBit(DoubleBitError),
+    "algorithm" ReedSolomon(ReedSolomonError),
+    "algorithm" Decode(DecodeError),
+    "algorithm" Varint(VarintError),
+    "algorithm" Regex(RegexError),
+    "algorithm" Sort(SortError),
+    "language" Brainfuck(BrainfuckError),
+    /// An arithmetic expression that failed to parse
+    "language" Parse(ParseError),
+    "language" Eval(EvalError),
+    "language" Grammar(GrammarError),
+    "language" Lambda(LambdaError),
+    "language" Assemble(AssembleError),
+    "language" Vm(VmError),
+    /// A resource request the banker refused
+    "os" Request(RequestError),
+    "os" Memory(MemoryError),
+    "os" Paging(PagingError),
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(feature = "concurrency")]
+impl From<RecvError> for Error {
+    fn from(_: RecvError) -> Error {
+        Error::Disconnected
+    }
+}
+
+#[cfg(feature = "concurrency")]
+impl From<TryRecvError> for Error {
+    fn from(e: TryRecvError) -> Error {
+        match e {
+            TryRecvError::Empty => Error::Empty,
+            TryRecvError::Disconnected => Error::Disconnected,
+        }
+    }
+}
+
+/// Drops the unsent value
+#[cfg(feature = "concurrency")]
+impl<T> From<SendError<T>> for Error {
+    fn from(_: SendError<T>) -> Error {
+        Error::Disconnected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn displays_shared_variants() {
+        let e = Error::IndexOutOfBounds { index: 5, len: 3 };
+        assert_eq!(e.to_string(), "index 5 out of bounds for length 3");
+        let e = Error::InvalidRange {
+            start: 4,
+            end: 2,
+            len: 9,
+        };
+        assert_eq!(e.to_string(), "range 4..2 invalid for length 9");
+    }
+
+    #[cfg(feature = "algorithm")]
+    #[test]
+    fn wraps_module_errors_transparently() {
+        use crate::algorithm::regex::Regex;
+
+        fn compile(pattern: &str) -> Result<Regex, Error> {
+            Ok(Regex::new(pattern)?)
+        }
+        let e = compile("(a").unwrap_err();
+        let Error::Regex(inner) = &e else {
+            panic!("expected a regex error, got {e:?}");
+        };
+        assert_eq!(e.to_string(), inner.to_string());
+    }
+
+    #[cfg(feature = "concurrency")]
+    #[test]
+    fn channel_errors_become_disconnected() {
+        use crate::concurrency::channel;
+
+        fn relay() -> Result<i32, Error> {
+            let (tx, rx) = channel();
+            tx.send(1)?;
+            drop(tx);
+            let first = rx.recv()?;
+            rx.recv()?;
+            Ok(first)
+        }
+        assert_eq!(relay(), Err(Error::Disconnected));
+    }
+}
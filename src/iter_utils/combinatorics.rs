@@ -0,0 +1,187 @@
+/// See `IterUtils::cartesian_product`
+#[derive(Debug, Clone)]
+pub struct CartesianProduct<I: Iterator, J> {
+    left: I,
+    right: J,
+    right_start: J,
+    current: Option<I::Item>,
+}
+
+impl<I: Iterator, J: Clone> CartesianProduct<I, J> {
+    pub(super) fn new(mut left: I, right: J) -> CartesianProduct<I, J> {
+        CartesianProduct {
+            current: left.next(),
+            left,
+            right_start: right.clone(),
+            right,
+        }
+    }
+}
+
+impl<I: Iterator, J> Iterator for CartesianProduct<I, J>
+where
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<(I::Item, J::Item)> {
+        loop {
+            let current = self.current.as_ref()?;
+            if let Some(b) = self.right.next() {
+                return Some((current.clone(), b));
+            }
+            self.current = self.left.next();
+            self.right = self.right_start.clone();
+        }
+    }
+}
+
+/// See `IterUtils::permutations`
+#[derive(Debug, Clone)]
+pub struct Permutations<T> {
+    pool: Vec<T>,
+    indices: Vec<usize>,
+    /// `cycles[i]` counts the choices left for position `i` before it resets,
+    /// as in Python's `itertools.permutations`
+    cycles: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<T> Permutations<T> {
+    pub(super) fn new(pool: Vec<T>, k: usize) -> Permutations<T> {
+        let n = pool.len();
+        Permutations {
+            indices: (0..n).collect(),
+            cycles: (0..k.min(n)).map(|i| n - i).collect(),
+            done: k > n,
+            started: false,
+            pool,
+        }
+    }
+
+    /// Steps `indices` to the next k-permutation, or returns false after the last
+    fn advance(&mut self) -> bool {
+        let n = self.pool.len();
+        for i in (0..self.cycles.len()).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                self.indices[i..].rotate_left(1);
+                self.cycles[i] = n - i;
+            } else {
+                let j = n - self.cycles[i];
+                self.indices.swap(i, j);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        if self.started && !self.advance() {
+            self.done = true;
+            return None;
+        }
+        self.started = true;
+        let k = self.cycles.len();
+        Some(
+            self.indices[..k]
+                .iter()
+                .map(|&i| self.pool[i].clone())
+                .collect(),
+        )
+    }
+}
+
+/// See `IterUtils::powerset`
+#[derive(Debug, Clone)]
+pub struct Powerset<T> {
+    pool: Vec<T>,
+    next_mask: u64,
+    end: u64,
+}
+
+impl<T> Powerset<T> {
+    pub(super) fn new(pool: Vec<T>) -> Powerset<T> {
+        assert!(
+            pool.len() < 64,
+            "powerset of {} items is too large",
+            pool.len()
+        );
+        Powerset {
+            end: 1 << pool.len(),
+            next_mask: 0,
+            pool,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Powerset<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.next_mask == self.end {
+            return None;
+        }
+        let mask = self.next_mask;
+        self.next_mask += 1;
+        Some(
+            self.pool
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| mask >> i & 1 == 1)
+                .map(|(_, item)| item.clone())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::iter_utils::IterUtils;
+
+    #[test]
+    fn cartesian_product_replays_the_right_side() {
+        let pairs: Vec<(i32, char)> = (1..=2).cartesian_product("ab".chars()).collect();
+        assert_eq!(pairs, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+        assert_eq!((0..3).cartesian_product(0..0).count(), 0);
+    }
+
+    #[test]
+    fn permutations_are_lexicographic_and_complete() {
+        let perms: Vec<Vec<i32>> = (1..=3).permutations(2).collect();
+        assert_eq!(
+            perms,
+            [[1, 2], [1, 3], [2, 1], [2, 3], [3, 1], [3, 2]].map(Vec::from)
+        );
+        for n in 0..6 {
+            for k in 0..=n + 1 {
+                let all: Vec<Vec<usize>> = (0..n).permutations(k).collect();
+                let expected = if k > n { 0 } else { (n - k + 1..=n).product() };
+                assert_eq!(all.len(), expected, "n={n} k={k}");
+                assert_eq!(all.iter().collect::<BTreeSet<_>>().len(), all.len());
+                assert!(all.windows(2).all(|w| w[0] < w[1]), "n={n} k={k}");
+            }
+        }
+    }
+
+    #[test]
+    fn powerset_counts_in_binary() {
+        let subsets: Vec<Vec<char>> = "abc".chars().powerset().collect();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(subsets[0], Vec::<char>::new());
+        assert_eq!(subsets[5], ['a', 'c']);
+        assert_eq!(subsets[7], ['a', 'b', 'c']);
+        assert_eq!((0..0).powerset().collect::<Vec<Vec<i32>>>(), [Vec::new()]);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::VecDeque;
+
+/// See `IterUtils::chunks`
+#[derive(Debug, Clone)]
+pub struct Chunks<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Chunks<I> {
+    pub(super) fn new(iter: I, size: usize) -> Chunks<I> {
+        assert!(size > 0, "chunk size must be positive");
+        Chunks { iter, size }
+    }
+}
+
+impl<I: Iterator> Iterator for Chunks<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+        (!chunk.is_empty()).then_some(chunk)
+    }
+}
+
+/// See `IterUtils::windows`
+#[derive(Debug, Clone)]
+pub struct Windows<I: Iterator> {
+    iter: I,
+    size: usize,
+    window: VecDeque<I::Item>,
+}
+
+impl<I: Iterator> Windows<I> {
+    pub(super) fn new(iter: I, size: usize) -> Windows<I> {
+        assert!(size > 0, "window size must be positive");
+        Windows {
+            iter,
+            size,
+            window: VecDeque::with_capacity(size),
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Windows<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+        Some(self.window.iter().cloned().collect())
+    }
+}
+
+/// See `IterUtils::run_length`
+#[derive(Debug, Clone)]
+pub struct RunLength<I: Iterator> {
+    iter: I,
+    /// The first item of the next run, already pulled from `iter`
+    pending: Option<I::Item>,
+}
+
+impl<I: Iterator> RunLength<I> {
+    pub(super) fn new(mut iter: I) -> RunLength<I> {
+        RunLength {
+            pending: iter.next(),
+            iter,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for RunLength<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<(I::Item, usize)> {
+        let item = self.pending.take()?;
+        let mut count = 1;
+        for next in self.iter.by_ref() {
+            if next != item {
+                self.pending = Some(next);
+                break;
+            }
+            count += 1;
+        }
+        Some((item, count))
+    }
+}
+
+/// See `IterUtils::dedup_by`
+#[derive(Debug, Clone)]
+pub struct DedupBy<I: Iterator, F> {
+    iter: I,
+    same: F,
+    /// The next item to yield, already known not to duplicate the one before
+    pending: Option<I::Item>,
+}
+
+impl<I: Iterator, F> DedupBy<I, F> {
+    pub(super) fn new(mut iter: I, same: F) -> DedupBy<I, F> {
+        DedupBy {
+            pending: iter.next(),
+            iter,
+            same,
+        }
+    }
+}
+
+impl<I: Iterator, F> Iterator for DedupBy<I, F>
+where
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let kept = self.pending.take()?;
+        self.pending = self.iter.by_ref().find(|item| !(self.same)(&kept, item));
+        Some(kept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iter_utils::IterUtils;
+
+    #[test]
+    fn chunks_keep_the_short_tail() {
+        let chunks: Vec<Vec<i32>> = (1..=7).chunks(3).collect();
+        assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+        assert_eq!((0..0).chunks(2).count(), 0);
+    }
+
+    #[test]
+    fn windows_overlap_and_need_a_full_window() {
+        let windows: Vec<Vec<char>> = "abcd".chars().windows(2).collect();
+        assert_eq!(windows, [vec!['a', 'b'], vec!['b', 'c'], vec!['c', 'd']]);
+        assert_eq!((0..2).windows(3).count(), 0);
+        assert_eq!((0..5).windows(5).count(), 1);
+    }
+
+    #[test]
+    fn run_length_matches_rle() {
+        let runs: Vec<(char, usize)> = "aaabccdddd".chars().run_length().collect();
+        assert_eq!(runs, [('a', 3), ('b', 1), ('c', 2), ('d', 4)]);
+    }
+
+    #[test]
+    fn dedup_by_compares_with_the_last_kept_item() {
+        // Within 2 of the last kept value counts as a duplicate
+        let kept: Vec<i32> = [1i32, 2, 3, 4, 10, 11, 13]
+            .into_iter()
+            .dedup_by(|a, b| (a - b).abs() <= 2)
+            .collect();
+        assert_eq!(kept, [1, 4, 10, 13]);
+        let words: Vec<&str> = ["a", "A", "b", "B", "a"]
+            .into_iter()
+            .dedup_by(|a, b| a.eq_ignore_ascii_case(b))
+            .collect();
+        assert_eq!(words, ["a", "b", "a"]);
+    }
+}
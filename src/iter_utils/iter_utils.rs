@@ -0,0 +1,66 @@
+use super::{CartesianProduct, Chunks, DedupBy, Permutations, Powerset, RunLength, Windows};
+
+/// Extra adaptors for any iterator, brought in with `use iter_utils::IterUtils`
+pub trait IterUtils: Iterator + Sized {
+    /// Yields the items in `Vec`s of `size`, the last one possibly shorter.
+    /// Panics if `size` is 0
+    fn chunks(self, size: usize) -> Chunks<Self> {
+        Chunks::new(self, size)
+    }
+
+    /// Yields every run of `size` consecutive items, overlapping. Panics if
+    /// `size` is 0
+    fn windows(self, size: usize) -> Windows<Self>
+    where
+        Self::Item: Clone,
+    {
+        Windows::new(self, size)
+    }
+
+    /// Collapses runs of equal items into `(item, run length)`
+    fn run_length(self) -> RunLength<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        RunLength::new(self)
+    }
+
+    /// Drops each item `same(kept, item)` says duplicates the last one kept
+    fn dedup_by<F>(self, same: F) -> DedupBy<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy::new(self, same)
+    }
+
+    /// Pairs every item with every item of `other`, which is replayed for
+    /// each item of `self`
+    fn cartesian_product<J>(self, other: J) -> CartesianProduct<Self, J::IntoIter>
+    where
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        CartesianProduct::new(self, other.into_iter())
+    }
+
+    /// Yields every ordering of `k` distinct positions, in lexicographic
+    /// order of the positions
+    fn permutations(self, k: usize) -> Permutations<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        Permutations::new(self.collect(), k)
+    }
+
+    /// Yields every subset, counting in binary: subset `m` holds the items
+    /// whose position is a set bit of `m`. Panics past 63 items
+    fn powerset(self) -> Powerset<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        Powerset::new(self.collect())
+    }
+}
+
+impl<I: Iterator> IterUtils for I {}
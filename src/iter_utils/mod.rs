@@ -0,0 +1,7 @@
+mod combinatorics;
+mod grouping;
+mod iter_utils;
+
+pub use self::combinatorics::{CartesianProduct, Permutations, Powerset};
+pub use self::grouping::{Chunks, DedupBy, RunLength, Windows};
+pub use self::iter_utils::IterUtils;
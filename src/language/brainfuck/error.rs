@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons a Brainfuck program fails to load or run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrainfuckError {
+    /// A '[' without its ']', at this byte offset of the source
+    UnmatchedOpen(usize),
+    /// A ']' without its '[', at this byte offset of the source
+    UnmatchedClose(usize),
+    /// The data pointer moved past either end of the tape
+    PointerOutOfBounds,
+}
+
+impl Display for BrainfuckError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BrainfuckError::UnmatchedOpen(at) => write!(f, "unmatched '[' at {at}"),
+            BrainfuckError::UnmatchedClose(at) => write!(f, "unmatched ']' at {at}"),
+            BrainfuckError::PointerOutOfBounds => write!(f, "data pointer moved off the tape"),
+        }
+    }
+}
+
+impl Error for BrainfuckError {}
@@ -0,0 +1,191 @@
+use super::{BrainfuckError, Op, Program};
+
+/// A tape of byte cells and a data pointer that runs Brainfuck programs.
+///
+/// Cells wrap on overflow, the pointer may not leave the tape, and reading
+/// at the end of input leaves the cell unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    tape: Vec<u8>,
+    pointer: usize,
+}
+
+impl Default for Machine {
+    /// The classic 30000-cell tape
+    fn default() -> Self {
+        Machine::new(30_000)
+    }
+}
+
+impl Machine {
+    /// A machine with `tape_size` zeroed cells and the pointer on the first
+    pub fn new(tape_size: usize) -> Machine {
+        assert!(tape_size > 0, "the tape needs at least one cell");
+        Machine {
+            tape: vec![0; tape_size],
+            pointer: 0,
+        }
+    }
+
+    pub fn tape(&self) -> &[u8] {
+        &self.tape
+    }
+
+    pub fn pointer(&self) -> usize {
+        self.pointer
+    }
+
+    /// Runs `program`, pulling input bytes from `input` (None at the end) and
+    /// handing each output byte to `output`
+    pub fn run(
+        &mut self,
+        program: &Program,
+        mut input: impl FnMut() -> Option<u8>,
+        mut output: impl FnMut(u8),
+    ) -> Result<(), BrainfuckError> {
+        let ops = program.ops();
+        let mut pc = 0;
+        while pc < ops.len() {
+            match ops[pc] {
+                Op::Add(n) => self.tape[self.pointer] = self.tape[self.pointer].wrapping_add(n),
+                Op::Move(step) => self.pointer = self.offset(step)?,
+                Op::Input => {
+                    if let Some(byte) = input() {
+                        self.tape[self.pointer] = byte;
+                    }
+                }
+                Op::Output => output(self.tape[self.pointer]),
+                Op::LoopStart(end) if self.tape[self.pointer] == 0 => pc = end,
+                Op::LoopEnd(start) if self.tape[self.pointer] != 0 => pc = start,
+                Op::LoopStart(_) | Op::LoopEnd(_) => {}
+                Op::Clear => self.tape[self.pointer] = 0,
+                Op::MulAdd(offset, factor) => {
+                    let value = self.tape[self.pointer];
+                    // The loop this replaces never runs on a zero cell, so
+                    // it cannot have gone off the tape
+                    if value != 0 {
+                        let target = self.offset(offset)?;
+                        self.tape[target] =
+                            self.tape[target].wrapping_add(value.wrapping_mul(factor));
+                    }
+                }
+                Op::Scan(step) => {
+                    while self.tape[self.pointer] != 0 {
+                        self.pointer = self.offset(step)?;
+                    }
+                }
+            }
+            pc += 1;
+        }
+        Ok(())
+    }
+
+    /// Runs `program` on a fixed input and collects its output
+    pub fn run_with_input(
+        &mut self,
+        program: &Program,
+        input: &[u8],
+    ) -> Result<Vec<u8>, BrainfuckError> {
+        let mut input = input.iter().copied();
+        let mut output = Vec::new();
+        self.run(program, || input.next(), |byte| output.push(byte))?;
+        Ok(output)
+    }
+
+    fn offset(&self, step: isize) -> Result<usize, BrainfuckError> {
+        self.pointer
+            .checked_add_signed(step)
+            .filter(|&p| p < self.tape.len())
+            .ok_or(BrainfuckError::PointerOutOfBounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Machine;
+    use crate::language::brainfuck::{BrainfuckError, Op, Program};
+
+    const HELLO_WORLD: &str = "++++++++[>++++[>++>+++>+++>+<<<<-]>+>+>->>+[<]<-]>>.>---.+++++++..+++.>>.<-.<.+++.------.--------.>>+.>++.";
+
+    fn run(source: &str, input: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let program = Program::parse(source).unwrap();
+        let plain = Machine::default().run_with_input(&program, input).unwrap();
+        let optimized = Machine::default()
+            .run_with_input(&program.optimize(), input)
+            .unwrap();
+        (plain, optimized)
+    }
+
+    #[test]
+    fn classic_programs() {
+        let (plain, optimized) = run(HELLO_WORLD, b"");
+        assert_eq!(plain, b"Hello World!\n");
+        assert_eq!(optimized, plain);
+        // cat, stopping at a zero byte since end of input leaves the cell alone
+        let (plain, optimized) = run(",[.,]", b"echo\0");
+        assert_eq!(plain, b"echo");
+        assert_eq!(optimized, plain);
+        // Reverse the input: store it, scan back, print back to front
+        let (plain, optimized) = run(">,[>,]<[.<]", b"stressed");
+        assert_eq!(plain, b"desserts");
+        assert_eq!(optimized, plain);
+    }
+
+    #[test]
+    fn optimizer_recognises_idioms() {
+        let ops = |source: &str| Program::parse(source).unwrap().optimize().ops().to_vec();
+        assert_eq!(ops("+++--->>><"), [Op::Move(2)]);
+        assert_eq!(ops("+[-]"), [Op::Add(1), Op::Clear]);
+        assert_eq!(ops("[<]"), [Op::Scan(-1)]);
+        assert_eq!(
+            ops("[->+++>>-<<<]"),
+            [Op::MulAdd(1, 3), Op::MulAdd(3, u8::MAX), Op::Clear]
+        );
+        // Not a counting loop: it moves, or decrements by two
+        assert_eq!(ops("[->+]").len(), 5);
+        assert_eq!(ops("[-->+<]").len(), 6);
+        let program = Program::parse(HELLO_WORLD).unwrap();
+        assert!(program.optimize().ops().len() < program.ops().len() / 2);
+    }
+
+    #[test]
+    fn multiplication_loop() {
+        let (plain, optimized) = run("++++++[>+++++++<-]>.", b"");
+        assert_eq!(plain, b"*");
+        assert_eq!(optimized, plain);
+        // 200 * 2 wraps to 144
+        let (plain, optimized) = run(&format!("{}[>++<-]>.", "+".repeat(200)), b"");
+        assert_eq!(plain, [144]);
+        assert_eq!(optimized, plain);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Program::parse("a[+]]"),
+            Err(BrainfuckError::UnmatchedClose(4))
+        );
+        assert_eq!(
+            Program::parse("+[[-]"),
+            Err(BrainfuckError::UnmatchedOpen(1))
+        );
+        let program = Program::parse("+[>+]").unwrap();
+        let mut machine = Machine::new(8);
+        assert_eq!(
+            machine.run_with_input(&program, b""),
+            Err(BrainfuckError::PointerOutOfBounds)
+        );
+        assert_eq!(machine.pointer(), 7);
+        let mut machine = Machine::new(4);
+        assert_eq!(
+            machine.run_with_input(&Program::parse("<").unwrap().optimize(), b""),
+            Err(BrainfuckError::PointerOutOfBounds)
+        );
+        // Reading past the end leaves the cell alone
+        let mut machine = Machine::new(1);
+        machine
+            .run_with_input(&Program::parse("+++,").unwrap(), b"")
+            .unwrap();
+        assert_eq!(machine.tape(), [3]);
+    }
+}
@@ -0,0 +1,7 @@
+mod error;
+mod machine;
+mod program;
+
+pub use self::error::BrainfuckError;
+pub use self::machine::Machine;
+pub use self::program::{Op, Program};
@@ -0,0 +1,150 @@
+use super::BrainfuckError;
+
+/// An instruction of a loaded program. Plain source maps onto `Add`,
+/// `Move`, `Input`, `Output` and the jumps one char at a time; the rest only
+/// come out of `Program::optimize`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// Adds to the current cell, wrapping
+    Add(u8),
+    /// Moves the data pointer
+    Move(isize),
+    Input,
+    Output,
+    /// Jumps past the matching `LoopEnd` at this index if the cell is zero
+    LoopStart(usize),
+    /// Jumps back past the matching `LoopStart` at this index unless the cell is zero
+    LoopEnd(usize),
+    /// Sets the current cell to zero, for `[-]`
+    Clear,
+    /// Adds the current cell times a factor to the cell at an offset, for
+    /// the body of a loop like `[->++<]`, which is then followed by `Clear`
+    MulAdd(isize, u8),
+    /// Moves by a step until reaching a zero cell, for `[>]` and `[<]`
+    Scan(isize),
+}
+
+/// A Brainfuck program with its brackets matched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    ops: Vec<Op>,
+}
+
+impl Program {
+    /// Loads the source one op per command, ignoring every other char
+    pub fn parse(source: &str) -> Result<Program, BrainfuckError> {
+        let mut ops = Vec::new();
+        let mut offsets = Vec::new();
+        for (offset, c) in source.char_indices() {
+            let op = match c {
+                '+' => Op::Add(1),
+                '-' => Op::Add(u8::MAX),
+                '>' => Op::Move(1),
+                '<' => Op::Move(-1),
+                ',' => Op::Input,
+                '.' => Op::Output,
+                '[' => Op::LoopStart(0),
+                ']' => Op::LoopEnd(0),
+                _ => continue,
+            };
+            ops.push(op);
+            offsets.push(offset);
+        }
+        link_loops(&mut ops).map_err(|error| match error {
+            BrainfuckError::UnmatchedOpen(i) => BrainfuckError::UnmatchedOpen(offsets[i]),
+            BrainfuckError::UnmatchedClose(i) => BrainfuckError::UnmatchedClose(offsets[i]),
+            error => error,
+        })?;
+        Ok(Program { ops })
+    }
+
+    pub fn ops(&self) -> &[Op] {
+        &self.ops
+    }
+
+    /// An equivalent program that merges runs of `+`/`-` and `<`/`>` into
+    /// single ops and replaces common loop idioms with a direct computation:
+    /// `[-]` clears, `[>]` scans, and a loop that returns to its cell after
+    /// subtracting one from it, like `[->+>++<<]`, multiplies into its
+    /// neighbours in one step instead of once per unit of the counter.
+    pub fn optimize(&self) -> Program {
+        let mut ops: Vec<Op> = Vec::new();
+        for &op in &self.ops {
+            match (ops.last_mut(), op) {
+                (Some(Op::Add(a)), Op::Add(b)) => *a = a.wrapping_add(b),
+                (Some(Op::Move(a)), Op::Move(b)) => *a += b,
+                (_, Op::LoopEnd(_)) => {
+                    let start = ops
+                        .iter()
+                        .rposition(|op| matches!(op, Op::LoopStart(_)))
+                        .unwrap();
+                    if let Some(replacement) = simplify_loop(&ops[start + 1..]) {
+                        ops.truncate(start);
+                        ops.extend(replacement);
+                    } else {
+                        ops.push(op);
+                    }
+                }
+                (_, op) => ops.push(op),
+            }
+            if matches!(ops.last(), Some(Op::Add(0) | Op::Move(0))) {
+                ops.pop();
+            }
+        }
+        link_loops(&mut ops).expect("optimizing keeps brackets balanced");
+        Program { ops }
+    }
+}
+
+/// Replaces an innermost loop body built only of adds and moves with the
+/// ops it amounts to, if it is one of the recognised idioms
+fn simplify_loop(body: &[Op]) -> Option<Vec<Op>> {
+    match body {
+        [Op::Add(1 | u8::MAX)] => return Some(vec![Op::Clear]),
+        [Op::Move(step)] => return Some(vec![Op::Scan(*step)]),
+        _ => {}
+    }
+    let mut offset = 0;
+    let mut deltas: Vec<(isize, u8)> = Vec::new();
+    for op in body {
+        match *op {
+            Op::Move(step) => offset += step,
+            Op::Add(n) => match deltas.iter_mut().find(|(at, _)| *at == offset) {
+                Some((_, total)) => *total = total.wrapping_add(n),
+                None => deltas.push((offset, n)),
+            },
+            _ => return None,
+        }
+    }
+    let counter = deltas.iter().find(|(at, _)| *at == 0).map(|&(_, n)| n);
+    if offset != 0 || counter != Some(u8::MAX) {
+        return None;
+    }
+    let mut ops: Vec<Op> = deltas
+        .into_iter()
+        .filter(|&(at, n)| at != 0 && n != 0)
+        .map(|(at, n)| Op::MulAdd(at, n))
+        .collect();
+    ops.push(Op::Clear);
+    Some(ops)
+}
+
+/// Fills in the jump targets, reporting an unmatched bracket by op index
+fn link_loops(ops: &mut [Op]) -> Result<(), BrainfuckError> {
+    let mut open = Vec::new();
+    for i in 0..ops.len() {
+        match ops[i] {
+            Op::LoopStart(_) => open.push(i),
+            Op::LoopEnd(_) => {
+                let start = open.pop().ok_or(BrainfuckError::UnmatchedClose(i))?;
+                ops[start] = Op::LoopStart(i);
+                ops[i] = Op::LoopEnd(start);
+            }
+            _ => {}
+        }
+    }
+    match open.pop() {
+        Some(start) => Err(BrainfuckError::UnmatchedOpen(start)),
+        None => Ok(()),
+    }
+}
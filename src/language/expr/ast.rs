@@ -0,0 +1,183 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use super::parser::Parser;
+use super::{ParseError, Span};
+
+/// The types a value can have
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Type {
+    Int,
+    Bool,
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Int => write!(f, "int"),
+            Type::Bool => write!(f, "bool"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Value {
+    Int(i64),
+    Bool(bool),
+}
+
+impl Value {
+    pub fn type_of(&self) -> Type {
+        match self {
+            Value::Int(_) => Type::Int,
+            Value::Bool(_) => Type::Bool,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{n}"),
+            Value::Bool(b) => write!(f, "{b}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnaryOp {
+    /// `-`, on ints
+    Neg,
+    /// `!`, on bools
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    /// Integer division, rounding toward zero
+    Div,
+    /// The remainder of `Div`
+    Rem,
+    /// `==`, on two values of the same type
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// `&&`, evaluating the right side only if the left is true
+    And,
+    /// `||`, evaluating the right side only if the left is false
+    Or,
+}
+
+impl UnaryOp {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            UnaryOp::Neg => "-",
+            UnaryOp::Not => "!",
+        }
+    }
+}
+
+impl BinaryOp {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Rem => "%",
+            BinaryOp::Eq => "==",
+            BinaryOp::Ne => "!=",
+            BinaryOp::Lt => "<",
+            BinaryOp::Le => "<=",
+            BinaryOp::Gt => ">",
+            BinaryOp::Ge => ">=",
+            BinaryOp::And => "&&",
+            BinaryOp::Or => "||",
+        }
+    }
+}
+
+/// An expression and the source it was parsed from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprKind {
+    Int(i64),
+    Bool(bool),
+    Var(String),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    /// `condition ? then : otherwise`
+    If(Box<Expr>, Box<Expr>, Box<Expr>),
+    /// Stands in for a part of the source that failed to parse
+    Error,
+}
+
+impl Expr {
+    pub fn new(kind: ExprKind, span: Span) -> Expr {
+        Expr { kind, span }
+    }
+
+    /// Parses expressions like `x > 0 && y % 2 == 1 ? -x : x * y` over ints,
+    /// bools and variables, failing with every error found
+    pub fn parse(source: &str) -> Result<Expr, Vec<ParseError>> {
+        match Parser::parse(source) {
+            (expr, errors) if errors.is_empty() => Ok(expr),
+            (_, errors) => Err(errors),
+        }
+    }
+
+    /// Parses as much as it can, returning a tree with `ExprKind::Error`
+    /// nodes where parts are missing alongside the errors, in source order
+    pub fn parse_recovering(source: &str) -> (Expr, Vec<ParseError>) {
+        Parser::parse(source)
+    }
+
+    /// Whether some part of the tree stands in for a parse error
+    pub fn has_errors(&self) -> bool {
+        match &self.kind {
+            ExprKind::Error => true,
+            ExprKind::Int(_) | ExprKind::Bool(_) | ExprKind::Var(_) => false,
+            ExprKind::Unary(_, operand) => operand.has_errors(),
+            ExprKind::Binary(_, left, right) => left.has_errors() || right.has_errors(),
+            ExprKind::If(condition, then, otherwise) => {
+                condition.has_errors() || then.has_errors() || otherwise.has_errors()
+            }
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = Vec<ParseError>;
+
+    fn from_str(source: &str) -> Result<Expr, Vec<ParseError>> {
+        Expr::parse(source)
+    }
+}
+
+/// Prints every operation in parentheses, so the structure is explicit
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ExprKind::Int(n) => write!(f, "{n}"),
+            ExprKind::Bool(b) => write!(f, "{b}"),
+            ExprKind::Var(name) => write!(f, "{name}"),
+            ExprKind::Unary(op, operand) => write!(f, "({}{operand})", op.symbol()),
+            ExprKind::Binary(op, left, right) => write!(f, "({left} {} {right})", op.symbol()),
+            ExprKind::If(condition, then, otherwise) => {
+                write!(f, "({condition} ? {then} : {otherwise})")
+            }
+            ExprKind::Error => write!(f, "<error>"),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use super::{Span, Type};
+
+/// Reasons an expression fails to parse, each with where in the source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A char no token starts with
+    UnexpectedChar(Span, char),
+    /// An integer literal past `i64::MAX`
+    IntegerTooLarge(Span),
+    /// A token that cannot start an expression, or the end of the input
+    ExpectedExpression(Span),
+    /// A '(' without its ')'
+    UnclosedParen(Span),
+    /// A '?' without its ':'
+    MissingColon(Span),
+    /// A token after a complete expression
+    TrailingInput(Span),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::UnexpectedChar(span, _)
+            | ParseError::IntegerTooLarge(span)
+            | ParseError::ExpectedExpression(span)
+            | ParseError::UnclosedParen(span)
+            | ParseError::MissingColon(span)
+            | ParseError::TrailingInput(span) => *span,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedChar(span, found) => write!(f, "unexpected {found:?} at {span}"),
+            ParseError::IntegerTooLarge(span) => write!(f, "integer too large at {span}"),
+            ParseError::ExpectedExpression(span) => write!(f, "expected an expression at {span}"),
+            ParseError::UnclosedParen(span) => write!(f, "unclosed '(' at {span}"),
+            ParseError::MissingColon(span) => write!(f, "'?' at {span} without its ':'"),
+            ParseError::TrailingInput(span) => write!(f, "unexpected input at {span}"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Reasons evaluation fails, each with the span of the expression at fault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// A variable the environment has no value for
+    UnboundVariable(Span, String),
+    /// An operand of the wrong type
+    TypeMismatch {
+        span: Span,
+        expected: Type,
+        found: Type,
+    },
+    DivisionByZero(Span),
+    /// An integer result outside the range of `i64`
+    Overflow(Span),
+    /// A part of the tree that failed to parse
+    Invalid(Span),
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UnboundVariable(span, name) => {
+                write!(f, "unbound variable {name} at {span}")
+            }
+            EvalError::TypeMismatch {
+                span,
+                expected,
+                found,
+            } => write!(f, "expected {expected} but found {found} at {span}"),
+            EvalError::DivisionByZero(span) => write!(f, "division by zero at {span}"),
+            EvalError::Overflow(span) => write!(f, "integer overflow at {span}"),
+            EvalError::Invalid(span) => write!(f, "unparsed expression at {span}"),
+        }
+    }
+}
+
+impl Error for EvalError {}
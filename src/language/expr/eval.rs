@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use super::{BinaryOp, EvalError, Expr, ExprKind, Span, Type, UnaryOp, Value};
+
+impl Expr {
+    /// Evaluates with the variables bound in `env`. `&&`, `||` and `?:`
+    /// evaluate only the operands they need, so `x != 0 && 10 / x > 1` is
+    /// safe for any x
+    pub fn eval(&self, env: &HashMap<String, Value>) -> Result<Value, EvalError> {
+        match &self.kind {
+            ExprKind::Int(n) => Ok(Value::Int(*n)),
+            ExprKind::Bool(b) => Ok(Value::Bool(*b)),
+            ExprKind::Var(name) => env
+                .get(name)
+                .copied()
+                .ok_or_else(|| EvalError::UnboundVariable(self.span, name.clone())),
+            ExprKind::Unary(UnaryOp::Neg, operand) => {
+                let n = operand.eval_int(env)?;
+                n.checked_neg()
+                    .map(Value::Int)
+                    .ok_or(EvalError::Overflow(self.span))
+            }
+            ExprKind::Unary(UnaryOp::Not, operand) => Ok(Value::Bool(!operand.eval_bool(env)?)),
+            ExprKind::Binary(BinaryOp::And, left, right) => {
+                Ok(Value::Bool(left.eval_bool(env)? && right.eval_bool(env)?))
+            }
+            ExprKind::Binary(BinaryOp::Or, left, right) => {
+                Ok(Value::Bool(left.eval_bool(env)? || right.eval_bool(env)?))
+            }
+            ExprKind::Binary(op @ (BinaryOp::Eq | BinaryOp::Ne), left, right) => {
+                let a = left.eval(env)?;
+                let b = right.eval(env)?;
+                if a.type_of() != b.type_of() {
+                    return Err(mismatch(right.span, a.type_of(), b.type_of()));
+                }
+                Ok(Value::Bool((a == b) == (*op == BinaryOp::Eq)))
+            }
+            ExprKind::Binary(op, left, right) => {
+                let a = left.eval_int(env)?;
+                let b = right.eval_int(env)?;
+                let overflow = EvalError::Overflow(self.span);
+                let value = match op {
+                    BinaryOp::Add => Value::Int(a.checked_add(b).ok_or(overflow)?),
+                    BinaryOp::Sub => Value::Int(a.checked_sub(b).ok_or(overflow)?),
+                    BinaryOp::Mul => Value::Int(a.checked_mul(b).ok_or(overflow)?),
+                    BinaryOp::Div | BinaryOp::Rem if b == 0 => {
+                        return Err(EvalError::DivisionByZero(self.span));
+                    }
+                    BinaryOp::Div => Value::Int(a.checked_div(b).ok_or(overflow)?),
+                    BinaryOp::Rem => Value::Int(a.checked_rem(b).ok_or(overflow)?),
+                    BinaryOp::Lt => Value::Bool(a < b),
+                    BinaryOp::Le => Value::Bool(a <= b),
+                    BinaryOp::Gt => Value::Bool(a > b),
+                    BinaryOp::Ge => Value::Bool(a >= b),
+                    BinaryOp::Eq | BinaryOp::Ne | BinaryOp::And | BinaryOp::Or => unreachable!(),
+                };
+                Ok(value)
+            }
+            ExprKind::If(condition, then, otherwise) => {
+                if condition.eval_bool(env)? {
+                    then.eval(env)
+                } else {
+                    otherwise.eval(env)
+                }
+            }
+            ExprKind::Error => Err(EvalError::Invalid(self.span)),
+        }
+    }
+
+    /// The type the expression evaluates to given the types of the
+    /// variables, checking every operand without evaluating anything. Both
+    /// branches of `?:` must have the same type
+    pub fn type_check(&self, env: &HashMap<String, Type>) -> Result<Type, EvalError> {
+        let expect = |expr: &Expr, expected: Type| match expr.type_check(env)? {
+            found if found == expected => Ok(()),
+            found => Err(mismatch(expr.span, expected, found)),
+        };
+        match &self.kind {
+            ExprKind::Int(_) => Ok(Type::Int),
+            ExprKind::Bool(_) => Ok(Type::Bool),
+            ExprKind::Var(name) => env
+                .get(name)
+                .copied()
+                .ok_or_else(|| EvalError::UnboundVariable(self.span, name.clone())),
+            ExprKind::Unary(UnaryOp::Neg, operand) => expect(operand, Type::Int).map(|_| Type::Int),
+            ExprKind::Unary(UnaryOp::Not, operand) => {
+                expect(operand, Type::Bool).map(|_| Type::Bool)
+            }
+            ExprKind::Binary(op, left, right) => match op {
+                BinaryOp::And | BinaryOp::Or => {
+                    expect(left, Type::Bool)?;
+                    expect(right, Type::Bool)?;
+                    Ok(Type::Bool)
+                }
+                BinaryOp::Eq | BinaryOp::Ne => {
+                    expect(right, left.type_check(env)?)?;
+                    Ok(Type::Bool)
+                }
+                BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+                    expect(left, Type::Int)?;
+                    expect(right, Type::Int)?;
+                    Ok(Type::Bool)
+                }
+                BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => {
+                    expect(left, Type::Int)?;
+                    expect(right, Type::Int)?;
+                    Ok(Type::Int)
+                }
+            },
+            ExprKind::If(condition, then, otherwise) => {
+                expect(condition, Type::Bool)?;
+                let ty = then.type_check(env)?;
+                expect(otherwise, ty)?;
+                Ok(ty)
+            }
+            ExprKind::Error => Err(EvalError::Invalid(self.span)),
+        }
+    }
+
+    fn eval_int(&self, env: &HashMap<String, Value>) -> Result<i64, EvalError> {
+        match self.eval(env)? {
+            Value::Int(n) => Ok(n),
+            value => Err(mismatch(self.span, Type::Int, value.type_of())),
+        }
+    }
+
+    fn eval_bool(&self, env: &HashMap<String, Value>) -> Result<bool, EvalError> {
+        match self.eval(env)? {
+            Value::Bool(b) => Ok(b),
+            value => Err(mismatch(self.span, Type::Bool, value.type_of())),
+        }
+    }
+}
+
+fn mismatch(span: Span, expected: Type, found: Type) -> EvalError {
+    EvalError::TypeMismatch {
+        span,
+        expected,
+        found,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::language::expr::{EvalError, Expr, Span, Type, Value};
+
+    fn eval(source: &str, env: &[(&str, Value)]) -> Result<Value, EvalError> {
+        let env = env
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value))
+            .collect();
+        Expr::parse(source).unwrap().eval(&env)
+    }
+
+    #[test]
+    fn arithmetic_and_logic() {
+        let env = [("x", Value::Int(-7)), ("flag", Value::Bool(true))];
+        assert_eq!(eval("1 + 2 * 3 - 4", &env), Ok(Value::Int(3)));
+        assert_eq!(eval("x / 2", &env), Ok(Value::Int(-3)));
+        assert_eq!(eval("x % 2", &env), Ok(Value::Int(-1)));
+        assert_eq!(eval("x < 0 ? -x : x", &env), Ok(Value::Int(7)));
+        assert_eq!(eval("!flag || x == -7", &env), Ok(Value::Bool(true)));
+        assert_eq!(eval("flag == (x >= 0)", &env), Ok(Value::Bool(false)));
+        // Short circuits skip the failing operand
+        assert_eq!(
+            eval("x == 0 || 10 / x < 0", &[("x", Value::Int(0))]),
+            Ok(Value::Bool(true))
+        );
+        assert_eq!(eval("false && y", &[]), Ok(Value::Bool(false)));
+        assert_eq!(eval("true ? 1 : y", &[]), Ok(Value::Int(1)));
+    }
+
+    #[test]
+    fn eval_errors() {
+        assert_eq!(
+            eval("1 + y", &[]),
+            Err(EvalError::UnboundVariable(Span::new(4, 5), "y".to_string()))
+        );
+        assert_eq!(
+            eval("1 + true", &[]),
+            Err(EvalError::TypeMismatch {
+                span: Span::new(4, 8),
+                expected: Type::Int,
+                found: Type::Bool
+            })
+        );
+        assert_eq!(
+            eval("1 / (2 - 2)", &[]),
+            Err(EvalError::DivisionByZero(Span::new(0, 11)))
+        );
+        assert_eq!(
+            eval("9223372036854775807 + 1", &[]),
+            Err(EvalError::Overflow(Span::new(0, 23)))
+        );
+        let (expr, _) = Expr::parse_recovering("1 + ");
+        assert_eq!(
+            expr.eval(&HashMap::new()),
+            Err(EvalError::Invalid(Span::new(4, 4)))
+        );
+    }
+
+    #[test]
+    fn type_check() {
+        let env = HashMap::from([("n".to_string(), Type::Int), ("b".to_string(), Type::Bool)]);
+        let check = |source: &str| Expr::parse(source).unwrap().type_check(&env);
+        assert_eq!(check("n * 2 > 3 && !b"), Ok(Type::Bool));
+        assert_eq!(check("b ? n : 0"), Ok(Type::Int));
+        // Unlike evaluation, checking looks at both branches
+        assert_eq!(
+            check("true ? 1 : false"),
+            Err(EvalError::TypeMismatch {
+                span: Span::new(11, 16),
+                expected: Type::Int,
+                found: Type::Bool
+            })
+        );
+        assert_eq!(
+            check("n == b"),
+            Err(EvalError::TypeMismatch {
+                span: Span::new(5, 6),
+                expected: Type::Int,
+                found: Type::Bool
+            })
+        );
+        assert_eq!(
+            check("m"),
+            Err(EvalError::UnboundVariable(Span::new(0, 1), "m".to_string()))
+        );
+    }
+}
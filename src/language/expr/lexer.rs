@@ -0,0 +1,84 @@
+use crate::language::lexer::{Lexer, Pattern, Position, Span, Token};
+
+use super::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    Int,
+    Ident,
+    True,
+    False,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Bang,
+    EqEq,
+    NotEq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    AndAnd,
+    OrOr,
+    LParen,
+    RParen,
+    Question,
+    Colon,
+    Error,
+    Eof,
+}
+
+const OPERATORS: [(&str, TokenKind); 20] = [
+    ("+", TokenKind::Plus),
+    ("-", TokenKind::Minus),
+    ("*", TokenKind::Star),
+    ("/", TokenKind::Slash),
+    ("%", TokenKind::Percent),
+    ("!", TokenKind::Bang),
+    ("==", TokenKind::EqEq),
+    ("!=", TokenKind::NotEq),
+    ("<", TokenKind::Lt),
+    ("<=", TokenKind::Le),
+    (">", TokenKind::Gt),
+    (">=", TokenKind::Ge),
+    ("&&", TokenKind::AndAnd),
+    ("||", TokenKind::OrOr),
+    ("(", TokenKind::LParen),
+    (")", TokenKind::RParen),
+    ("?", TokenKind::Question),
+    (":", TokenKind::Colon),
+    ("true", TokenKind::True),
+    ("false", TokenKind::False),
+];
+
+/// Splits the source into tokens ending with `Eof`. Each run of chars no
+/// token starts with is reported and left out, so one pass finds every
+/// lexical error
+pub(super) fn tokenize(source: &str) -> (Vec<Token<TokenKind>>, Vec<ParseError>) {
+    let mut lexer = Lexer::new(TokenKind::Error);
+    lexer.add_skip(Pattern::Whitespace);
+    for (text, kind) in OPERATORS {
+        lexer.add_rule(Pattern::exact(text), kind);
+    }
+    lexer.add_rule(Pattern::Integer, TokenKind::Int);
+    lexer.add_rule(Pattern::Identifier, TokenKind::Ident);
+
+    let mut errors = Vec::new();
+    let mut tokens = lexer.tokenize(source);
+    tokens.retain(|token| {
+        if token.kind != TokenKind::Error {
+            return true;
+        }
+        let found = token.text(source).chars().next().unwrap();
+        errors.push(ParseError::UnexpectedChar(token.span, found));
+        false
+    });
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        span: Span::new(source.len(), source.len()),
+        position: Position::of(source, source.len()),
+    });
+    (tokens, errors)
+}
@@ -0,0 +1,9 @@
+mod ast;
+mod error;
+mod eval;
+mod lexer;
+mod parser;
+
+pub use self::ast::{BinaryOp, Expr, ExprKind, Type, UnaryOp, Value};
+pub use self::error::{EvalError, ParseError};
+pub use crate::language::lexer::Span;
@@ -0,0 +1,270 @@
+use crate::language::lexer::Token;
+
+use super::lexer::{TokenKind, tokenize};
+use super::{BinaryOp, Expr, ExprKind, ParseError, Span, UnaryOp};
+
+/// Binding power of the operand of a prefix operator, above every infix one
+const PREFIX_POWER: u8 = 15;
+
+/// Pratt parser over the tokens, with binding powers from loosest to
+/// tightest
+///
+/// ```text
+/// c ? a : b                right associative
+/// ||  &&                   left associative
+/// ==  !=  <  <=  >  >=     left associative
+/// +  -                     left associative
+/// *  /  %                  left associative
+/// -x  !x                   prefix
+/// ```
+///
+/// On a token that cannot start an operand it reports one error for the
+/// whole run of such tokens, skips them and carries on, and where an
+/// operand is missing altogether it puts an `ExprKind::Error` in its place,
+/// so one pass reports every independent mistake.
+pub(super) struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<Token<TokenKind>>,
+    position: usize,
+    errors: Vec<ParseError>,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn parse(source: &'a str) -> (Expr, Vec<ParseError>) {
+        let (tokens, errors) = tokenize(source);
+        let mut parser = Parser {
+            source,
+            tokens,
+            position: 0,
+            errors,
+        };
+        let mut expr = parser.expression(0);
+        // Skip a stray token, like an extra ')', and keep going if an
+        // operator follows it
+        while parser.peek().kind != TokenKind::Eof {
+            let stray = parser.advance();
+            parser.errors.push(ParseError::TrailingInput(stray.span));
+            expr = parser.infix(expr, 0);
+        }
+        parser
+            .errors
+            .sort_by_key(|error| (error.span().start, error.span().end));
+        (expr, parser.errors)
+    }
+
+    fn peek(&self) -> &Token<TokenKind> {
+        &self.tokens[self.position]
+    }
+
+    fn advance(&mut self) -> Token<TokenKind> {
+        let token = self.tokens[self.position].clone();
+        if token.kind != TokenKind::Eof {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn expression(&mut self, min_power: u8) -> Expr {
+        let left = self.prefix();
+        self.infix(left, min_power)
+    }
+
+    fn prefix(&mut self) -> Expr {
+        let mut skipped: Option<Span> = None;
+        let expr = loop {
+            let token = self.peek().clone();
+            let kind = match token.kind {
+                TokenKind::Int => {
+                    let n = token.text(self.source).parse().unwrap_or_else(|_| {
+                        self.errors.push(ParseError::IntegerTooLarge(token.span));
+                        0
+                    });
+                    ExprKind::Int(n)
+                }
+                TokenKind::True => ExprKind::Bool(true),
+                TokenKind::False => ExprKind::Bool(false),
+                TokenKind::Ident => ExprKind::Var(token.text(self.source).to_string()),
+                TokenKind::Minus | TokenKind::Bang => {
+                    self.advance();
+                    let op = match token.kind {
+                        TokenKind::Minus => UnaryOp::Neg,
+                        _ => UnaryOp::Not,
+                    };
+                    let operand = self.expression(PREFIX_POWER);
+                    let span = token.span.to(operand.span);
+                    break Expr::new(ExprKind::Unary(op, Box::new(operand)), span);
+                }
+                TokenKind::LParen => {
+                    self.advance();
+                    let inner = self.expression(0);
+                    let end = if self.peek().kind == TokenKind::RParen {
+                        self.advance().span
+                    } else {
+                        self.errors.push(ParseError::UnclosedParen(token.span));
+                        inner.span
+                    };
+                    break Expr::new(inner.kind, token.span.to(end));
+                }
+                // Closers end the operand that is missing here
+                TokenKind::RParen | TokenKind::Colon | TokenKind::Eof => {
+                    let at = Span::new(token.span.start, token.span.start);
+                    if skipped.is_none() {
+                        self.errors.push(ParseError::ExpectedExpression(token.span));
+                    }
+                    break Expr::new(ExprKind::Error, skipped.unwrap_or(at));
+                }
+                _ => {
+                    self.advance();
+                    skipped = Some(skipped.map_or(token.span, |span| span.to(token.span)));
+                    continue;
+                }
+            };
+            self.advance();
+            break Expr::new(kind, token.span);
+        };
+        if let Some(span) = skipped {
+            self.errors.push(ParseError::ExpectedExpression(span));
+        }
+        expr
+    }
+
+    fn infix(&mut self, mut left: Expr, min_power: u8) -> Expr {
+        loop {
+            let token = self.peek().clone();
+            if token.kind == TokenKind::Question {
+                if 2 < min_power {
+                    return left;
+                }
+                self.advance();
+                let then = self.expression(0);
+                let otherwise = if self.peek().kind == TokenKind::Colon {
+                    self.advance();
+                    self.expression(1)
+                } else {
+                    self.errors.push(ParseError::MissingColon(token.span));
+                    Expr::new(ExprKind::Error, Span::new(then.span.end, then.span.end))
+                };
+                let span = left.span.to(otherwise.span);
+                left = Expr::new(
+                    ExprKind::If(Box::new(left), Box::new(then), Box::new(otherwise)),
+                    span,
+                );
+                continue;
+            }
+            let Some((op, power)) = binary_op(&token.kind) else {
+                return left;
+            };
+            if power < min_power {
+                return left;
+            }
+            self.advance();
+            let right = self.expression(power + 1);
+            let span = left.span.to(right.span);
+            left = Expr::new(ExprKind::Binary(op, Box::new(left), Box::new(right)), span);
+        }
+    }
+}
+
+/// The operator a token stands for between two operands, and its left
+/// binding power. Its right operand binds one tighter, making it left
+/// associative
+fn binary_op(kind: &TokenKind) -> Option<(BinaryOp, u8)> {
+    let op = match kind {
+        TokenKind::OrOr => (BinaryOp::Or, 3),
+        TokenKind::AndAnd => (BinaryOp::And, 5),
+        TokenKind::EqEq => (BinaryOp::Eq, 7),
+        TokenKind::NotEq => (BinaryOp::Ne, 7),
+        TokenKind::Lt => (BinaryOp::Lt, 9),
+        TokenKind::Le => (BinaryOp::Le, 9),
+        TokenKind::Gt => (BinaryOp::Gt, 9),
+        TokenKind::Ge => (BinaryOp::Ge, 9),
+        TokenKind::Plus => (BinaryOp::Add, 11),
+        TokenKind::Minus => (BinaryOp::Sub, 11),
+        TokenKind::Star => (BinaryOp::Mul, 13),
+        TokenKind::Slash => (BinaryOp::Div, 13),
+        TokenKind::Percent => (BinaryOp::Rem, 13),
+        _ => return None,
+    };
+    Some(op)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::expr::{BinaryOp, Expr, ExprKind, ParseError, Span};
+
+    fn parsed(source: &str) -> String {
+        Expr::parse(source).unwrap().to_string()
+    }
+
+    #[test]
+    fn precedence_and_associativity() {
+        assert_eq!(parsed("1 + 2 * 3"), "(1 + (2 * 3))");
+        assert_eq!(parsed("1 - 2 - 3"), "((1 - 2) - 3)");
+        assert_eq!(parsed("(1 - 2) * -x % 4"), "(((1 - 2) * (-x)) % 4)");
+        assert_eq!(parsed("--1"), "(-(-1))");
+        assert_eq!(
+            parsed("a < b == !c || d && e"),
+            "(((a < b) == (!c)) || (d && e))"
+        );
+        assert_eq!(parsed("a ? b : c ? d : e"), "(a ? b : (c ? d : e))");
+        assert_eq!(parsed("x > 0 ? x : -x"), "((x > 0) ? x : (-x))");
+        assert_eq!(parsed("true != false"), "(true != false)");
+        assert_eq!(
+            "12".parse(),
+            Ok(Expr::new(ExprKind::Int(12), Span::new(0, 2)))
+        );
+    }
+
+    #[test]
+    fn spans() {
+        let expr = Expr::parse(" (a + 10) * b").unwrap();
+        assert_eq!(expr.span, Span::new(1, 13));
+        let ExprKind::Binary(BinaryOp::Mul, left, right) = expr.kind else {
+            panic!("{expr:?}");
+        };
+        assert_eq!(left.span, Span::new(1, 9));
+        assert_eq!(right.span, Span::new(12, 13));
+        let ExprKind::Binary(BinaryOp::Add, _, ten) = left.kind else {
+            panic!("{left:?}");
+        };
+        assert_eq!(ten.span, Span::new(6, 8));
+    }
+
+    #[test]
+    fn error_recovery() {
+        // Skipped tokens leave the rest of the tree intact
+        let (expr, errors) = Expr::parse_recovering("1 + * / 2 * (3 $ 4");
+        assert_eq!(expr.to_string(), "(1 + (2 * 3))");
+        assert_eq!(
+            errors,
+            [
+                ParseError::ExpectedExpression(Span::new(4, 7)),
+                ParseError::UnclosedParen(Span::new(12, 13)),
+                ParseError::UnexpectedChar(Span::new(15, 16), '$'),
+                ParseError::TrailingInput(Span::new(17, 18)),
+            ]
+        );
+        // Missing operands become error nodes
+        let (expr, errors) = Expr::parse_recovering("(1 +) * (a ? b)");
+        assert_eq!(expr.to_string(), "((1 + <error>) * (a ? b : <error>))");
+        assert!(expr.has_errors());
+        assert_eq!(
+            errors,
+            [
+                ParseError::ExpectedExpression(Span::new(4, 5)),
+                ParseError::MissingColon(Span::new(11, 12)),
+            ]
+        );
+        let (expr, errors) = Expr::parse_recovering("1 ) + 2");
+        assert_eq!(expr.to_string(), "(1 + 2)");
+        assert_eq!(errors, [ParseError::TrailingInput(Span::new(2, 3))]);
+        assert_eq!(
+            Expr::parse(""),
+            Err(vec![ParseError::ExpectedExpression(Span::new(0, 0))])
+        );
+        assert_eq!(
+            Expr::parse("99999999999999999999"),
+            Err(vec![ParseError::IntegerTooLarge(Span::new(0, 20))])
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use super::{Grammar, ParseTree, Symbol};
+
+/// The CYK table of a CNF grammar over one input: for each span and
+/// nonterminal, how many derivations there are and one way to split it
+struct Chart<'a> {
+    grammar: &'a Grammar,
+    input: Vec<char>,
+    names: Vec<&'a str>,
+    /// `cells[start][len - 1][nonterminal]`
+    cells: Vec<Vec<Vec<Cell>>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Cell {
+    derivations: u128,
+    /// The length of the left part and the pair the span splits into, or
+    /// None for a single char
+    split: Option<(usize, usize, usize)>,
+}
+
+impl<'a> Chart<'a> {
+    fn fill(grammar: &'a Grammar, input: &str) -> Chart<'a> {
+        let input: Vec<char> = input.chars().collect();
+        let mut ids = HashMap::new();
+        let mut names = Vec::new();
+        for production in grammar.productions() {
+            ids.entry(production.lhs.as_str()).or_insert_with(|| {
+                names.push(production.lhs.as_str());
+                names.len() - 1
+            });
+        }
+        let mut terminals = Vec::new();
+        let mut pairs = Vec::new();
+        for production in grammar.productions() {
+            let a = ids[production.lhs.as_str()];
+            match production.rhs.as_slice() {
+                [Symbol::Terminal(c)] => terminals.push((a, *c)),
+                [Symbol::Nonterminal(b), Symbol::Nonterminal(c)] => {
+                    pairs.push((a, ids[b.as_str()], ids[c.as_str()]))
+                }
+                _ => {}
+            }
+        }
+
+        let n = input.len();
+        let mut cells = vec![Vec::new(); n];
+        for (start, row) in cells.iter_mut().enumerate() {
+            *row = vec![vec![Cell::default(); names.len()]; n - start];
+            for &(a, c) in &terminals {
+                if input[start] == c {
+                    row[0][a].derivations += 1;
+                }
+            }
+        }
+        for len in 2..=n {
+            for start in 0..=n - len {
+                for left in 1..len {
+                    for &(a, b, c) in &pairs {
+                        let ways = cells[start][left - 1][b]
+                            .derivations
+                            .saturating_mul(cells[start + left][len - left - 1][c].derivations);
+                        if ways == 0 {
+                            continue;
+                        }
+                        let cell = &mut cells[start][len - 1][a];
+                        cell.derivations = cell.derivations.saturating_add(ways);
+                        cell.split.get_or_insert((left, b, c));
+                    }
+                }
+            }
+        }
+        Chart {
+            grammar,
+            input,
+            names,
+            cells,
+        }
+    }
+
+    fn derivations(&self) -> u128 {
+        if self.input.is_empty() {
+            return self.derives_empty() as u128;
+        }
+        match self
+            .names
+            .iter()
+            .position(|&name| name == self.grammar.start())
+        {
+            Some(start) => self.cells[0][self.input.len() - 1][start].derivations,
+            None => 0,
+        }
+    }
+
+    fn derives_empty(&self) -> bool {
+        self.grammar
+            .productions()
+            .iter()
+            .any(|p| p.lhs == self.grammar.start() && p.rhs.is_empty())
+    }
+
+    fn tree(&self) -> Option<ParseTree> {
+        if self.derivations() == 0 {
+            return None;
+        }
+        if self.input.is_empty() {
+            return self.grammar.node(self.grammar.start(), Vec::new()).pop();
+        }
+        let start = self
+            .names
+            .iter()
+            .position(|&name| name == self.grammar.start())?;
+        self.build(0, self.input.len(), start).pop()
+    }
+
+    fn build(&self, start: usize, len: usize, nonterminal: usize) -> Vec<ParseTree> {
+        let children = match self.cells[start][len - 1][nonterminal].split {
+            None => vec![ParseTree::Leaf(self.input[start])],
+            Some((left, b, c)) => {
+                let mut children = self.build(start, left, b);
+                children.extend(self.build(start + left, len - left, c));
+                children
+            }
+        };
+        self.grammar.node(self.names[nonterminal], children)
+    }
+}
+
+impl Grammar {
+    /// A parse tree for `input` found by the CYK algorithm, or None if the
+    /// grammar does not derive it.
+    ///
+    /// The grammar is first converted to Chomsky normal form, and the tree
+    /// is one of the converted grammar with its helper nonterminals spliced
+    /// out, so unit steps and empty subtrees of the original grammar do not
+    /// show. Takes O(n^3 |G|) time.
+    pub fn cyk(&self, input: &str) -> Option<ParseTree> {
+        Chart::fill(&self.to_cnf(), input).tree()
+    }
+
+    /// How many distinct derivations the Chomsky normal form of the grammar
+    /// has for `input`, saturating at `u128::MAX`. More than one means the
+    /// input is ambiguous.
+    pub fn count_parses(&self, input: &str) -> u128 {
+        Chart::fill(&self.to_cnf(), input).derivations()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::grammar::{Grammar, ParseTree};
+
+    const ARITHMETIC: &str = "E -> E + T | E - T | T\nT -> T * F | T / F | F\nF -> ( E ) | N\nN -> D N | D\nD -> 0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9";
+
+    #[test]
+    fn arithmetic_expressions() {
+        let grammar = Grammar::parse(ARITHMETIC).unwrap();
+        for input in ["1", "12+3", "(1+2)*30-4/5", "((7))"] {
+            let tree = grammar.cyk(input).unwrap();
+            assert_eq!(tree.text(), input);
+            assert_eq!(grammar.count_parses(input), 1, "{input}");
+        }
+        for input in ["", "+", "1+", "(1", "1)", "1**2"] {
+            assert_eq!(grammar.cyk(input), None, "{input}");
+        }
+        // Unit steps like E -> T -> F do not show
+        assert_eq!(grammar.cyk("1*2").unwrap().to_string(), "(E (T 1) * (F 2))");
+    }
+
+    #[test]
+    fn ambiguous_grammar() {
+        let grammar = Grammar::parse("E -> E + E | E * E | a").unwrap();
+        assert_eq!(grammar.count_parses("a"), 1);
+        assert_eq!(grammar.count_parses("a+a*a"), 2);
+        // Catalan numbers count the ways to bracket n + 1 operands
+        assert_eq!(grammar.count_parses("a+a+a+a"), 5);
+        assert_eq!(grammar.count_parses(&"+a".repeat(9)[1..]), 1430);
+        assert_eq!(grammar.count_parses("a+"), 0);
+        let tree = grammar.cyk("a+a*a").unwrap();
+        assert!(
+            matches!(&tree, ParseTree::Node(name, children) if name == "E" && children.len() == 3)
+        );
+    }
+
+    #[test]
+    fn empty_string() {
+        let grammar = Grammar::parse("S -> a S b | ε").unwrap();
+        assert_eq!(
+            grammar.cyk(""),
+            Some(ParseTree::Node("S".to_string(), Vec::new()))
+        );
+        assert_eq!(grammar.cyk("aabb").unwrap().to_string(), "(S a (S a b) b)");
+        assert_eq!(grammar.cyk("aab"), None);
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::{Grammar, ParseTree, Symbol};
+
+/// A production with a dot after `dot` of its symbols, begun at `origin`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Item {
+    production: usize,
+    dot: usize,
+    origin: usize,
+}
+
+/// The Earley sets of a grammar over one input, where set `i` holds the
+/// items whose symbols before the dot derive `input[origin..i]`
+struct Chart<'a> {
+    grammar: &'a Grammar,
+    input: Vec<char>,
+    sets: Vec<Vec<Item>>,
+    members: Vec<HashSet<Item>>,
+}
+
+impl<'a> Chart<'a> {
+    /// Predicts, scans and completes set by set. Predicting a nullable
+    /// nonterminal also moves the dot past it right away, the Aycock and
+    /// Horspool fix for completions that would otherwise be missed
+    fn fill(grammar: &'a Grammar, input: &str) -> Chart<'a> {
+        let input: Vec<char> = input.chars().collect();
+        let nullable = grammar.nullable();
+        let mut chart = Chart {
+            grammar,
+            sets: vec![Vec::new(); input.len() + 1],
+            members: vec![HashSet::new(); input.len() + 1],
+            input,
+        };
+        for production in chart.starts() {
+            chart.add(
+                0,
+                Item {
+                    production,
+                    dot: 0,
+                    origin: 0,
+                },
+            );
+        }
+        for i in 0..=chart.input.len() {
+            let mut next = 0;
+            while next < chart.sets[i].len() {
+                let item = chart.sets[i][next];
+                next += 1;
+                let production = &grammar.productions()[item.production];
+                match production.rhs.get(item.dot) {
+                    Some(Symbol::Nonterminal(name)) => {
+                        let predicted: Vec<usize> = chart.productions_of(name).collect();
+                        for production in predicted {
+                            chart.add(
+                                i,
+                                Item {
+                                    production,
+                                    dot: 0,
+                                    origin: i,
+                                },
+                            );
+                        }
+                        if nullable.contains(name) {
+                            chart.add(
+                                i,
+                                Item {
+                                    dot: item.dot + 1,
+                                    ..item
+                                },
+                            );
+                        }
+                    }
+                    Some(Symbol::Terminal(c)) => {
+                        if chart.input.get(i) == Some(c) {
+                            chart.add(
+                                i + 1,
+                                Item {
+                                    dot: item.dot + 1,
+                                    ..item
+                                },
+                            );
+                        }
+                    }
+                    None => {
+                        let waiting: Vec<Item> = chart.sets[item.origin]
+                            .iter()
+                            .filter(|waiting| {
+                                let rhs = &grammar.productions()[waiting.production].rhs;
+                                matches!(rhs.get(waiting.dot), Some(Symbol::Nonterminal(name)) if *name == production.lhs)
+                            })
+                            .copied()
+                            .collect();
+                        for waiting in waiting {
+                            chart.add(
+                                i,
+                                Item {
+                                    dot: waiting.dot + 1,
+                                    ..waiting
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        chart
+    }
+
+    fn add(&mut self, set: usize, item: Item) {
+        if self.members[set].insert(item) {
+            self.sets[set].push(item);
+        }
+    }
+
+    fn productions_of(&self, name: &str) -> impl Iterator<Item = usize> + use<'_, 'a> {
+        let name = name.to_string();
+        self.grammar
+            .productions()
+            .iter()
+            .enumerate()
+            .filter(move |(_, p)| p.lhs == name)
+            .map(|(i, _)| i)
+    }
+
+    fn starts(&self) -> Vec<usize> {
+        self.productions_of(self.grammar.start()).collect()
+    }
+
+    /// A tree for the start over the whole input, read back from the sets
+    fn tree(&self) -> Option<ParseTree> {
+        let mut path = BTreeSet::new();
+        self.derive(self.grammar.start(), 0, self.input.len(), &mut path)
+            .and_then(|mut trees| trees.pop())
+    }
+
+    /// The nodes for `name` deriving `input[from..to]`, through a completed
+    /// item for one of its productions. `path` holds the spans being built
+    /// above, so cycles like `A -> A` are not followed forever
+    fn derive(
+        &self,
+        name: &'a str,
+        from: usize,
+        to: usize,
+        path: &mut BTreeSet<(&'a str, usize, usize)>,
+    ) -> Option<Vec<ParseTree>> {
+        if !path.insert((name, from, to)) {
+            return None;
+        }
+        let mut found = None;
+        for production in self.productions_of(name) {
+            let len = self.grammar.productions()[production].rhs.len();
+            let complete = Item {
+                production,
+                dot: len,
+                origin: from,
+            };
+            if !self.members[to].contains(&complete) {
+                continue;
+            }
+            if let Some(children) = self.children(production, len, from, to, path) {
+                found = Some(self.grammar.node(name, children));
+                break;
+            }
+        }
+        path.remove(&(name, from, to));
+        found
+    }
+
+    /// Trees for the first `dot` symbols of `production` deriving
+    /// `input[from..to]`, given that the item for them is in set `to`
+    fn children(
+        &self,
+        production: usize,
+        dot: usize,
+        from: usize,
+        to: usize,
+        path: &mut BTreeSet<(&'a str, usize, usize)>,
+    ) -> Option<Vec<ParseTree>> {
+        if dot == 0 {
+            return (from == to).then(Vec::new);
+        }
+        let before = Item {
+            production,
+            dot: dot - 1,
+            origin: from,
+        };
+        match &self.grammar.productions()[production].rhs[dot - 1] {
+            Symbol::Terminal(c) => {
+                if to == from || self.input[to - 1] != *c || !self.members[to - 1].contains(&before)
+                {
+                    return None;
+                }
+                let mut children = self.children(production, dot - 1, from, to - 1, path)?;
+                children.push(ParseTree::Leaf(*c));
+                Some(children)
+            }
+            Symbol::Nonterminal(name) => {
+                for mid in (from..=to).rev() {
+                    if !self.members[mid].contains(&before) {
+                        continue;
+                    }
+                    let Some(last) = self.derive(name, mid, to, path) else {
+                        continue;
+                    };
+                    if let Some(mut children) = self.children(production, dot - 1, from, mid, path)
+                    {
+                        children.extend(last);
+                        return Some(children);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+impl Grammar {
+    /// A parse tree for `input` found by Earley's algorithm, or None if the
+    /// grammar does not derive it.
+    ///
+    /// Works on the grammar as written, left recursion and ε-rules
+    /// included, so the tree uses its productions. Takes O(n^3) time in
+    /// general and O(n^2) on unambiguous grammars.
+    pub fn earley(&self, input: &str) -> Option<ParseTree> {
+        Chart::fill(self, input).tree()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::grammar::Grammar;
+
+    const ARITHMETIC: &str = "E -> E + T | E - T | T\nT -> T * F | T / F | F\nF -> ( E ) | N\nN -> D N | D\nD -> 0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9";
+
+    #[test]
+    fn arithmetic_expressions() {
+        let grammar = Grammar::parse(ARITHMETIC).unwrap();
+        assert_eq!(
+            grammar.earley("1+2*3").unwrap().to_string(),
+            "(E (E (T (F (N (D 1))))) + (T (T (F (N (D 2)))) * (F (N (D 3)))))"
+        );
+        for input in ["12+3", "(1+2)*30-4/5", "((7))"] {
+            assert_eq!(grammar.earley(input).unwrap().text(), input);
+        }
+        for input in ["", "+", "1+", "(1", "1)", "1**2"] {
+            assert_eq!(grammar.earley(input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn agrees_with_cyk() {
+        let grammars = [
+            "S -> a S b | ε",
+            "S -> S S | ( S ) | ε",
+            "E -> E + E | E * E | a",
+            "S -> A S A | a B\nA -> B | S\nB -> b | ε",
+            "S -> A | a\nA -> S | b A",
+        ];
+        for text in grammars {
+            let grammar = Grammar::parse(text).unwrap();
+            let mut inputs = vec![String::new()];
+            let mut frontier = vec![String::new()];
+            for _ in 0..4 {
+                frontier = frontier
+                    .iter()
+                    .flat_map(|s| ['a', 'b', '(', ')', '+'].map(|c| format!("{s}{c}")))
+                    .collect();
+                inputs.extend(frontier.iter().cloned());
+            }
+            for input in &inputs {
+                let earley = grammar.earley(input);
+                assert_eq!(
+                    earley.is_some(),
+                    grammar.cyk(input).is_some(),
+                    "{text}: {input}"
+                );
+                if let Some(tree) = earley {
+                    assert_eq!(tree.text(), *input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nullable_and_cyclic_rules() {
+        let grammar = Grammar::parse("S -> A A x\nA -> ε | A").unwrap();
+        assert_eq!(grammar.earley("x").unwrap().to_string(), "(S (A) (A) x)");
+        assert_eq!(grammar.earley("xx"), None);
+    }
+}
@@ -0,0 +1,24 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons a grammar fails to parse from text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrammarError {
+    /// This line (counted from 1) has no `->` or nothing before it
+    MissingArrow(usize),
+    /// The text has no productions at all
+    Empty,
+}
+
+impl Display for GrammarError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GrammarError::MissingArrow(line) => {
+                write!(f, "line {line} is not of the form `name -> symbols`")
+            }
+            GrammarError::Empty => write!(f, "the grammar has no productions"),
+        }
+    }
+}
+
+impl Error for GrammarError {}
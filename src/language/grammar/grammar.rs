@@ -0,0 +1,415 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use super::{GrammarError, ParseTree};
+
+/// A symbol on the right-hand side of a production
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Symbol {
+    Terminal(char),
+    Nonterminal(String),
+}
+
+impl Symbol {
+    pub fn nonterminal(name: &str) -> Symbol {
+        Symbol::Nonterminal(name.to_string())
+    }
+}
+
+/// `lhs -> rhs`, where an empty `rhs` derives the empty string
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Production {
+    pub lhs: String,
+    pub rhs: Vec<Symbol>,
+}
+
+/// A context-free grammar over chars, with named nonterminals
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grammar {
+    start: String,
+    productions: Vec<Production>,
+    /// Nonterminals made up by `to_cnf`, mapped to the nonterminal a parse
+    /// tree shows in their place, or None if their children belong to the
+    /// parent node
+    helpers: BTreeMap<String, Option<String>>,
+}
+
+impl Grammar {
+    /// A grammar with no productions yet
+    pub fn new(start: &str) -> Grammar {
+        Grammar {
+            start: start.to_string(),
+            productions: Vec::new(),
+            helpers: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_production(&mut self, lhs: &str, rhs: Vec<Symbol>) {
+        self.productions.push(Production {
+            lhs: lhs.to_string(),
+            rhs,
+        });
+    }
+
+    /// Parses one rule per line, like
+    ///
+    /// ```text
+    /// E -> E + T | T
+    /// T -> ( E ) | a
+    /// ```
+    ///
+    /// Symbols are separated by whitespace. A symbol that names the left
+    /// side of some rule is a nonterminal; any other is a run of terminal
+    /// chars, and `ε` stands for nothing. The first rule's nonterminal is
+    /// the start.
+    pub fn parse(text: &str) -> Result<Grammar, GrammarError> {
+        let mut rules = Vec::new();
+        for (line, source) in text.lines().enumerate() {
+            if source.trim().is_empty() {
+                continue;
+            }
+            match source.split_once("->") {
+                Some((lhs, rhs)) if !lhs.trim().is_empty() => rules.push((lhs.trim(), rhs)),
+                _ => return Err(GrammarError::MissingArrow(line + 1)),
+            }
+        }
+        let Some(&(start, _)) = rules.first() else {
+            return Err(GrammarError::Empty);
+        };
+        let names: BTreeSet<&str> = rules.iter().map(|&(lhs, _)| lhs).collect();
+        let mut grammar = Grammar::new(start);
+        for (lhs, rhs) in rules {
+            let mut alternative = Vec::new();
+            for token in rhs.split_whitespace().chain(["|"]) {
+                match token {
+                    "|" => grammar.add_production(lhs, std::mem::take(&mut alternative)),
+                    "ε" => {}
+                    name if names.contains(name) => alternative.push(Symbol::nonterminal(name)),
+                    chars => alternative.extend(chars.chars().map(Symbol::Terminal)),
+                }
+            }
+        }
+        Ok(grammar)
+    }
+
+    pub fn start(&self) -> &str {
+        &self.start
+    }
+
+    pub fn productions(&self) -> &[Production] {
+        &self.productions
+    }
+
+    /// Whether every production is `A -> B C`, with neither B nor C the
+    /// start, or `A -> a`, or `S -> ε` for the start S
+    pub fn is_cnf(&self) -> bool {
+        self.productions.iter().all(|p| match p.rhs.as_slice() {
+            [] => p.lhs == self.start,
+            [Symbol::Terminal(_)] => true,
+            [Symbol::Nonterminal(b), Symbol::Nonterminal(c)] => {
+                *b != self.start && *c != self.start
+            }
+            _ => false,
+        })
+    }
+
+    /// The nonterminals that derive the empty string
+    pub fn nullable(&self) -> BTreeSet<String> {
+        nullable(&self.productions)
+    }
+
+    /// An equivalent grammar in Chomsky normal form, built in the textbook
+    /// order: a fresh start, terminals in long rules moved into rules of
+    /// their own, long rules split into chains of pairs, ε-rules removed and
+    /// then unit rules removed.
+    ///
+    /// The new nonterminals are remembered, so parse trees of the result
+    /// show the original start at the root and splice the others out.
+    pub fn to_cnf(&self) -> Grammar {
+        let mut used: BTreeSet<String> = self.names().into_iter().collect();
+        let mut helpers = self.helpers.clone();
+        let mut productions = self.productions.clone();
+
+        let start = fresh_name(&self.start, &mut used);
+        helpers.insert(start.clone(), self.label(&self.start).map(str::to_string));
+        productions.push(Production {
+            lhs: start.clone(),
+            rhs: vec![Symbol::nonterminal(&self.start)],
+        });
+
+        let mut terminal_names: BTreeMap<char, String> = BTreeMap::new();
+        for production in &mut productions {
+            if production.rhs.len() < 2 {
+                continue;
+            }
+            for symbol in &mut production.rhs {
+                if let Symbol::Terminal(c) = *symbol {
+                    let name = terminal_names.entry(c).or_insert_with(|| {
+                        let name = fresh_name(&format!("<{c}>"), &mut used);
+                        helpers.insert(name.clone(), None);
+                        name
+                    });
+                    *symbol = Symbol::nonterminal(name);
+                }
+            }
+        }
+        productions.extend(terminal_names.into_iter().map(|(c, name)| Production {
+            lhs: name,
+            rhs: vec![Symbol::Terminal(c)],
+        }));
+
+        let mut binary = Vec::new();
+        for production in productions {
+            let mut lhs = production.lhs.clone();
+            let mut rest = production.rhs.as_slice();
+            while rest.len() > 2 {
+                let helper = fresh_name(&production.lhs, &mut used);
+                helpers.insert(helper.clone(), None);
+                binary.push(Production {
+                    lhs,
+                    rhs: vec![rest[0].clone(), Symbol::nonterminal(&helper)],
+                });
+                lhs = helper;
+                rest = &rest[1..];
+            }
+            binary.push(Production {
+                lhs,
+                rhs: rest.to_vec(),
+            });
+        }
+
+        let nullable = nullable(&binary);
+        let mut non_empty = BTreeSet::new();
+        for production in &binary {
+            let mut variants = vec![Vec::new()];
+            for symbol in &production.rhs {
+                let skippable =
+                    matches!(symbol, Symbol::Nonterminal(name) if nullable.contains(name));
+                let mut next = Vec::new();
+                for variant in variants {
+                    if skippable {
+                        next.push(variant.clone());
+                    }
+                    let mut with = variant;
+                    with.push(symbol.clone());
+                    next.push(with);
+                }
+                variants = next;
+            }
+            for rhs in variants.into_iter().filter(|rhs| !rhs.is_empty()) {
+                non_empty.insert(Production {
+                    lhs: production.lhs.clone(),
+                    rhs,
+                });
+            }
+        }
+
+        let mut cnf = BTreeSet::new();
+        if nullable.contains(&start) {
+            cnf.insert(Production {
+                lhs: start.clone(),
+                rhs: Vec::new(),
+            });
+        }
+        let lhs_names: BTreeSet<&String> = non_empty.iter().map(|p| &p.lhs).collect();
+        for lhs in lhs_names {
+            let mut reachable = BTreeSet::from([lhs]);
+            let mut stack = vec![lhs];
+            while let Some(name) = stack.pop() {
+                for production in non_empty.iter().filter(|p| p.lhs == *name) {
+                    match production.rhs.as_slice() {
+                        [Symbol::Nonterminal(next)] => {
+                            if reachable.insert(next) {
+                                stack.push(next);
+                            }
+                        }
+                        rhs => {
+                            cnf.insert(Production {
+                                lhs: lhs.clone(),
+                                rhs: rhs.to_vec(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut grammar = Grammar {
+            start,
+            productions: cnf.into_iter().collect(),
+            helpers,
+        };
+        grammar.remove_unreachable();
+        grammar
+    }
+
+    /// The node for nonterminal `name` over `children`, or just the children
+    /// if `name` is a helper that parse trees splice out
+    pub(super) fn node(&self, name: &str, children: Vec<ParseTree>) -> Vec<ParseTree> {
+        match self.label(name) {
+            Some(label) => vec![ParseTree::Node(label.to_string(), children)],
+            None => children,
+        }
+    }
+
+    fn label<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        match self.helpers.get(name) {
+            Some(label) => label.as_deref(),
+            None => Some(name),
+        }
+    }
+
+    fn names(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::from([self.start.clone()]);
+        for production in &self.productions {
+            names.insert(production.lhs.clone());
+            for symbol in &production.rhs {
+                if let Symbol::Nonterminal(name) = symbol {
+                    names.insert(name.clone());
+                }
+            }
+        }
+        names.extend(self.helpers.keys().cloned());
+        names
+    }
+
+    fn remove_unreachable(&mut self) {
+        let mut reachable = BTreeSet::from([self.start.clone()]);
+        let mut stack = vec![self.start.clone()];
+        while let Some(name) = stack.pop() {
+            for production in self.productions.iter().filter(|p| p.lhs == name) {
+                for symbol in &production.rhs {
+                    if let Symbol::Nonterminal(next) = symbol
+                        && reachable.insert(next.clone())
+                    {
+                        stack.push(next.clone());
+                    }
+                }
+            }
+        }
+        self.productions.retain(|p| reachable.contains(&p.lhs));
+    }
+}
+
+impl FromStr for Grammar {
+    type Err = GrammarError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Grammar::parse(text)
+    }
+}
+
+impl Display for Grammar {
+    /// One line per nonterminal in the notation `Grammar::parse` reads,
+    /// starting with the start
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut order = vec![self.start.as_str()];
+        for production in &self.productions {
+            if !order.contains(&production.lhs.as_str()) {
+                order.push(&production.lhs);
+            }
+        }
+        for lhs in order {
+            let alternatives: Vec<String> = self
+                .productions
+                .iter()
+                .filter(|p| p.lhs == lhs)
+                .map(|p| match p.rhs.as_slice() {
+                    [] => "ε".to_string(),
+                    rhs => rhs
+                        .iter()
+                        .map(|symbol| match symbol {
+                            Symbol::Terminal(c) => c.to_string(),
+                            Symbol::Nonterminal(name) => name.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                })
+                .collect();
+            if !alternatives.is_empty() {
+                writeln!(f, "{lhs} -> {}", alternatives.join(" | "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn nullable(productions: &[Production]) -> BTreeSet<String> {
+    let mut nullable = BTreeSet::new();
+    loop {
+        let before = nullable.len();
+        for production in productions {
+            let empty = production.rhs.iter().all(|symbol| match symbol {
+                Symbol::Terminal(_) => false,
+                Symbol::Nonterminal(name) => nullable.contains(name),
+            });
+            if empty {
+                nullable.insert(production.lhs.clone());
+            }
+        }
+        if nullable.len() == before {
+            return nullable;
+        }
+    }
+}
+
+/// `base` with primes appended until it names nothing in `used`, which it
+/// then joins
+fn fresh_name(base: &str, used: &mut BTreeSet<String>) -> String {
+    let mut name = base.to_string();
+    while used.contains(&name) {
+        name.push('\'');
+    }
+    used.insert(name.clone());
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grammar, Symbol};
+    use crate::language::grammar::GrammarError;
+
+    #[test]
+    fn parse_and_display() {
+        let grammar = Grammar::parse("S -> a S b | ε\n\n  S -> ab T\nT -> c").unwrap();
+        assert_eq!(grammar.start(), "S");
+        assert_eq!(grammar.productions().len(), 4);
+        assert_eq!(
+            grammar.productions()[0].rhs,
+            [
+                Symbol::Terminal('a'),
+                Symbol::nonterminal("S"),
+                Symbol::Terminal('b')
+            ]
+        );
+        assert_eq!(grammar.to_string(), "S -> a S b | ε | a b T\nT -> c\n");
+        assert_eq!(grammar.to_string().parse(), Ok(grammar));
+        assert_eq!(
+            Grammar::parse("S -> a\nb"),
+            Err(GrammarError::MissingArrow(2))
+        );
+        assert_eq!(Grammar::parse(" -> a"), Err(GrammarError::MissingArrow(1)));
+        assert_eq!(Grammar::parse("\n"), Err(GrammarError::Empty));
+    }
+
+    #[test]
+    fn cnf_conversion() {
+        let grammar = Grammar::parse("S -> A S A | a B\nA -> B | S\nB -> b | ε").unwrap();
+        assert!(!grammar.is_cnf());
+        assert_eq!(grammar.nullable().len(), 2);
+        let cnf = grammar.to_cnf();
+        assert!(cnf.is_cnf(), "{cnf}");
+        assert!(cnf.to_cnf().is_cnf());
+        // The start derives ε only if the original did
+        let cnf = Grammar::parse("S -> A B\nA -> a | ε\nB -> b | ε")
+            .unwrap()
+            .to_cnf();
+        assert!(cnf.is_cnf(), "{cnf}");
+        assert!(
+            cnf.productions()
+                .iter()
+                .any(|p| p.lhs == cnf.start() && p.rhs.is_empty())
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod cyk;
+mod earley;
+mod error;
+mod grammar;
+mod tree;
+
+pub use self::error::GrammarError;
+pub use self::grammar::{Grammar, Production, Symbol};
+pub use self::tree::ParseTree;
@@ -0,0 +1,35 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A derivation of some input: nonterminals at the nodes, the input's chars
+/// at the leaves
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseTree {
+    Leaf(char),
+    Node(String, Vec<ParseTree>),
+}
+
+impl ParseTree {
+    /// The chars at the leaves, left to right, which is the input parsed
+    pub fn text(&self) -> String {
+        match self {
+            ParseTree::Leaf(c) => c.to_string(),
+            ParseTree::Node(_, children) => children.iter().map(ParseTree::text).collect(),
+        }
+    }
+}
+
+impl Display for ParseTree {
+    /// Bracketed, like `(E (E (T a)) + (T a))`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTree::Leaf(c) => write!(f, "{c}"),
+            ParseTree::Node(name, children) => {
+                write!(f, "({name}")?;
+                for child in children {
+                    write!(f, " {child}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
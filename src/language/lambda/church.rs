@@ -0,0 +1,83 @@
+use super::Term;
+
+/// The Church numeral for `n`: `\f.\x.f (f ... (f x))` with `n` applications
+pub fn church_numeral(n: u64) -> Term {
+    let body = (0..n).fold(Term::var("x"), |body, _| Term::app(Term::var("f"), body));
+    Term::abs("f", Term::abs("x", body))
+}
+
+/// Reads back a Church numeral in normal form, whatever its variable names
+pub fn decode_church_numeral(term: &Term) -> Option<u64> {
+    let Term::Abs(f, inner) = term else {
+        return None;
+    };
+    let Term::Abs(x, body) = inner.as_ref() else {
+        return None;
+    };
+    if f == x {
+        return None;
+    }
+    let mut body: &Term = body;
+    let mut n = 0;
+    loop {
+        match body {
+            Term::Var(var) if var == x => return Some(n),
+            Term::App(function, argument) if matches!(function.as_ref(), Term::Var(g) if g == f) => {
+                n += 1;
+                body = argument;
+            }
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{church_numeral, decode_church_numeral};
+    use crate::language::lambda::{Strategy, Term};
+
+    fn apply(function: &str, args: &[Term]) -> Term {
+        args.iter().fold(Term::parse(function).unwrap(), |f, arg| {
+            Term::app(f, arg.clone())
+        })
+    }
+
+    #[test]
+    fn round_trips() {
+        for n in 0..10 {
+            assert_eq!(decode_church_numeral(&church_numeral(n)), Some(n));
+        }
+        assert_eq!(church_numeral(2).to_string(), "\\f.\\x.f (f x)");
+        assert_eq!(
+            decode_church_numeral(&Term::parse("\\a.\\b.a (a b)").unwrap()),
+            Some(2)
+        );
+        assert_eq!(
+            decode_church_numeral(&Term::parse("\\x.\\x.x").unwrap()),
+            None
+        );
+        assert_eq!(
+            decode_church_numeral(&Term::parse("\\f.\\x.x f").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let plus = "\\m n f x.m f (n f x)";
+        let times = "\\m n f.m (n f)";
+        let power = "\\b e.e b";
+        let pred = "\\n f x.n (\\g h.h (g f)) (\\u.x) (\\u.u)";
+        let cases = [(plus, 3, 4, 7), (times, 3, 4, 12), (power, 2, 5, 32)];
+        for (op, a, b, expected) in cases {
+            let term = apply(op, &[church_numeral(a), church_numeral(b)]);
+            for strategy in [Strategy::NormalOrder, Strategy::ApplicativeOrder] {
+                let result = term.normalize(strategy, 10_000).unwrap();
+                assert_eq!(decode_church_numeral(&result), Some(expected), "{op}");
+            }
+        }
+        let term = apply(pred, &[church_numeral(6)]);
+        let result = term.normalize(Strategy::NormalOrder, 10_000).unwrap();
+        assert_eq!(decode_church_numeral(&result), Some(5));
+    }
+}
@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons a lambda term fails to parse, each with the byte offset of the problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LambdaError {
+    /// A char that cannot start or continue a term here
+    UnexpectedChar { offset: usize, found: char },
+    /// The input ended in the middle of a term
+    UnexpectedEnd,
+    /// A '\' with no variable before its '.'
+    MissingParameter(usize),
+}
+
+impl Display for LambdaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LambdaError::UnexpectedChar { offset, found } => {
+                write!(f, "unexpected {found:?} at {offset}")
+            }
+            LambdaError::UnexpectedEnd => write!(f, "unexpected end of term"),
+            LambdaError::MissingParameter(at) => {
+                write!(f, "abstraction without a parameter at {at}")
+            }
+        }
+    }
+}
+
+impl Error for LambdaError {}
@@ -0,0 +1,8 @@
+mod church;
+mod error;
+mod parser;
+mod term;
+
+pub use self::church::{church_numeral, decode_church_numeral};
+pub use self::error::LambdaError;
+pub use self::term::{Reductions, Strategy, Term};
@@ -0,0 +1,108 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use super::{LambdaError, Term};
+
+/// Recursive descent over the grammar
+///
+/// ```text
+/// term        := application | abstraction
+/// abstraction := ('\' | 'λ') name+ '.' term
+/// application := atom+ abstraction?
+/// atom        := name | '(' term ')'
+/// ```
+///
+/// so application is left associative and an abstraction extends as far
+/// right as possible.
+pub(super) struct Parser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn parse(source: &'a str) -> Result<Term, LambdaError> {
+        let mut parser = Parser {
+            chars: source.char_indices().peekable(),
+        };
+        let term = parser.term()?;
+        match parser.peek() {
+            None => Ok(term),
+            Some((offset, found)) => Err(LambdaError::UnexpectedChar { offset, found }),
+        }
+    }
+
+    /// The next char that is not whitespace
+    fn peek(&mut self) -> Option<(usize, char)> {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        self.chars.peek().copied()
+    }
+
+    fn term(&mut self) -> Result<Term, LambdaError> {
+        let mut term: Option<Term> = None;
+        loop {
+            let next = match self.peek() {
+                Some((_, '\\' | 'λ')) => self.abstraction()?,
+                Some((_, '(')) => {
+                    self.chars.next();
+                    let inner = self.term()?;
+                    match self.peek() {
+                        Some((_, ')')) => {
+                            self.chars.next();
+                        }
+                        Some((offset, found)) => {
+                            return Err(LambdaError::UnexpectedChar { offset, found });
+                        }
+                        None => return Err(LambdaError::UnexpectedEnd),
+                    }
+                    inner
+                }
+                Some((_, c)) if is_name_char(c) => Term::var(&self.name()),
+                Some((offset, found)) if term.is_none() => {
+                    return Err(LambdaError::UnexpectedChar { offset, found });
+                }
+                None if term.is_none() => return Err(LambdaError::UnexpectedEnd),
+                _ => break,
+            };
+            term = Some(match term {
+                Some(function) => Term::app(function, next),
+                None => next,
+            });
+        }
+        Ok(term.unwrap())
+    }
+
+    fn abstraction(&mut self) -> Result<Term, LambdaError> {
+        let (start, _) = self.chars.next().unwrap();
+        let mut params = Vec::new();
+        loop {
+            match self.peek() {
+                Some((_, c)) if is_name_char(c) => params.push(self.name()),
+                Some((_, '.')) => {
+                    self.chars.next();
+                    if params.is_empty() {
+                        return Err(LambdaError::MissingParameter(start));
+                    }
+                    break;
+                }
+                Some((offset, found)) => return Err(LambdaError::UnexpectedChar { offset, found }),
+                None => return Err(LambdaError::UnexpectedEnd),
+            }
+        }
+        let body = self.term()?;
+        Ok(params
+            .iter()
+            .rev()
+            .fold(body, |body, param| Term::abs(param, body)))
+    }
+
+    fn name(&mut self) -> String {
+        let mut name = String::new();
+        while let Some((_, c)) = self.chars.next_if(|&(_, c)| is_name_char(c)) {
+            name.push(c);
+        }
+        name
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() && c != 'λ' || c == '_' || c == '\''
+}
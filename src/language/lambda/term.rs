@@ -0,0 +1,289 @@
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use super::LambdaError;
+use super::parser::Parser;
+
+/// A term of the untyped lambda calculus
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Term {
+    Var(String),
+    /// `\x.body`
+    Abs(String, Box<Term>),
+    /// `function argument`
+    App(Box<Term>, Box<Term>),
+}
+
+/// Which redex a reduction step contracts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Leftmost outermost first: arguments are substituted unevaluated.
+    /// Reaches the normal form whenever one exists
+    NormalOrder,
+    /// Leftmost innermost first: arguments are reduced before substitution,
+    /// like call by value. Can loop on terms that have a normal form
+    ApplicativeOrder,
+}
+
+impl Term {
+    pub fn var(name: &str) -> Term {
+        Term::Var(name.to_string())
+    }
+
+    pub fn abs(param: &str, body: Term) -> Term {
+        Term::Abs(param.to_string(), Box::new(body))
+    }
+
+    pub fn app(function: Term, argument: Term) -> Term {
+        Term::App(Box::new(function), Box::new(argument))
+    }
+
+    /// Parses terms like `\x y.x (y z)`, accepting `λ` for `\`
+    pub fn parse(source: &str) -> Result<Term, LambdaError> {
+        Parser::parse(source)
+    }
+
+    /// The variables not bound by an enclosing abstraction
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        match self {
+            Term::Var(name) => BTreeSet::from([name.clone()]),
+            Term::Abs(param, body) => {
+                let mut vars = body.free_vars();
+                vars.remove(param);
+                vars
+            }
+            Term::App(function, argument) => {
+                let mut vars = function.free_vars();
+                vars.extend(argument.free_vars());
+                vars
+            }
+        }
+    }
+
+    /// Replaces the free occurrences of `name` with `value`, renaming bound
+    /// variables where a free variable of `value` would otherwise be captured
+    pub fn substitute(&self, name: &str, value: &Term) -> Term {
+        match self {
+            Term::Var(var) if var == name => value.clone(),
+            Term::Var(_) => self.clone(),
+            Term::App(function, argument) => Term::app(
+                function.substitute(name, value),
+                argument.substitute(name, value),
+            ),
+            Term::Abs(param, _) if param == name => self.clone(),
+            Term::Abs(param, body) => {
+                let value_vars = value.free_vars();
+                if value_vars.contains(param) && body.free_vars().contains(name) {
+                    let mut avoid = value_vars;
+                    avoid.extend(body.free_vars());
+                    let fresh = fresh_name(param, &avoid);
+                    let renamed = body.substitute(param, &Term::Var(fresh.clone()));
+                    Term::abs(&fresh, renamed.substitute(name, value))
+                } else {
+                    Term::abs(param, body.substitute(name, value))
+                }
+            }
+        }
+    }
+
+    /// Checks if the terms are equal up to renaming bound variables
+    pub fn alpha_eq(&self, other: &Term) -> bool {
+        fn eq<'a>(a: &'a Term, b: &'a Term, bound: &mut Vec<(&'a str, &'a str)>) -> bool {
+            match (a, b) {
+                (Term::Var(x), Term::Var(y)) => {
+                    // The innermost binder of either name decides
+                    match bound.iter().rev().find(|(bx, by)| bx == x || by == y) {
+                        Some((bx, by)) => bx == x && by == y,
+                        None => x == y,
+                    }
+                }
+                (Term::Abs(x, a), Term::Abs(y, b)) => {
+                    bound.push((x, y));
+                    let equal = eq(a, b, bound);
+                    bound.pop();
+                    equal
+                }
+                (Term::App(f, a), Term::App(g, b)) => eq(f, g, bound) && eq(a, b, bound),
+                _ => false,
+            }
+        }
+        eq(self, other, &mut Vec::new())
+    }
+
+    /// Contracts one redex chosen by `strategy`, or None in normal form
+    pub fn step(&self, strategy: Strategy) -> Option<Term> {
+        match self {
+            Term::Var(_) => None,
+            Term::Abs(param, body) => Some(Term::abs(param, body.step(strategy)?)),
+            Term::App(function, argument) => {
+                let contract = || match function.as_ref() {
+                    Term::Abs(param, body) => Some(body.substitute(param, argument)),
+                    _ => None,
+                };
+                let inside = || {
+                    if let Some(function) = function.step(strategy) {
+                        return Some(Term::app(function, (**argument).clone()));
+                    }
+                    let argument = argument.step(strategy)?;
+                    Some(Term::app((**function).clone(), argument))
+                };
+                match strategy {
+                    Strategy::NormalOrder => contract().or_else(inside),
+                    Strategy::ApplicativeOrder => inside().or_else(contract),
+                }
+            }
+        }
+    }
+
+    /// The terms this one passes through on its way to normal form, one per
+    /// step, not including itself. Endless when there is no normal form
+    pub fn reductions(&self, strategy: Strategy) -> Reductions {
+        Reductions {
+            term: Some(self.clone()),
+            strategy,
+        }
+    }
+
+    /// Reduces to normal form, giving up with None after `max_steps` steps
+    pub fn normalize(&self, strategy: Strategy, max_steps: usize) -> Option<Term> {
+        let mut term = self.clone();
+        for _ in 0..max_steps {
+            match term.step(strategy) {
+                Some(next) => term = next,
+                None => return Some(term),
+            }
+        }
+        term.step(strategy).is_none().then_some(term)
+    }
+}
+
+/// The name `name` with primes appended until it is not in `avoid`
+fn fresh_name(name: &str, avoid: &BTreeSet<String>) -> String {
+    let mut fresh = format!("{name}'");
+    while avoid.contains(&fresh) {
+        fresh.push('\'');
+    }
+    fresh
+}
+
+/// The reduction sequence of a term, returned by `Term::reductions`
+#[derive(Debug, Clone)]
+pub struct Reductions {
+    term: Option<Term>,
+    strategy: Strategy,
+}
+
+impl Iterator for Reductions {
+    type Item = Term;
+
+    fn next(&mut self) -> Option<Term> {
+        let next = self.term.as_ref()?.step(self.strategy);
+        self.term = next.clone();
+        next
+    }
+}
+
+impl FromStr for Term {
+    type Err = LambdaError;
+
+    fn from_str(source: &str) -> Result<Term, LambdaError> {
+        Term::parse(source)
+    }
+}
+
+/// Prints with the fewest parentheses the parser needs to read it back
+impl Display for Term {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Var(name) => write!(f, "{name}"),
+            Term::Abs(param, body) => write!(f, "\\{param}.{body}"),
+            Term::App(function, argument) => {
+                match function.as_ref() {
+                    Term::Abs(..) => write!(f, "({function})")?,
+                    _ => write!(f, "{function}")?,
+                }
+                match argument.as_ref() {
+                    Term::Var(_) => write!(f, " {argument}"),
+                    _ => write!(f, " ({argument})"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Strategy, Term};
+    use crate::language::lambda::LambdaError;
+
+    fn parse(source: &str) -> Term {
+        Term::parse(source).unwrap()
+    }
+
+    #[test]
+    fn parses_and_prints() {
+        let term = parse("\\x y. x (y z) w");
+        assert_eq!(term.to_string(), "\\x.\\y.x (y z) w");
+        assert_eq!(parse(&term.to_string()), term);
+        assert_eq!(
+            parse("λf.(λx.f (x x)) (λx.f (x x))").to_string(),
+            "\\f.(\\x.f (x x)) (\\x.f (x x))"
+        );
+        assert_eq!(
+            parse("a \\x.x b"),
+            Term::app(Term::var("a"), parse("\\x.x b"))
+        );
+        assert_eq!(Term::parse("\\.x"), Err(LambdaError::MissingParameter(0)));
+        assert_eq!(Term::parse("(x"), Err(LambdaError::UnexpectedEnd));
+        assert_eq!(
+            Term::parse("x)"),
+            Err(LambdaError::UnexpectedChar {
+                offset: 1,
+                found: ')'
+            })
+        );
+    }
+
+    #[test]
+    fn substitution_avoids_capture() {
+        // (\x.\y.x) y must not become \y.y
+        let term = parse("(\\x.\\y.x) y");
+        let reduced = term.step(Strategy::NormalOrder).unwrap();
+        assert_eq!(reduced.to_string(), "\\y'.y");
+        assert!(reduced.alpha_eq(&parse("\\z.y")));
+        assert!(!reduced.alpha_eq(&parse("\\y.y")));
+        assert_eq!(
+            parse("\\x.y x'").substitute("y", &parse("x")).to_string(),
+            "\\x''.x x'"
+        );
+        assert!(parse("\\x.x").alpha_eq(&parse("\\y.y")));
+        assert!(!parse("\\x.\\y.x").alpha_eq(&parse("\\x.\\y.y")));
+    }
+
+    #[test]
+    fn strategies_differ_on_divergent_arguments() {
+        // (\x.z) applied to Ω: normal order discards Ω, applicative order loops
+        let term = parse("(\\x.z) ((\\x.x x) (\\x.x x))");
+        assert_eq!(
+            term.normalize(Strategy::NormalOrder, 100),
+            Some(Term::var("z"))
+        );
+        assert_eq!(term.normalize(Strategy::ApplicativeOrder, 100), None);
+    }
+
+    #[test]
+    fn traces_reductions() {
+        let term = parse("(\\x.x x) ((\\y.y) a)");
+        let normal: Vec<String> = term
+            .reductions(Strategy::NormalOrder)
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(normal, ["(\\y.y) a ((\\y.y) a)", "a ((\\y.y) a)", "a a"]);
+        let applicative: Vec<String> = term
+            .reductions(Strategy::ApplicativeOrder)
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(applicative, ["(\\x.x x) a", "a a"]);
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::Pattern;
+use super::pattern::Match;
+
+/// A byte range of the source, `start..end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// The smallest span covering both
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A line and column, both counted from 1, with columns in chars
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// The position of byte `offset` of `source`
+    pub fn of(source: &str, offset: usize) -> Position {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<K> {
+    pub kind: K,
+    pub span: Span,
+    /// Where the token starts
+    pub position: Position,
+}
+
+impl<K> Token<K> {
+    /// The source text the token covers
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.span.start..self.span.end]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule<K> {
+    pattern: Pattern,
+    /// None for text that is skipped, like whitespace
+    kind: Option<K>,
+}
+
+/// A tokenizer driven by a list of rules, each a pattern and the kind of
+/// token it makes.
+///
+/// At each position the rule with the longest match wins, and the earliest
+/// added wins a tie, so adding keywords before `Pattern::Identifier` makes
+/// `if` a keyword and `iffy` an identifier. Text no rule matches, and
+/// tokens begun but not finished, come out as tokens of the error kind
+/// rather than stopping the scan.
+#[derive(Debug, Clone)]
+pub struct Lexer<K> {
+    rules: Vec<Rule<K>>,
+    error: K,
+}
+
+impl<K: Clone> Lexer<K> {
+    /// A lexer with no rules yet, that marks unrecognised text with `error`
+    pub fn new(error: K) -> Lexer<K> {
+        Lexer {
+            rules: Vec::new(),
+            error,
+        }
+    }
+
+    pub fn add_rule(&mut self, pattern: Pattern, kind: K) {
+        self.rules.push(Rule {
+            pattern,
+            kind: Some(kind),
+        });
+    }
+
+    /// Adds a rule whose matches separate tokens but are not tokens
+    pub fn add_skip(&mut self, pattern: Pattern) {
+        self.rules.push(Rule {
+            pattern,
+            kind: None,
+        });
+    }
+
+    /// Splits `source` into tokens. A run of chars no rule matches makes
+    /// one error token
+    pub fn tokenize(&self, source: &str) -> Vec<Token<K>> {
+        let mut tokens: Vec<Token<K>> = Vec::new();
+        let mut position = Position { line: 1, column: 1 };
+        let mut offset = 0;
+        // An error token still open, extended while nothing matches
+        let mut unmatched: Option<(usize, Position)> = None;
+        while offset < source.len() {
+            let rest = &source[offset..];
+            let best = self
+                .rules
+                .iter()
+                .filter_map(|rule| rule.pattern.find(rest).map(|found| (rule, found)))
+                .fold(
+                    None,
+                    |best: Option<(&Rule<K>, Match)>, (rule, found)| match best {
+                        Some((_, longest)) if longest.len() >= found.len() => best,
+                        _ => Some((rule, found)),
+                    },
+                );
+            let len = match best {
+                None => rest.chars().next().map_or(1, char::len_utf8),
+                Some((_, found)) => found.len(),
+            };
+            match best {
+                None => {
+                    unmatched.get_or_insert((offset, position));
+                }
+                Some((rule, found)) => {
+                    if let Some((start, at)) = unmatched.take() {
+                        tokens.push(self.error_token(start, offset, at));
+                    }
+                    let kind = match found {
+                        Match::Malformed(_) => Some(self.error.clone()),
+                        Match::Token(_) => rule.kind.clone(),
+                    };
+                    if let Some(kind) = kind {
+                        tokens.push(Token {
+                            kind,
+                            span: Span::new(offset, offset + len),
+                            position,
+                        });
+                    }
+                }
+            }
+            for c in rest[..len].chars() {
+                if c == '\n' {
+                    position.line += 1;
+                    position.column = 1;
+                } else {
+                    position.column += 1;
+                }
+            }
+            offset += len;
+        }
+        if let Some((start, at)) = unmatched {
+            tokens.push(self.error_token(start, offset, at));
+        }
+        tokens
+    }
+
+    fn error_token(&self, start: usize, end: usize, position: Position) -> Token<K> {
+        Token {
+            kind: self.error.clone(),
+            span: Span::new(start, end),
+            position,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lexer, Position, Span, Token};
+    use crate::language::lexer::Pattern;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Kind {
+        Let,
+        If,
+        Ident,
+        Number,
+        Str,
+        Eq,
+        EqEq,
+        Plus,
+        Error,
+    }
+
+    fn lexer() -> Lexer<Kind> {
+        let mut lexer = Lexer::new(Kind::Error);
+        lexer.add_skip(Pattern::Whitespace);
+        lexer.add_skip(Pattern::LineComment("//".to_string()));
+        lexer.add_rule(Pattern::exact("let"), Kind::Let);
+        lexer.add_rule(Pattern::exact("if"), Kind::If);
+        lexer.add_rule(Pattern::Identifier, Kind::Ident);
+        lexer.add_rule(Pattern::Number, Kind::Number);
+        lexer.add_rule(Pattern::String('"'), Kind::Str);
+        lexer.add_rule(Pattern::exact("="), Kind::Eq);
+        lexer.add_rule(Pattern::exact("=="), Kind::EqEq);
+        lexer.add_rule(Pattern::exact("+"), Kind::Plus);
+        lexer
+    }
+
+    fn kinds_and_texts<'a>(tokens: &[Token<Kind>], source: &'a str) -> Vec<(Kind, &'a str)> {
+        tokens.iter().map(|t| (t.kind, t.text(source))).collect()
+    }
+
+    #[test]
+    fn longest_match_and_rule_order() {
+        let source = "let iffy = if == 1.5e3+x2 \"a \\\" b\" // done";
+        let tokens = lexer().tokenize(source);
+        assert_eq!(
+            kinds_and_texts(&tokens, source),
+            [
+                (Kind::Let, "let"),
+                (Kind::Ident, "iffy"),
+                (Kind::Eq, "="),
+                (Kind::If, "if"),
+                (Kind::EqEq, "=="),
+                (Kind::Number, "1.5e3"),
+                (Kind::Plus, "+"),
+                (Kind::Ident, "x2"),
+                (Kind::Str, "\"a \\\" b\""),
+            ]
+        );
+        // A dot or exponent without digits is not part of the number
+        let source = "1. 2e+";
+        let tokens = lexer().tokenize(source);
+        assert_eq!(
+            kinds_and_texts(&tokens, source),
+            [
+                (Kind::Number, "1"),
+                (Kind::Error, "."),
+                (Kind::Number, "2"),
+                (Kind::Ident, "e"),
+                (Kind::Plus, "+"),
+            ]
+        );
+    }
+
+    #[test]
+    fn positions() {
+        let source = "let x =\n  héllo // note\n\n+ 3";
+        let tokens = lexer().tokenize(source);
+        let positions: Vec<(usize, usize)> = tokens
+            .iter()
+            .map(|t| (t.position.line, t.position.column))
+            .collect();
+        assert_eq!(positions, [(1, 1), (1, 5), (1, 7), (2, 3), (4, 1), (4, 3)]);
+        assert_eq!(tokens[3].span, Span::new(10, 16));
+        assert_eq!(Position { line: 4, column: 3 }.to_string(), "4:3");
+        for token in &tokens {
+            assert_eq!(Position::of(source, token.span.start), token.position);
+        }
+    }
+
+    #[test]
+    fn error_tokens() {
+        let source = "a $$ b @\n\"open";
+        let tokens = lexer().tokenize(source);
+        assert_eq!(
+            kinds_and_texts(&tokens, source),
+            [
+                (Kind::Ident, "a"),
+                (Kind::Error, "$$"),
+                (Kind::Ident, "b"),
+                (Kind::Error, "@"),
+                (Kind::Error, "\"open"),
+            ]
+        );
+        assert_eq!(tokens[4].position, Position { line: 2, column: 1 });
+        assert!(Lexer::new(Kind::Error).tokenize("").is_empty());
+    }
+}
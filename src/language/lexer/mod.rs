@@ -0,0 +1,5 @@
+mod lexer;
+mod pattern;
+
+pub use self::lexer::{Lexer, Position, Span, Token};
+pub use self::pattern::Pattern;
@@ -0,0 +1,116 @@
+/// What a lexer rule matches at the current position
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// Exactly this text, for keywords and operators. Longest match keeps a
+    /// keyword like `if` from matching the start of an identifier like `iffy`
+    Exact(String),
+    /// A letter or '_' followed by letters, digits and '_'
+    Identifier,
+    /// A run of ASCII digits
+    Integer,
+    /// Digits with an optional fraction and exponent, like `6.02e23`
+    Number,
+    /// Text between two of this quote char, where '\' escapes the next
+    /// char. One left open to the end of the input is an error token
+    String(char),
+    /// This prefix and the rest of its line
+    LineComment(String),
+    Whitespace,
+    /// The length in bytes of the match at the start of the given text
+    Custom(fn(&str) -> Option<usize>),
+}
+
+/// How much of the text a pattern takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Match {
+    Token(usize),
+    /// A token begun but not finished, like an unterminated string
+    Malformed(usize),
+}
+
+impl Match {
+    pub(super) fn len(self) -> usize {
+        match self {
+            Match::Token(len) | Match::Malformed(len) => len,
+        }
+    }
+}
+
+impl Pattern {
+    pub fn exact(text: &str) -> Pattern {
+        Pattern::Exact(text.to_string())
+    }
+
+    /// The match at the start of `text`, if any and not empty
+    pub(super) fn find(&self, text: &str) -> Option<Match> {
+        let len = match self {
+            Pattern::Exact(exact) => text.starts_with(exact.as_str()).then_some(exact.len()),
+            Pattern::Identifier => {
+                let first = text.chars().next()?;
+                (first.is_alphabetic() || first == '_').then(|| {
+                    first.len_utf8()
+                        + prefix_len(&text[first.len_utf8()..], |c| {
+                            c.is_alphanumeric() || c == '_'
+                        })
+                })
+            }
+            Pattern::Integer => Some(prefix_len(text, |c| c.is_ascii_digit())),
+            Pattern::Number => {
+                let mut len = prefix_len(text, |c| c.is_ascii_digit());
+                if len > 0 {
+                    len += fraction_len(&text[len..]);
+                    len += exponent_len(&text[len..]);
+                }
+                Some(len)
+            }
+            Pattern::String(quote) => {
+                let mut chars = text.char_indices();
+                if chars.next()?.1 != *quote {
+                    return None;
+                }
+                while let Some((i, c)) = chars.next() {
+                    if c == '\\' {
+                        chars.next();
+                    } else if c == *quote {
+                        return Some(Match::Token(i + c.len_utf8()));
+                    }
+                }
+                return Some(Match::Malformed(text.len()));
+            }
+            Pattern::LineComment(prefix) => text
+                .starts_with(prefix.as_str())
+                .then(|| text.find('\n').unwrap_or(text.len())),
+            Pattern::Whitespace => Some(prefix_len(text, char::is_whitespace)),
+            Pattern::Custom(matcher) => matcher(text).filter(|&len| len <= text.len()),
+        };
+        len.filter(|&len| len > 0).map(Match::Token)
+    }
+}
+
+/// The length in bytes of the longest prefix of chars satisfying `accept`
+fn prefix_len(text: &str, accept: impl Fn(char) -> bool) -> usize {
+    text.find(|c| !accept(c)).unwrap_or(text.len())
+}
+
+/// `.` and at least one digit, or nothing
+fn fraction_len(text: &str) -> usize {
+    match text
+        .strip_prefix('.')
+        .map(|rest| prefix_len(rest, |c| c.is_ascii_digit()))
+    {
+        Some(digits) if digits > 0 => 1 + digits,
+        _ => 0,
+    }
+}
+
+/// `e` or `E`, an optional sign and at least one digit, or nothing
+fn exponent_len(text: &str) -> usize {
+    let Some(rest) = text.strip_prefix(['e', 'E']) else {
+        return 0;
+    };
+    let sign = usize::from(rest.starts_with(['+', '-']));
+    match prefix_len(&rest[sign..], |c| c.is_ascii_digit()) {
+        0 => 0,
+        digits => 1 + sign + digits,
+    }
+}
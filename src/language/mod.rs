@@ -0,0 +1,6 @@
+pub mod brainfuck;
+pub mod expr;
+pub mod grammar;
+pub mod lambda;
+pub mod lexer;
+pub mod vm;
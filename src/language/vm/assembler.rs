@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use crate::language::lexer::{Lexer, Pattern, Token};
+
+use super::{AssembleError, Instruction, Program};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Name,
+    Number,
+    Colon,
+    Error,
+}
+
+/// An operand as written: a number or a label
+enum Operand<'a> {
+    Number(i64),
+    Label(&'a str),
+}
+
+impl Program {
+    /// Assembles one instruction per line, like
+    ///
+    /// ```text
+    /// ; prints 5!
+    ///         push 5
+    ///         call fact 1
+    ///         print
+    ///         halt
+    /// fact:   load 0          ; n
+    ///         jz one
+    ///         load 0
+    ///         push 1
+    ///         sub
+    ///         call fact 1
+    ///         load 0
+    ///         mul
+    ///         ret
+    /// one:    push 1
+    ///         ret
+    /// ```
+    ///
+    /// A line may start with labels, each a name and ':', that stand for
+    /// the index of the next instruction. Jump and call targets are labels
+    /// or indices, and `;` starts a comment.
+    pub fn assemble(text: &str) -> Result<Program, AssembleError> {
+        let mut lexer = Lexer::new(Kind::Error);
+        lexer.add_skip(Pattern::Whitespace);
+        lexer.add_skip(Pattern::LineComment(";".to_string()));
+        lexer.add_rule(Pattern::Identifier, Kind::Name);
+        lexer.add_rule(Pattern::Custom(signed_integer), Kind::Number);
+        lexer.add_rule(Pattern::exact(":"), Kind::Colon);
+        let tokens = lexer.tokenize(text);
+
+        let mut lines: Vec<(usize, Vec<Token<Kind>>)> = Vec::new();
+        for token in tokens {
+            if token.kind == Kind::Error {
+                return Err(AssembleError::UnexpectedText(token.position.line));
+            }
+            match lines.last_mut() {
+                Some((line, tokens)) if *line == token.position.line => tokens.push(token),
+                _ => lines.push((token.position.line, vec![token])),
+            }
+        }
+
+        let mut labels = HashMap::new();
+        let mut statements = Vec::new();
+        for (line, tokens) in &lines {
+            let mut rest = tokens.as_slice();
+            while let [name, colon, after @ ..] = rest
+                && (name.kind, colon.kind) == (Kind::Name, Kind::Colon)
+            {
+                let label = name.text(text);
+                if labels.insert(label, statements.len()).is_some() {
+                    return Err(AssembleError::DuplicateLabel {
+                        line: *line,
+                        label: label.to_string(),
+                    });
+                }
+                rest = after;
+            }
+            if !rest.is_empty() {
+                statements.push((*line, rest));
+            }
+        }
+
+        let mut instructions = Vec::new();
+        for (line, tokens) in statements {
+            let (mnemonic, operands) = tokens.split_first().unwrap();
+            if mnemonic.kind != Kind::Name {
+                return Err(AssembleError::UnexpectedText(line));
+            }
+            let operands: Vec<Operand> = operands
+                .iter()
+                .map(|token| match token.kind {
+                    Kind::Number => token
+                        .text(text)
+                        .parse()
+                        .map(Operand::Number)
+                        .map_err(|_| AssembleError::BadOperands(line)),
+                    Kind::Name => Ok(Operand::Label(token.text(text))),
+                    _ => Err(AssembleError::UnexpectedText(line)),
+                })
+                .collect::<Result<_, _>>()?;
+            let target = |operand: &Operand| match *operand {
+                Operand::Number(n) => {
+                    usize::try_from(n).map_err(|_| AssembleError::BadOperands(line))
+                }
+                Operand::Label(label) => {
+                    labels
+                        .get(label)
+                        .copied()
+                        .ok_or_else(|| AssembleError::UnknownLabel {
+                            line,
+                            label: label.to_string(),
+                        })
+                }
+            };
+            let count = |operand: &Operand| match *operand {
+                Operand::Number(n) => {
+                    usize::try_from(n).map_err(|_| AssembleError::BadOperands(line))
+                }
+                Operand::Label(_) => Err(AssembleError::BadOperands(line)),
+            };
+            let name = mnemonic.text(text);
+            let plain = ZERO_OPERAND.into_iter().find(|i| i.name() == name);
+            let instruction = match (name, operands.as_slice(), plain) {
+                ("push", [Operand::Number(n)], _) => Instruction::Push(*n),
+                ("jmp", [at], _) => Instruction::Jump(target(at)?),
+                ("jz", [at], _) => Instruction::JumpIfZero(target(at)?),
+                ("jnz", [at], _) => Instruction::JumpIfNotZero(target(at)?),
+                ("call", [at, args], _) => Instruction::Call {
+                    target: target(at)?,
+                    args: count(args)?,
+                },
+                ("load", [n], _) => Instruction::Load(count(n)?),
+                ("store", [n], _) => Instruction::Store(count(n)?),
+                (_, [], Some(instruction)) => instruction,
+                _ if plain.is_some() || HAS_OPERANDS.contains(&name) => {
+                    return Err(AssembleError::BadOperands(line));
+                }
+                _ => {
+                    return Err(AssembleError::UnknownInstruction {
+                        line,
+                        name: name.to_string(),
+                    });
+                }
+            };
+            instructions.push(instruction);
+        }
+        Ok(Program::new(instructions))
+    }
+}
+
+const ZERO_OPERAND: [Instruction; 19] = [
+    Instruction::Pop,
+    Instruction::Dup,
+    Instruction::Swap,
+    Instruction::Add,
+    Instruction::Sub,
+    Instruction::Mul,
+    Instruction::Div,
+    Instruction::Rem,
+    Instruction::Neg,
+    Instruction::Not,
+    Instruction::Eq,
+    Instruction::Ne,
+    Instruction::Lt,
+    Instruction::Le,
+    Instruction::Gt,
+    Instruction::Ge,
+    Instruction::Ret,
+    Instruction::Print,
+    Instruction::Halt,
+];
+
+const HAS_OPERANDS: [&str; 7] = ["push", "jmp", "jz", "jnz", "call", "load", "store"];
+
+/// An optional '-' and at least one digit
+fn signed_integer(text: &str) -> Option<usize> {
+    let sign = usize::from(text.starts_with('-'));
+    match text[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len() - sign)
+    {
+        0 => None,
+        digits => Some(sign + digits),
+    }
+}
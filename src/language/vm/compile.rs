@@ -0,0 +1,170 @@
+use crate::language::expr::{BinaryOp, EvalError, Expr, ExprKind, UnaryOp};
+
+use super::{Instruction, Program};
+
+impl Program {
+    /// Compiles `expr` to code that leaves its value on the stack, reading
+    /// `variables[i]` from local `i`, so the values are the arguments to
+    /// run it with. Bools become 0 and 1, and `&&`, `||` and `?:` jump over
+    /// the operands they skip.
+    ///
+    /// Types are not checked; run `Expr::type_check` first to reject
+    /// expressions whose bools and ints would mix.
+    pub fn compile(expr: &Expr, variables: &[&str]) -> Result<Program, EvalError> {
+        let mut code = Vec::new();
+        emit(expr, variables, &mut code)?;
+        Ok(Program::new(code))
+    }
+}
+
+fn emit(expr: &Expr, variables: &[&str], code: &mut Vec<Instruction>) -> Result<(), EvalError> {
+    match &expr.kind {
+        ExprKind::Int(n) => code.push(Instruction::Push(*n)),
+        ExprKind::Bool(b) => code.push(Instruction::Push(i64::from(*b))),
+        ExprKind::Var(name) => {
+            let local = variables
+                .iter()
+                .position(|variable| variable == name)
+                .ok_or_else(|| EvalError::UnboundVariable(expr.span, name.clone()))?;
+            code.push(Instruction::Load(local));
+        }
+        ExprKind::Unary(op, operand) => {
+            emit(operand, variables, code)?;
+            code.push(match op {
+                UnaryOp::Neg => Instruction::Neg,
+                UnaryOp::Not => Instruction::Not,
+            });
+        }
+        // `a && b` is `a ? b : false`, and `a || b` is `a ? true : b`
+        ExprKind::Binary(op @ (BinaryOp::And | BinaryOp::Or), left, right) => {
+            emit(left, variables, code)?;
+            let skip = code.len();
+            code.push(match op {
+                BinaryOp::And => Instruction::JumpIfZero(0),
+                _ => Instruction::JumpIfNotZero(0),
+            });
+            emit(right, variables, code)?;
+            let end = code.len();
+            code.push(Instruction::Jump(0));
+            patch(code, skip);
+            code.push(Instruction::Push(i64::from(*op == BinaryOp::Or)));
+            patch(code, end);
+        }
+        ExprKind::Binary(op, left, right) => {
+            emit(left, variables, code)?;
+            emit(right, variables, code)?;
+            code.push(match op {
+                BinaryOp::Add => Instruction::Add,
+                BinaryOp::Sub => Instruction::Sub,
+                BinaryOp::Mul => Instruction::Mul,
+                BinaryOp::Div => Instruction::Div,
+                BinaryOp::Rem => Instruction::Rem,
+                BinaryOp::Eq => Instruction::Eq,
+                BinaryOp::Ne => Instruction::Ne,
+                BinaryOp::Lt => Instruction::Lt,
+                BinaryOp::Le => Instruction::Le,
+                BinaryOp::Gt => Instruction::Gt,
+                BinaryOp::Ge => Instruction::Ge,
+                BinaryOp::And | BinaryOp::Or => unreachable!(),
+            });
+        }
+        ExprKind::If(condition, then, otherwise) => {
+            emit(condition, variables, code)?;
+            let skip = code.len();
+            code.push(Instruction::JumpIfZero(0));
+            emit(then, variables, code)?;
+            let end = code.len();
+            code.push(Instruction::Jump(0));
+            patch(code, skip);
+            emit(otherwise, variables, code)?;
+            patch(code, end);
+        }
+        ExprKind::Error => return Err(EvalError::Invalid(expr.span)),
+    }
+    Ok(())
+}
+
+/// Points the jump at `at` to the next instruction to be emitted
+fn patch(code: &mut [Instruction], at: usize) {
+    let next = code.len();
+    match &mut code[at] {
+        Instruction::Jump(target)
+        | Instruction::JumpIfZero(target)
+        | Instruction::JumpIfNotZero(target) => *target = next,
+        instruction => unreachable!("{instruction} is not a jump"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::language::expr::{EvalError, Expr, Span, Value};
+    use crate::language::vm::{Instruction, Program, Vm, VmError};
+
+    #[test]
+    fn agrees_with_the_evaluator() {
+        let sources = [
+            "1 + 2 * 3 - -4",
+            "x / 3 + x % 3",
+            "x > y ? x - y : y - x",
+            "x == 0 || 100 / x > 10",
+            "x != 0 && 100 / x > 10",
+            "!(x < y) == (x >= y)",
+            "x < 0 ? y < 0 ? 1 : 2 : y < 0 ? 3 : 4",
+            "x * x * x * x * x * x * x",
+            "(x + y) / (x - y)",
+        ];
+        let values = [-20, -3, -1, 0, 1, 2, 7, 1000];
+        for source in sources {
+            let expr = Expr::parse(source).unwrap();
+            let program = Program::compile(&expr, &["x", "y"]).unwrap();
+            for x in values {
+                for y in values {
+                    let env = HashMap::from([
+                        ("x".to_string(), Value::Int(x)),
+                        ("y".to_string(), Value::Int(y)),
+                    ]);
+                    let expected = match expr.eval(&env) {
+                        Ok(Value::Int(n)) => Ok(n),
+                        Ok(Value::Bool(b)) => Ok(i64::from(b)),
+                        Err(EvalError::DivisionByZero(_)) => Err("division by zero"),
+                        Err(EvalError::Overflow(_)) => Err("overflow"),
+                        Err(error) => panic!("{error}"),
+                    };
+                    let actual = match Vm::new(&program, &[x, y]).run() {
+                        Ok(top) => Ok(top.unwrap()),
+                        Err(VmError::DivisionByZero(_)) => Err("division by zero"),
+                        Err(VmError::Overflow(_)) => Err("overflow"),
+                        Err(error) => panic!("{error}"),
+                    };
+                    assert_eq!(actual, expected, "{source} with x = {x}, y = {y}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generated_code() {
+        let expr = Expr::parse("a && b ? 1 : 2").unwrap();
+        let program = Program::compile(&expr, &["a", "b"]).unwrap();
+        assert_eq!(
+            program.instructions,
+            [
+                Instruction::Load(0),
+                Instruction::JumpIfZero(4),
+                Instruction::Load(1),
+                Instruction::Jump(5),
+                Instruction::Push(0),
+                Instruction::JumpIfZero(8),
+                Instruction::Push(1),
+                Instruction::Jump(9),
+                Instruction::Push(2),
+            ]
+        );
+        assert_eq!(
+            Program::compile(&expr, &["a"]),
+            Err(EvalError::UnboundVariable(Span::new(5, 6), "b".to_string()))
+        );
+    }
+}
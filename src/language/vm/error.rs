@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons assembly fails, each with the line (counted from 1) at fault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssembleError {
+    UnknownInstruction {
+        line: usize,
+        name: String,
+    },
+    /// Too few or too many operands, or one of the wrong kind
+    BadOperands(usize),
+    UnknownLabel {
+        line: usize,
+        label: String,
+    },
+    DuplicateLabel {
+        line: usize,
+        label: String,
+    },
+    /// Text that is not a name, number or ':'
+    UnexpectedText(usize),
+}
+
+impl Display for AssembleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AssembleError::UnknownInstruction { line, name } => {
+                write!(f, "unknown instruction {name} on line {line}")
+            }
+            AssembleError::BadOperands(line) => write!(f, "bad operands on line {line}"),
+            AssembleError::UnknownLabel { line, label } => {
+                write!(f, "unknown label {label} on line {line}")
+            }
+            AssembleError::DuplicateLabel { line, label } => {
+                write!(f, "label {label} defined again on line {line}")
+            }
+            AssembleError::UnexpectedText(line) => write!(f, "unexpected text on line {line}"),
+        }
+    }
+}
+
+impl Error for AssembleError {}
+
+/// Reasons a running program stops, each with the index of the instruction
+/// at fault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmError {
+    /// An instruction needed more values than the stack held
+    StackUnderflow(usize),
+    DivisionByZero(usize),
+    Overflow(usize),
+    /// A load or store past the current frame
+    BadLocal(usize),
+    /// A jump or call to an index past the end of the program
+    BadTarget(usize),
+    /// A `ret` with no call to return from
+    ReturnWithoutCall(usize),
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::StackUnderflow(pc) => write!(f, "stack underflow at {pc}"),
+            VmError::DivisionByZero(pc) => write!(f, "division by zero at {pc}"),
+            VmError::Overflow(pc) => write!(f, "integer overflow at {pc}"),
+            VmError::BadLocal(pc) => write!(f, "local out of the frame at {pc}"),
+            VmError::BadTarget(pc) => write!(f, "jump past the end of the program at {pc}"),
+            VmError::ReturnWithoutCall(pc) => write!(f, "return without a call at {pc}"),
+        }
+    }
+}
+
+impl Error for VmError {}
@@ -0,0 +1,125 @@
+use std::fmt::{self, Display, Formatter};
+
+/// An instruction of the stack machine. Values are `i64`, with 0 as false
+/// and 1 as true for comparisons; jump and call targets are instruction
+/// indices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Push(i64),
+    Pop,
+    /// Pushes a copy of the top
+    Dup,
+    /// Exchanges the top two
+    Swap,
+    /// Pops b, then a, and pushes a + b; the same for the other binary ops
+    Add,
+    Sub,
+    Mul,
+    /// Division rounding toward zero
+    Div,
+    Rem,
+    Neg,
+    /// Pushes 1 for 0 and 0 for anything else
+    Not,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Jump(usize),
+    /// Pops and jumps if it was zero
+    JumpIfZero(usize),
+    /// Pops and jumps if it was not zero
+    JumpIfNotZero(usize),
+    /// Calls the function at the target with the top `args` values as its
+    /// first locals
+    Call {
+        target: usize,
+        args: usize,
+    },
+    /// Pops the return value, drops the frame's locals and pushes it
+    /// for the caller
+    Ret,
+    /// Pushes local `n` of the current frame
+    Load(usize),
+    /// Pops into local `n` of the current frame
+    Store(usize),
+    /// Pops and appends to the output
+    Print,
+    Halt,
+}
+
+impl Instruction {
+    /// The assembler mnemonic
+    pub fn name(&self) -> &'static str {
+        match self {
+            Instruction::Push(_) => "push",
+            Instruction::Pop => "pop",
+            Instruction::Dup => "dup",
+            Instruction::Swap => "swap",
+            Instruction::Add => "add",
+            Instruction::Sub => "sub",
+            Instruction::Mul => "mul",
+            Instruction::Div => "div",
+            Instruction::Rem => "rem",
+            Instruction::Neg => "neg",
+            Instruction::Not => "not",
+            Instruction::Eq => "eq",
+            Instruction::Ne => "ne",
+            Instruction::Lt => "lt",
+            Instruction::Le => "le",
+            Instruction::Gt => "gt",
+            Instruction::Ge => "ge",
+            Instruction::Jump(_) => "jmp",
+            Instruction::JumpIfZero(_) => "jz",
+            Instruction::JumpIfNotZero(_) => "jnz",
+            Instruction::Call { .. } => "call",
+            Instruction::Ret => "ret",
+            Instruction::Load(_) => "load",
+            Instruction::Store(_) => "store",
+            Instruction::Print => "print",
+            Instruction::Halt => "halt",
+        }
+    }
+}
+
+/// Prints in the assembler's syntax, with targets as numbers
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())?;
+        match self {
+            Instruction::Push(n) => write!(f, " {n}"),
+            Instruction::Jump(at)
+            | Instruction::JumpIfZero(at)
+            | Instruction::JumpIfNotZero(at)
+            | Instruction::Load(at)
+            | Instruction::Store(at) => write!(f, " {at}"),
+            Instruction::Call { target, args } => write!(f, " {target} {args}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A sequence of instructions, run from the first
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn new(instructions: Vec<Instruction>) -> Program {
+        Program { instructions }
+    }
+}
+
+/// One instruction per line, prefixed by its index as a comment, in a form
+/// `Program::assemble` reads back
+impl Display for Program {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            writeln!(f, "{:<12} ; {i}", instruction.to_string())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,374 @@
+use super::{Instruction, Program, VmError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Frame {
+    return_to: usize,
+    /// Where the frame's locals start on the stack
+    base: usize,
+}
+
+/// A running program: a value stack, a stack of call frames and the output
+/// printed so far.
+///
+/// The program's own locals are the arguments it starts with, at the
+/// bottom of the stack; a call's locals are the arguments it was passed and
+/// anything pushed above them.
+#[derive(Debug, Clone)]
+pub struct Vm<'a> {
+    program: &'a Program,
+    pc: usize,
+    stack: Vec<i64>,
+    frames: Vec<Frame>,
+    output: Vec<i64>,
+    halted: bool,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a Program, args: &[i64]) -> Vm<'a> {
+        Vm {
+            program,
+            pc: 0,
+            stack: args.to_vec(),
+            frames: Vec::new(),
+            output: Vec::new(),
+            halted: false,
+        }
+    }
+
+    /// The index of the next instruction
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn stack(&self) -> &[i64] {
+        &self.stack
+    }
+
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    /// Whether the program has run `halt` or off its end
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Runs to the end and returns the value left on top of the stack
+    pub fn run(&mut self) -> Result<Option<i64>, VmError> {
+        self.run_traced(|_, _, _| {})
+    }
+
+    /// Like `run`, calling `trace` with the index of each instruction, the
+    /// instruction and the stack just before it runs
+    pub fn run_traced(
+        &mut self,
+        mut trace: impl FnMut(usize, Instruction, &[i64]),
+    ) -> Result<Option<i64>, VmError> {
+        while let Some(&instruction) = self.program.instructions.get(self.pc) {
+            if self.halted {
+                break;
+            }
+            trace(self.pc, instruction, &self.stack);
+            self.step()?;
+        }
+        self.halted = true;
+        Ok(self.stack.last().copied())
+    }
+
+    /// Runs one instruction, returning false if there was none left to run.
+    /// On an error the machine stays at the instruction at fault
+    pub fn step(&mut self) -> Result<bool, VmError> {
+        let Some(&instruction) = self.program.instructions.get(self.pc) else {
+            self.halted = true;
+            return Ok(false);
+        };
+        if self.halted {
+            return Ok(false);
+        }
+        let pc = self.pc;
+        let mut next = pc + 1;
+        match instruction {
+            Instruction::Push(n) => self.stack.push(n),
+            Instruction::Pop => {
+                self.pop()?;
+            }
+            Instruction::Dup => {
+                let top = *self.stack.last().ok_or(VmError::StackUnderflow(pc))?;
+                self.stack.push(top);
+            }
+            Instruction::Swap => {
+                let len = self.needs(2)?;
+                self.stack.swap(len - 1, len - 2);
+            }
+            Instruction::Neg => {
+                let a = self.pop()?;
+                self.stack
+                    .push(a.checked_neg().ok_or(VmError::Overflow(pc))?);
+            }
+            Instruction::Not => {
+                let a = self.pop()?;
+                self.stack.push(i64::from(a == 0));
+            }
+            Instruction::Add
+            | Instruction::Sub
+            | Instruction::Mul
+            | Instruction::Div
+            | Instruction::Rem
+            | Instruction::Eq
+            | Instruction::Ne
+            | Instruction::Lt
+            | Instruction::Le
+            | Instruction::Gt
+            | Instruction::Ge => {
+                self.needs(2)?;
+                let b = self.pop()?;
+                let a = self.pop()?;
+                let result = match instruction {
+                    Instruction::Add => a.checked_add(b),
+                    Instruction::Sub => a.checked_sub(b),
+                    Instruction::Mul => a.checked_mul(b),
+                    Instruction::Div | Instruction::Rem if b == 0 => {
+                        self.stack.extend([a, b]);
+                        return Err(VmError::DivisionByZero(pc));
+                    }
+                    Instruction::Div => a.checked_div(b),
+                    Instruction::Rem => a.checked_rem(b),
+                    Instruction::Eq => Some(i64::from(a == b)),
+                    Instruction::Ne => Some(i64::from(a != b)),
+                    Instruction::Lt => Some(i64::from(a < b)),
+                    Instruction::Le => Some(i64::from(a <= b)),
+                    Instruction::Gt => Some(i64::from(a > b)),
+                    _ => Some(i64::from(a >= b)),
+                };
+                let Some(result) = result else {
+                    self.stack.extend([a, b]);
+                    return Err(VmError::Overflow(pc));
+                };
+                self.stack.push(result);
+            }
+            Instruction::Jump(target) => next = self.target(target)?,
+            Instruction::JumpIfZero(target) | Instruction::JumpIfNotZero(target) => {
+                let target = self.target(target)?;
+                let zero = self.pop()? == 0;
+                if zero == matches!(instruction, Instruction::JumpIfZero(_)) {
+                    next = target;
+                }
+            }
+            Instruction::Call { target, args } => {
+                let target = self.target(target)?;
+                let base = self.needs(args)? - args;
+                self.frames.push(Frame {
+                    return_to: next,
+                    base,
+                });
+                next = target;
+            }
+            Instruction::Ret => {
+                let frame = self.frames.last().ok_or(VmError::ReturnWithoutCall(pc))?;
+                let (return_to, base) = (frame.return_to, frame.base);
+                let value = self.pop()?;
+                self.stack.truncate(base);
+                self.stack.push(value);
+                self.frames.pop();
+                next = return_to;
+            }
+            Instruction::Load(n) => {
+                let at = self.local(n)?;
+                self.stack.push(self.stack[at]);
+            }
+            Instruction::Store(n) => {
+                let value = self.pop()?;
+                match self.local(n) {
+                    Ok(at) => self.stack[at] = value,
+                    Err(error) => {
+                        self.stack.push(value);
+                        return Err(error);
+                    }
+                }
+            }
+            Instruction::Print => {
+                let value = self.pop()?;
+                self.output.push(value);
+            }
+            Instruction::Halt => {
+                self.halted = true;
+                return Ok(true);
+            }
+        }
+        self.pc = next;
+        Ok(true)
+    }
+
+    /// The stack length, if it holds at least `count` values
+    fn needs(&self, count: usize) -> Result<usize, VmError> {
+        match self.stack.len() {
+            len if len >= count => Ok(len),
+            _ => Err(VmError::StackUnderflow(self.pc)),
+        }
+    }
+
+    fn pop(&mut self) -> Result<i64, VmError> {
+        self.stack.pop().ok_or(VmError::StackUnderflow(self.pc))
+    }
+
+    fn target(&self, target: usize) -> Result<usize, VmError> {
+        match target <= self.program.instructions.len() {
+            true => Ok(target),
+            false => Err(VmError::BadTarget(self.pc)),
+        }
+    }
+
+    /// The stack index of local `n` of the current frame
+    fn local(&self, n: usize) -> Result<usize, VmError> {
+        let base = self.frames.last().map_or(0, |frame| frame.base);
+        Some(base + n)
+            .filter(|&at| at < self.stack.len())
+            .ok_or(VmError::BadLocal(self.pc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vm;
+    use crate::language::vm::{AssembleError, Instruction, Program, VmError};
+
+    const FACTORIAL: &str = "
+        ; prints 5! and 10!
+                push 5
+                call fact 1
+                print
+                push 10
+                call fact 1
+                print
+                halt
+        fact:   load 0          ; n
+                jz one
+                load 0
+                push 1
+                sub
+                call fact 1
+                load 0
+                mul
+                ret
+        one:    push 1
+                ret
+    ";
+
+    #[test]
+    fn recursive_calls() {
+        let program = Program::assemble(FACTORIAL).unwrap();
+        assert_eq!(
+            program.instructions[1],
+            Instruction::Call { target: 7, args: 1 }
+        );
+        let mut vm = Vm::new(&program, &[]);
+        assert_eq!(vm.run(), Ok(None));
+        assert_eq!(vm.output(), [120, 3_628_800]);
+        // The listing assembles back to the same program
+        assert_eq!(Program::assemble(&program.to_string()), Ok(program));
+    }
+
+    #[test]
+    fn loops_and_locals() {
+        // Sums 1..=n for the argument n, keeping the total in local 1
+        let program = Program::assemble(
+            "
+                    push 0
+            loop:   load 0
+                    jz done
+                    load 1
+                    load 0
+                    add
+                    store 1
+                    load 0
+                    push 1
+                    sub
+                    store 0
+                    jmp loop
+            done:   load 1
+            ",
+        )
+        .unwrap();
+        assert_eq!(Vm::new(&program, &[100]).run(), Ok(Some(5050)));
+        assert_eq!(Vm::new(&program, &[0]).run(), Ok(Some(0)));
+    }
+
+    #[test]
+    fn stepping_and_tracing() {
+        let program = Program::assemble("push 2\npush 3\nswap\nsub\nhalt\npush 9").unwrap();
+        let mut vm = Vm::new(&program, &[]);
+        assert_eq!(vm.step(), Ok(true));
+        assert_eq!(vm.step(), Ok(true));
+        assert_eq!((vm.pc(), vm.stack()), (2, [2, 3].as_slice()));
+        let mut trace = Vec::new();
+        let top = vm.run_traced(|pc, instruction, stack| {
+            trace.push(format!("{pc}: {instruction} {stack:?}"))
+        });
+        assert_eq!(top, Ok(Some(1)));
+        assert_eq!(trace, ["2: swap [2, 3]", "3: sub [3, 2]", "4: halt [1]"]);
+        assert!(vm.is_halted());
+        assert_eq!(vm.step(), Ok(false));
+    }
+
+    #[test]
+    fn runtime_errors() {
+        let run = |text: &str| Vm::new(&Program::assemble(text).unwrap(), &[]).run();
+        assert_eq!(run("push 1\nadd"), Err(VmError::StackUnderflow(1)));
+        assert_eq!(run("push 1\npush 0\ndiv"), Err(VmError::DivisionByZero(2)));
+        assert_eq!(
+            run("push -9223372036854775808\nneg"),
+            Err(VmError::Overflow(1))
+        );
+        assert_eq!(run("push 1\nload 1"), Err(VmError::BadLocal(1)));
+        assert_eq!(run("jmp 5"), Err(VmError::BadTarget(0)));
+        assert_eq!(run("push 1\nret"), Err(VmError::ReturnWithoutCall(1)));
+        let program = Program::assemble("push 1\npush 0\nrem").unwrap();
+        let mut vm = Vm::new(&program, &[]);
+        assert!(vm.run().is_err());
+        assert_eq!((vm.pc(), vm.stack()), (2, [1, 0].as_slice()));
+    }
+
+    #[test]
+    fn assembly_errors() {
+        assert_eq!(
+            Program::assemble("push 1\nfrob"),
+            Err(AssembleError::UnknownInstruction {
+                line: 2,
+                name: "frob".to_string()
+            })
+        );
+        assert_eq!(
+            Program::assemble("push"),
+            Err(AssembleError::BadOperands(1))
+        );
+        assert_eq!(
+            Program::assemble("add 1"),
+            Err(AssembleError::BadOperands(1))
+        );
+        assert_eq!(
+            Program::assemble("push x"),
+            Err(AssembleError::BadOperands(1))
+        );
+        assert_eq!(
+            Program::assemble("jmp nowhere"),
+            Err(AssembleError::UnknownLabel {
+                line: 1,
+                label: "nowhere".to_string()
+            })
+        );
+        assert_eq!(
+            Program::assemble("a: push 1\na: halt"),
+            Err(AssembleError::DuplicateLabel {
+                line: 2,
+                label: "a".to_string()
+            })
+        );
+        assert_eq!(
+            Program::assemble("push 1 # 2"),
+            Err(AssembleError::UnexpectedText(1))
+        );
+        // Labels can share a line and stand before the end
+        let program = Program::assemble("a: b: jmp end\nend:").unwrap();
+        assert_eq!(program.instructions, [Instruction::Jump(1)]);
+    }
+}
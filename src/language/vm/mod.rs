@@ -0,0 +1,9 @@
+mod assembler;
+mod compile;
+mod error;
+mod instruction;
+mod machine;
+
+pub use self::error::{AssembleError, VmError};
+pub use self::instruction::{Instruction, Program};
+pub use self::machine::Vm;
@@ -1 +1,29 @@
+#![allow(clippy::module_inception)]
+
+#[cfg(feature = "algorithm")]
+pub mod algorithm;
+#[cfg(feature = "arch")]
+pub mod arch;
+#[cfg(feature = "automata")]
+pub mod automata;
+pub mod complexity;
+#[cfg(feature = "concurrency")]
+pub mod concurrency;
+#[cfg(feature = "data_structure")]
 pub mod data_structure;
+mod error;
+pub mod iter_utils;
+#[cfg(feature = "language")]
+pub mod language;
+#[cfg(feature = "os")]
+pub mod os;
+pub mod prelude;
+#[cfg(feature = "algorithm")]
+pub mod testgen;
+#[cfg(feature = "data_structure")]
+pub mod traits;
+pub mod visualize;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use self::error::Error;
@@ -0,0 +1,192 @@
+use super::RequestError;
+
+/// A safe sequence for the state, or None if it is unsafe.
+///
+/// `available[j]` is how many units of resource j are free, `max[i][j]`
+/// the most process i may ever hold and `allocation[i][j]` what it holds
+/// now. A sequence is safe if each process in turn can get all it may
+/// still need from what is free plus what the processes before it release.
+pub fn bankers_algorithm(
+    available: &[u32],
+    max: &[Vec<u32>],
+    allocation: &[Vec<u32>],
+) -> Option<Vec<usize>> {
+    let mut work = available.to_vec();
+    let mut finished = vec![false; max.len()];
+    let mut sequence = Vec::with_capacity(max.len());
+    // Take the first process that can finish, then look again from the start
+    while let Some(i) = (0..max.len())
+        .find(|&i| !finished[i] && (0..work.len()).all(|j| max[i][j] - allocation[i][j] <= work[j]))
+    {
+        for (free, held) in work.iter_mut().zip(&allocation[i]) {
+            *free += held;
+        }
+        finished[i] = true;
+        sequence.push(i);
+    }
+    (sequence.len() == max.len()).then_some(sequence)
+}
+
+/// A resource-allocation state that only grants requests leaving it safe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Banker {
+    available: Vec<u32>,
+    max: Vec<Vec<u32>>,
+    allocation: Vec<Vec<u32>>,
+}
+
+impl Banker {
+    /// A state with one row of `max` and `allocation` per process and one
+    /// column per resource
+    pub fn new(available: Vec<u32>, max: Vec<Vec<u32>>, allocation: Vec<Vec<u32>>) -> Banker {
+        assert_eq!(max.len(), allocation.len(), "one row per process");
+        for (claim, held) in max.iter().zip(&allocation) {
+            assert!(
+                claim.len() == available.len() && held.len() == available.len(),
+                "one column per resource"
+            );
+            assert!(
+                held.iter().zip(claim).all(|(h, c)| h <= c),
+                "a process holds more than its maximum"
+            );
+        }
+        Banker {
+            available,
+            max,
+            allocation,
+        }
+    }
+
+    pub fn available(&self) -> &[u32] {
+        &self.available
+    }
+
+    pub fn allocation(&self, process: usize) -> &[u32] {
+        &self.allocation[process]
+    }
+
+    /// What the process may still ask for
+    pub fn need(&self, process: usize) -> Vec<u32> {
+        self.max[process]
+            .iter()
+            .zip(&self.allocation[process])
+            .map(|(claim, held)| claim - held)
+            .collect()
+    }
+
+    pub fn safe_sequence(&self) -> Option<Vec<usize>> {
+        bankers_algorithm(&self.available, &self.max, &self.allocation)
+    }
+
+    /// Grants `request` to the process if that leaves the state safe,
+    /// returning the safe sequence it leaves, and otherwise leaves the
+    /// state as it was
+    pub fn request(&mut self, process: usize, request: &[u32]) -> Result<Vec<usize>, RequestError> {
+        let need = self.need(process);
+        if request.iter().zip(&need).any(|(r, n)| r > n) {
+            return Err(RequestError::ExceedsClaim);
+        }
+        if request.iter().zip(&self.available).any(|(r, a)| r > a) {
+            return Err(RequestError::Unavailable);
+        }
+        self.transfer(process, request, true);
+        match self.safe_sequence() {
+            Some(sequence) => Ok(sequence),
+            None => {
+                self.transfer(process, request, false);
+                Err(RequestError::Unsafe)
+            }
+        }
+    }
+
+    /// Returns resources the process holds to the free pool
+    pub fn release(&mut self, process: usize, resources: &[u32]) {
+        assert!(
+            resources
+                .iter()
+                .zip(&self.allocation[process])
+                .all(|(r, held)| r <= held),
+            "a process can only release what it holds"
+        );
+        self.transfer(process, resources, false);
+    }
+
+    /// Moves resources from the pool to the process, or back
+    fn transfer(&mut self, process: usize, resources: &[u32], grant: bool) {
+        let held = &mut self.allocation[process];
+        for ((free, held), &amount) in self.available.iter_mut().zip(held).zip(resources) {
+            if grant {
+                *free -= amount;
+                *held += amount;
+            } else {
+                *free += amount;
+                *held -= amount;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Banker, bankers_algorithm};
+    use crate::os::deadlock::RequestError;
+
+    /// The textbook state: five processes and resources A, B and C with
+    /// 10, 5 and 7 units
+    fn textbook() -> Banker {
+        Banker::new(
+            vec![3, 3, 2],
+            vec![
+                vec![7, 5, 3],
+                vec![3, 2, 2],
+                vec![9, 0, 2],
+                vec![2, 2, 2],
+                vec![4, 3, 3],
+            ],
+            vec![
+                vec![0, 1, 0],
+                vec![2, 0, 0],
+                vec![3, 0, 2],
+                vec![2, 1, 1],
+                vec![0, 0, 2],
+            ],
+        )
+    }
+
+    #[test]
+    fn safe_sequences() {
+        let banker = textbook();
+        assert_eq!(banker.need(0), [7, 4, 3]);
+        assert_eq!(banker.safe_sequence(), Some(vec![1, 3, 0, 2, 4]));
+        // Two processes that each hold one unit and want both: unsafe
+        let max = [vec![2], vec![2]];
+        assert_eq!(bankers_algorithm(&[0], &max, &[vec![1], vec![1]]), None);
+        assert_eq!(
+            bankers_algorithm(&[1], &max, &[vec![1], vec![0]]),
+            Some(vec![0, 1])
+        );
+        assert_eq!(bankers_algorithm(&[], &[], &[]), Some(vec![]));
+    }
+
+    #[test]
+    fn requests() {
+        let mut banker = textbook();
+        assert_eq!(banker.request(1, &[1, 0, 2]), Ok(vec![1, 3, 0, 2, 4]));
+        assert_eq!(banker.available(), [2, 3, 0]);
+        assert_eq!(banker.allocation(1), [3, 0, 2]);
+        // P4 wants more than is free, P0 would leave no safe sequence
+        assert_eq!(
+            banker.request(4, &[3, 3, 0]),
+            Err(RequestError::Unavailable)
+        );
+        assert_eq!(banker.request(0, &[0, 2, 0]), Err(RequestError::Unsafe));
+        assert_eq!(banker.available(), [2, 3, 0]);
+        assert_eq!(
+            banker.request(3, &[1, 0, 0]),
+            Err(RequestError::ExceedsClaim)
+        );
+        banker.release(1, &[3, 0, 2]);
+        assert_eq!(banker.available(), [5, 3, 2]);
+        assert!(banker.request(0, &[0, 2, 0]).is_ok());
+    }
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons the banker turns down a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestError {
+    /// The process asked for more than it declared it might need
+    ExceedsClaim,
+    /// Fewer resources are free than asked for, so the process must wait
+    Unavailable,
+    /// Granting it would leave a state with no safe sequence
+    Unsafe,
+}
+
+impl Display for RequestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestError::ExceedsClaim => write!(f, "request exceeds the declared maximum"),
+            RequestError::Unavailable => write!(f, "not enough resources are available"),
+            RequestError::Unsafe => write!(f, "granting the request would be unsafe"),
+        }
+    }
+}
+
+impl Error for RequestError {}
@@ -0,0 +1,7 @@
+mod bankers;
+mod error;
+mod wait_for;
+
+pub use self::bankers::{Banker, bankers_algorithm};
+pub use self::error::RequestError;
+pub use self::wait_for::WaitForGraph;
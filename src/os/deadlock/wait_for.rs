@@ -0,0 +1,120 @@
+/// Which processes wait for a resource another one holds. A deadlock is a
+/// cycle in this graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitForGraph {
+    waits_for: Vec<Vec<usize>>,
+}
+
+impl WaitForGraph {
+    /// A graph of `processes` processes, none of them waiting
+    pub fn new(processes: usize) -> WaitForGraph {
+        WaitForGraph {
+            waits_for: vec![Vec::new(); processes],
+        }
+    }
+
+    /// Records that `waiter` waits for something `holder` holds
+    pub fn add_wait(&mut self, waiter: usize, holder: usize) {
+        assert!(holder < self.waits_for.len(), "no process {holder}");
+        if !self.waits_for[waiter].contains(&holder) {
+            self.waits_for[waiter].push(holder);
+        }
+    }
+
+    /// Drops every wait of `waiter`, as when its request is granted
+    pub fn clear_waits(&mut self, waiter: usize) {
+        self.waits_for[waiter].clear();
+    }
+
+    /// Some cycle of processes each waiting for the next, the last for the
+    /// first, found by depth-first search
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Mark {
+            New,
+            OnPath,
+            Done,
+        }
+        let mut marks = vec![Mark::New; self.waits_for.len()];
+        let mut path = Vec::new();
+        for root in 0..self.waits_for.len() {
+            if marks[root] != Mark::New {
+                continue;
+            }
+            // Each frame is a process and how many of its edges are done
+            let mut stack = vec![(root, 0)];
+            marks[root] = Mark::OnPath;
+            path.push(root);
+            while let Some((process, next)) = stack.last_mut() {
+                let process = *process;
+                let Some(&holder) = self.waits_for[process].get(*next) else {
+                    marks[process] = Mark::Done;
+                    path.pop();
+                    stack.pop();
+                    continue;
+                };
+                *next += 1;
+                match marks[holder] {
+                    Mark::OnPath => {
+                        let start = path.iter().position(|&p| p == holder).unwrap();
+                        return Some(path[start..].to_vec());
+                    }
+                    Mark::New => {
+                        marks[holder] = Mark::OnPath;
+                        path.push(holder);
+                        stack.push((holder, 0));
+                    }
+                    Mark::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// The processes that can never proceed: those on a cycle and those
+    /// waiting, directly or not, for one that is
+    pub fn deadlocked(&self) -> Vec<usize> {
+        // A process can proceed once everything it waits for can, so
+        // peel off those until nothing changes; the rest are stuck
+        let mut free = vec![false; self.waits_for.len()];
+        loop {
+            let mut changed = false;
+            for process in 0..self.waits_for.len() {
+                if !free[process] && self.waits_for[process].iter().all(|&h| free[h]) {
+                    free[process] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        (0..free.len()).filter(|&p| !free[p]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WaitForGraph;
+
+    #[test]
+    fn detects_cycles() {
+        let mut graph = WaitForGraph::new(5);
+        graph.add_wait(0, 1);
+        graph.add_wait(1, 2);
+        graph.add_wait(3, 4);
+        assert_eq!(graph.find_cycle(), None);
+        assert!(graph.deadlocked().is_empty());
+        // 2 waiting for 0 closes the loop, and 3 waits on it through 4
+        graph.add_wait(2, 0);
+        graph.add_wait(4, 1);
+        assert_eq!(graph.find_cycle(), Some(vec![0, 1, 2]));
+        assert_eq!(graph.deadlocked(), [0, 1, 2, 3, 4]);
+        graph.clear_waits(2);
+        assert_eq!(graph.find_cycle(), None);
+        let mut graph = WaitForGraph::new(2);
+        graph.add_wait(1, 1);
+        assert_eq!(graph.find_cycle(), Some(vec![1]));
+        assert_eq!(graph.deadlocked(), [1]);
+    }
+}
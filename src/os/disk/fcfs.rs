@@ -0,0 +1,6 @@
+use super::DiskSchedule;
+
+/// Serves requests in the order they arrived
+pub fn fcfs(head: u64, requests: &[u64]) -> DiskSchedule {
+    DiskSchedule::visiting(head, requests.to_vec())
+}
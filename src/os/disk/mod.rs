@@ -0,0 +1,84 @@
+mod fcfs;
+mod scan;
+mod schedule;
+mod sstf;
+
+pub use self::fcfs::fcfs;
+pub use self::scan::{c_look, c_scan, look, scan};
+pub use self::schedule::{Direction, DiskSchedule};
+pub use self::sstf::sstf;
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, c_look, c_scan, fcfs, look, scan, sstf};
+
+    /// The textbook queue, with the head at 53 on a 200-cylinder disk
+    const QUEUE: [u64; 8] = [98, 183, 37, 122, 14, 124, 65, 67];
+    const HEAD: u64 = 53;
+
+    #[test]
+    fn textbook_queue() {
+        let schedule = fcfs(HEAD, &QUEUE);
+        assert_eq!(schedule.order, QUEUE);
+        assert_eq!(schedule.head_movement(), 640);
+
+        let schedule = sstf(HEAD, &QUEUE);
+        assert_eq!(schedule.order, [65, 67, 37, 14, 98, 122, 124, 183]);
+        assert_eq!(schedule.head_movement(), 236);
+
+        let schedule = scan(HEAD, &QUEUE, Direction::Down, 200);
+        assert_eq!(schedule.order, [37, 14, 65, 67, 98, 122, 124, 183]);
+        assert_eq!(schedule.path[..4], [53, 37, 14, 0]);
+        assert_eq!(schedule.head_movement(), 236);
+
+        let schedule = c_scan(HEAD, &QUEUE, Direction::Up, 200);
+        assert_eq!(schedule.order, [65, 67, 98, 122, 124, 183, 14, 37]);
+        assert_eq!(schedule.path[6..9], [183, 199, 0]);
+        assert_eq!(schedule.head_movement(), 146 + 199 + 37);
+
+        let schedule = look(HEAD, &QUEUE, Direction::Up);
+        assert_eq!(schedule.order, [65, 67, 98, 122, 124, 183, 37, 14]);
+        assert_eq!(schedule.head_movement(), 130 + 169);
+
+        let schedule = c_look(HEAD, &QUEUE, Direction::Up);
+        assert_eq!(schedule.order, [65, 67, 98, 122, 124, 183, 14, 37]);
+        assert_eq!(schedule.head_movement(), 130 + 169 + 23);
+    }
+
+    #[test]
+    fn comparisons() {
+        let movement = [
+            fcfs(HEAD, &QUEUE).head_movement(),
+            sstf(HEAD, &QUEUE).head_movement(),
+            scan(HEAD, &QUEUE, Direction::Up, 200).head_movement(),
+            c_scan(HEAD, &QUEUE, Direction::Up, 200).head_movement(),
+            look(HEAD, &QUEUE, Direction::Up).head_movement(),
+            c_look(HEAD, &QUEUE, Direction::Up).head_movement(),
+        ];
+        assert_eq!(movement, [640, 236, 331, 382, 299, 322]);
+        // LOOK never travels further than SCAN, nor C-LOOK than C-SCAN
+        for direction in [Direction::Up, Direction::Down] {
+            assert!(
+                look(HEAD, &QUEUE, direction).head_movement()
+                    <= scan(HEAD, &QUEUE, direction, 200).head_movement()
+            );
+            assert!(
+                c_look(HEAD, &QUEUE, direction).head_movement()
+                    <= c_scan(HEAD, &QUEUE, direction, 200).head_movement()
+            );
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        // Nothing behind the head: no trip to the end of the disk
+        let schedule = scan(10, &[20, 15, 30], Direction::Up, 100);
+        assert_eq!(schedule.path, [10, 15, 20, 30]);
+        // A request at the head is served without moving
+        let schedule = c_look(50, &[50, 10, 60], Direction::Down);
+        assert_eq!(schedule.order, [50, 10, 60]);
+        assert_eq!(schedule.path, [50, 10, 60]);
+        assert_eq!(sstf(5, &[]).head_movement(), 0);
+        assert_eq!(scan(0, &[5], Direction::Down, 10).path, [0, 5]);
+    }
+}
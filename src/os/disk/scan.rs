@@ -0,0 +1,77 @@
+use super::{Direction, DiskSchedule};
+
+/// The elevator algorithm: serves requests on the way to the end of the
+/// disk in `direction`, then reverses and serves the rest on the way back.
+/// It only goes to the end if some request is left behind the head.
+/// Cylinders run from 0 to `cylinders - 1`
+pub fn scan(head: u64, requests: &[u64], direction: Direction, cylinders: u64) -> DiskSchedule {
+    sweep(head, requests, direction, Some(cylinders), false)
+}
+
+/// Circular SCAN: serves requests on the way to the end of the disk, then
+/// jumps to the other end and sweeps the same way again, giving more even
+/// waits than SCAN. The jump counts toward the head movement
+pub fn c_scan(head: u64, requests: &[u64], direction: Direction, cylinders: u64) -> DiskSchedule {
+    sweep(head, requests, direction, Some(cylinders), true)
+}
+
+/// SCAN that turns around at the last request instead of the end of the disk
+pub fn look(head: u64, requests: &[u64], direction: Direction) -> DiskSchedule {
+    sweep(head, requests, direction, None, false)
+}
+
+/// C-SCAN that jumps back from the last request to the first one on the
+/// other side instead of between the ends of the disk
+pub fn c_look(head: u64, requests: &[u64], direction: Direction) -> DiskSchedule {
+    sweep(head, requests, direction, None, true)
+}
+
+fn sweep(
+    head: u64,
+    requests: &[u64],
+    direction: Direction,
+    cylinders: Option<u64>,
+    circular: bool,
+) -> DiskSchedule {
+    if let Some(cylinders) = cylinders {
+        assert!(
+            head < cylinders && requests.iter().all(|&r| r < cylinders),
+            "every cylinder must be below {cylinders}"
+        );
+    }
+    let mut sorted = requests.to_vec();
+    sorted.sort_unstable();
+    // Requests at the head's cylinder are served first, going either way
+    let split = match direction {
+        Direction::Up => sorted.partition_point(|&r| r < head),
+        Direction::Down => sorted.partition_point(|&r| r <= head),
+    };
+    let (mut ahead, mut behind) = match direction {
+        Direction::Up => (sorted[split..].to_vec(), sorted[..split].to_vec()),
+        Direction::Down => (sorted[..split].to_vec(), sorted[split..].to_vec()),
+    };
+    if direction == Direction::Down {
+        ahead.reverse();
+    } else {
+        behind.reverse();
+    }
+    // `behind` now runs outward from the head
+    let mut path = vec![head];
+    path.extend(&ahead);
+    let mut order = ahead;
+    if !behind.is_empty() {
+        let (near_end, far_end) = match direction {
+            Direction::Up => (cylinders.map(|c| c - 1), cylinders.map(|_| 0)),
+            Direction::Down => (cylinders.map(|_| 0), cylinders.map(|c| c - 1)),
+        };
+        path.extend(near_end);
+        if circular {
+            behind.reverse();
+            path.extend(far_end);
+        }
+        path.extend(&behind);
+        order.extend(behind);
+    }
+    path.dedup();
+    DiskSchedule { order, path }
+}
@@ -0,0 +1,30 @@
+/// Which way a sweeping head moves first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Toward higher cylinders
+    Up,
+    /// Toward cylinder 0
+    Down,
+}
+
+/// The outcome of serving a request queue: the order of the requests and
+/// every cylinder the head stops at, turnarounds and jumps included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskSchedule {
+    pub order: Vec<u64>,
+    pub path: Vec<u64>,
+}
+
+impl DiskSchedule {
+    /// Serves `order` from `head` in a straight line from one to the next
+    pub(super) fn visiting(head: u64, order: Vec<u64>) -> DiskSchedule {
+        let mut path = vec![head];
+        path.extend(&order);
+        DiskSchedule { order, path }
+    }
+
+    /// The total distance the head travels along its path
+    pub fn head_movement(&self) -> u64 {
+        self.path.windows(2).map(|w| w[0].abs_diff(w[1])).sum()
+    }
+}
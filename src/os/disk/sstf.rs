@@ -0,0 +1,15 @@
+use super::DiskSchedule;
+
+/// Shortest seek time first: always serves the pending request closest to
+/// the head, the earlier one on a tie. Requests far from a busy area can
+/// wait indefinitely
+pub fn sstf(head: u64, requests: &[u64]) -> DiskSchedule {
+    let mut pending = requests.to_vec();
+    let mut order = Vec::with_capacity(pending.len());
+    let mut at = head;
+    while let Some(next) = (0..pending.len()).min_by_key(|&i| (pending[i].abs_diff(at), i)) {
+        at = pending.remove(next);
+        order.push(at);
+    }
+    DiskSchedule::visiting(head, order)
+}
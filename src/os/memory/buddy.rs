@@ -0,0 +1,170 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::{MemoryError, MemoryStats};
+
+/// A binary buddy allocator: memory is split into power-of-two blocks, an
+/// allocation gets the smallest block that holds it, halving a larger one
+/// as often as needed, and a freed block merges with its buddy, the other
+/// half it was split from, whenever that is free too.
+///
+/// The block of order k holds `min_block << k` bytes, and the buddy of the
+/// block at address a is at `a ^ size`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuddyAllocator {
+    min_block: usize,
+    /// Free block addresses by order
+    free: Vec<BTreeSet<usize>>,
+    /// The order and requested size of each allocated block, by address
+    allocated: HashMap<usize, (usize, usize)>,
+}
+
+impl BuddyAllocator {
+    /// An allocator over `0..size`, handing out blocks of at least
+    /// `min_block` bytes. Both must be powers of two
+    pub fn new(size: usize, min_block: usize) -> BuddyAllocator {
+        assert!(size.is_power_of_two(), "the size must be a power of two");
+        assert!(
+            min_block.is_power_of_two() && min_block <= size,
+            "the smallest block must be a power of two no larger than the memory"
+        );
+        let max_order = (size / min_block).trailing_zeros() as usize;
+        let mut free = vec![BTreeSet::new(); max_order + 1];
+        free[max_order].insert(0);
+        BuddyAllocator {
+            min_block,
+            free,
+            allocated: HashMap::new(),
+        }
+    }
+
+    pub fn block_size(&self, order: usize) -> usize {
+        self.min_block << order
+    }
+
+    /// The start of a block of at least `size` bytes, or None if no free
+    /// block is large enough
+    pub fn alloc(&mut self, size: usize) -> Option<usize> {
+        assert!(size > 0, "cannot allocate zero bytes");
+        let order = (0..self.free.len()).find(|&k| self.block_size(k) >= size)?;
+        let available = (order..self.free.len()).find(|&k| !self.free[k].is_empty())?;
+        let address = self.free[available].pop_first().unwrap();
+        // Keep the lower half and free the upper one, down to the order needed
+        for k in (order..available).rev() {
+            let upper = address + self.block_size(k);
+            self.free[k].insert(upper);
+        }
+        self.allocated.insert(address, (order, size));
+        Some(address)
+    }
+
+    /// Frees the block starting at `address`, merging it with its buddy for
+    /// as long as the buddy is free
+    pub fn free(&mut self, address: usize) -> Result<(), MemoryError> {
+        let (mut order, _) = self
+            .allocated
+            .remove(&address)
+            .ok_or(MemoryError::InvalidFree(address))?;
+        let mut address = address;
+        while order + 1 < self.free.len() {
+            let buddy = address ^ self.block_size(order);
+            if !self.free[order].remove(&buddy) {
+                break;
+            }
+            address = address.min(buddy);
+            order += 1;
+        }
+        self.free[order].insert(address);
+        Ok(())
+    }
+
+    /// The free block addresses of each order, smallest order first
+    pub fn free_lists(&self) -> Vec<Vec<usize>> {
+        self.free
+            .iter()
+            .map(|list| list.iter().copied().collect())
+            .collect()
+    }
+
+    pub fn stats(&self) -> MemoryStats {
+        let blocks = || {
+            self.free
+                .iter()
+                .enumerate()
+                .flat_map(|(k, list)| list.iter().map(move |_| self.block_size(k)))
+        };
+        let (used, requested) = self
+            .allocated
+            .values()
+            .fold((0, 0), |(used, requested), &(order, size)| {
+                (used + self.block_size(order), requested + size)
+            });
+        MemoryStats {
+            used,
+            free: blocks().sum(),
+            free_blocks: blocks().count(),
+            largest_free_block: blocks().max().unwrap_or(0),
+            internal_fragmentation: used - requested,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BuddyAllocator;
+    use crate::os::memory::MemoryError;
+
+    #[test]
+    fn splitting_and_merging() {
+        // 1 KiB in blocks of at least 64 bytes: orders 0 to 4
+        let mut memory = BuddyAllocator::new(1_024, 64);
+        let a = memory.alloc(70).unwrap();
+        assert_eq!(a, 0);
+        // 1024 split into 512 + 512, 256 + 256 and 128 + 128
+        assert_eq!(
+            memory.free_lists(),
+            [vec![], vec![128], vec![256], vec![512], vec![]]
+        );
+        let b = memory.alloc(35).unwrap();
+        let c = memory.alloc(80).unwrap();
+        let d = memory.alloc(60).unwrap();
+        assert_eq!((b, c, d), (128, 256, 192));
+        let stats = memory.stats();
+        assert_eq!(stats.used, 128 + 64 + 128 + 64);
+        assert_eq!(stats.internal_fragmentation, 58 + 29 + 48 + 4);
+        assert_eq!((stats.free, stats.free_blocks), (640, 2));
+
+        memory.free(b).unwrap();
+        // b's buddy at 192 is in use, so nothing merges yet
+        assert_eq!(memory.free_lists()[0], [128]);
+        // Freeing it merges the pair, but not with a at 0
+        memory.free(d).unwrap();
+        assert_eq!(memory.free_lists()[1], [128, 384]);
+        memory.free(a).unwrap();
+        memory.free(c).unwrap();
+        assert_eq!(
+            memory.free_lists(),
+            [vec![], vec![], vec![], vec![], vec![0]]
+        );
+        assert_eq!(memory.free(c), Err(MemoryError::InvalidFree(c)));
+    }
+
+    #[test]
+    fn exhaustion() {
+        let mut memory = BuddyAllocator::new(256, 16);
+        assert_eq!(memory.alloc(257), None);
+        let blocks: Vec<usize> = (0..16).map(|_| memory.alloc(16).unwrap()).collect();
+        assert_eq!(memory.alloc(1), None);
+        assert_eq!(memory.stats().free, 0);
+        // Freeing every other block leaves half the memory free but in
+        // pieces no larger than one block
+        for &block in blocks.iter().step_by(2) {
+            memory.free(block).unwrap();
+        }
+        let stats = memory.stats();
+        assert_eq!((stats.free, stats.largest_free_block), (128, 16));
+        assert_eq!(stats.external_fragmentation(), 0.875);
+        assert_eq!(memory.alloc(32), None);
+        memory.free(blocks[1]).unwrap();
+        assert_eq!(memory.alloc(32), Some(0));
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use super::{MemoryError, MemoryStats};
+
+/// How a contiguous allocator picks among the free blocks large enough
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fit {
+    /// The lowest address
+    First,
+    /// The smallest, leaving the smallest leftover
+    Best,
+    /// The largest, leaving the largest leftover
+    Worst,
+}
+
+/// A free or allocated range of memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub start: usize,
+    pub size: usize,
+}
+
+/// Variable-size allocation from one contiguous region, keeping a free list
+/// in address order. An allocation carves the front off the chosen free
+/// block, and a free merges the block with free neighbours on either side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContiguousAllocator {
+    fit: Fit,
+    free: Vec<Block>,
+    /// Size by start address
+    allocated: BTreeMap<usize, usize>,
+}
+
+impl ContiguousAllocator {
+    /// An allocator over addresses `0..size`, all free
+    pub fn new(size: usize, fit: Fit) -> ContiguousAllocator {
+        let free = match size {
+            0 => Vec::new(),
+            _ => vec![Block { start: 0, size }],
+        };
+        ContiguousAllocator {
+            fit,
+            free,
+            allocated: BTreeMap::new(),
+        }
+    }
+
+    /// The start of a new block of `size` bytes, or None if no free block
+    /// is large enough
+    pub fn alloc(&mut self, size: usize) -> Option<usize> {
+        assert!(size > 0, "cannot allocate zero bytes");
+        let fits = self.free.iter().enumerate().filter(|(_, b)| b.size >= size);
+        let (index, _) = match self.fit {
+            Fit::First => fits.min_by_key(|&(i, _)| i),
+            Fit::Best => fits.min_by_key(|&(i, b)| (b.size, i)),
+            Fit::Worst => fits.min_by_key(|&(i, b)| (usize::MAX - b.size, i)),
+        }?;
+        let block = &mut self.free[index];
+        let start = block.start;
+        block.start += size;
+        block.size -= size;
+        if block.size == 0 {
+            self.free.remove(index);
+        }
+        self.allocated.insert(start, size);
+        Some(start)
+    }
+
+    /// Frees the block starting at `address`, coalescing it with adjacent
+    /// free blocks
+    pub fn free(&mut self, address: usize) -> Result<(), MemoryError> {
+        let size = self
+            .allocated
+            .remove(&address)
+            .ok_or(MemoryError::InvalidFree(address))?;
+        let index = self.free.partition_point(|b| b.start < address);
+        self.free.insert(
+            index,
+            Block {
+                start: address,
+                size,
+            },
+        );
+        // Merge with the next block, then with the previous one
+        if index + 1 < self.free.len() && address + size == self.free[index + 1].start {
+            self.free[index].size += self.free.remove(index + 1).size;
+        }
+        if index > 0 && self.free[index - 1].start + self.free[index - 1].size == address {
+            self.free[index - 1].size += self.free.remove(index).size;
+        }
+        Ok(())
+    }
+
+    /// The free blocks in address order
+    pub fn free_blocks(&self) -> &[Block] {
+        &self.free
+    }
+
+    /// The allocated blocks in address order
+    pub fn allocated_blocks(&self) -> Vec<Block> {
+        self.allocated
+            .iter()
+            .map(|(&start, &size)| Block { start, size })
+            .collect()
+    }
+
+    pub fn stats(&self) -> MemoryStats {
+        MemoryStats {
+            used: self.allocated.values().sum(),
+            free: self.free.iter().map(|b| b.size).sum(),
+            free_blocks: self.free.len(),
+            largest_free_block: self.free.iter().map(|b| b.size).max().unwrap_or(0),
+            internal_fragmentation: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Block, ContiguousAllocator, Fit};
+    use crate::os::memory::MemoryError;
+
+    /// Memory with free holes of 100, 500, 200, 300 and 600 bytes in that
+    /// order, separated by allocated blocks
+    fn holes(fit: Fit) -> ContiguousAllocator {
+        let mut memory = ContiguousAllocator::new(1_705, fit);
+        let mut spacers = Vec::new();
+        let mut holes = Vec::new();
+        for size in [100, 500, 200, 300, 600] {
+            holes.push(memory.alloc(size).unwrap());
+            spacers.push(memory.alloc(1).unwrap());
+        }
+        for hole in holes {
+            memory.free(hole).unwrap();
+        }
+        assert_eq!(memory.free_blocks().len(), 5);
+        memory
+    }
+
+    #[test]
+    fn placement_policies() {
+        // The textbook exercise: place 212, 417, 112 and 426 bytes
+        let place = |fit| {
+            let mut memory = holes(fit);
+            [212, 417, 112, 426].map(|size| memory.alloc(size))
+        };
+        // First fit: 500, 600, the rest of 500, then nothing fits 426
+        assert_eq!(place(Fit::First), [Some(101), Some(1_104), Some(313), None]);
+        // Best fit: 300, 500, 200, 600
+        assert_eq!(
+            place(Fit::Best),
+            [Some(803), Some(101), Some(602), Some(1_104)]
+        );
+        // Worst fit: 600, 500, the rest of 600, then nothing fits 426
+        assert_eq!(
+            place(Fit::Worst),
+            [Some(1_104), Some(101), Some(1_316), None]
+        );
+    }
+
+    #[test]
+    fn coalescing() {
+        let mut memory = ContiguousAllocator::new(100, Fit::First);
+        let a = memory.alloc(10).unwrap();
+        let b = memory.alloc(20).unwrap();
+        let c = memory.alloc(30).unwrap();
+        assert_eq!(memory.alloc(50), None);
+        memory.free(a).unwrap();
+        memory.free(c).unwrap();
+        assert_eq!(
+            memory.free_blocks(),
+            [
+                Block { start: 0, size: 10 },
+                Block {
+                    start: 30,
+                    size: 70
+                }
+            ]
+        );
+        let stats = memory.stats();
+        assert_eq!((stats.used, stats.free, stats.free_blocks), (20, 80, 2));
+        assert_eq!(stats.external_fragmentation(), 0.125);
+        // Freeing b joins both neighbours into one block
+        memory.free(b).unwrap();
+        assert_eq!(
+            memory.free_blocks(),
+            [Block {
+                start: 0,
+                size: 100
+            }]
+        );
+        assert_eq!(memory.stats().external_fragmentation(), 0.0);
+        assert_eq!(memory.free(b), Err(MemoryError::InvalidFree(b)));
+        assert_eq!(memory.alloc(100), Some(0));
+        assert_eq!(
+            memory.allocated_blocks(),
+            [Block {
+                start: 0,
+                size: 100
+            }]
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons a free fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemoryError {
+    /// The address is not the start of an allocated block
+    InvalidFree(usize),
+}
+
+impl Display for MemoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryError::InvalidFree(address) => {
+                write!(f, "no allocated block starts at {address}")
+            }
+        }
+    }
+}
+
+impl Error for MemoryError {}
@@ -0,0 +1,9 @@
+mod buddy;
+mod contiguous;
+mod error;
+mod stats;
+
+pub use self::buddy::BuddyAllocator;
+pub use self::contiguous::{Block, ContiguousAllocator, Fit};
+pub use self::error::MemoryError;
+pub use self::stats::MemoryStats;
@@ -0,0 +1,23 @@
+/// A snapshot of how an allocator's memory is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    /// Bytes handed out in blocks, including any rounding up
+    pub used: usize,
+    /// Bytes in free blocks
+    pub free: usize,
+    pub free_blocks: usize,
+    pub largest_free_block: usize,
+    /// Bytes allocated beyond what was requested, from rounding block sizes up
+    pub internal_fragmentation: usize,
+}
+
+impl MemoryStats {
+    /// The share of free memory outside the largest free block, from 0
+    /// when it is all in one piece towards 1 as it splinters
+    pub fn external_fragmentation(&self) -> f64 {
+        if self.free == 0 {
+            return 0.0;
+        }
+        1.0 - self.largest_free_block as f64 / self.free as f64
+    }
+}
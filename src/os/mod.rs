@@ -0,0 +1,5 @@
+pub mod deadlock;
+pub mod disk;
+pub mod memory;
+pub mod paging;
+pub mod scheduling;
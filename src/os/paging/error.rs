@@ -0,0 +1,21 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Reasons a translation fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PagingError {
+    /// The address has more bits than the page tables index
+    AddressOutOfRange(u64),
+}
+
+impl Display for PagingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PagingError::AddressOutOfRange(address) => {
+                write!(f, "address {address:#x} is outside the address space")
+            }
+        }
+    }
+}
+
+impl Error for PagingError {}
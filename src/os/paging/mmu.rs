@@ -0,0 +1,284 @@
+use super::table::PageTable;
+use super::tlb::Tlb;
+use super::{PageAccess, PageFrames, PageReplacement, PagingError};
+
+/// The shape of a paged memory, with sizes in bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PagingConfig {
+    pub page_size: u64,
+    /// How many bits of the page number index each table, outermost first
+    pub level_bits: Vec<u32>,
+    pub tlb_entries: usize,
+    /// Physical frames, each one page
+    pub frames: usize,
+    pub replacement: PageReplacement,
+}
+
+impl PagingConfig {
+    pub fn new(
+        page_size: u64,
+        level_bits: &[u32],
+        tlb_entries: usize,
+        frames: usize,
+        replacement: PageReplacement,
+    ) -> PagingConfig {
+        assert!(
+            page_size.is_power_of_two(),
+            "the page size must be a power of two"
+        );
+        assert!(
+            !level_bits.is_empty() && level_bits.iter().all(|&bits| bits > 0),
+            "page tables need at least one level of at least one bit"
+        );
+        assert!(frames > 0, "memory needs at least one frame");
+        let config = PagingConfig {
+            page_size,
+            level_bits: level_bits.to_vec(),
+            tlb_entries,
+            frames,
+            replacement,
+        };
+        assert!(
+            config.address_bits() <= 64,
+            "virtual addresses wider than 64 bits"
+        );
+        config
+    }
+
+    pub fn offset_bits(&self) -> u32 {
+        self.page_size.trailing_zeros()
+    }
+
+    /// The width of a virtual address: the page number then the offset
+    pub fn address_bits(&self) -> u32 {
+        self.offset_bits() + self.level_bits.iter().sum::<u32>()
+    }
+}
+
+/// What translating every address so far took
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PagingStats {
+    pub tlb_hits: u64,
+    pub tlb_misses: u64,
+    /// Misses whose page was not resident either
+    pub page_faults: u64,
+    /// Faults that had to evict a resident page
+    pub evictions: u64,
+}
+
+impl PagingStats {
+    pub fn accesses(&self) -> u64 {
+        self.tlb_hits + self.tlb_misses
+    }
+
+    pub fn tlb_hit_rate(&self) -> f64 {
+        match self.accesses() {
+            0 => 0.0,
+            accesses => self.tlb_hits as f64 / accesses as f64,
+        }
+    }
+
+    pub fn fault_rate(&self) -> f64 {
+        match self.accesses() {
+            0 => 0.0,
+            accesses => self.page_faults as f64 / accesses as f64,
+        }
+    }
+}
+
+/// A process's virtual memory: a multi-level page table and a TLB in front
+/// of a few frames of physical memory, paged in on demand.
+///
+/// A translation tries the TLB, then walks the tables, and on a page fault
+/// loads the page into a free frame or one the replacement policy frees,
+/// unmapping the page that was there.
+#[derive(Debug, Clone)]
+pub struct VirtualMemory {
+    config: PagingConfig,
+    table: PageTable,
+    tlb: Tlb,
+    frames: PageFrames,
+    stats: PagingStats,
+}
+
+impl VirtualMemory {
+    pub fn new(config: PagingConfig) -> VirtualMemory {
+        VirtualMemory {
+            table: PageTable::new(&config.level_bits),
+            tlb: Tlb::new(config.tlb_entries),
+            frames: PageFrames::new(config.frames, config.replacement),
+            stats: PagingStats::default(),
+            config,
+        }
+    }
+
+    /// The physical address a virtual one maps to, paging it in if needed
+    pub fn translate(&mut self, address: u64) -> Result<u64, PagingError> {
+        let bits = self.config.address_bits();
+        if bits < 64 && address >> bits != 0 {
+            return Err(PagingError::AddressOutOfRange(address));
+        }
+        let page = address >> self.config.offset_bits();
+        let offset = address & (self.config.page_size - 1);
+        let frame = match self.tlb.lookup(page) {
+            Some(frame) => {
+                self.stats.tlb_hits += 1;
+                // Keep the replacement policy's view of use up to date
+                self.frames.access(page);
+                frame
+            }
+            None => {
+                self.stats.tlb_misses += 1;
+                let frame = self.page_in(page);
+                self.tlb.insert(page, frame);
+                frame
+            }
+        };
+        Ok(frame as u64 * self.config.page_size + offset)
+    }
+
+    /// Walks the tables for a page, faulting it in if it is not mapped
+    fn page_in(&mut self, page: u64) -> usize {
+        let indices = self.indices(page);
+        let mapped = self.table.get(&indices);
+        match self.frames.access(page) {
+            PageAccess::Hit(frame) => {
+                debug_assert_eq!(mapped, Some(frame));
+                frame
+            }
+            PageAccess::Fault { frame, evicted } => {
+                self.stats.page_faults += 1;
+                if let Some(evicted) = evicted {
+                    self.stats.evictions += 1;
+                    self.tlb.invalidate(evicted);
+                    let indices = self.indices(evicted);
+                    self.table.set(&self.config.level_bits, &indices, None);
+                }
+                self.table
+                    .set(&self.config.level_bits, &indices, Some(frame));
+                frame
+            }
+        }
+    }
+
+    /// The page number split into one table index per level
+    fn indices(&self, page: u64) -> Vec<usize> {
+        let mut shift = self.config.level_bits.iter().sum::<u32>();
+        self.config
+            .level_bits
+            .iter()
+            .map(|&bits| {
+                shift -= bits;
+                ((page >> shift) & ((1 << bits) - 1)) as usize
+            })
+            .collect()
+    }
+
+    /// Translates every address of a trace in order
+    pub fn run(&mut self, trace: impl IntoIterator<Item = u64>) -> Result<(), PagingError> {
+        for address in trace {
+            self.translate(address)?;
+        }
+        Ok(())
+    }
+
+    /// The frame a virtual address's page is in, without touching anything
+    pub fn resident_frame(&self, address: u64) -> Option<usize> {
+        self.frames.frame_of(address >> self.config.offset_bits())
+    }
+
+    /// How many page tables are allocated, which grows with how spread out
+    /// the touched pages are rather than with the address space
+    pub fn page_tables(&self) -> usize {
+        self.table.count()
+    }
+
+    pub fn stats(&self) -> PagingStats {
+        self.stats
+    }
+
+    pub fn config(&self) -> &PagingConfig {
+        &self.config
+    }
+
+    /// The mean time per access, given the TLB lookup time, the time of
+    /// one memory access, each table read being one, and of a page fault
+    pub fn effective_access_time(&self, tlb_time: f64, memory_time: f64, fault_time: f64) -> f64 {
+        let accesses = self.stats.accesses();
+        if accesses == 0 {
+            return 0.0;
+        }
+        let walks = self.stats.tlb_misses as f64 * self.config.level_bits.len() as f64;
+        let total = accesses as f64 * (tlb_time + memory_time)
+            + walks * memory_time
+            + self.stats.page_faults as f64 * fault_time;
+        total / accesses as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::os::paging::{
+        PageReplacement, PagingConfig, PagingError, PagingStats, VirtualMemory,
+    };
+
+    #[test]
+    fn demand_paging() {
+        // 4 KiB pages and two levels of two bits: 16 pages, 64 KiB
+        let config = PagingConfig::new(4096, &[2, 2], 2, 2, PageReplacement::Lru);
+        assert_eq!(config.address_bits(), 16);
+        let mut memory = VirtualMemory::new(config);
+        assert_eq!(memory.translate(0x1234), Ok(0x0234));
+        assert_eq!(memory.translate(0x1fff), Ok(0x0fff));
+        assert_eq!(memory.translate(0xf008), Ok(0x1008));
+        assert_eq!(memory.page_tables(), 3);
+        // Page 2 evicts page 1, the least recently used, and page 1 then
+        // evicts page 15
+        assert_eq!(memory.translate(0x2abc), Ok(0x0abc));
+        assert_eq!(memory.translate(0x1000), Ok(0x1000));
+        assert_eq!(memory.resident_frame(0xf000), None);
+        assert_eq!(memory.resident_frame(0x2000), Some(0));
+        assert_eq!(
+            memory.stats(),
+            PagingStats {
+                tlb_hits: 1,
+                tlb_misses: 4,
+                page_faults: 4,
+                evictions: 2,
+            }
+        );
+        assert_eq!(
+            memory.translate(0x10000),
+            Err(PagingError::AddressOutOfRange(0x10000))
+        );
+    }
+
+    #[test]
+    fn tlb_misses_without_faults() {
+        let config = PagingConfig::new(256, &[4, 4, 4], 1, 4, PageReplacement::Fifo);
+        let mut memory = VirtualMemory::new(config);
+        memory.run([0x000, 0x100, 0x010, 0x110, 0x020]).unwrap();
+        let stats = memory.stats();
+        assert_eq!(
+            (stats.tlb_hits, stats.tlb_misses, stats.page_faults),
+            (0, 5, 2)
+        );
+        assert_eq!(memory.page_tables(), 3);
+        // Every access reads the TLB and memory, misses also read three
+        // tables and faults cost 1000
+        let time = memory.effective_access_time(1.0, 10.0, 1000.0);
+        assert!((time - (5.0 * 11.0 + 15.0 * 10.0 + 2000.0) / 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sparse_address_space() {
+        let config = PagingConfig::new(4096, &[10, 10], 16, 8, PageReplacement::Clock);
+        let mut memory = VirtualMemory::new(config);
+        for page in 0..4u64 {
+            memory.translate(page << 22).unwrap();
+        }
+        // One table per touched top-level entry, not 2^20 entries
+        assert_eq!(memory.page_tables(), 5);
+        assert_eq!(memory.stats().fault_rate(), 1.0);
+    }
+}
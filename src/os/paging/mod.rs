@@ -0,0 +1,11 @@
+mod error;
+mod mmu;
+mod replacement;
+mod table;
+mod tlb;
+
+pub use self::error::PagingError;
+pub use self::mmu::{PagingConfig, PagingStats, VirtualMemory};
+pub use self::replacement::{
+    PageAccess, PageFrames, PageReplacement, optimal_page_faults, page_faults,
+};
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+/// Which resident page a full memory evicts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageReplacement {
+    /// The one loaded longest ago
+    Fifo,
+    /// The least recently used
+    Lru,
+    /// The next one without its referenced bit set, clearing bits on the way
+    Clock,
+}
+
+/// What an access to a page did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageAccess {
+    /// The page was resident in this frame
+    Hit(usize),
+    /// The page was loaded into this frame, evicting the page given
+    Fault { frame: usize, evicted: Option<u64> },
+}
+
+/// A fixed number of physical frames filled on demand
+#[derive(Debug, Clone)]
+pub struct PageFrames {
+    policy: PageReplacement,
+    pages: Vec<Option<u64>>,
+    resident: HashMap<u64, usize>,
+    /// When each frame was loaded and last used, in accesses
+    loaded: Vec<u64>,
+    used: Vec<u64>,
+    referenced: Vec<bool>,
+    hand: usize,
+    time: u64,
+}
+
+impl PageFrames {
+    pub fn new(frames: usize, policy: PageReplacement) -> PageFrames {
+        assert!(frames > 0, "memory needs at least one frame");
+        PageFrames {
+            policy,
+            pages: vec![None; frames],
+            resident: HashMap::new(),
+            loaded: vec![0; frames],
+            used: vec![0; frames],
+            referenced: vec![false; frames],
+            hand: 0,
+            time: 0,
+        }
+    }
+
+    /// The frame holding the page, if it is resident
+    pub fn frame_of(&self, page: u64) -> Option<usize> {
+        self.resident.get(&page).copied()
+    }
+
+    /// The page in each frame
+    pub fn pages(&self) -> &[Option<u64>] {
+        &self.pages
+    }
+
+    pub fn access(&mut self, page: u64) -> PageAccess {
+        self.time += 1;
+        if let Some(frame) = self.frame_of(page) {
+            self.used[frame] = self.time;
+            self.referenced[frame] = true;
+            return PageAccess::Hit(frame);
+        }
+        let frame = match self.pages.iter().position(Option::is_none) {
+            Some(free) => free,
+            None => self.victim(),
+        };
+        let evicted = self.pages[frame].replace(page);
+        if let Some(evicted) = evicted {
+            self.resident.remove(&evicted);
+        }
+        self.resident.insert(page, frame);
+        self.loaded[frame] = self.time;
+        self.used[frame] = self.time;
+        self.referenced[frame] = true;
+        PageAccess::Fault { frame, evicted }
+    }
+
+    fn victim(&mut self) -> usize {
+        let frames = 0..self.pages.len();
+        match self.policy {
+            PageReplacement::Fifo => frames.min_by_key(|&f| self.loaded[f]).unwrap(),
+            PageReplacement::Lru => frames.min_by_key(|&f| self.used[f]).unwrap(),
+            PageReplacement::Clock => loop {
+                let frame = self.hand;
+                self.hand = (self.hand + 1) % self.pages.len();
+                if !std::mem::take(&mut self.referenced[frame]) {
+                    break frame;
+                }
+            },
+        }
+    }
+}
+
+/// How many faults a reference string causes in `frames` empty frames
+pub fn page_faults(references: &[u64], frames: usize, policy: PageReplacement) -> usize {
+    let mut memory = PageFrames::new(frames, policy);
+    references
+        .iter()
+        .filter(|&&page| matches!(memory.access(page), PageAccess::Fault { .. }))
+        .count()
+}
+
+/// How many faults Bélády's optimal policy causes, which evicts the page
+/// used furthest in the future. No policy can do better, but it needs to
+/// know the whole reference string
+pub fn optimal_page_faults(references: &[u64], frames: usize) -> usize {
+    assert!(frames > 0, "memory needs at least one frame");
+    let mut resident: Vec<u64> = Vec::with_capacity(frames);
+    let mut faults = 0;
+    for (i, page) in references.iter().enumerate() {
+        if resident.contains(page) {
+            continue;
+        }
+        faults += 1;
+        if resident.len() < frames {
+            resident.push(*page);
+            continue;
+        }
+        let next_use = |p: &u64| {
+            references[i + 1..]
+                .iter()
+                .position(|r| r == p)
+                .unwrap_or(usize::MAX)
+        };
+        let victim = (0..resident.len())
+            .max_by_key(|&f| next_use(&resident[f]))
+            .unwrap();
+        resident[victim] = *page;
+    }
+    faults
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PageAccess, PageFrames, PageReplacement, optimal_page_faults, page_faults};
+
+    /// The textbook reference string
+    const REFERENCES: [u64; 20] = [7, 0, 1, 2, 0, 3, 0, 4, 2, 3, 0, 3, 2, 1, 2, 0, 1, 7, 0, 1];
+
+    #[test]
+    fn textbook_fault_counts() {
+        assert_eq!(page_faults(&REFERENCES, 3, PageReplacement::Fifo), 15);
+        assert_eq!(page_faults(&REFERENCES, 3, PageReplacement::Lru), 12);
+        assert_eq!(optimal_page_faults(&REFERENCES, 3), 9);
+        assert_eq!(page_faults(&REFERENCES, 3, PageReplacement::Clock), 14);
+    }
+
+    #[test]
+    fn beladys_anomaly() {
+        // FIFO faults more with four frames than with three
+        let references = [1, 2, 3, 4, 1, 2, 5, 1, 2, 3, 4, 5];
+        assert_eq!(page_faults(&references, 3, PageReplacement::Fifo), 9);
+        assert_eq!(page_faults(&references, 4, PageReplacement::Fifo), 10);
+        assert_eq!(page_faults(&references, 3, PageReplacement::Lru), 10);
+        assert_eq!(page_faults(&references, 4, PageReplacement::Lru), 8);
+    }
+
+    #[test]
+    fn reports_evictions() {
+        let mut memory = PageFrames::new(2, PageReplacement::Lru);
+        assert_eq!(
+            memory.access(5),
+            PageAccess::Fault {
+                frame: 0,
+                evicted: None
+            }
+        );
+        memory.access(6);
+        assert_eq!(memory.access(5), PageAccess::Hit(0));
+        assert_eq!(
+            memory.access(7),
+            PageAccess::Fault {
+                frame: 1,
+                evicted: Some(6)
+            }
+        );
+        assert_eq!(memory.pages(), [Some(5), Some(7)]);
+        assert_eq!(memory.frame_of(6), None);
+    }
+}
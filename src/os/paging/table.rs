@@ -0,0 +1,48 @@
+/// One page table: inner levels point at tables of the next level and the
+/// last level maps pages to frames. Tables are only allocated once some
+/// page under them is mapped
+#[derive(Debug, Clone)]
+pub(super) enum PageTable {
+    Directory(Vec<Option<Box<PageTable>>>),
+    Pages(Vec<Option<usize>>),
+}
+
+impl PageTable {
+    /// An empty table for the first of `level_bits`
+    pub(super) fn new(level_bits: &[u32]) -> PageTable {
+        let entries = 1 << level_bits[0];
+        if level_bits.len() == 1 {
+            PageTable::Pages(vec![None; entries])
+        } else {
+            PageTable::Directory(vec![None; entries])
+        }
+    }
+
+    /// The frame a page maps to, walking one table per index
+    pub(super) fn get(&self, indices: &[usize]) -> Option<usize> {
+        match self {
+            PageTable::Directory(tables) => tables[indices[0]].as_ref()?.get(&indices[1..]),
+            PageTable::Pages(frames) => frames[indices[0]],
+        }
+    }
+
+    /// Maps a page to a frame, or unmaps it, allocating tables on the way
+    pub(super) fn set(&mut self, level_bits: &[u32], indices: &[usize], frame: Option<usize>) {
+        match self {
+            PageTable::Directory(tables) => tables[indices[0]]
+                .get_or_insert_with(|| Box::new(PageTable::new(&level_bits[1..])))
+                .set(&level_bits[1..], &indices[1..], frame),
+            PageTable::Pages(frames) => frames[indices[0]] = frame,
+        }
+    }
+
+    /// How many tables there are, this one included
+    pub(super) fn count(&self) -> usize {
+        match self {
+            PageTable::Directory(tables) => {
+                1 + tables.iter().flatten().map(|t| t.count()).sum::<usize>()
+            }
+            PageTable::Pages(_) => 1,
+        }
+    }
+}
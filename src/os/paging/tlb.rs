@@ -0,0 +1,38 @@
+/// A fully associative TLB of page to frame entries with LRU replacement
+#[derive(Debug, Clone)]
+pub(super) struct Tlb {
+    capacity: usize,
+    /// Least recently used first
+    entries: Vec<(u64, usize)>,
+}
+
+impl Tlb {
+    pub(super) fn new(capacity: usize) -> Tlb {
+        Tlb {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub(super) fn lookup(&mut self, page: u64) -> Option<usize> {
+        let i = self.entries.iter().position(|&(p, _)| p == page)?;
+        let entry = self.entries.remove(i);
+        self.entries.push(entry);
+        Some(entry.1)
+    }
+
+    pub(super) fn insert(&mut self, page: u64, frame: usize) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.remove(0);
+        }
+        self.entries.push((page, frame));
+    }
+
+    /// Drops the entry for a page that is no longer resident
+    pub(super) fn invalidate(&mut self, page: u64) {
+        self.entries.retain(|&(p, _)| p != page);
+    }
+}
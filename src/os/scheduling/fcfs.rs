@@ -0,0 +1,40 @@
+use super::schedule::simulate;
+use super::{Process, Schedule};
+
+/// First come, first served: runs each process to completion in order of
+/// arrival
+pub fn fcfs(processes: &[Process]) -> Schedule {
+    simulate(processes, false, |p, _| p.arrival)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fcfs;
+    use crate::os::scheduling::Process;
+
+    #[test]
+    fn convoy_effect() {
+        // The textbook example: one long job ahead of two short ones
+        let processes = [
+            Process::new(1, 0, 24, 0),
+            Process::new(2, 0, 3, 0),
+            Process::new(3, 0, 3, 0),
+        ];
+        let schedule = fcfs(&processes);
+        assert_eq!(schedule.to_string(), "0 [P1] 24 [P2] 27 [P3] 30");
+        assert_eq!(schedule.average_waiting(), 17.0);
+        assert_eq!(schedule.average_turnaround(), 27.0);
+        // Short jobs first cut the average wait to 3
+        let reversed: Vec<Process> = processes.iter().rev().copied().collect();
+        assert_eq!(fcfs(&reversed).average_waiting(), 3.0);
+    }
+
+    #[test]
+    fn idle_gaps() {
+        let processes = [Process::new(1, 2, 3, 0), Process::new(2, 8, 2, 0)];
+        let schedule = fcfs(&processes);
+        assert_eq!(schedule.to_string(), "0 [--] 2 [P1] 5 [--] 8 [P2] 10");
+        assert_eq!(schedule.average_waiting(), 0.0);
+        assert_eq!(schedule.context_switches(), 1);
+    }
+}
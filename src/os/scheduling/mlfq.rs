@@ -0,0 +1,129 @@
+use crate::data_structure::Queue;
+
+use super::schedule::Recorder;
+use super::{Process, Schedule};
+
+/// Multi-level feedback queue: one round-robin queue per entry of `quanta`,
+/// highest priority first, each with its own quantum.
+///
+/// Arrivals join the top queue. A process that uses up its whole quantum
+/// moves down a queue, so CPU-bound jobs sink while short and interactive
+/// ones stay on top. An arrival preempts a process running below the top
+/// queue, which keeps its level. With `boost`, every `boost` units all
+/// processes go back to the top queue, so long jobs are not starved
+pub fn mlfq(processes: &[Process], quanta: &[u64], boost: Option<u64>) -> Schedule {
+    assert!(!quanta.is_empty(), "an MLFQ needs at least one queue");
+    assert!(quanta.iter().all(|&q| q > 0), "the quanta must be positive");
+    assert!(boost != Some(0), "the boost interval must be positive");
+    let mut recorder = Recorder::new(processes);
+    let mut by_arrival: Vec<usize> = (0..processes.len()).collect();
+    by_arrival.sort_by_key(|&i| processes[i].arrival);
+    let mut arrivals = by_arrival.into_iter().peekable();
+    let mut queues: Vec<Queue<usize>> = quanta.iter().map(|_| Queue::new()).collect();
+    let mut levels = vec![0; processes.len()];
+    let mut next_boost = boost.unwrap_or(u64::MAX);
+    let mut time = 0;
+    while !recorder.is_done() {
+        while let Some(i) = arrivals.next_if(|&i| processes[i].arrival <= time) {
+            queues[0].enqueue(i);
+        }
+        if let Some(interval) = boost.filter(|_| time >= next_boost) {
+            for level in 1..queues.len() {
+                while let Some(i) = queues[level].dequeue() {
+                    levels[i] = 0;
+                    queues[0].enqueue(i);
+                }
+            }
+            next_boost = (time / interval + 1) * interval;
+        }
+        let Some(level) = queues.iter().position(|q| !q.is_empty()) else {
+            let arrival = processes[*arrivals.peek().unwrap()].arrival;
+            recorder.idle(time, arrival);
+            time = arrival;
+            continue;
+        };
+        let index = queues[level].dequeue().unwrap();
+        let mut limit = quanta[level].min(next_boost - time);
+        if level > 0
+            && let Some(&next) = arrivals.peek()
+        {
+            limit = limit.min(processes[next].arrival - time);
+        }
+        let start = time;
+        time = recorder.run(index, time, limit);
+        while let Some(i) = arrivals.next_if(|&i| processes[i].arrival <= time) {
+            queues[0].enqueue(i);
+        }
+        if recorder.remaining(index) > 0 {
+            if time - start == quanta[level] {
+                levels[index] = (level + 1).min(quanta.len() - 1);
+            }
+            queues[levels[index]].enqueue(index);
+        }
+    }
+    recorder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mlfq;
+    use crate::os::scheduling::{Process, round_robin, sjf};
+
+    #[test]
+    fn demotes_on_a_used_up_quantum() {
+        let processes = [
+            Process::new(1, 0, 10, 0),
+            Process::new(2, 1, 3, 0),
+            Process::new(3, 2, 1, 0),
+        ];
+        let schedule = mlfq(&processes, &[2, 4, 8], None);
+        assert_eq!(
+            schedule.to_string(),
+            "0 [P1] 2 [P2] 4 [P3] 5 [P1] 9 [P2] 10 [P1] 14"
+        );
+        // One queue is plain round robin
+        assert_eq!(mlfq(&processes, &[3], None), round_robin(&processes, 3));
+    }
+
+    #[test]
+    fn arrivals_preempt_lower_queues() {
+        let processes = [Process::new(1, 0, 10, 0), Process::new(2, 3, 2, 0)];
+        let schedule = mlfq(&processes, &[1, 4], None);
+        assert_eq!(
+            schedule.to_string(),
+            "0 [P1] 3 [P2] 4 [P1] 8 [P2] 9 [P1] 12"
+        );
+    }
+
+    #[test]
+    fn boost_lifts_starved_jobs() {
+        let mut processes = vec![Process::new(1, 0, 6, 0)];
+        processes.extend((2..=6).map(|id| Process::new(id, 2 * (id as u64 - 1), 2, 0)));
+        let schedule = mlfq(&processes, &[2, 10], None);
+        assert_eq!(
+            schedule.to_string(),
+            "0 [P1] 2 [P2] 4 [P3] 6 [P4] 8 [P5] 10 [P6] 12 [P1] 16"
+        );
+        let schedule = mlfq(&processes, &[2, 10], Some(5));
+        assert_eq!(
+            schedule.to_string(),
+            "0 [P1] 2 [P2] 4 [P3] 6 [P1] 8 [P4] 10 [P5] 12 [P6] 14 [P1] 16"
+        );
+    }
+
+    #[test]
+    fn compared_with_round_robin_and_sjf() {
+        // Two CPU-bound jobs and a stream of short interactive ones
+        let mut processes = vec![Process::new(1, 0, 40, 0), Process::new(2, 0, 30, 0)];
+        processes.extend((3..=10).map(|id| Process::new(id, 5 * id as u64, 2, 0)));
+        let mlfq = mlfq(&processes, &[2, 8, 32], Some(50));
+        let rr = round_robin(&processes, 8);
+        let sjf = sjf(&processes);
+        // Short jobs get the CPU almost at once, unlike behind a long burst
+        assert!(mlfq.average_response() < rr.average_response());
+        assert!(mlfq.average_response() < sjf.average_response());
+        // and finish sooner on average than under plain round robin: 16.0
+        // against 32.0 here, with SJF at 24.4
+        assert!(mlfq.average_turnaround() < rr.average_turnaround());
+    }
+}
@@ -0,0 +1,15 @@
+mod fcfs;
+mod mlfq;
+mod priority;
+mod process;
+mod round_robin;
+mod schedule;
+mod sjf;
+
+pub use self::fcfs::fcfs;
+pub use self::mlfq::mlfq;
+pub use self::priority::priority;
+pub use self::process::Process;
+pub use self::round_robin::round_robin;
+pub use self::schedule::{ProcessStats, Schedule, Slice};
+pub use self::sjf::{sjf, srtf};
@@ -0,0 +1,43 @@
+use super::schedule::simulate;
+use super::{Process, Schedule};
+
+/// Runs the ready process with the lowest priority number, either to
+/// completion or, if `preemptive`, until a more urgent one arrives
+pub fn priority(processes: &[Process], preemptive: bool) -> Schedule {
+    simulate(processes, preemptive, |p, _| (p.priority, p.arrival))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::priority;
+    use crate::os::scheduling::Process;
+
+    #[test]
+    fn non_preemptive() {
+        let processes = [
+            Process::new(1, 0, 10, 3),
+            Process::new(2, 0, 1, 1),
+            Process::new(3, 0, 2, 4),
+            Process::new(4, 0, 1, 5),
+            Process::new(5, 0, 5, 2),
+        ];
+        let schedule = priority(&processes, false);
+        assert_eq!(schedule.order(), [2, 5, 1, 3, 4]);
+        assert_eq!(schedule.average_waiting(), 8.2);
+    }
+
+    #[test]
+    fn preemptive() {
+        let processes = [
+            Process::new(1, 0, 5, 3),
+            Process::new(2, 2, 2, 1),
+            Process::new(3, 3, 3, 2),
+        ];
+        let schedule = priority(&processes, true);
+        assert_eq!(schedule.to_string(), "0 [P1] 2 [P2] 4 [P3] 7 [P1] 10");
+        assert_eq!(schedule.stats[0].response(), 0);
+        assert_eq!(schedule.stats[0].waiting(), 5);
+        let schedule = priority(&processes, false);
+        assert_eq!(schedule.to_string(), "0 [P1] 5 [P2] 7 [P3] 10");
+    }
+}
@@ -0,0 +1,21 @@
+/// A process to schedule: when it arrives, how long it needs the CPU and
+/// its priority, where a lower number is more urgent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Process {
+    pub id: usize,
+    pub arrival: u64,
+    pub burst: u64,
+    pub priority: u32,
+}
+
+impl Process {
+    pub fn new(id: usize, arrival: u64, burst: u64, priority: u32) -> Process {
+        assert!(burst > 0, "process {id} needs some CPU time");
+        Process {
+            id,
+            arrival,
+            burst,
+            priority,
+        }
+    }
+}
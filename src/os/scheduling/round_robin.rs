@@ -0,0 +1,75 @@
+use crate::data_structure::Queue;
+
+use super::schedule::Recorder;
+use super::{Process, Schedule};
+
+/// Round robin: ready processes take turns of up to `quantum` units in a
+/// FIFO queue. A process whose turn runs out goes to the back, behind any
+/// that arrived during the turn
+pub fn round_robin(processes: &[Process], quantum: u64) -> Schedule {
+    assert!(quantum > 0, "the quantum must be positive");
+    let mut recorder = Recorder::new(processes);
+    let mut by_arrival: Vec<usize> = (0..processes.len()).collect();
+    by_arrival.sort_by_key(|&i| processes[i].arrival);
+    let mut arrivals = by_arrival.into_iter().peekable();
+    let mut queue = Queue::new();
+    let mut time = 0;
+    while !recorder.is_done() {
+        while let Some(i) = arrivals.next_if(|&i| processes[i].arrival <= time) {
+            queue.enqueue(i);
+        }
+        let Some(index) = queue.dequeue() else {
+            let arrival = processes[*arrivals.peek().unwrap()].arrival;
+            recorder.idle(time, arrival);
+            time = arrival;
+            continue;
+        };
+        time = recorder.run(index, time, quantum);
+        while let Some(i) = arrivals.next_if(|&i| processes[i].arrival <= time) {
+            queue.enqueue(i);
+        }
+        if recorder.remaining(index) > 0 {
+            queue.enqueue(index);
+        }
+    }
+    recorder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::round_robin;
+    use crate::os::scheduling::{Process, fcfs};
+
+    #[test]
+    fn time_slices() {
+        let processes = [
+            Process::new(1, 0, 24, 0),
+            Process::new(2, 0, 3, 0),
+            Process::new(3, 0, 3, 0),
+        ];
+        let schedule = round_robin(&processes, 4);
+        assert_eq!(schedule.to_string(), "0 [P1] 4 [P2] 7 [P3] 10 [P1] 30");
+        assert_eq!(schedule.average_waiting(), 17.0 / 3.0);
+        assert_eq!(schedule.average_response(), 11.0 / 3.0);
+        // A quantum longer than every burst is FCFS
+        assert_eq!(round_robin(&processes, 100), fcfs(&processes));
+    }
+
+    #[test]
+    fn arrivals_queue_ahead_of_the_preempted() {
+        let processes = [
+            Process::new(1, 0, 5, 0),
+            Process::new(2, 1, 3, 0),
+            Process::new(3, 2, 1, 0),
+            Process::new(4, 9, 2, 0),
+        ];
+        let schedule = round_robin(&processes, 2);
+        assert_eq!(
+            schedule.to_string(),
+            "0 [P1] 2 [P2] 4 [P3] 5 [P1] 7 [P2] 8 [P1] 9 [P4] 11"
+        );
+        assert_eq!(schedule.context_switches(), 6);
+        let schedule = round_robin(&[Process::new(1, 3, 2, 0)], 1);
+        assert_eq!(schedule.to_string(), "0 [--] 3 [P1] 5");
+    }
+}
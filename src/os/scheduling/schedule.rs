@@ -0,0 +1,226 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::Process;
+
+/// A stretch of time the CPU spends on one process, or idle if None
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slice {
+    pub process: Option<usize>,
+    pub start: u64,
+    pub end: u64,
+}
+
+/// When a process first ran and when it finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessStats {
+    pub process: Process,
+    pub first_run: u64,
+    pub completion: u64,
+}
+
+impl ProcessStats {
+    /// Time from arrival to completion
+    pub fn turnaround(&self) -> u64 {
+        self.completion - self.process.arrival
+    }
+
+    /// Time spent ready but not running
+    pub fn waiting(&self) -> u64 {
+        self.turnaround() - self.process.burst
+    }
+
+    /// Time from arrival to first running
+    pub fn response(&self) -> u64 {
+        self.first_run - self.process.arrival
+    }
+}
+
+/// The outcome of a scheduling simulation: a Gantt-chart timeline and the
+/// stats of each process, in the order the processes were given
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Schedule {
+    pub timeline: Vec<Slice>,
+    pub stats: Vec<ProcessStats>,
+}
+
+impl Schedule {
+    pub fn average_waiting(&self) -> f64 {
+        self.average(ProcessStats::waiting)
+    }
+
+    pub fn average_turnaround(&self) -> f64 {
+        self.average(ProcessStats::turnaround)
+    }
+
+    pub fn average_response(&self) -> f64 {
+        self.average(ProcessStats::response)
+    }
+
+    /// How many times the CPU moves from one process to another, not
+    /// counting moves from or to idle
+    pub fn context_switches(&self) -> usize {
+        let busy: Vec<usize> = self.timeline.iter().filter_map(|s| s.process).collect();
+        busy.windows(2).filter(|pair| pair[0] != pair[1]).count()
+    }
+
+    /// The ids in the order they first ran
+    pub fn order(&self) -> Vec<usize> {
+        let mut order = Vec::new();
+        for id in self.timeline.iter().filter_map(|s| s.process) {
+            if !order.contains(&id) {
+                order.push(id);
+            }
+        }
+        order
+    }
+
+    fn average(&self, metric: fn(&ProcessStats) -> u64) -> f64 {
+        if self.stats.is_empty() {
+            return 0.0;
+        }
+        self.stats.iter().map(metric).sum::<u64>() as f64 / self.stats.len() as f64
+    }
+}
+
+/// A one-line Gantt chart, like `0 [P1] 5 [P2] 8 [--] 10 [P1] 12`
+impl Display for Schedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some(first) = self.timeline.first() else {
+            return Ok(());
+        };
+        write!(f, "{}", first.start)?;
+        for slice in &self.timeline {
+            match slice.process {
+                Some(id) => write!(f, " [P{id}] {}", slice.end)?,
+                None => write!(f, " [--] {}", slice.end)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds a schedule as a simulation hands out CPU time
+pub(super) struct Recorder<'a> {
+    processes: &'a [Process],
+    remaining: Vec<u64>,
+    first_run: Vec<Option<u64>>,
+    completion: Vec<Option<u64>>,
+    timeline: Vec<Slice>,
+}
+
+impl<'a> Recorder<'a> {
+    pub(super) fn new(processes: &'a [Process]) -> Recorder<'a> {
+        Recorder {
+            processes,
+            remaining: processes.iter().map(|p| p.burst).collect(),
+            first_run: vec![None; processes.len()],
+            completion: vec![None; processes.len()],
+            timeline: Vec::new(),
+        }
+    }
+
+    pub(super) fn remaining(&self, index: usize) -> u64 {
+        self.remaining[index]
+    }
+
+    pub(super) fn is_done(&self) -> bool {
+        self.completion.iter().all(Option::is_some)
+    }
+
+    /// The indices of the unfinished processes that have arrived by `time`
+    pub(super) fn ready(&self, time: u64) -> impl Iterator<Item = usize> + '_ {
+        (0..self.processes.len())
+            .filter(move |&i| self.completion[i].is_none() && self.processes[i].arrival <= time)
+    }
+
+    /// The earliest arrival of an unfinished process after `time`
+    pub(super) fn next_arrival(&self, time: u64) -> Option<u64> {
+        (0..self.processes.len())
+            .filter(|&i| self.completion[i].is_none())
+            .map(|i| self.processes[i].arrival)
+            .filter(|&arrival| arrival > time)
+            .min()
+    }
+
+    /// Records the CPU going idle from `start` to `end`
+    pub(super) fn idle(&mut self, start: u64, end: u64) {
+        if start < end {
+            self.push(None, start, end);
+        }
+    }
+
+    /// Runs process `index` from `start` for up to `time` units, returning
+    /// when it stops
+    pub(super) fn run(&mut self, index: usize, start: u64, time: u64) -> u64 {
+        let time = time.min(self.remaining[index]);
+        let end = start + time;
+        self.first_run[index].get_or_insert(start);
+        self.remaining[index] -= time;
+        if self.remaining[index] == 0 {
+            self.completion[index] = Some(end);
+        }
+        self.push(Some(self.processes[index].id), start, end);
+        end
+    }
+
+    fn push(&mut self, process: Option<usize>, start: u64, end: u64) {
+        match self.timeline.last_mut() {
+            Some(last) if last.process == process && last.end == start => last.end = end,
+            _ => self.timeline.push(Slice {
+                process,
+                start,
+                end,
+            }),
+        }
+    }
+
+    pub(super) fn finish(self) -> Schedule {
+        let stats = self
+            .processes
+            .iter()
+            .enumerate()
+            .map(|(i, &process)| ProcessStats {
+                process,
+                first_run: self.first_run[i].unwrap(),
+                completion: self.completion[i].unwrap(),
+            })
+            .collect();
+        Schedule {
+            timeline: self.timeline,
+            stats,
+        }
+    }
+}
+
+/// Runs the ready process with the smallest `key` each time the CPU is free,
+/// and with `preemptive` also whenever a process arrives, idling while
+/// nothing is ready. Ties go to the process already running, then to the
+/// one given first
+pub(super) fn simulate<K: Ord>(
+    processes: &[Process],
+    preemptive: bool,
+    key: impl Fn(&Process, u64) -> K,
+) -> Schedule {
+    let mut recorder = Recorder::new(processes);
+    let mut time = 0;
+    let mut running = None;
+    while !recorder.is_done() {
+        let next = recorder.ready(time).min_by_key(|&i| {
+            let key = key(&processes[i], recorder.remaining(i));
+            (key, Some(i) != running, i)
+        });
+        let Some(index) = next else {
+            let arrival = recorder.next_arrival(time).unwrap();
+            recorder.idle(time, arrival);
+            time = arrival;
+            continue;
+        };
+        let limit = match (preemptive, recorder.next_arrival(time)) {
+            (true, Some(arrival)) => arrival - time,
+            _ => u64::MAX,
+        };
+        time = recorder.run(index, time, limit);
+        running = Some(index);
+    }
+    recorder.finish()
+}
@@ -0,0 +1,66 @@
+use super::schedule::simulate;
+use super::{Process, Schedule};
+
+/// Shortest job first: whenever the CPU is free, runs the ready process
+/// with the shortest burst to completion
+pub fn sjf(processes: &[Process]) -> Schedule {
+    simulate(processes, false, |p, _| (p.burst, p.arrival))
+}
+
+/// Shortest remaining time first, the preemptive SJF: on every arrival the
+/// process with the least time left takes the CPU. It minimises the average
+/// waiting time, at the risk of starving long jobs
+pub fn srtf(processes: &[Process]) -> Schedule {
+    simulate(processes, true, |p, remaining| (remaining, p.arrival))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sjf, srtf};
+    use crate::os::scheduling::{Process, fcfs, round_robin};
+
+    fn workload() -> [Process; 4] {
+        [
+            Process::new(1, 0, 8, 0),
+            Process::new(2, 1, 4, 0),
+            Process::new(3, 2, 9, 0),
+            Process::new(4, 3, 5, 0),
+        ]
+    }
+
+    #[test]
+    fn shortest_job_first() {
+        let schedule = sjf(&workload());
+        // P1 holds the CPU until 8 although shorter jobs arrive
+        assert_eq!(schedule.to_string(), "0 [P1] 8 [P2] 12 [P4] 17 [P3] 26");
+        assert_eq!(schedule.average_waiting(), 7.75);
+        let all_at_once = [
+            Process::new(1, 0, 6, 0),
+            Process::new(2, 0, 8, 0),
+            Process::new(3, 0, 7, 0),
+            Process::new(4, 0, 3, 0),
+        ];
+        assert_eq!(sjf(&all_at_once).order(), [4, 1, 3, 2]);
+        assert_eq!(sjf(&all_at_once).average_waiting(), 7.0);
+    }
+
+    #[test]
+    fn shortest_remaining_time_first() {
+        let schedule = srtf(&workload());
+        assert_eq!(
+            schedule.to_string(),
+            "0 [P1] 1 [P2] 5 [P4] 10 [P1] 17 [P3] 26"
+        );
+        assert_eq!(schedule.average_waiting(), 6.5);
+        assert_eq!(schedule.average_turnaround(), 13.0);
+        assert_eq!(schedule.context_switches(), 4);
+        // No other policy waits less on this workload
+        for other in [
+            fcfs(&workload()),
+            sjf(&workload()),
+            round_robin(&workload(), 4),
+        ] {
+            assert!(schedule.average_waiting() <= other.average_waiting());
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! The items most programs reach for, from whichever modules are enabled
+//!
+//! ```
+//! use rs_computer_science::prelude::*;
+//! ```
+//!
+//! The collection traits come along, but types that implement several of
+//! them still answer `len` without qualification:
+//!
+//! ```
+//! # #[cfg(feature = "data_structure")] {
+//! use rs_computer_science::prelude::*;
+//! use std::collections::VecDeque;
+//!
+//! let mut list = LinkedList::new();
+//! list.insert_at_tail(1);
+//! assert_eq!(list.len(), 1);
+//! assert!(!list.is_empty());
+//!
+//! let mut queue = Queue::new();
+//! queue.enqueue(1);
+//! assert_eq!(queue.len(), 1);
+//!
+//! let deque = VecDeque::from([1, 2]);
+//! assert_eq!(deque.len(), 2);
+//! # }
+//! ```
+
+#[cfg(feature = "algorithm")]
+pub use crate::algorithm::random::{Rng, XorShift64Star};
+#[cfg(feature = "algorithm")]
+pub use crate::algorithm::sort::Sorter;
+#[cfg(feature = "automata")]
+pub use crate::automata::{Dfa, Nfa, Pda};
+#[cfg(feature = "concurrency")]
+pub use crate::concurrency::{
+    BlockingQueue, MyArc, RwLock, Semaphore, SpinLock, ThreadPool, channel, sync_channel,
+};
+#[cfg(feature = "data_structure")]
+pub use crate::data_structure::{BinaryHeap, BitVec, LinkedList, Queue, Rope};
+pub use crate::iter_utils::IterUtils;
+#[cfg(feature = "data_structure")]
+pub use crate::traits::{List, Map, PriorityQueue, Queue as _, Set};
+pub use crate::visualize::{ToDot, ToMermaid};
@@ -0,0 +1,107 @@
+use crate::algorithm::random::Rng;
+
+use super::TestGen;
+
+/// The shape of a generated integer array
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /// Independent draws from `0..2^32`
+    Uniform,
+    /// Uniform draws in ascending order
+    Sorted,
+    /// Uniform draws in descending order
+    Reversed,
+    /// Sorted, then this many random pairs swapped
+    NearlySorted(usize),
+    /// Draws from only this many distinct keys, `0..keys`
+    FewUniques(u32),
+    /// Normally distributed draws, rounded to the nearest integer
+    Gaussian { mean: f64, std_dev: f64 },
+}
+
+impl TestGen {
+    /// `len` integers with the given shape
+    pub fn ints(&mut self, len: usize, shape: Shape) -> Vec<i64> {
+        let rng = &mut self.rng;
+        match shape {
+            Shape::Uniform => (0..len).map(|_| i64::from(rng.next_u32())).collect(),
+            Shape::Sorted => {
+                let mut values = self.ints(len, Shape::Uniform);
+                values.sort_unstable();
+                values
+            }
+            Shape::Reversed => {
+                let mut values = self.ints(len, Shape::Sorted);
+                values.reverse();
+                values
+            }
+            Shape::NearlySorted(swaps) => {
+                let mut values = self.ints(len, Shape::Sorted);
+                if len > 0 {
+                    for _ in 0..swaps {
+                        values.swap(self.rng.gen_index(len), self.rng.gen_index(len));
+                    }
+                }
+                values
+            }
+            Shape::FewUniques(keys) => {
+                assert!(keys > 0, "need at least one key");
+                (0..len)
+                    .map(|_| rng.gen_range(0..u64::from(keys)) as i64)
+                    .collect()
+            }
+            Shape::Gaussian { mean, std_dev } => (0..len)
+                .map(|_| (mean + std_dev * self.standard_normal()).round() as i64)
+                .collect(),
+        }
+    }
+
+    /// A draw from N(0, 1) by the Box–Muller transform
+    fn standard_normal(&mut self) -> f64 {
+        // 1 - u lies in (0, 1], so the logarithm is finite
+        let u = 1.0 - self.rng.gen_f64();
+        let v = self.rng.gen_f64();
+        (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testgen::{Shape, TestGen};
+
+    #[test]
+    fn shapes_hold() {
+        let mut tg = TestGen::new(1);
+        let sorted = tg.ints(500, Shape::Sorted);
+        assert!(sorted.is_sorted());
+        let reversed = tg.ints(500, Shape::Reversed);
+        assert!(reversed.iter().rev().is_sorted());
+        let nearly = tg.ints(500, Shape::NearlySorted(5));
+        let out_of_place = nearly.windows(2).filter(|w| w[0] > w[1]).count();
+        assert!((1..=10).contains(&out_of_place), "{out_of_place}");
+        let few = tg.ints(500, Shape::FewUniques(4));
+        assert!(few.iter().all(|v| (0..4).contains(v)));
+        assert!(tg.ints(0, Shape::NearlySorted(3)).is_empty());
+    }
+
+    #[test]
+    fn gaussian_has_the_requested_moments() {
+        let mut tg = TestGen::new(2);
+        let values = tg.ints(
+            20_000,
+            Shape::Gaussian {
+                mean: 100.0,
+                std_dev: 15.0,
+            },
+        );
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<i64>() as f64 / n;
+        let variance = values
+            .iter()
+            .map(|&v| (v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        assert!((mean - 100.0).abs() < 0.5, "{mean}");
+        assert!((variance.sqrt() - 15.0).abs() < 0.5, "{variance}");
+    }
+}
@@ -0,0 +1,148 @@
+use std::ops::Range;
+
+use crate::algorithm::random::Rng;
+
+use super::TestGen;
+
+impl TestGen {
+    /// A uniformly random labelled tree on `n` nodes as undirected
+    /// adjacency lists, decoded from a random Prüfer sequence
+    pub fn tree(&mut self, n: usize) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); n];
+        if n < 2 {
+            return adjacency;
+        }
+        let prufer: Vec<usize> = (0..n - 2).map(|_| self.rng.gen_index(n)).collect();
+        let mut degree = vec![1; n];
+        for &v in &prufer {
+            degree[v] += 1;
+        }
+        let mut link = |a: usize, b: usize| {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        };
+        // Each step joins the smallest current leaf to the next code entry
+        let mut leaf = degree
+            .iter()
+            .position(|&d| d == 1)
+            .expect("a tree has a leaf");
+        let mut next = leaf;
+        for &v in &prufer {
+            link(leaf, v);
+            degree[v] -= 1;
+            if degree[v] == 1 && v < next {
+                leaf = v;
+            } else {
+                next += 1;
+                while degree[next] != 1 {
+                    next += 1;
+                }
+                leaf = next;
+            }
+        }
+        link(leaf, n - 1);
+        adjacency
+    }
+
+    /// A directed graph on `n` nodes as adjacency lists, where each edge
+    /// `u -> v` with `u != v` is present with probability `density`
+    pub fn graph(&mut self, n: usize, density: f64) -> Vec<Vec<usize>> {
+        (0..n)
+            .map(|u| {
+                (0..n)
+                    .filter(|&v| v != u && self.rng.gen_bool(density))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// A directed graph as a weight matrix, where each edge `u -> v` with
+    /// `u != v` is present with probability `density` and carries a weight
+    /// drawn uniformly from `weights`
+    pub fn weighted_graph(
+        &mut self,
+        n: usize,
+        density: f64,
+        weights: Range<i64>,
+    ) -> Vec<Vec<Option<i64>>> {
+        assert!(weights.start < weights.end, "weight range is empty");
+        let span = weights.end.abs_diff(weights.start);
+        (0..n)
+            .map(|u| {
+                (0..n)
+                    .map(|v| {
+                        (v != u && self.rng.gen_bool(density)).then(|| {
+                            weights
+                                .start
+                                .wrapping_add(self.rng.gen_range(0..span) as i64)
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::testgen::TestGen;
+
+    fn is_tree(adjacency: &[Vec<usize>]) -> bool {
+        let n = adjacency.len();
+        let edges: usize = adjacency.iter().map(Vec::len).sum();
+        let mut seen = vec![false; n];
+        let mut stack = vec![0];
+        while let Some(u) = stack.pop() {
+            if !std::mem::replace(&mut seen[u], true) {
+                stack.extend(&adjacency[u]);
+            }
+        }
+        edges == 2 * (n - 1) && seen.into_iter().all(|s| s)
+    }
+
+    #[test]
+    fn trees_are_connected_and_acyclic() {
+        let mut tg = TestGen::new(4);
+        for n in 1..40 {
+            assert!(is_tree(&tg.tree(n)), "n = {n}");
+        }
+        assert!(tg.tree(0).is_empty());
+    }
+
+    #[test]
+    fn trees_are_uniform() {
+        // Cayley: 3^(3-2) = 3 labelled trees on three nodes, each a path
+        // whose middle node has degree 2
+        let mut tg = TestGen::new(5);
+        let mut centres = HashMap::new();
+        for _ in 0..3000 {
+            let tree = tg.tree(3);
+            let centre = (0..3).find(|&v| tree[v].len() == 2).unwrap();
+            *centres.entry(centre).or_insert(0) += 1;
+        }
+        assert_eq!(centres.len(), 3);
+        assert!(
+            centres.values().all(|&c| (900..1100).contains(&c)),
+            "{centres:?}"
+        );
+    }
+
+    #[test]
+    fn graphs_respect_density_and_weights() {
+        let mut tg = TestGen::new(6);
+        let graph = tg.graph(60, 0.25);
+        let edges: usize = graph.iter().map(Vec::len).sum();
+        assert!((780..990).contains(&edges), "{edges}");
+        assert!(graph.iter().enumerate().all(|(u, vs)| !vs.contains(&u)));
+
+        let weights = tg.weighted_graph(30, 1.0, -3..4);
+        for (u, row) in weights.iter().enumerate() {
+            for (v, w) in row.iter().enumerate() {
+                assert_eq!(w.is_some(), u != v);
+                assert!(w.is_none_or(|w| (-3..4).contains(&w)));
+            }
+        }
+    }
+}
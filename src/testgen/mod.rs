@@ -0,0 +1,12 @@
+//! Seeded random inputs for tests and benchmarks: integer arrays of a chosen
+//! shape, strings over an alphabet, trees and graphs. The same seed always
+//! yields the same data, so a failing case can be replayed.
+
+mod arrays;
+mod graphs;
+mod strings;
+mod testgen;
+
+pub use self::arrays::Shape;
+pub use self::strings::{ALPHANUMERIC, BINARY, DNA, LOWERCASE};
+pub use self::testgen::TestGen;
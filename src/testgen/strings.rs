@@ -0,0 +1,36 @@
+use crate::algorithm::random::Rng;
+
+use super::TestGen;
+
+pub const BINARY: &str = "01";
+pub const DNA: &str = "ACGT";
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+pub const ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+impl TestGen {
+    /// `len` chars drawn uniformly from `alphabet`, which may hold any
+    /// Unicode. Panics if the alphabet is empty.
+    pub fn string(&mut self, len: usize, alphabet: &str) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+        assert!(!chars.is_empty(), "alphabet is empty");
+        (0..len)
+            .map(|_| chars[self.rng.gen_index(chars.len())])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testgen::{BINARY, TestGen};
+
+    #[test]
+    fn draws_only_from_the_alphabet() {
+        let mut tg = TestGen::new(3);
+        let s = tg.string(1000, "αβγ");
+        assert_eq!(s.chars().count(), 1000);
+        assert!(s.chars().all(|c| "αβγ".contains(c)));
+        let bits = tg.string(1000, BINARY);
+        let ones = bits.matches('1').count();
+        assert!((400..600).contains(&ones), "{ones}");
+    }
+}
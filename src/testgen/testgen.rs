@@ -0,0 +1,42 @@
+use crate::algorithm::random::{Rng, XorShift64Star};
+
+/// A generator of test inputs. Successive calls give different data, but
+/// the whole sequence is determined by the seed.
+#[derive(Debug, Clone)]
+pub struct TestGen {
+    pub(super) rng: XorShift64Star,
+}
+
+impl TestGen {
+    pub fn new(seed: u64) -> TestGen {
+        TestGen {
+            rng: XorShift64Star::seed_from_u64(seed),
+        }
+    }
+
+    /// The underlying generator, for draws the helpers do not cover
+    pub fn rng(&mut self) -> &mut XorShift64Star {
+        &mut self.rng
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestGen;
+    use crate::testgen::{DNA, Shape};
+
+    #[test]
+    fn same_seed_same_data() {
+        let draw = |seed| {
+            let mut tg = TestGen::new(seed);
+            (
+                tg.ints(50, Shape::Uniform),
+                tg.string(20, DNA),
+                tg.tree(30),
+                tg.weighted_graph(8, 0.5, -5..10),
+            )
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+    }
+}
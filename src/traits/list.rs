@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+
+use crate::data_structure::LinkedList;
+
+/// A sequence with access by position. Out-of-range positions give None,
+/// or panic for `insert`, like `Vec::insert`
+pub trait List<T> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, index: usize) -> Option<&T>;
+
+    /// Inserts at `index`, shifting later elements back
+    fn insert(&mut self, index: usize, value: T);
+
+    /// Removes and returns the element at `index`, shifting later ones up
+    fn remove(&mut self, index: usize) -> Option<T>;
+
+    fn push_front(&mut self, value: T) {
+        self.insert(0, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.insert(self.len(), value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.len().checked_sub(1).and_then(|last| self.remove(last))
+    }
+}
+
+impl<T> List<T> for LinkedList<T> {
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        LinkedList::get(self, i32::try_from(index).ok()?)
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "Index out of bounds");
+        self.insert_at_ith(index as u32, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.delete_ith(index as u32)
+    }
+
+    fn push_front(&mut self, value: T) {
+        self.insert_at_head(value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.insert_at_tail(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.delete_head()
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.delete_tail()
+    }
+}
+
+impl<T> List<T> for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        Vec::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| Vec::remove(self, index))
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop()
+    }
+}
+
+impl<T> List<T> for VecDeque<T> {
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        VecDeque::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        VecDeque::remove(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        VecDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        VecDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        VecDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        VecDeque::pop_back(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::data_structure::LinkedList;
+    use crate::traits::List;
+
+    fn exercise<L: List<i32> + Default>() {
+        let mut list = L::default();
+        assert!(list.is_empty());
+        list.push_back(2);
+        list.push_front(0);
+        list.insert(1, 1);
+        list.push_back(3);
+        assert_eq!(list.len(), 4);
+        assert_eq!(
+            (0..4).map(|i| *list.get(i).unwrap()).collect::<Vec<_>>(),
+            [0, 1, 2, 3]
+        );
+        assert_eq!(list.get(4), None);
+        assert_eq!(list.remove(1), Some(1));
+        assert_eq!(list.remove(3), None);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        exercise::<LinkedList<i32>>();
+        exercise::<Vec<i32>>();
+        exercise::<VecDeque<i32>>();
+    }
+}
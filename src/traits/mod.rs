@@ -0,0 +1,11 @@
+mod list;
+mod map;
+mod priority_queue;
+mod queue;
+mod set;
+
+pub use self::list::List;
+pub use self::map::Map;
+pub use self::priority_queue::PriorityQueue;
+pub use self::queue::Queue;
+pub use self::set::Set;
@@ -0,0 +1,85 @@
+use std::collections;
+
+use crate::data_structure::BinaryHeap;
+
+/// Hands out the greatest element first. Wrap elements in `Reverse` for
+/// the least
+pub trait PriorityQueue<T: Ord> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push(&mut self, value: T);
+
+    /// Removes the greatest element, or None if empty
+    fn pop(&mut self) -> Option<T>;
+
+    /// The greatest element, or None if empty
+    fn peek(&self) -> Option<&T>;
+}
+
+impl<T: Ord> PriorityQueue<T> for BinaryHeap<T> {
+    fn len(&self) -> usize {
+        BinaryHeap::len(self)
+    }
+
+    fn push(&mut self, value: T) {
+        BinaryHeap::push(self, value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        BinaryHeap::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        BinaryHeap::peek(self)
+    }
+}
+
+impl<T: Ord> PriorityQueue<T> for collections::BinaryHeap<T> {
+    fn len(&self) -> usize {
+        collections::BinaryHeap::len(self)
+    }
+
+    fn push(&mut self, value: T) {
+        collections::BinaryHeap::push(self, value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        collections::BinaryHeap::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        collections::BinaryHeap::peek(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections;
+
+    use crate::data_structure::BinaryHeap;
+    use crate::traits::PriorityQueue;
+
+    fn exercise<P: PriorityQueue<i32> + Default>() {
+        let mut heap = P::default();
+        for value in [5, 1, 8, 3, 9, 2] {
+            heap.push(value);
+        }
+        assert_eq!(heap.peek(), Some(&9));
+        let mut drained = Vec::new();
+        while let Some(value) = heap.pop() {
+            drained.push(value);
+        }
+        assert_eq!(drained, [9, 8, 5, 3, 2, 1]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn implementations_agree() {
+        exercise::<BinaryHeap<i32>>();
+        exercise::<collections::BinaryHeap<i32>>();
+    }
+}
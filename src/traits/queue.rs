@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+
+use crate::data_structure;
+
+/// First in, first out
+pub trait Queue<T> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds an element at the back
+    fn enqueue(&mut self, value: T);
+
+    /// Removes the front element, or None if empty
+    fn dequeue(&mut self) -> Option<T>;
+
+    /// The front element, or None if empty
+    fn peek(&self) -> Option<&T>;
+}
+
+impl<T> Queue<T> for data_structure::Queue<T> {
+    fn len(&self) -> usize {
+        data_structure::Queue::len(self)
+    }
+
+    fn enqueue(&mut self, value: T) {
+        data_structure::Queue::enqueue(self, value);
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        data_structure::Queue::dequeue(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.peek_front()
+    }
+}
+
+impl<T> Queue<T> for data_structure::LinkedList<T> {
+    fn len(&self) -> usize {
+        data_structure::LinkedList::len(self)
+    }
+
+    fn enqueue(&mut self, value: T) {
+        self.insert_at_tail(value);
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        self.delete_head()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.get(0)
+    }
+}
+
+impl<T> Queue<T> for VecDeque<T> {
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn enqueue(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::data_structure::{self, LinkedList};
+    use crate::traits::Queue;
+
+    fn exercise<Q: Queue<i32> + Default>() {
+        let mut queue = Q::default();
+        assert_eq!(queue.dequeue(), None);
+        for i in 0..5 {
+            queue.enqueue(i);
+        }
+        assert_eq!(queue.peek(), Some(&0));
+        assert_eq!(queue.dequeue(), Some(0));
+        queue.enqueue(5);
+        assert_eq!(queue.len(), 5);
+        assert_eq!(
+            (0..5).map(|_| queue.dequeue().unwrap()).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5]
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn implementations_agree() {
+        exercise::<data_structure::Queue<i32>>();
+        exercise::<LinkedList<i32>>();
+        exercise::<VecDeque<i32>>();
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+
+/// A collection of distinct elements
+pub trait Set<T> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `value`, returning whether it was new
+    fn insert(&mut self, value: T) -> bool;
+
+    fn contains(&self, value: &T) -> bool;
+
+    /// Removes `value`, returning whether it was there
+    fn remove(&mut self, value: &T) -> bool;
+}
+
+impl<T: Eq + Hash> Set<T> for HashSet<T> {
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        HashSet::insert(self, value)
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        HashSet::remove(self, value)
+    }
+}
+
+impl<T: Ord> Set<T> for BTreeSet<T> {
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        BTreeSet::insert(self, value)
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        BTreeSet::contains(self, value)
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        BTreeSet::remove(self, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use crate::traits::Set;
+
+    fn exercise<S: Set<u8> + Default>() {
+        let mut set = S::default();
+        assert!(set.insert(3));
+        assert!(set.insert(1));
+        assert!(!set.insert(3));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&1) && !set.contains(&2));
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        exercise::<HashSet<u8>>();
+        exercise::<BTreeSet<u8>>();
+    }
+}
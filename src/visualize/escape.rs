@@ -0,0 +1,37 @@
+/// Escapes text for a double-quoted DOT label
+pub fn escape_dot(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escapes text for a double-quoted Mermaid node label
+pub fn escape_mermaid(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("#quot;"),
+            '\n' => escaped.push_str("<br>"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_dot, escape_mermaid};
+
+    #[test]
+    fn escapes_quotes_and_newlines() {
+        assert_eq!(escape_dot("say \"hi\"\\\n"), "say \\\"hi\\\"\\\\\\n");
+        assert_eq!(escape_mermaid("say \"hi\"\n"), "say #quot;hi#quot;<br>");
+    }
+}
@@ -0,0 +1,5 @@
+mod escape;
+mod to_dot;
+
+pub use self::escape::{escape_dot, escape_mermaid};
+pub use self::to_dot::{ToDot, ToMermaid};
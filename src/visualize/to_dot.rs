@@ -0,0 +1,11 @@
+/// Renders a snapshot of a structure as Graphviz DOT source, for
+/// `dot -Tsvg` or any online viewer
+pub trait ToDot {
+    fn to_dot(&self) -> String;
+}
+
+/// Renders a snapshot of a structure as a Mermaid flowchart, which Markdown
+/// viewers such as GitHub draw inline
+pub trait ToMermaid {
+    fn to_mermaid(&self) -> String;
+}
@@ -0,0 +1,70 @@
+use wasm_bindgen::prelude::*;
+
+use crate::data_structure::BinaryHeap;
+use crate::visualize::{ToDot, ToMermaid};
+
+/// A max-heap of integers
+#[wasm_bindgen(js_name = Heap)]
+#[derive(Default)]
+pub struct WasmHeap {
+    heap: BinaryHeap<i32>,
+}
+
+#[wasm_bindgen(js_class = Heap)]
+impl WasmHeap {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmHeap {
+        WasmHeap::default()
+    }
+
+    pub fn push(&mut self, value: i32) {
+        self.heap.push(value);
+    }
+
+    pub fn pop(&mut self) -> Option<i32> {
+        self.heap.pop()
+    }
+
+    pub fn peek(&self) -> Option<i32> {
+        self.heap.peek().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    #[wasm_bindgen(js_name = toDot)]
+    pub fn to_dot(&self) -> String {
+        self.heap.to_dot()
+    }
+
+    #[wasm_bindgen(js_name = toMermaid)]
+    pub fn to_mermaid(&self) -> String {
+        self.heap.to_mermaid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WasmHeap;
+
+    #[test]
+    fn pops_in_descending_order() {
+        let mut heap = WasmHeap::new();
+        for value in [4, 9, 1] {
+            heap.push(value);
+        }
+        assert_eq!(heap.peek(), Some(9));
+        assert!(heap.to_dot().contains("0 [label=\"9\"]"));
+        assert_eq!(
+            [heap.pop(), heap.pop(), heap.pop(), heap.pop()],
+            [Some(9), Some(4), Some(1), None]
+        );
+        assert!(heap.is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+use wasm_bindgen::prelude::*;
+
+use crate::data_structure::LinkedList;
+use crate::visualize::{ToDot, ToMermaid};
+
+/// A doubly linked list of integers
+#[wasm_bindgen(js_name = LinkedList)]
+#[derive(Default)]
+pub struct WasmList {
+    list: LinkedList<i32>,
+}
+
+#[wasm_bindgen(js_class = LinkedList)]
+impl WasmList {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmList {
+        WasmList::default()
+    }
+
+    #[wasm_bindgen(js_name = pushFront)]
+    pub fn push_front(&mut self, value: i32) {
+        self.list.insert_at_head(value);
+    }
+
+    #[wasm_bindgen(js_name = pushBack)]
+    pub fn push_back(&mut self, value: i32) {
+        self.list.insert_at_tail(value);
+    }
+
+    #[wasm_bindgen(js_name = popFront)]
+    pub fn pop_front(&mut self) -> Option<i32> {
+        self.list.delete_head()
+    }
+
+    #[wasm_bindgen(js_name = popBack)]
+    pub fn pop_back(&mut self) -> Option<i32> {
+        self.list.delete_tail()
+    }
+
+    /// Inserts at `index`, or returns false if it is past the end
+    pub fn insert(&mut self, index: u32, value: i32) -> bool {
+        if index > self.list.length {
+            return false;
+        }
+        self.list.insert_at_ith(index, value);
+        true
+    }
+
+    pub fn remove(&mut self, index: u32) -> Option<i32> {
+        if index >= self.list.length {
+            return None;
+        }
+        self.list.delete_ith(index)
+    }
+
+    pub fn get(&self, index: u32) -> Option<i32> {
+        self.list.get(i32::try_from(index).ok()?).copied()
+    }
+
+    pub fn len(&self) -> u32 {
+        self.list.length
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.list.length == 0
+    }
+
+    #[wasm_bindgen(js_name = toDot)]
+    pub fn to_dot(&self) -> String {
+        self.list.to_dot()
+    }
+
+    #[wasm_bindgen(js_name = toMermaid)]
+    pub fn to_mermaid(&self) -> String {
+        self.list.to_mermaid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WasmList;
+
+    #[test]
+    fn rejects_out_of_range_indices() {
+        let mut list = WasmList::new();
+        list.push_back(2);
+        list.push_front(1);
+        assert!(list.insert(2, 3));
+        assert!(!list.insert(5, 9));
+        assert_eq!(list.remove(3), None);
+        assert_eq!(
+            (0..3).map(|i| list.get(i)).collect::<Vec<_>>(),
+            [Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(
+            (list.pop_back(), list.pop_front(), list.pop_front()),
+            (Some(3), Some(1), None)
+        );
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,12 @@
+//! JavaScript bindings for browser demos. Build with
+//! `wasm-pack build --features wasm`
+//!
+//! The parallel sorts are left out: `wasm32-unknown-unknown` has no threads
+
+mod heap;
+mod list;
+mod sort;
+
+pub use self::heap::WasmHeap;
+pub use self::list::WasmList;
+pub use self::sort::{sort, sort_algorithms, sort_trace};
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
+
+use wasm_bindgen::prelude::*;
+
+use crate::algorithm::sort::{
+    BubbleSort, GapSequence, GnomeSort, HeapSort, InsertionSort, IntroSort, SelectionSort,
+    ShellSort, Sorter, TimSort,
+};
+
+/// The single-threaded comparison sorters, looked up by `Sorter::name`
+fn sorters<T: Ord + Clone>() -> Vec<Box<dyn Sorter<T>>> {
+    vec![
+        Box::new(BubbleSort),
+        Box::new(InsertionSort),
+        Box::new(SelectionSort),
+        Box::new(GnomeSort),
+        Box::new(ShellSort(GapSequence::Ciura)),
+        Box::new(HeapSort),
+        Box::new(TimSort),
+        Box::new(IntroSort),
+    ]
+}
+
+fn find<T: Ord + Clone>(algorithm: &str) -> Option<Box<dyn Sorter<T>>> {
+    sorters().into_iter().find(|s| s.name() == algorithm)
+}
+
+/// A value that logs every comparison made on it in a shared trace
+#[derive(Clone)]
+struct Traced {
+    value: i32,
+    trace: Arc<Mutex<Vec<i32>>>,
+}
+
+impl PartialEq for Traced {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Traced {}
+
+impl PartialOrd for Traced {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Traced {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.trace.lock().unwrap().extend([self.value, other.value]);
+        self.value.cmp(&other.value)
+    }
+}
+
+/// The names `sort` and `sortTrace` accept
+#[wasm_bindgen(js_name = sortAlgorithms)]
+pub fn sort_algorithms() -> Vec<String> {
+    sorters::<i32>()
+        .iter()
+        .map(|s| s.name().to_string())
+        .collect()
+}
+
+/// Sorts `values` with the named algorithm, or returns undefined if there is
+/// no such algorithm
+#[wasm_bindgen]
+pub fn sort(algorithm: &str, mut values: Vec<i32>) -> Option<Vec<i32>> {
+    find(algorithm)?.sort(&mut values);
+    Some(values)
+}
+
+/// The comparisons the named algorithm makes while sorting `values`,
+/// flattened into pairs: `[a0, b0, a1, b1, ...]`
+#[wasm_bindgen(js_name = sortTrace)]
+pub fn sort_trace(algorithm: &str, values: Vec<i32>) -> Option<Vec<i32>> {
+    let sorter = find(algorithm)?;
+    let trace = Arc::new(Mutex::new(Vec::new()));
+    let mut arr: Vec<Traced> = values
+        .into_iter()
+        .map(|value| Traced {
+            value,
+            trace: Arc::clone(&trace),
+        })
+        .collect();
+    sorter.sort(&mut arr);
+    drop(arr);
+    Some(Arc::into_inner(trace)?.into_inner().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sort, sort_algorithms, sort_trace};
+
+    #[test]
+    fn every_algorithm_sorts() {
+        for name in sort_algorithms() {
+            assert_eq!(
+                sort(&name, vec![3, -1, 2, 2]),
+                Some(vec![-1, 2, 2, 3]),
+                "{name}"
+            );
+        }
+        assert_eq!(sort("bogosort", vec![1]), None);
+    }
+
+    #[test]
+    fn traces_each_comparison() {
+        // Bubble sort over [2, 1, 3]: swap the first pair, then one clean pass
+        let trace = sort_trace("bubble_sort", vec![2, 1, 3]).unwrap();
+        assert_eq!(trace, [1, 2, 3, 2, 2, 1]);
+        assert_eq!(sort_trace("insertion_sort", vec![]), Some(vec![]));
+    }
+}